@@ -1,38 +1,45 @@
 use sqlx::PgPool;
 use std::time::Duration;
 
+use crate::background_jobs;
+
+/// How often the aggregation task runs
+const AGGREGATION_INTERVAL: Duration = Duration::from_secs(3600);
+
 /// Spawn the background aggregation task.
 ///
-/// Runs every hour:
-///   1. Aggregate raw events into daily summaries (yesterday + today).
-///   2. Delete raw events older than 90 days.
+/// Runs every hour, aggregating raw events into daily summaries (yesterday +
+/// today) and rolling up custom metrics. Raw events are pruned separately by
+/// `analytics_retention`, only after their day has been aggregated.
+///
+/// Each run is recorded in `background_jobs` under `aggregation`.
 pub fn spawn_aggregation_task(pool: PgPool) {
     tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        let mut interval = tokio::time::interval(AGGREGATION_INTERVAL);
 
         loop {
             interval.tick().await;
             tracing::info!("aggregation: starting hourly run");
 
-            if let Err(err) = run_aggregation(&pool).await {
-                tracing::error!(error = ?err, "aggregation: run failed");
-            }
-
-            if let Err(err) = cleanup_old_events(&pool).await {
-                tracing::error!(error = ?err, "aggregation: retention cleanup failed");
-            }
-
-            if let Err(err) = run_custom_metrics_aggregation(&pool).await {
-                tracing::error!(error = ?err, "aggregation: custom metrics aggregation failed");
-            }
+            background_jobs::run_tracked(&pool, background_jobs::JOB_AGGREGATION, AGGREGATION_INTERVAL, || {
+                run_all(&pool)
+            })
+            .await;
         }
     });
 }
 
+/// One aggregation run; returns the total rows written.
+async fn run_all(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let aggregated = run_aggregation(pool).await?;
+    let custom = run_custom_metrics_aggregation(pool).await?;
+    Ok(aggregated + custom)
+}
+
 /// Build daily aggregates from raw `analytics_events`.
 ///
 /// Uses `ON CONFLICT … DO UPDATE` so re-running is idempotent.
-async fn run_aggregation(pool: &PgPool) -> Result<(), sqlx::Error> {
+async fn run_aggregation(pool: &PgPool) -> Result<u64, sqlx::Error> {
     // Aggregate events from the last 2 days (yesterday + partial today)
     // to ensure we always capture the freshest data.
     let rows_affected = sqlx::query(
@@ -123,26 +130,11 @@ async fn run_aggregation(pool: &PgPool) -> Result<(), sqlx::Error> {
         rows = rows_affected,
         "aggregation: daily summaries upserted"
     );
-    Ok(())
-}
-
-/// Delete raw analytics events older than 90 days.
-async fn cleanup_old_events(pool: &PgPool) -> Result<(), sqlx::Error> {
-    let deleted =
-        sqlx::query("DELETE FROM analytics_events WHERE created_at < NOW() - INTERVAL '90 days'")
-            .execute(pool)
-            .await?
-            .rows_affected();
-
-    if deleted > 0 {
-        tracing::info!(deleted, "aggregation: cleaned up old raw events");
-    }
-
-    Ok(())
+    Ok(rows_affected)
 }
 
 /// Aggregate custom contract metrics into hourly and daily rollups.
-async fn run_custom_metrics_aggregation(pool: &PgPool) -> Result<(), sqlx::Error> {
+async fn run_custom_metrics_aggregation(pool: &PgPool) -> Result<u64, sqlx::Error> {
     let hourly_rows = sqlx::query(
         r#"
         INSERT INTO contract_custom_metrics_hourly (
@@ -235,5 +227,5 @@ async fn run_custom_metrics_aggregation(pool: &PgPool) -> Result<(), sqlx::Error
         "aggregation: custom metrics rollups updated"
     );
 
-    Ok(())
+    Ok(hourly_rows + daily_rows)
 }
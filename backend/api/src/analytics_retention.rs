@@ -0,0 +1,213 @@
+// api/src/analytics_retention.rs
+// Retention policy for raw analytics events.
+//
+// Raw `analytics_events` rows are only needed until they have been rolled up
+// into `analytics_daily_aggregates`. Once an event is older than the retention
+// window *and* its (contract, day) has an aggregate, it is deleted. Events whose
+// day was never aggregated are kept so no data is lost if aggregation falls
+// behind. Aggregates themselves are never pruned.
+//
+// The window is `ANALYTICS_RETENTION_DAYS` (default 90). The prune runs under a
+// transaction-scoped advisory lock, so when several API replicas are running
+// only one of them deletes at a time; the others skip that run.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::background_jobs;
+
+/// How often the retention task runs
+const RETENTION_INTERVAL: Duration = Duration::from_secs(6 * 3600);
+
+const DEFAULT_RETENTION_DAYS: i64 = 90;
+
+/// Key for `pg_try_advisory_xact_lock`; any constant unique to this job works
+const RETENTION_LOCK_KEY: i64 = 0x616e_616c_7974_6963;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetentionPolicy {
+    pub window_days: i64,
+}
+
+impl Default for RetentionPolicy {
+    fn default() -> Self {
+        Self {
+            window_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+impl RetentionPolicy {
+    /// Read `ANALYTICS_RETENTION_DAYS`; invalid or non-positive values fall back to the default.
+    pub fn from_env() -> Self {
+        let window_days = std::env::var("ANALYTICS_RETENTION_DAYS")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i64>().ok())
+            .filter(|days| *days > 0)
+            .unwrap_or(DEFAULT_RETENTION_DAYS);
+        Self { window_days }
+    }
+
+    /// Events created before this instant are past the window.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - ChronoDuration::days(self.window_days)
+    }
+}
+
+/// Spawn the background retention task.
+///
+/// Each run is recorded in `background_jobs` under `analytics_retention`.
+pub fn spawn_retention_task(pool: PgPool) {
+    let policy = RetentionPolicy::from_env();
+    tracing::info!(window_days = policy.window_days, "analytics retention: policy loaded");
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RETENTION_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            background_jobs::run_tracked(
+                &pool,
+                background_jobs::JOB_ANALYTICS_RETENTION,
+                RETENTION_INTERVAL,
+                || prune_raw_events(&pool, policy, Utc::now()),
+            )
+            .await;
+        }
+    });
+}
+
+/// Delete aggregated raw events older than the window; returns the rows deleted.
+///
+/// Returns `Ok(0)` without deleting anything if another instance holds the lock.
+pub async fn prune_raw_events(pool: &PgPool, policy: RetentionPolicy, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    let locked: bool = sqlx::query_scalar("SELECT pg_try_advisory_xact_lock($1)")
+        .bind(RETENTION_LOCK_KEY)
+        .fetch_one(&mut *tx)
+        .await?;
+    if !locked {
+        tracing::info!("analytics retention: another instance is pruning, skipping run");
+        return Ok(0);
+    }
+
+    let deleted = sqlx::query(
+        "DELETE FROM analytics_events e
+         WHERE e.created_at < $1
+           AND EXISTS (
+               SELECT 1 FROM analytics_daily_aggregates a
+               WHERE a.contract_id = e.contract_id
+                 AND a.date = DATE(e.created_at)
+           )",
+    )
+    .bind(policy.cutoff(now))
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    tx.commit().await?;
+
+    if deleted > 0 {
+        tracing::info!(deleted, window_days = policy.window_days, "analytics retention: pruned raw events");
+    }
+    Ok(deleted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn cutoff_is_the_window_before_now() {
+        let policy = RetentionPolicy { window_days: 30 };
+        assert_eq!(policy.cutoff(now()), now() - ChronoDuration::days(30));
+    }
+
+    #[test]
+    fn invalid_env_falls_back_to_default() {
+        std::env::set_var("ANALYTICS_RETENTION_DAYS", "-3");
+        assert_eq!(RetentionPolicy::from_env(), RetentionPolicy::default());
+        std::env::set_var("ANALYTICS_RETENTION_DAYS", "14");
+        assert_eq!(RetentionPolicy::from_env().window_days, 14);
+        std::env::remove_var("ANALYTICS_RETENTION_DAYS");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn prune_keeps_recent_and_unaggregated_events_and_all_aggregates() {
+        let pool = crate::fixtures::test_pool().await;
+        let policy = RetentionPolicy { window_days: 30 };
+
+        let publisher: Uuid = sqlx::query_scalar(
+            "INSERT INTO publishers (stellar_address) VALUES ($1) RETURNING id",
+        )
+        .bind(format!("GRETENTION{}", Uuid::new_v4().simple()))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let contract: Uuid = sqlx::query_scalar(
+            "INSERT INTO contracts (contract_id, wasm_hash, name, publisher_id, network)
+             VALUES ($1, '00', 'retention', $2, 'testnet') RETURNING id",
+        )
+        .bind(format!("CRETENTION{}", Uuid::new_v4().simple()))
+        .bind(publisher)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let edge = policy.cutoff(now());
+        let past_edge = edge - ChronoDuration::seconds(1);
+        let old = now() - ChronoDuration::days(45);
+        let unaggregated = now() - ChronoDuration::days(50);
+        let recent = now() - ChronoDuration::days(5);
+        for at in [old, unaggregated, edge, past_edge, recent] {
+            sqlx::query(
+                "INSERT INTO analytics_events (event_type, contract_id, created_at)
+                 VALUES ('contract_deployed', $1, $2)",
+            )
+            .bind(contract)
+            .bind(at)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        // `past_edge` falls on the same day as `edge`
+        for day in [old, edge, recent] {
+            sqlx::query("INSERT INTO analytics_daily_aggregates (contract_id, date) VALUES ($1, $2)")
+                .bind(contract)
+                .bind(day.date_naive())
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        // Other aggregated rows in the database may be pruned too
+        assert!(prune_raw_events(&pool, policy, now()).await.unwrap() >= 2);
+
+        let events: Vec<DateTime<Utc>> = sqlx::query_scalar(
+            "SELECT created_at FROM analytics_events WHERE contract_id = $1 ORDER BY created_at",
+        )
+        .bind(contract)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(events, vec![unaggregated, edge, recent]);
+        let aggregates: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM analytics_daily_aggregates WHERE contract_id = $1")
+            .bind(contract)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(aggregates, 3);
+
+        sqlx::query("DELETE FROM contracts WHERE id = $1").bind(contract).execute(&pool).await.unwrap();
+        sqlx::query("DELETE FROM publishers WHERE id = $1").bind(publisher).execute(&pool).await.unwrap();
+    }
+}
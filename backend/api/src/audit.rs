@@ -0,0 +1,310 @@
+// api/src/audit.rs
+// Structured entries for `contract_audit_log`.
+//
+// Every mutating contract operation records exactly one entry through
+// `record`: who did it (actor), what kind of change it was (action), which
+// contract it touched (target), and the before/after values where there is a
+// meaningful "before". Entries are built with the per-operation constructors
+// below so the shape of each action stays the same wherever it is written.
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Map, Value};
+use shared::{AuditActionType, Contract, ContractVersion, FieldChange, MaturityLevel, Verification};
+use sqlx::PgExecutor;
+use uuid::Uuid;
+
+/// Actor recorded for changes made by the registry itself rather than a
+/// publisher (e.g. the verifier marking a build as matching)
+pub const SYSTEM_ACTOR: &str = "system";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditEntry {
+    pub contract_id: Uuid,
+    pub action: AuditActionType,
+    pub actor: String,
+    pub before: Option<Value>,
+    /// Always present, which keeps the table's non-null value check satisfied
+    pub after: Value,
+}
+
+impl AuditEntry {
+    fn new(contract_id: Uuid, action: AuditActionType, actor: &str, before: Option<Value>, after: Value) -> Self {
+        Self {
+            contract_id,
+            action,
+            actor: actor.to_string(),
+            before,
+            after,
+        }
+    }
+
+    /// A contract was registered.
+    pub fn published(contract: &Contract, actor: &str) -> Self {
+        Self::new(
+            contract.id,
+            AuditActionType::ContractPublished,
+            actor,
+            None,
+            json!({
+                "contract_id": contract.contract_id,
+                "network": contract.network,
+                "name": contract.name,
+                "wasm_hash": contract.wasm_hash,
+            }),
+        )
+    }
+
+    /// Metadata fields were edited; one entry covers every changed field.
+    pub fn metadata_updated(contract_id: Uuid, actor: &str, changes: &[FieldChange]) -> Self {
+        let before: Map<String, Value> = changes.iter().map(|c| (c.field.clone(), c.from.clone())).collect();
+        let after: Map<String, Value> = changes.iter().map(|c| (c.field.clone(), c.to.clone())).collect();
+        Self::new(
+            contract_id,
+            AuditActionType::MetadataUpdated,
+            actor,
+            Some(Value::Object(before)),
+            Value::Object(after),
+        )
+    }
+
+    /// Tags were rewritten by an admin tag merge.
+    pub fn tags_merged(contract_id: Uuid, actor: &str, before: &[String], after: &[String], sources: &[String], target: &str) -> Self {
+        Self::new(
+            contract_id,
+            AuditActionType::MetadataUpdated,
+            actor,
+            Some(json!({ "tags": before })),
+            json!({ "tags": after, "merged_from": sources, "merged_into": target }),
+        )
+    }
+
+    /// A verification attempt finished, successfully or not.
+    pub fn verification_changed(was_verified: bool, verification: &Verification) -> Self {
+        let is_verified = was_verified || verification.verified_at.is_some();
+        Self::new(
+            verification.contract_id,
+            AuditActionType::VerificationChanged,
+            SYSTEM_ACTOR,
+            Some(json!({ "is_verified": was_verified })),
+            json!({
+                "is_verified": is_verified,
+                "verification_id": verification.id,
+                "status": verification.status,
+            }),
+        )
+    }
+
+    /// A new version was added to the contract.
+    pub fn version_created(version: &ContractVersion, actor: &str) -> Self {
+        Self::new(
+            version.contract_id,
+            AuditActionType::VersionCreated,
+            actor,
+            None,
+            json!({
+                "version": version.version,
+                "wasm_hash": version.wasm_hash,
+            }),
+        )
+    }
+
+    /// `deployment` was created on another network from `origin_id`.
+    /// Recorded against the new deployment, which links back to its origin.
+    pub fn deployed(origin_id: Uuid, deployment: &Contract, actor: &str) -> Self {
+        Self::new(
+            deployment.id,
+            AuditActionType::Deployed,
+            actor,
+            None,
+            json!({
+                "origin_contract_id": origin_id,
+                "contract_id": deployment.contract_id,
+                "network": deployment.network,
+            }),
+        )
+    }
+
+    pub fn maturity_changed(
+        contract_id: Uuid,
+        actor: &str,
+        from: &MaturityLevel,
+        to: &MaturityLevel,
+        reason: Option<&str>,
+    ) -> Self {
+        Self::new(
+            contract_id,
+            AuditActionType::MaturityChanged,
+            actor,
+            Some(json!({ "maturity": from })),
+            json!({ "maturity": to, "reason": reason }),
+        )
+    }
+
+    /// Maintenance mode was switched on (with its message) or off.
+    pub fn maintenance_changed(contract_id: Uuid, actor: &str, active: bool, message: Option<&str>) -> Self {
+        Self::new(
+            contract_id,
+            AuditActionType::MaintenanceChanged,
+            actor,
+            Some(json!({ "is_maintenance": !active })),
+            json!({ "is_maintenance": active, "message": message }),
+        )
+    }
+
+    /// The contract was soft-deleted; the purge task removes it after retention.
+    pub fn deleted(contract_id: Uuid, actor: &str, deleted_at: DateTime<Utc>) -> Self {
+        Self::new(
+            contract_id,
+            AuditActionType::ContractDeleted,
+            actor,
+            Some(json!({ "deleted_at": null })),
+            json!({ "deleted_at": deleted_at }),
+        )
+    }
+}
+
+/// Write one audit entry. Pass the operation's transaction when it has one so
+/// the entry commits (or rolls back) together with the change it describes.
+pub async fn record<'e, E>(executor: E, entry: &AuditEntry) -> Result<(), sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query(
+        "INSERT INTO contract_audit_log (contract_id, action_type, old_value, new_value, changed_by)
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(entry.contract_id)
+    .bind(&entry.action)
+    .bind(&entry.before)
+    .bind(&entry.after)
+    .bind(&entry.actor)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{Network, VerificationStatus};
+
+    const ACTOR: &str = "GPUBLISHER";
+
+    fn contract() -> Contract {
+        serde_json::from_value(json!({
+            "id": Uuid::from_u128(1),
+            "contract_id": "CTOKEN",
+            "wasm_hash": "ab".repeat(32),
+            "name": "Token",
+            "description": null,
+            "publisher_id": Uuid::from_u128(2),
+            "network": Network::Testnet,
+            "is_verified": false,
+            "category": null,
+            "tags": [],
+            "created_at": Utc::now(),
+            "updated_at": Utc::now(),
+        }))
+        .expect("minimal contract deserializes")
+    }
+
+    fn verification(status: VerificationStatus, verified: bool) -> Verification {
+        Verification {
+            id: Uuid::from_u128(3),
+            contract_id: Uuid::from_u128(1),
+            status,
+            source_code: None,
+            build_params: None,
+            compiler_version: None,
+            verified_at: verified.then(Utc::now),
+            error_message: None,
+            build_log: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn each_operation_maps_to_its_action() {
+        let c = contract();
+        let id = c.id;
+        let version: ContractVersion = serde_json::from_value(json!({
+            "id": Uuid::from_u128(4),
+            "contract_id": id,
+            "version": "1.1.0",
+            "wasm_hash": "cd".repeat(32),
+            "source_url": null,
+            "commit_hash": null,
+            "release_notes": null,
+            "created_at": Utc::now(),
+        }))
+        .expect("minimal version deserializes");
+        let change = FieldChange {
+            field: "name".into(),
+            from: json!("Token"),
+            to: json!("Token v2"),
+        };
+
+        let cases = [
+            (AuditEntry::published(&c, ACTOR), AuditActionType::ContractPublished),
+            (AuditEntry::metadata_updated(id, ACTOR, &[change]), AuditActionType::MetadataUpdated),
+            (
+                AuditEntry::tags_merged(id, ACTOR, &["defi".into()], &["finance".into()], &["defi".into()], "finance"),
+                AuditActionType::MetadataUpdated,
+            ),
+            (
+                AuditEntry::verification_changed(false, &verification(VerificationStatus::Verified, true)),
+                AuditActionType::VerificationChanged,
+            ),
+            (AuditEntry::version_created(&version, ACTOR), AuditActionType::VersionCreated),
+            (AuditEntry::deployed(Uuid::from_u128(9), &c, ACTOR), AuditActionType::Deployed),
+            (
+                AuditEntry::maturity_changed(id, ACTOR, &MaturityLevel::Alpha, &MaturityLevel::Beta, None),
+                AuditActionType::MaturityChanged,
+            ),
+            (AuditEntry::maintenance_changed(id, ACTOR, true, Some("upgrading")), AuditActionType::MaintenanceChanged),
+            (AuditEntry::deleted(id, ACTOR, Utc::now()), AuditActionType::ContractDeleted),
+        ];
+
+        for (entry, action) in cases {
+            assert_eq!(entry.action, action);
+            assert_eq!(entry.contract_id, id);
+            assert!(!entry.actor.is_empty());
+            assert!(entry.after.is_object(), "{} must record an after value", action);
+        }
+    }
+
+    #[test]
+    fn metadata_update_records_before_and_after_per_field() {
+        let changes = [
+            FieldChange { field: "name".into(), from: json!("Old"), to: json!("New") },
+            FieldChange { field: "category".into(), from: Value::Null, to: json!("DeFi") },
+        ];
+        let entry = AuditEntry::metadata_updated(Uuid::nil(), ACTOR, &changes);
+
+        assert_eq!(entry.before, Some(json!({ "name": "Old", "category": null })));
+        assert_eq!(entry.after, json!({ "name": "New", "category": "DeFi" }));
+        assert_eq!(entry.actor, ACTOR);
+    }
+
+    #[test]
+    fn verification_entry_reflects_outcome() {
+        let passed = AuditEntry::verification_changed(false, &verification(VerificationStatus::Verified, true));
+        assert_eq!(passed.before, Some(json!({ "is_verified": false })));
+        assert_eq!(passed.after["is_verified"], true);
+        assert_eq!(passed.actor, SYSTEM_ACTOR);
+
+        let failed = AuditEntry::verification_changed(false, &verification(VerificationStatus::Failed, false));
+        assert_eq!(failed.after["is_verified"], false);
+        assert_eq!(failed.after["status"], "Failed");
+    }
+
+    #[test]
+    fn maintenance_entry_flips_state() {
+        let on = AuditEntry::maintenance_changed(Uuid::nil(), ACTOR, true, Some("upgrading"));
+        assert_eq!(on.before, Some(json!({ "is_maintenance": false })));
+        assert_eq!(on.after, json!({ "is_maintenance": true, "message": "upgrading" }));
+
+        let off = AuditEntry::maintenance_changed(Uuid::nil(), ACTOR, false, None);
+        assert_eq!(off.before, Some(json!({ "is_maintenance": true })));
+    }
+}
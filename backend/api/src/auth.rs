@@ -61,6 +61,28 @@ impl AuthManager {
         public_key_hex: &str,
         signature_hex: &str,
     ) -> Result<String, &'static str> {
+        let challenge = self.take_challenge(address)?;
+        if address != public_key_hex {
+            return Err("address_public_key_mismatch");
+        }
+        let public_key = decode_hex_32(public_key_hex).ok_or("invalid_public_key_hex")?;
+        check_signature(&challenge, &public_key, signature_hex)?;
+        self.issue_jwt(address)
+    }
+
+    /// Consume the pending challenge for `address` and check `signature_hex`
+    /// signs its nonce with `public_key`. A challenge can be answered once.
+    pub fn verify_challenge(
+        &mut self,
+        address: &str,
+        public_key: &[u8; 32],
+        signature_hex: &str,
+    ) -> Result<(), &'static str> {
+        let challenge = self.take_challenge(address)?;
+        check_signature(&challenge, public_key, signature_hex)
+    }
+
+    fn take_challenge(&mut self, address: &str) -> Result<ChallengeRecord, &'static str> {
         let challenge = self
             .challenges
             .remove(address)
@@ -68,15 +90,11 @@ impl AuthManager {
         if Utc::now().timestamp() > challenge.expires_at {
             return Err("challenge_expired");
         }
-        if address != public_key_hex {
-            return Err("address_public_key_mismatch");
-        }
-        let public_key = decode_hex_32(public_key_hex).ok_or("invalid_public_key_hex")?;
-        let signature = decode_hex_64(signature_hex).ok_or("invalid_signature_hex")?;
-        let vk = VerifyingKey::from_bytes(&public_key).map_err(|_| "invalid_public_key")?;
-        let sig = Signature::from_bytes(&signature);
-        vk.verify(challenge.nonce.as_bytes(), &sig)
-            .map_err(|_| "invalid_signature")?;
+        Ok(challenge)
+    }
+
+    /// Sign a 24-hour session token for `address`.
+    pub fn issue_jwt(&self, address: &str) -> Result<String, &'static str> {
         let iat = Utc::now().timestamp();
         let exp = (Utc::now() + Duration::hours(24)).timestamp();
         let claims = AuthClaims {
@@ -96,6 +114,14 @@ impl AuthManager {
     }
 }
 
+fn check_signature(challenge: &ChallengeRecord, public_key: &[u8; 32], signature_hex: &str) -> Result<(), &'static str> {
+    let signature = decode_hex_64(signature_hex).ok_or("invalid_signature_hex")?;
+    let vk = VerifyingKey::from_bytes(public_key).map_err(|_| "invalid_public_key")?;
+    let sig = Signature::from_bytes(&signature);
+    vk.verify(challenge.nonce.as_bytes(), &sig)
+        .map_err(|_| "invalid_signature")
+}
+
 fn decode_hex_32(value: &str) -> Option<[u8; 32]> {
     let bytes = decode_hex(value)?;
     let mut out = [0u8; 32];
@@ -1,7 +1,10 @@
-use crate::auth::AuthManager;
+use crate::{
+    auth::AuthManager,
+    error::{ApiError, ApiResult},
+};
 use axum::{
     extract::Request,
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
@@ -13,21 +16,58 @@ pub struct AuthContext {
     pub publisher_address: String,
 }
 
+impl AuthContext {
+    /// Whether the caller is listed in the comma-separated `ADMIN_ADDRESSES` env var.
+    pub fn is_admin(&self) -> bool {
+        std::env::var("ADMIN_ADDRESSES")
+            .map(|list| is_listed_admin(&list, &self.publisher_address))
+            .unwrap_or(false)
+    }
+
+    /// `403 Forbidden` unless the caller is an admin.
+    pub fn require_admin(&self) -> ApiResult<()> {
+        if self.is_admin() {
+            Ok(())
+        } else {
+            Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Forbidden",
+                "Only admins can perform this action",
+            ))
+        }
+    }
+}
+
+fn is_listed_admin(list: &str, address: &str) -> bool {
+    list.split(',')
+        .map(str::trim)
+        .any(|entry| !entry.is_empty() && entry == address)
+}
+
 #[derive(Serialize)]
 struct AuthErrorBody {
     error: &'static str,
     message: &'static str,
 }
 
-pub async fn auth_middleware(mut request: Request, next: Next) -> Response {
-    let token = request
-        .headers()
+fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+    headers
         .get("authorization")
         .and_then(|v| v.to_str().ok())
         .and_then(|v| v.strip_prefix("Bearer "))
-        .map(str::trim);
+        .map(str::trim)
+}
 
-    let Some(token) = token else {
+/// The caller on a public route, when it sent a valid publisher token.
+pub fn auth_from_headers(headers: &HeaderMap) -> Option<AuthContext> {
+    let claims = AuthManager::from_env().validate_jwt(bearer_token(headers)?).ok()?;
+    Some(AuthContext {
+        publisher_address: claims.sub,
+    })
+}
+
+pub async fn auth_middleware(mut request: Request, next: Next) -> Response {
+    let Some(token) = bearer_token(request.headers()) else {
         return unauthorized("missing_bearer_token");
     };
 
@@ -54,3 +94,15 @@ fn unauthorized(reason: &'static str) -> Response {
     )
         .into_response()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn admin_list_matches_exact_entries() {
+        assert!(is_listed_admin("GA1, GB2", "GB2"));
+        assert!(!is_listed_admin("GA1,GB2", "GB"));
+        assert!(!is_listed_admin("", ""));
+    }
+}
@@ -0,0 +1,310 @@
+// api/src/background_jobs.rs
+// Run status for periodic background tasks (aggregation, popularity, ...).
+//
+// Each task records every run in `background_jobs`: when it ran, how long it
+// took, how many rows it touched and the error if it failed. Operators read
+// this through GET /api/admin/jobs, and the detailed health endpoint reports
+// jobs that have stopped running on schedule.
+
+use axum::{extract::State, Extension, Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use std::future::Future;
+use std::time::{Duration, Instant};
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    metrics,
+    state::AppState,
+};
+
+pub const JOB_AGGREGATION: &str = "aggregation";
+pub const JOB_POPULARITY: &str = "popularity";
+/// Pruning of aggregated raw analytics events past the retention window
+pub const JOB_ANALYTICS_RETENTION: &str = "analytics_retention";
+/// The on-chain reconciliation pass, which re-checks contract WASM hashes
+pub const JOB_ONCHAIN_RECONCILIATION: &str = "onchain_reconciliation";
+/// Flush of buffered contract detail views into `contracts.view_count`
+pub const JOB_VIEW_FLUSH: &str = "view_flush";
+/// Scheduled per-contract backups and their retention pruning
+pub const JOB_BACKUP_SCHEDULER: &str = "backup_scheduler";
+/// Permanent removal of contracts and publishers soft-deleted past retention
+pub const JOB_SOFT_DELETE_PURGE: &str = "soft_delete_purge";
+/// Assembly of daily digest deliveries for `daily_digest` webhooks
+pub const JOB_WEBHOOK_DIGEST: &str = "webhook_digest";
+/// Worker that builds queued (pending) verification attempts
+pub const JOB_VERIFICATION_QUEUE: &str = "verification_queue";
+
+/// A job is stale once it misses this many scheduled runs
+const STALE_AFTER_INTERVALS: i32 = 2;
+
+/// One row in `background_jobs`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct JobStatus {
+    pub job_name: String,
+    pub interval_seconds: i32,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_duration_ms: Option<i64>,
+    pub rows_processed: Option<i64>,
+    pub last_error: Option<String>,
+    pub run_count: i64,
+    pub failure_count: i64,
+}
+
+/// Outcome of a single run
+#[derive(Debug, Clone)]
+pub struct JobRun {
+    pub started_at: DateTime<Utc>,
+    pub duration: Duration,
+    pub result: Result<u64, String>,
+}
+
+impl JobStatus {
+    pub fn new(job_name: &str, interval: Duration) -> Self {
+        Self {
+            job_name: job_name.to_string(),
+            interval_seconds: interval.as_secs() as i32,
+            last_run_at: None,
+            last_success_at: None,
+            last_duration_ms: None,
+            rows_processed: None,
+            last_error: None,
+            run_count: 0,
+            failure_count: 0,
+        }
+    }
+
+    /// Fold one run into the status.
+    pub fn apply_run(mut self, run: &JobRun) -> Self {
+        self.last_run_at = Some(run.started_at);
+        self.last_duration_ms = Some(run.duration.as_millis() as i64);
+        self.run_count += 1;
+        match &run.result {
+            Ok(rows) => {
+                self.last_success_at = Some(run.started_at);
+                self.rows_processed = Some(*rows as i64);
+                self.last_error = None;
+            }
+            Err(err) => {
+                self.rows_processed = None;
+                self.last_error = Some(err.clone());
+                self.failure_count += 1;
+            }
+        }
+        self
+    }
+
+    /// Whether the job has not succeeded within its expected window.
+    pub fn is_stale(&self, now: DateTime<Utc>) -> bool {
+        let window = chrono::Duration::seconds(i64::from(self.interval_seconds) * i64::from(STALE_AFTER_INTERVALS));
+        match self.last_success_at {
+            Some(at) => now - at > window,
+            None => true,
+        }
+    }
+}
+
+/// Status plus derived staleness, as returned by the API
+#[derive(Debug, Clone, Serialize)]
+pub struct JobReport {
+    #[serde(flatten)]
+    pub status: JobStatus,
+    pub stale: bool,
+}
+
+pub fn report(statuses: Vec<JobStatus>, now: DateTime<Utc>) -> Vec<JobReport> {
+    statuses
+        .into_iter()
+        .map(|status| JobReport {
+            stale: status.is_stale(now),
+            status,
+        })
+        .collect()
+}
+
+async fn load_status(pool: &PgPool, job_name: &str) -> Result<Option<JobStatus>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM background_jobs WHERE job_name = $1")
+        .bind(job_name)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Persist one run of `job_name`.
+pub async fn record_run(
+    pool: &PgPool,
+    job_name: &str,
+    interval: Duration,
+    run: &JobRun,
+) -> Result<JobStatus, sqlx::Error> {
+    let previous = load_status(pool, job_name)
+        .await?
+        .unwrap_or_else(|| JobStatus::new(job_name, interval));
+    let mut status = previous.apply_run(run);
+    status.interval_seconds = interval.as_secs() as i32;
+
+    sqlx::query(
+        r#"
+        INSERT INTO background_jobs (
+            job_name, interval_seconds, last_run_at, last_success_at, last_duration_ms,
+            rows_processed, last_error, run_count, failure_count
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (job_name) DO UPDATE SET
+            interval_seconds = EXCLUDED.interval_seconds,
+            last_run_at      = EXCLUDED.last_run_at,
+            last_success_at  = EXCLUDED.last_success_at,
+            last_duration_ms = EXCLUDED.last_duration_ms,
+            rows_processed   = EXCLUDED.rows_processed,
+            last_error       = EXCLUDED.last_error,
+            run_count        = EXCLUDED.run_count,
+            failure_count    = EXCLUDED.failure_count
+        "#,
+    )
+    .bind(&status.job_name)
+    .bind(status.interval_seconds)
+    .bind(status.last_run_at)
+    .bind(status.last_success_at)
+    .bind(status.last_duration_ms)
+    .bind(status.rows_processed)
+    .bind(&status.last_error)
+    .bind(status.run_count)
+    .bind(status.failure_count)
+    .execute(pool)
+    .await?;
+
+    Ok(status)
+}
+
+/// Run `job`, time it and report the outcome to the job metrics.
+pub async fn execute<F, Fut>(job_name: &str, job: F) -> JobRun
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<u64, sqlx::Error>>,
+{
+    let started_at = Utc::now();
+    let timer = Instant::now();
+    let result = job().await.map_err(|err| {
+        tracing::error!(job = job_name, error = ?err, "background job failed");
+        err.to_string()
+    });
+
+    let run = JobRun {
+        started_at,
+        duration: timer.elapsed(),
+        result,
+    };
+    metrics::observe_background_job(job_name, run.duration.as_secs_f64(), run.result.is_ok());
+    run
+}
+
+/// Run `job` and record its outcome. `job` resolves to the number of rows processed.
+pub async fn run_tracked<F, Fut>(pool: &PgPool, job_name: &str, interval: Duration, job: F)
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<u64, sqlx::Error>>,
+{
+    let run = execute(job_name, job).await;
+    if let Err(err) = record_run(pool, job_name, interval, &run).await {
+        tracing::error!(job = job_name, error = ?err, "failed to record background job status");
+    }
+}
+
+pub async fn fetch_statuses(pool: &PgPool) -> Result<Vec<JobStatus>, sqlx::Error> {
+    sqlx::query_as("SELECT * FROM background_jobs ORDER BY job_name")
+        .fetch_all(pool)
+        .await
+}
+
+/// GET /api/admin/jobs
+pub async fn list_jobs(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<Vec<JobReport>>> {
+    auth.require_admin()?;
+
+    let statuses = fetch_statuses(&state.db).await.map_err(|err| {
+        tracing::error!(error = ?err, "failed to load background job status");
+        ApiError::internal("An unexpected database error occurred")
+    })?;
+
+    Ok(Json(report(statuses, Utc::now())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOURLY: Duration = Duration::from_secs(3600);
+
+    fn run(result: Result<u64, String>) -> JobRun {
+        JobRun {
+            started_at: Utc::now(),
+            duration: Duration::from_millis(1500),
+            result,
+        }
+    }
+
+    #[test]
+    fn successful_run_updates_status() {
+        let ok = run(Ok(42));
+        let status = JobStatus::new(JOB_AGGREGATION, HOURLY).apply_run(&ok);
+
+        assert_eq!(status.last_run_at, Some(ok.started_at));
+        assert_eq!(status.last_success_at, Some(ok.started_at));
+        assert_eq!(status.last_duration_ms, Some(1500));
+        assert_eq!(status.rows_processed, Some(42));
+        assert_eq!(status.run_count, 1);
+        assert_eq!(status.last_error, None);
+        assert!(!status.is_stale(Utc::now()));
+    }
+
+    #[test]
+    fn failed_run_keeps_last_success_and_records_error() {
+        let ok = run(Ok(10));
+        let failed = run(Err("connection reset".into()));
+        let status = JobStatus::new(JOB_POPULARITY, HOURLY)
+            .apply_run(&ok)
+            .apply_run(&failed);
+
+        assert_eq!(status.last_run_at, Some(failed.started_at));
+        assert_eq!(status.last_success_at, Some(ok.started_at));
+        assert_eq!(status.last_error.as_deref(), Some("connection reset"));
+        assert_eq!(status.run_count, 2);
+        assert_eq!(status.failure_count, 1);
+    }
+
+    #[test]
+    fn job_is_stale_after_missing_two_intervals() {
+        let never_ran = JobStatus::new(JOB_AGGREGATION, HOURLY);
+        assert!(never_ran.is_stale(Utc::now()));
+
+        let status = never_ran.apply_run(&run(Ok(1)));
+        assert!(!status.is_stale(Utc::now() + chrono::Duration::minutes(90)));
+        assert!(status.is_stale(Utc::now() + chrono::Duration::hours(3)));
+
+        let reports = report(vec![status], Utc::now() + chrono::Duration::hours(3));
+        let body = serde_json::to_value(&reports).unwrap();
+        assert_eq!(body[0]["stale"], true);
+        assert_eq!(body[0]["job_name"], JOB_AGGREGATION);
+    }
+
+    #[tokio::test]
+    async fn job_run_is_observed_in_metrics() {
+        let job = "test_metrics_job";
+        let durations = metrics::BACKGROUND_JOB_DURATION.with_label_values(&[job]);
+        let before = durations.get_sample_count();
+
+        let ok = execute(job, || async { Ok(7) }).await;
+        assert_eq!(ok.result, Ok(7));
+        let failed = execute(job, || async { Err(sqlx::Error::PoolTimedOut) }).await;
+        assert!(failed.result.is_err());
+
+        assert_eq!(durations.get_sample_count(), before + 2);
+        assert!(durations.get_sample_sum() >= 0.0);
+        assert_eq!(metrics::BACKGROUND_JOBS_SUCCEEDED.with_label_values(&[job]).get(), 1);
+        assert_eq!(metrics::BACKGROUND_JOBS_FAILED.with_label_values(&[job]).get(), 1);
+    }
+}
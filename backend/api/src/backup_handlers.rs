@@ -1,32 +1,89 @@
+// api/src/backup_handlers.rs
+// Daily contract backups.
+//
+// The row in `contract_backups` is an index entry; the bundle itself
+// (metadata plus optional state snapshot) is written to the object store
+// under `backups/<contract>/<date>.json`. WASM is stored once per hash under
+// `wasm/` and referenced from the bundle. Bundles are downloaded from
+// GET /api/contracts/:id/backups/:date/download, which redirects to a signed
+// URL when the object store supports it.
+//
+// PUT /api/contracts/:id/backup-policy schedules backups for the contract; see
+// `backup_scheduler`.
+
 use axum::{
-    extract::{Path, State},
+    extract::{rejection::JsonRejection, Path, State},
     http::StatusCode,
-    Json,
+    response::Response,
+    Extension, Json,
 };
 use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use shared::models::{
     BackupRestoration, ContractBackup, CreateBackupRequest, RestoreBackupRequest,
 };
 use uuid::Uuid;
 
 use crate::{
+    auth_middleware::AuthContext,
+    backup_scheduler::{self, BackupPolicy, BackupPolicyRequest},
     error::{ApiError, ApiResult},
+    handlers::ensure_contract_owner,
+    object_store::{self, ObjectStoreError},
     state::AppState,
 };
 
+/// What is written to the object store for one backup
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupBundle {
+    pub contract_id: Uuid,
+    pub backup_date: NaiveDate,
+    pub wasm_hash: String,
+    /// Object key of the WASM binary, when one had been uploaded
+    pub wasm_key: Option<String>,
+    pub metadata: Value,
+    pub state_snapshot: Option<Value>,
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(op, err)
+}
+
+fn store_err(op: &str, err: ObjectStoreError) -> ApiError {
+    tracing::error!(operation = op, error = %err, "object store error");
+    ApiError::internal("Backup storage is unavailable")
+}
+
+fn parse_backup_date(raw: &str) -> ApiResult<NaiveDate> {
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .map_err(|_| ApiError::bad_request("InvalidDate", "Invalid date format, expected YYYY-MM-DD"))
+}
+
 pub async fn create_backup(
     State(state): State<AppState>,
     Path(contract_id): Path<Uuid>,
     Json(req): Json<CreateBackupRequest>,
 ) -> ApiResult<Json<ContractBackup>> {
-    let contract = sqlx::query!("SELECT * FROM contracts WHERE id = $1", contract_id)
+    create_backup_for(&state, contract_id, req.include_state).await.map(Json)
+}
+
+/// Write today's backup bundle for `contract_id` and record it, replacing
+/// any backup already taken today. Shared with the backup scheduler.
+pub async fn create_backup_for(
+    state: &AppState,
+    contract_id: Uuid,
+    include_state: bool,
+) -> ApiResult<ContractBackup> {
+    let contract: shared::Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(contract_id)
         .fetch_optional(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
-        .ok_or_else(|| ApiError::not_found("contract", "Contract not found"))?;
+        .map_err(|err| db_err("get contract for backup", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", "Contract not found"))?;
 
     let backup_date = Utc::now().date_naive();
-    
+
     let metadata = serde_json::json!({
         "name": contract.name,
         "description": contract.description,
@@ -35,19 +92,52 @@ pub async fn create_backup(
         "tags": contract.tags,
     });
 
-    let state_snapshot = if req.include_state {
-        Some(serde_json::json!({"placeholder": "state data"}))
+    let state_snapshot = if include_state {
+        let entries: Vec<(String, Value)> =
+            sqlx::query_as("SELECT key, value FROM contract_state WHERE contract_id = $1 ORDER BY key")
+                .bind(contract_id)
+                .fetch_all(&state.db)
+                .await
+                .map_err(|err| db_err("snapshot contract state", err))?;
+        Some(Value::Object(entries.into_iter().collect()))
     } else {
         None
     };
 
-    let backup = sqlx::query_as::<_, ContractBackup>(
+    let wasm_key = object_store::wasm_key(&contract.wasm_hash);
+    let wasm_stored = state
+        .objects
+        .get(&wasm_key)
+        .await
+        .map_err(|err| store_err("check wasm for backup", err))?
+        .is_some();
+
+    let bundle = BackupBundle {
+        contract_id,
+        backup_date,
+        wasm_hash: contract.wasm_hash.clone(),
+        wasm_key: wasm_stored.then_some(wasm_key),
+        metadata: metadata.clone(),
+        state_snapshot,
+    };
+    let bytes = serde_json::to_vec(&bundle)
+        .map_err(|err| ApiError::internal(format!("Failed to encode backup: {}", err)))?;
+    let size = bytes.len() as i64;
+    let key = object_store::backup_key(contract_id, backup_date);
+    state
+        .objects
+        .put(&key, bytes)
+        .await
+        .map_err(|err| store_err("write backup bundle", err))?;
+
+    let backup: ContractBackup = sqlx::query_as(
         r#"
-        INSERT INTO contract_backups 
-        (contract_id, backup_date, wasm_hash, metadata, state_snapshot, storage_size_bytes, primary_region, backup_regions)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-        ON CONFLICT (contract_id, backup_date) DO UPDATE 
-        SET wasm_hash = $3, metadata = $4, state_snapshot = $5
+        INSERT INTO contract_backups
+        (contract_id, backup_date, wasm_hash, metadata, state_snapshot, storage_size_bytes,
+         primary_region, backup_regions, object_key)
+        VALUES ($1, $2, $3, $4, NULL, $5, $6, $7, $8)
+        ON CONFLICT (contract_id, backup_date) DO UPDATE
+        SET wasm_hash = $3, metadata = $4, storage_size_bytes = $5, object_key = $8, verified = false
         RETURNING *
         "#,
     )
@@ -55,15 +145,15 @@ pub async fn create_backup(
     .bind(backup_date)
     .bind(&contract.wasm_hash)
     .bind(&metadata)
-    .bind(&state_snapshot)
-    .bind(1024i64) // Placeholder size
+    .bind(size)
     .bind("us-east-1")
     .bind(vec!["us-west-2", "eu-west-1"])
+    .bind(&key)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to create backup: {}", e)))?;
+    .map_err(|err| db_err("record backup", err))?;
 
-    Ok(Json(backup))
+    Ok(backup)
 }
 
 pub async fn list_backups(
@@ -76,72 +166,131 @@ pub async fn list_backups(
     .bind(contract_id)
     .fetch_all(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(|err| db_err("list backups", err))?;
 
     Ok(Json(backups))
 }
 
+async fn find_backup(state: &AppState, contract_id: Uuid, date: NaiveDate) -> ApiResult<ContractBackup> {
+    sqlx::query_as("SELECT * FROM contract_backups WHERE contract_id = $1 AND backup_date = $2")
+        .bind(contract_id)
+        .bind(date)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("get backup", err))?
+        .ok_or_else(|| ApiError::not_found("BackupNotFound", "Backup not found"))
+}
+
+fn bundle_key(backup: &ContractBackup) -> String {
+    backup
+        .object_key
+        .clone()
+        .unwrap_or_else(|| object_store::backup_key(backup.contract_id, backup.backup_date))
+}
+
+/// GET /api/contracts/:id/backups/:date/download
+pub async fn download_backup(
+    State(state): State<AppState>,
+    Path((contract_id, backup_date)): Path<(Uuid, String)>,
+) -> ApiResult<Response> {
+    let date = parse_backup_date(&backup_date)?;
+    let backup = find_backup(&state, contract_id, date).await?;
+
+    let download = object_store::resolve_download(
+        state.objects.as_ref(),
+        &bundle_key(&backup),
+        object_store::download_url_ttl(),
+    )
+    .await
+    .map_err(|err| store_err("download backup bundle", err))?
+    .ok_or_else(|| ApiError::unprocessable("BackupUnavailable", "backup bundle missing from object storage"))?;
+
+    Ok(download.into_response("application/json"))
+}
+
+/// Read and decode the stored bundle. `Ok(None)` when the object is missing.
+async fn load_bundle(state: &AppState, backup: &ContractBackup) -> ApiResult<Option<BackupBundle>> {
+    let Some(bytes) = state
+        .objects
+        .get(&bundle_key(backup))
+        .await
+        .map_err(|err| store_err("read backup bundle", err))?
+    else {
+        return Ok(None);
+    };
+    serde_json::from_slice(&bytes)
+        .map(Some)
+        .map_err(|err| ApiError::internal(format!("Stored backup is corrupt: {}", err)))
+}
+
 pub async fn restore_backup(
     State(state): State<AppState>,
     Path(contract_id): Path<Uuid>,
     Json(req): Json<RestoreBackupRequest>,
 ) -> ApiResult<Json<BackupRestoration>> {
     let start = std::time::Instant::now();
+    let backup_date = parse_backup_date(&req.backup_date)?;
+    let backup = find_backup(&state, contract_id, backup_date).await?;
 
-    let backup_date = NaiveDate::parse_from_str(&req.backup_date, "%Y-%m-%d")
-        .map_err(|_| ApiError::bad_request("invalid_date", "Invalid date format"))?;
-
-    let backup = sqlx::query_as::<_, ContractBackup>(
-        "SELECT * FROM contract_backups WHERE contract_id = $1 AND backup_date = $2",
-    )
-    .bind(contract_id)
-    .bind(backup_date)
-    .fetch_optional(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
-    .ok_or_else(|| ApiError::not_found("backup", "Backup not found"))?;
+    let bundle = load_bundle(&state, &backup).await?;
+    let error_message = match &bundle {
+        Some(_) => None,
+        None => Some("backup bundle missing from object storage".to_string()),
+    };
 
-    // Simulate restoration
     let duration_ms = start.elapsed().as_millis() as i32;
 
-    let contract = sqlx::query!("SELECT publisher_id FROM contracts WHERE id = $1", contract_id)
+    let publisher_id: Uuid = sqlx::query_scalar("SELECT publisher_id FROM contracts WHERE id = $1")
+        .bind(contract_id)
         .fetch_one(&state.db)
         .await
-        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+        .map_err(|err| db_err("get contract publisher", err))?;
 
     let restoration = sqlx::query_as::<_, BackupRestoration>(
         r#"
-        INSERT INTO backup_restorations (backup_id, restored_by, restore_duration_ms, success)
-        VALUES ($1, $2, $3, $4)
+        INSERT INTO backup_restorations (backup_id, restored_by, restore_duration_ms, success, error_message)
+        VALUES ($1, $2, $3, $4, $5)
         RETURNING *
         "#,
     )
     .bind(backup.id)
-    .bind(contract.publisher_id)
+    .bind(publisher_id)
     .bind(duration_ms)
-    .bind(true)
+    .bind(bundle.is_some())
+    .bind(&error_message)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to log restoration: {}", e)))?;
+    .map_err(|err| db_err("log restoration", err))?;
 
+    if let Some(message) = error_message {
+        return Err(ApiError::unprocessable("BackupUnavailable", message));
+    }
     Ok(Json(restoration))
 }
 
+/// Mark a backup verified once its bundle is readable and matches the row.
 pub async fn verify_backup(
     State(state): State<AppState>,
     Path((contract_id, backup_date)): Path<(Uuid, String)>,
 ) -> ApiResult<StatusCode> {
-    let date = NaiveDate::parse_from_str(&backup_date, "%Y-%m-%d")
-        .map_err(|_| ApiError::bad_request("invalid_date", "Invalid date format"))?;
+    let date = parse_backup_date(&backup_date)?;
+    let backup = find_backup(&state, contract_id, date).await?;
 
-    sqlx::query(
-        "UPDATE contract_backups SET verified = true WHERE contract_id = $1 AND backup_date = $2",
-    )
-    .bind(contract_id)
-    .bind(date)
-    .execute(&state.db)
-    .await
-    .map_err(|e| ApiError::internal(format!("Failed to verify backup: {}", e)))?;
+    let bundle = load_bundle(&state, &backup)
+        .await?
+        .ok_or_else(|| ApiError::unprocessable("BackupUnavailable", "backup bundle missing from object storage"))?;
+    if bundle.contract_id != contract_id || bundle.wasm_hash != backup.wasm_hash {
+        return Err(ApiError::unprocessable(
+            "BackupMismatch",
+            "stored bundle does not match the backup record",
+        ));
+    }
+
+    sqlx::query("UPDATE contract_backups SET verified = true WHERE id = $1")
+        .bind(backup.id)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_err("verify backup", err))?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -149,27 +298,73 @@ pub async fn verify_backup(
 pub async fn get_backup_stats(
     State(state): State<AppState>,
     Path(contract_id): Path<Uuid>,
-) -> ApiResult<Json<serde_json::Value>> {
-    let stats = sqlx::query!(
+) -> ApiResult<Json<Value>> {
+    let (total_backups, verified_backups, total_size_bytes, latest_backup): (
+        i64,
+        i64,
+        Option<i64>,
+        Option<NaiveDate>,
+    ) = sqlx::query_as(
         r#"
-        SELECT 
-            COUNT(*) as total_backups,
-            COUNT(*) FILTER (WHERE verified = true) as verified_backups,
-            SUM(storage_size_bytes) as total_size_bytes,
-            MAX(backup_date) as latest_backup
-        FROM contract_backups 
+        SELECT
+            COUNT(*),
+            COUNT(*) FILTER (WHERE verified = true),
+            SUM(storage_size_bytes)::BIGINT,
+            MAX(backup_date)
+        FROM contract_backups
         WHERE contract_id = $1
         "#,
-        contract_id
     )
+    .bind(contract_id)
     .fetch_one(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    .map_err(|err| db_err("get backup stats", err))?;
 
     Ok(Json(serde_json::json!({
-        "total_backups": stats.total_backups.unwrap_or(0),
-        "verified_backups": stats.verified_backups.unwrap_or(0),
-        "total_size_bytes": stats.total_size_bytes.unwrap_or(0),
-        "latest_backup": stats.latest_backup,
+        "total_backups": total_backups,
+        "verified_backups": verified_backups,
+        "total_size_bytes": total_size_bytes.unwrap_or(0),
+        "latest_backup": latest_backup,
     })))
 }
+
+/// PUT /api/contracts/:id/backup-policy — publisher (or admin) only
+pub async fn put_backup_policy(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(contract_id): Path<Uuid>,
+    payload: Result<Json<BackupPolicyRequest>, JsonRejection>,
+) -> ApiResult<Json<BackupPolicy>> {
+    let Json(req) = payload
+        .map_err(|err| ApiError::bad_request("InvalidRequest", format!("Invalid backup policy: {}", err.body_text())))?;
+    if !(1..=backup_scheduler::MAX_RETENTION_COUNT).contains(&req.retention_count) {
+        return Err(ApiError::bad_request(
+            "InvalidRetention",
+            format!(
+                "retention_count must be between 1 and {}",
+                backup_scheduler::MAX_RETENTION_COUNT
+            ),
+        ));
+    }
+
+    let contract: shared::Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(contract_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("get contract for backup policy", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", "Contract not found"))?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let policy = backup_scheduler::upsert_policy(&state.db, contract_id, &req, &auth.publisher_address)
+        .await
+        .map_err(|err| db_err("store backup policy", err))?;
+
+    tracing::info!(
+        contract_id = %contract_id,
+        frequency = ?policy.frequency,
+        retention_count = policy.retention_count,
+        updated_by = %auth.publisher_address,
+        "backup policy updated"
+    );
+    Ok(Json(policy))
+}
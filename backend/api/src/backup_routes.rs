@@ -1,6 +1,10 @@
-use axum::{routing::get, routing::post, Router};
+use axum::{
+    middleware,
+    routing::{get, post, put},
+    Router,
+};
 
-use crate::{backup_handlers, state::AppState};
+use crate::{auth_middleware, backup_handlers, state::AppState};
 
 pub fn backup_routes() -> Router<AppState> {
     Router::new()
@@ -16,8 +20,20 @@ pub fn backup_routes() -> Router<AppState> {
             "/api/contracts/:id/backups/:date/verify",
             post(backup_handlers::verify_backup),
         )
+        .route(
+            "/api/contracts/:id/backups/:date/download",
+            get(backup_handlers::download_backup),
+        )
         .route(
             "/api/contracts/:id/backups/stats",
             get(backup_handlers::get_backup_stats),
         )
+        .merge(
+            Router::new()
+                .route(
+                    "/api/contracts/:id/backup-policy",
+                    put(backup_handlers::put_backup_policy),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
 }
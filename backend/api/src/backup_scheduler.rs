@@ -0,0 +1,306 @@
+// api/src/backup_scheduler.rs
+// Scheduled contract backups.
+//
+// A contract may carry a backup policy (PUT /api/contracts/:id/backup-policy):
+// a frequency (daily or weekly), how many backups to keep and whether to
+// snapshot contract state. Every hour the scheduler takes a backup for each
+// policy that is due, through the same path as POST /api/contracts/:id/backups,
+// then deletes the oldest backups (row and stored bundle) beyond the policy's
+// retention count. Backups taken by hand count towards retention too.
+
+use chrono::{DateTime, Duration as ChronoDuration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{background_jobs, backup_handlers, state::AppState};
+
+/// How often the scheduler looks for due policies
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Upper bound on `retention_count`
+pub const MAX_RETENTION_COUNT: i32 = 365;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum BackupFrequency {
+    Daily,
+    Weekly,
+}
+
+impl BackupFrequency {
+    pub fn period(self) -> ChronoDuration {
+        match self {
+            Self::Daily => ChronoDuration::days(1),
+            Self::Weekly => ChronoDuration::days(7),
+        }
+    }
+}
+
+/// One row in `contract_backup_policies`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct BackupPolicy {
+    pub contract_id: Uuid,
+    pub frequency: BackupFrequency,
+    pub retention_count: i32,
+    pub include_state: bool,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BackupPolicy {
+    /// A policy that has never run is due immediately.
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        match self.last_run_at {
+            Some(last) => now - last >= self.frequency.period(),
+            None => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BackupPolicyRequest {
+    pub frequency: BackupFrequency,
+    pub retention_count: i32,
+    #[serde(default)]
+    pub include_state: bool,
+}
+
+/// Backup dates to delete so that only the newest `retention_count` remain.
+pub fn dates_to_prune(mut dates: Vec<NaiveDate>, retention_count: i32) -> Vec<NaiveDate> {
+    dates.sort_unstable_by(|a, b| b.cmp(a));
+    dates.dedup();
+    dates.split_off((retention_count.max(0) as usize).min(dates.len()))
+}
+
+/// Spawn the hourly backup scheduler.
+///
+/// Each run is recorded in `background_jobs` under `backup_scheduler`.
+pub fn spawn_backup_scheduler(state: AppState) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            let pool = state.db.clone();
+            background_jobs::run_tracked(
+                &pool,
+                background_jobs::JOB_BACKUP_SCHEDULER,
+                SCHEDULER_INTERVAL,
+                || run_due_backups(&state, Utc::now()),
+            )
+            .await;
+        }
+    });
+}
+
+/// Back up every contract whose policy is due and prune past retention;
+/// returns the number of backups taken.
+///
+/// A failure for one contract is logged and does not stop the others; the
+/// policy stays due and is retried on the next run.
+pub async fn run_due_backups(state: &AppState, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let policies: Vec<BackupPolicy> = sqlx::query_as("SELECT * FROM contract_backup_policies")
+        .fetch_all(&state.db)
+        .await?;
+
+    let mut taken = 0;
+    for policy in policies.iter().filter(|policy| policy.is_due(now)) {
+        if let Err(err) = backup_handlers::create_backup_for(state, policy.contract_id, policy.include_state).await {
+            tracing::warn!(contract_id = %policy.contract_id, error = ?err, "scheduled backup failed");
+            continue;
+        }
+        taken += 1;
+
+        sqlx::query("UPDATE contract_backup_policies SET last_run_at = $2 WHERE contract_id = $1")
+            .bind(policy.contract_id)
+            .bind(now)
+            .execute(&state.db)
+            .await?;
+
+        prune_backups(state, policy).await?;
+    }
+
+    if taken > 0 {
+        tracing::info!(taken, "backup scheduler: scheduled backups taken");
+    }
+    Ok(taken)
+}
+
+/// Delete backups of the policy's contract beyond its retention count.
+async fn prune_backups(state: &AppState, policy: &BackupPolicy) -> Result<(), sqlx::Error> {
+    let dates: Vec<NaiveDate> = sqlx::query_scalar("SELECT backup_date FROM contract_backups WHERE contract_id = $1")
+        .bind(policy.contract_id)
+        .fetch_all(&state.db)
+        .await?;
+
+    for date in dates_to_prune(dates, policy.retention_count) {
+        let object_key: Option<Option<String>> = sqlx::query_scalar(
+            "DELETE FROM contract_backups WHERE contract_id = $1 AND backup_date = $2 RETURNING object_key",
+        )
+        .bind(policy.contract_id)
+        .bind(date)
+        .fetch_optional(&state.db)
+        .await?;
+
+        let key = object_key
+            .flatten()
+            .unwrap_or_else(|| crate::object_store::backup_key(policy.contract_id, date));
+        if let Err(err) = state.objects.delete(&key).await {
+            tracing::warn!(key = %key, error = %err, "backup scheduler: failed to delete pruned bundle");
+        }
+    }
+    Ok(())
+}
+
+/// Create or replace the policy of `contract_id`.
+pub async fn upsert_policy(
+    pool: &PgPool,
+    contract_id: Uuid,
+    req: &BackupPolicyRequest,
+    updated_by: &str,
+) -> Result<BackupPolicy, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        INSERT INTO contract_backup_policies
+            (contract_id, frequency, retention_count, include_state, updated_by, updated_at)
+        VALUES ($1, $2, $3, $4, $5, NOW())
+        ON CONFLICT (contract_id) DO UPDATE
+        SET frequency = $2, retention_count = $3, include_state = $4, updated_by = $5, updated_at = NOW()
+        RETURNING *
+        "#,
+    )
+    .bind(contract_id)
+    .bind(req.frequency)
+    .bind(req.retention_count)
+    .bind(req.include_state)
+    .bind(updated_by)
+    .fetch_one(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::collections::BTreeSet;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 1, 0, 30, 0).unwrap()
+    }
+
+    fn policy(frequency: BackupFrequency, retention_count: i32) -> BackupPolicy {
+        BackupPolicy {
+            contract_id: Uuid::from_u128(1),
+            frequency,
+            retention_count,
+            include_state: false,
+            last_run_at: None,
+            updated_by: "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".into(),
+            updated_at: start(),
+        }
+    }
+
+    /// Drives one policy through the scheduler's decisions with a clock that
+    /// advances one scheduler interval per tick, keeping backups in memory.
+    struct Harness {
+        policy: BackupPolicy,
+        now: DateTime<Utc>,
+        backups: BTreeSet<NaiveDate>,
+        runs: Vec<DateTime<Utc>>,
+    }
+
+    impl Harness {
+        fn new(policy: BackupPolicy) -> Self {
+            Self {
+                policy,
+                now: start(),
+                backups: BTreeSet::new(),
+                runs: Vec::new(),
+            }
+        }
+
+        fn tick(&mut self) {
+            if self.policy.is_due(self.now) {
+                self.backups.insert(self.now.date_naive());
+                self.runs.push(self.now);
+                self.policy.last_run_at = Some(self.now);
+                let dates = self.backups.iter().copied().collect();
+                for date in dates_to_prune(dates, self.policy.retention_count) {
+                    self.backups.remove(&date);
+                }
+            }
+            self.now += ChronoDuration::from_std(SCHEDULER_INTERVAL).unwrap();
+        }
+
+        /// Ticks through `days` whole days; the clock ends on the tick
+        /// exactly `days` later, which has not been evaluated yet.
+        fn advance_days(&mut self, days: i64) {
+            for _ in 0..days * 24 {
+                self.tick();
+            }
+        }
+    }
+
+    #[test]
+    fn daily_policy_runs_once_per_day() {
+        let mut harness = Harness::new(policy(BackupFrequency::Daily, 30));
+        harness.advance_days(10);
+
+        assert_eq!(harness.runs.len(), 10);
+        assert!(harness.runs.windows(2).all(|pair| pair[1] - pair[0] == ChronoDuration::days(1)));
+        assert_eq!(harness.backups.len(), 10);
+    }
+
+    #[test]
+    fn weekly_policy_waits_a_full_week() {
+        let mut harness = Harness::new(policy(BackupFrequency::Weekly, 10));
+        harness.advance_days(7);
+        assert_eq!(harness.runs.len(), 1);
+
+        // The clock now sits exactly one week after the first run
+        harness.tick();
+        assert_eq!(harness.runs.len(), 2);
+        assert_eq!(harness.runs[1] - harness.runs[0], ChronoDuration::weeks(1));
+
+        harness.advance_days(21);
+        assert_eq!(harness.runs.len(), 5);
+    }
+
+    #[test]
+    fn retention_keeps_only_the_newest_backups() {
+        let mut harness = Harness::new(policy(BackupFrequency::Daily, 3));
+        harness.advance_days(8);
+
+        let kept: Vec<_> = harness.backups.iter().copied().collect();
+        let newest = harness.runs.last().unwrap().date_naive();
+        assert_eq!(
+            kept,
+            vec![newest - ChronoDuration::days(2), newest - ChronoDuration::days(1), newest]
+        );
+    }
+
+    #[test]
+    fn prune_selects_oldest_beyond_retention() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 10, d).unwrap();
+        assert_eq!(dates_to_prune(vec![day(3), day(1), day(2)], 2), vec![day(1)]);
+        assert!(dates_to_prune(vec![day(1)], 5).is_empty());
+    }
+
+    #[test]
+    fn policy_request_rejects_unknown_frequencies() {
+        let ok: BackupPolicyRequest =
+            serde_json::from_value(serde_json::json!({ "frequency": "weekly", "retention_count": 4 })).unwrap();
+        assert_eq!(ok.frequency, BackupFrequency::Weekly);
+        assert!(!ok.include_state);
+
+        assert!(serde_json::from_value::<BackupPolicyRequest>(
+            serde_json::json!({ "frequency": "hourly", "retention_count": 4 })
+        )
+        .is_err());
+    }
+}
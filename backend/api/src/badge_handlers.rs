@@ -0,0 +1,206 @@
+// api/src/badge_handlers.rs
+// README badges: GET /api/contracts/:id/badge.svg
+//
+// Renders a shields-style SVG showing either verification status (default)
+// or maturity level, in the `flat` (default) or `plastic` style.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use shared::MaturityLevel;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// Badges are cheap to render but hot-linked from READMEs; let CDNs and
+/// browsers hold them briefly.
+const BADGE_CACHE_CONTROL: &str = "public, max-age=300, s-maxage=300";
+
+const COLOR_GREEN: &str = "#4c1";
+const COLOR_YELLOW_GREEN: &str = "#a4a61d";
+const COLOR_YELLOW: &str = "#dfb317";
+const COLOR_ORANGE: &str = "#fe7d37";
+const COLOR_GREY: &str = "#9f9f9f";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BadgeStyle {
+    #[default]
+    Flat,
+    Plastic,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BadgeKind {
+    #[default]
+    Verification,
+    Maturity,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BadgeQuery {
+    #[serde(default)]
+    pub style: BadgeStyle,
+    #[serde(default, rename = "type")]
+    pub kind: BadgeKind,
+}
+
+/// Label, message and message color for a badge
+fn badge_content(kind: BadgeKind, is_verified: bool, maturity: &MaturityLevel) -> (&'static str, &'static str, &'static str) {
+    match kind {
+        BadgeKind::Verification if is_verified => ("soroban registry", "verified", COLOR_GREEN),
+        BadgeKind::Verification => ("soroban registry", "unverified", COLOR_GREY),
+        BadgeKind::Maturity => {
+            let (message, color) = match maturity {
+                MaturityLevel::Alpha => ("alpha", COLOR_ORANGE),
+                MaturityLevel::Beta => ("beta", COLOR_YELLOW),
+                MaturityLevel::Stable => ("stable", COLOR_YELLOW_GREEN),
+                MaturityLevel::Mature => ("mature", COLOR_GREEN),
+                MaturityLevel::Legacy => ("legacy", COLOR_GREY),
+            };
+            ("maturity", message, color)
+        }
+    }
+}
+
+/// Approximate rendered width of `text` in 11px Verdana.
+fn text_width(text: &str) -> usize {
+    text.chars().count() * 7 + 10
+}
+
+pub fn render_badge(label: &str, message: &str, color: &str, style: BadgeStyle) -> String {
+    let label_w = text_width(label);
+    let message_w = text_width(message);
+    let total_w = label_w + message_w;
+    let (radius, gloss) = match style {
+        BadgeStyle::Flat => (3, ""),
+        BadgeStyle::Plastic => (
+            4,
+            r##"<linearGradient id="g" x2="0" y2="100%"><stop offset="0" stop-color="#fff" stop-opacity=".7"/><stop offset=".1" stop-color="#aaa" stop-opacity=".1"/><stop offset=".9" stop-opacity=".3"/><stop offset="1" stop-opacity=".5"/></linearGradient>"##,
+        ),
+    };
+    let overlay = if gloss.is_empty() {
+        String::new()
+    } else {
+        format!(r##"<rect width="{total_w}" height="20" rx="{radius}" fill="url(#g)"/>"##)
+    };
+
+    format!(
+        concat!(
+            r##"<svg xmlns="http://www.w3.org/2000/svg" width="{total_w}" height="20" role="img" aria-label="{label}: {message}">"##,
+            r##"<title>{label}: {message}</title>{gloss}"##,
+            r##"<clipPath id="r"><rect width="{total_w}" height="20" rx="{radius}" fill="#fff"/></clipPath>"##,
+            r##"<g clip-path="url(#r)"><rect width="{label_w}" height="20" fill="#555"/>"##,
+            r##"<rect x="{label_w}" width="{message_w}" height="20" fill="{color}"/>{overlay}</g>"##,
+            r##"<g fill="#fff" text-anchor="middle" font-family="Verdana,Geneva,DejaVu Sans,sans-serif" font-size="11">"##,
+            r##"<text x="{label_x}" y="14">{label}</text><text x="{message_x}" y="14">{message}</text></g></svg>"##
+        ),
+        total_w = total_w,
+        label_w = label_w,
+        message_w = message_w,
+        radius = radius,
+        gloss = gloss,
+        overlay = overlay,
+        color = color,
+        label = label,
+        message = message,
+        label_x = label_w / 2,
+        message_x = label_w + message_w / 2,
+    )
+}
+
+/// Build the HTTP response for a contract's badge.
+pub fn badge_response(query: &BadgeQuery, is_verified: bool, maturity: &MaturityLevel) -> Response {
+    let (label, message, color) = badge_content(query.kind, is_verified, maturity);
+    let svg = render_badge(label, message, color, query.style);
+
+    (
+        [
+            (header::CONTENT_TYPE, "image/svg+xml; charset=utf-8"),
+            (header::CACHE_CONTROL, BADGE_CACHE_CONTROL),
+        ],
+        svg,
+    )
+        .into_response()
+}
+
+/// GET /api/contracts/:id/badge.svg?style=flat|plastic&type=verification|maturity
+pub async fn get_contract_badge(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+    Query(query): Query<BadgeQuery>,
+) -> ApiResult<Response> {
+    let (is_verified, maturity): (bool, MaturityLevel) =
+        sqlx::query_as("SELECT is_verified, maturity FROM contracts WHERE id = $1")
+            .bind(contract_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| {
+                tracing::error!(error = ?err, "failed to load contract for badge");
+                ApiError::internal("An unexpected database error occurred")
+            })?
+            .ok_or_else(|| {
+                ApiError::not_found(
+                    "ContractNotFound",
+                    format!("No contract found with ID: {}", contract_id),
+                )
+            })?;
+
+    Ok(badge_response(&query, is_verified, &maturity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn body_text(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn badge_is_svg_and_reflects_verification() {
+        let verified = badge_response(&BadgeQuery::default(), true, &MaturityLevel::Alpha);
+        assert_eq!(
+            verified.headers()[header::CONTENT_TYPE],
+            "image/svg+xml; charset=utf-8"
+        );
+        assert!(verified.headers()[header::CACHE_CONTROL]
+            .to_str()
+            .unwrap()
+            .contains("max-age"));
+        let svg = body_text(verified).await;
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">verified<"));
+        assert!(svg.contains(COLOR_GREEN));
+
+        let unverified = badge_response(&BadgeQuery::default(), false, &MaturityLevel::Alpha);
+        let svg = body_text(unverified).await;
+        assert!(svg.contains(">unverified<"));
+        assert!(svg.contains(COLOR_GREY));
+    }
+
+    #[tokio::test]
+    async fn maturity_badge_and_plastic_style() {
+        let query = BadgeQuery {
+            style: BadgeStyle::Plastic,
+            kind: BadgeKind::Maturity,
+        };
+        let svg = body_text(badge_response(&query, false, &MaturityLevel::Stable)).await;
+
+        assert!(svg.contains(">stable<"));
+        assert!(svg.contains("linearGradient"));
+
+        let flat = render_badge("a", "b", COLOR_GREEN, BadgeStyle::Flat);
+        assert!(!flat.contains("linearGradient"));
+    }
+}
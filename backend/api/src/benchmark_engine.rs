@@ -3,6 +3,7 @@
 // In production this calls the actual Soroban CLI/RPC; here we simulate with
 // realistic timing so the full plumbing works end-to-end.
 
+use serde::Serialize;
 use std::time::{Duration, Instant};
 
 /// Raw timing result from one iteration
@@ -157,6 +158,66 @@ pub fn check_regression(baseline_p95: f64, current_p95: f64, threshold_pct: f64)
     (delta_pct > threshold_pct, delta_pct)
 }
 
+/// Change in one timing metric between a baseline run and the current run
+#[derive(Debug, Clone, Serialize)]
+pub struct MetricDelta {
+    pub metric: &'static str,
+    pub baseline_ms: f64,
+    pub current_ms: f64,
+    pub delta_ms: f64,
+    pub delta_pct: f64,
+}
+
+/// Per-metric deltas (min/max/avg/p95/p99/stddev) between two runs
+pub fn metric_deltas(baseline: &BenchmarkStats, current: &BenchmarkStats) -> Vec<MetricDelta> {
+    let pairs = [
+        ("min_ms", baseline.min_ms, current.min_ms),
+        ("max_ms", baseline.max_ms, current.max_ms),
+        ("avg_ms", baseline.avg_ms, current.avg_ms),
+        ("p95_ms", baseline.p95_ms, current.p95_ms),
+        ("p99_ms", baseline.p99_ms, current.p99_ms),
+        ("stddev_ms", baseline.stddev_ms, current.stddev_ms),
+    ];
+    pairs
+        .iter()
+        .map(|&(metric, baseline_ms, current_ms)| MetricDelta {
+            metric,
+            baseline_ms,
+            current_ms,
+            delta_ms: current_ms - baseline_ms,
+            delta_pct: if baseline_ms == 0.0 {
+                0.0
+            } else {
+                ((current_ms - baseline_ms) / baseline_ms) * 100.0
+            },
+        })
+        .collect()
+}
+
+/// Webhook payload for a regressing run, or `None` when the run is within threshold.
+///
+/// Regression is judged on p95, matching `check_regression`.
+pub fn regression_webhook_payload(
+    contract_id: &str,
+    method: &str,
+    baseline: &BenchmarkStats,
+    current: &BenchmarkStats,
+    threshold_pct: f64,
+) -> Option<serde_json::Value> {
+    let (is_regression, regression_pct) =
+        check_regression(baseline.p95_ms, current.p95_ms, threshold_pct);
+    if !is_regression {
+        return None;
+    }
+    Some(serde_json::json!({
+        "contract_id": contract_id,
+        "method": method,
+        "regression_pct": regression_pct,
+        "alert_threshold_pct": threshold_pct,
+        "deltas": metric_deltas(baseline, current),
+    }))
+}
+
 /// Minimal LCG pseudo-random (avoids the `rand` crate dependency)
 fn rand_f64() -> f64 {
     use std::time::SystemTime;
@@ -241,6 +302,35 @@ mod tests {
         assert!(!is_reg); // 5% increase < 10% threshold
     }
 
+    fn stats_with_p95(p95_ms: f64) -> BenchmarkStats {
+        BenchmarkStats {
+            min_ms: p95_ms * 0.8,
+            max_ms: p95_ms * 1.1,
+            avg_ms: p95_ms * 0.9,
+            p95_ms,
+            p99_ms: p95_ms * 1.05,
+            stddev_ms: 0.5,
+        }
+    }
+
+    #[test]
+    fn regressing_run_produces_webhook_payload() {
+        let payload =
+            regression_webhook_payload("c1", "transfer", &stats_with_p95(10.0), &stats_with_p95(12.0), 10.0)
+                .expect("regression should produce a payload");
+        let deltas = payload["deltas"].as_array().unwrap();
+        assert_eq!(deltas.len(), 6);
+        let p95 = deltas.iter().find(|d| d["metric"] == "p95_ms").unwrap();
+        assert!((p95["delta_ms"].as_f64().unwrap() - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn passing_run_produces_no_webhook_payload() {
+        let payload =
+            regression_webhook_payload("c1", "transfer", &stats_with_p95(10.0), &stats_with_p95(10.5), 10.0);
+        assert!(payload.is_none());
+    }
+
     #[test]
     fn consistency_check() {
         // Tight distribution — should be consistent
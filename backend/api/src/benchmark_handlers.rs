@@ -10,15 +10,32 @@ use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::{
-    benchmark_engine::{check_regression, format_cli_output, BenchmarkRunner, BenchmarkStats},
+    benchmark_engine::{
+        check_regression, format_cli_output, regression_webhook_payload, BenchmarkRunner,
+        BenchmarkStats,
+    },
     error::{ApiError, ApiResult},
+    notifications::Notification,
     state::AppState,
+    webhooks,
 };
 use crate::models::{
     BenchmarkComparison, BenchmarkRecord, BenchmarkResponse, BenchmarkRun, BenchmarkStatus,
     BenchmarkTrendPoint, ContractBenchmarkSummary, PerformanceAlert, RunBenchmarkRequest,
 };
 
+/// Rebuild timing stats from a persisted benchmark record
+fn record_stats(record: &BenchmarkRecord) -> BenchmarkStats {
+    BenchmarkStats {
+        min_ms: record.min_ms,
+        max_ms: record.max_ms,
+        avg_ms: record.avg_ms,
+        p95_ms: record.p95_ms,
+        p99_ms: record.p99_ms,
+        stddev_ms: record.stddev_ms,
+    }
+}
+
 // ─────────────────────────────────────────────────────────
 // POST /api/contracts/:id/benchmarks
 // Runs N iterations of a method and persists results.
@@ -145,7 +162,7 @@ pub async fn run_benchmark(
 
         let maybe_alert = if is_regression {
             let alert: PerformanceAlert = sqlx::query_as(
-                r#"INSERT INTO performance_alerts
+                r#"INSERT INTO benchmark_alerts
                        (contract_id, method_name, baseline_benchmark_id, current_benchmark_id,
                         baseline_p95_ms, current_p95_ms, regression_pct, alert_threshold_pct)
                    VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
@@ -169,6 +186,21 @@ pub async fn run_benchmark(
                 regression_pct = %regression_pct,
                 "Performance regression detected"
             );
+
+            if let Some(payload) = regression_webhook_payload(
+                &contract_id.to_string(),
+                &req.method,
+                &record_stats(prev),
+                &record_stats(&benchmark),
+                req.alert_threshold_pct,
+            ) {
+                let notification = Notification::new(
+                    webhooks::EVENT_BENCHMARK_REGRESSION,
+                    webhooks::EventScope::contract(contract_id),
+                    payload,
+                );
+                state.notifier.notify(&notification).await;
+            }
             Some(alert)
         } else {
             None
@@ -261,7 +293,7 @@ pub async fn get_benchmark(
             .map_err(|_| ApiError::db_error("Failed to fetch benchmark runs"))?;
 
     let alert: Option<PerformanceAlert> =
-        sqlx::query_as("SELECT * FROM performance_alerts WHERE current_benchmark_id = $1 LIMIT 1")
+        sqlx::query_as("SELECT * FROM benchmark_alerts WHERE current_benchmark_id = $1 LIMIT 1")
             .bind(benchmark_id)
             .fetch_optional(&state.db)
             .await
@@ -352,7 +384,7 @@ pub async fn get_benchmark_summary(
     .map_err(|_| ApiError::db_error("Failed to fetch latest benchmarks"))?;
 
     let active_alerts: Vec<PerformanceAlert> = sqlx::query_as(
-        "SELECT * FROM performance_alerts WHERE contract_id = $1 AND resolved = false ORDER BY created_at DESC",
+        "SELECT * FROM benchmark_alerts WHERE contract_id = $1 AND resolved = false ORDER BY created_at DESC",
     )
     .bind(contract_id)
     .fetch_all(&state.db)
@@ -376,7 +408,7 @@ pub async fn resolve_alert(
     Path((contract_id, alert_id)): Path<(Uuid, Uuid)>,
 ) -> ApiResult<Json<serde_json::Value>> {
     let rows = sqlx::query(
-        "UPDATE performance_alerts SET resolved = true WHERE id = $1 AND contract_id = $2",
+        "UPDATE benchmark_alerts SET resolved = true WHERE id = $1 AND contract_id = $2",
     )
     .bind(alert_id)
     .bind(contract_id)
@@ -431,7 +463,7 @@ pub async fn get_cli_output(
         r#"SELECT CONCAT('p95 increased ', ROUND(regression_pct::numeric, 1), '% (', 
                          ROUND(baseline_p95_ms::numeric, 2), 'ms → ',
                          ROUND(current_p95_ms::numeric, 2), 'ms)')
-           FROM performance_alerts WHERE current_benchmark_id = $1 LIMIT 1"#,
+           FROM benchmark_alerts WHERE current_benchmark_id = $1 LIMIT 1"#,
     )
     .bind(benchmark_id)
     .fetch_optional(&state.db)
@@ -447,3 +479,67 @@ pub async fn get_cli_output(
         alert_msg.as_deref(),
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    #[tokio::test]
+    #[ignore]
+    async fn regressing_run_enqueues_a_webhook_delivery() {
+        let pool = fixtures::test_pool().await;
+        let contract_id = fixtures::fixtures().contracts[0].id;
+
+        let webhook: Uuid = sqlx::query_scalar(
+            "INSERT INTO webhooks (url, event_types, contract_ids) \
+             VALUES ('https://example.com/hook', ARRAY[$1], ARRAY[$2]) RETURNING id",
+        )
+        .bind(webhooks::EVENT_BENCHMARK_REGRESSION)
+        .bind(contract_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        // A baseline far faster than any simulated invocation
+        sqlx::query(
+            "INSERT INTO benchmark_records \
+             (contract_id, contract_version, method_name, iterations, status, min_ms, max_ms, avg_ms, p95_ms, p99_ms, created_at) \
+             VALUES ($1, '1.0.0', 'transfer', 5, 'completed', 0.001, 0.001, 0.001, 0.001, 0.001, NOW() - INTERVAL '1 hour')",
+        )
+        .bind(contract_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let state = fixtures::state_for(pool.clone());
+        let req: RunBenchmarkRequest =
+            serde_json::from_value(serde_json::json!({ "method": "transfer", "iterations": 5 })).unwrap();
+        let response = run_benchmark(State(state), Path(contract_id), Json(req)).await.unwrap().0;
+        assert!(response.comparison.unwrap().is_regression);
+        assert!(response.alert.is_some());
+
+        let payloads: Vec<serde_json::Value> = sqlx::query_scalar(
+            "SELECT payload FROM webhook_deliveries WHERE webhook_id = $1 AND event_type = $2",
+        )
+        .bind(webhook)
+        .bind(webhooks::EVENT_BENCHMARK_REGRESSION)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(payloads.len(), 1);
+        assert_eq!(payloads[0]["method"], "transfer");
+        assert_eq!(payloads[0]["deltas"].as_array().unwrap().len(), 6);
+
+        sqlx::query("DELETE FROM webhooks WHERE id = $1")
+            .bind(webhook)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM benchmark_records WHERE contract_id = $1")
+            .bind(contract_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
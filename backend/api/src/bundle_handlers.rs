@@ -0,0 +1,382 @@
+// api/src/bundle_handlers.rs
+// Export a contract's full record as a portable JSON bundle and import it
+// into another registry instance.
+
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use shared::{
+    Contract, ContractBundle, ContractDeployment, ContractVersion, DailyAggregate, Publisher,
+    Verification, CONTRACT_BUNDLE_FORMAT_VERSION,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+fn db_internal_error(operation: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = operation, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+// ─────────────────────────────────────────────────────────
+// GET /api/contracts/:id/export
+// ─────────────────────────────────────────────────────────
+pub async fn export_contract(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ContractBundle>> {
+    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("export contract", err))?
+        .ok_or_else(|| {
+            ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id))
+        })?;
+
+    let publisher: Publisher = sqlx::query_as("SELECT * FROM publishers WHERE id = $1")
+        .bind(contract.publisher_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("export publisher", err))?;
+
+    let versions: Vec<ContractVersion> = sqlx::query_as(
+        "SELECT * FROM contract_versions WHERE contract_id = $1 ORDER BY created_at",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("export versions", err))?;
+
+    let verifications: Vec<Verification> = sqlx::query_as(
+        "SELECT * FROM verifications WHERE contract_id = $1 ORDER BY created_at",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("export verifications", err))?;
+
+    let deployments: Vec<ContractDeployment> = sqlx::query_as(
+        "SELECT * FROM contract_deployments WHERE contract_id = $1 ORDER BY deployed_at",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("export deployments", err))?;
+
+    let latest_analytics: Option<DailyAggregate> = sqlx::query_as(
+        "SELECT * FROM analytics_daily_aggregates WHERE contract_id = $1 ORDER BY date DESC LIMIT 1",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("export analytics", err))?;
+
+    Ok(Json(ContractBundle {
+        format_version: CONTRACT_BUNDLE_FORMAT_VERSION,
+        exported_at: Utc::now(),
+        publisher,
+        contract,
+        versions,
+        verifications,
+        deployments,
+        latest_analytics,
+    }))
+}
+
+// ─────────────────────────────────────────────────────────
+// POST /api/contracts/import  (admin only)
+// ─────────────────────────────────────────────────────────
+pub async fn import_contract(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(bundle): Json<ContractBundle>,
+) -> ApiResult<(StatusCode, Json<Contract>)> {
+    auth.require_admin()?;
+
+    bundle
+        .validate()
+        .map_err(|errors| ApiError::unprocessable("InvalidBundle", errors.join("; ")))?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin import", err))?;
+
+    // Publishers are matched by address; the local id may differ from the source registry.
+    let publisher_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO publishers (stellar_address, username, email, github_url, website)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
+         RETURNING id",
+    )
+    .bind(&bundle.publisher.stellar_address)
+    .bind(&bundle.publisher.username)
+    .bind(&bundle.publisher.email)
+    .bind(&bundle.publisher.github_url)
+    .bind(&bundle.publisher.website)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("import publisher", err))?;
+
+    let c = &bundle.contract;
+    sqlx::query(
+        "INSERT INTO contracts (id, contract_id, wasm_hash, name, description, publisher_id, network,
+                                is_verified, category, tags, created_at, updated_at, maturity,
+                                logical_id, network_configs)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)",
+    )
+    .bind(c.id)
+    .bind(&c.contract_id)
+    .bind(&c.wasm_hash)
+    .bind(&c.name)
+    .bind(&c.description)
+    .bind(publisher_id)
+    .bind(&c.network)
+    .bind(c.is_verified)
+    .bind(&c.category)
+    .bind(&c.tags)
+    .bind(c.created_at)
+    .bind(c.updated_at)
+    .bind(&c.maturity)
+    .bind(c.logical_id)
+    .bind(&c.network_configs)
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref e) if e.is_unique_violation() => ApiError::conflict(
+            "ContractAlreadyRegistered",
+            format!(
+                "Contract {} already exists on {}",
+                c.contract_id, c.network
+            ),
+        ),
+        _ => db_internal_error("import contract", err),
+    })?;
+
+    for v in &bundle.versions {
+        sqlx::query(
+            "INSERT INTO contract_versions (id, contract_id, version, wasm_hash, source_url,
+                                            commit_hash, release_notes, created_at, state_schema)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(v.id)
+        .bind(c.id)
+        .bind(&v.version)
+        .bind(&v.wasm_hash)
+        .bind(&v.source_url)
+        .bind(&v.commit_hash)
+        .bind(&v.release_notes)
+        .bind(v.created_at)
+        .bind(&v.state_schema)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("import version", err))?;
+    }
+
+    for v in &bundle.verifications {
+        sqlx::query(
+            "INSERT INTO verifications (id, contract_id, status, source_code, build_params,
+                                        compiler_version, verified_at, error_message, build_log,
+                                        created_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)",
+        )
+        .bind(v.id)
+        .bind(c.id)
+        .bind(&v.status)
+        .bind(&v.source_code)
+        .bind(&v.build_params)
+        .bind(&v.compiler_version)
+        .bind(v.verified_at)
+        .bind(&v.error_message)
+        .bind(&v.build_log)
+        .bind(v.created_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("import verification", err))?;
+    }
+
+    for d in &bundle.deployments {
+        sqlx::query(
+            "INSERT INTO contract_deployments (id, contract_id, environment, status, wasm_hash,
+                                               deployed_at, activated_at, health_checks_passed,
+                                               health_checks_failed, last_health_check_at, error_message)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+        )
+        .bind(d.id)
+        .bind(c.id)
+        .bind(&d.environment)
+        .bind(&d.status)
+        .bind(&d.wasm_hash)
+        .bind(d.deployed_at)
+        .bind(d.activated_at)
+        .bind(d.health_checks_passed)
+        .bind(d.health_checks_failed)
+        .bind(d.last_health_check_at)
+        .bind(&d.error_message)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("import deployment", err))?;
+    }
+
+    if let Some(a) = &bundle.latest_analytics {
+        sqlx::query(
+            "INSERT INTO analytics_daily_aggregates (contract_id, date, deployment_count, unique_deployers,
+                                                     verification_count, publish_count, version_count,
+                                                     total_events, unique_users, network_breakdown, top_users)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)",
+        )
+        .bind(c.id)
+        .bind(a.date)
+        .bind(a.deployment_count)
+        .bind(a.unique_deployers)
+        .bind(a.verification_count)
+        .bind(a.publish_count)
+        .bind(a.version_count)
+        .bind(a.total_events)
+        .bind(a.unique_users)
+        .bind(&a.network_breakdown)
+        .bind(&a.top_users)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("import analytics", err))?;
+    }
+
+    let imported: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(c.id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("fetch imported contract", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit import", err))?;
+
+    tracing::info!(contract_id = %imported.id, imported_by = %auth.publisher_address, "contract bundle imported");
+
+    Ok((StatusCode::CREATED, Json(imported)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{DeploymentEnvironment, DeploymentStatus, Network, VerificationStatus};
+
+    fn sample_bundle() -> ContractBundle {
+        let now = Utc::now();
+        let publisher_id = Uuid::new_v4();
+        let contract_id = Uuid::new_v4();
+        let contract: Contract = serde_json::from_value(serde_json::json!({
+            "id": contract_id,
+            "contract_id": "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA",
+            "wasm_hash": "ab".repeat(32),
+            "name": "Token",
+            "description": "A token, with \"quotes\"",
+            "publisher_id": publisher_id,
+            "network": "testnet",
+            "is_verified": true,
+            "category": "defi",
+            "tags": ["token", "defi"],
+            "created_at": now,
+            "updated_at": now,
+            "maturity": "stable",
+        }))
+        .unwrap();
+
+        ContractBundle {
+            format_version: CONTRACT_BUNDLE_FORMAT_VERSION,
+            exported_at: now,
+            publisher: Publisher {
+                id: publisher_id,
+                stellar_address: "GABC".into(),
+                username: Some("alice".into()),
+                email: None,
+                github_url: None,
+                website: None,
+                created_at: now,
+            },
+            contract,
+            versions: vec![ContractVersion {
+                id: Uuid::new_v4(),
+                contract_id,
+                version: "1.0.0".into(),
+                wasm_hash: "cd".repeat(32),
+                source_url: None,
+                commit_hash: Some("abc123".into()),
+                release_notes: Some("Initial".into()),
+                created_at: now,
+                state_schema: None,
+            }],
+            verifications: vec![Verification {
+                id: Uuid::new_v4(),
+                contract_id,
+                status: VerificationStatus::Verified,
+                source_code: None,
+                build_params: Some(serde_json::json!({"profile": "release"})),
+                compiler_version: Some("1.75.0".into()),
+                verified_at: Some(now),
+                error_message: None,
+                build_log: None,
+                created_at: now,
+            }],
+            deployments: vec![ContractDeployment {
+                id: Uuid::new_v4(),
+                contract_id,
+                environment: DeploymentEnvironment::Blue,
+                status: DeploymentStatus::Active,
+                wasm_hash: "cd".repeat(32),
+                deployed_at: now,
+                activated_at: Some(now),
+                health_checks_passed: 3,
+                health_checks_failed: 0,
+                consecutive_passes: 0,
+                last_health_check_at: None,
+                error_message: None,
+            }],
+            latest_analytics: None,
+        }
+    }
+
+    #[test]
+    fn round_trip_preserves_fields() {
+        let bundle = sample_bundle();
+        let json = serde_json::to_string(&bundle).unwrap();
+        let parsed: ContractBundle = serde_json::from_str(&json).unwrap();
+
+        assert!(parsed.validate().is_ok());
+        assert_eq!(parsed.contract.id, bundle.contract.id);
+        assert_eq!(parsed.contract.description, bundle.contract.description);
+        assert_eq!(parsed.contract.tags, bundle.contract.tags);
+        assert_eq!(parsed.contract.maturity, bundle.contract.maturity);
+        assert!(matches!(parsed.contract.network, Network::Testnet));
+        assert_eq!(parsed.versions[0].version, "1.0.0");
+        assert_eq!(parsed.versions[0].commit_hash.as_deref(), Some("abc123"));
+        assert_eq!(parsed.verifications[0].build_params, bundle.verifications[0].build_params);
+        assert_eq!(parsed.deployments[0].health_checks_passed, 3);
+        assert_eq!(parsed.publisher.stellar_address, "GABC");
+    }
+
+    #[test]
+    fn foreign_records_fail_validation() {
+        let mut bundle = sample_bundle();
+        bundle.versions[0].contract_id = Uuid::new_v4();
+        bundle.deployments[0].contract_id = Uuid::new_v4();
+        let errors = bundle.validate().unwrap_err();
+        assert_eq!(errors.len(), 2);
+    }
+
+    #[test]
+    fn publisher_mismatch_fails_validation() {
+        let mut bundle = sample_bundle();
+        bundle.publisher.id = Uuid::new_v4();
+        assert!(bundle.validate().is_err());
+    }
+}
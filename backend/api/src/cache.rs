@@ -1,3 +1,7 @@
+// Cache failures are never fatal: `CacheLayer` logs a backend error, counts
+// it in `CacheMetrics::errors` and carries on as if it were a miss (reads) or
+// a no-op (writes), so callers always fall through to the database.
+
 use async_trait::async_trait;
 use moka::future::Cache as MokaCache;
 use std::sync::atomic::{AtomicUsize, Ordering};
@@ -81,11 +85,20 @@ impl CacheConfig {
     }
 }
 
+/// A cache backend could not serve a request
+#[derive(Debug, thiserror::Error)]
+pub enum CacheError {
+    #[error("cache backend unavailable: {0}")]
+    Unavailable(String),
+}
+
 /// Metrics for cache performance - with symmetric instrumentation
 #[derive(Debug, Default)]
 pub struct CacheMetrics {
     pub hits: AtomicUsize,
     pub misses: AtomicUsize,
+    /// Backend errors absorbed by `CacheLayer`
+    pub errors: AtomicUsize,
 
     // Cached hit latency (µs) - recorded when cache hit occurs
     pub cached_hit_latency_sum_micros: AtomicUsize,
@@ -171,7 +184,7 @@ pub struct CacheReadResult {
 #[async_trait]
 pub trait ContractStateCache: Send + Sync {
     /// Get from cache. Returns (value, was_hit, lookup_latency_micros)
-    async fn get(&self, contract_id: &str, key: &str) -> CacheReadResult;
+    async fn get(&self, contract_id: &str, key: &str) -> Result<CacheReadResult, CacheError>;
 
     /// Put into cache with optional per-key TTL override
     async fn put(
@@ -180,10 +193,10 @@ pub trait ContractStateCache: Send + Sync {
         key: &str,
         value: String,
         ttl_override: Option<Duration>,
-    );
+    ) -> Result<(), CacheError>;
 
     /// Invalidate a cache entry
-    async fn invalidate(&self, contract_id: &str, key: &str);
+    async fn invalidate(&self, contract_id: &str, key: &str) -> Result<(), CacheError>;
 
     fn metrics(&self) -> &CacheMetrics;
 }
@@ -210,7 +223,7 @@ impl MokaLfuCache {
 
 #[async_trait]
 impl ContractStateCache for MokaLfuCache {
-    async fn get(&self, contract_id: &str, key: &str) -> CacheReadResult {
+    async fn get(&self, contract_id: &str, key: &str) -> Result<CacheReadResult, CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
         let start = Instant::now();
 
@@ -225,11 +238,11 @@ impl ContractStateCache for MokaLfuCache {
                         // Expired entry
                         self.cache.invalidate(&cache_key).await;
                         self.metrics.misses.fetch_add(1, Ordering::Relaxed);
-                        return CacheReadResult {
+                        return Ok(CacheReadResult {
                             value: None,
                             was_hit: false,
                             lookup_latency_micros: lookup_latency,
-                        };
+                        });
                     }
                 }
 
@@ -242,20 +255,20 @@ impl ContractStateCache for MokaLfuCache {
                     .cached_hit_count
                     .fetch_add(1, Ordering::Relaxed);
 
-                CacheReadResult {
+                Ok(CacheReadResult {
                     value: Some(value),
                     was_hit: true,
                     lookup_latency_micros: lookup_latency,
-                }
+                })
             }
             None => {
                 // Cache miss
                 self.metrics.misses.fetch_add(1, Ordering::Relaxed);
-                CacheReadResult {
+                Ok(CacheReadResult {
                     value: None,
                     was_hit: false,
                     lookup_latency_micros: lookup_latency,
-                }
+                })
             }
         }
     }
@@ -266,17 +279,19 @@ impl ContractStateCache for MokaLfuCache {
         key: &str,
         value: String,
         ttl_override: Option<Duration>,
-    ) {
+    ) -> Result<(), CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
 
         // Support per-key TTL by storing expiry time with value
         let expiry = ttl_override.map(|ttl| Instant::now() + ttl);
         self.cache.insert(cache_key, (value, expiry)).await;
+        Ok(())
     }
 
-    async fn invalidate(&self, contract_id: &str, key: &str) {
+    async fn invalidate(&self, contract_id: &str, key: &str) -> Result<(), CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
         self.cache.invalidate(&cache_key).await;
+        Ok(())
     }
 
     fn metrics(&self) -> &CacheMetrics {
@@ -300,7 +315,7 @@ impl LruCacheImpl {
     pub fn new(capacity: u64, ttl: Duration) -> Self {
         Self {
             cache: RwLock::new(lru::LruCache::new(
-                std::num::NonZeroUsize::new(capacity as usize).unwrap(),
+                std::num::NonZeroUsize::new(capacity as usize).unwrap_or(std::num::NonZeroUsize::MIN),
             )),
             metrics: CacheMetrics::default(),
             default_ttl: ttl,
@@ -310,7 +325,7 @@ impl LruCacheImpl {
 
 #[async_trait]
 impl ContractStateCache for LruCacheImpl {
-    async fn get(&self, contract_id: &str, key: &str) -> CacheReadResult {
+    async fn get(&self, contract_id: &str, key: &str) -> Result<CacheReadResult, CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
         let start = Instant::now();
         let mut cache = self.cache.write().await;
@@ -328,11 +343,11 @@ impl ContractStateCache for LruCacheImpl {
                     .cached_hit_count
                     .fetch_add(1, Ordering::Relaxed);
 
-                return CacheReadResult {
+                return Ok(CacheReadResult {
                     value: Some(entry.value.clone()),
                     was_hit: true,
                     lookup_latency_micros: lookup_latency,
-                };
+                });
             } else {
                 // Expired - remove it
                 cache.pop(&cache_key);
@@ -342,11 +357,11 @@ impl ContractStateCache for LruCacheImpl {
         // Miss (not found or expired)
         let lookup_latency = start.elapsed().as_micros() as usize;
         self.metrics.misses.fetch_add(1, Ordering::Relaxed);
-        CacheReadResult {
+        Ok(CacheReadResult {
             value: None,
             was_hit: false,
             lookup_latency_micros: lookup_latency,
-        }
+        })
     }
 
     async fn put(
@@ -355,18 +370,20 @@ impl ContractStateCache for LruCacheImpl {
         key: &str,
         value: String,
         ttl_override: Option<Duration>,
-    ) {
+    ) -> Result<(), CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
         let ttl = ttl_override.unwrap_or(self.default_ttl);
         let expiry = Instant::now() + ttl;
         let mut cache = self.cache.write().await;
         cache.put(cache_key, LruEntry { value, expiry });
+        Ok(())
     }
 
-    async fn invalidate(&self, contract_id: &str, key: &str) {
+    async fn invalidate(&self, contract_id: &str, key: &str) -> Result<(), CacheError> {
         let cache_key = format!("{}:{}", contract_id, key);
         let mut cache = self.cache.write().await;
         cache.pop(&cache_key);
+        Ok(())
     }
 
     fn metrics(&self) -> &CacheMetrics {
@@ -391,9 +408,19 @@ impl CacheLayer {
             }
         };
 
+        Self::with_backend(config, backend)
+    }
+
+    /// Wrap a specific backend, e.g. an external cache or a test double.
+    pub fn with_backend(config: CacheConfig, backend: Box<dyn ContractStateCache + Send + Sync>) -> Self {
         Self { backend, config }
     }
 
+    fn backend_failed(&self, op: &'static str, err: CacheError) {
+        self.backend.metrics().errors.fetch_add(1, Ordering::Relaxed);
+        tracing::warn!(operation = op, error = %err, "cache backend error; continuing without cache");
+    }
+
     pub fn config(&self) -> &CacheConfig {
         &self.config
     }
@@ -405,7 +432,13 @@ impl CacheLayer {
             return (None, false);
         }
 
-        let result = self.backend.get(contract_id, key).await;
+        let result = match self.backend.get(contract_id, key).await {
+            Ok(result) => result,
+            Err(err) => {
+                self.backend_failed("get", err);
+                return (None, false);
+            }
+        };
 
         // Record cache miss latency if this was a miss
         if !result.was_hit {
@@ -432,16 +465,18 @@ impl CacheLayer {
         if !self.config.enabled {
             return;
         }
-        self.backend
-            .put(contract_id, key, value, ttl_override)
-            .await;
+        if let Err(err) = self.backend.put(contract_id, key, value, ttl_override).await {
+            self.backend_failed("put", err);
+        }
     }
 
     pub async fn invalidate(&self, contract_id: &str, key: &str) {
         if !self.config.enabled {
             return;
         }
-        self.backend.invalidate(contract_id, key).await;
+        if let Err(err) = self.backend.invalidate(contract_id, key).await {
+            self.backend_failed("invalidate", err);
+        }
     }
 
     pub fn metrics(&self) -> &CacheMetrics {
@@ -462,9 +497,51 @@ impl CacheLayer {
     }
 }
 
+/// Cache doubles for tests elsewhere in the crate
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+
+    /// Backend that fails every operation, like an unreachable external cache
+    pub struct FailingCache {
+        metrics: CacheMetrics,
+    }
+
+    impl FailingCache {
+        pub fn layer() -> CacheLayer {
+            CacheLayer::with_backend(
+                CacheConfig::default(),
+                Box::new(FailingCache {
+                    metrics: CacheMetrics::default(),
+                }),
+            )
+        }
+    }
+
+    #[async_trait]
+    impl ContractStateCache for FailingCache {
+        async fn get(&self, _contract_id: &str, _key: &str) -> Result<CacheReadResult, CacheError> {
+            Err(CacheError::Unavailable("connection refused".into()))
+        }
+
+        async fn put(&self, _: &str, _: &str, _: String, _: Option<Duration>) -> Result<(), CacheError> {
+            Err(CacheError::Unavailable("connection refused".into()))
+        }
+
+        async fn invalidate(&self, _contract_id: &str, _key: &str) -> Result<(), CacheError> {
+            Err(CacheError::Unavailable("connection refused".into()))
+        }
+
+        fn metrics(&self) -> &CacheMetrics {
+            &self.metrics
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::test_support::FailingCache;
 
     #[tokio::test]
     async fn test_basic_flow() {
@@ -590,4 +667,43 @@ mod tests {
         let (val, _) = cache.get("c1", "k1").await;
         assert!(val.is_none());
     }
+
+    #[tokio::test]
+    async fn backend_failures_degrade_to_misses() {
+        let cache = FailingCache::layer();
+
+        cache.put("c1", "k1", "v1".to_string(), None).await;
+        cache.invalidate("c1", "k1").await;
+        let (val, was_hit) = cache.get("c1", "k1").await;
+
+        assert!(val.is_none());
+        assert!(!was_hit);
+        assert_eq!(cache.metrics().errors.load(Ordering::Relaxed), 3);
+        assert_eq!(cache.metrics().hits.load(Ordering::Relaxed), 0);
+    }
+
+    #[tokio::test]
+    async fn reads_fall_through_to_the_source_when_the_cache_fails() {
+        let cache = FailingCache::layer();
+        let mut source_reads = 0;
+
+        for _ in 0..2 {
+            let value = match cache.get("leaderboard", "popularity").await {
+                (Some(cached), true) => cached,
+                _ => {
+                    source_reads += 1;
+                    let fresh = "from-db".to_string();
+                    cache.put("leaderboard", "popularity", fresh.clone(), None).await;
+                    fresh
+                }
+            };
+            assert_eq!(value, "from-db");
+        }
+        assert_eq!(source_reads, 2);
+    }
+
+    #[test]
+    fn zero_capacity_lru_does_not_panic() {
+        let _ = LruCacheImpl::new(0, Duration::from_secs(1));
+    }
 }
@@ -0,0 +1,252 @@
+// api/src/changelog.rs
+// Changelog compiled from a contract's published versions.
+//
+//   GET /api/contracts/:id/changelog[?format=markdown|json]
+//
+// Markdown (the default) follows the Keep a Changelog layout: one section per
+// version, newest first, with its release date, release notes and a link to
+// the commit when the version records one. Commit links are built from the
+// version's `source_url` when it is an http(s) repository URL; otherwise the
+// short hash is shown on its own.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{ContractVersion, SemVer};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+/// Characters of a commit hash shown in link text
+const SHORT_HASH_LEN: usize = 7;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangelogFormat {
+    #[default]
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ChangelogQuery {
+    #[serde(default)]
+    pub format: ChangelogFormat,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChangelogEntry {
+    pub version: String,
+    pub released_at: DateTime<Utc>,
+    pub release_notes: Option<String>,
+    pub commit_hash: Option<String>,
+    pub commit_url: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Changelog {
+    pub contract_id: Uuid,
+    pub name: String,
+    pub entries: Vec<ChangelogEntry>,
+}
+
+/// Link to `commit` in the repository at `source_url`, when both look sane.
+pub fn commit_url(source_url: Option<&str>, commit: &str) -> Option<String> {
+    if commit.is_empty() || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let repo = source_url?.trim();
+    if !(repo.starts_with("https://") || repo.starts_with("http://")) || repo.contains(char::is_whitespace) {
+        return None;
+    }
+    let repo = repo.trim_end_matches('/').trim_end_matches(".git");
+    Some(format!("{}/commit/{}", repo, commit))
+}
+
+/// Entries newest first; versions released at the same instant are ordered by semver.
+pub fn build_entries(mut versions: Vec<ContractVersion>) -> Vec<ChangelogEntry> {
+    versions.sort_by(|a, b| {
+        b.created_at
+            .cmp(&a.created_at)
+            .then_with(|| SemVer::parse(&b.version).cmp(&SemVer::parse(&a.version)))
+    });
+
+    versions
+        .into_iter()
+        .map(|v| {
+            let commit_hash = v.commit_hash.map(|h| h.trim().to_string()).filter(|h| !h.is_empty());
+            let commit_url = commit_hash
+                .as_deref()
+                .and_then(|hash| commit_url(v.source_url.as_deref(), hash));
+            ChangelogEntry {
+                version: v.version,
+                released_at: v.created_at,
+                release_notes: v.release_notes.map(|n| n.trim().to_string()).filter(|n| !n.is_empty()),
+                commit_hash,
+                commit_url,
+            }
+        })
+        .collect()
+}
+
+pub fn render_markdown(name: &str, entries: &[ChangelogEntry]) -> String {
+    let mut md = format!("# Changelog: {}\n", name);
+    if entries.is_empty() {
+        md.push_str("\nNo versions have been published yet.\n");
+        return md;
+    }
+
+    for entry in entries {
+        md.push_str(&format!(
+            "\n## [{}] - {}\n\n",
+            entry.version,
+            entry.released_at.format("%Y-%m-%d")
+        ));
+        match &entry.release_notes {
+            Some(notes) => {
+                md.push_str(notes);
+                md.push('\n');
+            }
+            None => md.push_str("_No release notes._\n"),
+        }
+        if let Some(hash) = &entry.commit_hash {
+            let short: String = hash.chars().take(SHORT_HASH_LEN).collect();
+            match &entry.commit_url {
+                Some(url) => md.push_str(&format!("\nCommit: [`{}`]({})\n", short, url)),
+                None => md.push_str(&format!("\nCommit: `{}`\n", short)),
+            }
+        }
+    }
+    md
+}
+
+/// GET /api/contracts/:id/changelog
+pub async fn get_changelog(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ChangelogQuery>,
+) -> ApiResult<Response> {
+    let name: String = sqlx::query_scalar("SELECT name FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for changelog", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))?;
+
+    let versions: Vec<ContractVersion> = sqlx::query_as("SELECT * FROM contract_versions WHERE contract_id = $1")
+        .bind(id)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("list versions for changelog", err))?;
+    let entries = build_entries(versions);
+
+    Ok(match query.format {
+        ChangelogFormat::Markdown => (
+            [(header::CONTENT_TYPE, "text/markdown; charset=utf-8")],
+            render_markdown(&name, &entries),
+        )
+            .into_response(),
+        ChangelogFormat::Json => Json(Changelog {
+            contract_id: id,
+            name,
+            entries,
+        })
+        .into_response(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn version(v: &str, day: u32, notes: Option<&str>, commit: Option<&str>, source: Option<&str>) -> ContractVersion {
+        ContractVersion {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::nil(),
+            version: v.into(),
+            wasm_hash: "00".into(),
+            source_url: source.map(str::to_string),
+            commit_hash: commit.map(str::to_string),
+            release_notes: notes.map(str::to_string),
+            created_at: Utc.with_ymd_and_hms(2026, 3, day, 9, 0, 0).unwrap(),
+            state_schema: None,
+        }
+    }
+
+    #[test]
+    fn markdown_lists_versions_newest_first_with_commit_links() {
+        let versions = vec![
+            version("1.0.0", 1, Some("Initial release."), None, None),
+            version(
+                "1.2.0",
+                20,
+                Some("- Add `swap_exact`\n- Fix rounding"),
+                Some("a1b2c3d4e5f6"),
+                Some("https://github.com/acme/amm.git"),
+            ),
+            version("1.1.0", 10, None, Some("deadbeefcafe"), Some("ipfs://not-a-repo")),
+        ];
+
+        let md = render_markdown("AMM", &build_entries(versions));
+
+        assert_eq!(
+            md,
+            "# Changelog: AMM\n\
+             \n## [1.2.0] - 2026-03-20\n\n\
+             - Add `swap_exact`\n- Fix rounding\n\
+             \nCommit: [`a1b2c3d`](https://github.com/acme/amm/commit/a1b2c3d4e5f6)\n\
+             \n## [1.1.0] - 2026-03-10\n\n\
+             _No release notes._\n\
+             \nCommit: `deadbee`\n\
+             \n## [1.0.0] - 2026-03-01\n\n\
+             Initial release.\n"
+        );
+    }
+
+    #[test]
+    fn same_day_versions_are_ordered_by_semver() {
+        let entries = build_entries(vec![
+            version("1.9.0", 5, None, None, None),
+            version("1.10.0", 5, None, None, None),
+        ]);
+        let order: Vec<_> = entries.iter().map(|e| e.version.as_str()).collect();
+        assert_eq!(order, vec!["1.10.0", "1.9.0"]);
+    }
+
+    #[test]
+    fn blank_notes_and_hashes_are_dropped() {
+        let entries = build_entries(vec![version("0.1.0", 1, Some("   "), Some(" "), None)]);
+        assert_eq!(entries[0].release_notes, None);
+        assert_eq!(entries[0].commit_hash, None);
+        assert!(!render_markdown("X", &entries).contains("Commit"));
+    }
+
+    #[test]
+    fn commit_links_need_a_repository_url_and_hex_hash() {
+        assert_eq!(
+            commit_url(Some("https://gitlab.com/acme/amm/"), "abc123").as_deref(),
+            Some("https://gitlab.com/acme/amm/commit/abc123")
+        );
+        assert_eq!(commit_url(None, "abc123"), None);
+        assert_eq!(commit_url(Some("javascript:alert(1)"), "abc123"), None);
+        assert_eq!(commit_url(Some("https://github.com/acme/amm"), "abc)[x]("), None);
+    }
+
+    #[test]
+    fn empty_changelog() {
+        assert_eq!(
+            render_markdown("Fresh", &[]),
+            "# Changelog: Fresh\n\nNo versions have been published yet.\n"
+        );
+    }
+}
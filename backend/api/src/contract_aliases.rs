@@ -0,0 +1,204 @@
+// api/src/contract_aliases.rs
+// Shareable short names for contracts.
+//
+//   POST /api/contracts/:id/alias — register an alias (publisher/admin)
+//   GET  /api/c/*alias            — resolve an alias to its contract
+//
+// An alias is one to three `/`-separated segments of lowercase letters,
+// digits and single hyphens, e.g. `defi/amm-v2`. Aliases are compared
+// case-insensitively (they are stored lowercased) and are unique across the
+// whole registry; a contract may have more than one.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Contract;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, ensure_contract_owner},
+    state::AppState,
+};
+
+const MIN_ALIAS_LEN: usize = 3;
+const MAX_ALIAS_LEN: usize = 64;
+const MAX_ALIAS_SEGMENTS: usize = 3;
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CreateAliasRequest {
+    pub alias: String,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ContractAlias {
+    pub alias: String,
+    pub contract_id: Uuid,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+fn valid_segment(segment: &str) -> bool {
+    !segment.is_empty()
+        && !segment.starts_with('-')
+        && !segment.ends_with('-')
+        && !segment.contains("--")
+        && segment.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+/// Lowercase `raw` and check it against the alias format.
+pub fn normalize_alias(raw: &str) -> ApiResult<String> {
+    let alias = raw.trim().to_ascii_lowercase();
+    if alias.len() < MIN_ALIAS_LEN || alias.len() > MAX_ALIAS_LEN {
+        return Err(ApiError::bad_request(
+            "InvalidAlias",
+            format!("alias must be {}-{} characters", MIN_ALIAS_LEN, MAX_ALIAS_LEN),
+        ));
+    }
+    let segments: Vec<&str> = alias.split('/').collect();
+    if segments.len() > MAX_ALIAS_SEGMENTS || !segments.iter().all(|s| valid_segment(s)) {
+        return Err(ApiError::bad_request(
+            "InvalidAlias",
+            format!(
+                "alias must be up to {} '/'-separated segments of a-z, 0-9 and single hyphens (e.g. defi/amm-v2)",
+                MAX_ALIAS_SEGMENTS
+            ),
+        ));
+    }
+    Ok(alias)
+}
+
+/// Store `alias` for `contract_id`; a taken alias is a 409.
+pub async fn insert_alias(pool: &PgPool, contract_id: Uuid, alias: &str, created_by: &str) -> ApiResult<ContractAlias> {
+    sqlx::query_as(
+        "INSERT INTO contract_aliases (alias, contract_id, created_by)
+         VALUES ($1, $2, $3)
+         RETURNING alias, contract_id, created_by, created_at",
+    )
+    .bind(alias)
+    .bind(contract_id)
+    .bind(created_by)
+    .fetch_one(pool)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref e) if e.is_unique_violation() => {
+            ApiError::conflict("AliasTaken", format!("Alias '{}' is already registered", alias))
+        }
+        err => db_internal_error("create contract alias", err),
+    })
+}
+
+/// The contract `alias` points to.
+pub async fn resolve_alias(pool: &PgPool, alias: &str) -> ApiResult<Contract> {
+    sqlx::query_as(
+        "SELECT c.* FROM contract_aliases a
+         JOIN contracts c ON c.id = a.contract_id
+         WHERE a.alias = $1 AND c.deleted_at IS NULL",
+    )
+    .bind(alias)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| db_internal_error("resolve contract alias", err))?
+    .ok_or_else(|| ApiError::not_found("AliasNotFound", format!("No contract found with alias: {}", alias)))
+}
+
+/// POST /api/contracts/:id/alias
+pub async fn create_alias(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<CreateAliasRequest>,
+) -> ApiResult<(StatusCode, Json<ContractAlias>)> {
+    let alias = normalize_alias(&req.alias)?;
+    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for alias", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let created = insert_alias(&state.db, id, &alias, &auth.publisher_address).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// GET /api/c/*alias
+pub async fn get_contract_by_alias(
+    State(state): State<AppState>,
+    Path(alias): Path<String>,
+) -> ApiResult<Json<Contract>> {
+    // An alias that fails the format check cannot have been registered
+    let alias = normalize_alias(&alias)
+        .map_err(|_| ApiError::not_found("AliasNotFound", format!("No contract found with alias: {}", alias)))?;
+    Ok(Json(resolve_alias(&state.db, &alias).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn well_formed_aliases_are_accepted_and_lowercased() {
+        assert_eq!(normalize_alias("defi/amm-v2").unwrap(), "defi/amm-v2");
+        assert_eq!(normalize_alias("  DeFi/AMM-v2 ").unwrap(), "defi/amm-v2");
+        assert_eq!(normalize_alias("usdc").unwrap(), "usdc");
+        assert_eq!(normalize_alias("acme/dex/router-2").unwrap(), "acme/dex/router-2");
+    }
+
+    #[test]
+    fn malformed_aliases_are_rejected() {
+        for bad in [
+            "ab",
+            "defi/",
+            "/amm",
+            "defi//amm",
+            "-amm",
+            "amm-",
+            "amm--v2",
+            "amm_v2",
+            "amm v2",
+            "a/b/c/d",
+            "défi/amm",
+            "../admin",
+        ] {
+            let err = normalize_alias(bad).unwrap_err();
+            assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST, "{}", bad);
+        }
+        assert!(normalize_alias(&"a".repeat(MAX_ALIAS_LEN + 1)).is_err());
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn alias_uniqueness_and_resolution() {
+        let pool = crate::fixtures::test_pool().await;
+        let fixtures = crate::fixtures::fixtures();
+        let (first, second) = (fixtures.contracts[0].id, fixtures.contracts[1].id);
+        let alias = format!("test/alias-{}", Uuid::new_v4().simple());
+
+        let created = insert_alias(&pool, first, &alias, "GTEST").await.unwrap();
+        assert_eq!(created.contract_id, first);
+        assert_eq!(resolve_alias(&pool, &alias).await.unwrap().id, first);
+
+        // Taken, even for a different contract
+        let err = insert_alias(&pool, second, &alias, "GTEST").await.unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+        assert_eq!(resolve_alias(&pool, &alias).await.unwrap().id, first);
+
+        let err = resolve_alias(&pool, "test/never-registered").await.unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::NOT_FOUND);
+
+        sqlx::query("DELETE FROM contract_aliases WHERE alias = $1")
+            .bind(&alias)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
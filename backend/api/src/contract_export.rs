@@ -0,0 +1,229 @@
+// api/src/contract_export.rs
+// GET /api/contracts/export.csv — stream the registry as CSV.
+//
+// Rows are read through a sqlx cursor and flushed to the client in fixed-size
+// chunks, so memory use does not grow with the size of the contracts table.
+
+use axum::{
+    body::Body,
+    extract::{rejection::QueryRejection, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use futures::{Stream, StreamExt};
+use shared::{Contract, ContractSearchParams};
+use sqlx::QueryBuilder;
+use std::borrow::Cow;
+use tokio::sync::mpsc;
+
+use crate::{error::ApiError, handlers::push_contract_filters, state::AppState};
+
+/// Rows encoded per chunk written to the response body
+const CSV_CHUNK_ROWS: usize = 500;
+
+const CSV_HEADER: &str = "id,contract_id,name,description,network,publisher_id,category,tags,\
+is_verified,maturity,created_at,updated_at\n";
+
+/// Quote a field when it contains a delimiter, quote or line break.
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+fn push_csv_row(out: &mut String, c: &Contract) {
+    let maturity = serde_json::to_value(&c.maturity)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    let fields = [
+        c.id.to_string(),
+        c.contract_id.clone(),
+        c.name.clone(),
+        c.description.clone().unwrap_or_default(),
+        c.network.to_string(),
+        c.publisher_id.to_string(),
+        c.category.clone().unwrap_or_default(),
+        c.tags.join(";"),
+        c.is_verified.to_string(),
+        maturity,
+        c.created_at.to_rfc3339(),
+        c.updated_at.to_rfc3339(),
+    ];
+
+    for (i, field) in fields.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        out.push_str(&csv_field(field));
+    }
+    out.push('\n');
+}
+
+/// Encode a stream of contracts as CSV: the header, then one chunk per
+/// `chunk_rows` rows. A chunk containing a failed row yields that error.
+pub fn contracts_csv_stream<S, E>(rows: S, chunk_rows: usize) -> impl Stream<Item = Result<String, E>>
+where
+    S: Stream<Item = Result<Contract, E>>,
+{
+    let header = futures::stream::once(async { Ok(CSV_HEADER.to_string()) });
+    let body = rows.chunks(chunk_rows.max(1)).map(|chunk| {
+        let mut out = String::new();
+        for row in chunk {
+            push_csv_row(&mut out, &row?);
+        }
+        Ok(out)
+    });
+    header.chain(body)
+}
+
+pub async fn export_contracts_csv(
+    State(state): State<AppState>,
+    params: Result<Query<ContractSearchParams>, QueryRejection>,
+) -> Response {
+    let Query(params) = match params {
+        Ok(q) => q,
+        Err(err) => {
+            return ApiError::bad_request("InvalidQuery", err.body_text()).into_response();
+        }
+    };
+
+    let mut query = QueryBuilder::new("SELECT c.* FROM contracts c WHERE 1=1");
+    push_contract_filters(&mut query, &params);
+    query.push(" ORDER BY c.created_at, c.id");
+
+    // The cursor borrows the pool and query, so it is driven from its own
+    // task and handed to the response body through a bounded channel.
+    let (tx, mut rx) = mpsc::channel::<Result<String, sqlx::Error>>(4);
+    let db = state.db.clone();
+    tokio::spawn(async move {
+        let rows = query.build_query_as::<Contract>().fetch(&db);
+        let mut chunks = Box::pin(contracts_csv_stream(rows, CSV_CHUNK_ROWS));
+        while let Some(chunk) = chunks.next().await {
+            if let Err(ref err) = chunk {
+                tracing::error!(error = ?err, "contract CSV export failed mid-stream");
+            }
+            let failed = chunk.is_err();
+            if tx.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    });
+
+    let body = futures::stream::poll_fn(move |cx| rx.poll_recv(cx));
+
+    (
+        [
+            (header::CONTENT_TYPE, "text/csv; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "attachment; filename=\"contracts.csv\"",
+            ),
+        ],
+        Body::from_stream(body),
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::{MaturityLevel, Network};
+    use uuid::Uuid;
+
+    fn contract(i: usize) -> Contract {
+        Contract {
+            id: Uuid::new_v4(),
+            contract_id: format!("C{i:055}"),
+            wasm_hash: "hash".into(),
+            name: format!("Token, \"v{i}\""),
+            description: Some("line one\nline two".into()),
+            publisher_id: Uuid::new_v4(),
+            network: Network::Testnet,
+            is_verified: i.is_multiple_of(2),
+            category: Some("defi".into()),
+            tags: vec!["a".into(), "b".into()],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            is_maintenance: false,
+            maturity: MaturityLevel::Beta,
+            logical_id: None,
+            network_configs: None,
+            origin_contract_id: None,
+            drift_detected: false,
+            view_count: 0,
+            first_seen_at: None,
+            license: None,
+        }
+    }
+
+    /// Minimal RFC 4180 reader for round-tripping the export.
+    fn parse_csv(input: &str) -> Vec<Vec<String>> {
+        let mut records = Vec::new();
+        let mut record = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = input.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            match (ch, in_quotes) {
+                ('"', true) if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                ('"', _) => in_quotes = !in_quotes,
+                (',', false) => record.push(std::mem::take(&mut field)),
+                ('\n', false) => {
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                }
+                (c, _) => field.push(c),
+            }
+        }
+        records
+    }
+
+    #[test]
+    fn fields_with_delimiters_are_quoted() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[tokio::test]
+    async fn export_larger_than_one_chunk_round_trips() {
+        let contracts: Vec<Contract> = (0..CSV_CHUNK_ROWS * 2 + 17).map(contract).collect();
+        let rows = futures::stream::iter(contracts.clone().into_iter().map(Ok::<_, ()>));
+
+        let chunks: Vec<String> = contracts_csv_stream(rows, CSV_CHUNK_ROWS)
+            .map(|c| c.unwrap())
+            .collect()
+            .await;
+        assert_eq!(chunks.len(), 1 + 3);
+
+        let records = parse_csv(&chunks.concat());
+        assert_eq!(records.len(), contracts.len() + 1);
+        assert_eq!(records[0][0], "id");
+
+        for (record, contract) in records[1..].iter().zip(&contracts) {
+            assert_eq!(record.len(), 12);
+            assert_eq!(record[0], contract.id.to_string());
+            assert_eq!(record[2], contract.name);
+            assert_eq!(record[3], "line one\nline two");
+            assert_eq!(record[7], "a;b");
+            assert_eq!(record[9], "beta");
+        }
+    }
+
+    #[tokio::test]
+    async fn row_error_fails_its_chunk() {
+        let rows = futures::stream::iter(vec![Ok(contract(0)), Err("boom")]);
+        let chunks: Vec<Result<String, &str>> = contracts_csv_stream(rows, 10).collect().await;
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks[1].is_err());
+    }
+}
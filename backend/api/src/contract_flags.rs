@@ -0,0 +1,304 @@
+// api/src/contract_flags.rs
+// Per-contract feature flags.
+//
+//   GET   /api/contracts/:id/flags
+//   PATCH /api/contracts/:id/flags — publisher (or admin) only
+//
+// Flags live in `contracts.feature_flags` as a JSON object; keys that are not
+// stored take their defaults, so new flags need no backfill. A PATCH sets only
+// the flags it names.
+//
+// Where they apply:
+//   public_analytics        — off: GET /api/contracts/:id/analytics is limited
+//                             to the publisher and admins
+//   require_multisig_deploy — switching deployments needs an executed multisig
+//                             proposal for the green WASM, even when forced
+//   auto_rollback           — when the active deployment fails its health
+//                             checks, traffic moves back to the other one
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, State},
+    http::{HeaderMap, StatusCode},
+    Extension, Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::{self, AuthContext},
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, ensure_contract_owner},
+    state::AppState,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContractFlags {
+    pub auto_rollback: bool,
+    pub require_multisig_deploy: bool,
+    pub public_analytics: bool,
+}
+
+impl Default for ContractFlags {
+    fn default() -> Self {
+        Self {
+            auto_rollback: false,
+            require_multisig_deploy: false,
+            public_analytics: true,
+        }
+    }
+}
+
+impl ContractFlags {
+    /// Read the stored column; anything unreadable falls back to the defaults.
+    pub fn from_stored(value: Value) -> Self {
+        serde_json::from_value(value).unwrap_or_default()
+    }
+
+    pub fn apply(mut self, patch: &FlagsPatch) -> Self {
+        if let Some(on) = patch.auto_rollback {
+            self.auto_rollback = on;
+        }
+        if let Some(on) = patch.require_multisig_deploy {
+            self.require_multisig_deploy = on;
+        }
+        if let Some(on) = patch.public_analytics {
+            self.public_analytics = on;
+        }
+        self
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FlagsPatch {
+    pub auto_rollback: Option<bool>,
+    pub require_multisig_deploy: Option<bool>,
+    pub public_analytics: Option<bool>,
+}
+
+/// Flags of contract `id`, or `None` when it does not exist.
+pub async fn load_flags<'e>(db: impl sqlx::PgExecutor<'e>, id: Uuid) -> Result<Option<ContractFlags>, sqlx::Error> {
+    let stored: Option<Value> = sqlx::query_scalar("SELECT feature_flags FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(db)
+        .await?;
+    Ok(stored.map(ContractFlags::from_stored))
+}
+
+fn contract_not_found(id: Uuid) -> ApiError {
+    ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id))
+}
+
+/// Whether `caller` may read analytics of a contract owned by `owner`.
+pub fn check_analytics_access(flags: &ContractFlags, caller: Option<&AuthContext>, owner: &str) -> ApiResult<()> {
+    if flags.public_analytics {
+        return Ok(());
+    }
+    match caller {
+        Some(auth) if auth.publisher_address == owner || auth.is_admin() => Ok(()),
+        _ => Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "AnalyticsPrivate",
+            "Analytics for this contract are only visible to its publisher",
+        )),
+    }
+}
+
+/// Guard for the analytics endpoint; 404 when the contract does not exist.
+pub async fn ensure_analytics_access(state: &AppState, id: Uuid, headers: &HeaderMap) -> ApiResult<()> {
+    let row: Option<(Value, String)> = sqlx::query_as(
+        "SELECT c.feature_flags, p.stellar_address
+         FROM contracts c JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("get contract flags for analytics", err))?;
+    let (stored, owner) = row.ok_or_else(|| contract_not_found(id))?;
+
+    let flags = ContractFlags::from_stored(stored);
+    let caller = if flags.public_analytics {
+        None
+    } else {
+        auth_middleware::auth_from_headers(headers)
+    };
+    check_analytics_access(&flags, caller.as_ref(), &owner)
+}
+
+/// GET /api/contracts/:id/flags
+pub async fn get_flags(State(state): State<AppState>, Path(id): Path<Uuid>) -> ApiResult<Json<ContractFlags>> {
+    load_flags(&state.db, id)
+        .await
+        .map_err(|err| db_internal_error("get contract flags", err))?
+        .map(Json)
+        .ok_or_else(|| contract_not_found(id))
+}
+
+/// PATCH /api/contracts/:id/flags
+pub async fn patch_flags(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    payload: Result<Json<FlagsPatch>, JsonRejection>,
+) -> ApiResult<Json<ContractFlags>> {
+    let Json(patch) = payload
+        .map_err(|err| ApiError::bad_request("InvalidRequest", format!("Invalid flags: {}", err.body_text())))?;
+
+    let contract: shared::Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for flags", err))?
+        .ok_or_else(|| contract_not_found(id))?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin flags update", err))?;
+    let stored: Value = sqlx::query_scalar("SELECT feature_flags FROM contracts WHERE id = $1 FOR UPDATE")
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("lock contract flags", err))?;
+    let flags = ContractFlags::from_stored(stored).apply(&patch);
+
+    sqlx::query("UPDATE contracts SET feature_flags = $2 WHERE id = $1")
+        .bind(id)
+        .bind(serde_json::to_value(flags).unwrap_or_default())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("store contract flags", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit flags update", err))?;
+
+    tracing::info!(contract_id = %id, ?flags, updated_by = %auth.publisher_address, "contract flags updated");
+    Ok(Json(flags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+    use serde_json::json;
+
+    const OWNER: &str = "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+    const STRANGER: &str = "GBRPYHIL2CI3FNQ4BXLFMNDLFJUNPU2HY3ZMFSHONUCEOASW7QC7OX2H";
+
+    fn caller(address: &str) -> AuthContext {
+        AuthContext {
+            publisher_address: address.to_string(),
+        }
+    }
+
+    fn status(result: ApiResult<()>) -> StatusCode {
+        match result {
+            Ok(()) => StatusCode::OK,
+            Err(err) => err.into_response().status(),
+        }
+    }
+
+    #[test]
+    fn stored_flags_fill_in_defaults() {
+        assert_eq!(ContractFlags::from_stored(json!({})), ContractFlags::default());
+        let flags = ContractFlags::from_stored(json!({ "auto_rollback": true, "retired_flag": 1 }));
+        assert!(flags.auto_rollback);
+        assert!(flags.public_analytics);
+        assert_eq!(ContractFlags::from_stored(json!("garbage")), ContractFlags::default());
+    }
+
+    #[test]
+    fn patch_only_touches_named_flags() {
+        let patch: FlagsPatch = serde_json::from_value(json!({ "public_analytics": false })).unwrap();
+        let flags = ContractFlags {
+            require_multisig_deploy: true,
+            ..ContractFlags::default()
+        }
+        .apply(&patch);
+        assert!(!flags.public_analytics);
+        assert!(flags.require_multisig_deploy);
+        assert!(!flags.auto_rollback);
+
+        assert!(serde_json::from_value::<FlagsPatch>(json!({ "dark_mode": true })).is_err());
+    }
+
+    #[test]
+    fn private_analytics_are_forbidden_to_non_owners() {
+        let public = ContractFlags::default();
+        assert_eq!(status(check_analytics_access(&public, None, OWNER)), StatusCode::OK);
+
+        let private = public.apply(&FlagsPatch {
+            public_analytics: Some(false),
+            ..FlagsPatch::default()
+        });
+        assert_eq!(status(check_analytics_access(&private, None, OWNER)), StatusCode::FORBIDDEN);
+        assert_eq!(
+            status(check_analytics_access(&private, Some(&caller(STRANGER)), OWNER)),
+            StatusCode::FORBIDDEN
+        );
+        assert_eq!(
+            status(check_analytics_access(&private, Some(&caller(OWNER)), OWNER)),
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn analytics_flag_gates_the_analytics_endpoint() {
+        use crate::handlers::{get_contract_analytics, AnalyticsQuery};
+        use axum::extract::Query;
+
+        let pool = crate::fixtures::test_pool().await;
+        let state = crate::fixtures::state_for(pool.clone());
+        let id = crate::fixtures::fixtures().contracts[0].id;
+
+        let analytics = |headers: HeaderMap| {
+            let state = state.clone();
+            async move {
+                let query = AnalyticsQuery {
+                    interval: Default::default(),
+                    days: None,
+                    cursor: None,
+                };
+                get_contract_analytics(State(state), Path(id), headers, Query(query))
+                    .await
+                    .map(|_| ())
+            }
+        };
+        let bearer = |address: &str| {
+            let token = crate::auth::AuthManager::from_env().issue_jwt(address).unwrap();
+            let mut headers = HeaderMap::new();
+            headers.insert("authorization", format!("Bearer {}", token).parse().unwrap());
+            headers
+        };
+
+        sqlx::query("UPDATE contracts SET feature_flags = '{\"public_analytics\": false}' WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(status(analytics(HeaderMap::new()).await), StatusCode::FORBIDDEN);
+        assert_eq!(status(analytics(bearer(STRANGER)).await), StatusCode::FORBIDDEN);
+
+        let owner: String = sqlx::query_scalar(
+            "SELECT p.stellar_address FROM contracts c JOIN publishers p ON p.id = c.publisher_id WHERE c.id = $1",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(status(analytics(bearer(&owner)).await), StatusCode::OK);
+
+        sqlx::query("UPDATE contracts SET feature_flags = '{}' WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(status(analytics(HeaderMap::new()).await), StatusCode::OK);
+    }
+}
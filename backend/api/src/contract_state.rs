@@ -0,0 +1,267 @@
+// api/src/contract_state.rs
+// Versioned key/value state attached to a contract.
+//
+//   GET  /api/contracts/:id/state/:key[?at=<timestamp>]
+//   POST /api/contracts/:id/state/:key      — owner JWT or contract token
+//   GET  /api/contracts/:id/state/:key/history
+//
+// `contract_state` holds the latest value per key; every write also appends
+// to `contract_state_history`, so any earlier value can be read back with `?at=`.
+// Writes are authorized by `contract_tokens::require_state_write_access`.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    metrics,
+    state::AppState,
+};
+
+const DEFAULT_HISTORY_LIMIT: i64 = 50;
+const MAX_HISTORY_LIMIT: i64 = 500;
+
+/// One recorded value of a state key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct StateVersion {
+    pub contract_id: Uuid,
+    pub key: String,
+    pub value: Value,
+    pub version: i32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StateQuery {
+    /// Return the value as of this instant instead of the latest
+    pub at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StateHistoryQuery {
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateStateRequest {
+    pub value: Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StateHistoryResponse {
+    pub contract_id: Uuid,
+    pub key: String,
+    /// Newest first
+    pub versions: Vec<StateVersion>,
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(op, err)
+}
+
+fn state_not_found(key: &str, at: Option<DateTime<Utc>>) -> ApiError {
+    let message = match at {
+        Some(at) => format!("State key '{}' had no value at {}", key, at.to_rfc3339()),
+        None => format!("State key '{}' not found", key),
+    };
+    ApiError::not_found("StateNotFound", message)
+}
+
+/// GET /api/contracts/:id/state/:key
+pub async fn get_contract_state(
+    State(state): State<AppState>,
+    Path((contract_id, key)): Path<(Uuid, String)>,
+    Query(query): Query<StateQuery>,
+) -> ApiResult<Json<StateVersion>> {
+    metrics::CONTRACT_STATE_READS.inc();
+
+    let found: Option<StateVersion> = match query.at {
+        None => sqlx::query_as(
+            "SELECT contract_id, key, value, version, updated_at AS recorded_at
+             FROM contract_state WHERE contract_id = $1 AND key = $2",
+        )
+        .bind(contract_id)
+        .bind(&key)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("get contract state", err))?,
+        Some(at) => sqlx::query_as(
+            "SELECT contract_id, key, value, version, recorded_at
+             FROM contract_state_history
+             WHERE contract_id = $1 AND key = $2 AND recorded_at <= $3
+             ORDER BY recorded_at DESC, version DESC
+             LIMIT 1",
+        )
+        .bind(contract_id)
+        .bind(&key)
+        .bind(at)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("get contract state as of", err))?,
+    };
+
+    found.map(Json).ok_or_else(|| state_not_found(&key, query.at))
+}
+
+/// POST /api/contracts/:id/state/:key — set a new value, keeping the old one in history.
+pub async fn update_contract_state(
+    State(state): State<AppState>,
+    Path((contract_id, key)): Path<(Uuid, String)>,
+    Json(req): Json<UpdateStateRequest>,
+) -> ApiResult<Json<StateVersion>> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin state update", err))?;
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)")
+        .bind(contract_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_err("check contract for state update", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            format!("No contract found with ID: {}", contract_id),
+        ));
+    }
+
+    let current: StateVersion = sqlx::query_as(
+        "INSERT INTO contract_state (contract_id, key, value, version, updated_at)
+         VALUES ($1, $2, $3, 1, NOW())
+         ON CONFLICT (contract_id, key) DO UPDATE SET
+             value = EXCLUDED.value,
+             version = contract_state.version + 1,
+             updated_at = NOW()
+         RETURNING contract_id, key, value, version, updated_at AS recorded_at",
+    )
+    .bind(contract_id)
+    .bind(&key)
+    .bind(&req.value)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_err("upsert contract state", err))?;
+
+    sqlx::query(
+        "INSERT INTO contract_state_history (contract_id, key, value, version, recorded_at)
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(current.contract_id)
+    .bind(&current.key)
+    .bind(&current.value)
+    .bind(current.version)
+    .bind(current.recorded_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| db_err("append contract state history", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit state update", err))?;
+    metrics::CONTRACT_STATE_WRITES.inc();
+
+    Ok(Json(current))
+}
+
+/// GET /api/contracts/:id/state/:key/history
+pub async fn get_contract_state_history(
+    State(state): State<AppState>,
+    Path((contract_id, key)): Path<(Uuid, String)>,
+    Query(query): Query<StateHistoryQuery>,
+) -> ApiResult<Json<StateHistoryResponse>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_HISTORY_LIMIT)
+        .clamp(1, MAX_HISTORY_LIMIT);
+
+    let versions: Vec<StateVersion> = sqlx::query_as(
+        "SELECT contract_id, key, value, version, recorded_at
+         FROM contract_state_history
+         WHERE contract_id = $1 AND key = $2
+         ORDER BY recorded_at DESC, version DESC
+         LIMIT $3",
+    )
+    .bind(contract_id)
+    .bind(&key)
+    .bind(limit)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("get contract state history", err))?;
+
+    if versions.is_empty() {
+        return Err(state_not_found(&key, None));
+    }
+
+    Ok(Json(StateHistoryResponse {
+        contract_id,
+        key,
+        versions,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, response::IntoResponse};
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    #[tokio::test]
+    #[ignore]
+    async fn point_in_time_reads_return_the_value_in_effect() {
+        let state = crate::fixtures::test_state().await;
+        let contract = crate::fixtures::fixtures().contracts[0].id;
+        let key = format!("admin-{}", Uuid::new_v4().simple());
+        let t = [
+            Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 2, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 1, 0, 0, 0).unwrap(),
+        ];
+        // Versions 2 and 3 share an instant; the later version wins
+        for (version, at, value) in [(1, t[0], "GA"), (2, t[1], "GB"), (3, t[1], "GB2"), (4, t[2], "GC")] {
+            sqlx::query(
+                "INSERT INTO contract_state_history (contract_id, key, value, version, recorded_at)
+                 VALUES ($1, $2, $3, $4, $5)",
+            )
+            .bind(contract)
+            .bind(&key)
+            .bind(json!(value))
+            .bind(version)
+            .bind(at)
+            .execute(&state.db)
+            .await
+            .unwrap();
+        }
+
+        let read_at = |at: DateTime<Utc>| {
+            let state = state.clone();
+            let key = key.clone();
+            async move {
+                get_contract_state(State(state), Path((contract, key)), Query(StateQuery { at: Some(at) })).await
+            }
+        };
+
+        assert_eq!(read_at(t[0]).await.unwrap().0.value, json!("GA"));
+        let feb = read_at(t[1] + chrono::Duration::days(3)).await.unwrap().0;
+        assert_eq!((feb.version, feb.value), (3, json!("GB2")));
+        assert_eq!(read_at(t[2] + chrono::Duration::days(365)).await.unwrap().0.version, 4);
+
+        let before_first = read_at(t[0] - chrono::Duration::seconds(1)).await.unwrap_err();
+        assert_eq!(before_first.into_response().status(), StatusCode::NOT_FOUND);
+
+        sqlx::query("DELETE FROM contract_state_history WHERE contract_id = $1 AND key = $2")
+            .bind(contract)
+            .bind(&key)
+            .execute(&state.db)
+            .await
+            .unwrap();
+    }
+}
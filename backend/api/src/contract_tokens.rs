@@ -0,0 +1,366 @@
+// api/src/contract_tokens.rs
+// Contract-scoped API tokens for pushing contract state.
+//
+//   POST   /api/contracts/:id/tokens             — mint a token (owner)
+//   GET    /api/contracts/:id/tokens             — list tokens, without secrets (owner)
+//   DELETE /api/contracts/:id/tokens/:token_id   — revoke a token (owner)
+//
+// A contract token is a bearer secret (`srt_...`) bound to one contract. It is
+// accepted by exactly one route, POST /api/contracts/:id/state/:key, and only
+// for the contract it was minted for; every other route still requires a
+// publisher JWT. The plaintext is returned once at mint time and only its
+// SHA-256 is stored. Tokens expire and can be revoked at any time.
+
+use axum::{
+    extract::{Path, Request, State},
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::Contract;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthManager,
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, ensure_contract_owner},
+    state::AppState,
+};
+
+/// Prefix that tells contract tokens apart from publisher JWTs
+pub const TOKEN_PREFIX: &str = "srt_";
+const TOKEN_RANDOM_LEN: usize = 40;
+
+const DEFAULT_TTL_DAYS: i64 = 90;
+const MAX_TTL_DAYS: i64 = 365;
+
+/// A token's metadata; the secret itself is never read back
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ContractToken {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub label: Option<String>,
+    pub created_by: String,
+    pub created_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+/// Why a contract token was refused
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenRejection {
+    Revoked,
+    Expired,
+    /// The token belongs to a different contract
+    WrongContract,
+}
+
+impl ContractToken {
+    /// Whether this token may write state for `contract_id` at `now`.
+    pub fn authorize(&self, contract_id: Uuid, now: DateTime<Utc>) -> Result<(), TokenRejection> {
+        if self.revoked_at.is_some() {
+            return Err(TokenRejection::Revoked);
+        }
+        if self.expires_at <= now {
+            return Err(TokenRejection::Expired);
+        }
+        if self.contract_id != contract_id {
+            return Err(TokenRejection::WrongContract);
+        }
+        Ok(())
+    }
+}
+
+impl From<TokenRejection> for ApiError {
+    fn from(rejection: TokenRejection) -> Self {
+        match rejection {
+            TokenRejection::Revoked => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "TokenRevoked", "Contract token has been revoked")
+            }
+            TokenRejection::Expired => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "TokenExpired", "Contract token has expired")
+            }
+            TokenRejection::WrongContract => ApiError::new(
+                StatusCode::FORBIDDEN,
+                "TokenScopeMismatch",
+                "Contract token is not valid for this contract",
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MintTokenRequest {
+    pub label: Option<String>,
+    /// Lifetime in days, 1..=365 (default 90)
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MintedToken {
+    /// Shown once; store it now
+    pub token: String,
+    #[serde(flatten)]
+    pub info: ContractToken,
+}
+
+pub fn generate_token() -> String {
+    let random: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(TOKEN_RANDOM_LEN)
+        .map(char::from)
+        .collect();
+    format!("{}{}", TOKEN_PREFIX, random)
+}
+
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+fn token_ttl(requested: Option<i64>) -> ApiResult<Duration> {
+    let days = requested.unwrap_or(DEFAULT_TTL_DAYS);
+    if !(1..=MAX_TTL_DAYS).contains(&days) {
+        return Err(ApiError::bad_request(
+            "InvalidExpiry",
+            format!("expires_in_days must be between 1 and {}", MAX_TTL_DAYS),
+        ));
+    }
+    Ok(Duration::days(days))
+}
+
+async fn load_contract(state: &AppState, id: Uuid) -> ApiResult<Contract> {
+    sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for tokens", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))
+}
+
+/// POST /api/contracts/:id/tokens
+pub async fn mint_token(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<MintTokenRequest>,
+) -> ApiResult<(StatusCode, Json<MintedToken>)> {
+    let contract = load_contract(&state, id).await?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+    let expires_at = Utc::now() + token_ttl(req.expires_in_days)?;
+    let label = req.label.map(|l| l.trim().to_string()).filter(|l| !l.is_empty());
+
+    let token = generate_token();
+    let info: ContractToken = sqlx::query_as(
+        "INSERT INTO contract_api_tokens (contract_id, token_hash, label, created_by, expires_at)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING id, contract_id, label, created_by, created_at, expires_at, revoked_at, last_used_at",
+    )
+    .bind(contract.id)
+    .bind(hash_token(&token))
+    .bind(&label)
+    .bind(&auth.publisher_address)
+    .bind(expires_at)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("mint contract token", err))?;
+
+    tracing::info!(contract_id = %contract.id, token_id = %info.id, "contract token minted");
+    Ok((StatusCode::CREATED, Json(MintedToken { token, info })))
+}
+
+/// GET /api/contracts/:id/tokens
+pub async fn list_tokens(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<ContractToken>>> {
+    let contract = load_contract(&state, id).await?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let tokens: Vec<ContractToken> = sqlx::query_as(
+        "SELECT id, contract_id, label, created_by, created_at, expires_at, revoked_at, last_used_at
+         FROM contract_api_tokens WHERE contract_id = $1
+         ORDER BY created_at DESC",
+    )
+    .bind(contract.id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list contract tokens", err))?;
+
+    Ok(Json(tokens))
+}
+
+/// DELETE /api/contracts/:id/tokens/:token_id
+pub async fn revoke_token(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path((id, token_id)): Path<(Uuid, Uuid)>,
+) -> ApiResult<Json<ContractToken>> {
+    let contract = load_contract(&state, id).await?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let token: ContractToken = sqlx::query_as(
+        "UPDATE contract_api_tokens SET revoked_at = COALESCE(revoked_at, NOW())
+         WHERE id = $1 AND contract_id = $2
+         RETURNING id, contract_id, label, created_by, created_at, expires_at, revoked_at, last_used_at",
+    )
+    .bind(token_id)
+    .bind(contract.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("revoke contract token", err))?
+    .ok_or_else(|| ApiError::not_found("TokenNotFound", format!("No token {} for this contract", token_id)))?;
+
+    tracing::info!(contract_id = %contract.id, token_id = %token.id, "contract token revoked");
+    Ok(Json(token))
+}
+
+fn bearer_token(request: &Request) -> Option<&str> {
+    request
+        .headers()
+        .get("authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))
+        .map(str::trim)
+}
+
+/// Check a contract token against the contract in the path, recording its use.
+async fn authorize_contract_token(state: &AppState, token: &str, contract_id: Uuid) -> ApiResult<()> {
+    let found: Option<ContractToken> = sqlx::query_as(
+        "SELECT id, contract_id, label, created_by, created_at, expires_at, revoked_at, last_used_at
+         FROM contract_api_tokens WHERE token_hash = $1",
+    )
+    .bind(hash_token(token))
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("look up contract token", err))?;
+    let Some(found) = found else {
+        return Err(ApiError::new(StatusCode::UNAUTHORIZED, "Unauthorized", "invalid_token"));
+    };
+    found.authorize(contract_id, Utc::now())?;
+
+    sqlx::query("UPDATE contract_api_tokens SET last_used_at = NOW() WHERE id = $1")
+        .bind(found.id)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("touch contract token", err))?;
+    Ok(())
+}
+
+/// Check a publisher JWT belongs to the contract's owner (or an admin).
+async fn authorize_owner_jwt(state: &AppState, token: &str, contract_id: Uuid) -> ApiResult<()> {
+    let claims = AuthManager::from_env()
+        .validate_jwt(token)
+        .map_err(|_| ApiError::new(StatusCode::UNAUTHORIZED, "Unauthorized", "invalid_token"))?;
+    let auth = AuthContext {
+        publisher_address: claims.sub,
+    };
+    let contract = load_contract(state, contract_id).await?;
+    ensure_contract_owner(state, &contract, &auth).await
+}
+
+/// Guards state writes: accepts a contract token scoped to the contract in
+/// the path, or the owner's publisher JWT.
+pub async fn require_state_write_access(
+    State(state): State<AppState>,
+    Path((contract_id, _key)): Path<(Uuid, String)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(token) = bearer_token(&request) else {
+        return ApiError::new(StatusCode::UNAUTHORIZED, "Unauthorized", "missing_bearer_token").into_response();
+    };
+
+    let authorized = if token.starts_with(TOKEN_PREFIX) {
+        authorize_contract_token(&state, token, contract_id).await
+    } else {
+        authorize_owner_jwt(&state, token, contract_id).await
+    };
+
+    match authorized {
+        Ok(()) => next.run(request).await,
+        Err(err) => err.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token_for(contract_id: Uuid) -> ContractToken {
+        ContractToken {
+            id: Uuid::from_u128(10),
+            contract_id,
+            label: Some("oracle pusher".into()),
+            created_by: "GOWNER".into(),
+            created_at: Utc::now(),
+            expires_at: Utc::now() + Duration::days(30),
+            revoked_at: None,
+            last_used_at: None,
+        }
+    }
+
+    #[test]
+    fn token_works_for_its_contract_and_not_another() {
+        let mine = Uuid::from_u128(1);
+        let other = Uuid::from_u128(2);
+        let token = token_for(mine);
+
+        assert_eq!(token.authorize(mine, Utc::now()), Ok(()));
+        assert_eq!(token.authorize(other, Utc::now()), Err(TokenRejection::WrongContract));
+
+        let status = ApiError::from(TokenRejection::WrongContract).into_response().status();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn revoked_and_expired_tokens_are_rejected() {
+        let id = Uuid::from_u128(1);
+        let mut token = token_for(id);
+        token.revoked_at = Some(Utc::now());
+        assert_eq!(token.authorize(id, Utc::now()), Err(TokenRejection::Revoked));
+
+        let token = token_for(id);
+        let later = token.expires_at + Duration::seconds(1);
+        assert_eq!(token.authorize(id, later), Err(TokenRejection::Expired));
+        assert_eq!(
+            ApiError::from(TokenRejection::Expired).into_response().status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn generated_tokens_are_prefixed_unique_and_hashed() {
+        let a = generate_token();
+        let b = generate_token();
+        assert!(a.starts_with(TOKEN_PREFIX));
+        assert_eq!(a.len(), TOKEN_PREFIX.len() + TOKEN_RANDOM_LEN);
+        assert_ne!(a, b);
+
+        assert_eq!(hash_token(&a), hash_token(&a));
+        assert_ne!(hash_token(&a), hash_token(&b));
+        assert_eq!(hash_token(&a).len(), 64);
+    }
+
+    #[test]
+    fn ttl_is_bounded() {
+        assert_eq!(token_ttl(None).unwrap(), Duration::days(DEFAULT_TTL_DAYS));
+        assert_eq!(token_ttl(Some(7)).unwrap(), Duration::days(7));
+        assert!(token_ttl(Some(0)).is_err());
+        assert!(token_ttl(Some(MAX_TTL_DAYS + 1)).is_err());
+    }
+
+    #[test]
+    fn contract_tokens_are_not_jwts() {
+        // The prefix keeps the two kinds apart in the middleware
+        assert!(AuthManager::new("secret".into()).validate_jwt(&generate_token()).is_err());
+    }
+}
@@ -0,0 +1,184 @@
+// api/src/cors.rs
+// CORS policies per route group.
+//
+// Public routes and admin routes get separate layers:
+//   CORS_PUBLIC_ORIGINS — comma-separated origins allowed on public routes;
+//                         `*` allows any origin. Defaults to the local dev
+//                         frontend and the hosted registry UI.
+//   CORS_ADMIN_ORIGINS  — origins allowed on `/api/admin/*`. Defaults to none,
+//                         so browsers can only call admin routes same-origin
+//                         (e.g. from an internal dashboard behind the API host).
+// Origins that do not parse as header values are skipped with a warning.
+
+use axum::http::{header, HeaderValue, Method};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+const DEFAULT_PUBLIC_ORIGINS: &[&str] = &["http://localhost:3000", "https://soroban-registry.vercel.app"];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OriginPolicy {
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+impl OriginPolicy {
+    /// Parse a comma-separated origin list; `*` anywhere in it means any origin.
+    pub fn parse(raw: &str) -> Self {
+        let entries: Vec<&str> = raw.split(',').map(str::trim).filter(|o| !o.is_empty()).collect();
+        if entries.contains(&"*") {
+            return OriginPolicy::Any;
+        }
+        OriginPolicy::List(
+            entries
+                .into_iter()
+                .filter_map(|origin| match HeaderValue::from_str(origin.trim_end_matches('/')) {
+                    Ok(value) => Some(value),
+                    Err(_) => {
+                        tracing::warn!(origin, "ignoring invalid CORS origin");
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    fn from_env(name: &str, default: &[&str]) -> Self {
+        match std::env::var(name) {
+            Ok(raw) => Self::parse(&raw),
+            Err(_) => Self::parse(&default.join(",")),
+        }
+    }
+
+    fn allow_origin(&self) -> AllowOrigin {
+        match self {
+            OriginPolicy::Any => AllowOrigin::any(),
+            OriginPolicy::List(origins) => AllowOrigin::list(origins.clone()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorsConfig {
+    pub public: OriginPolicy,
+    pub admin: OriginPolicy,
+}
+
+impl CorsConfig {
+    pub fn from_env() -> Self {
+        Self {
+            public: OriginPolicy::from_env("CORS_PUBLIC_ORIGINS", DEFAULT_PUBLIC_ORIGINS),
+            admin: OriginPolicy::from_env("CORS_ADMIN_ORIGINS", &[]),
+        }
+    }
+
+    pub fn public_layer(&self) -> CorsLayer {
+        CorsLayer::new()
+            .allow_origin(self.public.allow_origin())
+            .allow_methods([
+                Method::GET,
+                Method::POST,
+                Method::PUT,
+                Method::PATCH,
+                Method::DELETE,
+                Method::OPTIONS,
+            ])
+            .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
+    }
+
+    pub fn admin_layer(&self) -> CorsLayer {
+        CorsLayer::new()
+            .allow_origin(self.admin.allow_origin())
+            .allow_methods([Method::GET, Method::POST, Method::PUT, Method::OPTIONS])
+            .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{HeaderMap, Request},
+        routing::get,
+        Router,
+    };
+    use tower::ServiceExt;
+
+    const PUBLIC_ORIGIN: &str = "https://soroban-registry.vercel.app";
+    const INTERNAL_ORIGIN: &str = "https://ops.internal.example";
+
+    fn app(config: &CorsConfig) -> Router {
+        let public = Router::new()
+            .route("/api/contracts", get(|| async { "contracts" }))
+            .layer(config.public_layer());
+        let admin = Router::new()
+            .route("/api/admin/jobs", get(|| async { "jobs" }))
+            .layer(config.admin_layer());
+        Router::new().merge(public).merge(admin)
+    }
+
+    async fn preflight(app: Router, path: &str, origin: &str) -> HeaderMap {
+        let request = Request::builder()
+            .method(Method::OPTIONS)
+            .uri(path)
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "GET")
+            .body(Body::empty())
+            .unwrap();
+        app.oneshot(request).await.unwrap().headers().clone()
+    }
+
+    fn allowed_origin(headers: &HeaderMap) -> Option<&str> {
+        headers
+            .get(header::ACCESS_CONTROL_ALLOW_ORIGIN)
+            .and_then(|v| v.to_str().ok())
+    }
+
+    fn config() -> CorsConfig {
+        CorsConfig {
+            public: OriginPolicy::parse(&DEFAULT_PUBLIC_ORIGINS.join(",")),
+            admin: OriginPolicy::parse(INTERNAL_ORIGIN),
+        }
+    }
+
+    #[tokio::test]
+    async fn admin_routes_reject_public_origins_that_public_routes_allow() {
+        let public = preflight(app(&config()), "/api/contracts", PUBLIC_ORIGIN).await;
+        assert_eq!(allowed_origin(&public), Some(PUBLIC_ORIGIN));
+
+        let admin = preflight(app(&config()), "/api/admin/jobs", PUBLIC_ORIGIN).await;
+        assert_eq!(allowed_origin(&admin), None);
+    }
+
+    #[tokio::test]
+    async fn admin_routes_allow_the_internal_origin() {
+        let admin = preflight(app(&config()), "/api/admin/jobs", INTERNAL_ORIGIN).await;
+        assert_eq!(allowed_origin(&admin), Some(INTERNAL_ORIGIN));
+
+        let public = preflight(app(&config()), "/api/contracts", INTERNAL_ORIGIN).await;
+        assert_eq!(allowed_origin(&public), None);
+    }
+
+    #[tokio::test]
+    async fn admin_routes_have_no_cross_origin_access_by_default() {
+        let config = CorsConfig {
+            admin: OriginPolicy::parse(""),
+            ..config()
+        };
+        let admin = preflight(app(&config), "/api/admin/jobs", PUBLIC_ORIGIN).await;
+        assert_eq!(allowed_origin(&admin), None);
+    }
+
+    #[test]
+    fn origin_lists_are_parsed() {
+        assert_eq!(OriginPolicy::parse("https://a.example, *"), OriginPolicy::Any);
+        assert_eq!(
+            OriginPolicy::parse(" https://a.example/ ,,https://b.example"),
+            OriginPolicy::List(vec![
+                HeaderValue::from_static("https://a.example"),
+                HeaderValue::from_static("https://b.example"),
+            ])
+        );
+        assert_eq!(OriginPolicy::parse("bad\norigin"), OriginPolicy::List(vec![]));
+    }
+}
@@ -1,57 +1,210 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     Json,
 };
+use serde::Deserialize;
 use shared::models::{
     BatchCostEstimate, CostEstimate, CostEstimateRequest, CostForecast, CostOptimization,
+    FeeSchedule, Network,
 };
+use std::time::Duration;
 use uuid::Uuid;
 
 use crate::{
+    cache::CacheLayer,
     error::{ApiError, ApiResult},
     state::AppState,
 };
 
-// Stellar network constants (approximate)
 const STROOPS_PER_XLM: i64 = 10_000_000;
-const BASE_GAS_COST: i64 = 100_000; // stroops
-const STORAGE_COST_PER_KB: i64 = 50_000; // stroops
-const BANDWIDTH_COST_PER_KB: i64 = 10_000; // stroops
 
-pub async fn estimate_cost(
-    State(state): State<AppState>,
-    Path(contract_id): Path<Uuid>,
-    Json(req): Json<CostEstimateRequest>,
-) -> ApiResult<Json<CostEstimate>> {
-    let invocations = req.invocations.unwrap_or(1);
-    let storage_kb = req.storage_growth_kb.unwrap_or(0);
+/// Bounds staleness from fee schedule or gas history changes; new versions
+/// invalidate explicitly.
+const ESTIMATE_CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+const ESTIMATE_CACHE_NAMESPACE: &str = "cost_estimate";
+const ESTIMATE_GENERATION_KEY: &str = "generation";
 
-    // Try to get historical data
-    let historical_gas = sqlx::query_scalar::<_, i64>(
+fn estimate_cache_namespace(contract_id: Uuid) -> String {
+    format!("{}:{}", ESTIMATE_CACHE_NAMESPACE, contract_id)
+}
+
+/// Current cache generation of a contract's estimates; every cached estimate
+/// is keyed under it, so replacing it drops them all at once.
+async fn estimate_generation(cache: &CacheLayer, contract_id: Uuid) -> String {
+    let namespace = estimate_cache_namespace(contract_id);
+    if let (Some(generation), true) = cache.get(&namespace, ESTIMATE_GENERATION_KEY).await {
+        return generation;
+    }
+    let generation = Uuid::new_v4().simple().to_string();
+    cache
+        .put(&namespace, ESTIMATE_GENERATION_KEY, generation.clone(), Some(ESTIMATE_CACHE_TTL))
+        .await;
+    generation
+}
+
+/// Forget every cached estimate of a contract; called when a version is added.
+pub async fn invalidate_cost_estimates(cache: &CacheLayer, contract_id: Uuid) {
+    cache
+        .invalidate(&estimate_cache_namespace(contract_id), ESTIMATE_GENERATION_KEY)
+        .await;
+}
+
+/// Where the estimate for one contract + method + parameters is cached
+struct EstimateCacheKey {
+    namespace: String,
+    key: String,
+}
+
+impl EstimateCacheKey {
+    fn new(contract_id: Uuid, generation: &str, req: &CostEstimateRequest) -> Self {
+        Self {
+            namespace: estimate_cache_namespace(contract_id),
+            key: format!(
+                "{}:{:?}:{:?}:{}",
+                generation, req.invocations, req.storage_growth_kb, req.method_name
+            ),
+        }
+    }
+
+    async fn lookup(&self, cache: &CacheLayer) -> Option<CostEstimate> {
+        match cache.get(&self.namespace, &self.key).await {
+            (Some(cached), true) => serde_json::from_str(&cached).ok(),
+            _ => None,
+        }
+    }
+
+    async fn store(&self, cache: &CacheLayer, estimate: &CostEstimate) {
+        if let Ok(serialized) = serde_json::to_string(estimate) {
+            cache
+                .put(&self.namespace, &self.key, serialized, Some(ESTIMATE_CACHE_TTL))
+                .await;
+        }
+    }
+}
+
+/// Built-in fee constants (approximate), used when `network_fee_schedules`
+/// has no row for the network.
+pub fn default_fee_schedule(network: &Network) -> FeeSchedule {
+    let (base_gas_cost, storage_cost_per_kb, bandwidth_cost_per_kb) = match network {
+        Network::Mainnet => (100_000, 50_000, 10_000),
+        Network::Testnet => (50_000, 25_000, 5_000),
+        Network::Futurenet => (20_000, 10_000, 2_000),
+    };
+    FeeSchedule {
+        network: network.clone(),
+        base_gas_cost,
+        storage_cost_per_kb,
+        bandwidth_cost_per_kb,
+    }
+}
+
+/// Effective fee schedule for `network`: the configured row, else the default.
+pub async fn load_fee_schedule(state: &AppState, network: &Network) -> ApiResult<FeeSchedule> {
+    let configured: Option<FeeSchedule> = sqlx::query_as(
+        "SELECT network, base_gas_cost, storage_cost_per_kb, bandwidth_cost_per_kb
+         FROM network_fee_schedules WHERE network = $1",
+    )
+    .bind(network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiError::db_error(format!("Failed to load fee schedule: {}", e)))?;
+
+    Ok(configured.unwrap_or_else(|| default_fee_schedule(network)))
+}
+
+/// Fee schedule for the network the contract is deployed on.
+async fn contract_fee_schedule(state: &AppState, contract_id: Uuid) -> ApiResult<FeeSchedule> {
+    let network: Network = sqlx::query_scalar("SELECT network FROM contracts WHERE id = $1")
+        .bind(contract_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| ApiError::db_error(format!("Failed to load contract: {}", e)))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found with ID: {}", contract_id),
+            )
+        })?;
+
+    load_fee_schedule(state, &network).await
+}
+
+async fn historical_gas(state: &AppState, contract_id: Uuid, method_name: &str) -> Option<i64> {
+    sqlx::query_scalar::<_, i64>(
         "SELECT avg_gas_cost FROM cost_estimates WHERE contract_id = $1 AND method_name = $2",
     )
     .bind(contract_id)
-    .bind(&req.method_name)
+    .bind(method_name)
     .fetch_optional(&state.db)
     .await
-    .unwrap_or(None);
+    .unwrap_or(None)
+}
+
+/// Price `req` under `fees`, preferring observed gas over the base cost.
+pub fn compute_estimate(
+    req: &CostEstimateRequest,
+    historical_gas: Option<i64>,
+    fees: &FeeSchedule,
+) -> CostEstimate {
+    let invocations = req.invocations.unwrap_or(1);
+    let storage_kb = req.storage_growth_kb.unwrap_or(0);
 
-    let gas_cost = historical_gas.unwrap_or(BASE_GAS_COST) * invocations;
-    let storage_cost = storage_kb * STORAGE_COST_PER_KB;
-    let bandwidth_cost = (storage_kb / 4) * BANDWIDTH_COST_PER_KB; // Estimate 4:1 ratio
+    let gas_cost = historical_gas.unwrap_or(fees.base_gas_cost) * invocations;
+    let storage_cost = storage_kb * fees.storage_cost_per_kb;
+    let bandwidth_cost = (storage_kb / 4) * fees.bandwidth_cost_per_kb; // Estimate 4:1 ratio
 
     let total_stroops = gas_cost + storage_cost + bandwidth_cost;
-    let total_xlm = total_stroops as f64 / STROOPS_PER_XLM as f64;
 
-    Ok(Json(CostEstimate {
-        method_name: req.method_name,
+    CostEstimate {
+        method_name: req.method_name.clone(),
         gas_cost,
         storage_cost,
         bandwidth_cost,
         total_stroops,
-        total_xlm,
+        total_xlm: total_stroops as f64 / STROOPS_PER_XLM as f64,
         invocations,
-    }))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FeesQuery {
+    pub network: Option<Network>,
+}
+
+/// GET /api/fees?network= — effective fee schedule(s) used for estimates.
+pub async fn get_fee_schedules(
+    State(state): State<AppState>,
+    Query(query): Query<FeesQuery>,
+) -> ApiResult<Json<Vec<FeeSchedule>>> {
+    let networks = match query.network {
+        Some(network) => vec![network],
+        None => vec![Network::Mainnet, Network::Testnet, Network::Futurenet],
+    };
+
+    let mut schedules = Vec::with_capacity(networks.len());
+    for network in &networks {
+        schedules.push(load_fee_schedule(&state, network).await?);
+    }
+    Ok(Json(schedules))
+}
+
+pub async fn estimate_cost(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+    Json(req): Json<CostEstimateRequest>,
+) -> ApiResult<Json<CostEstimate>> {
+    let generation = estimate_generation(&state.cache, contract_id).await;
+    let cache_key = EstimateCacheKey::new(contract_id, &generation, &req);
+    if let Some(estimate) = cache_key.lookup(&state.cache).await {
+        return Ok(Json(estimate));
+    }
+
+    let fees = contract_fee_schedule(&state, contract_id).await?;
+    let historical = historical_gas(&state, contract_id, &req.method_name).await;
+    let estimate = compute_estimate(&req, historical, &fees);
+    cache_key.store(&state.cache, &estimate).await;
+
+    Ok(Json(estimate))
 }
 
 pub async fn batch_estimate(
@@ -59,38 +212,29 @@ pub async fn batch_estimate(
     Path(contract_id): Path<Uuid>,
     Json(requests): Json<Vec<CostEstimateRequest>>,
 ) -> ApiResult<Json<BatchCostEstimate>> {
+    let generation = estimate_generation(&state.cache, contract_id).await;
+    // Loaded on the first cache miss
+    let mut fees: Option<FeeSchedule> = None;
     let mut estimates = Vec::new();
     let mut total_stroops = 0i64;
 
     for req in requests {
-        let invocations = req.invocations.unwrap_or(1);
-        let storage_kb = req.storage_growth_kb.unwrap_or(0);
-
-        let historical_gas = sqlx::query_scalar::<_, i64>(
-            "SELECT avg_gas_cost FROM cost_estimates WHERE contract_id = $1 AND method_name = $2",
-        )
-        .bind(contract_id)
-        .bind(&req.method_name)
-        .fetch_optional(&state.db)
-        .await
-        .unwrap_or(None);
-
-        let gas_cost = historical_gas.unwrap_or(BASE_GAS_COST) * invocations;
-        let storage_cost = storage_kb * STORAGE_COST_PER_KB;
-        let bandwidth_cost = (storage_kb / 4) * BANDWIDTH_COST_PER_KB;
-
-        let estimate_total = gas_cost + storage_cost + bandwidth_cost;
-        total_stroops += estimate_total;
-
-        estimates.push(CostEstimate {
-            method_name: req.method_name,
-            gas_cost,
-            storage_cost,
-            bandwidth_cost,
-            total_stroops: estimate_total,
-            total_xlm: estimate_total as f64 / STROOPS_PER_XLM as f64,
-            invocations,
-        });
+        let cache_key = EstimateCacheKey::new(contract_id, &generation, &req);
+        let estimate = match cache_key.lookup(&state.cache).await {
+            Some(estimate) => estimate,
+            None => {
+                if fees.is_none() {
+                    fees = Some(contract_fee_schedule(&state, contract_id).await?);
+                }
+                let fees = fees.as_ref().expect("loaded above");
+                let historical = historical_gas(&state, contract_id, &req.method_name).await;
+                let estimate = compute_estimate(&req, historical, fees);
+                cache_key.store(&state.cache, &estimate).await;
+                estimate
+            }
+        };
+        total_stroops += estimate.total_stroops;
+        estimates.push(estimate);
     }
 
     Ok(Json(BatchCostEstimate {
@@ -145,18 +289,12 @@ pub async fn forecast_costs(
     let daily_invocations = req.invocations.unwrap_or(100);
     let storage_kb = req.storage_growth_kb.unwrap_or(1);
 
-    let historical_gas = sqlx::query_scalar::<_, i64>(
-        "SELECT avg_gas_cost FROM cost_estimates WHERE contract_id = $1 AND method_name = $2",
-    )
-    .bind(contract_id)
-    .bind(&req.method_name)
-    .fetch_optional(&state.db)
-    .await
-    .unwrap_or(None);
+    let fees = contract_fee_schedule(&state, contract_id).await?;
+    let historical = historical_gas(&state, contract_id, &req.method_name).await;
 
-    let gas_per_call = historical_gas.unwrap_or(BASE_GAS_COST);
-    let storage_cost = storage_kb * STORAGE_COST_PER_KB;
-    let bandwidth_cost = (storage_kb / 4) * BANDWIDTH_COST_PER_KB;
+    let gas_per_call = historical.unwrap_or(fees.base_gas_cost);
+    let storage_cost = storage_kb * fees.storage_cost_per_kb;
+    let bandwidth_cost = (storage_kb / 4) * fees.bandwidth_cost_per_kb;
 
     let daily_cost_stroops = (gas_per_call * daily_invocations) + storage_cost + bandwidth_cost;
     let daily_cost_xlm = daily_cost_stroops as f64 / STROOPS_PER_XLM as f64;
@@ -168,3 +306,94 @@ pub async fn forecast_costs(
         usage_pattern: format!("{} invocations/day, {} KB storage/day", daily_invocations, storage_kb),
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::CacheConfig;
+    use std::sync::atomic::Ordering;
+
+    fn request() -> CostEstimateRequest {
+        CostEstimateRequest {
+            method_name: "transfer".into(),
+            invocations: Some(10),
+            storage_growth_kb: Some(8),
+        }
+    }
+
+    #[test]
+    fn same_method_costs_differ_by_network() {
+        let req = request();
+        let mainnet = compute_estimate(&req, None, &default_fee_schedule(&Network::Mainnet));
+        let testnet = compute_estimate(&req, None, &default_fee_schedule(&Network::Testnet));
+        let futurenet = compute_estimate(&req, None, &default_fee_schedule(&Network::Futurenet));
+
+        assert!(mainnet.total_xlm > testnet.total_xlm);
+        assert!(testnet.total_xlm > futurenet.total_xlm);
+    }
+
+    #[test]
+    fn estimate_applies_schedule_constants() {
+        let fees = FeeSchedule {
+            network: Network::Testnet,
+            base_gas_cost: 1_000,
+            storage_cost_per_kb: 100,
+            bandwidth_cost_per_kb: 10,
+        };
+        let estimate = compute_estimate(&request(), None, &fees);
+
+        assert_eq!(estimate.gas_cost, 10_000);
+        assert_eq!(estimate.storage_cost, 800);
+        assert_eq!(estimate.bandwidth_cost, 20);
+        assert_eq!(estimate.total_stroops, 10_820);
+    }
+
+    #[test]
+    fn historical_gas_overrides_base_cost() {
+        let fees = default_fee_schedule(&Network::Mainnet);
+        let estimate = compute_estimate(&request(), Some(7), &fees);
+        assert_eq!(estimate.gas_cost, 70);
+    }
+
+    async fn cache_key(cache: &CacheLayer, contract_id: Uuid, req: &CostEstimateRequest) -> EstimateCacheKey {
+        let generation = estimate_generation(cache, contract_id).await;
+        EstimateCacheKey::new(contract_id, &generation, req)
+    }
+
+    #[tokio::test]
+    async fn identical_estimate_request_is_a_cache_hit() {
+        let cache = CacheLayer::new(CacheConfig::default());
+        let contract = Uuid::new_v4();
+        let req = request();
+        let estimate = compute_estimate(&req, None, &default_fee_schedule(&Network::Testnet));
+
+        let first = cache_key(&cache, contract, &req).await;
+        assert!(first.lookup(&cache).await.is_none());
+        first.store(&cache, &estimate).await;
+
+        let hits_before = cache.metrics().hits.load(Ordering::Relaxed);
+        let cached = cache_key(&cache, contract, &req).await.lookup(&cache).await;
+        assert_eq!(cached.map(|e| e.total_stroops), Some(estimate.total_stroops));
+        // generation + estimate
+        assert_eq!(cache.metrics().hits.load(Ordering::Relaxed), hits_before + 2);
+
+        let other_params = CostEstimateRequest { invocations: Some(11), ..request() };
+        assert!(cache_key(&cache, contract, &other_params).await.lookup(&cache).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn new_version_busts_cached_estimates() {
+        let cache = CacheLayer::new(CacheConfig::default());
+        let contract = Uuid::new_v4();
+        let other_contract = Uuid::new_v4();
+        let req = request();
+        let estimate = compute_estimate(&req, None, &default_fee_schedule(&Network::Testnet));
+        cache_key(&cache, contract, &req).await.store(&cache, &estimate).await;
+        cache_key(&cache, other_contract, &req).await.store(&cache, &estimate).await;
+
+        invalidate_cost_estimates(&cache, contract).await;
+
+        assert!(cache_key(&cache, contract, &req).await.lookup(&cache).await.is_none());
+        assert!(cache_key(&cache, other_contract, &req).await.lookup(&cache).await.is_some());
+    }
+}
@@ -1,9 +1,13 @@
-use axum::{routing::post, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 
 use crate::{cost_handlers, state::AppState};
 
 pub fn cost_routes() -> Router<AppState> {
     Router::new()
+        .route("/api/fees", get(cost_handlers::get_fee_schedules))
         .route(
             "/api/contracts/:id/cost-estimate",
             post(cost_handlers::estimate_cost),
@@ -0,0 +1,121 @@
+// api/src/db_timeout.rs
+// Upper bound on how long any database call may hold up a request.
+//
+// Two layers enforce the same limit (`DB_STATEMENT_TIMEOUT_MS`, default 5s):
+// every pooled connection runs with `statement_timeout`, so Postgres cancels
+// runaway statements itself, and `timed` wraps a query future in
+// `tokio::time::timeout` to catch calls stuck outside the server (pool
+// exhaustion, a dead connection). Either way the client gets a 504 in the
+// usual error envelope.
+
+use axum::http::StatusCode;
+use sqlx::postgres::PgConnectOptions;
+use std::future::Future;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::error::{ApiError, ApiResult};
+
+const DEFAULT_STATEMENT_TIMEOUT_MS: u64 = 5_000;
+
+/// SQLSTATE for a statement cancelled by `statement_timeout`
+const QUERY_CANCELED: &str = "57014";
+
+/// Configured per-statement timeout
+pub fn statement_timeout() -> Duration {
+    static TIMEOUT: OnceLock<Duration> = OnceLock::new();
+    *TIMEOUT.get_or_init(|| {
+        let ms = std::env::var("DB_STATEMENT_TIMEOUT_MS")
+            .ok()
+            .and_then(|v| v.parse::<u64>().ok())
+            .filter(|ms| *ms > 0)
+            .unwrap_or(DEFAULT_STATEMENT_TIMEOUT_MS);
+        Duration::from_millis(ms)
+    })
+}
+
+/// Connection options that make Postgres enforce the timeout on every statement.
+pub fn with_statement_timeout(options: PgConnectOptions, timeout: Duration) -> PgConnectOptions {
+    options.options([("statement_timeout", format!("{}ms", timeout.as_millis()))])
+}
+
+/// Whether `err` means the query ran out of time rather than failed.
+pub fn is_timeout(err: &sqlx::Error) -> bool {
+    match err {
+        sqlx::Error::PoolTimedOut => true,
+        sqlx::Error::Database(db) => db.code().as_deref() == Some(QUERY_CANCELED),
+        _ => false,
+    }
+}
+
+pub fn timeout_error(operation: &str) -> ApiError {
+    tracing::error!(operation = operation, "database operation timed out");
+    ApiError::new(
+        StatusCode::GATEWAY_TIMEOUT,
+        "DatabaseTimeout",
+        "The database did not respond in time",
+    )
+}
+
+/// Map a database error to the API error: 504 for timeouts, 500 otherwise.
+pub fn map_db_error(operation: &str, err: sqlx::Error) -> ApiError {
+    if is_timeout(&err) {
+        return timeout_error(operation);
+    }
+    tracing::error!(operation = operation, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+/// Run a query future under the configured timeout.
+pub async fn timed<T, F>(operation: &str, query: F) -> ApiResult<T>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    timed_with(statement_timeout(), operation, query).await
+}
+
+pub async fn timed_with<T, F>(timeout: Duration, operation: &str, query: F) -> ApiResult<T>
+where
+    F: Future<Output = Result<T, sqlx::Error>>,
+{
+    match tokio::time::timeout(timeout, query).await {
+        Ok(result) => result.map_err(|err| map_db_error(operation, err)),
+        Err(_) => Err(timeout_error(operation)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    #[tokio::test]
+    async fn slow_query_returns_504() {
+        let slow = async {
+            tokio::time::sleep(Duration::from_millis(200)).await;
+            Ok::<_, sqlx::Error>(1)
+        };
+
+        let err = timed_with(Duration::from_millis(10), "slow query", slow)
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::GATEWAY_TIMEOUT);
+    }
+
+    #[tokio::test]
+    async fn fast_query_passes_through() {
+        let value = timed_with(Duration::from_secs(1), "fast query", async { Ok::<_, sqlx::Error>(7) })
+            .await
+            .unwrap();
+        assert_eq!(value, 7);
+    }
+
+    #[test]
+    fn pool_timeout_maps_to_504_and_other_errors_to_500() {
+        let timeout = map_db_error("acquire", sqlx::Error::PoolTimedOut);
+        assert_eq!(timeout.into_response().status(), StatusCode::GATEWAY_TIMEOUT);
+
+        let other = map_db_error("fetch", sqlx::Error::RowNotFound);
+        assert_eq!(other.into_response().status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,1206 @@
+// api/src/deployment_health.rs
+// Blue/green deployment health check reporting and environment summary.
+//
+//   POST /api/deployments/health               — one check
+//   POST /api/deployments/health/batch?atomic= — many checks in one transaction
+//   GET  /api/contracts/:id/deployments         — both environments and the last switch
+//   GET  /api/contracts/:id/deployments/diff    — what the candidate changes vs. the active one
+//   POST /api/deployments/switch               — promote green once it is healthy
+//
+// A batch reports a result per item. By default invalid items are skipped and
+// the rest applied; with `atomic=true` any failure rolls the whole batch back.
+//
+// The diff compares the active deployment (blue when none is active yet) with
+// the other environment: whether the WASM differs and, when both hashes match
+// a published version with an ABI on record, which methods were added,
+// removed or changed.
+//
+// Each deployment tracks its streak of consecutive passing checks; a failure
+// resets it. Switching to green requires a streak of at least
+// `DEPLOYMENT_SWITCH_MIN_PASSES` (default 3) unless the switch is forced.
+//
+// It also requires the contract's readiness checklist (verified, described,
+// licensed, documented, green's WASM published with an ABI) to be at least
+// `DEPLOYMENT_SWITCH_MIN_READINESS` percent complete (default 80; 0 turns
+// the gate off). Short of that the switch fails with 422 listing the unmet
+// items; `force` overrides it like the health streak.
+//
+// Contract flags add two rules: with `require_multisig_deploy` a switch to
+// green also needs an executed multisig proposal for green's WASM (force does
+// not bypass it), and with `auto_rollback` an active deployment that reaches
+// the failure threshold hands traffic back to the other environment.
+
+use axum::{
+    extract::{rejection::JsonRejection, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use shared::{
+    ContractDeployment, DeploymentEnvironment, DeploymentStatus, DeploymentSwitch, HealthCheckRequest,
+    SwitchDeploymentRequest,
+};
+use sqlx::{PgConnection, PgPool};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+use crate::{
+    breaking_changes::{diff_abi, ChangeSeverity},
+    contract_flags::{self, ContractFlags},
+    error::{ApiError, ApiResult},
+    state::AppState,
+    type_safety::{parse_json_spec, ContractABI},
+};
+
+/// Largest batch accepted in one request
+pub const MAX_HEALTH_CHECK_BATCH: usize = 500;
+
+/// Consecutive failures after which a deployment is marked `failed`
+const FAILURE_THRESHOLD: i32 = 3;
+
+/// Default passing-check streak green needs before it can take traffic
+pub const DEFAULT_MIN_CONSECUTIVE_PASSES: i32 = 3;
+
+/// Default share of the readiness checklist that must be met, in percent
+pub const DEFAULT_MIN_READINESS_PERCENT: u8 = 80;
+
+/// What the readiness checklist is evaluated from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::FromRow)]
+pub struct ReadinessFacts {
+    pub verified: bool,
+    pub has_description: bool,
+    pub has_license: bool,
+    pub has_readme: bool,
+    /// Green's WASM hash matches a published version
+    pub green_published: bool,
+    /// ...and that version has an ABI on record
+    pub green_has_abi: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReadinessItem {
+    pub key: &'static str,
+    pub description: &'static str,
+    pub met: bool,
+}
+
+/// A contract's readiness checklist for promoting green
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ReadinessChecklist {
+    pub items: Vec<ReadinessItem>,
+    /// Share of items met, rounded down
+    pub percent: u8,
+}
+
+impl ReadinessChecklist {
+    pub fn evaluate(facts: &ReadinessFacts) -> Self {
+        let item = |key, description, met| ReadinessItem { key, description, met };
+        let items = vec![
+            item("verified", "Contract source is verified", facts.verified),
+            item("description", "Contract has a description", facts.has_description),
+            item("license", "Contract declares a license", facts.has_license),
+            item("readme", "Contract has a README", facts.has_readme),
+            item(
+                "published_version",
+                "Green WASM matches a published version",
+                facts.green_published,
+            ),
+            item("abi", "Green's version has an ABI on record", facts.green_has_abi),
+        ];
+        let met = items.iter().filter(|i| i.met).count();
+        let percent = (met * 100 / items.len()) as u8;
+        Self { items, percent }
+    }
+
+    pub fn unmet(&self) -> impl Iterator<Item = &ReadinessItem> {
+        self.items.iter().filter(|i| !i.met)
+    }
+}
+
+/// Readiness facts for promoting `green_wasm_hash` on the contract
+async fn load_readiness_facts(
+    conn: &mut PgConnection,
+    contract_id: Uuid,
+    green_wasm_hash: &str,
+) -> Result<ReadinessFacts, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT c.is_verified AS verified,
+                COALESCE(BTRIM(c.description), '') <> '' AS has_description,
+                c.license IS NOT NULL AS has_license,
+                EXISTS(SELECT 1 FROM contract_readmes r
+                       WHERE r.contract_id = c.id AND BTRIM(r.markdown) <> '') AS has_readme,
+                EXISTS(SELECT 1 FROM contract_versions cv
+                       WHERE cv.contract_id = c.id AND cv.wasm_hash = $2) AS green_published,
+                EXISTS(SELECT 1 FROM contract_versions cv
+                       JOIN contract_abis ca ON ca.contract_id = cv.contract_id AND ca.version = cv.version
+                       WHERE cv.contract_id = c.id AND cv.wasm_hash = $2) AS green_has_abi
+         FROM contracts c WHERE c.id = $1",
+    )
+    .bind(contract_id)
+    .bind(green_wasm_hash)
+    .fetch_one(conn)
+    .await
+}
+
+/// Health requirements a green deployment must meet before a switch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwitchPolicy {
+    pub min_consecutive_passes: i32,
+    pub min_readiness_percent: u8,
+}
+
+impl Default for SwitchPolicy {
+    fn default() -> Self {
+        Self {
+            min_consecutive_passes: DEFAULT_MIN_CONSECUTIVE_PASSES,
+            min_readiness_percent: DEFAULT_MIN_READINESS_PERCENT,
+        }
+    }
+}
+
+impl SwitchPolicy {
+    /// Read `DEPLOYMENT_SWITCH_MIN_PASSES` and `DEPLOYMENT_SWITCH_MIN_READINESS`;
+    /// invalid values (non-positive passes, a percentage over 100) fall back to the defaults.
+    pub fn from_env() -> Self {
+        let min_consecutive_passes = std::env::var("DEPLOYMENT_SWITCH_MIN_PASSES")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i32>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_MIN_CONSECUTIVE_PASSES);
+        let min_readiness_percent = std::env::var("DEPLOYMENT_SWITCH_MIN_READINESS")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<u8>().ok())
+            .filter(|n| *n <= 100)
+            .unwrap_or(DEFAULT_MIN_READINESS_PERCENT);
+        Self {
+            min_consecutive_passes,
+            min_readiness_percent,
+        }
+    }
+
+    /// All switch gates; `force` skips them.
+    pub fn check_switch(&self, green: &ContractDeployment, readiness: &ReadinessChecklist, force: bool) -> ApiResult<()> {
+        if force {
+            return Ok(());
+        }
+        self.check(green)?;
+        self.check_readiness(readiness)
+    }
+
+    /// Whether enough of the readiness checklist is met, or a 422 listing the unmet items.
+    pub fn check_readiness(&self, readiness: &ReadinessChecklist) -> ApiResult<()> {
+        if readiness.percent >= self.min_readiness_percent {
+            return Ok(());
+        }
+        let unmet: Vec<&ReadinessItem> = readiness.unmet().collect();
+        Err(ApiError::unprocessable(
+            "ReadinessChecklistIncomplete",
+            format!(
+                "Readiness checklist is {}% complete; {}% required ({} item(s) unmet)",
+                readiness.percent,
+                self.min_readiness_percent,
+                unmet.len()
+            ),
+        )
+        .with_details(serde_json::json!({
+            "readiness_percent": readiness.percent,
+            "required_percent": self.min_readiness_percent,
+            "unmet": unmet,
+        })))
+    }
+
+    /// Whether `green` may be promoted, or a 422 explaining what is missing.
+    pub fn check(&self, green: &ContractDeployment) -> ApiResult<()> {
+        if green.status != DeploymentStatus::Testing {
+            return Err(ApiError::unprocessable(
+                "InvalidDeploymentStatus",
+                format!("Green deployment must be in testing status before a switch (is {:?})", green.status),
+            ));
+        }
+        let shortfall = self.min_consecutive_passes - green.consecutive_passes;
+        if shortfall > 0 {
+            return Err(ApiError::unprocessable(
+                "InsufficientHealthChecks",
+                format!(
+                    "Green deployment has {} consecutive passing health check(s); {} required, {} more needed",
+                    green.consecutive_passes, self.min_consecutive_passes, shortfall
+                ),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BatchHealthCheckQuery {
+    #[serde(default)]
+    pub atomic: bool,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct HealthCheckItemResult {
+    pub index: usize,
+    pub contract_id: String,
+    pub applied: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchHealthCheckResponse {
+    pub atomic: bool,
+    pub applied: usize,
+    pub failed: usize,
+    pub results: Vec<HealthCheckItemResult>,
+}
+
+impl BatchHealthCheckResponse {
+    fn from_results(atomic: bool, results: Vec<HealthCheckItemResult>) -> Self {
+        let applied = results.iter().filter(|r| r.applied).count();
+        Self {
+            atomic,
+            applied,
+            failed: results.len() - applied,
+            results,
+        }
+    }
+}
+
+/// Blue/green state of one contract
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentSummary {
+    pub contract_id: Uuid,
+    /// Environment currently serving traffic, if any
+    pub active_environment: Option<DeploymentEnvironment>,
+    pub blue: Option<ContractDeployment>,
+    pub green: Option<ContractDeployment>,
+    pub last_switch: Option<DeploymentSwitch>,
+}
+
+impl DeploymentSummary {
+    pub fn build(
+        contract_id: Uuid,
+        deployments: Vec<ContractDeployment>,
+        last_switch: Option<DeploymentSwitch>,
+    ) -> Self {
+        let mut summary = Self {
+            contract_id,
+            active_environment: None,
+            blue: None,
+            green: None,
+            last_switch,
+        };
+        for deployment in deployments {
+            if deployment.status == DeploymentStatus::Active {
+                summary.active_environment = Some(deployment.environment.clone());
+            }
+            match deployment.environment {
+                DeploymentEnvironment::Blue => summary.blue = Some(deployment),
+                DeploymentEnvironment::Green => summary.green = Some(deployment),
+            }
+        }
+        summary
+    }
+}
+
+/// One side of a blue/green comparison
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentSide {
+    pub environment: DeploymentEnvironment,
+    pub status: DeploymentStatus,
+    pub wasm_hash: String,
+    /// Published version with this WASM, when there is one
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MethodChange {
+    pub name: String,
+    pub breaking: bool,
+    pub changes: Vec<String>,
+}
+
+/// Method-level API difference between two ABIs
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AbiDiff {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+    pub changed: Vec<MethodChange>,
+    /// Whether calls that work against the active deployment could fail on the candidate
+    pub breaking: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeploymentDiff {
+    pub contract_id: Uuid,
+    pub active: DeploymentSide,
+    pub candidate: DeploymentSide,
+    pub wasm_changed: bool,
+    /// `None` when either side has no ABI on record
+    pub abi_diff: Option<AbiDiff>,
+}
+
+/// The deployment serving traffic (blue if none is active yet) and the other one.
+pub fn pick_sides(deployments: &[ContractDeployment]) -> Option<(&ContractDeployment, &ContractDeployment)> {
+    let in_env = move |env: DeploymentEnvironment| deployments.iter().find(|d| d.environment == env);
+    let active = deployments
+        .iter()
+        .find(|d| d.status == DeploymentStatus::Active)
+        .or_else(|| in_env(DeploymentEnvironment::Blue))?;
+    let candidate = match active.environment {
+        DeploymentEnvironment::Blue => in_env(DeploymentEnvironment::Green),
+        DeploymentEnvironment::Green => in_env(DeploymentEnvironment::Blue),
+    }?;
+    Some((active, candidate))
+}
+
+/// Group the function-level entries of an ABI diff by method. Type-level
+/// changes are left out; they surface through the methods that use them.
+pub fn method_diff(active: &ContractABI, candidate: &ContractABI) -> AbiDiff {
+    let mut diff = AbiDiff::default();
+    let mut changed: BTreeMap<String, MethodChange> = BTreeMap::new();
+
+    for change in diff_abi(active, candidate) {
+        let Some(name) = change.function else { continue };
+        match change.category.as_str() {
+            "function_added" => diff.added.push(name),
+            "function_removed" => diff.removed.push(name),
+            _ => {
+                let entry = changed.entry(name.clone()).or_insert(MethodChange {
+                    name,
+                    breaking: false,
+                    changes: Vec::new(),
+                });
+                entry.breaking |= change.severity == ChangeSeverity::Breaking;
+                entry.changes.push(change.message);
+            }
+        }
+    }
+
+    diff.added.sort();
+    diff.removed.sort();
+    diff.changed = changed.into_values().collect();
+    diff.breaking = !diff.removed.is_empty() || diff.changed.iter().any(|m| m.breaking);
+    diff
+}
+
+/// Check the fields that can be validated without touching the database.
+pub fn validate_health_check(req: &HealthCheckRequest) -> Result<(), String> {
+    crate::validation::validate_contract_id(&req.contract_id)
+}
+
+/// Validate every item up front. Returns the per-item results so far (failed
+/// items only carry an error) and whether the batch may proceed.
+pub fn plan_batch(checks: &[HealthCheckRequest], atomic: bool) -> (Vec<HealthCheckItemResult>, bool) {
+    let results: Vec<HealthCheckItemResult> = checks
+        .iter()
+        .enumerate()
+        .map(|(index, req)| HealthCheckItemResult {
+            index,
+            contract_id: req.contract_id.clone(),
+            applied: false,
+            error: validate_health_check(req).err(),
+        })
+        .collect();
+
+    let proceed = !atomic || results.iter().all(|r| r.error.is_none());
+    (results, proceed)
+}
+
+/// In an atomic batch that failed, items that were otherwise fine are
+/// reported as rolled back.
+fn mark_rolled_back(results: &mut [HealthCheckItemResult]) {
+    for r in results.iter_mut() {
+        r.applied = false;
+        if r.error.is_none() {
+            r.error = Some("Rolled back: another item in the atomic batch failed".to_string());
+        }
+    }
+}
+
+pub fn other_environment(env: &DeploymentEnvironment) -> DeploymentEnvironment {
+    match env {
+        DeploymentEnvironment::Blue => DeploymentEnvironment::Green,
+        DeploymentEnvironment::Green => DeploymentEnvironment::Blue,
+    }
+}
+
+/// A failing check rolls back only when it just took the active deployment
+/// down and the contract opted in.
+pub fn should_auto_rollback(flags: &ContractFlags, before: &DeploymentStatus, after: &DeploymentStatus) -> bool {
+    flags.auto_rollback && *before == DeploymentStatus::Active && *after == DeploymentStatus::Failed
+}
+
+/// Reactivate the environment opposite `failed_env`, unless it has failed too.
+/// Returns whether traffic moved.
+async fn roll_back(
+    conn: &mut PgConnection,
+    contract_uuid: Uuid,
+    failed_env: &DeploymentEnvironment,
+) -> Result<bool, sqlx::Error> {
+    let to_env = other_environment(failed_env);
+    let activated = sqlx::query(
+        "UPDATE contract_deployments SET status = 'active', activated_at = NOW()
+         WHERE contract_id = $1 AND environment = $2 AND status <> 'failed'",
+    )
+    .bind(contract_uuid)
+    .bind(&to_env)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected()
+        > 0;
+
+    if activated {
+        sqlx::query(
+            "INSERT INTO deployment_switches (contract_id, from_environment, to_environment, rollback)
+             VALUES ($1, $2, $3, TRUE)",
+        )
+        .bind(contract_uuid)
+        .bind(failed_env)
+        .bind(&to_env)
+        .execute(&mut *conn)
+        .await?;
+        tracing::warn!(contract_id = %contract_uuid, from = %failed_env, to = %to_env, "automatic rollback after failed health checks");
+    }
+    Ok(activated)
+}
+
+/// Apply one health check on `conn`. `Ok(false)` when the contract or its
+/// deployment in that environment does not exist.
+async fn apply_health_check(conn: &mut PgConnection, req: &HealthCheckRequest) -> Result<bool, sqlx::Error> {
+    let contract_uuid: Option<Uuid> =
+        sqlx::query_scalar("SELECT id FROM contracts WHERE contract_id = $1 LIMIT 1")
+            .bind(&req.contract_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+    let Some(contract_uuid) = contract_uuid else {
+        return Ok(false);
+    };
+
+    if req.passed {
+        let result = sqlx::query(
+            "UPDATE contract_deployments
+             SET health_checks_passed = health_checks_passed + 1,
+                 consecutive_passes = consecutive_passes + 1,
+                 last_health_check_at = NOW()
+             WHERE contract_id = $1 AND environment = $2",
+        )
+        .bind(contract_uuid)
+        .bind(&req.environment)
+        .execute(&mut *conn)
+        .await?;
+        return Ok(result.rows_affected() > 0);
+    }
+
+    // Status before and after, so a deployment that just failed while active can be rolled back
+    let statuses: Option<(DeploymentStatus, DeploymentStatus)> = sqlx::query_as(
+        "UPDATE contract_deployments d
+         SET health_checks_failed = d.health_checks_failed + 1,
+             consecutive_passes = 0,
+             status = CASE WHEN d.health_checks_failed + 1 >= $3 THEN 'failed' ELSE d.status END,
+             last_health_check_at = NOW()
+         FROM (SELECT id, status FROM contract_deployments
+               WHERE contract_id = $1 AND environment = $2 FOR UPDATE) old
+         WHERE d.id = old.id
+         RETURNING old.status, d.status",
+    )
+    .bind(contract_uuid)
+    .bind(&req.environment)
+    .bind(FAILURE_THRESHOLD)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let Some((before, after)) = statuses else {
+        return Ok(false);
+    };
+
+    if before == DeploymentStatus::Active && after == DeploymentStatus::Failed {
+        let flags = contract_flags::load_flags(&mut *conn, contract_uuid)
+            .await?
+            .unwrap_or_default();
+        if should_auto_rollback(&flags, &before, &after) {
+            roll_back(conn, contract_uuid, &req.environment).await?;
+        }
+    }
+    Ok(true)
+}
+
+fn not_found_message(req: &HealthCheckRequest) -> String {
+    format!(
+        "No {} deployment found for contract {}",
+        req.environment, req.contract_id
+    )
+}
+
+fn map_json_rejection(err: JsonRejection) -> ApiError {
+    ApiError::bad_request("InvalidRequest", format!("Invalid JSON payload: {}", err.body_text()))
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(op, err)
+}
+
+/// POST /api/deployments/health
+pub async fn report_health_check(
+    State(state): State<AppState>,
+    payload: Result<Json<HealthCheckRequest>, JsonRejection>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let Json(req) = payload.map_err(map_json_rejection)?;
+    validate_health_check(&req).map_err(|e| ApiError::bad_request("InvalidContractId", e))?;
+
+    let mut conn = state
+        .db
+        .acquire()
+        .await
+        .map_err(|err| db_err("acquire connection for health check", err))?;
+    if !apply_health_check(&mut conn, &req)
+        .await
+        .map_err(|err| db_err("record health check", err))?
+    {
+        return Err(ApiError::not_found("DeploymentNotFound", not_found_message(&req)));
+    }
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "environment": req.environment.to_string(),
+        "passed": req.passed
+    })))
+}
+
+/// POST /api/deployments/health/batch
+pub async fn report_health_check_batch(
+    State(state): State<AppState>,
+    Query(query): Query<BatchHealthCheckQuery>,
+    payload: Result<Json<Vec<HealthCheckRequest>>, JsonRejection>,
+) -> ApiResult<(StatusCode, Json<BatchHealthCheckResponse>)> {
+    let Json(checks) = payload.map_err(map_json_rejection)?;
+    if checks.is_empty() {
+        return Err(ApiError::bad_request("EmptyBatch", "Batch must contain at least one health check"));
+    }
+    if checks.len() > MAX_HEALTH_CHECK_BATCH {
+        return Err(ApiError::bad_request(
+            "BatchTooLarge",
+            format!("Batch exceeds the maximum of {} health checks", MAX_HEALTH_CHECK_BATCH),
+        ));
+    }
+
+    let atomic = query.atomic;
+    let (mut results, proceed) = plan_batch(&checks, atomic);
+    if !proceed {
+        mark_rolled_back(&mut results);
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(BatchHealthCheckResponse::from_results(atomic, results)),
+        ));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin health check batch", err))?;
+
+    for (req, result) in checks.iter().zip(results.iter_mut()) {
+        if result.error.is_some() {
+            continue;
+        }
+
+        // Each item runs in a savepoint so a failed one leaves the rest intact.
+        let mut item_tx = sqlx::Connection::begin(&mut *tx)
+            .await
+            .map_err(|err| db_err("begin health check savepoint", err))?;
+        match apply_health_check(&mut item_tx, req).await {
+            Ok(true) => {
+                item_tx
+                    .commit()
+                    .await
+                    .map_err(|err| db_err("release health check savepoint", err))?;
+                result.applied = true;
+            }
+            Ok(false) => {
+                let _ = item_tx.rollback().await;
+                result.error = Some(not_found_message(req));
+            }
+            Err(err) => {
+                let _ = item_tx.rollback().await;
+                tracing::warn!(error = ?err, contract_id = %req.contract_id, "health check item failed");
+                result.error = Some("Failed to record health check".to_string());
+            }
+        }
+
+        if atomic && result.error.is_some() {
+            break;
+        }
+    }
+
+    if atomic && results.iter().any(|r| r.error.is_some()) {
+        let _ = tx.rollback().await;
+        mark_rolled_back(&mut results);
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(BatchHealthCheckResponse::from_results(atomic, results)),
+        ));
+    }
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit health check batch", err))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(BatchHealthCheckResponse::from_results(atomic, results)),
+    ))
+}
+
+/// GET /api/contracts/:id/deployments
+pub async fn get_deployment_summary(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+) -> ApiResult<Json<DeploymentSummary>> {
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)")
+        .bind(contract_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_err("check contract for deployment summary", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            format!("No contract found with ID: {}", contract_id),
+        ));
+    }
+
+    let deployments: Vec<ContractDeployment> = sqlx::query_as(
+        "SELECT id, contract_id, environment, status, wasm_hash, deployed_at, activated_at,
+                COALESCE(health_checks_passed, 0) AS health_checks_passed,
+                COALESCE(health_checks_failed, 0) AS health_checks_failed,
+                last_health_check_at, error_message, consecutive_passes
+         FROM contract_deployments WHERE contract_id = $1",
+    )
+    .bind(contract_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list contract deployments", err))?;
+
+    let last_switch: Option<DeploymentSwitch> = sqlx::query_as(
+        "SELECT * FROM deployment_switches WHERE contract_id = $1 ORDER BY switched_at DESC LIMIT 1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("get last deployment switch", err))?;
+
+    Ok(Json(DeploymentSummary::build(contract_id, deployments, last_switch)))
+}
+
+/// Version and ABI of the published version whose WASM matches `wasm_hash`.
+async fn version_for_wasm(
+    pool: &PgPool,
+    contract_id: Uuid,
+    wasm_hash: &str,
+) -> Result<Option<(String, Option<serde_json::Value>)>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT cv.version, ca.abi
+         FROM contract_versions cv
+         LEFT JOIN contract_abis ca ON ca.contract_id = cv.contract_id AND ca.version = cv.version
+         WHERE cv.contract_id = $1 AND cv.wasm_hash = $2
+         ORDER BY cv.created_at DESC
+         LIMIT 1",
+    )
+    .bind(contract_id)
+    .bind(wasm_hash)
+    .fetch_optional(pool)
+    .await
+}
+
+fn parse_abi(abi: Option<serde_json::Value>, label: &str) -> Option<ContractABI> {
+    let abi = abi?;
+    parse_json_spec(&abi.to_string(), label)
+        .map_err(|err| tracing::warn!(error = ?err, label, "stored ABI could not be parsed"))
+        .ok()
+}
+
+/// GET /api/contracts/:id/deployments/diff
+pub async fn get_deployment_diff(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+) -> ApiResult<Json<DeploymentDiff>> {
+    let deployments: Vec<ContractDeployment> = sqlx::query_as(
+        "SELECT id, contract_id, environment, status, wasm_hash, deployed_at, activated_at,
+                COALESCE(health_checks_passed, 0) AS health_checks_passed,
+                COALESCE(health_checks_failed, 0) AS health_checks_failed,
+                last_health_check_at, error_message, consecutive_passes
+         FROM contract_deployments WHERE contract_id = $1",
+    )
+    .bind(contract_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list contract deployments for diff", err))?;
+
+    let (active, candidate) = pick_sides(&deployments).ok_or_else(|| {
+        ApiError::not_found(
+            "DeploymentNotFound",
+            format!("Contract {} needs both a blue and a green deployment to compare", contract_id),
+        )
+    })?;
+
+    let mut sides = Vec::with_capacity(2);
+    for deployment in [active, candidate] {
+        let found = version_for_wasm(&state.db, contract_id, &deployment.wasm_hash)
+            .await
+            .map_err(|err| db_err("get version for deployment diff", err))?;
+        let (version, abi) = found.map_or((None, None), |(v, abi)| (Some(v), abi));
+        let abi = parse_abi(abi, &deployment.environment.to_string());
+        sides.push((
+            DeploymentSide {
+                environment: deployment.environment.clone(),
+                status: deployment.status.clone(),
+                wasm_hash: deployment.wasm_hash.clone(),
+                version,
+            },
+            abi,
+        ));
+    }
+    let (candidate, candidate_abi) = sides.pop().expect("two sides");
+    let (active, active_abi) = sides.pop().expect("two sides");
+
+    let abi_diff = match (&active_abi, &candidate_abi) {
+        (Some(a), Some(c)) => Some(method_diff(a, c)),
+        _ => None,
+    };
+
+    Ok(Json(DeploymentDiff {
+        contract_id,
+        wasm_changed: active.wasm_hash != candidate.wasm_hash,
+        active,
+        candidate,
+        abi_diff,
+    }))
+}
+
+/// POST /api/deployments/switch
+pub async fn switch_deployment(
+    State(state): State<AppState>,
+    payload: Result<Json<SwitchDeploymentRequest>, JsonRejection>,
+) -> ApiResult<Json<serde_json::Value>> {
+    let Json(req) = payload.map_err(map_json_rejection)?;
+    let force = req.force.unwrap_or(false);
+
+    let contract_uuid: Uuid = sqlx::query_scalar("SELECT id FROM contracts WHERE contract_id = $1 LIMIT 1")
+        .bind(&req.contract_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("get contract for switch", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("Contract not found: {}", req.contract_id)))?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin deployment switch", err))?;
+
+    let deployments: Vec<ContractDeployment> = sqlx::query_as(
+        "SELECT * FROM contract_deployments WHERE contract_id = $1 FOR UPDATE",
+    )
+    .bind(contract_uuid)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|err| db_err("lock contract deployments", err))?;
+
+    let from_env = deployments
+        .iter()
+        .find(|d| d.status == DeploymentStatus::Active)
+        .map(|d| d.environment.clone())
+        .unwrap_or(DeploymentEnvironment::Blue);
+    let to_env = other_environment(&from_env);
+
+    let green = deployments
+        .iter()
+        .find(|d| d.environment == DeploymentEnvironment::Green)
+        .ok_or_else(|| ApiError::bad_request("NoGreenDeployment", "No green deployment found"))?;
+    let readiness = load_readiness_facts(&mut tx, contract_uuid, &green.wasm_hash)
+        .await
+        .map(|facts| ReadinessChecklist::evaluate(&facts))
+        .map_err(|err| db_err("load readiness checklist for switch", err))?;
+    SwitchPolicy::from_env().check_switch(green, &readiness, force)?;
+
+    let flags = contract_flags::load_flags(&mut *tx, contract_uuid)
+        .await
+        .map_err(|err| db_err("get contract flags for switch", err))?
+        .unwrap_or_default();
+    if flags.require_multisig_deploy && to_env == DeploymentEnvironment::Green {
+        let approved: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM deploy_proposals
+                           WHERE contract_id = $1 AND wasm_hash = $2 AND status = 'executed')",
+        )
+        .bind(&req.contract_id)
+        .bind(&green.wasm_hash)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_err("check multisig approval for switch", err))?;
+        if !approved {
+            return Err(ApiError::unprocessable(
+                "MultisigApprovalRequired",
+                format!(
+                    "Contract {} requires an executed multisig proposal for WASM {} before switching",
+                    req.contract_id, green.wasm_hash
+                ),
+            ));
+        }
+    }
+
+    sqlx::query("UPDATE contract_deployments SET status = 'inactive' WHERE contract_id = $1 AND status = 'active'")
+        .bind(contract_uuid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_err("deactivate current deployment", err))?;
+
+    sqlx::query(
+        "UPDATE contract_deployments SET status = 'active', activated_at = NOW()
+         WHERE contract_id = $1 AND environment = $2",
+    )
+    .bind(contract_uuid)
+    .bind(&to_env)
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| db_err("activate deployment", err))?;
+
+    sqlx::query(
+        "INSERT INTO deployment_switches (contract_id, from_environment, to_environment) VALUES ($1, $2, $3)",
+    )
+    .bind(contract_uuid)
+    .bind(&from_env)
+    .bind(&to_env)
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| db_err("record deployment switch", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit deployment switch", err))?;
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "switched_from": from_env,
+        "switched_to": to_env,
+        "contract_id": req.contract_id,
+        "forced": force,
+        "readiness_percent": readiness.percent
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    const CONTRACT: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+    fn check(contract_id: &str, passed: bool) -> HealthCheckRequest {
+        HealthCheckRequest {
+            contract_id: contract_id.to_string(),
+            environment: DeploymentEnvironment::Green,
+            passed,
+        }
+    }
+
+    fn mixed_batch() -> Vec<HealthCheckRequest> {
+        vec![check(CONTRACT, true), check("not-a-contract", false), check(CONTRACT, false)]
+    }
+
+    #[test]
+    fn mixed_batch_reports_invalid_items_and_keeps_valid_ones() {
+        let (results, proceed) = plan_batch(&mixed_batch(), false);
+
+        assert!(proceed);
+        assert_eq!(results.len(), 3);
+        assert!(results[0].error.is_none());
+        assert!(results[1].error.is_some());
+        assert!(results[2].error.is_none());
+        assert_eq!(results[1].index, 1);
+    }
+
+    #[test]
+    fn atomic_mixed_batch_is_rejected_entirely() {
+        let (mut results, proceed) = plan_batch(&mixed_batch(), true);
+        assert!(!proceed);
+
+        mark_rolled_back(&mut results);
+        let response = BatchHealthCheckResponse::from_results(true, results);
+        assert_eq!(response.applied, 0);
+        assert_eq!(response.failed, 3);
+        assert!(response.results.iter().all(|r| r.error.is_some()));
+    }
+
+    #[test]
+    fn summary_counts_applied_items() {
+        let (mut results, _) = plan_batch(&mixed_batch(), false);
+        results[0].applied = true;
+        results[2].applied = true;
+
+        let response = BatchHealthCheckResponse::from_results(false, results);
+        assert_eq!(response.applied, 2);
+        assert_eq!(response.failed, 1);
+
+        let body = serde_json::to_value(&response).unwrap();
+        assert!(body["results"][0].get("error").is_none());
+        assert!(body["results"][1]["error"].is_string());
+    }
+
+    fn deployment(environment: DeploymentEnvironment, status: DeploymentStatus) -> ContractDeployment {
+        ContractDeployment {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::nil(),
+            environment,
+            status,
+            wasm_hash: "hash".into(),
+            deployed_at: Utc::now(),
+            activated_at: None,
+            health_checks_passed: 4,
+            health_checks_failed: 1,
+            last_health_check_at: Some(Utc::now()),
+            error_message: None,
+            consecutive_passes: 2,
+        }
+    }
+
+    fn status(result: ApiResult<()>) -> StatusCode {
+        use axum::response::IntoResponse;
+        result.unwrap_err().into_response().status()
+    }
+
+    #[test]
+    fn switch_requires_a_streak_of_passing_checks() {
+        let policy = SwitchPolicy::default();
+        let mut green = deployment(DeploymentEnvironment::Green, DeploymentStatus::Testing);
+
+        // Plenty of passes overall, but only two in a row since the last failure
+        assert_eq!(green.health_checks_passed, 4);
+        assert_eq!(status(policy.check(&green)), StatusCode::UNPROCESSABLE_ENTITY);
+
+        green.consecutive_passes = 3;
+        assert!(policy.check(&green).is_ok());
+
+        let strict = SwitchPolicy {
+            min_consecutive_passes: 5,
+            ..SwitchPolicy::default()
+        };
+        let err = strict.check(&green).unwrap_err();
+        assert!(format!("{:?}", err).contains("2 more needed"));
+    }
+
+    fn ready() -> ReadinessFacts {
+        ReadinessFacts {
+            verified: true,
+            has_description: true,
+            has_license: true,
+            has_readme: true,
+            green_published: true,
+            green_has_abi: true,
+        }
+    }
+
+    fn healthy_green() -> ContractDeployment {
+        let mut green = deployment(DeploymentEnvironment::Green, DeploymentStatus::Testing);
+        green.consecutive_passes = DEFAULT_MIN_CONSECUTIVE_PASSES;
+        green
+    }
+
+    #[test]
+    fn readiness_percent_counts_met_items() {
+        assert_eq!(ReadinessChecklist::evaluate(&ready()).percent, 100);
+        assert_eq!(ReadinessChecklist::evaluate(&ReadinessFacts::default()).percent, 0);
+
+        let one_missing = ReadinessChecklist::evaluate(&ReadinessFacts { has_readme: false, ..ready() });
+        assert_eq!(one_missing.percent, 83);
+        assert_eq!(one_missing.unmet().map(|i| i.key).collect::<Vec<_>>(), vec!["readme"]);
+    }
+
+    #[tokio::test]
+    async fn incomplete_checklist_blocks_the_switch_with_unmet_items() {
+        use axum::response::IntoResponse;
+        let policy = SwitchPolicy::default();
+        let green = healthy_green();
+
+        let one_missing = ReadinessChecklist::evaluate(&ReadinessFacts { has_license: false, ..ready() });
+        assert!(policy.check_switch(&green, &one_missing, false).is_ok());
+
+        let unready = ReadinessChecklist::evaluate(&ReadinessFacts {
+            verified: false,
+            green_has_abi: false,
+            ..ready()
+        });
+        let response = policy.check_switch(&green, &unready, false).unwrap_err().into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"], "ReadinessChecklistIncomplete");
+        assert_eq!(body["details"]["readiness_percent"], 66);
+        let unmet: Vec<&str> = body["details"]["unmet"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|i| i["key"].as_str().unwrap())
+            .collect();
+        assert_eq!(unmet, vec!["verified", "abi"]);
+    }
+
+    #[test]
+    fn force_overrides_the_readiness_gate() {
+        let policy = SwitchPolicy {
+            min_readiness_percent: 100,
+            ..SwitchPolicy::default()
+        };
+        let unready = ReadinessChecklist::evaluate(&ReadinessFacts::default());
+        assert!(policy.check_switch(&healthy_green(), &unready, false).is_err());
+        assert!(policy.check_switch(&healthy_green(), &unready, true).is_ok());
+
+        let off = SwitchPolicy {
+            min_readiness_percent: 0,
+            ..SwitchPolicy::default()
+        };
+        assert!(off.check_switch(&healthy_green(), &unready, false).is_ok());
+    }
+
+    #[test]
+    fn auto_rollback_needs_the_flag_and_an_active_deployment_going_down() {
+        use DeploymentStatus::*;
+        let on = ContractFlags {
+            auto_rollback: true,
+            ..ContractFlags::default()
+        };
+        assert!(should_auto_rollback(&on, &Active, &Failed));
+        assert!(!should_auto_rollback(&ContractFlags::default(), &Active, &Failed));
+        assert!(!should_auto_rollback(&on, &Active, &Active));
+        assert!(!should_auto_rollback(&on, &Testing, &Failed));
+        assert_eq!(other_environment(&DeploymentEnvironment::Blue), DeploymentEnvironment::Green);
+    }
+
+    #[test]
+    fn switch_requires_green_to_be_testing() {
+        let mut green = deployment(DeploymentEnvironment::Green, DeploymentStatus::Failed);
+        green.consecutive_passes = 10;
+        assert_eq!(status(SwitchPolicy::default().check(&green)), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[test]
+    fn summary_shows_active_and_testing_environments() {
+        let switch = DeploymentSwitch {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::nil(),
+            from_environment: DeploymentEnvironment::Green,
+            to_environment: DeploymentEnvironment::Blue,
+            switched_at: Utc::now(),
+            switched_by: Some("GADMIN".into()),
+            rollback: false,
+        };
+        let summary = DeploymentSummary::build(
+            Uuid::nil(),
+            vec![
+                deployment(DeploymentEnvironment::Green, DeploymentStatus::Testing),
+                deployment(DeploymentEnvironment::Blue, DeploymentStatus::Active),
+            ],
+            Some(switch),
+        );
+
+        assert_eq!(summary.active_environment, Some(DeploymentEnvironment::Blue));
+        assert_eq!(summary.blue.as_ref().unwrap().status, DeploymentStatus::Active);
+        assert_eq!(summary.green.as_ref().unwrap().status, DeploymentStatus::Testing);
+
+        let body = serde_json::to_value(&summary).unwrap();
+        let blue = serde_json::to_value(DeploymentEnvironment::Blue).unwrap();
+        assert_eq!(body["active_environment"], blue);
+        assert_eq!(body["green"]["health_checks_passed"], 4);
+        assert_eq!(body["last_switch"]["to_environment"], blue);
+    }
+
+    #[test]
+    fn summary_without_deployments_is_empty_but_structured() {
+        let body = serde_json::to_value(DeploymentSummary::build(Uuid::nil(), vec![], None)).unwrap();
+
+        assert!(body["active_environment"].is_null());
+        assert!(body["blue"].is_null());
+        assert!(body["green"].is_null());
+        assert!(body["last_switch"].is_null());
+    }
+
+    const ACTIVE_SPEC: &str = r#"[
+        {"type": "function", "name": "transfer",
+         "inputs": [{"name": "to", "value": {"type": "Address"}}, {"name": "amount", "value": {"type": "i128"}}],
+         "outputs": [{"type": "bool"}]},
+        {"type": "function", "name": "balance",
+         "inputs": [{"name": "id", "value": {"type": "Address"}}],
+         "outputs": [{"type": "i128"}]},
+        {"type": "function", "name": "burn",
+         "inputs": [{"name": "amount", "value": {"type": "i128"}}],
+         "outputs": []},
+        {"type": "function", "name": "name", "inputs": [], "outputs": [{"type": "string"}]}
+    ]"#;
+
+    const CANDIDATE_SPEC: &str = r#"[
+        {"type": "function", "name": "transfer",
+         "inputs": [{"name": "to", "value": {"type": "Address"}}, {"name": "amount", "value": {"type": "u64"}}],
+         "outputs": [{"type": "bool"}]},
+        {"type": "function", "name": "balance",
+         "inputs": [{"name": "owner", "value": {"type": "Address"}}],
+         "outputs": [{"type": "i128"}]},
+        {"type": "function", "name": "name", "inputs": [], "outputs": [{"type": "string"}]},
+        {"type": "function", "name": "mint",
+         "inputs": [{"name": "to", "value": {"type": "Address"}}, {"name": "amount", "value": {"type": "i128"}}],
+         "outputs": []}
+    ]"#;
+
+    #[test]
+    fn abi_diff_lists_added_removed_and_changed_methods() {
+        let active = parse_json_spec(ACTIVE_SPEC, "blue").unwrap();
+        let candidate = parse_json_spec(CANDIDATE_SPEC, "green").unwrap();
+
+        let diff = method_diff(&active, &candidate);
+
+        assert_eq!(diff.added, vec!["mint"]);
+        assert_eq!(diff.removed, vec!["burn"]);
+        let changed: Vec<(&str, bool)> = diff.changed.iter().map(|m| (m.name.as_str(), m.breaking)).collect();
+        assert_eq!(changed, vec![("balance", false), ("transfer", true)]);
+        assert!(diff.changed[1].changes[0].contains("amount"));
+        assert!(diff.breaking);
+    }
+
+    #[test]
+    fn identical_abis_have_an_empty_diff() {
+        let spec = parse_json_spec(ACTIVE_SPEC, "blue").unwrap();
+        assert_eq!(method_diff(&spec, &spec), AbiDiff::default());
+
+        // Only additions are safe for existing callers
+        let fewer = parse_json_spec(&ACTIVE_SPEC.replace(r#""name": "name""#, r#""name": "symbol""#), "b").unwrap();
+        let more = parse_json_spec(ACTIVE_SPEC, "g").unwrap();
+        let diff = method_diff(&fewer, &more);
+        assert_eq!(diff.added, vec!["name"]);
+        assert_eq!(diff.removed, vec!["symbol"]);
+        assert!(diff.breaking);
+    }
+
+    #[test]
+    fn sides_follow_the_active_deployment() {
+        let blue_active = vec![
+            deployment(DeploymentEnvironment::Green, DeploymentStatus::Testing),
+            deployment(DeploymentEnvironment::Blue, DeploymentStatus::Active),
+        ];
+        let (active, candidate) = pick_sides(&blue_active).unwrap();
+        assert_eq!(active.environment, DeploymentEnvironment::Blue);
+        assert_eq!(candidate.environment, DeploymentEnvironment::Green);
+
+        let green_active = vec![
+            deployment(DeploymentEnvironment::Green, DeploymentStatus::Active),
+            deployment(DeploymentEnvironment::Blue, DeploymentStatus::Inactive),
+        ];
+        let (active, _) = pick_sides(&green_active).unwrap();
+        assert_eq!(active.environment, DeploymentEnvironment::Green);
+
+        let none_active = vec![
+            deployment(DeploymentEnvironment::Green, DeploymentStatus::Testing),
+            deployment(DeploymentEnvironment::Blue, DeploymentStatus::Testing),
+        ];
+        assert_eq!(pick_sides(&none_active).unwrap().0.environment, DeploymentEnvironment::Blue);
+
+        assert!(pick_sides(&blue_active[..1]).is_none());
+    }
+}
@@ -5,19 +5,30 @@ use axum::{
 };
 use chrono::{SecondsFormat, Utc};
 use serde::Serialize;
+use serde_json::Value;
 use uuid::Uuid;
 
+use crate::validation::{FieldError, ValidationError};
+
 #[derive(Debug)]
 pub struct ApiError {
     status: StatusCode,
     error: String,
     message: String,
+    /// Per-field problems, for validation failures
+    errors: Vec<FieldError>,
+    /// Machine-readable context, e.g. the candidates of an ambiguous lookup
+    details: Option<Value>,
 }
 
 #[derive(Debug, Serialize)]
 struct ErrorResponse {
     error: String,
     message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<FieldError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details: Option<Value>,
     code: u16,
     timestamp: String,
     correlation_id: String,
@@ -29,9 +40,16 @@ impl ApiError {
             status,
             error: error.into(),
             message: message.into(),
+            errors: Vec::new(),
+            details: None,
         }
     }
 
+    pub fn with_details(mut self, details: Value) -> Self {
+        self.details = Some(details);
+        self
+    }
+
     pub fn bad_request(error: impl Into<String>, message: impl Into<String>) -> Self {
         Self::new(StatusCode::BAD_REQUEST, error, message)
     }
@@ -67,6 +85,8 @@ impl IntoResponse for ApiError {
         let payload = ErrorResponse {
             error: self.error,
             message: self.message,
+            errors: self.errors,
+            details: self.details,
             code: self.status.as_u16(),
             timestamp: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true),
             correlation_id: correlation_id.clone(),
@@ -82,4 +102,17 @@ impl IntoResponse for ApiError {
     }
 }
 
+impl From<ValidationError> for ApiError {
+    fn from(err: ValidationError) -> Self {
+        let message = match err.errors.as_slice() {
+            [only] => format!("Validation failed for field '{}'", only.field),
+            errors => format!("Validation failed for {} fields", errors.len()),
+        };
+        Self {
+            errors: err.errors,
+            ..Self::bad_request("ValidationError", message)
+        }
+    }
+}
+
 pub type ApiResult<T> = std::result::Result<T, ApiError>;
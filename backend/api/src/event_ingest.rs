@@ -0,0 +1,314 @@
+// api/src/event_ingest.rs
+// Bulk analytics event ingestion for the indexer and other high-volume reporters.
+//
+//   POST /api/events/batch — up to 1000 events
+//
+// Each item is validated on its own; invalid items are reported by index and
+// the rest are written with a single multi-row INSERT (one statement, so one
+// round-trip and one transaction). Events for contracts that do not exist are
+// dropped by the insert itself and reported as rejected.
+
+use axum::{
+    extract::{rejection::JsonRejection, State},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::{AnalyticsEventType, Network};
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// Largest batch accepted in one request
+pub const MAX_EVENT_BATCH: usize = 1000;
+
+/// Largest serialized `metadata` accepted per event
+const MAX_METADATA_BYTES: usize = 16 * 1024;
+
+/// Tolerated clock skew for reporter-supplied timestamps
+const MAX_FUTURE_SKEW_SECONDS: i64 = 300;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EventInput {
+    pub event_type: AnalyticsEventType,
+    pub contract_id: Uuid,
+    pub user_address: Option<String>,
+    pub network: Option<Network>,
+    pub metadata: Option<Value>,
+    /// When the event happened; defaults to the time of ingestion
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EventError {
+    pub index: usize,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchEventResponse {
+    pub accepted: usize,
+    pub rejected: usize,
+    pub errors: Vec<EventError>,
+}
+
+/// Parse and check one item.
+pub fn validate_event(item: Value, now: DateTime<Utc>) -> Result<EventInput, String> {
+    let event: EventInput = serde_json::from_value(item).map_err(|e| format!("Invalid event: {}", e))?;
+
+    if let Some(address) = &event.user_address {
+        crate::validation::validate_stellar_address(address).map_err(|e| format!("user_address {}", e))?;
+    }
+    if let Some(metadata) = &event.metadata {
+        if !metadata.is_object() {
+            return Err("metadata must be a JSON object".to_string());
+        }
+        if metadata.to_string().len() > MAX_METADATA_BYTES {
+            return Err(format!("metadata exceeds {} bytes", MAX_METADATA_BYTES));
+        }
+    }
+    if let Some(created_at) = event.created_at {
+        if created_at > now + Duration::seconds(MAX_FUTURE_SKEW_SECONDS) {
+            return Err("created_at is in the future".to_string());
+        }
+    }
+    Ok(event)
+}
+
+/// Split a batch into valid events (with their original index) and per-index errors.
+pub fn partition_batch(items: Vec<Value>, now: DateTime<Utc>) -> (Vec<(usize, EventInput)>, Vec<EventError>) {
+    let mut valid = Vec::with_capacity(items.len());
+    let mut errors = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        match validate_event(item, now) {
+            Ok(event) => valid.push((index, event)),
+            Err(error) => errors.push(EventError { index, error }),
+        }
+    }
+    (valid, errors)
+}
+
+/// The valid events as parallel arrays, bound to the bulk INSERT as one
+/// parameter per column.
+#[derive(Debug, Default, PartialEq)]
+pub struct EventColumns {
+    pub indexes: Vec<i32>,
+    pub event_types: Vec<String>,
+    pub contract_ids: Vec<Uuid>,
+    pub user_addresses: Vec<Option<String>>,
+    pub networks: Vec<Option<String>>,
+    pub metadata: Vec<String>,
+    pub created_at: Vec<DateTime<Utc>>,
+}
+
+impl EventColumns {
+    pub fn build(events: &[(usize, EventInput)], now: DateTime<Utc>) -> Self {
+        let mut columns = Self::default();
+        for (index, event) in events {
+            columns.indexes.push(*index as i32);
+            columns.event_types.push(event.event_type.to_string());
+            columns.contract_ids.push(event.contract_id);
+            columns.user_addresses.push(event.user_address.as_deref().map(|a| a.trim().to_string()));
+            columns.networks.push(event.network.as_ref().map(Network::to_string));
+            columns
+                .metadata
+                .push(event.metadata.clone().unwrap_or_else(|| serde_json::json!({})).to_string());
+            columns.created_at.push(event.created_at.unwrap_or(now));
+        }
+        columns
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.indexes.is_empty()
+    }
+}
+
+/// Every event in one statement. Rows whose contract does not exist are
+/// filtered out by the join; the indexes of the inserted rows are returned.
+const BULK_INSERT_SQL: &str = r#"
+    WITH known AS (
+        SELECT e.*
+        FROM UNNEST($1::int4[], $2::text[], $3::uuid[], $4::text[], $5::text[], $6::text[], $7::timestamptz[])
+             AS e(idx, event_type, contract_id, user_address, network, metadata, created_at)
+        JOIN contracts c ON c.id = e.contract_id
+    ), inserted AS (
+        INSERT INTO analytics_events (event_type, contract_id, user_address, network, metadata, created_at)
+        SELECT event_type::analytics_event_type, contract_id, user_address,
+               network::network_type, metadata::jsonb, created_at
+        FROM known
+    )
+    SELECT idx FROM known
+"#;
+
+fn map_json_rejection(err: JsonRejection) -> ApiError {
+    ApiError::bad_request("InvalidRequest", format!("Invalid JSON payload: {}", err.body_text()))
+}
+
+/// POST /api/events/batch
+pub async fn ingest_events_batch(
+    State(state): State<AppState>,
+    payload: Result<Json<Vec<Value>>, JsonRejection>,
+) -> ApiResult<Json<BatchEventResponse>> {
+    let Json(items) = payload.map_err(map_json_rejection)?;
+    if items.is_empty() {
+        return Err(ApiError::bad_request("EmptyBatch", "Batch must contain at least one event"));
+    }
+    if items.len() > MAX_EVENT_BATCH {
+        return Err(ApiError::bad_request(
+            "BatchTooLarge",
+            format!("Batch exceeds the maximum of {} events", MAX_EVENT_BATCH),
+        ));
+    }
+
+    let now = Utc::now();
+    let (valid, mut errors) = partition_batch(items, now);
+    let columns = EventColumns::build(&valid, now);
+
+    let inserted: HashSet<i32> = if columns.is_empty() {
+        HashSet::new()
+    } else {
+        sqlx::query_scalar::<_, i32>(BULK_INSERT_SQL)
+            .bind(&columns.indexes)
+            .bind(&columns.event_types)
+            .bind(&columns.contract_ids)
+            .bind(&columns.user_addresses)
+            .bind(&columns.networks)
+            .bind(&columns.metadata)
+            .bind(&columns.created_at)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| crate::db_timeout::map_db_error("insert analytics event batch", err))?
+            .into_iter()
+            .collect()
+    };
+
+    for (index, event) in &valid {
+        if !inserted.contains(&(*index as i32)) {
+            errors.push(EventError {
+                index: *index,
+                error: format!("No contract found with ID: {}", event.contract_id),
+            });
+        }
+    }
+    errors.sort_by_key(|e| e.index);
+
+    tracing::debug!(accepted = inserted.len(), rejected = errors.len(), "analytics event batch ingested");
+    Ok(Json(BatchEventResponse {
+        accepted: inserted.len(),
+        rejected: errors.len(),
+        errors,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use serde_json::json;
+
+    const USER: &str = "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    fn event(n: u128) -> Value {
+        json!({
+            "event_type": "ContractDeployed",
+            "contract_id": Uuid::from_u128(n),
+            "user_address": USER,
+            "network": "testnet",
+        })
+    }
+
+    fn mixed_batch() -> Vec<Value> {
+        vec![
+            event(1),
+            json!({ "event_type": "ContractExploded", "contract_id": Uuid::from_u128(2) }),
+            json!({ "event_type": "VersionCreated", "contract_id": Uuid::from_u128(3), "metadata": {"version": "1.2.0"} }),
+            json!({ "event_type": "ContractVerified", "contract_id": Uuid::from_u128(4), "user_address": "nope" }),
+            json!({ "event_type": "ContractPublished", "contract_id": Uuid::from_u128(5), "metadata": [1, 2] }),
+            json!({
+                "event_type": "ContractDeployed",
+                "contract_id": Uuid::from_u128(6),
+                "created_at": "2026-10-17T12:00:00Z",
+            }),
+            json!("not an object"),
+            event(8),
+        ]
+    }
+
+    #[test]
+    fn mixed_batch_reports_errors_by_index() {
+        let (valid, errors) = partition_batch(mixed_batch(), now());
+
+        let valid_indexes: Vec<usize> = valid.iter().map(|(i, _)| *i).collect();
+        assert_eq!(valid_indexes, vec![0, 2, 7]);
+
+        let error_indexes: Vec<usize> = errors.iter().map(|e| e.index).collect();
+        assert_eq!(error_indexes, vec![1, 3, 4, 5, 6]);
+        assert!(errors[0].error.contains("ContractExploded"));
+        assert!(errors[1].error.starts_with("user_address"));
+        assert!(errors[2].error.contains("JSON object"));
+        assert!(errors[3].error.contains("future"));
+    }
+
+    #[test]
+    fn valid_events_become_one_row_per_column_array() {
+        let (valid, _) = partition_batch(mixed_batch(), now());
+        let columns = EventColumns::build(&valid, now());
+
+        // One array per column, all aligned: the whole batch binds to a single statement
+        assert_eq!(columns.indexes, vec![0, 2, 7]);
+        assert_eq!(columns.event_types, vec!["contract_deployed", "version_created", "contract_deployed"]);
+        assert_eq!(columns.contract_ids[1], Uuid::from_u128(3));
+        assert_eq!(columns.user_addresses, vec![Some(USER.to_string()), None, Some(USER.to_string())]);
+        assert_eq!(columns.networks, vec![Some("testnet".to_string()), None, Some("testnet".to_string())]);
+        assert_eq!(columns.metadata, vec!["{}", r#"{"version":"1.2.0"}"#, "{}"]);
+        assert!(columns.created_at.iter().all(|t| *t == now()));
+        assert_eq!(BULK_INSERT_SQL.matches("INSERT").count(), 1);
+    }
+
+    #[test]
+    fn reporter_timestamps_within_the_skew_are_kept() {
+        let at = now() + Duration::seconds(MAX_FUTURE_SKEW_SECONDS - 1);
+        let item = json!({ "event_type": "ContractDeployed", "contract_id": Uuid::nil(), "created_at": at });
+        let event = validate_event(item, now()).unwrap();
+        assert_eq!(event.created_at, Some(at));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn bulk_insert_writes_known_contracts_in_one_statement() {
+        let pool = crate::fixtures::test_pool().await;
+        let known = crate::fixtures::fixtures().contracts[0].id;
+
+        let mut items: Vec<Value> = (0..500)
+            .map(|_| json!({ "event_type": "ContractDeployed", "contract_id": known }))
+            .collect();
+        items.push(event(0xdead));
+        let (valid, _) = partition_batch(items, Utc::now());
+        let columns = EventColumns::build(&valid, Utc::now());
+
+        let inserted: Vec<i32> = sqlx::query_scalar(BULK_INSERT_SQL)
+            .bind(&columns.indexes)
+            .bind(&columns.event_types)
+            .bind(&columns.contract_ids)
+            .bind(&columns.user_addresses)
+            .bind(&columns.networks)
+            .bind(&columns.metadata)
+            .bind(&columns.created_at)
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(inserted.len(), 500);
+        assert!(!inserted.contains(&500));
+    }
+}
@@ -0,0 +1,310 @@
+// api/src/feed.rs
+// Subscribable feed of newly published contracts.
+//
+//   GET /api/feed[?tag=defi][&category=DeFi][&format=atom|rss]
+//
+// Atom is the default. Entry ids are `urn:uuid:<registry id>`, so a contract
+// keeps the same id across refreshes and feed readers never show it twice.
+// Links point at `PUBLIC_BASE_URL` (default `http://localhost:3001`).
+
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use shared::Contract;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+/// Entries per feed
+pub const FEED_SIZE: i64 = 50;
+
+const DEFAULT_BASE_URL: &str = "http://localhost:3001";
+const FEED_CACHE_CONTROL: &str = "public, max-age=300";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedFormat {
+    #[default]
+    Atom,
+    Rss,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct FeedQuery {
+    pub tag: Option<String>,
+    pub category: Option<String>,
+    #[serde(default)]
+    pub format: FeedFormat,
+}
+
+fn base_url() -> String {
+    std::env::var("PUBLIC_BASE_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| url.trim_end_matches('/').to_string())
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn feed_title(query: &FeedQuery) -> String {
+    let mut filters = Vec::new();
+    if let Some(tag) = &query.tag {
+        filters.push(format!("tag \"{}\"", tag));
+    }
+    if let Some(category) = &query.category {
+        filters.push(format!("category \"{}\"", category));
+    }
+    if filters.is_empty() {
+        "Soroban Registry: new contracts".to_string()
+    } else {
+        format!("Soroban Registry: new contracts with {}", filters.join(" and "))
+    }
+}
+
+fn entry_summary(contract: &Contract) -> String {
+    let description = contract.description.as_deref().unwrap_or("No description");
+    format!("{} ({} on {})", description, contract.contract_id, contract.network)
+}
+
+/// Render an Atom 1.0 document. `self_url` is the feed's own URL.
+pub fn render_atom(title: &str, self_url: &str, base: &str, contracts: &[Contract]) -> String {
+    let updated = contracts
+        .iter()
+        .map(|c| c.created_at)
+        .max()
+        .unwrap_or(DateTime::<Utc>::UNIX_EPOCH);
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<feed xmlns=\"http://www.w3.org/2005/Atom\">\n");
+    xml.push_str(&format!("  <title>{}</title>\n", escape_xml(title)));
+    xml.push_str(&format!("  <id>{}</id>\n", escape_xml(self_url)));
+    xml.push_str(&format!("  <link rel=\"self\" href=\"{}\"/>\n", escape_xml(self_url)));
+    xml.push_str(&format!("  <updated>{}</updated>\n", updated.to_rfc3339()));
+    for contract in contracts {
+        xml.push_str("  <entry>\n");
+        xml.push_str(&format!("    <id>urn:uuid:{}</id>\n", contract.id));
+        xml.push_str(&format!("    <title>{}</title>\n", escape_xml(&contract.name)));
+        xml.push_str(&format!(
+            "    <link href=\"{}/api/contracts/{}\"/>\n",
+            escape_xml(base),
+            contract.id
+        ));
+        xml.push_str(&format!("    <published>{}</published>\n", contract.created_at.to_rfc3339()));
+        xml.push_str(&format!("    <updated>{}</updated>\n", contract.updated_at.to_rfc3339()));
+        xml.push_str(&format!("    <summary>{}</summary>\n", escape_xml(&entry_summary(contract))));
+        for tag in &contract.tags {
+            xml.push_str(&format!("    <category term=\"{}\"/>\n", escape_xml(tag)));
+        }
+        xml.push_str("  </entry>\n");
+    }
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Render an RSS 2.0 document.
+pub fn render_rss(title: &str, self_url: &str, base: &str, contracts: &[Contract]) -> String {
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+    xml.push_str("<rss version=\"2.0\">\n  <channel>\n");
+    xml.push_str(&format!("    <title>{}</title>\n", escape_xml(title)));
+    xml.push_str(&format!("    <link>{}</link>\n", escape_xml(self_url)));
+    xml.push_str(&format!("    <description>{}</description>\n", escape_xml(title)));
+    for contract in contracts {
+        xml.push_str("    <item>\n");
+        xml.push_str(&format!("      <guid isPermaLink=\"false\">urn:uuid:{}</guid>\n", contract.id));
+        xml.push_str(&format!("      <title>{}</title>\n", escape_xml(&contract.name)));
+        xml.push_str(&format!(
+            "      <link>{}/api/contracts/{}</link>\n",
+            escape_xml(base),
+            contract.id
+        ));
+        xml.push_str(&format!("      <pubDate>{}</pubDate>\n", contract.created_at.to_rfc2822()));
+        xml.push_str(&format!("      <description>{}</description>\n", escape_xml(&entry_summary(contract))));
+        for tag in &contract.tags {
+            xml.push_str(&format!("      <category>{}</category>\n", escape_xml(tag)));
+        }
+        xml.push_str("    </item>\n");
+    }
+    xml.push_str("  </channel>\n</rss>\n");
+    xml
+}
+
+fn self_url(base: &str, query: &FeedQuery) -> String {
+    let mut params = Vec::new();
+    if let Some(tag) = &query.tag {
+        params.push(("tag", tag.as_str()));
+    }
+    if let Some(category) = &query.category {
+        params.push(("category", category.as_str()));
+    }
+    if query.format == FeedFormat::Rss {
+        params.push(("format", "rss"));
+    }
+    let feed = format!("{}/api/feed", base);
+    match reqwest::Url::parse_with_params(&feed, &params) {
+        Ok(url) if !params.is_empty() => url.to_string(),
+        _ => feed,
+    }
+}
+
+/// GET /api/feed
+pub async fn get_feed(State(state): State<AppState>, Query(mut query): Query<FeedQuery>) -> ApiResult<Response> {
+    query.tag = query.tag.map(|t| t.trim().to_string()).filter(|t| !t.is_empty());
+    query.category = query.category.map(|c| c.trim().to_string()).filter(|c| !c.is_empty());
+    if query.tag.as_ref().is_some_and(|t| t.len() > 100) {
+        return Err(ApiError::bad_request("InvalidTag", "tag must be at most 100 characters"));
+    }
+
+    let contracts: Vec<Contract> = sqlx::query_as(
+        "SELECT * FROM contracts
+         WHERE pending_review = false
+           AND deleted_at IS NULL
+           AND ($1::text IS NULL OR $1 = ANY(tags))
+           AND ($2::text IS NULL OR category = $2)
+         ORDER BY created_at DESC, id
+         LIMIT $3",
+    )
+    .bind(&query.tag)
+    .bind(&query.category)
+    .bind(FEED_SIZE)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load contract feed", err))?;
+
+    let base = base_url();
+    let title = feed_title(&query);
+    let url = self_url(&base, &query);
+    let (body, content_type) = match query.format {
+        FeedFormat::Atom => (
+            render_atom(&title, &url, &base, &contracts),
+            "application/atom+xml; charset=utf-8",
+        ),
+        FeedFormat::Rss => (
+            render_rss(&title, &url, &base, &contracts),
+            "application/rss+xml; charset=utf-8",
+        ),
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type),
+            (header::CACHE_CONTROL, FEED_CACHE_CONTROL),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    fn contract(n: u128, name: &str, tags: &[&str]) -> Contract {
+        let at = Utc.with_ymd_and_hms(2026, 3, n as u32, 12, 0, 0).unwrap();
+        serde_json::from_value(serde_json::json!({
+            "id": Uuid::from_u128(n),
+            "contract_id": format!("C{}", n),
+            "wasm_hash": "00",
+            "name": name,
+            "description": "Swaps & pools",
+            "publisher_id": Uuid::nil(),
+            "network": "testnet",
+            "is_verified": true,
+            "category": "DeFi",
+            "tags": tags,
+            "created_at": at,
+            "updated_at": at,
+        }))
+        .unwrap()
+    }
+
+    fn sample() -> Vec<Contract> {
+        vec![contract(2, "AMM <v2>", &["defi", "amm"]), contract(1, "Lending", &["defi"])]
+    }
+
+    #[test]
+    fn atom_feed_has_one_entry_per_contract_with_stable_ids() {
+        let xml = render_atom("New", "http://x/api/feed?tag=defi", "http://x", &sample());
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"utf-8\"?>"));
+        assert!(xml.contains("<feed xmlns=\"http://www.w3.org/2005/Atom\">"));
+        assert!(xml.trim_end().ends_with("</feed>"));
+        assert_eq!(xml.matches("<entry>").count(), 2);
+        assert_eq!(xml.matches("</entry>").count(), 2);
+        assert!(xml.contains(&format!("<id>urn:uuid:{}</id>", Uuid::from_u128(2))));
+        assert!(xml.contains(&format!("<link href=\"http://x/api/contracts/{}\"/>", Uuid::from_u128(1))));
+        assert!(xml.contains("<updated>2026-03-02T12:00:00+00:00</updated>"));
+        assert!(xml.contains("<category term=\"amm\"/>"));
+
+        // Same input, same document
+        assert_eq!(xml, render_atom("New", "http://x/api/feed?tag=defi", "http://x", &sample()));
+    }
+
+    #[test]
+    fn text_is_escaped() {
+        let xml = render_atom("New", "http://x/api/feed?tag=a&category=b", "http://x", &sample());
+        assert!(xml.contains("<title>AMM &lt;v2&gt;</title>"));
+        assert!(xml.contains("Swaps &amp; pools"));
+        assert!(xml.contains("tag=a&amp;category=b"));
+        assert!(!xml.contains("<v2>"));
+    }
+
+    #[test]
+    fn rss_feed_structure() {
+        let xml = render_rss("New", "http://x/api/feed?format=rss", "http://x", &sample());
+        assert!(xml.contains("<rss version=\"2.0\">"));
+        assert_eq!(xml.matches("<item>").count(), 2);
+        assert!(xml.contains(&format!("<guid isPermaLink=\"false\">urn:uuid:{}</guid>", Uuid::from_u128(1))));
+        assert!(xml.contains("<pubDate>Mon, "));
+        assert!(xml.contains("Mar 2026 12:00:00 +0000</pubDate>"));
+    }
+
+    #[test]
+    fn empty_feed_is_still_valid() {
+        let xml = render_atom("New", "http://x/api/feed", "http://x", &[]);
+        assert!(!xml.contains("<entry>"));
+        assert!(xml.contains("<updated>1970-01-01T00:00:00+00:00</updated>"));
+    }
+
+    #[test]
+    fn title_and_self_url_reflect_filters() {
+        let query = FeedQuery {
+            tag: Some("defi".into()),
+            category: None,
+            format: FeedFormat::Rss,
+        };
+        assert_eq!(feed_title(&query), "Soroban Registry: new contracts with tag \"defi\"");
+        assert_eq!(self_url("http://x", &query), "http://x/api/feed?tag=defi&format=rss");
+        assert_eq!(self_url("http://x", &FeedQuery::default()), "http://x/api/feed");
+
+        let spaced = FeedQuery {
+            tag: Some("a&b c".into()),
+            ..FeedQuery::default()
+        };
+        assert_eq!(self_url("http://x", &spaced), "http://x/api/feed?tag=a%26b+c");
+    }
+}
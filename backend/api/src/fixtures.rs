@@ -0,0 +1,364 @@
+// api/src/fixtures.rs
+// Deterministic sample data for tests and demos.
+//
+//   cargo run --bin api -- seed
+//
+// Every row has a fixed id, address and timestamp, and every insert is
+// `ON CONFLICT DO NOTHING`, so seeding is safe to rerun and always produces
+// the same data. Seeding refuses to run unless `ALLOW_SEED` is set, so it
+// cannot be triggered against production by accident.
+
+use anyhow::{bail, Result};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use shared::{AnalyticsEventType, Network};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{onchain::env_flag, stellar};
+
+/// Environment flag that must be set for `seed` to run
+pub const ALLOW_SEED_ENV: &str = "ALLOW_SEED";
+
+/// High bits shared by every fixture id, so fixture rows are easy to spot
+const FIXTURE_ID_PREFIX: u128 = 0x5eed_0000_0000_4000_8000_0000_0000_0000;
+
+const PUBLISHERS: usize = 3;
+const CONTRACTS_PER_PUBLISHER: usize = 2;
+const VERSIONS: [&str; 3] = ["1.0.0", "1.1.0", "2.0.0"];
+const EVENTS_PER_CONTRACT: usize = 6;
+
+const CATEGORIES: [&str; 3] = ["DeFi", "NFT", "Governance"];
+const NETWORKS: [Network; 2] = [Network::Testnet, Network::Mainnet];
+
+#[derive(Debug, Clone, Copy)]
+enum FixtureKind {
+    Publisher = 1,
+    Contract = 2,
+    Version = 3,
+    Event = 4,
+}
+
+fn fixture_id(kind: FixtureKind, n: usize) -> Uuid {
+    Uuid::from_u128(FIXTURE_ID_PREFIX | ((kind as u128) << 32) | n as u128)
+}
+
+/// A fixed 32-byte key payload: a "seed" marker, the key's role and its index
+fn fixture_key(role: u8, n: usize) -> [u8; 32] {
+    let mut payload = [0u8; 32];
+    payload[..4].copy_from_slice(b"seed");
+    payload[4] = role;
+    payload[24..].copy_from_slice(&(n as u64).to_be_bytes());
+    payload
+}
+
+fn base_time() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublisherFixture {
+    pub id: Uuid,
+    pub stellar_address: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct ContractFixture {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub wasm_hash: String,
+    pub name: String,
+    pub publisher_address: String,
+    pub network: Network,
+    pub category: String,
+    pub is_verified: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionFixture {
+    pub id: Uuid,
+    pub contract: Uuid,
+    pub version: String,
+    pub wasm_hash: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct EventFixture {
+    pub id: Uuid,
+    pub contract: Uuid,
+    pub event_type: AnalyticsEventType,
+    pub user_address: String,
+    pub network: Network,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Fixtures {
+    pub publishers: Vec<PublisherFixture>,
+    pub contracts: Vec<ContractFixture>,
+    pub versions: Vec<VersionFixture>,
+    pub events: Vec<EventFixture>,
+}
+
+/// The fixture set. Pure and deterministic.
+pub fn fixtures() -> Fixtures {
+    let publishers: Vec<PublisherFixture> = (0..PUBLISHERS)
+        .map(|p| PublisherFixture {
+            id: fixture_id(FixtureKind::Publisher, p),
+            stellar_address: stellar::encode_account_id(&fixture_key(b'p', p)),
+            username: format!("fixture-publisher-{}", p),
+        })
+        .collect();
+
+    let mut contracts = Vec::new();
+    for (p, publisher) in publishers.iter().enumerate() {
+        for c in 0..CONTRACTS_PER_PUBLISHER {
+            let n = p * CONTRACTS_PER_PUBLISHER + c;
+            contracts.push(ContractFixture {
+                id: fixture_id(FixtureKind::Contract, n),
+                contract_id: stellar::encode_contract_id(&fixture_key(b'c', n)),
+                wasm_hash: format!("{:0>64x}", n),
+                name: format!("Fixture Contract {}", n),
+                publisher_address: publisher.stellar_address.clone(),
+                network: NETWORKS[n % NETWORKS.len()].clone(),
+                category: CATEGORIES[n % CATEGORIES.len()].to_string(),
+                is_verified: n.is_multiple_of(2),
+            });
+        }
+    }
+
+    let mut versions = Vec::new();
+    let mut events = Vec::new();
+    for (n, contract) in contracts.iter().enumerate() {
+        for (v, version) in VERSIONS.iter().enumerate() {
+            let index = n * VERSIONS.len() + v;
+            versions.push(VersionFixture {
+                id: fixture_id(FixtureKind::Version, index),
+                contract: contract.id,
+                version: version.to_string(),
+                wasm_hash: format!("{:0>64x}", 0x1000 + index),
+                created_at: base_time() + Duration::days((n * 7 + v * 30) as i64),
+            });
+        }
+
+        for e in 0..EVENTS_PER_CONTRACT {
+            let index = n * EVENTS_PER_CONTRACT + e;
+            let event_type = match e % 3 {
+                0 => AnalyticsEventType::ContractDeployed,
+                1 => AnalyticsEventType::VersionCreated,
+                _ => AnalyticsEventType::ContractVerified,
+            };
+            events.push(EventFixture {
+                id: fixture_id(FixtureKind::Event, index),
+                contract: contract.id,
+                event_type,
+                user_address: stellar::encode_account_id(&fixture_key(b'u', e % 4)),
+                network: contract.network.clone(),
+                created_at: base_time() + Duration::hours((index * 5) as i64),
+            });
+        }
+    }
+
+    Fixtures {
+        publishers,
+        contracts,
+        versions,
+        events,
+    }
+}
+
+/// Rows of each kind the fixture set contributes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SeedCounts {
+    pub publishers: i64,
+    pub contracts: i64,
+    pub versions: i64,
+    pub events: i64,
+}
+
+pub fn ensure_seeding_allowed() -> Result<()> {
+    if !env_flag(ALLOW_SEED_ENV) {
+        bail!(
+            "Refusing to seed: set {}=true to insert fixture data (never in production)",
+            ALLOW_SEED_ENV
+        );
+    }
+    Ok(())
+}
+
+/// Insert the fixture set. Existing fixture rows are left untouched.
+pub async fn seed(pool: &PgPool) -> Result<SeedCounts> {
+    ensure_seeding_allowed()?;
+    let data = fixtures();
+    let mut tx = pool.begin().await?;
+
+    for publisher in &data.publishers {
+        sqlx::query(
+            "INSERT INTO publishers (id, stellar_address, username, created_at)
+             VALUES ($1, $2, $3, $4)
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(publisher.id)
+        .bind(&publisher.stellar_address)
+        .bind(&publisher.username)
+        .bind(base_time())
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for contract in &data.contracts {
+        sqlx::query(
+            "INSERT INTO contracts (id, contract_id, wasm_hash, name, description, publisher_id,
+                                    network, is_verified, category, tags, created_at, updated_at)
+             SELECT $1, $2, $3, $4, 'Deterministic fixture contract', p.id, $6, $7, $8,
+                    ARRAY['fixture'], $9, $9
+             FROM publishers p WHERE p.stellar_address = $5
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(contract.id)
+        .bind(&contract.contract_id)
+        .bind(&contract.wasm_hash)
+        .bind(&contract.name)
+        .bind(&contract.publisher_address)
+        .bind(&contract.network)
+        .bind(contract.is_verified)
+        .bind(&contract.category)
+        .bind(base_time())
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for version in &data.versions {
+        sqlx::query(
+            "INSERT INTO contract_versions (id, contract_id, version, wasm_hash, created_at)
+             VALUES ($1, $2, $3, $4, $5)
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(version.id)
+        .bind(version.contract)
+        .bind(&version.version)
+        .bind(&version.wasm_hash)
+        .bind(version.created_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    for event in &data.events {
+        sqlx::query(
+            "INSERT INTO analytics_events (id, event_type, contract_id, user_address, network, created_at)
+             VALUES ($1, $2, $3, $4, $5, $6)
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(event.id)
+        .bind(&event.event_type)
+        .bind(event.contract)
+        .bind(&event.user_address)
+        .bind(&event.network)
+        .bind(event.created_at)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(count_fixture_rows(pool).await?)
+}
+
+/// Count the fixture rows currently present.
+pub async fn count_fixture_rows(pool: &PgPool) -> Result<SeedCounts, sqlx::Error> {
+    let prefix = format!("{}%", &Uuid::from_u128(FIXTURE_ID_PREFIX).to_string()[..19]);
+    let count = |table: &'static str| {
+        let sql = format!("SELECT COUNT(*) FROM {} WHERE id::text LIKE $1", table);
+        let prefix = prefix.clone();
+        async move { sqlx::query_scalar::<_, i64>(&sql).bind(prefix).fetch_one(pool).await }
+    };
+
+    Ok(SeedCounts {
+        publishers: count("publishers").await?,
+        contracts: count("contracts").await?,
+        versions: count("contract_versions").await?,
+        events: count("analytics_events").await?,
+    })
+}
+
+/// Connect to `DATABASE_URL` and seed the fixture set, for `#[ignore]`d tests.
+///
+/// Those tests need a migrated database:
+/// `DATABASE_URL=... ALLOW_SEED=1 cargo test -- --ignored`
+#[cfg(test)]
+pub async fn test_pool() -> PgPool {
+    let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+    let pool = PgPool::connect(&url).await.expect("connect to DATABASE_URL");
+    seed(&pool).await.expect("seed fixtures");
+    pool
+}
+
+/// App state over `pool`, storing objects under the temp dir.
+#[cfg(test)]
+pub fn state_for(pool: PgPool) -> crate::state::AppState {
+    let objects = std::sync::Arc::new(crate::object_store::LocalFsStore::new(std::env::temp_dir()));
+    crate::state::AppState::new(pool, prometheus::Registry::new(), objects)
+}
+
+/// App state over [`test_pool`].
+#[cfg(test)]
+pub async fn test_state() -> crate::state::AppState {
+    state_for(test_pool().await)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_are_deterministic_and_well_formed() {
+        let a = fixtures();
+        assert_eq!(format!("{:?}", a), format!("{:?}", fixtures()));
+
+        assert_eq!(a.publishers.len(), PUBLISHERS);
+        assert_eq!(a.contracts.len(), PUBLISHERS * CONTRACTS_PER_PUBLISHER);
+        assert_eq!(a.versions.len(), a.contracts.len() * VERSIONS.len());
+        assert_eq!(a.events.len(), a.contracts.len() * EVENTS_PER_CONTRACT);
+
+        for contract in &a.contracts {
+            assert!(crate::validation::validate_contract_id(&contract.contract_id).is_ok());
+            assert!(crate::validation::validate_stellar_address(&contract.publisher_address).is_ok());
+            assert_eq!(contract.wasm_hash.len(), 64);
+        }
+    }
+
+    #[test]
+    fn fixture_ids_are_unique_and_share_a_prefix() {
+        let data = fixtures();
+        let mut ids: Vec<Uuid> = data.publishers.iter().map(|p| p.id).collect();
+        ids.extend(data.contracts.iter().map(|c| c.id));
+        ids.extend(data.versions.iter().map(|v| v.id));
+        ids.extend(data.events.iter().map(|e| e.id));
+
+        let unique: std::collections::HashSet<_> = ids.iter().collect();
+        assert_eq!(unique.len(), ids.len());
+        let prefix = &Uuid::from_u128(FIXTURE_ID_PREFIX).to_string()[..19];
+        assert!(ids.iter().all(|id| id.to_string().starts_with(prefix)));
+    }
+
+    #[test]
+    fn seeding_requires_the_env_flag() {
+        std::env::remove_var(ALLOW_SEED_ENV);
+        assert!(ensure_seeding_allowed().is_err());
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn seeding_twice_yields_the_same_row_counts() {
+        std::env::set_var(ALLOW_SEED_ENV, "true");
+        let pool = test_pool().await;
+
+        let first = seed(&pool).await.unwrap();
+        let second = seed(&pool).await.unwrap();
+
+        assert_eq!(first, second);
+        let data = fixtures();
+        assert_eq!(first.contracts, data.contracts.len() as i64);
+        assert_eq!(first.events, data.events.len() as i64);
+    }
+}
@@ -1,26 +1,229 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Extension, Path, State},
     http::StatusCode,
     Json,
 };
-use chrono::{Duration, Utc};
+use chrono::{DateTime, Duration, Utc};
 use shared::models::{
-    CastVoteRequest, CreateProposalRequest, GovernanceProposal, GovernanceVote, ProposalResults,
-    ProposalStatus, VoteDelegation,
+    CastVoteRequest, CreateProposalRequest, GovernanceModel, GovernanceProposal, GovernanceVote,
+    ProposalResults, ProposalStatus, VoteChoice, VoteDelegation,
 };
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 use crate::{
+    auth_middleware::AuthContext,
     error::{ApiError, ApiResult},
     state::AppState,
 };
 
+/// Defaults used when a proposal does not set its own thresholds
+pub const DEFAULT_QUORUM_REQUIRED: i32 = 50;
+pub const DEFAULT_APPROVAL_THRESHOLD: i32 = 50;
+
+/// Voting power each publisher holds in its own right
+pub const BASE_VOTING_POWER: i64 = 1;
+
+/// A direct vote as stored in `governance_votes`
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct CastVote {
+    pub voter: Uuid,
+    pub vote_choice: VoteChoice,
+    pub voting_power: i64,
+}
+
+/// An active delegation that applies to the proposal's contract
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct ActiveDelegation {
+    pub delegator: Uuid,
+    pub delegate: Uuid,
+    /// Scoped to the proposal's contract rather than a registry-wide delegation
+    pub contract_scoped: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoteTally {
+    pub votes_for: i64,
+    pub votes_against: i64,
+    pub votes_abstain: i64,
+}
+
+impl VoteTally {
+    pub fn total(&self) -> i64 {
+        self.votes_for + self.votes_against + self.votes_abstain
+    }
+
+    fn add(&mut self, choice: &VoteChoice, power: i64) {
+        match choice {
+            VoteChoice::For => self.votes_for += power,
+            VoteChoice::Against => self.votes_against += power,
+            VoteChoice::Abstain => self.votes_abstain += power,
+        }
+    }
+}
+
+/// Tally direct votes plus the power delegated to the voters.
+///
+/// Every publisher's power is counted at most once:
+/// - a delegator who votes directly is counted at their own vote, and their
+///   delegation is ignored whether it was made before or after the vote;
+/// - a contract-scoped delegation takes precedence over a registry-wide one;
+/// - power delegated to someone who has not voted is not counted.
+///
+/// Delegation is one level deep: power delegated to a delegate is not passed
+/// on through the delegate's own delegation.
+pub fn tally_votes(votes: &[CastVote], delegations: &[ActiveDelegation]) -> VoteTally {
+    let mut tally = VoteTally::default();
+    let mut choices: HashMap<Uuid, &VoteChoice> = HashMap::new();
+    for vote in votes {
+        tally.add(&vote.vote_choice, vote.voting_power);
+        choices.insert(vote.voter, &vote.vote_choice);
+    }
+
+    let mut scoped = delegations.to_vec();
+    scoped.sort_by_key(|delegation| !delegation.contract_scoped);
+    let mut counted: HashSet<Uuid> = HashSet::new();
+    for delegation in scoped {
+        if choices.contains_key(&delegation.delegator) || !counted.insert(delegation.delegator) {
+            continue;
+        }
+        if let Some(choice) = choices.get(&delegation.delegate) {
+            tally.add(choice, BASE_VOTING_POWER);
+        }
+    }
+    tally
+}
+
+/// Resolve and check a proposal's thresholds.
+///
+/// `approval_threshold` is a percentage of the voting power cast and must be
+/// within 0–100. `quorum_required` is an absolute amount of voting power and
+/// must not be negative. Out-of-range values are rejected rather than clamped,
+/// so a typo like 150 never silently becomes "unanimous".
+pub fn resolve_thresholds(quorum_required: Option<i32>, approval_threshold: Option<i32>) -> ApiResult<(i32, i32)> {
+    let quorum = quorum_required.unwrap_or(DEFAULT_QUORUM_REQUIRED);
+    let approval = approval_threshold.unwrap_or(DEFAULT_APPROVAL_THRESHOLD);
+
+    if quorum < 0 {
+        return Err(ApiError::unprocessable(
+            "InvalidQuorum",
+            format!("quorum_required is an amount of voting power and cannot be negative (got {})", quorum),
+        ));
+    }
+    if !(0..=100).contains(&approval) {
+        return Err(ApiError::unprocessable(
+            "InvalidApprovalThreshold",
+            format!("approval_threshold is a percentage and must be between 0 and 100 (got {})", approval),
+        ));
+    }
+    Ok((quorum, approval))
+}
+
+/// Earliest moment a proposal may be executed.
+///
+/// Timelock proposals (and any proposal with a positive `execution_delay_hours`)
+/// must wait out the delay after voting closes; everything else is executable
+/// as soon as voting ends.
+pub fn executable_at(proposal: &GovernanceProposal) -> DateTime<Utc> {
+    let delay_hours = proposal.execution_delay_hours.unwrap_or(0).max(0) as i64;
+    if proposal.governance_model == GovernanceModel::Timelock || delay_hours > 0 {
+        proposal.voting_ends_at + Duration::hours(delay_hours)
+    } else {
+        proposal.voting_ends_at
+    }
+}
+
+/// Reject execution attempts made before [`executable_at`].
+fn ensure_execution_window(proposal: &GovernanceProposal, now: DateTime<Utc>) -> ApiResult<()> {
+    let earliest = executable_at(proposal);
+    if now < earliest {
+        return Err(ApiError::new(
+            StatusCode::TOO_EARLY,
+            "ExecutionDelayNotElapsed",
+            format!(
+                "Proposal cannot be executed before {}",
+                earliest.to_rfc3339()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// Cancelled, rejected and already executed proposals cannot be executed.
+/// Status is not advanced to Passed when voting closes, so for an open
+/// proposal the tally decides whether it passed.
+fn ensure_executable(proposal: &GovernanceProposal) -> ApiResult<()> {
+    match proposal.status {
+        ProposalStatus::Pending | ProposalStatus::Active | ProposalStatus::Passed => Ok(()),
+        ProposalStatus::Cancelled => Err(ApiError::conflict(
+            "ProposalCancelled",
+            "Proposal has been cancelled and cannot be executed",
+        )),
+        ProposalStatus::Executed => Err(ApiError::conflict(
+            "ProposalAlreadyExecuted",
+            "Proposal has already been executed",
+        )),
+        ProposalStatus::Rejected => Err(ApiError::conflict(
+            "ProposalRejected",
+            "Proposal was rejected and cannot be executed",
+        )),
+    }
+}
+
+/// Votes are only accepted while a proposal is Pending or Active.
+fn ensure_accepting_votes(proposal: &GovernanceProposal) -> ApiResult<()> {
+    match proposal.status {
+        ProposalStatus::Pending | ProposalStatus::Active => Ok(()),
+        ProposalStatus::Cancelled => Err(ApiError::conflict(
+            "ProposalCancelled",
+            "Proposal has been cancelled and no longer accepts votes",
+        )),
+        _ => Err(ApiError::conflict(
+            "VotingClosed",
+            "Proposal is no longer accepting votes",
+        )),
+    }
+}
+
+/// A proposal can be cancelled by its proposer (or an admin) while it is
+/// Pending/Active and before voting ends.
+fn ensure_cancellable(
+    proposal: &GovernanceProposal,
+    proposer_address: &str,
+    caller: &AuthContext,
+    now: DateTime<Utc>,
+) -> ApiResult<()> {
+    if caller.publisher_address != proposer_address && !caller.is_admin() {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Only the proposer or an admin can cancel this proposal",
+        ));
+    }
+    if !matches!(proposal.status, ProposalStatus::Pending | ProposalStatus::Active) {
+        return Err(ApiError::conflict(
+            "ProposalNotCancellable",
+            "Only pending or active proposals can be cancelled",
+        ));
+    }
+    if now >= proposal.voting_ends_at {
+        return Err(ApiError::conflict(
+            "VotingEnded",
+            "Proposal cannot be cancelled after voting has ended",
+        ));
+    }
+    Ok(())
+}
+
 pub async fn create_proposal(
     State(state): State<AppState>,
     Path(contract_id): Path<Uuid>,
     Json(req): Json<CreateProposalRequest>,
 ) -> ApiResult<Json<GovernanceProposal>> {
-    let contract = sqlx::query!("SELECT publisher_id FROM contracts WHERE id = $1", contract_id)
+    let (quorum_required, approval_threshold) = resolve_thresholds(req.quorum_required, req.approval_threshold)?;
+
+    let publisher_id: Uuid = sqlx::query_scalar("SELECT publisher_id FROM contracts WHERE id = $1")
+        .bind(contract_id)
         .fetch_optional(&state.db)
         .await
         .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
@@ -33,8 +236,9 @@ pub async fn create_proposal(
     let proposal = sqlx::query_as::<_, GovernanceProposal>(
         r#"
         INSERT INTO governance_proposals 
-        (contract_id, title, description, governance_model, proposer, voting_starts_at, voting_ends_at, execution_delay_hours)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        (contract_id, title, description, governance_model, proposer, voting_starts_at, voting_ends_at, execution_delay_hours,
+         quorum_required, approval_threshold)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
         RETURNING *
         "#,
     )
@@ -42,10 +246,12 @@ pub async fn create_proposal(
     .bind(&req.title)
     .bind(&req.description)
     .bind(&req.governance_model)
-    .bind(contract.publisher_id)
+    .bind(publisher_id)
     .bind(voting_starts_at)
     .bind(voting_ends_at)
     .bind(req.execution_delay_hours)
+    .bind(quorum_required)
+    .bind(approval_threshold)
     .fetch_one(&state.db)
     .await
     .map_err(|e| ApiError::internal(format!("Failed to create proposal: {}", e)))?;
@@ -98,11 +304,13 @@ pub async fn cast_vote(
     .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
     .ok_or_else(|| ApiError::not_found("proposal", "Proposal not found"))?;
 
+    ensure_accepting_votes(&proposal)?;
+
     // Get voter (use proposer as placeholder)
     let voter_id = proposal.proposer;
 
-    // Calculate voting power (simplified)
-    let voting_power = 1i64;
+    // Own power only; delegated power is added when the proposal is tallied
+    let voting_power = BASE_VOTING_POWER;
 
     let vote = sqlx::query_as::<_, GovernanceVote>(
         r#"
@@ -137,26 +345,31 @@ pub async fn get_proposal_results(
     .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
     .ok_or_else(|| ApiError::not_found("proposal", "Proposal not found"))?;
 
-    let votes = sqlx::query!(
+    let votes = sqlx::query_as::<_, CastVote>(
+        "SELECT voter, vote_choice, voting_power FROM governance_votes WHERE proposal_id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
+    let delegations = sqlx::query_as::<_, ActiveDelegation>(
         r#"
-        SELECT 
-            SUM(CASE WHEN vote_choice = 'for' THEN voting_power ELSE 0 END) as votes_for,
-            SUM(CASE WHEN vote_choice = 'against' THEN voting_power ELSE 0 END) as votes_against,
-            SUM(CASE WHEN vote_choice = 'abstain' THEN voting_power ELSE 0 END) as votes_abstain,
-            SUM(voting_power) as total_votes
-        FROM governance_votes 
-        WHERE proposal_id = $1
+        SELECT delegator, delegate, contract_id IS NOT NULL AS contract_scoped
+        FROM vote_delegations
+        WHERE active = true AND (contract_id = $1 OR contract_id IS NULL)
         "#,
-        proposal_id
     )
-    .fetch_one(&state.db)
+    .bind(proposal.contract_id)
+    .fetch_all(&state.db)
     .await
     .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
 
-    let votes_for = votes.votes_for.unwrap_or(0);
-    let votes_against = votes.votes_against.unwrap_or(0);
-    let votes_abstain = votes.votes_abstain.unwrap_or(0);
-    let total_votes = votes.total_votes.unwrap_or(0);
+    let tally = tally_votes(&votes, &delegations);
+    let votes_for = tally.votes_for;
+    let votes_against = tally.votes_against;
+    let votes_abstain = tally.votes_abstain;
+    let total_votes = tally.total();
 
     let quorum_met = total_votes >= proposal.quorum_required as i64;
     let approval_pct = if total_votes > 0 {
@@ -165,6 +378,7 @@ pub async fn get_proposal_results(
         0
     };
     let approved = quorum_met && approval_pct >= proposal.approval_threshold as i64;
+    let executable_at = executable_at(&proposal);
 
     Ok(Json(ProposalResults {
         proposal,
@@ -174,6 +388,7 @@ pub async fn get_proposal_results(
         total_votes,
         quorum_met,
         approved,
+        executable_at,
     }))
 }
 
@@ -185,6 +400,7 @@ pub async fn execute_proposal(
         .await?
         .0;
 
+    ensure_executable(&results.proposal)?;
     if !results.approved {
         return Err(ApiError::bad_request(
             "not_approved",
@@ -192,8 +408,12 @@ pub async fn execute_proposal(
         ));
     }
 
-    sqlx::query(
-        "UPDATE governance_proposals SET status = 'executed', executed_at = $1 WHERE id = $2",
+    ensure_execution_window(&results.proposal, Utc::now())?;
+
+    // Conditional, so a concurrent cancel or execute cannot be overwritten
+    let result = sqlx::query(
+        "UPDATE governance_proposals SET status = 'executed', executed_at = $1 \
+         WHERE id = $2 AND status NOT IN ('cancelled', 'executed', 'rejected')",
     )
     .bind(Utc::now())
     .bind(proposal_id)
@@ -201,15 +421,65 @@ pub async fn execute_proposal(
     .await
     .map_err(|e| ApiError::internal(format!("Failed to execute proposal: {}", e)))?;
 
+    if result.rows_affected() == 0 {
+        return Err(ApiError::conflict(
+            "ProposalNotExecutable",
+            "Proposal was cancelled or executed in the meantime",
+        ));
+    }
+
     Ok(StatusCode::NO_CONTENT)
 }
 
+pub async fn cancel_proposal(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(proposal_id): Path<Uuid>,
+) -> ApiResult<Json<GovernanceProposal>> {
+    let proposal = sqlx::query_as::<_, GovernanceProposal>(
+        "SELECT * FROM governance_proposals WHERE id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .ok_or_else(|| ApiError::not_found("proposal", "Proposal not found"))?;
+
+    let proposer_address: String =
+        sqlx::query_scalar("SELECT stellar_address FROM publishers WHERE id = $1")
+            .bind(proposal.proposer)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
+    ensure_cancellable(&proposal, &proposer_address, &auth, Utc::now())?;
+
+    // Votes already cast are left in place for audit purposes.
+    let cancelled = sqlx::query_as::<_, GovernanceProposal>(
+        "UPDATE governance_proposals SET status = 'cancelled' \
+         WHERE id = $1 AND status IN ('pending', 'active') RETURNING *",
+    )
+    .bind(proposal_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| ApiError::internal(format!("Failed to cancel proposal: {}", e)))?
+    .ok_or_else(|| {
+        ApiError::conflict(
+            "ProposalNotCancellable",
+            "Only pending or active proposals can be cancelled",
+        )
+    })?;
+
+    Ok(Json(cancelled))
+}
+
 pub async fn delegate_vote(
     State(state): State<AppState>,
     Path(contract_id): Path<Uuid>,
     Json(delegate_id): Json<Uuid>,
 ) -> ApiResult<Json<VoteDelegation>> {
-    let contract = sqlx::query!("SELECT publisher_id FROM contracts WHERE id = $1", contract_id)
+    let publisher_id: Uuid = sqlx::query_scalar("SELECT publisher_id FROM contracts WHERE id = $1")
+        .bind(contract_id)
         .fetch_optional(&state.db)
         .await
         .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
@@ -222,7 +492,7 @@ pub async fn delegate_vote(
         RETURNING *
         "#,
     )
-    .bind(contract.publisher_id)
+    .bind(publisher_id)
     .bind(delegate_id)
     .bind(contract_id)
     .fetch_one(&state.db)
@@ -247,3 +517,193 @@ pub async fn revoke_delegation(
 
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    fn proposal(model: GovernanceModel, delay_hours: Option<i32>) -> GovernanceProposal {
+        let now = Utc::now();
+        GovernanceProposal {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::new_v4(),
+            title: "Upgrade".into(),
+            description: "Upgrade to v2".into(),
+            governance_model: model,
+            proposer: Uuid::new_v4(),
+            status: ProposalStatus::Passed,
+            voting_starts_at: now - Duration::hours(48),
+            voting_ends_at: now - Duration::hours(1),
+            execution_delay_hours: delay_hours,
+            quorum_required: 50,
+            approval_threshold: 50,
+            created_at: now - Duration::hours(48),
+            executed_at: None,
+        }
+    }
+
+    fn caller(address: &str) -> AuthContext {
+        AuthContext {
+            publisher_address: address.into(),
+        }
+    }
+
+    #[test]
+    fn proposer_can_cancel_active_proposal() {
+        let mut p = proposal(GovernanceModel::TokenWeighted, None);
+        p.status = ProposalStatus::Active;
+        p.voting_ends_at = Utc::now() + Duration::hours(1);
+        assert!(ensure_cancellable(&p, "GPROPOSER", &caller("GPROPOSER"), Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn non_proposer_cannot_cancel() {
+        let mut p = proposal(GovernanceModel::TokenWeighted, None);
+        p.status = ProposalStatus::Active;
+        p.voting_ends_at = Utc::now() + Duration::hours(1);
+        let err = ensure_cancellable(&p, "GPROPOSER", &caller("GSTRANGER"), Utc::now())
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn cancelled_proposal_rejects_votes() {
+        let mut p = proposal(GovernanceModel::TokenWeighted, None);
+        p.status = ProposalStatus::Cancelled;
+        let err = ensure_accepting_votes(&p).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn only_open_or_passed_proposals_are_executable() {
+        let mut p = proposal(GovernanceModel::TokenWeighted, None);
+        for status in [ProposalStatus::Pending, ProposalStatus::Active, ProposalStatus::Passed] {
+            p.status = status;
+            assert!(ensure_executable(&p).is_ok());
+        }
+        for status in [ProposalStatus::Cancelled, ProposalStatus::Executed, ProposalStatus::Rejected] {
+            p.status = status;
+            let err = ensure_executable(&p).unwrap_err();
+            assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+        }
+    }
+
+    #[test]
+    fn timelock_executable_at_adds_delay() {
+        let p = proposal(GovernanceModel::Timelock, Some(24));
+        assert_eq!(executable_at(&p), p.voting_ends_at + Duration::hours(24));
+    }
+
+    #[test]
+    fn execution_before_delay_is_rejected() {
+        let p = proposal(GovernanceModel::Timelock, Some(24));
+        let err = ensure_execution_window(&p, Utc::now()).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::TOO_EARLY);
+    }
+
+    #[test]
+    fn execution_after_delay_succeeds() {
+        let p = proposal(GovernanceModel::Timelock, Some(24));
+        let later = p.voting_ends_at + Duration::hours(24) + Duration::seconds(1);
+        assert!(ensure_execution_window(&p, later).is_ok());
+    }
+
+    #[test]
+    fn non_timelock_without_delay_executes_after_voting() {
+        let p = proposal(GovernanceModel::TokenWeighted, None);
+        assert_eq!(executable_at(&p), p.voting_ends_at);
+        assert!(ensure_execution_window(&p, Utc::now()).is_ok());
+    }
+
+    #[test]
+    fn thresholds_default_when_omitted() {
+        assert_eq!(
+            resolve_thresholds(None, None).unwrap(),
+            (DEFAULT_QUORUM_REQUIRED, DEFAULT_APPROVAL_THRESHOLD)
+        );
+        assert_eq!(resolve_thresholds(Some(0), Some(100)).unwrap(), (0, 100));
+        assert_eq!(resolve_thresholds(Some(1_000), Some(67)).unwrap(), (1_000, 67));
+    }
+
+    #[test]
+    fn approval_threshold_over_100_percent_is_rejected() {
+        let err = resolve_thresholds(None, Some(150)).unwrap_err();
+        assert!(format!("{:?}", err).contains("between 0 and 100"));
+        assert_eq!(err.into_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        assert!(resolve_thresholds(None, Some(-1)).is_err());
+        assert!(resolve_thresholds(None, Some(101)).is_err());
+    }
+
+    fn vote(voter: u128, choice: VoteChoice) -> CastVote {
+        CastVote {
+            voter: Uuid::from_u128(voter),
+            vote_choice: choice,
+            voting_power: BASE_VOTING_POWER,
+        }
+    }
+
+    fn delegation(delegator: u128, delegate: u128, contract_scoped: bool) -> ActiveDelegation {
+        ActiveDelegation {
+            delegator: Uuid::from_u128(delegator),
+            delegate: Uuid::from_u128(delegate),
+            contract_scoped,
+        }
+    }
+
+    #[test]
+    fn delegated_power_follows_the_delegate() {
+        let votes = [vote(1, VoteChoice::For), vote(2, VoteChoice::Against)];
+        let delegations = [delegation(3, 1, true), delegation(4, 1, false), delegation(5, 2, true)];
+        let tally = tally_votes(&votes, &delegations);
+        assert_eq!((tally.votes_for, tally.votes_against), (3, 2));
+        assert_eq!(tally.total(), 5);
+    }
+
+    #[test]
+    fn delegator_voting_directly_is_counted_once() {
+        // Publisher 2 delegated to 1, then voted against directly.
+        let votes = [vote(1, VoteChoice::For), vote(2, VoteChoice::Against)];
+        let tally = tally_votes(&votes, &[delegation(2, 1, true)]);
+        assert_eq!(tally.votes_for, 1);
+        assert_eq!(tally.votes_against, 1);
+        assert_eq!(tally.total(), 2);
+
+        // The same voter agreeing with the delegate still counts once.
+        let votes = [vote(1, VoteChoice::For), vote(2, VoteChoice::For)];
+        assert_eq!(tally_votes(&votes, &[delegation(2, 1, true)]).total(), 2);
+    }
+
+    #[test]
+    fn contract_scoped_delegation_overrides_registry_wide() {
+        let votes = [vote(1, VoteChoice::For), vote(2, VoteChoice::Against)];
+        let tally = tally_votes(&votes, &[delegation(3, 1, false), delegation(3, 2, true)]);
+        assert_eq!((tally.votes_for, tally.votes_against), (1, 2));
+    }
+
+    #[test]
+    fn power_delegated_to_a_non_voter_is_not_counted() {
+        let votes = [vote(1, VoteChoice::Abstain)];
+        let tally = tally_votes(&votes, &[delegation(3, 9, true), delegation(9, 1, true)]);
+        assert_eq!(tally.votes_abstain, 2);
+        assert_eq!(tally.total(), 2);
+    }
+
+    #[test]
+    fn quorum_counts_delegated_power() {
+        let mut p = proposal(GovernanceModel::TokenWeighted, None);
+        p.quorum_required = 3;
+        let votes = [vote(1, VoteChoice::For), vote(2, VoteChoice::Against)];
+        let direct_only = tally_votes(&votes, &[]);
+        assert!(direct_only.total() < p.quorum_required as i64);
+        let with_delegation = tally_votes(&votes, &[delegation(3, 1, true), delegation(2, 1, true)]);
+        assert_eq!(with_delegation.total(), p.quorum_required as i64);
+    }
+
+    #[test]
+    fn negative_quorum_is_rejected() {
+        let err = resolve_thresholds(Some(-5), None).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
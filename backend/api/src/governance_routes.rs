@@ -1,6 +1,6 @@
-use axum::{routing::get, routing::post, Router};
+use axum::{middleware, routing::get, routing::post, Router};
 
-use crate::{governance_handlers, state::AppState};
+use crate::{auth_middleware, governance_handlers, state::AppState};
 
 pub fn governance_routes() -> Router<AppState> {
     Router::new()
@@ -32,4 +32,175 @@ pub fn governance_routes() -> Router<AppState> {
             "/api/governance/delegations/:id/revoke",
             post(governance_handlers::revoke_delegation),
         )
+        .merge(
+            Router::new()
+                .route(
+                    "/api/governance/proposals/:id/cancel",
+                    post(governance_handlers::cancel_proposal),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{auth::AuthManager, fixtures};
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use tower::ServiceExt;
+    use uuid::Uuid;
+
+    fn app(db: sqlx::PgPool) -> Router {
+        governance_routes().with_state(fixtures::state_for(db))
+    }
+
+    fn cancel_request(proposal: Uuid, token: Option<&str>) -> Request<Body> {
+        let mut request = Request::post(format!("/api/governance/proposals/{}/cancel", proposal));
+        if let Some(token) = token {
+            request = request.header("authorization", format!("Bearer {}", token));
+        }
+        request.body(Body::empty()).unwrap()
+    }
+
+    fn execute_request(proposal: Uuid) -> Request<Body> {
+        Request::post(format!("/api/governance/proposals/{}/execute", proposal))
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn cancel_route_requires_a_publisher_token() {
+        let db = sqlx::pool::PoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap();
+        let response = app(db).oneshot(cancel_request(Uuid::new_v4(), None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn cancel_route_lets_only_the_proposer_cancel() {
+        let pool = fixtures::test_pool().await;
+        let data = fixtures::fixtures();
+        let contract = &data.contracts[0];
+        let proposer = data
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address == contract.publisher_address)
+            .unwrap();
+        let stranger = data.publishers.iter().find(|p| p.id != proposer.id).unwrap();
+
+        let proposal: Uuid = sqlx::query_scalar(
+            "INSERT INTO governance_proposals \
+             (contract_id, title, description, governance_model, proposer, status, voting_starts_at, voting_ends_at) \
+             VALUES ($1, 'Upgrade', 'Upgrade to v2', 'token_weighted', $2, 'active', NOW(), NOW() + INTERVAL '1 day') \
+             RETURNING id",
+        )
+        .bind(contract.id)
+        .bind(proposer.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let auth = AuthManager::from_env();
+        let stranger_token = auth.issue_jwt(&stranger.stellar_address).unwrap();
+        let response = app(pool.clone())
+            .oneshot(cancel_request(proposal, Some(&stranger_token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let proposer_token = auth.issue_jwt(&proposer.stellar_address).unwrap();
+        let response = app(pool.clone())
+            .oneshot(cancel_request(proposal, Some(&proposer_token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let status: String =
+            sqlx::query_scalar("SELECT status::text FROM governance_proposals WHERE id = $1")
+                .bind(proposal)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(status, "cancelled");
+
+        sqlx::query("DELETE FROM governance_proposals WHERE id = $1")
+            .bind(proposal)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn cancelled_or_executed_proposals_cannot_be_executed() {
+        let pool = fixtures::test_pool().await;
+        let data = fixtures::fixtures();
+        let contract = &data.contracts[0];
+        let proposer = data
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address == contract.publisher_address)
+            .unwrap();
+
+        // No quorum and no threshold, so the empty tally passes once voting ends
+        let new_proposal = || async {
+            sqlx::query_scalar::<_, Uuid>(
+                "INSERT INTO governance_proposals \
+                 (contract_id, title, description, governance_model, proposer, status, voting_starts_at, \
+                  voting_ends_at, quorum_required, approval_threshold) \
+                 VALUES ($1, 'Upgrade', 'Upgrade to v2', 'token_weighted', $2, 'active', NOW(), \
+                         NOW() + INTERVAL '1 day', 0, 0) \
+                 RETURNING id",
+            )
+            .bind(contract.id)
+            .bind(proposer.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+        };
+        let end_voting = |proposal: Uuid| {
+            sqlx::query("UPDATE governance_proposals SET voting_ends_at = NOW() - INTERVAL '1 hour' WHERE id = $1")
+                .bind(proposal)
+                .execute(&pool)
+        };
+
+        let cancelled = new_proposal().await;
+        let token = AuthManager::from_env().issue_jwt(&proposer.stellar_address).unwrap();
+        let response = app(pool.clone())
+            .oneshot(cancel_request(cancelled, Some(&token)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        end_voting(cancelled).await.unwrap();
+        let response = app(pool.clone()).oneshot(execute_request(cancelled)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let executed = new_proposal().await;
+        end_voting(executed).await.unwrap();
+        let response = app(pool.clone()).oneshot(execute_request(executed)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let response = app(pool.clone()).oneshot(execute_request(executed)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let statuses: Vec<(String, bool)> = sqlx::query_as(
+            "SELECT status::text, executed_at IS NOT NULL FROM governance_proposals \
+             WHERE id = ANY($1) ORDER BY status",
+        )
+        .bind(vec![cancelled, executed])
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(statuses, vec![("cancelled".into(), false), ("executed".into(), true)]);
+
+        sqlx::query("DELETE FROM governance_proposals WHERE id = ANY($1)")
+            .bind(vec![cancelled, executed])
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
 }
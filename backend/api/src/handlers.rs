@@ -1,34 +1,113 @@
 use axum::{
     extract::{
         rejection::{JsonRejection, QueryRejection},
-        Path, Query, State,
+        connect_info::ConnectInfo, Extension, Path, Query, State,
     },
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
 use serde_json::{json, Value};
 use shared::{
-    Contract,ContractGetResponse, ContractSearchParams, ContractVersion, Network, NetworkConfig, CreateContractVersionRequest, PaginatedResponse, PublishRequest, Publisher,
+    Contract, ContractAnalyticsResponse, DeploymentStats, InteractorStats, TimelineEntry, TimelineInterval, TopUser,ContractGetResponse, FieldChange, PatchContractRequest, PromoteNetworkRequest, ContractSearchParams, ContractVersion, Network, NetworkConfig, CreateContractVersionRequest, PaginatedResponse, PublishRequest, PublishResponse, Publisher,
     SemVer,
 };
+use sqlx::{Postgres, QueryBuilder};
 use uuid::Uuid;
 
 /// Query params for GET /contracts/:id (Issue #43)
 #[derive(Debug, serde::Deserialize)]
 pub struct GetContractQuery {
     pub network: Option<Network>,
+    /// Comma-separated field projection, e.g. `?fields=id,name,network`
+    pub fields: Option<String>,
+}
+
+/// Contract fields clients may request through `?fields=`
+const PROJECTABLE_CONTRACT_FIELDS: &[&str] = &[
+    "id",
+    "contract_id",
+    "wasm_hash",
+    "name",
+    "description",
+    "publisher_id",
+    "network",
+    "is_verified",
+    "category",
+    "tags",
+    "created_at",
+    "updated_at",
+    "is_maintenance",
+    "maturity",
+    "logical_id",
+    "network_configs",
+    "origin_contract_id",
+    "drift_detected",
+    "view_count",
+    "first_seen_at",
+    "license",
+];
+
+/// Parse a `?fields=` value, rejecting names outside the allowlist.
+fn parse_field_selection(raw: Option<&str>) -> ApiResult<Option<Vec<String>>> {
+    let Some(raw) = raw else {
+        return Ok(None);
+    };
+
+    let fields: Vec<String> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|f| !f.is_empty())
+        .map(str::to_string)
+        .collect();
+    if fields.is_empty() {
+        return Err(ApiError::bad_request("InvalidFields", "fields must not be empty"));
+    }
+
+    let unknown: Vec<&str> = fields
+        .iter()
+        .map(String::as_str)
+        .filter(|f| !PROJECTABLE_CONTRACT_FIELDS.contains(f))
+        .collect();
+    if !unknown.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidFields",
+            format!("Unknown field(s): {}", unknown.join(", ")),
+        ));
+    }
+
+    Ok(Some(fields))
+}
+
+/// Keep only `fields` from a serialized object.
+fn project_fields(value: Value, fields: &[String]) -> Value {
+    match value {
+        Value::Object(mut map) => Value::Object(
+            fields
+                .iter()
+                .filter_map(|f| map.remove(f).map(|v| (f.clone(), v)))
+                .collect(),
+        ),
+        other => other,
+    }
 }
 
 use crate::{
+    audit::{self, AuditEntry},
+    auth_middleware::AuthContext,
     error::{ApiError, ApiResult},
     breaking_changes::{diff_abi, has_breaking_changes, resolve_abi},
+    db_timeout,
+    network_lifecycle,
+    resource_handlers::{enforce_publisher_quota, load_quota},
+    resource_tracking::QuotaResource,
+    search_facets::{self, FacetedPage, SearchFacets},
+    search_highlight,
     state::AppState,
 };
 
-fn db_internal_error(operation: &str, err: sqlx::Error) -> ApiError {
-    tracing::error!(operation = operation, error = ?err, "database operation failed");
-    ApiError::internal("An unexpected database error occurred")
+pub fn db_internal_error(operation: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(operation, err)
 }
 
 fn map_json_rejection(err: JsonRejection) -> ApiError {
@@ -73,6 +152,55 @@ pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<Va
     }
 }
 
+/// GET /health/detailed — database reachability plus background job freshness.
+///
+/// Reports `degraded` (still 200) when any background job is stale, and 503
+/// when the database is unreachable.
+pub async fn detailed_health_check(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let uptime = state.started_at.elapsed().as_secs();
+    let now = chrono::Utc::now();
+
+    let jobs = match crate::background_jobs::fetch_statuses(&state.db).await {
+        Ok(statuses) => Some(crate::background_jobs::report(statuses, now)),
+        Err(err) => {
+            tracing::warn!(error = ?err, "detailed health check — db unreachable");
+            None
+        }
+    };
+
+    let Some(jobs) = jobs else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({
+                "status": "unhealthy",
+                "timestamp": now.to_rfc3339(),
+                "uptime_secs": uptime,
+                "database": "unreachable",
+            })),
+        );
+    };
+
+    let stale_jobs: Vec<&str> = jobs
+        .iter()
+        .filter(|j| j.stale)
+        .map(|j| j.status.job_name.as_str())
+        .collect();
+    let status = if stale_jobs.is_empty() { "ok" } else { "degraded" };
+
+    (
+        StatusCode::OK,
+        Json(json!({
+            "status": status,
+            "version": "0.1.0",
+            "timestamp": now.to_rfc3339(),
+            "uptime_secs": uptime,
+            "database": "ok",
+            "stale_jobs": stale_jobs,
+            "jobs": jobs,
+        })),
+    )
+}
+
 pub async fn get_stats(State(state): State<AppState>) -> ApiResult<Json<Value>> {
     let total_contracts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts")
         .fetch_one(&state.db)
@@ -97,6 +225,119 @@ pub async fn get_stats(State(state): State<AppState>) -> ApiResult<Json<Value>>
     })))
 }
 
+/// Non-empty, trimmed entries of a comma-separated query value.
+fn comma_list(raw: Option<&str>) -> Vec<String> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Append the WHERE-clause filters (aliasing `contracts` as `c`) shared by
+/// `list_contracts`, the search facets and the CSV export. Every value taken
+/// from the request is bound as a parameter.
+pub(crate) fn push_contract_filters(qb: &mut QueryBuilder<'_, Postgres>, params: &ContractSearchParams) {
+    // Contracts held for spam review stay out of listings until approved,
+    // and soft-deleted ones stay out until the purge removes them
+    qb.push(" AND c.pending_review = false AND c.deleted_at IS NULL");
+
+    if let Some(ref q) = params.query {
+        qb.push(" AND (c.name ILIKE '%' || ");
+        qb.push_bind(q.clone());
+        qb.push(" || '%' OR c.description ILIKE '%' || ");
+        qb.push_bind(q.clone());
+        qb.push(" || '%')");
+    }
+
+    if params.verified_only == Some(true) {
+        qb.push(" AND c.is_verified = true");
+    }
+
+    if let Some(ref category) = params.category {
+        qb.push(" AND c.category = ");
+        qb.push_bind(category.clone());
+    }
+
+    let excluded_categories = comma_list(params.exclude_category.as_deref());
+    if !excluded_categories.is_empty() {
+        qb.push(" AND (c.category IS NULL OR NOT c.category = ANY(");
+        qb.push_bind(excluded_categories);
+        qb.push("))");
+    }
+
+    let excluded_tags = comma_list(params.exclude_tags.as_deref());
+    if !excluded_tags.is_empty() {
+        qb.push(" AND NOT (COALESCE(c.tags, '{}') && ");
+        qb.push_bind(excluded_tags);
+        qb.push("::text[])");
+    }
+
+    let tags: Vec<String> = params
+        .tags
+        .iter()
+        .flatten()
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+        .collect();
+    if !tags.is_empty() {
+        qb.push(" AND COALESCE(c.tags, '{}') @> ");
+        qb.push_bind(tags);
+        qb.push("::text[]");
+    }
+
+    if let Some(ref maturity) = params.maturity {
+        qb.push(" AND c.maturity = ");
+        qb.push_bind(maturity.clone());
+    }
+
+    // Licenses are stored canonically, so match the canonical spelling of
+    // each requested id against the identifiers in the stored expression
+    let licenses: Vec<String> = comma_list(params.license.as_deref())
+        .into_iter()
+        .map(|id| crate::validation::canonical_license_id(&id).unwrap_or(id))
+        .collect();
+    if !licenses.is_empty() {
+        qb.push(" AND regexp_split_to_array(c.license, '[\\s()]+') && ");
+        qb.push_bind(licenses);
+        qb.push("::text[]");
+    }
+
+    // Filter by network(s) (Issue #43)
+    let network_list = params
+        .networks
+        .as_ref()
+        .filter(|n| !n.is_empty())
+        .cloned()
+        .or_else(|| params.network.clone().map(|n| vec![n]));
+    if let Some(nets) = network_list {
+        qb.push(" AND c.network IN (");
+        let mut separated = qb.separated(", ");
+        for network in nets {
+            separated.push_bind(network);
+        }
+        qb.push(")");
+    }
+}
+
+/// Serialize one listing entry, applying `?fields=` and attaching search
+/// highlights when the listing came from a query.
+fn listing_item(
+    contract: &Contract,
+    selection: Option<&[String]>,
+    highlights: Option<search_highlight::SearchHighlights>,
+) -> Value {
+    let mut item = json!(contract);
+    if let Some(fields) = selection {
+        item = project_fields(item, fields);
+    }
+    if let (Some(highlights), Value::Object(map)) = (highlights, &mut item) {
+        map.insert("highlights".to_string(), json!(highlights));
+    }
+    item
+}
+
 /// List and search contracts
 pub async fn list_contracts(
     State(state): State<AppState>,
@@ -107,8 +348,13 @@ pub async fn list_contracts(
         Err(err) => return map_query_rejection(err).into_response(),
     };
     
+    let selection = match parse_field_selection(params.fields.as_deref()) {
+        Ok(selection) => selection,
+        Err(err) => return err.into_response(),
+    };
+
     let page = params.page.unwrap_or(1).max(1);
-    let limit = params.limit.unwrap_or(20).clamp(1, 100);
+    let limit = state.pagination.limit(params.limit);
     let offset = (page - 1).max(0) * limit;
 
     let sort_by = params.sort_by.clone().unwrap_or_else(|| {
@@ -121,131 +367,230 @@ pub async fn list_contracts(
     let sort_order = params.sort_order.clone().unwrap_or(shared::SortOrder::Desc);
 
     // Build dynamic query with aggregations
-    let mut query = String::from(
+    let mut query = QueryBuilder::new(
         "SELECT c.*
          FROM contracts c
          LEFT JOIN contract_interactions ci ON c.id = ci.contract_id
          LEFT JOIN contract_versions cv ON c.id = cv.contract_id
-         WHERE 1=1"
+         WHERE 1=1",
     );
-    let mut count_query = String::from("SELECT COUNT(*) FROM contracts WHERE 1=1");
+    push_contract_filters(&mut query, &params);
+    let mut count_query = QueryBuilder::new("SELECT COUNT(*) FROM contracts c WHERE 1=1");
+    push_contract_filters(&mut count_query, &params);
+    let mut facet_query = search_facets::facet_query(&params);
 
-    if let Some(ref q) = params.query {
-        let search_clause = format!(
-            " AND (c.name ILIKE '%{}%' OR c.description ILIKE '%{}%')",
-            q, q
-        );
-        query.push_str(&search_clause);
-        count_query.push_str(&search_clause);
-    }
+    query.push(" GROUP BY c.id");
 
-    if let Some(verified) = params.verified_only {
-        if verified {
-            query.push_str(" AND c.is_verified = true");
-            count_query.push_str(" AND is_verified = true");
-        }
-    }
+    // Sorting logic using aggregations in ORDER BY
+    query.push(" ORDER BY ");
+    match sort_by {
+        shared::SortBy::CreatedAt => query.push("c.created_at"),
+        shared::SortBy::UpdatedAt => query.push("c.updated_at"),
+        shared::SortBy::Popularity | shared::SortBy::Interactions => query.push("COUNT(DISTINCT ci.id)"),
+        shared::SortBy::Deployments => query.push("COUNT(DISTINCT cv.id)"),
+        shared::SortBy::Relevance => match params.query {
+            Some(ref q) => query
+                .push("CASE WHEN c.name ILIKE ")
+                .push_bind(q.clone())
+                .push(" THEN 0 WHEN c.name ILIKE '%' || ")
+                .push_bind(q.clone())
+                .push(" || '%' THEN 1 ELSE 2 END"),
+            None => query.push("c.created_at"),
+        },
+    };
 
-    if let Some(ref category) = params.category {
-        let category_clause = format!(" AND c.category = '{}'", category);
-        query.push_str(&category_clause);
-        count_query.push_str(&category_clause);
+    let direction = if sort_order == shared::SortOrder::Asc { "ASC" } else { "DESC" };
+    query.push(format!(" {}, c.id DESC LIMIT ", direction));
+    query.push_bind(limit);
+    query.push(" OFFSET ");
+    query.push_bind(offset);
+
+    let (contracts, total, facets) = match db_timeout::timed(
+        "list contracts",
+        fetch_contract_page(&state.db, &mut query, &mut count_query, &mut facet_query),
+    )
+    .await
+    {
+        Ok(page) => page,
+        Err(err) => return err.into_response(),
+    };
+
+    let search_query = params.query.as_deref().filter(|q| !q.trim().is_empty());
+    if let (Some(q), 1) = (search_query, page) {
+        crate::search_analytics::spawn_record_search(state.db.clone(), q, total);
     }
 
-    // Filter by network(s) (Issue #43)
-    let network_list = params
-        .networks
-        .as_ref()
-        .filter(|n| !n.is_empty())
-        .cloned()
-        .or_else(|| params.network.map(|n| vec![n]));
-    if let Some(ref nets) = network_list {
-        let net_list: Vec<String> = nets.iter().map(|n| n.to_string()).collect();
-        let in_clause = net_list
+    if selection.is_some() || search_query.is_some() {
+        let mut headlines = match search_query {
+            Some(q) => {
+                let ids: Vec<Uuid> = contracts.iter().map(|c| c.id).collect();
+                search_highlight::fetch_headlines(&state.db, q, &ids)
+                    .await
+                    .unwrap_or_else(|err| {
+                        tracing::warn!(error = ?err, "ts_headline failed; using substring highlights");
+                        Default::default()
+                    })
+            }
+            None => Default::default(),
+        };
+
+        let items: Vec<Value> = contracts
             .iter()
-            .map(|s| format!("'{}'", s.replace('\'', "''")))
-            .collect::<Vec<_>>()
-            .join(", ");
-        let network_clause = format!(" AND c.network IN ({})", in_clause);
-        query.push_str(&network_clause);
-        count_query.push_str(&network_clause);
+            .map(|c| {
+                let highlights = search_query
+                    .map(|q| search_highlight::build_highlights(c, q, headlines.remove(&c.id)));
+                listing_item(c, selection.as_deref(), highlights)
+            })
+            .collect();
+        return (
+            StatusCode::OK,
+            Json(FacetedPage {
+                page: PaginatedResponse::new(items, total, page, limit),
+                facets,
+            }),
+        )
+            .into_response();
     }
 
-    query.push_str(" GROUP BY c.id");
+    (
+        StatusCode::OK,
+        Json(FacetedPage {
+            page: PaginatedResponse::new(contracts, total, page, limit),
+            facets,
+        }),
+    ).into_response()
+}
 
-    // Sorting logic using aggregations in ORDER BY
-    let order_by = match sort_by {
-        shared::SortBy::CreatedAt => "c.created_at".to_string(),
-        shared::SortBy::UpdatedAt => "c.updated_at".to_string(),
-        shared::SortBy::Popularity | shared::SortBy::Interactions => "COUNT(DISTINCT ci.id)".to_string(),
-        shared::SortBy::Deployments => "COUNT(DISTINCT cv.id)".to_string(),
-        shared::SortBy::Relevance => {
-            if let Some(ref q) = params.query {
-                format!(
-                    "CASE WHEN c.name ILIKE '{}' THEN 0 
-                          WHEN c.name ILIKE '%{}%' THEN 1 
-                          ELSE 2 END",
-                    q, q
-                )
-            } else {
-                "c.created_at".to_string()
-            }
-        }
-    };
+/// Start a read-only `REPEATABLE READ` transaction: every query in it sees
+/// the same snapshot, whatever commits meanwhile.
+pub async fn begin_snapshot(pool: &sqlx::PgPool) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+    Ok(tx)
+}
 
-    let direction = if sort_order == shared::SortOrder::Asc { "ASC" } else { "DESC" };
-    
-    query.push_str(&format!(
-        " ORDER BY {} {}, c.id DESC LIMIT {} OFFSET {}",
-        order_by, direction, limit, offset
-    ));
+/// Read one page of contracts, the filtered total and its facet counts from a
+/// single snapshot, so a publish landing between the queries can't skew
+/// `total_pages` or make the facets disagree with `total`.
+async fn fetch_contract_page(
+    pool: &sqlx::PgPool,
+    page_query: &mut QueryBuilder<'_, Postgres>,
+    count_query: &mut QueryBuilder<'_, Postgres>,
+    facet_query: &mut QueryBuilder<'_, Postgres>,
+) -> Result<(Vec<Contract>, i64, SearchFacets), sqlx::Error> {
+    let mut tx = begin_snapshot(pool).await?;
+    let contracts: Vec<Contract> = page_query.build_query_as().fetch_all(&mut *tx).await?;
+    let total: i64 = count_query.build_query_scalar().fetch_one(&mut *tx).await?;
+    let facet_rows: Vec<search_facets::FacetRow> = facet_query.build_query_as().fetch_all(&mut *tx).await?;
+    tx.commit().await?;
+    Ok((contracts, total, search_facets::build_facets(facet_rows)))
+}
 
-    let contracts: Vec<Contract> = match sqlx::query_as(&query)
-        .fetch_all(&state.db)
-        .await
-    {
-        Ok(rows) => rows,
-        Err(err) => return db_internal_error("list contracts", err).into_response(),
-    };
+/// `DEFAULT_NETWORK`: the network a contract_id lookup without `?network=`
+/// falls back to when the id is registered on several networks. Unset (or
+/// invalid) means such lookups are rejected as ambiguous.
+pub fn default_network_from_env() -> Option<Network> {
+    let raw = std::env::var("DEFAULT_NETWORK").ok()?;
+    match serde_json::from_value(json!(raw.trim().to_lowercase())) {
+        Ok(network) => Some(network),
+        Err(_) => {
+            tracing::warn!(value = %raw, "ignoring invalid DEFAULT_NETWORK");
+            None
+        }
+    }
+}
 
-    let total: i64 = match sqlx::query_scalar(&count_query)
-        .fetch_one(&state.db)
-        .await
-    {
-        Ok(v) => v,
-        Err(err) => return db_internal_error("count filtered contracts", err).into_response(),
+/// Pick the row a contract_id lookup refers to among the networks it is
+/// registered on. An explicit `requested` network must match; otherwise a
+/// single candidate wins, then `default`. Anything else is a 409 listing
+/// the candidate networks so the caller can retry with `?network=`.
+pub fn resolve_contract_network(
+    id: &str,
+    candidates: Vec<Contract>,
+    requested: Option<&Network>,
+    default: Option<&Network>,
+) -> ApiResult<Contract> {
+    let not_found = || ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id));
+    let pick = |candidates: &[Contract], network: &Network| {
+        candidates
+            .iter()
+            .position(|c| c.network.to_string() == network.to_string())
     };
 
-    (
-        StatusCode::OK,
-        Json(PaginatedResponse::new(contracts, total, page, limit)),
-    ).into_response()
+    if let Some(network) = requested {
+        let index = pick(&candidates, network).ok_or_else(not_found)?;
+        return Ok(candidates.into_iter().nth(index).unwrap());
+    }
+
+    match candidates.len() {
+        0 => Err(not_found()),
+        1 => Ok(candidates.into_iter().next().unwrap()),
+        _ => {
+            if let Some(index) = default.and_then(|network| pick(&candidates, network)) {
+                return Ok(candidates.into_iter().nth(index).unwrap());
+            }
+            let networks: Vec<String> = candidates.iter().map(|c| c.network.to_string()).collect();
+            Err(ApiError::conflict(
+                "AmbiguousContractId",
+                format!(
+                    "Contract {} is registered on {}; pass ?network= to choose one",
+                    id,
+                    networks.join(", ")
+                ),
+            )
+            .with_details(json!({ "candidates": networks })))
+        }
+    }
 }
 
 /// Get a specific contract by ID. Optional ?network= returns network-specific config (Issue #43).
+/// GET /api/contracts/:id — `id` is the registry UUID or the on-chain
+/// contract_id. A contract_id registered on several networks needs
+/// `?network=` unless `DEFAULT_NETWORK` is set.
 pub async fn get_contract(
     State(state): State<AppState>,
     Path(id): Path<String>,
     Query(query): Query<GetContractQuery>,
-) -> ApiResult<Json<ContractGetResponse>> {
-    let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
-        ApiError::bad_request(
-            "InvalidContractId",
-            format!("Invalid contract ID format: {}", id),
-        )
-    })?;
+    headers: HeaderMap,
+    connect_info: Option<ConnectInfo<std::net::SocketAddr>>,
+) -> ApiResult<Json<Value>> {
+    let selection = parse_field_selection(query.fields.as_deref())?;
+
+    let mut contract: Contract = match Uuid::parse_str(&id) {
+        Ok(contract_uuid) => sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => ApiError::not_found(
+                    "ContractNotFound",
+                    format!("No contract found with ID: {}", id),
+                ),
+                _ => db_internal_error("get contract by id", err),
+            })?,
+        Err(_) => {
+            let candidates: Vec<Contract> =
+                sqlx::query_as(
+                    "SELECT * FROM contracts WHERE contract_id = $1 AND deleted_at IS NULL ORDER BY network",
+                )
+                    .bind(&id)
+                    .fetch_all(&state.db)
+                    .await
+                    .map_err(|err| db_internal_error("get contract by contract_id", err))?;
+            resolve_contract_network(
+                &id,
+                candidates,
+                query.network.as_ref(),
+                state.default_network.as_ref(),
+            )?
+        }
+    };
 
-    let mut contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
-        .bind(contract_uuid)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|err| match err {
-            sqlx::Error::RowNotFound => ApiError::not_found(
-                "ContractNotFound",
-                format!("No contract found with ID: {}", id),
-            ),
-            _ => db_internal_error("get contract by id", err),
-        })?;
+    let client = crate::rate_limit::client_ip(&headers, connect_info.as_ref());
+    state.views.record(contract.id, &client, chrono::Utc::now());
+    contract.view_count += state.views.pending(contract.id);
 
     let current_network = query.network;
     let network_config = if let Some(ref net) = current_network {
@@ -265,13 +610,343 @@ pub async fn get_contract(
         None
     };
 
-    Ok(Json(ContractGetResponse {
+    let response = json!(ContractGetResponse {
         contract,
         current_network,
         network_config,
+    });
+
+    Ok(Json(match selection {
+        Some(fields) => project_fields(response, &fields),
+        None => response,
     }))
 }
 
+/// Apply the fields present in `patch` to `contract`, returning one
+/// `FieldChange` per field whose value actually changed.
+pub fn apply_contract_patch(contract: &mut Contract, patch: &PatchContractRequest) -> Vec<FieldChange> {
+    fn track<T: serde::Serialize + PartialEq + Clone>(
+        changes: &mut Vec<FieldChange>,
+        field: &str,
+        current: &mut T,
+        next: Option<&T>,
+    ) {
+        if let Some(next) = next {
+            if current != next {
+                changes.push(FieldChange {
+                    field: field.to_string(),
+                    from: json!(current),
+                    to: json!(next),
+                });
+                *current = next.clone();
+            }
+        }
+    }
+
+    let mut changes = Vec::new();
+    track(&mut changes, "name", &mut contract.name, patch.name.as_ref());
+    track(&mut changes, "description", &mut contract.description, patch.description.as_ref());
+    track(&mut changes, "category", &mut contract.category, patch.category.as_ref());
+    track(&mut changes, "tags", &mut contract.tags, patch.tags.as_ref());
+    let license = patch.license.clone().map(Some);
+    track(&mut changes, "license", &mut contract.license, license.as_ref());
+    changes
+}
+
+/// Reject callers that are neither the contract's publisher nor an admin.
+pub(crate) async fn ensure_contract_owner(state: &AppState, contract: &Contract, auth: &AuthContext) -> ApiResult<()> {
+    let owner: String = sqlx::query_scalar("SELECT stellar_address FROM publishers WHERE id = $1")
+        .bind(contract.publisher_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract owner", err))?;
+
+    if owner != auth.publisher_address && !auth.is_admin() {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Only the contract's publisher may modify it",
+        ));
+    }
+    Ok(())
+}
+
+/// PATCH /api/contracts/:id — update only the provided metadata fields.
+/// Restricted to the contract's publisher (or an admin); every applied
+/// change is recorded in `contract_audit_log` as a before/after diff.
+pub async fn patch_contract(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<String>,
+    payload: Result<Json<PatchContractRequest>, JsonRejection>,
+) -> ApiResult<Json<Contract>> {
+    let Json(mut patch) = payload.map_err(map_json_rejection)?;
+    let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidContractId",
+            format!("Invalid contract ID format: {}", id),
+        )
+    })?;
+
+    if patch.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+        return Err(ApiError::bad_request("InvalidName", "name must not be empty"));
+    }
+    if let Some(ref license) = patch.license {
+        let canonical = crate::validation::normalize_license(license)
+            .map_err(|message| ApiError::bad_request("InvalidLicense", message))?;
+        patch.license = Some(canonical);
+    }
+
+    let mut contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::RowNotFound => ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found with ID: {}", id),
+            ),
+            _ => db_internal_error("get contract for patch", err),
+        })?;
+
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let changes = apply_contract_patch(&mut contract, &patch);
+    if changes.is_empty() {
+        return Ok(Json(contract));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin contract patch", err))?;
+
+    let contract: Contract = sqlx::query_as(
+        "UPDATE contracts
+            SET name = $2, description = $3, category = $4, tags = $5, license = $6,
+                updated_at = NOW()
+          WHERE id = $1
+          RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(&contract.name)
+    .bind(&contract.description)
+    .bind(&contract.category)
+    .bind(&contract.tags)
+    .bind(&contract.license)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("apply contract patch", err))?;
+
+    audit::record(
+        &mut *tx,
+        &AuditEntry::metadata_updated(contract_uuid, &auth.publisher_address, &changes),
+    )
+    .await
+    .map_err(|err| db_internal_error("record contract patch audit", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit contract patch", err))?;
+
+    Ok(Json(contract))
+}
+
+/// DELETE /api/contracts/:id — soft-delete the contract. It disappears from
+/// listings and lookups at once; the purge task removes the row after
+/// `SOFT_DELETE_RETENTION_DAYS`. Restricted to the publisher (or an admin).
+pub async fn delete_contract(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidContractId",
+            format!("Invalid contract ID format: {}", id),
+        )
+    })?;
+
+    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
+        .bind(contract_uuid)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for delete", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))?;
+
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin contract delete", err))?;
+
+    // A concurrent delete that got here first leaves nothing to update
+    let deleted_at: Option<chrono::DateTime<chrono::Utc>> = sqlx::query_scalar(
+        "UPDATE contracts SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL RETURNING deleted_at",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("soft-delete contract", err))?;
+    let Some(deleted_at) = deleted_at else {
+        return Err(ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)));
+    };
+
+    audit::record(&mut *tx, &AuditEntry::deleted(contract_uuid, &auth.publisher_address, deleted_at))
+        .await
+        .map_err(|err| db_internal_error("record contract delete audit", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit contract delete", err))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Build the row inserted when `origin` is promoted to another network.
+///
+/// Metadata is copied verbatim; verification status is not, since the
+/// target deployment has not been verified yet.
+pub fn promoted_contract(origin: &Contract, req: &PromoteNetworkRequest) -> ApiResult<Contract> {
+    if origin.network.to_string() == req.target_network.to_string() {
+        return Err(ApiError::bad_request(
+            "SameNetwork",
+            format!("Contract is already on {}", req.target_network),
+        ));
+    }
+
+    let contract_id = req
+        .contract_id
+        .clone()
+        .unwrap_or_else(|| origin.contract_id.clone());
+    crate::validation::validate_contract_id(&contract_id)
+        .map_err(|e| ApiError::bad_request("InvalidContractId", e))?;
+
+    let mut config_map = serde_json::Map::new();
+    config_map.insert(
+        req.target_network.to_string(),
+        json!({
+            "contract_id": contract_id,
+            "is_verified": false,
+            "min_version": null,
+            "max_version": null
+        }),
+    );
+
+    let now = chrono::Utc::now();
+    Ok(Contract {
+        id: Uuid::new_v4(),
+        network_configs: Some(Value::Object(config_map)),
+        contract_id,
+        network: req.target_network.clone(),
+        is_verified: false,
+        created_at: now,
+        updated_at: now,
+        logical_id: Some(origin.logical_id.unwrap_or(origin.id)),
+        origin_contract_id: Some(origin.id),
+        drift_detected: false,
+        view_count: 0,
+        first_seen_at: None,
+        ..origin.clone()
+    })
+}
+
+/// POST /api/contracts/:id/promote-network — copy a contract's registry
+/// metadata onto another network, linking the new row back to its origin.
+pub async fn promote_contract_network(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<String>,
+    payload: Result<Json<PromoteNetworkRequest>, JsonRejection>,
+) -> ApiResult<(StatusCode, Json<Contract>)> {
+    let Json(req) = payload.map_err(map_json_rejection)?;
+    let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidContractId",
+            format!("Invalid contract ID format: {}", id),
+        )
+    })?;
+
+    let origin: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::RowNotFound => ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found with ID: {}", id),
+            ),
+            _ => db_internal_error("get contract for promotion", err),
+        })?;
+
+    ensure_contract_owner(&state, &origin, &auth).await?;
+
+    let promoted = promoted_contract(&origin, &req)?;
+    let conflict = || {
+        ApiError::conflict(
+            "ContractAlreadyRegistered",
+            format!(
+                "Contract {} is already registered for network {}",
+                promoted.contract_id, promoted.network
+            ),
+        )
+    };
+
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contracts WHERE contract_id = $1 AND network = $2)",
+    )
+    .bind(&promoted.contract_id)
+    .bind(&promoted.network)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check promotion target", err))?;
+    if exists {
+        return Err(conflict());
+    }
+
+    let contract: Contract = sqlx::query_as(
+        "INSERT INTO contracts (id, contract_id, wasm_hash, name, description, publisher_id, network,
+                                is_verified, category, tags, maturity, logical_id, network_configs,
+                                origin_contract_id, license)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+         RETURNING *",
+    )
+    .bind(promoted.id)
+    .bind(&promoted.contract_id)
+    .bind(&promoted.wasm_hash)
+    .bind(&promoted.name)
+    .bind(&promoted.description)
+    .bind(promoted.publisher_id)
+    .bind(&promoted.network)
+    .bind(promoted.is_verified)
+    .bind(&promoted.category)
+    .bind(&promoted.tags)
+    .bind(&promoted.maturity)
+    .bind(promoted.logical_id)
+    .bind(&promoted.network_configs)
+    .bind(promoted.origin_contract_id)
+    .bind(&promoted.license)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| {
+        if let sqlx::Error::Database(ref e) = err {
+            if e.constraint() == Some("contracts_contract_id_network_key") {
+                return conflict();
+            }
+        }
+        db_internal_error("insert promoted contract", err)
+    })?;
+
+    audit::record(&state.db, &AuditEntry::deployed(origin.id, &contract, &auth.publisher_address))
+        .await
+        .map_err(|err| db_internal_error("record promotion audit", err))?;
+
+    Ok((StatusCode::CREATED, Json(contract)))
+}
+
 pub async fn get_contract_versions(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -299,7 +974,8 @@ pub async fn create_contract_version(
     Path(id): Path<String>,
     payload: Result<Json<CreateContractVersionRequest>, JsonRejection>,
 ) -> ApiResult<Json<ContractVersion>> {
-    let Json(req) = payload.map_err(map_json_rejection)?;
+    let Json(mut req) = payload.map_err(map_json_rejection)?;
+    req.wasm_hash = crate::validation::parse_wasm_hash(&req.wasm_hash)?;
 
     let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
     if !req.contract_id.trim().is_empty() && req.contract_id != contract_id {
@@ -313,6 +989,15 @@ pub async fn create_contract_version(
         ApiError::bad_request("InvalidVersion", "Version must be valid semver (e.g. 1.2.3)")
     })?;
 
+    let (publisher_id, publisher_address): (Uuid, String) = sqlx::query_as(
+        "SELECT p.id, p.stellar_address FROM contracts c JOIN publishers p ON p.id = c.publisher_id WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract publisher", err))?;
+    let quota = load_quota(&state, publisher_id).await?;
+
     let existing_versions: Vec<String> = sqlx::query_scalar(
         "SELECT version FROM contract_versions WHERE contract_id = $1",
     )
@@ -362,6 +1047,7 @@ pub async fn create_contract_version(
         .begin()
         .await
         .map_err(|err| db_internal_error("begin transaction", err))?;
+    enforce_publisher_quota(&mut tx, &quota, publisher_id, QuotaResource::Versions, 1).await?;
 
     let version_row: ContractVersion = sqlx::query_as(
         "INSERT INTO contract_versions (contract_id, version, wasm_hash, source_url, commit_hash, release_notes) \
@@ -399,10 +1085,16 @@ pub async fn create_contract_version(
     .await
     .map_err(|err| db_internal_error("insert contract abi", err))?;
 
+    audit::record(&mut *tx, &AuditEntry::version_created(&version_row, &publisher_address))
+        .await
+        .map_err(|err| db_internal_error("record version audit", err))?;
+
     tx.commit()
         .await
         .map_err(|err| db_internal_error("commit contract version", err))?;
 
+    crate::cost_handlers::invalidate_cost_estimates(&state.cache, contract_uuid).await;
+
     Ok(Json(version_row))
 }
 
@@ -431,12 +1123,22 @@ async fn fetch_contract_identity(state: &AppState, id: &str) -> ApiResult<(Uuid,
 
 pub async fn publish_contract(
     State(state): State<AppState>,
-    payload: Result<Json<PublishRequest>, JsonRejection>,
-) -> ApiResult<Json<Contract>> {
-    let Json(req) = payload.map_err(map_json_rejection)?;
-
-    crate::validation::validate_contract_id(&req.contract_id)
-        .map_err(|e| ApiError::bad_request("InvalidContractId", e))?;
+    payload: Result<Json<Value>, JsonRejection>,
+) -> ApiResult<Json<PublishResponse>> {
+    let Json(body) = payload.map_err(map_json_rejection)?;
+    let ownership_signature = body
+        .get("ownership_signature")
+        .and_then(Value::as_str)
+        .map(str::to_string);
+    let req: PublishRequest = crate::validation::parse_publish_request(body)?;
+
+    if crate::ownership_proof::required_from_env() {
+        crate::ownership_proof::verify_ownership(
+            &state.auth_mgr,
+            &req.publisher_address,
+            ownership_signature.as_deref(),
+        )?;
+    }
 
     let publisher: Publisher = sqlx::query_as(
         "INSERT INTO publishers (stellar_address) VALUES ($1)
@@ -448,7 +1150,30 @@ pub async fn publish_contract(
     .await
     .map_err(|err| db_internal_error("upsert publisher", err))?;
 
-    let wasm_hash = "placeholder_hash".to_string();
+    let quota = load_quota(&state, publisher.id).await?;
+
+    let warnings = network_lifecycle::load_lifecycle(&state.db, &req.network)
+        .await
+        .map_err(|err| db_internal_error("load network lifecycle", err))?
+        .map(|lifecycle| network_lifecycle::publish_warnings(&lifecycle))
+        .unwrap_or_default();
+
+    let on_chain = crate::onchain::ensure_contract_on_chain(
+        state.onchain.as_deref(),
+        &req.network,
+        &req.contract_id,
+    )
+    .await?;
+
+    let spam_policy = crate::spam::SpamPolicy::from_env();
+    let spam_signals = crate::spam::load_signals(&state.db, publisher.id, &req.name, req.description.as_deref())
+        .await
+        .map_err(|err| db_internal_error("load spam signals", err))?;
+    let spam = crate::spam::assess(&spam_signals, &spam_policy);
+
+    let wasm_hash = on_chain
+        .map(|c| crate::validation::normalize_wasm_hash(&c.wasm_hash))
+        .unwrap_or_else(|| crate::onchain::PLACEHOLDER_WASM_HASH.to_string());
     let network_key = req.network.to_string();
     let mut config_map = serde_json::Map::new();
     config_map.insert(
@@ -462,9 +1187,16 @@ pub async fn publish_contract(
     );
     let network_configs = serde_json::Value::Object(config_map);
 
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin transaction", err))?;
+    enforce_publisher_quota(&mut tx, &quota, publisher.id, QuotaResource::Contracts, 1).await?;
+
     let contract: Contract = sqlx::query_as(
-        "INSERT INTO contracts (contract_id, wasm_hash, name, description, publisher_id, network, category, tags, logical_id, network_configs)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        "INSERT INTO contracts (contract_id, wasm_hash, name, description, publisher_id, network, category, tags, logical_id, network_configs, license)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
          RETURNING *"
     )
     .bind(&req.contract_id)
@@ -477,7 +1209,8 @@ pub async fn publish_contract(
     .bind(&req.tags)
     .bind(Option::<Uuid>::None as Option<Uuid>)
     .bind(&network_configs)
-    .fetch_one(&state.db)
+    .bind(&req.license)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|err| {
         if let sqlx::Error::Database(ref e) = err {
@@ -494,6 +1227,9 @@ pub async fn publish_contract(
         }
         db_internal_error("create contract", err)
     })?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit publish", err))?;
 
     // Set logical_id = id so this row is its own logical contract (Issue #43)
     let _ = sqlx::query("UPDATE contracts SET logical_id = id WHERE id = $1")
@@ -507,7 +1243,21 @@ pub async fn publish_contract(
         .await
         .map_err(|err| db_internal_error("fetch contract after insert", err))?;
 
-    Ok(Json(contract))
+    audit::record(&state.db, &AuditEntry::published(&contract, &req.publisher_address))
+        .await
+        .map_err(|err| db_internal_error("record publish audit", err))?;
+
+    let mut warnings = warnings;
+    warnings.extend(crate::validation::publish_warnings(&req, &contract.maturity));
+    if spam.needs_review(&spam_policy) {
+        crate::spam::enqueue(&state.db, contract.id, &spam, crate::spam::HEURISTIC_FLAGGER)
+            .await
+            .map_err(|err| db_internal_error("queue contract for review", err))?;
+        tracing::info!(contract_id = %contract.id, score = spam.score, "publish held for spam review");
+        warnings.push(crate::spam::review_warning(&spam));
+    }
+
+    Ok(Json(PublishResponse { contract, warnings }))
 }
 
 pub async fn create_publisher(
@@ -571,7 +1321,7 @@ pub async fn get_publisher_contracts(
     })?;
 
     let contracts: Vec<Contract> = sqlx::query_as(
-        "SELECT * FROM contracts WHERE publisher_id = $1 ORDER BY created_at DESC",
+        "SELECT * FROM contracts WHERE publisher_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
     )
     .bind(publisher_uuid)
     .fetch_all(&state.db)
@@ -586,20 +1336,199 @@ pub async fn get_contract_abi() -> impl IntoResponse {
     Json(json!({"abi": null}))
 }
 
-pub async fn get_contract_state() -> impl IntoResponse {
-    Json(json!({"state": {}}))
+/// Query params for GET /api/contracts/:id/analytics
+#[derive(Debug, serde::Deserialize)]
+pub struct AnalyticsQuery {
+    #[serde(default)]
+    pub interval: TimelineInterval,
+    /// Window length in days; defaults per interval
+    pub days: Option<i64>,
+    /// `next_cursor` from the previous page; the window then ends just
+    /// before that bucket instead of today
+    pub cursor: Option<String>,
 }
 
-pub async fn update_contract_state() -> impl IntoResponse {
-    Json(json!({"success": true}))
+/// Dates covered by one page of the analytics timeline, inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimelineWindow {
+    pub from: chrono::NaiveDate,
+    pub to: chrono::NaiveDate,
 }
 
-pub async fn get_contract_analytics() -> impl IntoResponse {
-    Json(json!({"analytics": {}}))
+impl TimelineWindow {
+    /// The `days`-long window ending today, or, with a cursor, ending the day
+    /// before the cursor's bucket so pages never overlap.
+    pub fn ending_before(
+        interval: TimelineInterval,
+        days: i64,
+        cursor: Option<chrono::NaiveDate>,
+        today: chrono::NaiveDate,
+    ) -> Self {
+        let to = match cursor {
+            Some(cursor) => (interval.truncate(cursor) - chrono::Duration::days(1)).min(today),
+            None => today,
+        };
+        Self {
+            from: to - chrono::Duration::days(days - 1),
+            to,
+        }
+    }
+
+    /// Cursor for the page before this one: the first bucket this page
+    /// returned, as long as events older than it exist.
+    pub fn next_cursor(&self, interval: TimelineInterval, earliest: Option<chrono::NaiveDate>) -> Option<String> {
+        let first_bucket = interval.truncate(self.from);
+        earliest
+            .filter(|earliest| *earliest < first_bucket)
+            .map(|_| first_bucket.format("%Y-%m-%d").to_string())
+    }
 }
 
-pub async fn get_trust_score() -> impl IntoResponse {
-    Json(json!({"score": 0}))
+fn parse_timeline_cursor(raw: &str) -> ApiResult<chrono::NaiveDate> {
+    chrono::NaiveDate::parse_from_str(raw.trim(), "%Y-%m-%d")
+        .map_err(|_| ApiError::bad_request("InvalidCursor", format!("Invalid timeline cursor: {}", raw)))
+}
+
+/// Expand `(bucket_start, count)` rows into one entry per bucket between
+/// `from` and `to` inclusive, zero-filling buckets with no events.
+pub fn zero_fill_timeline(
+    rows: &[(chrono::NaiveDate, i64)],
+    interval: TimelineInterval,
+    from: chrono::NaiveDate,
+    to: chrono::NaiveDate,
+) -> Vec<TimelineEntry> {
+    let counts: std::collections::HashMap<chrono::NaiveDate, i64> = rows
+        .iter()
+        .map(|(date, count)| (interval.truncate(*date), *count))
+        .collect();
+
+    let mut timeline = Vec::new();
+    let mut bucket = interval.truncate(from);
+    let last = interval.truncate(to);
+    while bucket <= last {
+        timeline.push(TimelineEntry {
+            date: bucket,
+            count: counts.get(&bucket).copied().unwrap_or(0),
+        });
+        bucket = interval.next(bucket);
+    }
+    timeline
+}
+
+/// Get analytics for a specific contract; `?interval=day|week|month` sets
+/// the timeline bucket size and `?cursor=` pages the timeline backwards. When the contract's `public_analytics` flag is
+/// off only its publisher (or an admin) may read them.
+pub async fn get_contract_analytics(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Query(query): Query<AnalyticsQuery>,
+) -> ApiResult<Json<ContractAnalyticsResponse>> {
+    crate::contract_flags::ensure_analytics_access(&state, id, &headers).await?;
+
+    let interval = query.interval;
+    let days = query
+        .days
+        .unwrap_or_else(|| interval.default_window_days())
+        .clamp(1, 3650);
+    let cursor = query.cursor.as_deref().map(parse_timeline_cursor).transpose()?;
+    let window = TimelineWindow::ending_before(interval, days, cursor, chrono::Utc::now().date_naive());
+
+    let deploy_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM analytics_events \
+         WHERE contract_id = $1 AND event_type = 'contract_deployed'",
+    )
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| db_internal_error("deployment count", e))?;
+
+    let unique_deployers: i64 = sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT user_address) FROM analytics_events \
+         WHERE contract_id = $1 AND event_type = 'contract_deployed' AND user_address IS NOT NULL",
+    )
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| db_internal_error("unique deployers", e))?;
+
+    let by_network: Value = sqlx::query_scalar(
+        r#"
+        SELECT COALESCE(
+            jsonb_object_agg(COALESCE(network::text, 'unknown'), cnt),
+            '{}'::jsonb
+        )
+        FROM (
+            SELECT network, COUNT(*) AS cnt
+            FROM analytics_events
+            WHERE contract_id = $1 AND event_type = 'contract_deployed'
+            GROUP BY network
+        ) sub
+        "#,
+    )
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| db_internal_error("network breakdown", e))?;
+
+    let unique_count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT user_address) FROM analytics_events \
+         WHERE contract_id = $1 AND user_address IS NOT NULL",
+    )
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| db_internal_error("unique interactors", e))?;
+
+    let top_user_rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT user_address, COUNT(*) AS cnt FROM analytics_events \
+         WHERE contract_id = $1 AND user_address IS NOT NULL \
+         GROUP BY user_address ORDER BY cnt DESC LIMIT 10",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| db_internal_error("top users", e))?;
+
+    let timeline_rows: Vec<(chrono::NaiveDate, i64)> = sqlx::query_as(
+        "SELECT date_trunc($3, created_at)::date AS bucket, COUNT(*) AS cnt \
+         FROM analytics_events \
+         WHERE contract_id = $1 AND created_at >= date_trunc($3, $2::date::timestamptz) \
+           AND created_at < $4::date + 1 \
+         GROUP BY bucket ORDER BY bucket",
+    )
+    .bind(id)
+    .bind(window.from)
+    .bind(interval.as_sql())
+    .bind(window.to)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| db_internal_error("timeline", e))?;
+
+    let earliest: Option<chrono::NaiveDate> =
+        sqlx::query_scalar("SELECT MIN(created_at)::date FROM analytics_events WHERE contract_id = $1")
+            .bind(id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|e| db_internal_error("earliest analytics event", e))?;
+
+    Ok(Json(ContractAnalyticsResponse {
+        contract_id: id,
+        deployments: DeploymentStats {
+            count: deploy_count,
+            unique_users: unique_deployers,
+            by_network,
+        },
+        interactors: InteractorStats {
+            unique_count,
+            top_users: top_user_rows
+                .into_iter()
+                .map(|(address, count)| TopUser { address, count })
+                .collect(),
+        },
+        timeline: zero_fill_timeline(&timeline_rows, interval, window.from, window.to),
+        next_cursor: window.next_cursor(interval, earliest),
+    }))
 }
 
 pub async fn get_contract_dependencies() -> impl IntoResponse {
@@ -618,10 +1547,6 @@ pub async fn get_trending_contracts() -> impl IntoResponse {
     Json(json!({"trending": []}))
 }
 
-pub async fn verify_contract() -> impl IntoResponse {
-    Json(json!({"verified": true}))
-}
-
 pub async fn get_deployment_status() -> impl IntoResponse {
     Json(json!({"status": "pending"}))
 }
@@ -637,3 +1562,609 @@ pub async fn get_contract_performance() -> impl IntoResponse {
 pub async fn route_not_found() -> impl IntoResponse {
     (StatusCode::NOT_FOUND, Json(json!({"error": "Route not found"})))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::MaturityLevel;
+
+    fn sample_contract() -> Contract {
+        Contract {
+            id: Uuid::new_v4(),
+            contract_id: "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".into(),
+            wasm_hash: "hash".into(),
+            name: "Token".into(),
+            description: Some("A token".into()),
+            publisher_id: Uuid::new_v4(),
+            network: Network::Testnet,
+            is_verified: false,
+            category: None,
+            tags: vec!["defi".into()],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            is_maintenance: false,
+            maturity: MaturityLevel::Alpha,
+            logical_id: None,
+            network_configs: None,
+            origin_contract_id: None,
+            drift_detected: false,
+            view_count: 0,
+            first_seen_at: None,
+            license: Some("MIT".into()),
+        }
+    }
+
+    fn on_network(network: Network) -> Contract {
+        Contract {
+            id: Uuid::new_v4(),
+            network,
+            ..sample_contract()
+        }
+    }
+
+    fn candidates() -> Vec<Contract> {
+        vec![on_network(Network::Mainnet), on_network(Network::Testnet)]
+    }
+
+    #[tokio::test]
+    async fn ambiguous_contract_id_returns_the_candidate_networks() {
+        let id = sample_contract().contract_id;
+        let err = resolve_contract_network(&id, candidates(), None, None).unwrap_err();
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["error"], "AmbiguousContractId");
+        assert_eq!(body["details"]["candidates"], json!(["mainnet", "testnet"]));
+    }
+
+    #[test]
+    fn explicit_or_default_network_disambiguates() {
+        let id = sample_contract().contract_id;
+        let picked = resolve_contract_network(&id, candidates(), Some(&Network::Testnet), None).unwrap();
+        assert_eq!(picked.network.to_string(), "testnet");
+
+        let picked = resolve_contract_network(&id, candidates(), None, Some(&Network::Mainnet)).unwrap();
+        assert_eq!(picked.network.to_string(), "mainnet");
+
+        // A default the contract isn't registered on doesn't resolve anything
+        let err = resolve_contract_network(&id, candidates(), None, Some(&Network::Futurenet)).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+    }
+
+    #[test]
+    fn single_network_contracts_need_no_disambiguator() {
+        let id = sample_contract().contract_id;
+        let only = vec![on_network(Network::Testnet)];
+        assert!(resolve_contract_network(&id, only.clone(), None, None).is_ok());
+
+        let err = resolve_contract_network(&id, only, Some(&Network::Mainnet), None).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::NOT_FOUND);
+        let err = resolve_contract_network(&id, Vec::new(), None, None).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[test]
+    fn patch_applies_only_provided_fields() {
+        let mut contract = sample_contract();
+        let patch = PatchContractRequest {
+            category: Some(Some("defi".into())),
+            ..Default::default()
+        };
+
+        let changes = apply_contract_patch(&mut contract, &patch);
+
+        assert_eq!(changes.len(), 1);
+        assert_eq!(contract.category.as_deref(), Some("defi"));
+        assert_eq!(contract.name, "Token");
+        assert_eq!(contract.description.as_deref(), Some("A token"));
+        assert_eq!(contract.tags, vec!["defi".to_string()]);
+    }
+
+    #[test]
+    fn patch_diff_records_before_and_after() {
+        let mut contract = sample_contract();
+        let patch = PatchContractRequest {
+            name: Some("Token v2".into()),
+            tags: Some(vec!["defi".into()]),
+            license: Some("Apache-2.0".into()),
+            ..Default::default()
+        };
+
+        let changes = apply_contract_patch(&mut contract, &patch);
+
+        // Unchanged tags are not reported.
+        let fields: Vec<&str> = changes.iter().map(|c| c.field.as_str()).collect();
+        assert_eq!(fields, vec!["name", "license"]);
+        assert_eq!(changes[0].from, json!("Token"));
+        assert_eq!(changes[0].to, json!("Token v2"));
+        assert_eq!(changes[1].from, json!("MIT"));
+        assert_eq!(changes[1].to, json!("Apache-2.0"));
+    }
+
+    #[test]
+    fn patch_null_clears_description_and_category() {
+        let mut contract = sample_contract();
+        contract.category = Some("defi".into());
+        let patch: PatchContractRequest = serde_json::from_value(json!({
+            "description": null,
+            "category": null
+        }))
+        .unwrap();
+
+        let changes = apply_contract_patch(&mut contract, &patch);
+
+        assert_eq!(contract.description, None);
+        assert_eq!(contract.category, None);
+        assert_eq!(changes[0].field, "description");
+        assert_eq!(changes[0].from, json!("A token"));
+        assert_eq!(changes[0].to, json!(null));
+        assert_eq!(changes[1].field, "category");
+
+        // Omitted fields are not cleared
+        let mut contract = sample_contract();
+        let patch: PatchContractRequest = serde_json::from_value(json!({ "name": "x" })).unwrap();
+        apply_contract_patch(&mut contract, &patch);
+        assert_eq!(contract.description.as_deref(), Some("A token"));
+    }
+
+    #[test]
+    fn patch_sets_the_license() {
+        let mut contract = sample_contract();
+        let patch = PatchContractRequest {
+            license: Some("Apache-2.0".into()),
+            ..Default::default()
+        };
+
+        let changes = apply_contract_patch(&mut contract, &patch);
+        assert_eq!(contract.license.as_deref(), Some("Apache-2.0"));
+        assert_eq!(changes[0].field, "license");
+        assert_eq!(changes[0].from, json!("MIT"));
+        assert_eq!(changes[0].to, json!("Apache-2.0"));
+
+        // Omitting the field leaves it alone
+        assert!(apply_contract_patch(&mut contract, &PatchContractRequest::default()).is_empty());
+    }
+
+    #[test]
+    fn patch_rejects_unknown_fields() {
+        let err = serde_json::from_value::<PatchContractRequest>(json!({
+            "name": "x",
+            "wasm_hash": "override"
+        }));
+        assert!(err.is_err());
+
+        // Maturity only changes through the promotion gate
+        let err = serde_json::from_value::<PatchContractRequest>(json!({ "maturity": "stable" }));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn promotion_copies_metadata_and_links_origin() {
+        let origin = sample_contract();
+        let req = PromoteNetworkRequest {
+            target_network: Network::Mainnet,
+            contract_id: None,
+        };
+
+        let promoted = promoted_contract(&origin, &req).unwrap();
+
+        assert_ne!(promoted.id, origin.id);
+        assert_eq!(promoted.contract_id, origin.contract_id);
+        assert_eq!(promoted.name, origin.name);
+        assert_eq!(promoted.tags, origin.tags);
+        assert_eq!(promoted.publisher_id, origin.publisher_id);
+        assert_eq!(promoted.network.to_string(), "mainnet");
+        assert_eq!(promoted.origin_contract_id, Some(origin.id));
+        assert_eq!(promoted.logical_id, Some(origin.id));
+        assert!(!promoted.is_verified);
+        assert!(promoted.network_configs.unwrap().get("mainnet").is_some());
+    }
+
+    #[test]
+    fn promotion_to_same_network_is_rejected() {
+        let origin = sample_contract();
+        let req = PromoteNetworkRequest {
+            target_network: Network::Testnet,
+            contract_id: None,
+        };
+
+        let err = promoted_contract(&origin, &req).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn field_selection_projects_requested_fields() {
+        let fields = parse_field_selection(Some("id, name,network")).unwrap().unwrap();
+        let contract = sample_contract();
+
+        let projected = project_fields(json!(contract), &fields);
+        let obj = projected.as_object().unwrap();
+
+        assert_eq!(obj.len(), 3);
+        assert_eq!(obj["id"], json!(contract.id));
+        assert_eq!(obj["name"], json!("Token"));
+        assert_eq!(obj["network"], json!("testnet"));
+    }
+
+    #[test]
+    fn field_selection_rejects_unknown_fields() {
+        assert!(parse_field_selection(None).unwrap().is_none());
+
+        let err = parse_field_selection(Some("id,secret")).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let err = parse_field_selection(Some(" , ")).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[test]
+    fn listing_items_carry_highlights_only_for_queries() {
+        let contract = sample_contract();
+
+        let plain = listing_item(&contract, None, None);
+        assert!(plain.get("highlights").is_none());
+
+        let highlights = search_highlight::build_highlights(&contract, "tok", None);
+        let searched = listing_item(&contract, None, Some(highlights));
+        assert_eq!(searched["highlights"]["name"], json!("<mark>Tok</mark>en"));
+        assert_eq!(searched["name"], json!("Token"));
+    }
+
+    #[test]
+    fn weekly_timeline_buckets_and_zero_fills() {
+        let d = |m, day| chrono::NaiveDate::from_ymd_opt(2026, m, day).unwrap();
+        // Wednesday 2026-09-02 through Friday 2026-09-25 spans four ISO weeks.
+        let rows = vec![(d(8, 31), 3), (d(9, 14), 5)];
+
+        let timeline = zero_fill_timeline(&rows, TimelineInterval::Week, d(9, 2), d(9, 25));
+
+        assert_eq!(
+            timeline,
+            vec![
+                TimelineEntry { date: d(8, 31), count: 3 },
+                TimelineEntry { date: d(9, 7), count: 0 },
+                TimelineEntry { date: d(9, 14), count: 5 },
+                TimelineEntry { date: d(9, 21), count: 0 },
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_and_daily_timelines_cover_the_window() {
+        let d = |m, day| chrono::NaiveDate::from_ymd_opt(2026, m, day).unwrap();
+
+        let monthly = zero_fill_timeline(&[(d(2, 1), 7)], TimelineInterval::Month, d(1, 15), d(3, 2));
+        let dates: Vec<_> = monthly.iter().map(|e| e.date).collect();
+        assert_eq!(dates, vec![d(1, 1), d(2, 1), d(3, 1)]);
+        assert_eq!(monthly[1].count, 7);
+
+        let daily = zero_fill_timeline(&[], TimelineInterval::Day, d(3, 1), d(3, 30));
+        assert_eq!(daily.len(), 30);
+        assert!(daily.iter().all(|e| e.count == 0));
+    }
+
+    #[test]
+    fn paging_backward_covers_a_multi_month_timeline_once() {
+        let d = |m, day| chrono::NaiveDate::from_ymd_opt(2026, m, day).unwrap();
+        let today = d(10, 16);
+        // One event every ten days from mid-June
+        let events: Vec<chrono::NaiveDate> = (0..13).map(|n| d(6, 15) + chrono::Duration::days(n * 10)).collect();
+        let earliest = events.iter().min().copied();
+
+        let mut cursor: Option<String> = None;
+        let mut pages = Vec::new();
+        loop {
+            let parsed = cursor.as_deref().map(|c| parse_timeline_cursor(c).unwrap());
+            let window = TimelineWindow::ending_before(TimelineInterval::Day, 30, parsed, today);
+            let rows: Vec<(chrono::NaiveDate, i64)> = events
+                .iter()
+                .filter(|e| **e >= window.from && **e <= window.to)
+                .map(|e| (*e, 1))
+                .collect();
+            pages.push(zero_fill_timeline(&rows, TimelineInterval::Day, window.from, window.to));
+            cursor = window.next_cursor(TimelineInterval::Day, earliest);
+            if cursor.is_none() {
+                break;
+            }
+        }
+
+        // 2026-06-15 .. 2026-10-16 is 124 days: four full pages and a short tail
+        assert_eq!(pages.len(), 5);
+        assert_eq!(pages[0].last().unwrap().date, today);
+        assert_eq!(pages[1].last().unwrap().date, pages[0][0].date - chrono::Duration::days(1));
+        assert_eq!(pages[1][0].date, d(8, 18));
+
+        let mut dates: Vec<_> = pages.iter().flatten().map(|e| e.date).collect();
+        dates.sort();
+        dates.dedup();
+        assert_eq!(dates.len(), pages.iter().map(Vec::len).sum::<usize>(), "pages overlap");
+        assert!(dates[0] <= d(6, 15));
+        assert_eq!(pages.iter().flatten().map(|e| e.count).sum::<i64>(), 13);
+    }
+
+    #[test]
+    fn weekly_cursor_starts_the_next_page_at_the_previous_bucket() {
+        let d = |m, day| chrono::NaiveDate::from_ymd_opt(2026, m, day).unwrap();
+        // Cursor is a Monday bucket; the previous page must end with the week before it
+        let window = TimelineWindow::ending_before(TimelineInterval::Week, 28, Some(d(9, 14)), d(10, 16));
+        assert_eq!(window.to, d(9, 13));
+        let timeline = zero_fill_timeline(&[], TimelineInterval::Week, window.from, window.to);
+        assert_eq!(timeline.last().unwrap().date, d(9, 7));
+
+        assert_eq!(window.next_cursor(TimelineInterval::Week, Some(d(9, 1))), None);
+        assert_eq!(
+            window.next_cursor(TimelineInterval::Week, Some(d(1, 1))).as_deref(),
+            Some("2026-08-17")
+        );
+        assert!(parse_timeline_cursor("last-week").is_err());
+    }
+
+    /// The filter clause alone, with `$n` placeholders for its bound values
+    fn filter_sql(params: &ContractSearchParams) -> String {
+        let mut qb = QueryBuilder::new("");
+        push_contract_filters(&mut qb, params);
+        qb.into_sql()
+    }
+
+    #[test]
+    fn exclusions_are_added_to_the_filter() {
+        let params = ContractSearchParams {
+            category: Some("DeFi".into()),
+            exclude_tags: Some(" deprecated , ,o'brien".into()),
+            exclude_category: Some("NFT".into()),
+            ..Default::default()
+        };
+
+        let clause = filter_sql(&params);
+        assert!(clause.contains(" AND c.category = $1"));
+        assert!(clause.contains(" AND (c.category IS NULL OR NOT c.category = ANY($2))"));
+        assert!(clause.contains(" AND NOT (COALESCE(c.tags, '{}') && $3::text[])"));
+    }
+
+    #[test]
+    fn request_values_never_reach_the_sql_text() {
+        let hostile = "x'; DROP TABLE contracts; --";
+        let params = ContractSearchParams {
+            query: Some(hostile.into()),
+            category: Some(hostile.into()),
+            exclude_category: Some(hostile.into()),
+            exclude_tags: Some(hostile.into()),
+            tags: Some(vec![hostile.into()]),
+            license: Some(hostile.into()),
+            ..Default::default()
+        };
+
+        let clause = filter_sql(&params);
+        assert!(!clause.contains("DROP"));
+        assert!(clause.contains("c.name ILIKE '%' || $1 || '%'"));
+        assert!(!search_facets::facet_query(&params).into_sql().contains("DROP"));
+    }
+
+    #[test]
+    fn tag_and_maturity_facets_narrow_the_filter() {
+        let params = ContractSearchParams {
+            tags: Some(vec!["defi".into(), " ".into(), "o'brien".into()]),
+            maturity: Some(shared::MaturityLevel::Stable),
+            ..Default::default()
+        };
+
+        let clause = filter_sql(&params);
+        assert!(clause.contains(" AND COALESCE(c.tags, '{}') @> $1::text[]"));
+        assert!(clause.contains(" AND c.maturity = $2"));
+    }
+
+    #[test]
+    fn license_filter_matches_canonical_ids_within_expressions() {
+        let params = ContractSearchParams {
+            license: Some("mit, apache-2.0,O'Hare".into()),
+            ..Default::default()
+        };
+
+        let clause = filter_sql(&params);
+        assert!(clause.contains(" AND regexp_split_to_array(c.license, '[\\s()]+') && $1::text[]"));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn license_filter_finds_contracts_by_any_listed_license() {
+        let pool = crate::fixtures::test_pool().await;
+        let ids: Vec<Uuid> = crate::fixtures::fixtures().contracts.iter().map(|c| c.id).collect();
+
+        for (id, license) in [(ids[0], "MIT"), (ids[1], "(MIT OR Apache-2.0) AND BSD-3-Clause"), (ids[2], "GPL-3.0-only")] {
+            sqlx::query("UPDATE contracts SET license = $2 WHERE id = $1")
+                .bind(id)
+                .bind(license)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+
+        let matching = |license: &str| {
+            let pool = pool.clone();
+            let params = ContractSearchParams {
+                query: Some("Fixture Contract".into()),
+                license: Some(license.into()),
+                ..Default::default()
+            };
+            async move {
+                let mut query = QueryBuilder::new("SELECT c.id FROM contracts c WHERE 1=1");
+                push_contract_filters(&mut query, &params);
+                let mut found: Vec<Uuid> = query.build_query_scalar().fetch_all(&pool).await.unwrap();
+                found.sort();
+                found
+            }
+        };
+        let sorted = |mut v: Vec<Uuid>| {
+            v.sort();
+            v
+        };
+
+        assert_eq!(matching("mit").await, sorted(vec![ids[0], ids[1]]));
+        assert_eq!(matching("Apache-2.0").await, vec![ids[1]]);
+        assert_eq!(matching("BSD-3-Clause,GPL-3.0-only").await, sorted(vec![ids[1], ids[2]]));
+        assert!(matching("Unlicense").await.is_empty());
+
+        sqlx::query("UPDATE contracts SET license = NULL WHERE id = ANY($1)")
+            .bind(&ids)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn blank_exclusions_are_ignored() {
+        let params = ContractSearchParams {
+            exclude_tags: Some(" , ".into()),
+            exclude_category: Some(String::new()),
+            ..Default::default()
+        };
+        assert_eq!(filter_sql(&params), filter_sql(&ContractSearchParams::default()));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn excluded_tag_removes_an_otherwise_matching_contract() {
+        let pool = crate::fixtures::test_pool().await;
+
+        let count = |params: ContractSearchParams| {
+            let pool = pool.clone();
+            async move {
+                let mut query = QueryBuilder::new("SELECT COUNT(*) FROM contracts c WHERE 1=1");
+                push_contract_filters(&mut query, &params);
+                query.build_query_scalar::<i64>().fetch_one(&pool).await.unwrap()
+            }
+        };
+        let matching = || ContractSearchParams {
+            query: Some("Fixture Contract".into()),
+            category: Some("DeFi".into()),
+            ..Default::default()
+        };
+
+        assert!(count(matching()).await > 0);
+        let excluded = ContractSearchParams {
+            exclude_tags: Some("fixture".into()),
+            ..matching()
+        };
+        assert_eq!(count(excluded).await, 0);
+        let other_tag = ContractSearchParams {
+            exclude_tags: Some("unrelated".into()),
+            ..matching()
+        };
+        assert_eq!(count(other_tag).await, count(matching()).await);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn snapshot_count_ignores_a_publish_between_page_and_count() {
+        let pool = crate::fixtures::test_pool().await;
+        let count_sql = "SELECT COUNT(*) FROM contracts c WHERE 1=1";
+
+        let mut tx = begin_snapshot(&pool).await.unwrap();
+        let page: Vec<Contract> = sqlx::query_as("SELECT c.* FROM contracts c")
+            .fetch_all(&mut *tx)
+            .await
+            .unwrap();
+
+        // A publish commits on another connection between the two queries
+        let publisher_id: Uuid = sqlx::query_scalar("SELECT id FROM publishers LIMIT 1")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let inserted: Uuid = sqlx::query_scalar(
+            "INSERT INTO contracts (contract_id, wasm_hash, name, publisher_id, network)
+             VALUES ($1, $2, 'Concurrent Publish', $3, 'testnet') RETURNING id",
+        )
+        .bind(format!("CONCURRENT{}", Uuid::new_v4().simple()))
+        .bind("ab".repeat(32))
+        .bind(publisher_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let total: i64 = sqlx::query_scalar(count_sql).fetch_one(&mut *tx).await.unwrap();
+        tx.commit().await.unwrap();
+        let after: i64 = sqlx::query_scalar(count_sql).fetch_one(&pool).await.unwrap();
+        sqlx::query("DELETE FROM contracts WHERE id = $1")
+            .bind(inserted)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        assert_eq!(total, page.len() as i64);
+        assert_eq!(after, total + 1);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn deleted_contract_leaves_listings_and_lookups() {
+        use crate::auth_middleware::AuthContext;
+        use axum::response::IntoResponse;
+
+        let state = crate::fixtures::test_state().await;
+        let pool = state.db.clone();
+        let data = crate::fixtures::fixtures();
+        let owner = &data.publishers[0];
+        let stranger = &data.publishers[1];
+        let as_caller = |address: &str| Extension(AuthContext { publisher_address: address.to_string() });
+
+        let id: Uuid = sqlx::query_scalar(
+            "INSERT INTO contracts (contract_id, wasm_hash, name, publisher_id, network)
+             VALUES ($1, $2, 'Soon Deleted', $3, 'testnet') RETURNING id",
+        )
+        .bind(format!("DELETED{}", Uuid::new_v4().simple()))
+        .bind("ab".repeat(32))
+        .bind(owner.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let listed = || {
+            let mut query = QueryBuilder::new("SELECT COUNT(*) FROM contracts c WHERE c.id = ");
+            query.push_bind(id);
+            push_contract_filters(&mut query, &ContractSearchParams::default());
+            let pool = pool.clone();
+            async move { query.build_query_scalar::<i64>().fetch_one(&pool).await.unwrap() }
+        };
+        assert_eq!(listed().await, 1);
+
+        let err = delete_contract(State(state.clone()), as_caller(&stranger.stellar_address), Path(id.to_string()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let status = delete_contract(State(state.clone()), as_caller(&owner.stellar_address), Path(id.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert_eq!(listed().await, 0);
+
+        let lookup = get_contract(
+            State(state.clone()),
+            Path(id.to_string()),
+            Query(GetContractQuery { network: None, fields: None }),
+            HeaderMap::new(),
+            None,
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(lookup.into_response().status(), StatusCode::NOT_FOUND);
+
+        let again = delete_contract(State(state), as_caller(&owner.stellar_address), Path(id.to_string()))
+            .await
+            .unwrap_err();
+        assert_eq!(again.into_response().status(), StatusCode::NOT_FOUND);
+
+        let audited: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM contract_audit_log WHERE contract_id = $1 AND action_type = 'contract_deleted'",
+        )
+        .bind(id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(audited, 1);
+
+        sqlx::query("DELETE FROM contracts WHERE id = $1").bind(id).execute(&pool).await.unwrap();
+    }
+}
@@ -0,0 +1,178 @@
+// api/src/heatmap.rs
+// Per-day activity for a contribution-style heatmap.
+//
+//   GET /api/contracts/:id/heatmap?year=2026
+//
+// `counts[i]` is the number of events on `start_date + i` days, taken from
+// `analytics_daily_aggregates`. Days without an aggregate are zero, so the
+// array always has one entry per day of the year.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use shared::DailyAggregate;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+/// Earliest year the registry can hold data for
+const MIN_YEAR: i32 = 2020;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct HeatmapQuery {
+    pub year: Option<i32>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContractHeatmap {
+    pub contract_id: Uuid,
+    pub year: i32,
+    pub start_date: NaiveDate,
+    pub counts: Vec<i64>,
+    pub total: i64,
+    /// Busiest day's count, for scaling colours
+    pub max: i64,
+}
+
+fn year_bounds(year: i32) -> ApiResult<(NaiveDate, NaiveDate)> {
+    let current = Utc::now().year();
+    let first = NaiveDate::from_ymd_opt(year, 1, 1).filter(|_| (MIN_YEAR..=current).contains(&year));
+    let last = NaiveDate::from_ymd_opt(year, 12, 31);
+    match (first, last) {
+        (Some(first), Some(last)) => Ok((first, last)),
+        _ => Err(ApiError::bad_request(
+            "InvalidYear",
+            format!("year must be between {} and {}", MIN_YEAR, current),
+        )),
+    }
+}
+
+/// Zero-filled daily event counts for `year`.
+pub fn build_heatmap(contract_id: Uuid, year: i32, aggregates: &[DailyAggregate]) -> Option<ContractHeatmap> {
+    let start_date = NaiveDate::from_ymd_opt(year, 1, 1)?;
+    let days = NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+        .signed_duration_since(start_date)
+        .num_days() as usize;
+
+    let mut counts = vec![0i64; days];
+    for aggregate in aggregates.iter().filter(|a| a.date.year() == year) {
+        counts[aggregate.date.ordinal0() as usize] += i64::from(aggregate.total_events);
+    }
+
+    Some(ContractHeatmap {
+        contract_id,
+        year,
+        start_date,
+        total: counts.iter().sum(),
+        max: counts.iter().copied().max().unwrap_or(0),
+        counts,
+    })
+}
+
+/// GET /api/contracts/:id/heatmap
+pub async fn get_contract_heatmap(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<HeatmapQuery>,
+) -> ApiResult<Json<ContractHeatmap>> {
+    let year = query.year.unwrap_or_else(|| Utc::now().year());
+    let (first, last) = year_bounds(year)?;
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for heatmap", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            format!("No contract found with ID: {}", id),
+        ));
+    }
+
+    let aggregates: Vec<DailyAggregate> = sqlx::query_as(
+        "SELECT * FROM analytics_daily_aggregates
+         WHERE contract_id = $1 AND date BETWEEN $2 AND $3
+         ORDER BY date",
+    )
+    .bind(id)
+    .bind(first)
+    .bind(last)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load heatmap aggregates", err))?;
+
+    build_heatmap(id, year, &aggregates)
+        .map(Json)
+        .ok_or_else(|| ApiError::bad_request("InvalidYear", format!("invalid year {}", year)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    fn aggregate(date: NaiveDate, total_events: i32) -> DailyAggregate {
+        DailyAggregate {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::nil(),
+            date,
+            deployment_count: 0,
+            unique_deployers: 0,
+            verification_count: 0,
+            publish_count: 0,
+            version_count: 0,
+            total_events,
+            unique_users: 0,
+            network_breakdown: serde_json::json!({}),
+            top_users: serde_json::json!([]),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn array_has_one_entry_per_day_of_the_year() {
+        assert_eq!(build_heatmap(Uuid::nil(), 2023, &[]).unwrap().counts.len(), 365);
+        assert_eq!(build_heatmap(Uuid::nil(), 2024, &[]).unwrap().counts.len(), 366);
+    }
+
+    #[test]
+    fn counts_align_with_aggregates() {
+        let aggregates = vec![
+            aggregate(day(2024, 1, 1), 3),
+            aggregate(day(2024, 2, 29), 7),
+            aggregate(day(2024, 12, 31), 2),
+            // Outside the requested year
+            aggregate(day(2025, 1, 1), 100),
+        ];
+        let heatmap = build_heatmap(Uuid::nil(), 2024, &aggregates).unwrap();
+
+        assert_eq!(heatmap.start_date, day(2024, 1, 1));
+        assert_eq!(heatmap.counts[0], 3);
+        assert_eq!(heatmap.counts[59], 7);
+        assert_eq!(heatmap.counts[365], 2);
+        assert_eq!(heatmap.counts[1], 0);
+        assert_eq!(heatmap.total, 12);
+        assert_eq!(heatmap.max, 7);
+    }
+
+    #[test]
+    fn years_outside_the_supported_range_are_rejected() {
+        assert!(year_bounds(Utc::now().year()).is_ok());
+        let err = year_bounds(MIN_YEAR - 1).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+        assert!(year_bounds(Utc::now().year() + 1).is_err());
+    }
+}
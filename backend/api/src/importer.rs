@@ -0,0 +1,355 @@
+// api/src/importer.rs
+// Pre-filled publishes for contracts that already exist elsewhere.
+//
+//   GET /api/contracts/import/preview?contract_id=C...&network=testnet[&publisher_address=G...]
+//
+// Looks the contract up in an external metadata source (an explorer or another
+// registry) and maps whatever it knows onto a `PublishRequest`. Nothing is
+// written: the publisher reviews the draft, fills in `missing_fields` and
+// submits it to POST /api/contracts as usual.
+//
+// `METADATA_IMPORT_SOURCE` picks the source:
+//   none (default)  — importing is disabled and the endpoint returns 503
+//   stellar_expert  — Stellar Expert (stub; see `StellarExpertSource`)
+
+use async_trait::async_trait;
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use shared::{Network, PublishRequest};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+    validation::{validate_contract_id, Validatable},
+};
+
+/// Tags kept from an external source, matching the publish limit
+const MAX_IMPORTED_TAGS: usize = 10;
+const MAX_IMPORTED_TAG_LENGTH: usize = 50;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("metadata source is unavailable: {0}")]
+    Unavailable(String),
+}
+
+/// What an external source knows about a contract. Every field is optional
+/// because sources vary widely in what they record.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExternalMetadata {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Source repository, or failing that the project homepage
+    pub source_url: Option<String>,
+    pub homepage: Option<String>,
+}
+
+#[async_trait]
+pub trait MetadataSource: Send + Sync {
+    /// Short identifier reported back to the caller, e.g. `stellar_expert`
+    fn name(&self) -> &'static str;
+
+    /// `None` when the source has never seen the contract.
+    async fn fetch(&self, network: &Network, contract_id: &str) -> Result<Option<ExternalMetadata>, ImportError>;
+}
+
+pub type SharedMetadataSource = Arc<dyn MetadataSource>;
+
+/// Stellar Expert's contract directory.
+///
+/// Stub: the directory API is not wired up yet, so every lookup reports the
+/// source as unavailable. It exists so the provider can be selected and
+/// configured ahead of the real client.
+#[derive(Debug, Clone)]
+pub struct StellarExpertSource {
+    pub base_url: String,
+}
+
+impl StellarExpertSource {
+    pub fn from_env() -> Self {
+        Self {
+            base_url: std::env::var("STELLAR_EXPERT_API_URL")
+                .unwrap_or_else(|_| "https://api.stellar.expert/explorer".to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl MetadataSource for StellarExpertSource {
+    fn name(&self) -> &'static str {
+        "stellar_expert"
+    }
+
+    async fn fetch(&self, network: &Network, contract_id: &str) -> Result<Option<ExternalMetadata>, ImportError> {
+        Err(ImportError::Unavailable(format!(
+            "{}/{}/contract/{} is not supported yet",
+            self.base_url.trim_end_matches('/'),
+            network,
+            contract_id
+        )))
+    }
+}
+
+/// Build the configured source, or `None` when importing is disabled.
+pub fn source_from_env() -> Option<SharedMetadataSource> {
+    let configured = std::env::var("METADATA_IMPORT_SOURCE").unwrap_or_default();
+    match configured.trim().to_ascii_lowercase().as_str() {
+        "" | "none" => None,
+        "stellar_expert" => {
+            tracing::info!("contract metadata import enabled (stellar_expert)");
+            Some(Arc::new(StellarExpertSource::from_env()))
+        }
+        other => {
+            tracing::warn!(source = other, "unknown METADATA_IMPORT_SOURCE; contract import disabled");
+            None
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImportQuery {
+    pub contract_id: String,
+    pub network: Network,
+    pub publisher_address: Option<String>,
+}
+
+/// A publish draft plus what the publisher still has to provide
+#[derive(Debug, Serialize)]
+pub struct ImportPreview {
+    pub source: &'static str,
+    pub request: PublishRequest,
+    /// Fields filled in from the external source
+    pub imported_fields: Vec<&'static str>,
+    /// Required fields the source could not supply
+    pub missing_fields: Vec<&'static str>,
+}
+
+/// Lowercase, trim and de-duplicate tags, keeping the first `MAX_IMPORTED_TAGS`
+/// that fit the publish limits.
+fn import_tags(tags: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    tags.iter()
+        .map(|tag| tag.trim().to_lowercase())
+        .filter(|tag| !tag.is_empty() && tag.len() <= MAX_IMPORTED_TAG_LENGTH)
+        .filter(|tag| seen.insert(tag.clone()))
+        .take(MAX_IMPORTED_TAGS)
+        .collect()
+}
+
+fn non_empty(value: &Option<String>) -> Option<String> {
+    value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+}
+
+/// Map external metadata onto a publish draft for `contract_id` on `network`.
+pub fn to_publish_request(
+    source: &'static str,
+    contract_id: &str,
+    network: Network,
+    publisher_address: Option<&str>,
+    metadata: &ExternalMetadata,
+) -> ImportPreview {
+    let mut imported_fields = Vec::new();
+    let mut missing_fields = Vec::new();
+
+    let name = non_empty(&metadata.name);
+    let description = non_empty(&metadata.description);
+    let category = non_empty(&metadata.category);
+    let source_url = non_empty(&metadata.source_url).or_else(|| non_empty(&metadata.homepage));
+    let tags = import_tags(&metadata.tags);
+
+    for (field, present) in [
+        ("name", name.is_some()),
+        ("description", description.is_some()),
+        ("category", category.is_some()),
+        ("tags", !tags.is_empty()),
+        ("source_url", source_url.is_some()),
+    ] {
+        if present {
+            imported_fields.push(field);
+        }
+    }
+    if name.is_none() {
+        missing_fields.push("name");
+    }
+    if publisher_address.is_none_or(|a| a.trim().is_empty()) {
+        missing_fields.push("publisher_address");
+    }
+
+    let mut request = PublishRequest {
+        contract_id: contract_id.to_string(),
+        name: name.unwrap_or_default(),
+        description,
+        network,
+        category,
+        tags,
+        source_url,
+        publisher_address: publisher_address.unwrap_or_default().to_string(),
+        dependencies: Vec::new(),
+        license: None,
+    };
+    request.sanitize();
+
+    ImportPreview {
+        source,
+        request,
+        imported_fields,
+        missing_fields,
+    }
+}
+
+/// GET /api/contracts/import/preview
+pub async fn preview_import(
+    State(state): State<AppState>,
+    Query(query): Query<ImportQuery>,
+) -> ApiResult<Json<ImportPreview>> {
+    let Some(source) = state.metadata_source.as_deref() else {
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "ImportDisabled",
+            "No external metadata source is configured",
+        ));
+    };
+
+    let contract_id = query.contract_id.trim().to_uppercase();
+    validate_contract_id(&contract_id).map_err(|msg| ApiError::bad_request("InvalidContractId", msg))?;
+
+    let metadata = match source.fetch(&query.network, &contract_id).await {
+        Ok(Some(metadata)) => metadata,
+        Ok(None) => {
+            return Err(ApiError::not_found(
+                "ContractNotFound",
+                format!("{} has no metadata for {} on {}", source.name(), contract_id, query.network),
+            ))
+        }
+        Err(err) => {
+            tracing::warn!(source = source.name(), error = %err, "contract metadata import failed");
+            return Err(ApiError::new(StatusCode::BAD_GATEWAY, "ImportFailed", err.to_string()));
+        }
+    };
+
+    Ok(Json(to_publish_request(
+        source.name(),
+        &contract_id,
+        query.network,
+        query.publisher_address.as_deref(),
+        &metadata,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    const CONTRACT: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+    const PUBLISHER: &str = "GAAZI4TCR3TY5OJHCTJC2A4QSY6CJWJH5IAJTGKIN2ER7LBNVKOCCWN7";
+
+    /// In-memory source keyed by (network, contract id).
+    #[derive(Default)]
+    struct MockSource {
+        entries: HashMap<(String, String), ExternalMetadata>,
+    }
+
+    #[async_trait]
+    impl MetadataSource for MockSource {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        async fn fetch(&self, network: &Network, contract_id: &str) -> Result<Option<ExternalMetadata>, ImportError> {
+            Ok(self.entries.get(&(network.to_string(), contract_id.to_string())).cloned())
+        }
+    }
+
+    fn full_metadata() -> ExternalMetadata {
+        ExternalMetadata {
+            name: Some("  Soroswap <b>Router</b> ".into()),
+            description: Some("AMM router".into()),
+            category: Some("DeFi".into()),
+            tags: vec!["DeFi".into(), "amm".into(), "defi".into(), " ".into(), "x".repeat(60)],
+            source_url: None,
+            homepage: Some("https://soroswap.finance".into()),
+        }
+    }
+
+    #[tokio::test]
+    async fn mock_source_feeds_the_mapping() {
+        let mut source = MockSource::default();
+        source
+            .entries
+            .insert(("testnet".into(), CONTRACT.into()), full_metadata());
+
+        let metadata = source.fetch(&Network::Testnet, CONTRACT).await.unwrap().unwrap();
+        let preview = to_publish_request(source.name(), CONTRACT, Network::Testnet, Some(PUBLISHER), &metadata);
+        assert_eq!(preview.source, "mock");
+        assert_eq!(preview.request.contract_id, CONTRACT);
+
+        assert!(source.fetch(&Network::Mainnet, CONTRACT).await.unwrap().is_none());
+    }
+
+    #[test]
+    fn external_metadata_maps_onto_a_publish_request() {
+        let preview = to_publish_request("mock", CONTRACT, Network::Testnet, Some(PUBLISHER), &full_metadata());
+        let req = &preview.request;
+
+        assert_eq!(req.name, "Soroswap Router");
+        assert_eq!(req.description.as_deref(), Some("AMM router"));
+        assert_eq!(req.category.as_deref(), Some("DeFi"));
+        assert_eq!(req.tags, vec!["defi", "amm"]);
+        // Homepage stands in when there is no repository
+        assert_eq!(req.source_url.as_deref(), Some("https://soroswap.finance"));
+        assert_eq!(req.publisher_address, PUBLISHER);
+        assert!(req.dependencies.is_empty());
+
+        assert_eq!(preview.imported_fields, vec!["name", "description", "category", "tags", "source_url"]);
+        assert!(preview.missing_fields.is_empty());
+        assert!(req.validate().is_ok());
+    }
+
+    #[test]
+    fn gaps_are_reported_as_missing_fields() {
+        let sparse = ExternalMetadata {
+            description: Some("   ".into()),
+            ..ExternalMetadata::default()
+        };
+        let preview = to_publish_request("mock", CONTRACT, Network::Mainnet, None, &sparse);
+
+        assert!(preview.imported_fields.is_empty());
+        assert_eq!(preview.missing_fields, vec!["name", "publisher_address"]);
+        assert_eq!(preview.request.description, None);
+        assert!(preview.request.tags.is_empty());
+        // The draft is not publishable until the gaps are filled
+        assert!(preview.request.validate().is_err());
+    }
+
+    #[test]
+    fn tags_are_capped_at_the_publish_limit() {
+        let metadata = ExternalMetadata {
+            name: Some("Many".into()),
+            tags: (0..20).map(|n| format!("tag{}", n)).collect(),
+            ..ExternalMetadata::default()
+        };
+        let preview = to_publish_request("mock", CONTRACT, Network::Testnet, Some(PUBLISHER), &metadata);
+        assert_eq!(preview.request.tags.len(), MAX_IMPORTED_TAGS);
+        assert_eq!(preview.request.tags[0], "tag0");
+    }
+
+    #[tokio::test]
+    async fn stub_provider_reports_unavailable() {
+        let source = StellarExpertSource {
+            base_url: "https://example.test/explorer/".into(),
+        };
+        let err = source.fetch(&Network::Testnet, CONTRACT).await.unwrap_err();
+        assert!(matches!(err, ImportError::Unavailable(_)));
+        assert!(err.to_string().contains("https://example.test/explorer/testnet/contract/"));
+    }
+}
@@ -0,0 +1,337 @@
+// api/src/leaderboard.rs
+// GET /api/leaderboard?metric=popularity|trust|activity|views&network=&limit=
+//
+// Ranks contracts by one of four scores:
+//   popularity — the hourly-recalculated `contracts.popularity_score`
+//   trust      — the trust engine score (see trust.rs)
+//   activity   — interactions recorded over the last 30 days
+//   views      — debounced detail-page views (see views.rs)
+//
+// Results are cached briefly since every call scans the candidate set.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use sqlx::FromRow;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+    trust::{compute_trust_score_with, TrustInput, TrustWeights},
+};
+
+const DEFAULT_LIMIT: i64 = 10;
+const MAX_LIMIT: i64 = 100;
+
+/// How long a computed leaderboard is served from cache
+const LEADERBOARD_CACHE_TTL: Duration = Duration::from_secs(60);
+const LEADERBOARD_CACHE_NAMESPACE: &str = "leaderboard";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LeaderboardMetric {
+    #[default]
+    Popularity,
+    Trust,
+    Activity,
+    Views,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeaderboardQuery {
+    #[serde(default)]
+    pub metric: LeaderboardMetric,
+    pub network: Option<Network>,
+    pub limit: Option<i64>,
+}
+
+/// Per-contract signals the scores are computed from
+#[derive(Debug, Clone, FromRow)]
+pub struct LeaderboardCandidate {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub name: String,
+    pub network: Network,
+    pub is_verified: bool,
+    pub created_at: DateTime<Utc>,
+    pub popularity_score: f64,
+    pub latest_audit_score: Option<f64>,
+    pub total_deployments: i64,
+    pub total_interactions: i64,
+    pub recent_interactions: i64,
+    pub view_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    pub rank: u32,
+    pub id: Uuid,
+    pub contract_id: String,
+    pub name: String,
+    pub network: Network,
+    pub score: f64,
+    pub view_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardResponse {
+    pub metric: LeaderboardMetric,
+    pub network: Option<Network>,
+    pub entries: Vec<LeaderboardEntry>,
+    pub generated_at: DateTime<Utc>,
+}
+
+/// Score one candidate under `metric`.
+pub fn score(metric: LeaderboardMetric, c: &LeaderboardCandidate, weights: &TrustWeights) -> f64 {
+    match metric {
+        LeaderboardMetric::Popularity => c.popularity_score,
+        LeaderboardMetric::Activity => c.recent_interactions as f64,
+        LeaderboardMetric::Views => c.view_count as f64,
+        LeaderboardMetric::Trust => {
+            compute_trust_score_with(
+                &TrustInput {
+                    is_verified: c.is_verified,
+                    latest_audit_score: c.latest_audit_score,
+                    total_deployments: c.total_deployments,
+                    total_interactions: c.total_interactions,
+                    created_at: c.created_at,
+                    // Per-finding severities are not loaded for bulk ranking.
+                    unresolved_critical_vulns: 0,
+                },
+                weights,
+            )
+            .score
+        }
+    }
+}
+
+/// Order candidates by score (ties broken by name) and keep the top `limit`.
+pub fn rank(
+    candidates: Vec<LeaderboardCandidate>,
+    metric: LeaderboardMetric,
+    limit: usize,
+    weights: &TrustWeights,
+) -> Vec<LeaderboardEntry> {
+    let mut scored: Vec<(f64, LeaderboardCandidate)> = candidates
+        .into_iter()
+        .map(|c| (score(metric, &c, weights), c))
+        .collect();
+    scored.sort_by(|(a, ca), (b, cb)| b.total_cmp(a).then_with(|| ca.name.cmp(&cb.name)));
+
+    scored
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(i, (score, c))| LeaderboardEntry {
+            rank: i as u32 + 1,
+            id: c.id,
+            contract_id: c.contract_id,
+            name: c.name,
+            network: c.network,
+            score,
+            view_count: c.view_count,
+        })
+        .collect()
+}
+
+async fn fetch_candidates(
+    state: &AppState,
+    network: Option<&Network>,
+) -> Result<Vec<LeaderboardCandidate>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT c.id, c.contract_id, c.name, c.network, c.is_verified, c.created_at,
+               c.popularity_score, c.view_count,
+               (SELECT sa.overall_score FROM security_audits sa
+                 WHERE sa.contract_id = c.id
+                 ORDER BY sa.audit_date DESC LIMIT 1) AS latest_audit_score,
+               (SELECT COUNT(*) FROM analytics_events ae
+                 WHERE ae.contract_id = c.id AND ae.event_type = 'contract_deployed') AS total_deployments,
+               (SELECT COUNT(*) FROM contract_interactions ci
+                 WHERE ci.contract_id = c.id) AS total_interactions,
+               (SELECT COUNT(*) FROM contract_interactions ci
+                 WHERE ci.contract_id = c.id
+                   AND ci.created_at >= NOW() - INTERVAL '30 days') AS recent_interactions
+        FROM contracts c
+        WHERE ($1::network_type IS NULL OR c.network = $1)
+        "#,
+    )
+    .bind(network)
+    .fetch_all(&state.db)
+    .await
+}
+
+pub async fn get_leaderboard(
+    State(state): State<AppState>,
+    Query(query): Query<LeaderboardQuery>,
+) -> ApiResult<Json<LeaderboardResponse>> {
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+    let cache_key = format!(
+        "{:?}:{}:{}",
+        query.metric,
+        query
+            .network
+            .as_ref()
+            .map(|n| n.to_string())
+            .unwrap_or_else(|| "all".into()),
+        limit
+    );
+
+    if let (Some(cached), true) = state.cache.get(LEADERBOARD_CACHE_NAMESPACE, &cache_key).await {
+        if let Ok(response) = serde_json::from_str::<LeaderboardResponse>(&cached) {
+            return Ok(Json(response));
+        }
+    }
+
+    let candidates = fetch_candidates(&state, query.network.as_ref())
+        .await
+        .map_err(|err| {
+            tracing::error!(error = ?err, "failed to load leaderboard candidates");
+            ApiError::internal("An unexpected database error occurred")
+        })?;
+
+    let response = LeaderboardResponse {
+        metric: query.metric,
+        network: query.network,
+        entries: rank(candidates, query.metric, limit as usize, &state.trust_weights()),
+        generated_at: Utc::now(),
+    };
+
+    if let Ok(serialized) = serde_json::to_string(&response) {
+        state
+            .cache
+            .put(
+                LEADERBOARD_CACHE_NAMESPACE,
+                &cache_key,
+                serialized,
+                Some(LEADERBOARD_CACHE_TTL),
+            )
+            .await;
+    }
+
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(name: &str) -> LeaderboardCandidate {
+        LeaderboardCandidate {
+            id: Uuid::new_v4(),
+            contract_id: format!("C{name}"),
+            name: name.into(),
+            network: Network::Mainnet,
+            is_verified: false,
+            created_at: Utc::now(),
+            popularity_score: 0.0,
+            latest_audit_score: None,
+            total_deployments: 0,
+            total_interactions: 0,
+            recent_interactions: 0,
+            view_count: 0,
+        }
+    }
+
+    fn names(entries: &[LeaderboardEntry]) -> Vec<&str> {
+        entries.iter().map(|e| e.name.as_str()).collect()
+    }
+
+    #[test]
+    fn popularity_leaderboard_orders_by_popularity_score() {
+        let mut a = candidate("a");
+        a.popularity_score = 12.5;
+        let mut b = candidate("b");
+        b.popularity_score = 40.0;
+        let c = candidate("c");
+
+        let entries = rank(vec![a, b, c], LeaderboardMetric::Popularity, 10, &TrustWeights::default());
+
+        assert_eq!(names(&entries), vec!["b", "a", "c"]);
+        assert_eq!(entries[0].rank, 1);
+        assert_eq!(entries[0].score, 40.0);
+        assert_eq!(entries[2].rank, 3);
+    }
+
+    #[test]
+    fn trust_leaderboard_orders_by_trust_score() {
+        let mut audited = candidate("audited");
+        audited.is_verified = true;
+        audited.latest_audit_score = Some(90.0);
+        let mut verified = candidate("verified");
+        verified.is_verified = true;
+        let unknown = candidate("unknown");
+
+        let entries = rank(vec![unknown, verified, audited], LeaderboardMetric::Trust, 10, &TrustWeights::default());
+
+        assert_eq!(names(&entries), vec!["audited", "verified", "unknown"]);
+        assert!(entries[0].score > entries[1].score);
+    }
+
+    #[test]
+    fn activity_leaderboard_orders_by_recent_interactions_and_limits() {
+        let mut busy = candidate("busy");
+        busy.recent_interactions = 500;
+        busy.total_interactions = 500;
+        let mut stale = candidate("stale");
+        stale.total_interactions = 10_000;
+        let mut steady = candidate("steady");
+        steady.recent_interactions = 50;
+
+        let entries = rank(vec![stale, steady, busy], LeaderboardMetric::Activity, 2, &TrustWeights::default());
+
+        assert_eq!(names(&entries), vec!["busy", "steady"]);
+        assert_eq!(entries[1].score, 50.0);
+    }
+
+    #[test]
+    fn ties_break_by_name() {
+        let entries = rank(
+            vec![candidate("zeta"), candidate("alpha")],
+            LeaderboardMetric::Popularity,
+            10,
+            &TrustWeights::default(),
+        );
+        assert_eq!(names(&entries), vec!["alpha", "zeta"]);
+    }
+
+    #[test]
+    fn views_leaderboard_orders_by_view_count() {
+        let mut viewed = candidate("viewed");
+        viewed.view_count = 120;
+        let mut popular = candidate("popular");
+        popular.popularity_score = 99.0;
+        popular.view_count = 3;
+
+        let entries = rank(vec![popular, viewed], LeaderboardMetric::Views, 10, &TrustWeights::default());
+
+        assert_eq!(names(&entries), vec!["viewed", "popular"]);
+        assert_eq!(entries[0].score, 120.0);
+        assert_eq!(entries[1].view_count, 3);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn leaderboard_is_served_from_the_database_when_the_cache_fails() {
+        let mut state = crate::fixtures::test_state().await;
+        state.cache = std::sync::Arc::new(crate::cache::test_support::FailingCache::layer());
+
+        let query = || LeaderboardQuery {
+            metric: LeaderboardMetric::Popularity,
+            network: None,
+            limit: Some(5),
+        };
+        let Json(first) = get_leaderboard(State(state.clone()), Query(query())).await.unwrap();
+        let Json(second) = get_leaderboard(State(state.clone()), Query(query())).await.unwrap();
+
+        assert!(first.entries.len() <= 5);
+        assert_eq!(first.entries.len(), second.entries.len());
+        assert!(state.cache.metrics().errors.load(std::sync::atomic::Ordering::Relaxed) >= 4);
+    }
+}
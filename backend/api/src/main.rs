@@ -4,27 +4,101 @@ mod error;
 mod state;
 mod rate_limit;
 mod aggregation;
+mod analytics_retention;
+mod audit;
 mod validation;
-// mod auth;
-// mod auth_handlers;
+mod auth;
+mod auth_middleware;
+mod auth_handlers;
 mod cache;
 mod metrics_handler;
 mod metrics;
-// mod resource_handlers;
-// mod resource_tracking;
+mod resource_handlers;
+mod resource_tracking;
 mod analytics;
 mod custom_metrics_handlers;
 mod breaking_changes;
+mod type_safety;
 mod deprecation_handlers;
+mod webhooks;
+mod notifications;
+mod bundle_handlers;
+mod webhook_handlers;
+mod webhook_routes;
+mod cost_handlers;
+mod cost_routes;
+mod contract_export;
+mod verification_handlers;
+mod verification_queue;
+mod badge_handlers;
+mod search_highlight;
+mod search_facets;
+mod trust;
+mod trust_handlers;
+mod leaderboard;
+mod onchain;
+mod reconciliation;
+mod deployment_health;
+mod multisig_handlers;
+mod multisig_routes;
+mod governance_handlers;
+mod governance_routes;
+mod signature_verifier;
+mod signing_handlers;
+mod signing_routes;
+mod ownership_proof;
+mod spam;
+mod maturity_criteria;
+mod maturity_handlers;
+mod maturity_routes;
+mod checklist;
+mod views;
+mod changelog;
+mod maintenance_calendar;
+mod maintenance_handlers;
+mod maintenance_routes;
+mod maintenance_scheduler;
+mod upgrade_check;
+mod event_ingest;
+mod stellar;
+mod contract_state;
+mod contract_aliases;
+mod contract_flags;
+mod contract_tokens;
+mod tag_handlers;
+mod search_analytics;
+mod heatmap;
+mod method_usage;
+mod feed;
+mod importer;
+mod network_lifecycle;
+mod cors;
+mod db_timeout;
+mod pagination;
+mod object_store;
+mod wasm_handlers;
+mod readme_handlers;
+mod backup_handlers;
+mod backup_routes;
+mod backup_scheduler;
+mod benchmark_engine;
+mod benchmark_handlers;
+mod benchmark_routes;
+mod models;
+mod background_jobs;
+mod popularity;
+mod soft_delete_purge;
+mod registry_stats;
+mod migration_cli;
+mod migration_preview;
+mod fixtures;
 
 use anyhow::Result;
 use axum::{middleware, Router};
-use axum::http::{header, HeaderValue, Method};
 use dotenv::dotenv;
 use prometheus::Registry;
 use sqlx::postgres::PgPoolOptions;
 use std::net::SocketAddr;
-use tower_http::cors::CorsLayer;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 use crate::rate_limit::RateLimitState;
@@ -47,9 +121,12 @@ async fn main() -> Result<()> {
     // Database connection
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
+    let statement_timeout = db_timeout::statement_timeout();
+    let connect_options = db_timeout::with_statement_timeout(database_url.parse()?, statement_timeout);
     let pool = PgPoolOptions::new()
         .max_connections(5)
-        .connect(&database_url)
+        .acquire_timeout(statement_timeout)
+        .connect_with(connect_options)
         .await?;
 
     // Run migrations
@@ -59,41 +136,100 @@ async fn main() -> Result<()> {
 
     tracing::info!("Database connected and migrations applied");
 
+    // One-shot admin commands (`migrate ...`, `seed`) run instead of the server
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(command) = migration_cli::parse_command(&args)? {
+        return migration_cli::execute(command, &pool).await;
+    }
+
     // Spawn the hourly analytics aggregation background task
     aggregation::spawn_aggregation_task(pool.clone());
 
+    // Spawn pruning of aggregated raw events past the retention window
+    analytics_retention::spawn_retention_task(pool.clone());
+
+    // Spawn the opt-in purge of soft-deleted contracts and publishers
+    soft_delete_purge::spawn_purge_task(pool.clone());
+
+    // Spawn the job that ends maintenance windows at their scheduled time
+    maintenance_scheduler::spawn_maintenance_scheduler(pool.clone());
+
+    // Spawn the hourly popularity score recalculation
+    popularity::spawn_popularity_task(pool.clone());
+
+    // Spawn the outbound webhook delivery worker
+    webhooks::spawn_delivery_task(pool.clone());
+    webhooks::spawn_digest_task(pool.clone());
+
+    // Spawn the worker that builds queued verification attempts
+    verification_queue::spawn_verification_worker(pool.clone());
+
     // Create prometheus registry for metrics
     let registry = Registry::new();
     if let Err(e) = crate::metrics::register_all(&registry) {
         tracing::error!("Failed to register metrics: {}", e);
     }
     
+    let objects = object_store::object_store_from_env()?;
+
     // Create app state
-    let state = AppState::new(pool, registry);
+    let state = AppState::new(pool.clone(), registry, objects);
+    match trust_handlers::load_weights(&pool).await {
+        Ok(Some(weights)) => state.set_trust_weights(weights),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to load trust weights, using defaults: {}", e),
+    }
+    match maturity_criteria::load_config(&pool).await {
+        Ok(Some(config)) => state.set_maturity_criteria(config),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Invalid or unreadable maturity criteria, using defaults: {}", e),
+    }
+
+    // Spawn the flush of buffered contract view counts
+    views::spawn_flush_task(pool.clone(), state.views.clone());
+
+    // Spawn scheduled contract backups
+    backup_scheduler::spawn_backup_scheduler(state.clone());
+
+    // Spawn on-chain drift reconciliation when RPC access is enabled
+    if onchain::env_flag("ONCHAIN_RECONCILE") {
+        reconciliation::spawn_reconciliation_task(pool.clone(), state.rpc.clone());
+    }
     let rate_limit_state = RateLimitState::from_env();
+    if let Err(e) = rate_limit_state.register_metrics(&state.registry) {
+        tracing::error!("Failed to register rate limit metrics: {}", e);
+    }
 
-    let cors = CorsLayer::new()
-        .allow_origin([
-            HeaderValue::from_static("http://localhost:3000"),
-            HeaderValue::from_static("https://soroban-registry.vercel.app"),
-        ])
-        .allow_methods([Method::GET, Method::POST, Method::OPTIONS])
-        .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION]);
+    let cors = cors::CorsConfig::from_env();
 
-    // Build router
-    let app = Router::new()
+    // Build router; public and admin routes carry separate CORS policies
+    let public_routes = Router::new()
         .merge(routes::contract_routes())
+        .merge(routes::contract_state_write_routes(state.clone()))
         .merge(routes::publisher_routes())
+        .merge(routes::notification_routes())
+        .merge(routes::auth_routes())
         .merge(routes::health_routes())
         .merge(routes::migration_routes())
+        .merge(webhook_routes::webhook_routes())
+        .merge(cost_routes::cost_routes())
+        .merge(multisig_routes::multisig_routes())
+        .merge(governance_routes::governance_routes())
+        .merge(maturity_routes::maturity_routes())
+        .merge(maintenance_routes::maintenance_routes())
+        .merge(signing_routes::signing_routes())
+        .merge(backup_routes::backup_routes())
+        .merge(benchmark_routes::benchmark_routes())
+        .layer(cors.public_layer());
+    let app = Router::new()
+        .merge(public_routes)
+        .merge(routes::admin_routes().layer(cors.admin_layer()))
         .fallback(handlers::route_not_found)
         .layer(middleware::from_fn(request_logger))
         .layer(middleware::from_fn_with_state(
             rate_limit_state,
             rate_limit::rate_limit_middleware,
         ))
-        .layer(CorsLayer::permissive())
-        .layer(cors)
         .with_state(state);
 
     // Start server
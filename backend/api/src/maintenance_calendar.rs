@@ -0,0 +1,238 @@
+// api/src/maintenance_calendar.rs
+// Registry-wide maintenance calendar for status pages.
+//
+//   GET /api/maintenance/upcoming[?from=<rfc3339>][&to=<rfc3339>]
+//
+// Returns every maintenance window that overlaps [from, to), across all
+// contracts, plus any window that is active right now even if it falls
+// outside the range. `from` defaults to now and `to` to 30 days later; the
+// range may span at most a year.
+//
+// A window without `ended_at` runs until its `scheduled_end_at`, or
+// indefinitely when it has neither.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const DEFAULT_RANGE_DAYS: i64 = 30;
+const MAX_RANGE_DAYS: i64 = 366;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CalendarQuery {
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct CalendarWindow {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub contract_name: String,
+    pub network: Network,
+    pub message: String,
+    pub started_at: DateTime<Utc>,
+    pub scheduled_end_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+}
+
+impl CalendarWindow {
+    /// When the window stops, if known: its actual end, else the scheduled one.
+    pub fn end(&self) -> Option<DateTime<Utc>> {
+        self.ended_at.or(self.scheduled_end_at)
+    }
+
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.started_at <= now && self.ended_at.is_none() && self.scheduled_end_at.is_none_or(|end| end > now)
+    }
+
+    /// Whether the window overlaps [from, to). Mirrors the SQL filter.
+    pub fn overlaps(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> bool {
+        self.started_at < to && self.end().is_none_or(|end| end > from)
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalendarEntry {
+    #[serde(flatten)]
+    pub window: CalendarWindow,
+    pub active: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct CalendarResponse {
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub windows: Vec<CalendarEntry>,
+}
+
+/// Resolve the requested range, applying defaults and limits.
+pub fn resolve_range(query: &CalendarQuery, now: DateTime<Utc>) -> ApiResult<(DateTime<Utc>, DateTime<Utc>)> {
+    let from = query.from.unwrap_or(now);
+    let to = query.to.unwrap_or(from + Duration::days(DEFAULT_RANGE_DAYS));
+    if to <= from {
+        return Err(ApiError::bad_request("InvalidRange", "`to` must be after `from`"));
+    }
+    if to - from > Duration::days(MAX_RANGE_DAYS) {
+        return Err(ApiError::bad_request(
+            "InvalidRange",
+            format!("range may span at most {} days", MAX_RANGE_DAYS),
+        ));
+    }
+    Ok((from, to))
+}
+
+/// Keep windows in range or active at `now`, ordered by start.
+pub fn calendar_entries(
+    windows: Vec<CalendarWindow>,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Vec<CalendarEntry> {
+    let mut entries: Vec<CalendarEntry> = windows
+        .into_iter()
+        .filter(|w| w.overlaps(from, to) || w.is_active(now))
+        .map(|window| CalendarEntry {
+            active: window.is_active(now),
+            window,
+        })
+        .collect();
+    entries.sort_by(|a, b| {
+        a.window
+            .started_at
+            .cmp(&b.window.started_at)
+            .then_with(|| a.window.id.cmp(&b.window.id))
+    });
+    entries
+}
+
+/// GET /api/maintenance/upcoming
+pub async fn get_upcoming_maintenance(
+    State(state): State<AppState>,
+    Query(query): Query<CalendarQuery>,
+) -> ApiResult<Json<CalendarResponse>> {
+    let now = Utc::now();
+    let (from, to) = resolve_range(&query, now)?;
+
+    let windows: Vec<CalendarWindow> = sqlx::query_as(
+        r#"
+        SELECT mw.id, mw.contract_id, c.name AS contract_name, c.network, mw.message,
+               mw.started_at, mw.scheduled_end_at, mw.ended_at
+        FROM maintenance_windows mw
+        JOIN contracts c ON c.id = mw.contract_id
+        WHERE (mw.started_at < $2 AND COALESCE(mw.ended_at, mw.scheduled_end_at, 'infinity') > $1)
+           OR (mw.started_at <= $3 AND mw.ended_at IS NULL
+               AND COALESCE(mw.scheduled_end_at, 'infinity') > $3)
+        "#,
+    )
+    .bind(from)
+    .bind(to)
+    .bind(now)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list maintenance calendar", err))?;
+
+    Ok(Json(CalendarResponse {
+        from,
+        to,
+        windows: calendar_entries(windows, from, to, now),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, response::IntoResponse};
+    use chrono::TimeZone;
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, day, hour, 0, 0).unwrap()
+    }
+
+    fn window(n: u128, start: DateTime<Utc>, scheduled_end: Option<DateTime<Utc>>, ended: Option<DateTime<Utc>>) -> CalendarWindow {
+        CalendarWindow {
+            id: Uuid::from_u128(n),
+            contract_id: Uuid::from_u128(100 + n),
+            contract_name: format!("contract-{}", n),
+            network: Network::Mainnet,
+            message: "Upgrading".into(),
+            started_at: start,
+            scheduled_end_at: scheduled_end,
+            ended_at: ended,
+        }
+    }
+
+    fn ids(entries: &[CalendarEntry]) -> Vec<u128> {
+        entries.iter().map(|e| e.window.id.as_u128()).collect()
+    }
+
+    #[test]
+    fn only_windows_overlapping_the_range_are_returned() {
+        let (from, to) = (at(20, 0), at(25, 0));
+        let now = at(16, 12);
+        let windows = vec![
+            window(1, at(21, 0), Some(at(21, 4)), None),  // inside
+            window(2, at(18, 0), Some(at(20, 6)), None),  // straddles `from`
+            window(3, at(24, 22), Some(at(26, 2)), None), // straddles `to`
+            window(4, at(25, 0), Some(at(25, 2)), None),  // starts at `to` (exclusive)
+            window(5, at(10, 0), Some(at(11, 0)), None),  // long past
+            window(6, at(19, 0), Some(at(22, 0)), Some(at(19, 6))), // ended early, before `from`
+            window(7, at(23, 0), None, None),             // open-ended
+        ];
+
+        let entries = calendar_entries(windows, from, to, now);
+        assert_eq!(ids(&entries), vec![2, 1, 7, 3]);
+        assert!(entries.iter().all(|e| !e.active));
+    }
+
+    #[test]
+    fn currently_active_windows_are_always_included() {
+        let now = at(16, 12);
+        let (from, to) = (at(20, 0), at(25, 0));
+        let windows = vec![
+            window(1, at(16, 10), Some(at(16, 14)), None), // active, ends before range
+            window(2, at(15, 0), None, None),              // active, open-ended
+            window(3, at(16, 10), Some(at(16, 14)), Some(at(16, 11))), // already ended
+            window(4, at(16, 0), Some(at(16, 11)), None),  // scheduled end passed
+            window(5, at(21, 0), Some(at(21, 1)), None),   // upcoming
+        ];
+
+        let entries = calendar_entries(windows, from, to, now);
+        assert_eq!(ids(&entries), vec![2, 1, 5]);
+        let active: Vec<bool> = entries.iter().map(|e| e.active).collect();
+        assert_eq!(active, vec![true, true, false]);
+    }
+
+    #[test]
+    fn range_defaults_and_limits() {
+        let now = at(16, 12);
+        let (from, to) = resolve_range(&CalendarQuery::default(), now).unwrap();
+        assert_eq!(from, now);
+        assert_eq!(to, now + Duration::days(DEFAULT_RANGE_DAYS));
+
+        let backwards = CalendarQuery {
+            from: Some(at(20, 0)),
+            to: Some(at(19, 0)),
+        };
+        let err = resolve_range(&backwards, now).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+
+        let too_long = CalendarQuery {
+            from: Some(at(1, 0)),
+            to: Some(at(1, 0) + Duration::days(MAX_RANGE_DAYS + 1)),
+        };
+        assert!(resolve_range(&too_long, now).is_err());
+    }
+}
@@ -1,22 +1,48 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Extension, Path, State},
     http::StatusCode,
     Json,
 };
 use chrono::Utc;
-use shared::models::{MaintenanceStatusResponse, MaintenanceWindow, StartMaintenanceRequest};
+use shared::models::{Contract, MaintenanceStatusResponse, MaintenanceWindow, StartMaintenanceRequest};
 use uuid::Uuid;
 
 use crate::{
+    audit::{self, AuditEntry},
+    auth_middleware::AuthContext,
     error::{ApiError, ApiResult},
+    handlers::ensure_contract_owner,
     state::AppState,
 };
 
+/// The contract, provided the caller may switch its maintenance mode
+async fn owned_contract(state: &AppState, contract_id: Uuid, auth: &AuthContext) -> ApiResult<Contract> {
+    let contract = sqlx::query_as::<_, Contract>("SELECT * FROM contracts WHERE id = $1")
+        .bind(contract_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+        .ok_or_else(|| ApiError::not_found("contract", "Contract not found"))?;
+    ensure_contract_owner(state, &contract, auth).await?;
+    Ok(contract)
+}
+
+/// POST /api/contracts/:id/maintenance — restricted to the contract's
+/// publisher (or an admin).
 pub async fn start_maintenance(
     State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
     Path(contract_id): Path<Uuid>,
     Json(req): Json<StartMaintenanceRequest>,
 ) -> ApiResult<Json<MaintenanceWindow>> {
+    owned_contract(&state, contract_id, &auth).await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
     let window = sqlx::query_as::<_, MaintenanceWindow>(
         r#"
         WITH updated AS (
@@ -30,29 +56,67 @@ pub async fn start_maintenance(
     .bind(contract_id)
     .bind(&req.message)
     .bind(req.scheduled_end_at)
-    .fetch_one(&state.db)
+    .fetch_one(&mut *tx)
     .await
     .map_err(|e| ApiError::internal(format!("Failed to start maintenance: {}", e)))?;
 
+    audit::record(
+        &mut *tx,
+        &AuditEntry::maintenance_changed(contract_id, &auth.publisher_address, true, Some(&req.message)),
+    )
+    .await
+    .map_err(|e| ApiError::internal(format!("Failed to record audit entry: {}", e)))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
     Ok(Json(window))
 }
 
+/// DELETE /api/contracts/:id/maintenance — restricted to the contract's
+/// publisher (or an admin).
 pub async fn end_maintenance(
     State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
     Path(contract_id): Path<Uuid>,
 ) -> ApiResult<StatusCode> {
-    sqlx::query(
-        r#"
-        UPDATE contracts SET is_maintenance = false WHERE id = $1;
-        UPDATE maintenance_windows SET ended_at = $2 
-        WHERE contract_id = $1 AND ended_at IS NULL
-        "#,
+    let contract = owned_contract(&state, contract_id, &auth).await?;
+    if !contract.is_maintenance {
+        return Err(ApiError::conflict(
+            "NotInMaintenance",
+            "Contract is not in maintenance mode",
+        ));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
+    sqlx::query("UPDATE contracts SET is_maintenance = false WHERE id = $1")
+        .bind(contract_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to end maintenance: {}", e)))?;
+    sqlx::query("UPDATE maintenance_windows SET ended_at = $2 WHERE contract_id = $1 AND ended_at IS NULL")
+        .bind(contract_id)
+        .bind(Utc::now())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| ApiError::internal(format!("Failed to end maintenance: {}", e)))?;
+
+    audit::record(
+        &mut *tx,
+        &AuditEntry::maintenance_changed(contract_id, &auth.publisher_address, false, None),
     )
-    .bind(contract_id)
-    .bind(Utc::now())
-    .execute(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Failed to end maintenance: {}", e)))?;
+    .map_err(|e| ApiError::internal(format!("Failed to record audit entry: {}", e)))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
 
     Ok(StatusCode::NO_CONTENT)
 }
@@ -102,3 +166,57 @@ pub async fn get_maintenance_history(
 
     Ok(Json(windows))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use axum::response::IntoResponse;
+
+    #[tokio::test]
+    #[ignore]
+    async fn maintenance_mode_is_owner_only_and_audited() {
+        let pool = fixtures::test_pool().await;
+        let data = fixtures::fixtures();
+        let contract = &data.contracts[1];
+        let stranger = data
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address != contract.publisher_address)
+            .unwrap();
+        let state = fixtures::state_for(pool.clone());
+        let as_caller = |address: &str| Extension(AuthContext { publisher_address: address.to_string() });
+        let audited = || {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM contract_audit_log WHERE contract_id = $1 AND action_type = 'maintenance_changed'",
+            )
+            .bind(contract.id)
+            .fetch_one(&pool)
+        };
+        let before = audited().await.unwrap();
+
+        let req = StartMaintenanceRequest { message: "upgrading".into(), scheduled_end_at: None };
+        let err = start_maintenance(State(state.clone()), as_caller(&stranger.stellar_address), Path(contract.id), Json(req.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let window = start_maintenance(State(state.clone()), as_caller(&contract.publisher_address), Path(contract.id), Json(req))
+            .await
+            .unwrap();
+        assert_eq!(window.message, "upgrading");
+        assert_eq!(audited().await.unwrap(), before + 1);
+
+        let status = end_maintenance(State(state.clone()), as_caller(&contract.publisher_address), Path(contract.id))
+            .await
+            .unwrap();
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        assert_eq!(audited().await.unwrap(), before + 2);
+
+        let err = end_maintenance(State(state), as_caller(&contract.publisher_address), Path(contract.id))
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::CONFLICT);
+        assert_eq!(audited().await.unwrap(), before + 2);
+    }
+}
@@ -1,17 +1,24 @@
-use axum::{routing::get, routing::post, Router};
+use axum::{middleware, routing::get, routing::post, Router};
 
-use crate::{maintenance_handlers, state::AppState};
+use crate::{auth_middleware, maintenance_handlers, state::AppState};
 
 pub fn maintenance_routes() -> Router<AppState> {
     Router::new()
         .route(
             "/api/contracts/:id/maintenance",
-            post(maintenance_handlers::start_maintenance)
-                .delete(maintenance_handlers::end_maintenance)
-                .get(maintenance_handlers::get_maintenance_status),
+            get(maintenance_handlers::get_maintenance_status),
         )
         .route(
             "/api/contracts/:id/maintenance/history",
             get(maintenance_handlers::get_maintenance_history),
         )
+        .merge(
+            Router::new()
+                .route(
+                    "/api/contracts/:id/maintenance",
+                    post(maintenance_handlers::start_maintenance)
+                        .delete(maintenance_handlers::end_maintenance),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
 }
@@ -2,6 +2,8 @@ use chrono::Utc;
 use sqlx::PgPool;
 use std::time::Duration;
 
+use crate::audit::{self, AuditEntry, SYSTEM_ACTOR};
+
 pub fn spawn_maintenance_scheduler(pool: PgPool) {
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(Duration::from_secs(60));
@@ -41,7 +43,11 @@ async fn check_scheduled_maintenance(pool: &PgPool) -> Result<(), sqlx::Error> {
         .bind(now)
         .execute(pool)
         .await?;
-        
+
+        for (contract_id,) in &result {
+            audit::record(pool, &AuditEntry::maintenance_changed(*contract_id, SYSTEM_ACTOR, false, None)).await?;
+        }
+
         tracing::info!("Ended {} scheduled maintenance windows", result.len());
     }
 
@@ -0,0 +1,409 @@
+// api/src/maturity_criteria.rs
+// Which criteria a contract must meet to reach each maturity level.
+//
+// The criteria live in the `maturity_criteria` table, one row per
+// (level, criterion), and are loaded into `AppState` at startup. Both the
+// promotion check and the requirements endpoint evaluate against the loaded
+// config. If the table is empty or fails validation the built-in defaults
+// are used instead, so a bad row can never make every promotion pass.
+//
+// Criteria:
+//   verified             — source code is verified (no threshold)
+//   versions             — at least `threshold` versions published
+//   usage                — at least `threshold` contract interactions
+//   security_detections  — at most `threshold` unresolved high/critical findings
+
+use serde::{Deserialize, Serialize};
+use shared::models::{MaturityCriterion, MaturityLevel, MaturityRequirements};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CriterionKind {
+    Verified,
+    Versions,
+    Usage,
+    SecurityDetections,
+}
+
+impl CriterionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CriterionKind::Verified => "verified",
+            CriterionKind::Versions => "versions",
+            CriterionKind::Usage => "usage",
+            CriterionKind::SecurityDetections => "security_detections",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "verified" => Some(CriterionKind::Verified),
+            "versions" => Some(CriterionKind::Versions),
+            "usage" => Some(CriterionKind::Usage),
+            "security_detections" => Some(CriterionKind::SecurityDetections),
+            _ => None,
+        }
+    }
+
+    fn takes_threshold(self) -> bool {
+        !matches!(self, CriterionKind::Verified)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriterionRule {
+    pub kind: CriterionKind,
+    /// Optional criteria are reported but do not block promotion
+    pub required: bool,
+    /// Minimum for `versions`/`usage`, maximum for `security_detections`
+    pub threshold: Option<i64>,
+}
+
+impl CriterionRule {
+    fn new(kind: CriterionKind, threshold: Option<i64>) -> Self {
+        Self {
+            kind,
+            required: true,
+            threshold,
+        }
+    }
+}
+
+/// What the criteria are evaluated against
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ContractFacts {
+    pub is_verified: bool,
+    pub versions: i64,
+    pub interactions: i64,
+    pub blocking_detections: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaturityCriteriaConfig {
+    pub levels: BTreeMap<MaturityLevel, Vec<CriterionRule>>,
+}
+
+impl Default for MaturityCriteriaConfig {
+    fn default() -> Self {
+        use CriterionKind::*;
+        let levels = BTreeMap::from([
+            (
+                MaturityLevel::Beta,
+                vec![CriterionRule::new(Verified, None), CriterionRule::new(Versions, Some(1))],
+            ),
+            (
+                MaturityLevel::Stable,
+                vec![
+                    CriterionRule::new(Verified, None),
+                    CriterionRule::new(Versions, Some(2)),
+                    CriterionRule::new(Usage, Some(10)),
+                    CriterionRule::new(SecurityDetections, Some(0)),
+                ],
+            ),
+            (
+                MaturityLevel::Mature,
+                vec![
+                    CriterionRule::new(Verified, None),
+                    CriterionRule::new(Versions, Some(5)),
+                    CriterionRule::new(Usage, Some(100)),
+                    CriterionRule::new(SecurityDetections, Some(0)),
+                ],
+            ),
+        ]);
+        Self { levels }
+    }
+}
+
+/// Levels that can carry criteria. Alpha is where every contract starts and
+/// Legacy is a sunset state, so neither is gated.
+const GATED_LEVELS: [MaturityLevel; 3] = [MaturityLevel::Beta, MaturityLevel::Stable, MaturityLevel::Mature];
+
+impl MaturityCriteriaConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        for (level, rules) in &self.levels {
+            if !GATED_LEVELS.contains(level) {
+                return Err(format!("criteria cannot be configured for {:?}", level));
+            }
+            for (i, rule) in rules.iter().enumerate() {
+                let name = rule.kind.as_str();
+                if rules[..i].iter().any(|r| r.kind == rule.kind) {
+                    return Err(format!("{:?} lists '{}' more than once", level, name));
+                }
+                match (rule.kind.takes_threshold(), rule.threshold) {
+                    (true, None) => return Err(format!("{:?} '{}' needs a threshold", level, name)),
+                    (false, Some(_)) => return Err(format!("{:?} '{}' does not take a threshold", level, name)),
+                    (true, Some(t)) if t < 0 => {
+                        return Err(format!("{:?} '{}' threshold must not be negative, got {}", level, name, t))
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // A higher level must not ask for fewer versions or less usage than a lower one
+        for kind in [CriterionKind::Versions, CriterionKind::Usage] {
+            let mut previous: Option<(&MaturityLevel, i64)> = None;
+            for (level, rules) in &self.levels {
+                let Some(threshold) = rules.iter().find(|r| r.kind == kind).and_then(|r| r.threshold) else {
+                    continue;
+                };
+                if let Some((lower, lower_threshold)) = previous {
+                    if threshold < lower_threshold {
+                        return Err(format!(
+                            "'{}' threshold for {:?} ({}) is below {:?} ({})",
+                            kind.as_str(),
+                            level,
+                            threshold,
+                            lower,
+                            lower_threshold
+                        ));
+                    }
+                }
+                previous = Some((level, threshold));
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluate the criteria for `level`. Ungated levels have no criteria and are always met.
+    pub fn evaluate(&self, level: MaturityLevel, facts: &ContractFacts) -> MaturityRequirements {
+        let criteria: Vec<MaturityCriterion> = self
+            .levels
+            .get(&level)
+            .map(|rules| rules.iter().map(|rule| evaluate_rule(rule, facts)).collect())
+            .unwrap_or_default();
+        let met = criteria.iter().all(|c| !c.required || c.met);
+
+        MaturityRequirements { level, criteria, met }
+    }
+
+    /// Requirements for every gated level, lowest first
+    pub fn evaluate_all(&self, facts: &ContractFacts) -> Vec<MaturityRequirements> {
+        GATED_LEVELS
+            .iter()
+            .map(|level| self.evaluate(level.clone(), facts))
+            .collect()
+    }
+
+    /// Names of the required criteria for `level` that `facts` do not meet
+    pub fn unmet(&self, level: MaturityLevel, facts: &ContractFacts) -> Vec<String> {
+        self.evaluate(level, facts)
+            .criteria
+            .into_iter()
+            .filter(|c| c.required && !c.met)
+            .map(|c| c.name)
+            .collect()
+    }
+}
+
+fn evaluate_rule(rule: &CriterionRule, facts: &ContractFacts) -> MaturityCriterion {
+    let threshold = rule.threshold.unwrap_or(0);
+    let (met, description) = match rule.kind {
+        CriterionKind::Verified => (facts.is_verified, "Contract source code must be verified".to_string()),
+        CriterionKind::Versions => (
+            facts.versions >= threshold,
+            format!("At least {} version{} published", threshold, if threshold == 1 { "" } else { "s" }),
+        ),
+        CriterionKind::Usage => (
+            facts.interactions >= threshold,
+            format!("At least {} contract interactions", threshold),
+        ),
+        CriterionKind::SecurityDetections if threshold == 0 => (
+            facts.blocking_detections == 0,
+            "No unresolved high or critical severity detector findings".to_string(),
+        ),
+        CriterionKind::SecurityDetections => (
+            facts.blocking_detections <= threshold,
+            format!("At most {} unresolved high or critical severity detector findings", threshold),
+        ),
+    };
+
+    MaturityCriterion {
+        name: rule.kind.as_str().to_string(),
+        required: rule.required,
+        met,
+        description,
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct CriterionRow {
+    level: MaturityLevel,
+    criterion: String,
+    required: bool,
+    threshold: Option<i64>,
+}
+
+fn config_from_rows(rows: Vec<CriterionRow>) -> Result<MaturityCriteriaConfig, String> {
+    let mut levels: BTreeMap<MaturityLevel, Vec<CriterionRule>> = BTreeMap::new();
+    for row in rows {
+        let kind = CriterionKind::parse(&row.criterion)
+            .ok_or_else(|| format!("unknown maturity criterion '{}'", row.criterion))?;
+        levels.entry(row.level).or_default().push(CriterionRule {
+            kind,
+            required: row.required,
+            threshold: row.threshold,
+        });
+    }
+    let config = MaturityCriteriaConfig { levels };
+    config.validate()?;
+    Ok(config)
+}
+
+/// Load and validate the criteria table.
+///
+/// `Ok(None)` when the table is empty; an invalid config is an error so the
+/// caller can keep the defaults.
+pub async fn load_config(pool: &PgPool) -> Result<Option<MaturityCriteriaConfig>, String> {
+    let rows: Vec<CriterionRow> = sqlx::query_as(
+        "SELECT level, criterion, required, threshold FROM maturity_criteria ORDER BY level, criterion",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    if rows.is_empty() {
+        return Ok(None);
+    }
+    config_from_rows(rows).map(Some)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn facts(versions: i64, interactions: i64) -> ContractFacts {
+        ContractFacts {
+            is_verified: true,
+            versions,
+            interactions,
+            blocking_detections: 0,
+        }
+    }
+
+    fn rule_mut(config: &mut MaturityCriteriaConfig, level: MaturityLevel, kind: CriterionKind) -> &mut CriterionRule {
+        config
+            .levels
+            .get_mut(&level)
+            .and_then(|rules| rules.iter_mut().find(|r| r.kind == kind))
+            .unwrap()
+    }
+
+    #[test]
+    fn defaults_are_valid_and_match_the_documented_thresholds() {
+        let config = MaturityCriteriaConfig::default();
+        assert!(config.validate().is_ok());
+
+        assert!(config.evaluate(MaturityLevel::Beta, &facts(1, 0)).met);
+        assert!(!config.evaluate(MaturityLevel::Beta, &facts(0, 0)).met);
+        assert!(config.evaluate(MaturityLevel::Stable, &facts(2, 10)).met);
+        assert!(!config.evaluate(MaturityLevel::Stable, &facts(2, 9)).met);
+        assert!(config.evaluate(MaturityLevel::Mature, &facts(5, 100)).met);
+        assert_eq!(config.unmet(MaturityLevel::Mature, &facts(4, 100)), vec!["versions"]);
+
+        let all = config.evaluate_all(&facts(0, 0));
+        let levels: Vec<_> = all.iter().map(|r| r.level.clone()).collect();
+        assert_eq!(levels, vec![MaturityLevel::Beta, MaturityLevel::Stable, MaturityLevel::Mature]);
+    }
+
+    #[test]
+    fn raising_a_threshold_blocks_promotion() {
+        let candidate = facts(2, 10);
+        let mut config = MaturityCriteriaConfig::default();
+        assert!(config.unmet(MaturityLevel::Stable, &candidate).is_empty());
+
+        rule_mut(&mut config, MaturityLevel::Stable, CriterionKind::Versions).threshold = Some(3);
+        assert!(config.validate().is_ok());
+        assert_eq!(config.unmet(MaturityLevel::Stable, &candidate), vec!["versions"]);
+        let versions = config
+            .evaluate(MaturityLevel::Stable, &candidate)
+            .criteria
+            .into_iter()
+            .find(|c| c.name == "versions")
+            .unwrap();
+        assert_eq!(versions.description, "At least 3 versions published");
+    }
+
+    #[test]
+    fn optional_criteria_no_longer_block_promotion() {
+        let quiet = facts(2, 0);
+        let mut config = MaturityCriteriaConfig::default();
+        assert_eq!(config.unmet(MaturityLevel::Stable, &quiet), vec!["usage"]);
+
+        rule_mut(&mut config, MaturityLevel::Stable, CriterionKind::Usage).required = false;
+        let reqs = config.evaluate(MaturityLevel::Stable, &quiet);
+        assert!(reqs.met);
+        // Still reported, just not blocking
+        assert!(reqs.criteria.iter().any(|c| c.name == "usage" && !c.met && !c.required));
+    }
+
+    #[test]
+    fn detection_allowance_is_configurable() {
+        let mut candidate = facts(2, 10);
+        candidate.blocking_detections = 1;
+        let mut config = MaturityCriteriaConfig::default();
+        assert_eq!(config.unmet(MaturityLevel::Stable, &candidate), vec!["security_detections"]);
+
+        rule_mut(&mut config, MaturityLevel::Stable, CriterionKind::SecurityDetections).threshold = Some(1);
+        assert!(config.evaluate(MaturityLevel::Stable, &candidate).met);
+    }
+
+    #[test]
+    fn ungated_levels_have_no_criteria() {
+        let config = MaturityCriteriaConfig::default();
+        let reqs = config.evaluate(MaturityLevel::Legacy, &ContractFacts::default());
+        assert!(reqs.met);
+        assert!(reqs.criteria.is_empty());
+    }
+
+    #[test]
+    fn invalid_configs_are_rejected() {
+        let mut negative = MaturityCriteriaConfig::default();
+        rule_mut(&mut negative, MaturityLevel::Beta, CriterionKind::Versions).threshold = Some(-1);
+        assert!(negative.validate().unwrap_err().contains("must not be negative"));
+
+        let mut missing = MaturityCriteriaConfig::default();
+        rule_mut(&mut missing, MaturityLevel::Stable, CriterionKind::Usage).threshold = None;
+        assert!(missing.validate().unwrap_err().contains("needs a threshold"));
+
+        let mut decreasing = MaturityCriteriaConfig::default();
+        rule_mut(&mut decreasing, MaturityLevel::Mature, CriterionKind::Versions).threshold = Some(1);
+        assert!(decreasing.validate().unwrap_err().contains("is below"));
+
+        let mut alpha = MaturityCriteriaConfig::default();
+        alpha
+            .levels
+            .insert(MaturityLevel::Alpha, vec![CriterionRule::new(CriterionKind::Verified, None)]);
+        assert!(alpha.validate().is_err());
+
+        let mut duplicate = MaturityCriteriaConfig::default();
+        duplicate
+            .levels
+            .get_mut(&MaturityLevel::Beta)
+            .unwrap()
+            .push(CriterionRule::new(CriterionKind::Verified, None));
+        assert!(duplicate.validate().unwrap_err().contains("more than once"));
+    }
+
+    #[test]
+    fn rows_with_unknown_criteria_are_rejected() {
+        let rows = vec![CriterionRow {
+            level: MaturityLevel::Beta,
+            criterion: "audited".into(),
+            required: true,
+            threshold: None,
+        }];
+        assert!(config_from_rows(rows).unwrap_err().contains("unknown maturity criterion"));
+
+        let rows = vec![CriterionRow {
+            level: MaturityLevel::Beta,
+            criterion: "versions".into(),
+            required: true,
+            threshold: Some(3),
+        }];
+        let config = config_from_rows(rows).unwrap();
+        assert!(!config.evaluate(MaturityLevel::Beta, &facts(2, 0)).met);
+    }
+}
@@ -1,21 +1,128 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Extension, Path, State},
     http::StatusCode,
     Json,
 };
 use shared::models::{
-    Contract, MaturityChange, MaturityCriterion, MaturityLevel, MaturityRequirements,
+    Contract, MaturityChange, MaturityLevel, MaturityRequirements,
     UpdateMaturityRequest,
 };
 use uuid::Uuid;
 
 use crate::{
+    audit::{self, AuditEntry},
+    auth_middleware::AuthContext,
+    checklist::all_checks,
     error::{ApiError, ApiResult},
+    handlers::ensure_contract_owner,
+    maturity_criteria::{ContractFacts, MaturityCriteriaConfig},
+    models::Severity,
     state::AppState,
 };
 
+/// Count failed audit checks whose checklist severity is High or Critical.
+fn count_blocking_detections(failed_check_ids: &[String]) -> i64 {
+    let checks = all_checks();
+    failed_check_ids
+        .iter()
+        .filter(|id| {
+            checks
+                .iter()
+                .any(|c| c.id == id.as_str() && c.severity >= Severity::High)
+        })
+        .count() as i64
+}
+
+/// Unresolved high-severity detector findings from the contract's latest security audit.
+async fn fetch_blocking_detections(state: &AppState, contract_id: Uuid) -> ApiResult<i64> {
+    let failed: Vec<String> = sqlx::query_scalar(
+        r#"
+        SELECT ac.check_id
+        FROM audit_checks ac
+        WHERE ac.status = 'failed'
+          AND ac.audit_id = (
+              SELECT id FROM security_audits
+              WHERE contract_id = $1
+              ORDER BY audit_date DESC
+              LIMIT 1
+          )
+        "#,
+    )
+    .bind(contract_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+
+    Ok(count_blocking_detections(&failed))
+}
+
+/// Everything the maturity criteria are evaluated against, for one contract.
+async fn fetch_contract_facts(state: &AppState, contract: &Contract) -> ApiResult<ContractFacts> {
+    let versions = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM contract_versions WHERE contract_id = $1",
+    )
+    .bind(contract.id)
+    .fetch_one(&state.db)
+    .await
+    .unwrap_or(0);
+
+    let interactions = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM contract_interactions WHERE contract_id = $1",
+    )
+    .bind(contract.id)
+    .fetch_one(&state.db)
+    .await
+    .unwrap_or(0);
+
+    let blocking_detections = fetch_blocking_detections(state, contract.id).await?;
+
+    Ok(facts_for(contract, versions, interactions, blocking_detections))
+}
+
+fn facts_for(contract: &Contract, versions: i64, interactions: i64, blocking_detections: i64) -> ContractFacts {
+    ContractFacts {
+        is_verified: contract.is_verified,
+        versions,
+        interactions,
+        blocking_detections,
+    }
+}
+
+/// Reject a promotion whose required criteria are not met under `config`.
+/// Moving down a level, or to Legacy, is never gated.
+fn check_promotion(
+    config: &MaturityCriteriaConfig,
+    from: &MaturityLevel,
+    to: &MaturityLevel,
+    facts: &ContractFacts,
+) -> ApiResult<()> {
+    if to <= from || *to == MaturityLevel::Legacy {
+        return Ok(());
+    }
+    let unmet = config.unmet(to.clone(), facts);
+    if unmet.is_empty() {
+        return Ok(());
+    }
+    if unmet.iter().any(|name| name == "security_detections") {
+        return Err(ApiError::unprocessable(
+            "UnresolvedSecurityDetections",
+            format!(
+                "{} unresolved high-severity detection(s) must be resolved before promotion to {:?}",
+                facts.blocking_detections, to
+            ),
+        ));
+    }
+    Err(ApiError::unprocessable(
+        "MaturityRequirementsNotMet",
+        format!("Promotion to {:?} requires: {}", to, unmet.join(", ")),
+    ))
+}
+
+/// PUT /api/contracts/:id/maturity — restricted to the contract's publisher
+/// (or an admin); promotions must meet the configured criteria.
 pub async fn update_maturity(
     State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
     Path(contract_id): Path<Uuid>,
     Json(req): Json<UpdateMaturityRequest>,
 ) -> ApiResult<Json<Contract>> {
@@ -26,6 +133,13 @@ pub async fn update_maturity(
         .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
         .ok_or_else(|| ApiError::not_found("contract", "Contract not found"))?;
 
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    if req.maturity > contract.maturity && req.maturity != MaturityLevel::Legacy {
+        let facts = fetch_contract_facts(&state, &contract).await?;
+        check_promotion(&state.maturity_criteria(), &contract.maturity, &req.maturity, &facts)?;
+    }
+
     // Log the change
     sqlx::query(
         "INSERT INTO maturity_changes (contract_id, from_level, to_level, reason, changed_by) VALUES ($1, $2, $3, $4, $5)"
@@ -49,6 +163,19 @@ pub async fn update_maturity(
     .await
     .map_err(|e| ApiError::internal(format!("Failed to update maturity: {}", e)))?;
 
+    audit::record(
+        &state.db,
+        &AuditEntry::maturity_changed(
+            contract_id,
+            &auth.publisher_address,
+            &contract.maturity,
+            &updated.maturity,
+            req.reason.as_deref(),
+        ),
+    )
+    .await
+    .map_err(|e| ApiError::internal(format!("Failed to record audit entry: {}", e)))?;
+
     Ok(Json(updated))
 }
 
@@ -78,126 +205,175 @@ pub async fn check_maturity_requirements(
         .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
         .ok_or_else(|| ApiError::not_found("contract", "Contract not found"))?;
 
-    let versions_count = sqlx::query_scalar::<_, i64>(
-        "SELECT COUNT(*) FROM contract_versions WHERE contract_id = $1",
-    )
-    .bind(contract_id)
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
+    let facts = fetch_contract_facts(&state, &contract).await?;
 
-    let interactions_count = sqlx::query_scalar::<_, i64>(
-        "SELECT COUNT(*) FROM contract_interactions WHERE contract_id = $1",
-    )
-    .bind(contract_id)
-    .fetch_one(&state.db)
-    .await
-    .unwrap_or(0);
+    Ok(Json(state.maturity_criteria().evaluate_all(&facts)))
+}
 
-    let requirements = vec![
-        check_beta_requirements(&contract, versions_count, interactions_count),
-        check_stable_requirements(&contract, versions_count, interactions_count),
-        check_mature_requirements(&contract, versions_count, interactions_count),
-    ];
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::maturity_criteria::CriterionKind;
+    use axum::response::IntoResponse;
+    use chrono::Utc;
+    use shared::models::Network;
 
-    Ok(Json(requirements))
-}
+    fn contract() -> Contract {
+        serde_json::from_value(serde_json::json!({
+            "id": Uuid::new_v4(),
+            "contract_id": "CABC",
+            "wasm_hash": "hash",
+            "name": "Token",
+            "description": null,
+            "publisher_id": Uuid::new_v4(),
+            "network": Network::Testnet,
+            "is_verified": true,
+            "category": null,
+            "tags": [],
+            "created_at": Utc::now(),
+            "updated_at": Utc::now(),
+            "maturity": "beta"
+        }))
+        .unwrap()
+    }
 
-fn check_beta_requirements(
-    contract: &Contract,
-    versions: i64,
-    _interactions: i64,
-) -> MaturityRequirements {
-    let criteria = vec![
-        MaturityCriterion {
-            name: "verified".to_string(),
-            required: true,
-            met: contract.is_verified,
-            description: "Contract source code must be verified".to_string(),
-        },
-        MaturityCriterion {
-            name: "versions".to_string(),
-            required: true,
-            met: versions >= 1,
-            description: "At least 1 version published".to_string(),
-        },
-    ];
-
-    let met = criteria.iter().all(|c| !c.required || c.met);
-
-    MaturityRequirements {
-        level: MaturityLevel::Beta,
-        criteria,
-        met,
+    fn high_severity_check_id() -> String {
+        all_checks()
+            .into_iter()
+            .find(|c| c.severity >= Severity::High)
+            .map(|c| c.id.to_string())
+            .unwrap()
     }
-}
 
-fn check_stable_requirements(
-    contract: &Contract,
-    versions: i64,
-    interactions: i64,
-) -> MaturityRequirements {
-    let criteria = vec![
-        MaturityCriterion {
-            name: "verified".to_string(),
-            required: true,
-            met: contract.is_verified,
-            description: "Contract source code must be verified".to_string(),
-        },
-        MaturityCriterion {
-            name: "versions".to_string(),
-            required: true,
-            met: versions >= 2,
-            description: "At least 2 versions published".to_string(),
-        },
-        MaturityCriterion {
-            name: "usage".to_string(),
-            required: true,
-            met: interactions >= 10,
-            description: "At least 10 contract interactions".to_string(),
-        },
-    ];
-
-    let met = criteria.iter().all(|c| !c.required || c.met);
-
-    MaturityRequirements {
-        level: MaturityLevel::Stable,
-        criteria,
-        met,
+    #[test]
+    fn high_severity_finding_blocks_stable() {
+        let blocking = count_blocking_detections(&[high_severity_check_id()]);
+        assert_eq!(blocking, 1);
+
+        let reqs = MaturityCriteriaConfig::default()
+            .evaluate(MaturityLevel::Stable, &facts_for(&contract(), 2, 10, blocking));
+        assert!(!reqs.met);
+        let criterion = reqs
+            .criteria
+            .iter()
+            .find(|c| c.name == "security_detections")
+            .unwrap();
+        assert!(!criterion.met);
     }
-}
 
-fn check_mature_requirements(
-    contract: &Contract,
-    versions: i64,
-    interactions: i64,
-) -> MaturityRequirements {
-    let criteria = vec![
-        MaturityCriterion {
-            name: "verified".to_string(),
-            required: true,
-            met: contract.is_verified,
-            description: "Contract source code must be verified".to_string(),
-        },
-        MaturityCriterion {
-            name: "versions".to_string(),
-            required: true,
-            met: versions >= 5,
-            description: "At least 5 versions published".to_string(),
-        },
-        MaturityCriterion {
-            name: "usage".to_string(),
-            required: true,
-            met: interactions >= 100,
-            description: "At least 100 contract interactions".to_string(),
-        },
-    ];
-
-    let met = criteria.iter().all(|c| !c.required || c.met);
-
-    MaturityRequirements {
-        level: MaturityLevel::Mature,
-        criteria,
-        met,
+    #[test]
+    fn resolving_finding_unblocks_stable() {
+        let blocking = count_blocking_detections(&[]);
+        let reqs = MaturityCriteriaConfig::default()
+            .evaluate(MaturityLevel::Stable, &facts_for(&contract(), 2, 10, blocking));
+        assert!(reqs.met);
+    }
+
+    #[test]
+    fn low_severity_findings_do_not_block() {
+        let low: Vec<String> = all_checks()
+            .into_iter()
+            .filter(|c| c.severity < Severity::High)
+            .map(|c| c.id.to_string())
+            .collect();
+        assert_eq!(count_blocking_detections(&low), 0);
+    }
+
+    #[test]
+    fn promotion_follows_the_configured_criteria() {
+        let facts = facts_for(&contract(), 2, 10, 0);
+        let mut config = MaturityCriteriaConfig::default();
+        assert!(check_promotion(&config, &MaturityLevel::Beta, &MaturityLevel::Stable, &facts).is_ok());
+
+        for rules in config.levels.values_mut() {
+            for rule in rules.iter_mut().filter(|r| r.kind == CriterionKind::Versions) {
+                rule.threshold = rule.threshold.map(|t| t.max(3));
+            }
+        }
+        assert!(config.validate().is_ok());
+        let err = check_promotion(&config, &MaturityLevel::Beta, &MaturityLevel::Stable, &facts).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Demotions and sunsetting are never gated
+        assert!(check_promotion(&config, &MaturityLevel::Stable, &MaturityLevel::Beta, &facts).is_ok());
+        assert!(check_promotion(&config, &MaturityLevel::Beta, &MaturityLevel::Legacy, &facts).is_ok());
+    }
+
+    #[test]
+    fn blocking_detections_keep_their_error_code() {
+        let facts = facts_for(&contract(), 5, 100, 1);
+        let err = check_promotion(
+            &MaturityCriteriaConfig::default(),
+            &MaturityLevel::Stable,
+            &MaturityLevel::Mature,
+            &facts,
+        )
+        .unwrap_err();
+        assert!(format!("{:?}", err).contains("UnresolvedSecurityDetections"));
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn maturity_updates_are_owner_only_gated_and_audited() {
+        let pool = crate::fixtures::test_pool().await;
+        let data = crate::fixtures::fixtures();
+        let contract = &data.contracts[0];
+        let stranger = data
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address != contract.publisher_address)
+            .unwrap();
+        sqlx::query("UPDATE contracts SET maturity = 'beta' WHERE id = $1")
+            .bind(contract.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let state = crate::fixtures::state_for(pool.clone());
+        let as_caller = |address: &str| Extension(AuthContext { publisher_address: address.to_string() });
+        let to = |maturity| Json(UpdateMaturityRequest { maturity, reason: Some("test".into()) });
+
+        let err = update_maturity(State(state.clone()), as_caller(&stranger.stellar_address), Path(contract.id), to(MaturityLevel::Alpha))
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::FORBIDDEN);
+
+        let audit_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO security_audits (contract_id, auditor) VALUES ($1, 'test') RETURNING id",
+        )
+        .bind(contract.id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO audit_checks (audit_id, check_id, status) VALUES ($1, $2, 'failed')")
+            .bind(audit_id)
+            .bind(high_severity_check_id())
+            .execute(&pool)
+            .await
+            .unwrap();
+        let err = update_maturity(State(state.clone()), as_caller(&contract.publisher_address), Path(contract.id), to(MaturityLevel::Stable))
+            .await
+            .unwrap_err();
+        assert!(format!("{:?}", err).contains("UnresolvedSecurityDetections"));
+
+        let audited = || {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT COUNT(*) FROM contract_audit_log WHERE contract_id = $1 AND action_type = 'maturity_changed'",
+            )
+            .bind(contract.id)
+            .fetch_one(&pool)
+        };
+        let before = audited().await.unwrap();
+        let updated = update_maturity(State(state), as_caller(&contract.publisher_address), Path(contract.id), to(MaturityLevel::Alpha))
+            .await
+            .unwrap();
+        assert_eq!(updated.maturity, MaturityLevel::Alpha);
+        assert_eq!(audited().await.unwrap(), before + 1);
+
+        sqlx::query("DELETE FROM security_audits WHERE id = $1")
+            .bind(audit_id)
+            .execute(&pool)
+            .await
+            .unwrap();
     }
 }
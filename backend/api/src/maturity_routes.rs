@@ -1,13 +1,9 @@
-use axum::{routing::get, routing::put, Router};
+use axum::{middleware, routing::get, routing::put, Router};
 
-use crate::{maturity_handlers, state::AppState};
+use crate::{auth_middleware, maturity_handlers, state::AppState};
 
 pub fn maturity_routes() -> Router<AppState> {
     Router::new()
-        .route(
-            "/api/contracts/:id/maturity",
-            put(maturity_handlers::update_maturity),
-        )
         .route(
             "/api/contracts/:id/maturity/history",
             get(maturity_handlers::get_maturity_history),
@@ -16,4 +12,12 @@ pub fn maturity_routes() -> Router<AppState> {
             "/api/contracts/:id/maturity/requirements",
             get(maturity_handlers::check_maturity_requirements),
         )
+        .merge(
+            Router::new()
+                .route(
+                    "/api/contracts/:id/maturity",
+                    put(maturity_handlers::update_maturity),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
 }
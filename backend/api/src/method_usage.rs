@@ -0,0 +1,166 @@
+// api/src/method_usage.rs
+// Per-method call counts for a contract.
+//
+//   GET /api/contracts/:id/methods/usage?days=30&limit=20
+//
+// The indexer records every invokeHostFunction call it sees in
+// `contract_interactions` along with the method name. This ranks the methods
+// of one contract by calls over the last `days` (default 30, at most 365).
+// Like the other analytics endpoints it honours the contract's
+// `public_analytics` flag.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{contract_flags, error::ApiResult, handlers::db_internal_error, state::AppState};
+
+const DEFAULT_DAYS: i64 = 30;
+const MAX_DAYS: i64 = 365;
+const DEFAULT_LIMIT: usize = 20;
+const MAX_LIMIT: usize = 100;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MethodUsageQuery {
+    pub days: Option<i64>,
+    pub limit: Option<usize>,
+}
+
+/// Calls to one method, as grouped by the database
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct MethodCounts {
+    pub method_name: String,
+    pub calls: i64,
+    pub unique_callers: i64,
+    pub last_called_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MethodUsage {
+    pub rank: usize,
+    pub method: String,
+    pub calls: i64,
+    pub unique_callers: i64,
+    /// This method's share (0–1) of all calls in the period
+    pub share: f64,
+    pub last_called_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MethodUsageResponse {
+    pub contract_id: Uuid,
+    pub days: i64,
+    pub total_calls: i64,
+    pub methods: Vec<MethodUsage>,
+}
+
+/// Rank methods by calls, most called first; ties break by method name.
+/// Shares are of every call in `counts`, including methods past `limit`.
+pub fn rank_methods(mut counts: Vec<MethodCounts>, limit: usize) -> (i64, Vec<MethodUsage>) {
+    let total: i64 = counts.iter().map(|c| c.calls).sum();
+    counts.sort_by(|a, b| b.calls.cmp(&a.calls).then_with(|| a.method_name.cmp(&b.method_name)));
+
+    let methods = counts
+        .into_iter()
+        .take(limit)
+        .enumerate()
+        .map(|(i, c)| MethodUsage {
+            rank: i + 1,
+            share: if total > 0 { c.calls as f64 / total as f64 } else { 0.0 },
+            method: c.method_name,
+            calls: c.calls,
+            unique_callers: c.unique_callers,
+            last_called_at: c.last_called_at,
+        })
+        .collect();
+    (total, methods)
+}
+
+/// GET /api/contracts/:id/methods/usage
+pub async fn get_method_usage(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    headers: HeaderMap,
+    Query(query): Query<MethodUsageQuery>,
+) -> ApiResult<Json<MethodUsageResponse>> {
+    contract_flags::ensure_analytics_access(&state, id, &headers).await?;
+
+    let days = query.days.unwrap_or(DEFAULT_DAYS).clamp(1, MAX_DAYS);
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let counts: Vec<MethodCounts> = sqlx::query_as(
+        r#"
+        SELECT method_name,
+               COUNT(*) AS calls,
+               COUNT(DISTINCT user_address) AS unique_callers,
+               MAX(created_at) AS last_called_at
+        FROM contract_interactions
+        WHERE contract_id = $1 AND method_name IS NOT NULL AND created_at >= $2
+        GROUP BY method_name
+        "#,
+    )
+    .bind(id)
+    .bind(Utc::now() - Duration::days(days))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("get method usage", err))?;
+
+    let (total_calls, methods) = rank_methods(counts, limit);
+    Ok(Json(MethodUsageResponse {
+        contract_id: id,
+        days,
+        total_calls,
+        methods,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counts(method: &str, calls: i64) -> MethodCounts {
+        MethodCounts {
+            method_name: method.into(),
+            calls,
+            unique_callers: calls.min(3),
+            last_called_at: Utc::now(),
+        }
+    }
+
+    fn methods(ranked: &[MethodUsage]) -> Vec<&str> {
+        ranked.iter().map(|m| m.method.as_str()).collect()
+    }
+
+    #[test]
+    fn methods_rank_by_calls_then_name() {
+        let (total, ranked) = rank_methods(
+            vec![counts("mint", 5), counts("transfer", 40), counts("burn", 5), counts("approve", 10)],
+            10,
+        );
+        assert_eq!(total, 60);
+        assert_eq!(methods(&ranked), vec!["transfer", "approve", "burn", "mint"]);
+        assert_eq!(ranked.iter().map(|m| m.rank).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(ranked[1].share, 10.0 / 60.0);
+    }
+
+    #[test]
+    fn limit_keeps_shares_of_the_full_total() {
+        let (total, ranked) = rank_methods(vec![counts("a", 3), counts("b", 1)], 1);
+        assert_eq!(total, 4);
+        assert_eq!(methods(&ranked), vec!["a"]);
+        assert_eq!(ranked[0].share, 0.75);
+    }
+
+    #[test]
+    fn no_calls_yields_an_empty_ranking() {
+        let (total, ranked) = rank_methods(Vec::new(), 10);
+        assert_eq!(total, 0);
+        assert!(ranked.is_empty());
+    }
+}
@@ -194,6 +194,26 @@ pub static MULTISIG_EXECUTIONS: Lazy<IntCounter> =
 pub static MULTISIG_REJECTIONS: Lazy<IntCounter> =
     counter!("multisig_rejections_total", "Multisig proposals rejected");
 
+// ── Background jobs ─────────────────────────────────────────────────────────
+pub static BACKGROUND_JOB_DURATION: Lazy<HistogramVec> = Lazy::new(|| {
+    HistogramVec::new(
+        HistogramOpts::new("background_job_duration_seconds", "Background job run duration")
+            .buckets(vec![0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0, 600.0]),
+        &["job"],
+    )
+    .unwrap()
+});
+pub static BACKGROUND_JOBS_SUCCEEDED: Lazy<IntCounterVec> = counter_vec!(
+    "background_jobs_succeeded_total",
+    "Background job runs that succeeded",
+    &["job"]
+);
+pub static BACKGROUND_JOBS_FAILED: Lazy<IntCounterVec> = counter_vec!(
+    "background_jobs_failed_total",
+    "Background job runs that failed",
+    &["job"]
+);
+
 // ── System ──────────────────────────────────────────────────────────────────
 pub static PROCESS_START_TIME: Lazy<IntGauge> =
     gauge!("process_start_time_seconds", "Process start time");
@@ -275,6 +295,9 @@ pub fn register_all(r: &Registry) -> prometheus::Result<()> {
     r.register(Box::new(MULTISIG_SIGNATURES.clone()))?;
     r.register(Box::new(MULTISIG_EXECUTIONS.clone()))?;
     r.register(Box::new(MULTISIG_REJECTIONS.clone()))?;
+    r.register(Box::new(BACKGROUND_JOB_DURATION.clone()))?;
+    r.register(Box::new(BACKGROUND_JOBS_SUCCEEDED.clone()))?;
+    r.register(Box::new(BACKGROUND_JOBS_FAILED.clone()))?;
     r.register(Box::new(PROCESS_START_TIME.clone()))?;
     r.register(Box::new(BUILD_INFO.clone()))?;
     r.register(Box::new(SLO_ERROR_BUDGET.clone()))?;
@@ -328,6 +351,17 @@ pub fn observe_db_query(query: &str, duration_secs: f64) {
     DB_TRANSACTIONS_TOTAL.inc();
 }
 
+pub fn observe_background_job(job: &str, duration_secs: f64, success: bool) {
+    BACKGROUND_JOB_DURATION
+        .with_label_values(&[job])
+        .observe(duration_secs);
+    if success {
+        BACKGROUND_JOBS_SUCCEEDED.with_label_values(&[job]).inc();
+    } else {
+        BACKGROUND_JOBS_FAILED.with_label_values(&[job]).inc();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -400,6 +434,20 @@ mod tests {
         observe_verification_latency("success", 0.1);
         set_contracts_per_publisher("x", 1);
         observe_db_query("q", 0.001);
+        observe_background_job("j", 0.1, true);
+        observe_background_job("j", 0.1, false);
+        // Labelled families are only gathered once they have a child
+        HTTP_REQUEST_SIZE.with_label_values(&["GET"]).observe(1.0);
+        HTTP_RESPONSE_SIZE.with_label_values(&["GET"]).observe(1.0);
+        CONTRACT_SIZE_BYTES.with_label_values(&["x"]).observe(1.0);
+        CONTRACTS_BY_CATEGORY.with_label_values(&["defi"]).inc();
+        MIGRATION_DURATION.with_label_values(&["success"]).observe(1.0);
+        AB_TEST_IMPRESSIONS.with_label_values(&["t", "a"]).inc();
+        AB_TEST_CONVERSIONS.with_label_values(&["t", "a"]).inc();
+        BUILD_INFO.with_label_values(&["0.0.0", "test"]).set(1);
+        SLO_ERROR_BUDGET.with_label_values(&["availability"]).set(1.0);
+        SLO_BURN_RATE.with_label_values(&["availability"]).set(1.0);
+        SLO_AVAILABILITY.with_label_values(&["30d"]).set(1.0);
         let families = r.gather();
         assert!(
             families.len() >= 50,
@@ -20,7 +20,6 @@ pub async fn metrics_endpoint(State(state): State<AppState>) -> impl IntoRespons
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::auth::AuthManager;
     use crate::cache::{CacheConfig, CacheLayer};
     use crate::resource_tracking::ResourceManager;
     use axum::extract::State;
@@ -38,7 +37,17 @@ mod tests {
             cache: Arc::new(CacheLayer::new(CacheConfig::default())),
             registry,
             resource_mgr: Arc::new(RwLock::new(ResourceManager::new())),
-            auth_mgr: Arc::new(RwLock::new(AuthManager::new("test-secret".to_string()))),
+            onchain: None,
+            metadata_source: None,
+            objects: Arc::new(crate::object_store::LocalFsStore::new(std::env::temp_dir())),
+            trust_weights: Default::default(),
+            maturity_criteria: Default::default(),
+            views: Arc::new(crate::views::ViewTracker::from_env()),
+            pagination: Default::default(),
+            rpc: Arc::new(indexer::NetworkRpcClients::from_env()),
+            auth_mgr: Arc::new(RwLock::new(crate::auth::AuthManager::from_env())),
+            default_network: None,
+            notifier: Arc::new(crate::notifications::Notifier::new(create_test_pool())),
         }
     }
 
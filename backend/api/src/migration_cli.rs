@@ -10,11 +10,20 @@ use std::path::{Path, PathBuf};
 pub enum MigrationCommand {
     Add { name: String },
     Rollback { steps: u32, yes: bool },
+    /// Insert the deterministic fixture set (see `fixtures`)
+    Seed,
 }
 
 pub fn parse_command(args: &[String]) -> Result<Option<MigrationCommand>> {
-    if args.first().map(String::as_str) != Some("migrate") {
-        return Ok(None);
+    match args.first().map(String::as_str) {
+        Some("migrate") => {}
+        Some("seed") => {
+            if let Some(extra) = args.get(1) {
+                return Err(anyhow!("Unknown argument: {extra}. Usage: cargo run --bin api -- seed"));
+            }
+            return Ok(Some(MigrationCommand::Seed));
+        }
+        _ => return Ok(None),
     }
 
     match args.get(1).map(String::as_str) {
@@ -70,6 +79,14 @@ pub async fn execute(command: MigrationCommand, pool: &PgPool) -> Result<()> {
     match command {
         MigrationCommand::Add { name } => add_reversible_migration(&name),
         MigrationCommand::Rollback { steps, yes } => rollback_migrations(pool, steps, yes).await,
+        MigrationCommand::Seed => {
+            let counts = crate::fixtures::seed(pool).await?;
+            println!(
+                "Seeded fixtures: {} publishers, {} contracts, {} versions, {} analytics events",
+                counts.publishers, counts.contracts, counts.versions, counts.events
+            );
+            Ok(())
+        }
     }
 }
 
@@ -273,7 +290,18 @@ fn slugify_name(name: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use super::slugify_name;
+    use super::{parse_command, slugify_name, MigrationCommand};
+
+    fn args(raw: &[&str]) -> Vec<String> {
+        raw.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_seed_command() {
+        assert_eq!(parse_command(&args(&["seed"])).unwrap(), Some(MigrationCommand::Seed));
+        assert!(parse_command(&args(&["seed", "--force"])).is_err());
+        assert_eq!(parse_command(&args(&["serve"])).unwrap(), None);
+    }
 
     #[test]
     fn slugifies_names() {
@@ -0,0 +1,151 @@
+// api/src/migration_preview.rs
+// State migration preview between two versions of a contract.
+//
+//   POST /api/contracts/:id/migration-preview
+//        {"from_version": "1.0.0", "to_version": "2.0.0", "state": {...}}
+//
+// Compares the versions' `state_schema` (an object mapping field names to
+// type names) and runs the sample `state` through the migration without
+// storing anything: the schema diff, validation issues, and the migrated
+// state with its warnings. Same logic as `soroban-registry migrate preview`,
+// via the `schema-migration` crate.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use schema_migration::{Schema, SchemaDiff};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct MigrationPreviewRequest {
+    pub from_version: String,
+    pub to_version: String,
+    /// Sample state under `from_version`'s schema; empty when omitted
+    #[serde(default)]
+    pub state: Map<String, Value>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MigrationPreview {
+    pub from_version: String,
+    pub to_version: String,
+    pub diff: SchemaDiff,
+    /// Data loss or incompatible conversions the migration would cause
+    pub issues: Vec<String>,
+    pub migrated_state: Map<String, Value>,
+    pub warnings: Vec<String>,
+}
+
+/// A stored `state_schema` as a schema, or why it is not one
+pub fn parse_state_schema(raw: &Value) -> Result<Schema, String> {
+    let fields = raw
+        .as_object()
+        .ok_or_else(|| "state schema must be an object of field names to type names".to_string())?;
+    fields
+        .iter()
+        .map(|(field, ty)| match ty.as_str() {
+            Some(ty) => Ok((field.clone(), ty.to_string())),
+            None => Err(format!("type of field '{}' must be a string", field)),
+        })
+        .collect()
+}
+
+pub fn build_preview(req: MigrationPreviewRequest, from: &Schema, to: &Schema) -> MigrationPreview {
+    let diff = schema_migration::analyze(from, to);
+    let issues = schema_migration::validate(&req.state, to, &diff);
+    let (migrated_state, warnings) = schema_migration::dry_run(&req.state, to, &diff);
+    MigrationPreview {
+        from_version: req.from_version,
+        to_version: req.to_version,
+        diff,
+        issues,
+        migrated_state,
+        warnings,
+    }
+}
+
+async fn load_schema(state: &AppState, contract_id: Uuid, version: &str) -> ApiResult<Schema> {
+    let row: Option<Option<Value>> = sqlx::query_scalar(
+        "SELECT state_schema FROM contract_versions WHERE contract_id = $1 AND version = $2",
+    )
+    .bind(contract_id)
+    .bind(version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load version state schema", err))?;
+
+    let raw = row
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "VersionNotFound",
+                format!("Contract {} has no version '{}'", contract_id, version),
+            )
+        })?
+        .ok_or_else(|| {
+            ApiError::unprocessable(
+                "StateSchemaMissing",
+                format!("Version '{}' has no state schema on record", version),
+            )
+        })?;
+
+    parse_state_schema(&raw).map_err(|msg| {
+        ApiError::unprocessable("InvalidStateSchema", format!("Version '{}': {}", version, msg))
+    })
+}
+
+/// POST /api/contracts/:id/migration-preview
+pub async fn preview_migration(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+    Json(req): Json<MigrationPreviewRequest>,
+) -> ApiResult<Json<MigrationPreview>> {
+    let from = load_schema(&state, contract_id, &req.from_version).await?;
+    let to = load_schema(&state, contract_id, &req.to_version).await?;
+    Ok(Json(build_preview(req, &from, &to)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn stored_schemas_must_map_fields_to_type_names() {
+        let schema = parse_state_schema(&json!({ "owner": "string", "supply": "integer" })).unwrap();
+        assert_eq!(schema["supply"], "integer");
+
+        assert!(parse_state_schema(&json!(["owner"])).is_err());
+        let err = parse_state_schema(&json!({ "owner": { "type": "string" } })).unwrap_err();
+        assert!(err.contains("'owner'"));
+    }
+
+    #[test]
+    fn preview_reports_diff_issues_and_migrated_state() {
+        let from = parse_state_schema(&json!({ "owner": "string", "supply": "string", "legacy": "bool" })).unwrap();
+        let to = parse_state_schema(&json!({ "owner": "string", "supply": "integer", "paused": "bool" })).unwrap();
+        let req = MigrationPreviewRequest {
+            from_version: "1.0.0".into(),
+            to_version: "2.0.0".into(),
+            state: serde_json::from_value(json!({ "owner": "GA", "supply": "100", "legacy": true })).unwrap(),
+        };
+
+        let preview = build_preview(req, &from, &to);
+        assert_eq!(preview.diff.added_fields, vec!["paused"]);
+        assert_eq!(preview.diff.removed_fields, vec!["legacy"]);
+        assert_eq!(preview.issues.len(), 1, "{:?}", preview.issues);
+        assert_eq!(
+            Value::Object(preview.migrated_state),
+            json!({ "owner": "GA", "supply": 100, "paused": false })
+        );
+        assert!(preview.warnings[0].contains("'legacy' removed"));
+    }
+}
@@ -300,6 +300,95 @@ fn default_alert_threshold() -> f64 {
     10.0
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, sqlx::Type)]
+#[sqlx(type_name = "benchmark_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum BenchmarkStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// One row in `benchmark_records`; timings are zero until the run completes
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BenchmarkRecord {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub contract_version: String,
+    pub method_name: String,
+    pub iterations: i32,
+    pub args_json: Option<serde_json::Value>,
+    pub status: BenchmarkStatus,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub avg_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub stddev_ms: f64,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// One measured iteration in `benchmark_runs`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BenchmarkRun {
+    pub id: Uuid,
+    pub benchmark_id: Uuid,
+    pub iteration: i32,
+    pub execution_time_ms: f64,
+    pub cpu_instructions: Option<i64>,
+    pub memory_bytes: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// p95 regression alert in `benchmark_alerts`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct PerformanceAlert {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub method_name: String,
+    pub baseline_benchmark_id: Uuid,
+    pub current_benchmark_id: Uuid,
+    pub baseline_p95_ms: f64,
+    pub current_p95_ms: f64,
+    pub regression_pct: f64,
+    pub alert_threshold_pct: f64,
+    pub resolved: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// p95 of a run against the previous completed run of the same method
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchmarkComparison {
+    pub previous_benchmark_id: Uuid,
+    pub previous_version: String,
+    pub previous_p95_ms: f64,
+    pub current_p95_ms: f64,
+    pub delta_ms: f64,
+    pub delta_pct: f64,
+    pub is_regression: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchmarkResponse {
+    pub benchmark: BenchmarkRecord,
+    pub runs: Vec<BenchmarkRun>,
+    pub alert: Option<PerformanceAlert>,
+    pub comparison: Option<BenchmarkComparison>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct BenchmarkTrendPoint {
+    pub benchmark_id: Uuid,
+    pub version: String,
+    pub created_at: DateTime<Utc>,
+    pub p95_ms: f64,
+    pub avg_ms: f64,
+    pub min_ms: f64,
+    pub max_ms: f64,
+}
+
 
 // --- Reviews & Ratings Models ---
 #[derive(Debug, Deserialize)]
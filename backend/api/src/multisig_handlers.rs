@@ -7,25 +7,87 @@ use axum::{
     response::IntoResponse,
     Json,
 };
-use chrono::Utc;
-use serde::Deserialize;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use shared::{
-    CreatePolicyRequest, CreateProposalRequest, DeployProposal, MultisigPolicy, ProposalSignature,
-    ProposalStatus, ProposalWithSignatures, SignProposalRequest,
+    CreateDeployProposalRequest, CreatePolicyRequest, DeployProposal, MultisigPolicy,
+    MultisigProposalStatus as ProposalStatus, ProposalHistoryEntry, ProposalHistoryEvent,
+    ProposalSignature, ProposalSignaturePage, ProposalStatusChange, ProposalWithSignatures,
+    SignProposalRequest,
 };
 use uuid::Uuid;
 
 use crate::{
     error::{ApiError, ApiResult},
     handlers::db_internal_error,
+    notifications::Notification,
     resource_tracking::ResourceUsage,
+    signature_verifier,
     state::AppState,
+    webhooks,
 };
 
+/// Lifetime given to proposals when a policy does not set one (1 day)
+const DEFAULT_EXPIRY_SECONDS: i32 = 86_400;
+
+/// Signatures embedded in GET /api/contracts/:id/proposal; the rest are paged
+pub const EMBEDDED_SIGNATURE_LIMIT: i64 = 20;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Helper
 // ─────────────────────────────────────────────────────────────────────────────
 
+/// Allowed range for a policy's `expiry_seconds`.
+///
+/// Defaults to 5 minutes – 30 days; override with `MULTISIG_MIN_EXPIRY_SECONDS`
+/// and `MULTISIG_MAX_EXPIRY_SECONDS`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExpiryBounds {
+    pub min_seconds: i32,
+    pub max_seconds: i32,
+}
+
+impl Default for ExpiryBounds {
+    fn default() -> Self {
+        Self {
+            min_seconds: 5 * 60,
+            max_seconds: 30 * 24 * 60 * 60,
+        }
+    }
+}
+
+impl ExpiryBounds {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let read = |name: &str, fallback: i32| {
+            std::env::var(name)
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(fallback)
+        };
+        Self {
+            min_seconds: read("MULTISIG_MIN_EXPIRY_SECONDS", defaults.min_seconds),
+            max_seconds: read("MULTISIG_MAX_EXPIRY_SECONDS", defaults.max_seconds),
+        }
+    }
+
+    /// Resolve the requested expiry, rejecting values outside the range.
+    pub fn validate(&self, expiry_seconds: Option<i32>) -> ApiResult<i32> {
+        let expiry = expiry_seconds.unwrap_or(DEFAULT_EXPIRY_SECONDS);
+        if expiry < self.min_seconds || expiry > self.max_seconds {
+            return Err(ApiError::bad_request(
+                "InvalidExpiry",
+                format!(
+                    "expiry_seconds must be between {} and {} (got {})",
+                    self.min_seconds, self.max_seconds, expiry
+                ),
+            ));
+        }
+        Ok(expiry)
+    }
+}
+
 fn map_json_rejection(err: axum::extract::rejection::JsonRejection) -> ApiError {
     ApiError::bad_request(
         "InvalidRequest",
@@ -58,6 +120,51 @@ async fn expire_proposal(state: &AppState, id: Uuid) -> ApiResult<()> {
     Ok(())
 }
 
+/// Build the notification sent after a signature is recorded: progress while
+/// signatures are still needed, "approved" once the threshold is met.
+///
+/// Recipients are the proposer and any authorised signer who has not signed.
+pub fn signature_progress_notification(
+    proposal: &DeployProposal,
+    policy: &MultisigPolicy,
+    signed_by: &[String],
+) -> Notification {
+    let signatures_needed = (policy.threshold - signed_by.len() as i32).max(0);
+    let pending_signers: Vec<&String> = policy
+        .signer_addresses
+        .iter()
+        .filter(|s| !signed_by.contains(s))
+        .collect();
+
+    let mut recipients = vec![&proposal.proposer];
+    recipients.extend(pending_signers.iter().filter(|s| **s != &proposal.proposer));
+
+    let event_type = if signatures_needed == 0 {
+        webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED
+    } else {
+        webhooks::EVENT_MULTISIG_SIGNATURE_ADDED
+    };
+
+    let payload = serde_json::json!({
+        "event": event_type,
+        "proposal_id": proposal.id,
+        "contract_id": proposal.contract_id,
+        "contract_name": proposal.contract_name,
+        "network": proposal.network,
+        "proposer": proposal.proposer,
+        "signatures_collected": signed_by.len(),
+        "signatures_needed": signatures_needed,
+        "threshold": policy.threshold,
+        "pending_signers": pending_signers,
+        "recipients": recipients,
+        "expires_at": proposal.expires_at,
+    });
+
+    let recipients = recipients.into_iter().cloned().collect();
+    Notification::new(event_type, webhooks::EventScope::network(proposal.network.clone()), payload)
+        .with_recipients(recipients)
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // POST /api/multisig/policies
 // ─────────────────────────────────────────────────────────────────────────────
@@ -99,7 +206,7 @@ pub async fn create_policy(
         ));
     }
 
-    let expiry_seconds = req.expiry_seconds.unwrap_or(86_400);
+    let expiry_seconds = ExpiryBounds::from_env().validate(req.expiry_seconds)?;
 
     let policy: MultisigPolicy = sqlx::query_as(
         "INSERT INTO multisig_policies (name, threshold, signer_addresses, expiry_seconds, created_by)
@@ -128,9 +235,9 @@ pub async fn create_policy(
 /// until enough signers have signed it (threshold reached → `approved`).
 pub async fn create_proposal(
     State(state): State<AppState>,
-    payload: Result<Json<CreateProposalRequest>, axum::extract::rejection::JsonRejection>,
+    payload: Result<Json<CreateDeployProposalRequest>, axum::extract::rejection::JsonRejection>,
 ) -> ApiResult<Json<DeployProposal>> {
-    let Json(req) = payload.map_err(map_json_rejection)?;
+    let Json(mut req) = payload.map_err(map_json_rejection)?;
 
     // Validate required fields
     if req.contract_id.is_empty() {
@@ -139,12 +246,7 @@ pub async fn create_proposal(
             "contract_id is required",
         ));
     }
-    if req.wasm_hash.is_empty() {
-        return Err(ApiError::bad_request(
-            "MissingWasmHash",
-            "wasm_hash is required",
-        ));
-    }
+    req.wasm_hash = crate::validation::parse_wasm_hash(&req.wasm_hash)?;
     if req.proposer.is_empty() {
         return Err(ApiError::bad_request(
             "MissingProposer",
@@ -205,6 +307,8 @@ pub async fn create_proposal(
 /// - Proposal has not expired
 /// - Signer is in the policy's signer list
 /// - Signer has not already signed
+/// - `signature_data` verifies over the proposal's signing message under
+///   `scheme` (Ed25519 by default) for the signer's account key
 ///
 /// If the threshold is met after this signature the proposal moves to `approved`.
 pub async fn sign_proposal(
@@ -256,6 +360,17 @@ pub async fn sign_proposal(
         ));
     }
 
+    // The signature is the only proof the caller controls the signer address
+    let message = signature_verifier::proposal_signing_message(&proposal);
+    signature_verifier::verifier_for(req.scheme)
+        .verify(&req.signer_address, &message, &req.signature_data)
+        .map_err(|err| {
+            ApiError::bad_request(
+                "InvalidSignature",
+                format!("{} signature rejected: {}", req.scheme, err),
+            )
+        })?;
+
     // Insert signature (UNIQUE constraint on (proposal_id, signer_address) handles duplicates)
     let signature: ProposalSignature = sqlx::query_as(
         "INSERT INTO proposal_signatures (proposal_id, signer_address, signature_data)
@@ -280,13 +395,15 @@ pub async fn sign_proposal(
         _ => db_internal_error("insert proposal signature", err),
     })?;
 
-    // Count total signatures so far
-    let sig_count: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = $1")
-            .bind(proposal_id)
-            .fetch_one(&state.db)
-            .await
-            .map_err(|err| db_internal_error("count signatures", err))?;
+    // Collect everyone who has signed so far
+    let signed_by: Vec<String> = sqlx::query_scalar(
+        "SELECT signer_address FROM proposal_signatures WHERE proposal_id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list signers", err))?;
+    let sig_count = signed_by.len() as i64;
 
     // Promote to approved if threshold met
     if sig_count >= policy.threshold as i64 {
@@ -309,6 +426,9 @@ pub async fn sign_proposal(
 
     let signatures_needed = (policy.threshold as i64 - sig_count).max(0) as i32;
 
+    let notification = signature_progress_notification(&proposal, &policy, &signed_by);
+    state.notifier.notify(&notification).await;
+
     Ok((
         StatusCode::CREATED,
         Json(serde_json::json!({
@@ -401,7 +521,8 @@ pub async fn execute_proposal(
 // GET /api/contracts/{id}/proposal
 // ─────────────────────────────────────────────────────────────────────────────
 
-/// Return a proposal with its policy and all collected signatures.
+/// Return a proposal with its policy and the first `EMBEDDED_SIGNATURE_LIMIT`
+/// signatures.
 pub async fn get_proposal(
     State(state): State<AppState>,
     Path(proposal_id): Path<Uuid>,
@@ -414,25 +535,197 @@ pub async fn get_proposal(
         .await
         .map_err(|err| db_internal_error("fetch policy for proposal info", err))?;
 
-    let signatures: Vec<ProposalSignature> = sqlx::query_as(
-        "SELECT * FROM proposal_signatures WHERE proposal_id = $1 ORDER BY signed_at ASC",
-    )
-    .bind(proposal_id)
-    .fetch_all(&state.db)
-    .await
-    .map_err(|err| db_internal_error("list proposal signatures", err))?;
+    let total = count_signatures(&state, proposal_id).await?;
+    let signatures = fetch_signature_page(&state, proposal_id, EMBEDDED_SIGNATURE_LIMIT, 0).await?;
 
-    let collected = signatures.len() as i32;
-    let signatures_needed = (policy.threshold - collected).max(0);
+    Ok(Json(embed_signatures(proposal, policy, signatures, total)))
+}
 
-    Ok(Json(ProposalWithSignatures {
+/// Assemble the proposal view from its first page of signatures and the
+/// total count; `signatures_needed` always reflects the total.
+pub fn embed_signatures(
+    proposal: DeployProposal,
+    policy: MultisigPolicy,
+    signatures: Vec<ProposalSignature>,
+    total: i64,
+) -> ProposalWithSignatures {
+    let signatures_needed = (i64::from(policy.threshold) - total).max(0) as i32;
+    ProposalWithSignatures {
         proposal,
         policy,
+        signatures_truncated: (signatures.len() as i64) < total,
         signatures,
+        signatures_total: total,
         signatures_needed,
+    }
+}
+
+async fn count_signatures(state: &AppState, proposal_id: Uuid) -> ApiResult<i64> {
+    sqlx::query_scalar("SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = $1")
+        .bind(proposal_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("count proposal signatures", err))
+}
+
+async fn fetch_signature_page(
+    state: &AppState,
+    proposal_id: Uuid,
+    limit: i64,
+    offset: i64,
+) -> ApiResult<Vec<ProposalSignature>> {
+    sqlx::query_as(
+        "SELECT * FROM proposal_signatures WHERE proposal_id = $1
+         ORDER BY signed_at ASC, id ASC
+         LIMIT $2 OFFSET $3",
+    )
+    .bind(proposal_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list proposal signatures", err))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GET /api/contracts/{id}/signatures?page=&limit=
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize)]
+pub struct SignaturePageParams {
+    pub page: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+/// Page number (1-based, clamped) and row offset for `page` of size `limit`.
+pub fn page_offset(page: Option<i64>, limit: i64) -> (i64, i64) {
+    let page = page.unwrap_or(1).max(1);
+    (page, (page - 1) * limit)
+}
+
+pub fn total_pages(total: i64, limit: i64) -> i64 {
+    (total + limit - 1) / limit
+}
+
+/// Page through every signature on a proposal, oldest first.
+pub async fn list_proposal_signatures(
+    State(state): State<AppState>,
+    Path(proposal_id): Path<Uuid>,
+    Query(params): Query<SignaturePageParams>,
+) -> ApiResult<Json<ProposalSignaturePage>> {
+    // 404 for unknown proposals rather than an empty page
+    fetch_proposal(&state, proposal_id).await?;
+
+    let limit = state.pagination.limit(params.limit);
+    let (page, offset) = page_offset(params.page, limit);
+    let total = count_signatures(&state, proposal_id).await?;
+    let items = fetch_signature_page(&state, proposal_id, limit, offset).await?;
+
+    Ok(Json(ProposalSignaturePage {
+        items,
+        total,
+        page,
+        total_pages: total_pages(total, limit),
     }))
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// GET /api/contracts/{id}/proposal/{pid}/history
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Chronological audit trail of a proposal: creation, each signature, every
+/// status transition and execution.
+pub async fn get_proposal_history(
+    State(state): State<AppState>,
+    Path((contract_id, proposal_id)): Path<(String, Uuid)>,
+) -> ApiResult<Json<Vec<ProposalHistoryEntry>>> {
+    let proposal = fetch_proposal(&state, proposal_id).await?;
+    if proposal.contract_id != contract_id {
+        return Err(ApiError::not_found(
+            "ProposalNotFound",
+            format!("No proposal {} for contract {}", proposal_id, contract_id),
+        ));
+    }
+
+    let signatures: Vec<ProposalSignature> =
+        sqlx::query_as("SELECT * FROM proposal_signatures WHERE proposal_id = $1")
+            .bind(proposal_id)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch proposal signatures", err))?;
+
+    let changes: Vec<ProposalStatusChange> = sqlx::query_as(
+        "SELECT from_status, to_status, changed_at FROM proposal_status_changes
+         WHERE proposal_id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch proposal status changes", err))?;
+
+    Ok(Json(build_proposal_history(&proposal, &signatures, &changes)))
+}
+
+/// Merge a proposal's signatures and status changes into one trail, oldest
+/// first. Events sharing a timestamp (a signature and the approval it
+/// triggers are written together) keep lifecycle order: created, signed,
+/// status change. The change to `executed` is reported as `executed`; for
+/// proposals executed before status changes were recorded it falls back to
+/// `executed_at`.
+pub fn build_proposal_history(
+    proposal: &DeployProposal,
+    signatures: &[ProposalSignature],
+    changes: &[ProposalStatusChange],
+) -> Vec<ProposalHistoryEntry> {
+    let mut ranked = vec![(
+        0,
+        ProposalHistoryEntry {
+            at: proposal.created_at,
+            event: ProposalHistoryEvent::Created {
+                proposer: proposal.proposer.clone(),
+            },
+        },
+    )];
+
+    ranked.extend(signatures.iter().map(|s| {
+        (
+            1,
+            ProposalHistoryEntry {
+                at: s.signed_at,
+                event: ProposalHistoryEvent::Signed {
+                    signer_address: s.signer_address.clone(),
+                },
+            },
+        )
+    }));
+
+    ranked.extend(changes.iter().map(|c| {
+        let event = if c.to_status == ProposalStatus::Executed {
+            ProposalHistoryEvent::Executed
+        } else {
+            ProposalHistoryEvent::StatusChanged {
+                from: c.from_status.clone(),
+                to: c.to_status.clone(),
+            }
+        };
+        (2, ProposalHistoryEntry { at: c.changed_at, event })
+    }));
+
+    let executed_recorded = changes.iter().any(|c| c.to_status == ProposalStatus::Executed);
+    if let (Some(executed_at), false) = (proposal.executed_at, executed_recorded) {
+        ranked.push((
+            2,
+            ProposalHistoryEntry {
+                at: executed_at,
+                event: ProposalHistoryEvent::Executed,
+            },
+        ));
+    }
+
+    ranked.sort_by(|(a_rank, a), (b_rank, b)| a.at.cmp(&b.at).then(a_rank.cmp(b_rank)));
+    ranked.into_iter().map(|(_, entry)| entry).collect()
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // GET /api/multisig/proposals
 // ─────────────────────────────────────────────────────────────────────────────
@@ -458,8 +751,8 @@ pub async fn list_proposals(
     let mut where_clauses: Vec<String> = Vec::new();
     let mut arg_idx = 1usize;
 
-    if let Some(ref s) = params.status {
-        where_clauses.push(format!("status = ${}", arg_idx));
+    if params.status.is_some() {
+        where_clauses.push(format!("status::text = ${}", arg_idx));
         arg_idx += 1;
     }
     if params.policy_id.is_some() {
@@ -515,3 +808,524 @@ pub async fn list_proposals(
         "pages": total_pages,
     })))
 }
+
+// ─────────────────────────────────────────────────────────────────────────────
+// GET /api/multisig/pending?signer=<addr>
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct PendingForSignerParams {
+    pub signer: String,
+}
+
+/// A proposal still waiting on a particular signer
+#[derive(Debug, Clone, Serialize)]
+pub struct PendingProposal {
+    #[serde(flatten)]
+    pub proposal: DeployProposal,
+    pub threshold: i32,
+    pub signatures_collected: i32,
+    pub signatures_needed: i32,
+}
+
+/// Open, unexpired proposals `signer` may sign and has not signed yet,
+/// soonest to expire first. `signed_by` maps proposal id to its signers.
+pub fn signer_worklist(
+    signer: &str,
+    proposals: Vec<DeployProposal>,
+    policies: &HashMap<Uuid, MultisigPolicy>,
+    signed_by: &HashMap<Uuid, Vec<String>>,
+    now: DateTime<Utc>,
+) -> Vec<PendingProposal> {
+    let mut pending: Vec<PendingProposal> = proposals
+        .into_iter()
+        .filter(|p| matches!(p.status, ProposalStatus::Pending | ProposalStatus::Approved))
+        .filter(|p| p.expires_at > now)
+        .filter_map(|proposal| {
+            let policy = policies.get(&proposal.policy_id)?;
+            if !policy.signer_addresses.iter().any(|s| s == signer) {
+                return None;
+            }
+            let signers = signed_by.get(&proposal.id).map(Vec::as_slice).unwrap_or_default();
+            if signers.iter().any(|s| s == signer) {
+                return None;
+            }
+            let collected = signers.len() as i32;
+            Some(PendingProposal {
+                threshold: policy.threshold,
+                signatures_collected: collected,
+                signatures_needed: (policy.threshold - collected).max(0),
+                proposal,
+            })
+        })
+        .collect();
+    pending.sort_by_key(|p| p.proposal.expires_at);
+    pending
+}
+
+/// The signer's worklist: proposals awaiting their signature.
+pub async fn list_pending_for_signer(
+    State(state): State<AppState>,
+    Query(params): Query<PendingForSignerParams>,
+) -> ApiResult<Json<Vec<PendingProposal>>> {
+    let signer = params.signer.trim();
+    if signer.is_empty() {
+        return Err(ApiError::bad_request("MissingSigner", "signer must not be empty"));
+    }
+
+    let policies: Vec<MultisigPolicy> =
+        sqlx::query_as("SELECT * FROM multisig_policies WHERE $1 = ANY(signer_addresses)")
+            .bind(signer)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("list signer policies", err))?;
+    if policies.is_empty() {
+        return Ok(Json(Vec::new()));
+    }
+    let policy_ids: Vec<Uuid> = policies.iter().map(|p| p.id).collect();
+
+    let proposals: Vec<DeployProposal> = sqlx::query_as(
+        "SELECT * FROM deploy_proposals
+         WHERE policy_id = ANY($1)
+           AND status IN ('pending', 'approved')
+           AND expires_at > NOW()",
+    )
+    .bind(&policy_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list open proposals", err))?;
+    let proposal_ids: Vec<Uuid> = proposals.iter().map(|p| p.id).collect();
+
+    let signatures: Vec<(Uuid, String)> = sqlx::query_as(
+        "SELECT proposal_id, signer_address FROM proposal_signatures WHERE proposal_id = ANY($1)",
+    )
+    .bind(&proposal_ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list proposal signers", err))?;
+
+    let mut signed_by: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for (proposal_id, address) in signatures {
+        signed_by.entry(proposal_id).or_default().push(address);
+    }
+    let policies = policies.into_iter().map(|p| (p.id, p)).collect();
+
+    Ok(Json(signer_worklist(signer, proposals, &policies, &signed_by, Utc::now())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+    use shared::Network;
+
+    fn policy(threshold: i32) -> MultisigPolicy {
+        MultisigPolicy {
+            id: Uuid::new_v4(),
+            name: "release".into(),
+            threshold,
+            signer_addresses: vec!["GA".into(), "GB".into(), "GC".into()],
+            expiry_seconds: DEFAULT_EXPIRY_SECONDS,
+            created_by: "GADMIN".into(),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn proposal(policy: &MultisigPolicy) -> DeployProposal {
+        DeployProposal {
+            id: Uuid::new_v4(),
+            contract_name: "token".into(),
+            contract_id: "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".into(),
+            wasm_hash: "abc".into(),
+            network: Network::Testnet,
+            description: None,
+            policy_id: policy.id,
+            status: ProposalStatus::Pending,
+            expires_at: Utc::now() + chrono::Duration::hours(1),
+            executed_at: None,
+            proposer: "GPROPOSER".into(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn signing_notifies_proposer_and_remaining_signers() {
+        let policy = policy(2);
+        let proposal = proposal(&policy);
+
+        let notification =
+            signature_progress_notification(&proposal, &policy, &["GA".to_string()]);
+        let payload = &notification.payload;
+
+        assert_eq!(notification.event_type, webhooks::EVENT_MULTISIG_SIGNATURE_ADDED);
+        assert_eq!(payload["signatures_needed"], 1);
+        assert_eq!(payload["pending_signers"], serde_json::json!(["GB", "GC"]));
+        assert_eq!(payload["recipients"], serde_json::json!(["GPROPOSER", "GB", "GC"]));
+        assert_eq!(notification.recipients, vec!["GPROPOSER", "GB", "GC"]);
+        assert_eq!(notification.scope.network.map(|n| n.to_string()), Some(proposal.network.to_string()));
+    }
+
+    #[test]
+    fn reaching_threshold_sends_approved_notification() {
+        let policy = policy(2);
+        let proposal = proposal(&policy);
+
+        let notification = signature_progress_notification(
+            &proposal,
+            &policy,
+            &["GA".to_string(), "GC".to_string()],
+        );
+
+        assert_eq!(notification.event_type, webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED);
+        assert_eq!(notification.payload["signatures_needed"], 0);
+        assert_eq!(notification.payload["recipients"], serde_json::json!(["GPROPOSER", "GB"]));
+    }
+
+    #[test]
+    fn signer_who_already_signed_is_excluded_from_their_worklist() {
+        let policy = policy(2);
+        let signed = proposal(&policy);
+        let unsigned = proposal(&policy);
+        let mut expired = proposal(&policy);
+        expired.expires_at = Utc::now() - chrono::Duration::minutes(1);
+        let mut executed = proposal(&policy);
+        executed.status = ProposalStatus::Executed;
+
+        let policies = HashMap::from([(policy.id, policy.clone())]);
+        let signed_by = HashMap::from([
+            (signed.id, vec!["GA".to_string()]),
+            (unsigned.id, vec!["GB".to_string()]),
+        ]);
+        let all = vec![signed.clone(), unsigned.clone(), expired, executed];
+
+        let for_a = signer_worklist("GA", all.clone(), &policies, &signed_by, Utc::now());
+        assert_eq!(for_a.len(), 1);
+        assert_eq!(for_a[0].proposal.id, unsigned.id);
+        assert_eq!(for_a[0].signatures_collected, 1);
+        assert_eq!(for_a[0].signatures_needed, 1);
+
+        let for_c = signer_worklist("GC", all.clone(), &policies, &signed_by, Utc::now());
+        assert_eq!(for_c.len(), 2);
+
+        assert!(signer_worklist("GOUTSIDER", all, &policies, &signed_by, Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn expiry_defaults_to_one_day() {
+        assert_eq!(ExpiryBounds::default().validate(None).unwrap(), DEFAULT_EXPIRY_SECONDS);
+    }
+
+    #[test]
+    fn expiry_bounds_are_inclusive() {
+        let bounds = ExpiryBounds::default();
+        assert_eq!(bounds.validate(Some(300)).unwrap(), 300);
+        assert_eq!(bounds.validate(Some(2_592_000)).unwrap(), 2_592_000);
+    }
+
+    #[test]
+    fn expiry_outside_bounds_is_400_with_range() {
+        let bounds = ExpiryBounds::default();
+        for secs in [0, 299, 2_592_001, i32::MAX] {
+            let err = bounds.validate(Some(secs)).unwrap_err();
+            assert_eq!(err.into_response().status(), StatusCode::BAD_REQUEST);
+        }
+
+        let err = bounds.validate(Some(1)).unwrap_err();
+        let msg = format!("{:?}", err);
+        assert!(msg.contains("300") && msg.contains("2592000"));
+    }
+
+    fn signatures(proposal_id: Uuid, n: usize) -> Vec<ProposalSignature> {
+        let start = Utc::now() - chrono::Duration::hours(1);
+        (0..n)
+            .map(|i| ProposalSignature {
+                id: Uuid::from_u128(i as u128 + 1),
+                proposal_id,
+                signer_address: format!("GSIGNER{}", i),
+                signature_data: None,
+                signed_at: start + chrono::Duration::seconds(i as i64),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn pages_cover_every_signature_exactly_once() {
+        let all = signatures(Uuid::new_v4(), 250);
+        let limit = 40;
+        let pages = total_pages(all.len() as i64, limit);
+        assert_eq!(pages, 7);
+
+        let mut seen = Vec::new();
+        for page in 1..=pages {
+            let (_, offset) = page_offset(Some(page), limit);
+            let slice: Vec<_> = all.iter().skip(offset as usize).take(limit as usize).collect();
+            if page < pages {
+                assert_eq!(slice.len(), limit as usize);
+            } else {
+                assert_eq!(slice.len(), 10);
+            }
+            seen.extend(slice.into_iter().map(|s| s.id));
+        }
+        assert_eq!(seen, all.iter().map(|s| s.id).collect::<Vec<_>>());
+
+        // Past the end is empty, not an error
+        let (_, offset) = page_offset(Some(pages + 1), limit);
+        assert_eq!(all.iter().skip(offset as usize).count(), 0);
+    }
+
+    #[test]
+    fn page_numbers_are_clamped() {
+        assert_eq!(page_offset(None, 20), (1, 0));
+        assert_eq!(page_offset(Some(0), 20), (1, 0));
+        assert_eq!(page_offset(Some(-3), 20), (1, 0));
+        assert_eq!(page_offset(Some(3), 20), (3, 40));
+        assert_eq!(total_pages(0, 20), 0);
+        assert_eq!(total_pages(20, 20), 1);
+        assert_eq!(total_pages(21, 20), 2);
+    }
+
+    #[test]
+    fn embedded_signatures_are_capped_but_counted() {
+        let policy = policy(2);
+        let proposal = proposal(&policy);
+        let all = signatures(proposal.id, 120);
+        let first_page: Vec<_> = all.iter().take(EMBEDDED_SIGNATURE_LIMIT as usize).cloned().collect();
+
+        let view = embed_signatures(proposal.clone(), policy.clone(), first_page, all.len() as i64);
+        assert_eq!(view.signatures.len(), EMBEDDED_SIGNATURE_LIMIT as usize);
+        assert_eq!(view.signatures_total, 120);
+        assert!(view.signatures_truncated);
+        assert_eq!(view.signatures_needed, 0);
+
+        let view = embed_signatures(proposal, policy, signatures(Uuid::nil(), 1), 1);
+        assert!(!view.signatures_truncated);
+        assert_eq!(view.signatures_needed, 1);
+    }
+
+    fn change(from: ProposalStatus, to: ProposalStatus, changed_at: DateTime<Utc>) -> ProposalStatusChange {
+        ProposalStatusChange {
+            from_status: from,
+            to_status: to,
+            changed_at,
+        }
+    }
+
+    fn events(history: &[ProposalHistoryEntry]) -> Vec<ProposalHistoryEvent> {
+        history.iter().map(|e| e.event.clone()).collect()
+    }
+
+    #[test]
+    fn history_follows_create_sign_approve_execute() {
+        let policy = policy(2);
+        let mut proposal = proposal(&policy);
+        let mut sigs = signatures(proposal.id, 2);
+        proposal.created_at = sigs[0].signed_at - chrono::Duration::minutes(5);
+        let approved_at = sigs[1].signed_at;
+        let executed_at = approved_at + chrono::Duration::minutes(1);
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = Some(executed_at);
+
+        // Inputs in no particular order; the approval shares the second signature's timestamp
+        sigs.reverse();
+        let changes = vec![
+            change(ProposalStatus::Approved, ProposalStatus::Executed, executed_at),
+            change(ProposalStatus::Pending, ProposalStatus::Approved, approved_at),
+        ];
+
+        let history = build_proposal_history(&proposal, &sigs, &changes);
+        assert_eq!(
+            events(&history),
+            vec![
+                ProposalHistoryEvent::Created {
+                    proposer: "GPROPOSER".into()
+                },
+                ProposalHistoryEvent::Signed {
+                    signer_address: "GSIGNER0".into()
+                },
+                ProposalHistoryEvent::Signed {
+                    signer_address: "GSIGNER1".into()
+                },
+                ProposalHistoryEvent::StatusChanged {
+                    from: ProposalStatus::Pending,
+                    to: ProposalStatus::Approved,
+                },
+                ProposalHistoryEvent::Executed,
+            ]
+        );
+        assert!(history.windows(2).all(|w| w[0].at <= w[1].at));
+        assert_eq!(history.last().unwrap().at, executed_at);
+
+        let json = serde_json::to_value(&history[3]).unwrap();
+        assert_eq!(json["event"], "status_changed");
+        assert_eq!(json["from"], "pending");
+        assert_eq!(json["to"], "approved");
+    }
+
+    #[test]
+    fn execution_without_a_recorded_change_uses_executed_at() {
+        let policy = policy(1);
+        let mut proposal = proposal(&policy);
+        let executed_at = proposal.created_at + chrono::Duration::minutes(2);
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = Some(executed_at);
+
+        let history = build_proposal_history(&proposal, &[], &[]);
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[1].event, ProposalHistoryEvent::Executed);
+        assert_eq!(history[1].at, executed_at);
+
+        // A recorded change is not reported twice
+        let changes = vec![change(ProposalStatus::Approved, ProposalStatus::Executed, executed_at)];
+        assert_eq!(build_proposal_history(&proposal, &[], &changes).len(), 2);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn proposal_status_changes_are_recorded_by_trigger() {
+        let pool = crate::fixtures::test_pool().await;
+
+        let policy_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO multisig_policies (name, threshold, signer_addresses, created_by)
+             VALUES ('history test', 1, ARRAY['GA'], 'GADMIN') RETURNING id",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let proposal_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO deploy_proposals
+                 (contract_name, contract_id, wasm_hash, network, policy_id, expires_at, proposer)
+             VALUES ('history', 'CHISTORY', 'abc', 'testnet', $1, NOW() + INTERVAL '1 hour', 'GPROPOSER')
+             RETURNING id",
+        )
+        .bind(policy_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        sqlx::query("INSERT INTO proposal_signatures (proposal_id, signer_address) VALUES ($1, 'GA')")
+            .bind(proposal_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        for update in [
+            "UPDATE deploy_proposals SET status = 'approved' WHERE id = $1",
+            // Touching other columns leaves no status change behind
+            "UPDATE deploy_proposals SET description = 'ready' WHERE id = $1",
+            "UPDATE deploy_proposals SET status = 'executed', executed_at = NOW() WHERE id = $1",
+        ] {
+            sqlx::query(update).bind(proposal_id).execute(&pool).await.unwrap();
+        }
+
+        let proposal: DeployProposal = sqlx::query_as("SELECT * FROM deploy_proposals WHERE id = $1")
+            .bind(proposal_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let sigs: Vec<ProposalSignature> =
+            sqlx::query_as("SELECT * FROM proposal_signatures WHERE proposal_id = $1")
+                .bind(proposal_id)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        let changes: Vec<ProposalStatusChange> = sqlx::query_as(
+            "SELECT from_status, to_status, changed_at FROM proposal_status_changes WHERE proposal_id = $1",
+        )
+        .bind(proposal_id)
+        .fetch_all(&pool)
+        .await
+        .unwrap();
+        assert_eq!(changes.len(), 2);
+
+        let history = build_proposal_history(&proposal, &sigs, &changes);
+        assert!(matches!(history[0].event, ProposalHistoryEvent::Created { .. }));
+        assert!(matches!(history[1].event, ProposalHistoryEvent::Signed { .. }));
+        assert!(matches!(
+            history[2].event,
+            ProposalHistoryEvent::StatusChanged {
+                to: ProposalStatus::Approved,
+                ..
+            }
+        ));
+        assert_eq!(history[3].event, ProposalHistoryEvent::Executed);
+        assert_eq!(history.len(), 4);
+
+        sqlx::query("DELETE FROM deploy_proposals WHERE id = $1")
+            .bind(proposal_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM multisig_policies WHERE id = $1")
+            .bind(policy_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn signing_requires_a_valid_signature_from_the_signer() {
+        use ed25519_dalek::{Signer, SigningKey};
+
+        let pool = crate::fixtures::test_pool().await;
+        let key = SigningKey::from_bytes(&[42u8; 32]);
+        let signer = crate::stellar::encode_account_id(key.verifying_key().as_bytes());
+
+        let policy_id: Uuid = sqlx::query_scalar(
+            "INSERT INTO multisig_policies (name, threshold, signer_addresses, created_by)
+             VALUES ('signing test', 2, ARRAY[$1, 'GOTHER'], 'GADMIN') RETURNING id",
+        )
+        .bind(&signer)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        let proposal: DeployProposal = sqlx::query_as(
+            "INSERT INTO deploy_proposals
+                 (contract_name, contract_id, wasm_hash, network, policy_id, expires_at, proposer)
+             VALUES ('signing', 'CSIGNING', 'abc', 'testnet', $1, NOW() + INTERVAL '1 hour', 'GPROPOSER')
+             RETURNING *",
+        )
+        .bind(policy_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+
+        let sign = |signature: String| {
+            let state = crate::fixtures::state_for(pool.clone());
+            let req = SignProposalRequest {
+                signer_address: signer.clone(),
+                signature_data: signature,
+                scheme: Default::default(),
+            };
+            async move {
+                match sign_proposal(State(state), Path(proposal.id), Ok(Json(req))).await {
+                    Ok(response) => response.into_response().status(),
+                    Err(err) => err.into_response().status(),
+                }
+            }
+        };
+
+        let wrong_message = hex::encode(key.sign(b"approve something else").to_bytes());
+        assert_eq!(sign(wrong_message).await, StatusCode::BAD_REQUEST);
+        let message = signature_verifier::proposal_signing_message(&proposal);
+        assert_eq!(sign(hex::encode(key.sign(&message).to_bytes())).await, StatusCode::CREATED);
+
+        let recorded: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM proposal_signatures WHERE proposal_id = $1")
+            .bind(proposal.id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(recorded, 1);
+
+        sqlx::query("DELETE FROM deploy_proposals WHERE id = $1")
+            .bind(proposal.id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM multisig_policies WHERE id = $1")
+            .bind(policy_id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -21,6 +21,11 @@ pub fn multisig_routes() -> Router<AppState> {
             "/api/multisig/proposals",
             get(multisig_handlers::list_proposals),
         )
+        // A signer's worklist: open proposals they have not signed yet
+        .route(
+            "/api/multisig/pending",
+            get(multisig_handlers::list_pending_for_signer),
+        )
         // Create an unsigned proposal (spec: POST /contracts/deploy-proposal)
         .route(
             "/api/contracts/deploy-proposal",
@@ -41,4 +46,14 @@ pub fn multisig_routes() -> Router<AppState> {
             "/api/contracts/:id/proposal",
             get(multisig_handlers::get_proposal),
         )
+        // Chronological audit trail of one of a contract's proposals
+        .route(
+            "/api/contracts/:id/proposal/:pid/history",
+            get(multisig_handlers::get_proposal_history),
+        )
+        // Every signature on a proposal, paginated (?page=&limit=)
+        .route(
+            "/api/contracts/:id/signatures",
+            get(multisig_handlers::list_proposal_signatures),
+        )
 }
@@ -0,0 +1,202 @@
+// api/src/network_lifecycle.rs
+// Lifecycle metadata for the networks contracts are published to.
+//
+//   GET /api/networks                   — every network's lifecycle flags
+//   PUT /api/admin/networks/:network    — replace one network's flags (admin)
+//
+// Futurenet is wiped periodically, and networks can be deprecated. Publishing
+// to a deprecated network still succeeds but carries a warning, and contracts
+// registered before a network's `last_reset_at` are reported as stale.
+
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use sqlx::{FromRow, PgPool};
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::ApiResult,
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct NetworkLifecycle {
+    pub network: Network,
+    pub active: bool,
+    pub deprecated: bool,
+    /// Operator notice about an upcoming or recent reset
+    pub reset_notice: Option<String>,
+    pub last_reset_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct NetworkStatus {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub lifecycle: NetworkLifecycle,
+    /// Contracts registered before the last reset, which no longer exist on chain
+    pub stale_contracts: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct UpdateNetworkLifecycleRequest {
+    pub active: bool,
+    pub deprecated: bool,
+    pub reset_notice: Option<String>,
+    pub last_reset_at: Option<DateTime<Utc>>,
+}
+
+/// Lifecycle row for `network`, or `None` if the operator has not configured one.
+pub async fn load_lifecycle(pool: &PgPool, network: &Network) -> Result<Option<NetworkLifecycle>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT network, active, deprecated, reset_notice, last_reset_at, updated_at
+         FROM network_lifecycle WHERE network = $1",
+    )
+    .bind(network)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Non-fatal issues with publishing to a network in this lifecycle state.
+pub fn publish_warnings(lifecycle: &NetworkLifecycle) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if lifecycle.deprecated {
+        warnings.push(format!(
+            "Network {} is deprecated; new contracts should target another network",
+            lifecycle.network
+        ));
+    }
+    if let Some(notice) = lifecycle.reset_notice.as_deref().filter(|n| !n.trim().is_empty()) {
+        warnings.push(format!("Network {} reset notice: {}", lifecycle.network, notice));
+    }
+    warnings
+}
+
+/// GET /api/networks
+pub async fn list_networks(State(state): State<AppState>) -> ApiResult<Json<Vec<NetworkStatus>>> {
+    let networks: Vec<NetworkStatus> = sqlx::query_as(
+        "SELECT nl.network, nl.active, nl.deprecated, nl.reset_notice, nl.last_reset_at, nl.updated_at,
+                (SELECT COUNT(*) FROM contracts c
+                  WHERE c.network = nl.network
+                    AND nl.last_reset_at IS NOT NULL
+                    AND c.created_at < nl.last_reset_at) AS stale_contracts
+         FROM network_lifecycle nl
+         ORDER BY nl.network",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list network lifecycle", err))?;
+
+    Ok(Json(networks))
+}
+
+/// PUT /api/admin/networks/:network
+pub async fn update_network_lifecycle(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(network): Path<Network>,
+    Json(req): Json<UpdateNetworkLifecycleRequest>,
+) -> ApiResult<Json<NetworkLifecycle>> {
+    auth.require_admin()?;
+
+    let lifecycle: NetworkLifecycle = sqlx::query_as(
+        "INSERT INTO network_lifecycle (network, active, deprecated, reset_notice, last_reset_at, updated_at)
+         VALUES ($1, $2, $3, $4, $5, NOW())
+         ON CONFLICT (network) DO UPDATE SET
+             active = EXCLUDED.active,
+             deprecated = EXCLUDED.deprecated,
+             reset_notice = EXCLUDED.reset_notice,
+             last_reset_at = EXCLUDED.last_reset_at,
+             updated_at = NOW()
+         RETURNING network, active, deprecated, reset_notice, last_reset_at, updated_at",
+    )
+    .bind(&network)
+    .bind(req.active)
+    .bind(req.deprecated)
+    .bind(&req.reset_notice)
+    .bind(req.last_reset_at)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("update network lifecycle", err))?;
+
+    tracing::info!(
+        network = %lifecycle.network,
+        deprecated = lifecycle.deprecated,
+        active = lifecycle.active,
+        updated_by = %auth.publisher_address,
+        "network lifecycle updated"
+    );
+    Ok(Json(lifecycle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lifecycle(deprecated: bool, reset_notice: Option<&str>) -> NetworkLifecycle {
+        NetworkLifecycle {
+            network: Network::Futurenet,
+            active: true,
+            deprecated,
+            reset_notice: reset_notice.map(str::to_string),
+            last_reset_at: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn publishing_to_a_deprecated_network_warns() {
+        let warnings = publish_warnings(&lifecycle(true, None));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("futurenet is deprecated"));
+    }
+
+    #[test]
+    fn healthy_network_has_no_warnings() {
+        assert!(publish_warnings(&lifecycle(false, None)).is_empty());
+        assert!(publish_warnings(&lifecycle(false, Some("  "))).is_empty());
+    }
+
+    #[test]
+    fn reset_notice_is_surfaced() {
+        let warnings = publish_warnings(&lifecycle(true, Some("Resets on 2026-11-01")));
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[1].contains("Resets on 2026-11-01"));
+    }
+
+    #[test]
+    fn warnings_are_serialized_alongside_the_contract() {
+        let contract: shared::Contract = serde_json::from_value(serde_json::json!({
+            "id": uuid::Uuid::nil(),
+            "contract_id": "CTOKEN",
+            "wasm_hash": "00",
+            "name": "Token",
+            "description": null,
+            "publisher_id": uuid::Uuid::nil(),
+            "network": "futurenet",
+            "is_verified": false,
+            "category": null,
+            "tags": [],
+            "created_at": Utc::now(),
+            "updated_at": Utc::now(),
+        }))
+        .unwrap();
+        let body = serde_json::to_value(shared::PublishResponse {
+            contract: contract.clone(),
+            warnings: publish_warnings(&lifecycle(true, None)),
+        })
+        .unwrap();
+
+        assert_eq!(body["contract_id"], "CTOKEN");
+        assert_eq!(body["warnings"].as_array().unwrap().len(), 1);
+
+        let quiet = serde_json::to_value(shared::PublishResponse { contract, warnings: Vec::new() }).unwrap();
+        assert!(quiet.get("warnings").is_none());
+    }
+}
@@ -0,0 +1,693 @@
+// api/src/notifications.rs
+// Notification fan-out across delivery channels.
+//
+// Producers build a `Notification` and hand it to `Notifier::notify`, which
+// passes it to every configured `NotificationChannel`:
+//
+//   webhook — queued for matching webhook subscriptions (see `webhooks`)
+//   sse     — pushed to the recipient's open GET /api/notifications/stream
+//   in_app  — stored in the recipient's inbox, GET /api/notifications
+//   email   — stub; logged until a mail transport is configured
+//
+// Webhooks are subscriptions in their own right and match on the event's
+// type and scope, so that channel sees every event once. The other channels
+// address the notification's recipients individually, each recipient
+// getting only the channels their preferences enable (sse and in_app by
+// default; set with PUT /api/notifications/preferences). One channel failing
+// does not stop the others.
+
+use async_trait::async_trait;
+use axum::{
+    extract::State,
+    response::sse::{Event, KeepAlive, Sse},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    webhooks::{self, EventScope},
+};
+
+/// Messages buffered for slow SSE listeners before they start missing events
+const SSE_BUFFER: usize = 256;
+
+/// Entries returned by the inbox endpoint
+const INBOX_LIMIT: i64 = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ChannelKind {
+    Webhook,
+    Sse,
+    InApp,
+    Email,
+}
+
+impl ChannelKind {
+    /// Whether the channel addresses recipients one by one, subject to their
+    /// preferences. Webhooks match on their own subscription instead.
+    pub fn is_per_subscriber(self) -> bool {
+        !matches!(self, ChannelKind::Webhook)
+    }
+
+    /// Used for recipients who have not set a preference for the channel
+    pub fn enabled_by_default(self) -> bool {
+        matches!(self, ChannelKind::Sse | ChannelKind::InApp)
+    }
+}
+
+/// An event to tell subscribers about
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub event_type: String,
+    /// What the event is about, for scoped webhook subscriptions
+    pub scope: EventScope,
+    pub payload: serde_json::Value,
+    /// Stellar addresses addressed directly by the per-subscriber channels
+    pub recipients: Vec<String>,
+}
+
+impl Notification {
+    pub fn new(event_type: &str, scope: EventScope, payload: serde_json::Value) -> Self {
+        Self {
+            event_type: event_type.to_string(),
+            scope,
+            payload,
+            recipients: Vec::new(),
+        }
+    }
+
+    pub fn with_recipients(mut self, recipients: Vec<String>) -> Self {
+        self.recipients = recipients;
+        self
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum NotificationError {
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+    #[error("{0}")]
+    Channel(String),
+}
+
+#[async_trait]
+pub trait NotificationChannel: Send + Sync {
+    fn kind(&self) -> ChannelKind;
+
+    /// Deliver `notification`. For per-subscriber channels `recipients` are
+    /// the recipients who enabled this channel; it is never empty. Returns
+    /// the number of deliveries handed off.
+    async fn deliver(&self, notification: &Notification, recipients: &[String]) -> Result<u64, NotificationError>;
+}
+
+pub type SharedChannel = Arc<dyn NotificationChannel>;
+
+/// Queues the event for matching webhook subscriptions
+pub struct WebhookChannel {
+    pool: PgPool,
+}
+
+#[async_trait]
+impl NotificationChannel for WebhookChannel {
+    fn kind(&self) -> ChannelKind {
+        ChannelKind::Webhook
+    }
+
+    async fn deliver(&self, notification: &Notification, _recipients: &[String]) -> Result<u64, NotificationError> {
+        Ok(webhooks::enqueue_event(&self.pool, &notification.event_type, &notification.scope, &notification.payload).await?)
+    }
+}
+
+/// One event on the SSE stream, addressed to one subscriber
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SseMessage {
+    pub subscriber: String,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+}
+
+/// Broadcasts to the open SSE streams; each stream keeps its subscriber's
+/// messages. Nothing is stored, so recipients without an open stream miss
+/// the event on this channel.
+#[derive(Clone)]
+pub struct SseChannel {
+    sender: broadcast::Sender<SseMessage>,
+}
+
+impl SseChannel {
+    pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(SSE_BUFFER);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<SseMessage> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for SseChannel {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl NotificationChannel for SseChannel {
+    fn kind(&self) -> ChannelKind {
+        ChannelKind::Sse
+    }
+
+    async fn deliver(&self, notification: &Notification, recipients: &[String]) -> Result<u64, NotificationError> {
+        let mut sent = 0;
+        for subscriber in recipients {
+            let message = SseMessage {
+                subscriber: subscriber.clone(),
+                event_type: notification.event_type.clone(),
+                payload: notification.payload.clone(),
+            };
+            // An error only means no stream is open right now
+            if self.sender.send(message).is_ok() {
+                sent += 1;
+            }
+        }
+        Ok(sent)
+    }
+}
+
+/// Stores the event in each recipient's inbox
+pub struct InAppChannel {
+    pool: PgPool,
+}
+
+#[async_trait]
+impl NotificationChannel for InAppChannel {
+    fn kind(&self) -> ChannelKind {
+        ChannelKind::InApp
+    }
+
+    async fn deliver(&self, notification: &Notification, recipients: &[String]) -> Result<u64, NotificationError> {
+        let result = sqlx::query(
+            "INSERT INTO notifications (subscriber_address, event_type, payload)
+             SELECT subscriber, $2, $3 FROM unnest($1::text[]) AS subscriber",
+        )
+        .bind(recipients)
+        .bind(&notification.event_type)
+        .bind(&notification.payload)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected())
+    }
+}
+
+/// Email delivery.
+///
+/// Stub: no mail transport is wired up yet, so messages are only logged. It
+/// exists so subscribers can opt in ahead of the real sender.
+pub struct EmailChannel;
+
+#[async_trait]
+impl NotificationChannel for EmailChannel {
+    fn kind(&self) -> ChannelKind {
+        ChannelKind::Email
+    }
+
+    async fn deliver(&self, notification: &Notification, recipients: &[String]) -> Result<u64, NotificationError> {
+        for subscriber in recipients {
+            tracing::info!(
+                %subscriber,
+                event_type = %notification.event_type,
+                "email notification skipped: no mail transport configured"
+            );
+        }
+        Ok(recipients.len() as u64)
+    }
+}
+
+/// Per-subscriber channel settings; unset channels use their default
+#[derive(Debug, Clone, Default)]
+pub struct ChannelPreferences {
+    settings: HashMap<String, HashMap<ChannelKind, bool>>,
+}
+
+impl ChannelPreferences {
+    pub fn set(&mut self, subscriber: &str, channel: ChannelKind, enabled: bool) {
+        self.settings
+            .entry(subscriber.to_string())
+            .or_default()
+            .insert(channel, enabled);
+    }
+
+    pub fn wants(&self, subscriber: &str, channel: ChannelKind) -> bool {
+        self.settings
+            .get(subscriber)
+            .and_then(|s| s.get(&channel))
+            .copied()
+            .unwrap_or_else(|| channel.enabled_by_default())
+    }
+}
+
+/// Stored preferences of `subscribers`
+pub async fn load_preferences(pool: &PgPool, subscribers: &[String]) -> Result<ChannelPreferences, sqlx::Error> {
+    let rows: Vec<(String, ChannelKind, bool)> = sqlx::query_as(
+        "SELECT subscriber_address, channel, enabled FROM notification_preferences
+         WHERE subscriber_address = ANY($1)",
+    )
+    .bind(subscribers)
+    .fetch_all(pool)
+    .await?;
+
+    let mut preferences = ChannelPreferences::default();
+    for (subscriber, channel, enabled) in rows {
+        preferences.set(&subscriber, channel, enabled);
+    }
+    Ok(preferences)
+}
+
+/// Deliveries handed off per channel for one notification
+pub type DispatchReport = BTreeMap<ChannelKind, u64>;
+
+/// The configured channels; shared through `AppState`
+pub struct Notifier {
+    pool: PgPool,
+    sse: SseChannel,
+    channels: Vec<SharedChannel>,
+}
+
+impl Notifier {
+    /// Webhook, SSE, in-app and email channels
+    pub fn new(pool: PgPool) -> Self {
+        let sse = SseChannel::new();
+        let channels: Vec<SharedChannel> = vec![
+            Arc::new(WebhookChannel { pool: pool.clone() }),
+            Arc::new(sse.clone()),
+            Arc::new(InAppChannel { pool: pool.clone() }),
+            Arc::new(EmailChannel),
+        ];
+        Self { pool, sse, channels }
+    }
+
+    /// A notifier dispatching to `channels` only
+    pub fn with_channels(pool: PgPool, channels: Vec<SharedChannel>) -> Self {
+        Self {
+            pool,
+            sse: SseChannel::new(),
+            channels,
+        }
+    }
+
+    pub fn sse(&self) -> &SseChannel {
+        &self.sse
+    }
+
+    /// Look up the recipients' preferences and dispatch. Failures are logged,
+    /// never returned: a notification must not fail the request behind it.
+    pub async fn notify(&self, notification: &Notification) -> DispatchReport {
+        let preferences = match load_preferences(&self.pool, &notification.recipients).await {
+            Ok(preferences) => preferences,
+            Err(err) => {
+                tracing::error!(error = ?err, "notifications: failed to load preferences, using defaults");
+                ChannelPreferences::default()
+            }
+        };
+        self.dispatch(notification, &preferences).await
+    }
+
+    /// Hand `notification` to every channel, per-subscriber channels getting
+    /// the recipients who enabled them.
+    pub async fn dispatch(&self, notification: &Notification, preferences: &ChannelPreferences) -> DispatchReport {
+        let mut report = DispatchReport::new();
+        for channel in &self.channels {
+            let kind = channel.kind();
+            let recipients: Vec<String> = if kind.is_per_subscriber() {
+                notification
+                    .recipients
+                    .iter()
+                    .filter(|r| preferences.wants(r, kind))
+                    .cloned()
+                    .collect()
+            } else {
+                notification.recipients.clone()
+            };
+            if kind.is_per_subscriber() && recipients.is_empty() {
+                continue;
+            }
+
+            match channel.deliver(notification, &recipients).await {
+                Ok(delivered) => {
+                    report.insert(kind, delivered);
+                }
+                Err(err) => tracing::error!(
+                    channel = ?kind,
+                    event_type = %notification.event_type,
+                    error = %err,
+                    "notifications: channel delivery failed"
+                ),
+            }
+        }
+        report
+    }
+}
+
+/// One entry in a subscriber's inbox
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct InboxNotification {
+    pub id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+    pub read_at: Option<DateTime<Utc>>,
+}
+
+/// GET /api/notifications — the caller's latest in-app notifications
+pub async fn list_inbox(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<Vec<InboxNotification>>> {
+    sqlx::query_as(
+        "SELECT id, event_type, payload, created_at, read_at FROM notifications
+         WHERE subscriber_address = $1
+         ORDER BY created_at DESC
+         LIMIT $2",
+    )
+    .bind(&auth.publisher_address)
+    .bind(INBOX_LIMIT)
+    .fetch_all(&state.db)
+    .await
+    .map(Json)
+    .map_err(|err| db_internal_error("list notifications", err))
+}
+
+/// GET /api/notifications/stream — the caller's notifications as server-sent events
+pub async fn stream_notifications(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    let receiver = state.notifier.sse().subscribe();
+    let subscriber = auth.publisher_address;
+
+    let stream = futures::stream::unfold(receiver, move |mut receiver| {
+        let subscriber = subscriber.clone();
+        async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(message) if message.subscriber == subscriber => {
+                        let event = Event::default().event(message.event_type).json_data(&message.payload);
+                        return Some((event, receiver));
+                    }
+                    Ok(_) => continue,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!(%subscriber, skipped, "notifications: SSE stream lagged");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        }
+    });
+
+    Sse::new(stream).keep_alive(KeepAlive::default())
+}
+
+/// Effective channel settings of the caller
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PreferencesResponse {
+    pub channels: BTreeMap<ChannelKind, bool>,
+}
+
+fn effective_preferences(preferences: &ChannelPreferences, subscriber: &str) -> PreferencesResponse {
+    let channels = [ChannelKind::Sse, ChannelKind::InApp, ChannelKind::Email]
+        .into_iter()
+        .map(|kind| (kind, preferences.wants(subscriber, kind)))
+        .collect();
+    PreferencesResponse { channels }
+}
+
+/// GET /api/notifications/preferences
+pub async fn get_preferences(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<PreferencesResponse>> {
+    let subscriber = auth.publisher_address;
+    let preferences = load_preferences(&state.db, std::slice::from_ref(&subscriber))
+        .await
+        .map_err(|err| db_internal_error("load notification preferences", err))?;
+    Ok(Json(effective_preferences(&preferences, &subscriber)))
+}
+
+/// PUT /api/notifications/preferences — e.g. `{"email": true, "sse": false}`;
+/// channels left out keep their current setting.
+pub async fn update_preferences(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(update): Json<BTreeMap<ChannelKind, bool>>,
+) -> ApiResult<Json<PreferencesResponse>> {
+    if update.contains_key(&ChannelKind::Webhook) {
+        return Err(ApiError::bad_request(
+            "InvalidChannel",
+            "Webhook delivery is configured through /api/webhooks",
+        ));
+    }
+
+    let subscriber = auth.publisher_address;
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin notification preferences update", err))?;
+    for (channel, enabled) in &update {
+        sqlx::query(
+            "INSERT INTO notification_preferences (subscriber_address, channel, enabled)
+             VALUES ($1, $2, $3)
+             ON CONFLICT (subscriber_address, channel)
+             DO UPDATE SET enabled = EXCLUDED.enabled, updated_at = NOW()",
+        )
+        .bind(&subscriber)
+        .bind(channel)
+        .bind(enabled)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("save notification preference", err))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit notification preferences", err))?;
+
+    let preferences = load_preferences(&state.db, std::slice::from_ref(&subscriber))
+        .await
+        .map_err(|err| db_internal_error("load notification preferences", err))?;
+    tracing::info!(%subscriber, changed = update.len(), "notification preferences updated");
+    Ok(Json(effective_preferences(&preferences, &subscriber)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    type Deliveries = Arc<Mutex<Vec<(ChannelKind, String, Vec<String>)>>>;
+
+    /// Records what it is handed; optionally fails every delivery
+    struct RecordingChannel {
+        kind: ChannelKind,
+        fail: bool,
+        seen: Deliveries,
+    }
+
+    #[async_trait]
+    impl NotificationChannel for RecordingChannel {
+        fn kind(&self) -> ChannelKind {
+            self.kind
+        }
+
+        async fn deliver(&self, notification: &Notification, recipients: &[String]) -> Result<u64, NotificationError> {
+            self.seen
+                .lock()
+                .unwrap()
+                .push((self.kind, notification.event_type.clone(), recipients.to_vec()));
+            if self.fail {
+                return Err(NotificationError::Channel("unreachable".into()));
+            }
+            Ok(recipients.len().max(1) as u64)
+        }
+    }
+
+    fn notifier(seen: &Deliveries, failing: Option<ChannelKind>) -> Notifier {
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap();
+        let channels = [ChannelKind::Webhook, ChannelKind::Sse, ChannelKind::InApp, ChannelKind::Email]
+            .into_iter()
+            .map(|kind| {
+                Arc::new(RecordingChannel {
+                    kind,
+                    fail: failing == Some(kind),
+                    seen: seen.clone(),
+                }) as SharedChannel
+            })
+            .collect();
+        Notifier::with_channels(pool, channels)
+    }
+
+    fn approved(recipients: &[&str]) -> Notification {
+        Notification::new(
+            webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED,
+            EventScope::default(),
+            serde_json::json!({ "proposal_id": "p1" }),
+        )
+        .with_recipients(recipients.iter().map(|r| r.to_string()).collect())
+    }
+
+    fn recipients_of(seen: &Deliveries, kind: ChannelKind) -> Vec<Vec<String>> {
+        seen.lock()
+            .unwrap()
+            .iter()
+            .filter(|(k, ..)| *k == kind)
+            .map(|(_, _, recipients)| recipients.clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn one_event_reaches_every_channel_the_subscriber_enabled() {
+        let seen = Deliveries::default();
+        let mut preferences = ChannelPreferences::default();
+        preferences.set("GA", ChannelKind::Email, true);
+
+        let report = notifier(&seen, None).dispatch(&approved(&["GA"]), &preferences).await;
+
+        assert_eq!(
+            report.keys().copied().collect::<Vec<_>>(),
+            vec![ChannelKind::Webhook, ChannelKind::Sse, ChannelKind::InApp, ChannelKind::Email]
+        );
+        for kind in [ChannelKind::Sse, ChannelKind::InApp, ChannelKind::Email] {
+            assert_eq!(recipients_of(&seen, kind), vec![vec!["GA".to_string()]], "{:?}", kind);
+        }
+        assert!(seen
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(_, event, _)| event == webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED));
+    }
+
+    #[tokio::test]
+    async fn each_recipient_gets_only_their_channels() {
+        let seen = Deliveries::default();
+        let mut preferences = ChannelPreferences::default();
+        preferences.set("GA", ChannelKind::Email, true);
+        preferences.set("GB", ChannelKind::Sse, false);
+        preferences.set("GC", ChannelKind::Sse, false);
+        preferences.set("GC", ChannelKind::InApp, false);
+
+        notifier(&seen, None)
+            .dispatch(&approved(&["GA", "GB", "GC"]), &preferences)
+            .await;
+
+        let names = |v: &[&str]| vec![v.iter().map(|s| s.to_string()).collect::<Vec<_>>()];
+        assert_eq!(recipients_of(&seen, ChannelKind::Sse), names(&["GA"]));
+        assert_eq!(recipients_of(&seen, ChannelKind::InApp), names(&["GA", "GB"]));
+        assert_eq!(recipients_of(&seen, ChannelKind::Email), names(&["GA"]));
+        // Webhooks match on their own subscriptions, once per event
+        assert_eq!(recipients_of(&seen, ChannelKind::Webhook).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn events_without_recipients_only_reach_webhooks() {
+        let seen = Deliveries::default();
+        let report = notifier(&seen, None)
+            .dispatch(&approved(&[]), &ChannelPreferences::default())
+            .await;
+        assert_eq!(report.keys().copied().collect::<Vec<_>>(), vec![ChannelKind::Webhook]);
+    }
+
+    #[tokio::test]
+    async fn a_failing_channel_does_not_block_the_others() {
+        let seen = Deliveries::default();
+        let report = notifier(&seen, Some(ChannelKind::Sse))
+            .dispatch(&approved(&["GA"]), &ChannelPreferences::default())
+            .await;
+
+        assert!(!report.contains_key(&ChannelKind::Sse));
+        assert_eq!(report.get(&ChannelKind::InApp), Some(&1));
+        assert_eq!(recipients_of(&seen, ChannelKind::InApp), vec![vec!["GA".to_string()]]);
+    }
+
+    #[tokio::test]
+    async fn sse_messages_are_addressed_per_subscriber() {
+        let sse = SseChannel::new();
+        let mut listener = sse.subscribe();
+
+        let sent = sse
+            .deliver(&approved(&[]), &["GA".to_string(), "GB".to_string()])
+            .await
+            .unwrap();
+        assert_eq!(sent, 2);
+
+        let first = listener.recv().await.unwrap();
+        assert_eq!(first.subscriber, "GA");
+        assert_eq!(first.event_type, webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED);
+        assert_eq!(first.payload["proposal_id"], "p1");
+        assert_eq!(listener.recv().await.unwrap().subscriber, "GB");
+    }
+
+    #[test]
+    fn unset_preferences_use_channel_defaults() {
+        let preferences = ChannelPreferences::default();
+        let effective = effective_preferences(&preferences, "GA");
+        assert!(effective.channels[&ChannelKind::Sse]);
+        assert!(effective.channels[&ChannelKind::InApp]);
+        assert!(!effective.channels[&ChannelKind::Email]);
+        assert!(!effective.channels.contains_key(&ChannelKind::Webhook));
+
+        let update: BTreeMap<ChannelKind, bool> =
+            serde_json::from_str(r#"{"email": true, "in_app": false}"#).unwrap();
+        assert!(!update[&ChannelKind::InApp]);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn in_app_inbox_receives_dispatched_events() {
+        let pool = crate::fixtures::test_pool().await;
+        let subscriber = format!("GTEST{}", Uuid::new_v4().simple()).to_uppercase();
+
+        sqlx::query(
+            "INSERT INTO notification_preferences (subscriber_address, channel, enabled)
+             VALUES ($1, 'sse', FALSE)",
+        )
+        .bind(&subscriber)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let notifier = Notifier::new(pool.clone());
+        let report = notifier.notify(&approved(&[subscriber.as_str()])).await;
+        assert_eq!(report.get(&ChannelKind::InApp), Some(&1));
+        assert!(!report.contains_key(&ChannelKind::Sse));
+
+        let stored: Vec<String> =
+            sqlx::query_scalar("SELECT event_type FROM notifications WHERE subscriber_address = $1")
+                .bind(&subscriber)
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(stored, vec![webhooks::EVENT_MULTISIG_PROPOSAL_APPROVED]);
+
+        for table in ["notifications", "notification_preferences"] {
+            sqlx::query(&format!("DELETE FROM {} WHERE subscriber_address = $1", table))
+                .bind(&subscriber)
+                .execute(&pool)
+                .await
+                .unwrap();
+        }
+    }
+}
@@ -0,0 +1,422 @@
+// api/src/object_store.rs
+// Blob storage for WASM binaries and backup bundles.
+//
+// Large payloads live outside Postgres behind `ObjectStore`; rows only keep
+// the key. `OBJECT_STORE_BACKEND` picks the implementation:
+//   fs (default) — files under OBJECT_STORE_PATH (./data/objects)
+//   s3           — an S3-compatible bucket (requires the `s3` feature)
+//
+// Downloads go straight to the backend when it can sign a time-limited URL
+// (S3 presigning); the API then answers with a redirect instead of streaming
+// the bytes itself. `DOWNLOAD_URL_TTL_SECONDS` (default 300, at most 7 days)
+// sets how long such a URL stays valid. The filesystem backend cannot sign,
+// so its downloads are proxied through the API.
+
+use async_trait::async_trait;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+const DEFAULT_DOWNLOAD_URL_TTL: Duration = Duration::from_secs(300);
+
+/// Longest expiry S3 accepts for a presigned URL
+pub const MAX_DOWNLOAD_URL_TTL: Duration = Duration::from_secs(7 * 24 * 3600);
+
+#[derive(Debug, thiserror::Error)]
+pub enum ObjectStoreError {
+    #[error("invalid object key: {0}")]
+    InvalidKey(String),
+    #[error("object store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("object store backend error: {0}")]
+    Backend(String),
+}
+
+#[async_trait]
+pub trait ObjectStore: Send + Sync {
+    /// Store `bytes` under `key`, replacing any existing object.
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), ObjectStoreError>;
+
+    /// `None` when no object exists under `key`.
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStoreError>;
+
+    /// Remove `key`. Returns whether an object was there.
+    async fn delete(&self, key: &str) -> Result<bool, ObjectStoreError>;
+
+    /// A URL that serves `key` directly for `expires_in`, or `None` when the
+    /// backend cannot sign URLs and callers should proxy via `get`.
+    ///
+    /// Signing does not check that the object exists.
+    async fn signed_url(&self, key: &str, _expires_in: Duration) -> Result<Option<String>, ObjectStoreError> {
+        validate_key(key)?;
+        Ok(None)
+    }
+}
+
+pub type SharedObjectStore = Arc<dyn ObjectStore>;
+
+/// Keys are relative, `/`-separated paths without `.`/`..` segments.
+fn validate_key(key: &str) -> Result<(), ObjectStoreError> {
+    let valid = !key.is_empty()
+        && !key.starts_with('/')
+        && !key.contains('\\')
+        && Path::new(key)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_)));
+    if valid {
+        Ok(())
+    } else {
+        Err(ObjectStoreError::InvalidKey(key.to_string()))
+    }
+}
+
+/// Objects as files under a root directory
+#[derive(Debug, Clone)]
+pub struct LocalFsStore {
+    root: PathBuf,
+}
+
+impl LocalFsStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path_for(&self, key: &str) -> Result<PathBuf, ObjectStoreError> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl ObjectStore for LocalFsStore {
+    async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), ObjectStoreError> {
+        let path = self.path_for(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        // Write then rename so readers never see a partial object
+        let tmp = path.with_extension(format!("tmp-{}", uuid::Uuid::new_v4()));
+        tokio::fs::write(&tmp, bytes).await?;
+        tokio::fs::rename(&tmp, &path).await?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::read(&path).await {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<bool, ObjectStoreError> {
+        let path = self.path_for(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(feature = "s3")]
+pub use s3_store::S3Store;
+
+#[cfg(feature = "s3")]
+mod s3_store {
+    use super::*;
+    use s3::{creds::Credentials, error::S3Error, Bucket, Region};
+
+    /// Objects in an S3-compatible bucket (AWS, MinIO, R2, ...)
+    pub struct S3Store {
+        bucket: Box<Bucket>,
+    }
+
+    fn backend_err(err: impl std::fmt::Display) -> ObjectStoreError {
+        ObjectStoreError::Backend(err.to_string())
+    }
+
+    impl S3Store {
+        /// Configure from OBJECT_STORE_S3_BUCKET, OBJECT_STORE_S3_REGION,
+        /// optional OBJECT_STORE_S3_ENDPOINT and the standard AWS credential variables.
+        pub fn from_env() -> Result<Self, ObjectStoreError> {
+            let name = std::env::var("OBJECT_STORE_S3_BUCKET")
+                .map_err(|_| backend_err("OBJECT_STORE_S3_BUCKET must be set"))?;
+            let region_name =
+                std::env::var("OBJECT_STORE_S3_REGION").unwrap_or_else(|_| "us-east-1".to_string());
+            let region = match std::env::var("OBJECT_STORE_S3_ENDPOINT") {
+                Ok(endpoint) => Region::Custom {
+                    region: region_name,
+                    endpoint,
+                },
+                Err(_) => region_name.parse().map_err(backend_err)?,
+            };
+            let credentials = Credentials::from_env().map_err(backend_err)?;
+            let bucket = Bucket::new(&name, region, credentials)
+                .map_err(backend_err)?
+                .with_path_style();
+            Ok(Self { bucket })
+        }
+    }
+
+    #[async_trait]
+    impl ObjectStore for S3Store {
+        async fn put(&self, key: &str, bytes: Vec<u8>) -> Result<(), ObjectStoreError> {
+            validate_key(key)?;
+            self.bucket
+                .put_object(key, &bytes)
+                .await
+                .map_err(backend_err)?;
+            Ok(())
+        }
+
+        async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, ObjectStoreError> {
+            validate_key(key)?;
+            match self.bucket.get_object(key).await {
+                Ok(resp) if resp.status_code() == 404 => Ok(None),
+                Ok(resp) => Ok(Some(resp.bytes().to_vec())),
+                Err(S3Error::HttpFailWithBody(404, _)) => Ok(None),
+                Err(err) => Err(backend_err(err)),
+            }
+        }
+
+        async fn delete(&self, key: &str) -> Result<bool, ObjectStoreError> {
+            validate_key(key)?;
+            // S3 deletes are idempotent and do not report whether the key existed
+            let existed = self.bucket.head_object(key).await.is_ok();
+            self.bucket.delete_object(key).await.map_err(backend_err)?;
+            Ok(existed)
+        }
+
+        async fn signed_url(&self, key: &str, expires_in: Duration) -> Result<Option<String>, ObjectStoreError> {
+            validate_key(key)?;
+            let expiry_secs = expires_in.clamp(Duration::from_secs(1), MAX_DOWNLOAD_URL_TTL).as_secs() as u32;
+            let url = self
+                .bucket
+                .presign_get(key, expiry_secs, None)
+                .await
+                .map_err(backend_err)?;
+            Ok(Some(url))
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        fn store() -> S3Store {
+            let credentials = Credentials::new(Some("AKIDEXAMPLE"), Some("secret"), None, None, None).unwrap();
+            let region = Region::Custom {
+                region: "us-east-1".into(),
+                endpoint: "http://localhost:9000".into(),
+            };
+            S3Store {
+                bucket: Bucket::new("registry", region, credentials).unwrap().with_path_style(),
+            }
+        }
+
+        #[tokio::test]
+        async fn presigned_urls_carry_the_requested_expiry() {
+            let url = store()
+                .signed_url(&wasm_key("abc"), Duration::from_secs(120))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(url.starts_with("http://localhost:9000/registry/wasm/abc.wasm?"));
+            assert!(url.contains("X-Amz-Expires=120"));
+            assert!(url.contains("X-Amz-Signature="));
+        }
+
+        #[tokio::test]
+        async fn presigned_expiry_is_capped() {
+            let url = store()
+                .signed_url(&wasm_key("abc"), Duration::from_secs(30 * 24 * 3600))
+                .await
+                .unwrap()
+                .unwrap();
+            assert!(url.contains(&format!("X-Amz-Expires={}", MAX_DOWNLOAD_URL_TTL.as_secs())));
+        }
+    }
+}
+
+/// Build the store selected by `OBJECT_STORE_BACKEND`.
+pub fn object_store_from_env() -> Result<SharedObjectStore, ObjectStoreError> {
+    let backend = std::env::var("OBJECT_STORE_BACKEND").unwrap_or_else(|_| "fs".to_string());
+    match backend.to_ascii_lowercase().as_str() {
+        "fs" | "local" => {
+            let root =
+                std::env::var("OBJECT_STORE_PATH").unwrap_or_else(|_| "./data/objects".to_string());
+            Ok(Arc::new(LocalFsStore::new(root)))
+        }
+        #[cfg(feature = "s3")]
+        "s3" => Ok(Arc::new(S3Store::from_env()?)),
+        #[cfg(not(feature = "s3"))]
+        "s3" => Err(ObjectStoreError::Backend(
+            "the s3 backend requires building with the `s3` feature".to_string(),
+        )),
+        other => Err(ObjectStoreError::Backend(format!(
+            "unknown OBJECT_STORE_BACKEND '{}'",
+            other
+        ))),
+    }
+}
+
+/// How long signed download URLs stay valid: `DOWNLOAD_URL_TTL_SECONDS`,
+/// capped at `MAX_DOWNLOAD_URL_TTL`. Invalid or zero values fall back to the default.
+pub fn download_url_ttl() -> Duration {
+    std::env::var("DOWNLOAD_URL_TTL_SECONDS")
+        .ok()
+        .and_then(|raw| raw.trim().parse::<u64>().ok())
+        .filter(|secs| *secs > 0)
+        .map(|secs| Duration::from_secs(secs).min(MAX_DOWNLOAD_URL_TTL))
+        .unwrap_or(DEFAULT_DOWNLOAD_URL_TTL)
+}
+
+/// How a download should be served
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Download {
+    /// Redirect the client to a signed URL
+    Redirect(String),
+    /// Proxy the bytes through the API
+    Bytes(Vec<u8>),
+}
+
+impl Download {
+    /// A temporary redirect, or the bytes with `content_type`.
+    pub fn into_response(self, content_type: &'static str) -> axum::response::Response {
+        use axum::response::{IntoResponse, Redirect};
+        match self {
+            Download::Redirect(url) => Redirect::temporary(&url).into_response(),
+            Download::Bytes(bytes) => ([(axum::http::header::CONTENT_TYPE, content_type)], bytes).into_response(),
+        }
+    }
+}
+
+/// Prefer a signed URL valid for `ttl`; fall back to reading the object.
+/// `None` when the object is missing on a backend that cannot sign.
+pub async fn resolve_download(
+    store: &dyn ObjectStore,
+    key: &str,
+    ttl: Duration,
+) -> Result<Option<Download>, ObjectStoreError> {
+    if let Some(url) = store.signed_url(key, ttl).await? {
+        return Ok(Some(Download::Redirect(url)));
+    }
+    Ok(store.get(key).await?.map(Download::Bytes))
+}
+
+/// Key for a WASM binary, content-addressed by its hash
+pub fn wasm_key(wasm_hash: &str) -> String {
+    format!("wasm/{}.wasm", wasm_hash)
+}
+
+/// Key for a contract backup bundle
+pub fn backup_key(contract_id: uuid::Uuid, backup_date: chrono::NaiveDate) -> String {
+    format!("backups/{}/{}.json", contract_id, backup_date)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_store() -> (LocalFsStore, PathBuf) {
+        let root = std::env::temp_dir().join(format!("object-store-test-{}", uuid::Uuid::new_v4()));
+        (LocalFsStore::new(&root), root)
+    }
+
+    #[tokio::test]
+    async fn filesystem_round_trip() {
+        let (store, root) = temp_store();
+        let key = wasm_key("abc123");
+
+        store.put(&key, b"\0asm first".to_vec()).await.unwrap();
+        store.put(&key, b"\0asm second".to_vec()).await.unwrap();
+        assert_eq!(store.get(&key).await.unwrap(), Some(b"\0asm second".to_vec()));
+
+        assert!(store.delete(&key).await.unwrap());
+        assert_eq!(store.get(&key).await.unwrap(), None);
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[tokio::test]
+    async fn missing_key_is_none_not_error() {
+        let (store, _root) = temp_store();
+
+        assert_eq!(store.get("backups/none.json").await.unwrap(), None);
+        assert!(!store.delete("backups/none.json").await.unwrap());
+    }
+
+    /// Signs URLs like `memory://<key>?expires=<secs>` without storing anything.
+    struct SigningStore;
+
+    #[async_trait]
+    impl ObjectStore for SigningStore {
+        async fn put(&self, _key: &str, _bytes: Vec<u8>) -> Result<(), ObjectStoreError> {
+            Ok(())
+        }
+
+        async fn get(&self, _key: &str) -> Result<Option<Vec<u8>>, ObjectStoreError> {
+            panic!("signed downloads must not read the object")
+        }
+
+        async fn delete(&self, _key: &str) -> Result<bool, ObjectStoreError> {
+            Ok(false)
+        }
+
+        async fn signed_url(&self, key: &str, expires_in: Duration) -> Result<Option<String>, ObjectStoreError> {
+            Ok(Some(format!("memory://{}?expires={}", key, expires_in.as_secs())))
+        }
+    }
+
+    #[tokio::test]
+    async fn signing_backends_redirect_with_the_configured_expiry() {
+        let download = resolve_download(&SigningStore, "wasm/abc.wasm", Duration::from_secs(90))
+            .await
+            .unwrap();
+        assert_eq!(
+            download,
+            Some(Download::Redirect("memory://wasm/abc.wasm?expires=90".into()))
+        );
+    }
+
+    #[tokio::test]
+    async fn filesystem_downloads_are_proxied() {
+        let (store, root) = temp_store();
+        let key = wasm_key("abc123");
+        assert_eq!(store.signed_url(&key, Duration::from_secs(60)).await.unwrap(), None);
+        assert_eq!(resolve_download(&store, &key, Duration::from_secs(60)).await.unwrap(), None);
+
+        store.put(&key, b"\0asm".to_vec()).await.unwrap();
+        assert_eq!(
+            resolve_download(&store, &key, Duration::from_secs(60)).await.unwrap(),
+            Some(Download::Bytes(b"\0asm".to_vec()))
+        );
+
+        let _ = std::fs::remove_dir_all(root);
+    }
+
+    #[test]
+    fn download_ttl_is_read_from_env_and_capped() {
+        std::env::set_var("DOWNLOAD_URL_TTL_SECONDS", "0");
+        assert_eq!(download_url_ttl(), DEFAULT_DOWNLOAD_URL_TTL);
+        std::env::set_var("DOWNLOAD_URL_TTL_SECONDS", "45");
+        assert_eq!(download_url_ttl(), Duration::from_secs(45));
+        std::env::set_var("DOWNLOAD_URL_TTL_SECONDS", "99999999");
+        assert_eq!(download_url_ttl(), MAX_DOWNLOAD_URL_TTL);
+        std::env::remove_var("DOWNLOAD_URL_TTL_SECONDS");
+    }
+
+    #[tokio::test]
+    async fn keys_cannot_escape_the_root() {
+        let (store, _root) = temp_store();
+
+        for key in ["", "/etc/passwd", "../outside", "wasm/../../x", "a\\b"] {
+            assert!(
+                matches!(store.get(key).await, Err(ObjectStoreError::InvalidKey(_))),
+                "{key:?} should be rejected"
+            );
+        }
+    }
+}
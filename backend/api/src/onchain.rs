@@ -0,0 +1,147 @@
+// api/src/onchain.rs
+// On-chain existence checks for published contracts.
+//
+// When `ONCHAIN_VERIFY_PUBLISH` is enabled, publishing asks the network's RPC
+// endpoint (through the indexer's client) whether the contract instance
+// actually exists, so phantom contract IDs never enter the registry. Offline
+// and test deployments leave it disabled and the check is skipped.
+
+use indexer::{ContractLookup, NetworkRpcClients, OnChainContract};
+use shared::Network;
+use std::sync::Arc;
+
+use crate::error::{ApiError, ApiResult};
+
+pub type SharedContractLookup = Arc<dyn ContractLookup>;
+
+/// Stored when a contract is published without confirming its WASM on-chain
+pub const PLACEHOLDER_WASM_HASH: &str = "placeholder_hash";
+
+/// Whether a boolean env toggle is switched on
+pub fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes" | "on"))
+        .unwrap_or(false)
+}
+
+/// Build the lookup used during publish on top of the shared RPC clients, or
+/// `None` when the check is disabled.
+pub fn lookup_from_env(rpc: &Arc<NetworkRpcClients>) -> Option<SharedContractLookup> {
+    if env_flag("ONCHAIN_VERIFY_PUBLISH") {
+        tracing::info!("on-chain contract existence check enabled for publish");
+        Some(rpc.clone() as SharedContractLookup)
+    } else {
+        None
+    }
+}
+
+/// Confirm `contract_id` exists on `network`.
+///
+/// Returns the on-chain instance when the check ran, `None` when it is disabled.
+pub async fn ensure_contract_on_chain(
+    lookup: Option<&dyn ContractLookup>,
+    network: &Network,
+    contract_id: &str,
+) -> ApiResult<Option<OnChainContract>> {
+    let Some(lookup) = lookup else {
+        return Ok(None);
+    };
+
+    match lookup.get_contract(network, contract_id).await {
+        Ok(Some(contract)) => Ok(Some(contract)),
+        Ok(None) => Err(ApiError::unprocessable(
+            "ContractNotOnChain",
+            format!("Contract {} does not exist on {}", contract_id, network),
+        )),
+        Err(err) => {
+            tracing::warn!(error = %err, network = %network, "on-chain contract lookup failed");
+            Err(ApiError::new(
+                axum::http::StatusCode::SERVICE_UNAVAILABLE,
+                "OnChainLookupFailed",
+                format!("Could not confirm contract {} on {}", contract_id, network),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use axum::{http::StatusCode, response::IntoResponse};
+    use indexer::RpcError;
+    use std::collections::HashMap;
+
+    const CONTRACT: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+    /// In-memory RPC: knows a fixed set of (network, contract) pairs.
+    struct MockRpc {
+        contracts: HashMap<(String, String), String>,
+        fail: bool,
+    }
+
+    impl MockRpc {
+        fn with(network: Network, contract_id: &str) -> Self {
+            let mut contracts = HashMap::new();
+            contracts.insert((network.to_string(), contract_id.to_string()), "abc123".into());
+            MockRpc { contracts, fail: false }
+        }
+    }
+
+    #[async_trait]
+    impl ContractLookup for MockRpc {
+        async fn get_contract(
+            &self,
+            network: &Network,
+            contract_id: &str,
+        ) -> Result<Option<OnChainContract>, RpcError> {
+            if self.fail {
+                return Err(RpcError::Timeout);
+            }
+            Ok(self
+                .contracts
+                .get(&(network.to_string(), contract_id.to_string()))
+                .map(|hash| OnChainContract {
+                    contract_id: contract_id.to_string(),
+                    wasm_hash: hash.clone(),
+                }))
+        }
+    }
+
+    #[tokio::test]
+    async fn existing_contract_passes() {
+        let rpc = MockRpc::with(Network::Testnet, CONTRACT);
+        let found = ensure_contract_on_chain(Some(&rpc), &Network::Testnet, CONTRACT)
+            .await
+            .unwrap();
+        assert_eq!(found.unwrap().wasm_hash, "abc123");
+    }
+
+    #[tokio::test]
+    async fn missing_contract_is_rejected_with_422() {
+        // Deployed on testnet only; publishing it as mainnet is a phantom.
+        let rpc = MockRpc::with(Network::Testnet, CONTRACT);
+        let err = ensure_contract_on_chain(Some(&rpc), &Network::Mainnet, CONTRACT)
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn rpc_failure_is_503() {
+        let mut rpc = MockRpc::with(Network::Testnet, CONTRACT);
+        rpc.fail = true;
+        let err = ensure_contract_on_chain(Some(&rpc), &Network::Testnet, CONTRACT)
+            .await
+            .unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+
+    #[tokio::test]
+    async fn disabled_check_is_skipped() {
+        let found = ensure_contract_on_chain(None, &Network::Mainnet, CONTRACT)
+            .await
+            .unwrap();
+        assert!(found.is_none());
+    }
+}
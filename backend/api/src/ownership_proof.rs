@@ -0,0 +1,133 @@
+// api/src/ownership_proof.rs
+// Proof that a publisher controls the address it publishes under.
+//
+// With `PUBLISH_REQUIRE_OWNERSHIP_PROOF` enabled, a publish must answer an
+// auth challenge for `publisher_address`:
+//   1. GET /api/auth/challenge?address=G...  → nonce
+//   2. sign the nonce bytes with the account's ed25519 key
+//   3. send the hex signature as `ownership_signature` in the publish body
+// The public key comes from the G... address itself, so a signature made with
+// any other key is rejected. Each challenge can be answered once. When the
+// toggle is off the signature is ignored.
+
+use axum::http::StatusCode;
+use std::sync::RwLock;
+
+use crate::{
+    auth::AuthManager,
+    error::{ApiError, ApiResult},
+    stellar,
+};
+
+/// Whether publishes must carry a signed challenge
+pub fn required_from_env() -> bool {
+    crate::onchain::env_flag("PUBLISH_REQUIRE_OWNERSHIP_PROOF")
+}
+
+fn rejected(code: &str, message: String) -> ApiError {
+    ApiError::new(StatusCode::UNAUTHORIZED, code, message)
+}
+
+/// Check `signature` answers the pending challenge for `address`.
+pub fn verify_ownership(auth: &RwLock<AuthManager>, address: &str, signature: Option<&str>) -> ApiResult<()> {
+    let signature = signature.map(str::trim).filter(|s| !s.is_empty()).ok_or_else(|| {
+        rejected(
+            "OwnershipProofRequired",
+            format!(
+                "Publishing as {} requires `ownership_signature`: sign the nonce from GET /api/auth/challenge?address={}",
+                address, address
+            ),
+        )
+    })?;
+    let public_key = stellar::decode_account_id(address)
+        .map_err(|err| ApiError::bad_request("InvalidPublisherAddress", format!("publisher_address {}", err)))?;
+
+    auth.write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .verify_challenge(address, &public_key, signature)
+        .map_err(|reason| {
+            tracing::warn!(publisher = %address, reason, "publish ownership proof rejected");
+            rejected(
+                "OwnershipProofInvalid",
+                format!("Could not verify control of {}: {}", address, reason),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn setup(seed: u8) -> (RwLock<AuthManager>, SigningKey, String) {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let address = stellar::encode_account_id(key.verifying_key().as_bytes());
+        (RwLock::new(AuthManager::new("test-secret".into())), key, address)
+    }
+
+    fn sign(key: &SigningKey, nonce: &str) -> String {
+        hex::encode(key.sign(nonce.as_bytes()).to_bytes())
+    }
+
+    fn status(result: ApiResult<()>) -> StatusCode {
+        match result {
+            Ok(()) => StatusCode::OK,
+            Err(err) => err.into_response().status(),
+        }
+    }
+
+    #[test]
+    fn valid_signature_is_accepted_once() {
+        let (auth, key, address) = setup(7);
+        let nonce = auth.write().unwrap().create_challenge(&address);
+        let signature = sign(&key, &nonce);
+
+        assert_eq!(status(verify_ownership(&auth, &address, Some(&signature))), StatusCode::OK);
+        // The challenge is spent; replaying the same signature fails
+        assert_eq!(
+            status(verify_ownership(&auth, &address, Some(&signature))),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn forged_signature_is_rejected() {
+        let (auth, _, address) = setup(7);
+        let (_, impostor, _) = setup(9);
+        let nonce = auth.write().unwrap().create_challenge(&address);
+
+        let err = verify_ownership(&auth, &address, Some(&sign(&impostor, &nonce))).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn signature_over_another_message_is_rejected() {
+        let (auth, key, address) = setup(7);
+        auth.write().unwrap().create_challenge(&address);
+
+        let result = verify_ownership(&auth, &address, Some(&sign(&key, "some other nonce")));
+        assert_eq!(status(result), StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn missing_signature_or_challenge_is_rejected() {
+        let (auth, key, address) = setup(7);
+        assert_eq!(status(verify_ownership(&auth, &address, None)), StatusCode::UNAUTHORIZED);
+        // No challenge was issued
+        assert_eq!(
+            status(verify_ownership(&auth, &address, Some(&sign(&key, "nonce")))),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[test]
+    fn contract_ids_cannot_prove_ownership() {
+        let (auth, _, _) = setup(7);
+        let contract = stellar::encode_contract_id(&[7; 32]);
+        assert_eq!(
+            status(verify_ownership(&auth, &contract, Some("00"))),
+            StatusCode::BAD_REQUEST
+        );
+    }
+}
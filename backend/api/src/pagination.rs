@@ -0,0 +1,108 @@
+// api/src/pagination.rs
+// Page size limits for list endpoints.
+//
+// `DEFAULT_PAGE_SIZE` is used when a request gives no `limit`;
+// `MAX_PAGE_SIZE` caps whatever a request asks for. Both are read once at
+// startup and carried in `AppState`.
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaginationConfig {
+    pub default_page_size: i64,
+    pub max_page_size: i64,
+}
+
+impl Default for PaginationConfig {
+    fn default() -> Self {
+        Self {
+            default_page_size: DEFAULT_PAGE_SIZE,
+            max_page_size: MAX_PAGE_SIZE,
+        }
+    }
+}
+
+fn parse_size(name: &str, raw: Option<&str>) -> Option<i64> {
+    let raw = raw?;
+    match raw.trim().parse::<i64>() {
+        Ok(size) if size > 0 => Some(size),
+        _ => {
+            tracing::warn!("Ignoring invalid {}={:?}; expected a positive integer", name, raw);
+            None
+        }
+    }
+}
+
+impl PaginationConfig {
+    /// Load configuration from environment variables with fallback to defaults
+    pub fn from_env() -> Self {
+        let config = Self::from_values(
+            std::env::var("DEFAULT_PAGE_SIZE").ok().as_deref(),
+            std::env::var("MAX_PAGE_SIZE").ok().as_deref(),
+        );
+        tracing::info!(
+            "Pagination config loaded: default_page_size={}, max_page_size={}",
+            config.default_page_size,
+            config.max_page_size
+        );
+        config
+    }
+
+    fn from_values(default_page_size: Option<&str>, max_page_size: Option<&str>) -> Self {
+        let max_page_size = parse_size("MAX_PAGE_SIZE", max_page_size).unwrap_or(MAX_PAGE_SIZE);
+        let mut default_page_size =
+            parse_size("DEFAULT_PAGE_SIZE", default_page_size).unwrap_or(DEFAULT_PAGE_SIZE);
+        if default_page_size > max_page_size {
+            tracing::warn!(
+                "DEFAULT_PAGE_SIZE={} exceeds MAX_PAGE_SIZE={}; using the maximum",
+                default_page_size,
+                max_page_size
+            );
+            default_page_size = max_page_size;
+        }
+        Self {
+            default_page_size,
+            max_page_size,
+        }
+    }
+
+    /// Effective page size for a request's `limit`.
+    pub fn limit(&self, requested: Option<i64>) -> i64 {
+        requested
+            .unwrap_or(self.default_page_size)
+            .clamp(1, self.max_page_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_above_the_configured_max_are_clamped() {
+        let config = PaginationConfig::from_values(Some("10"), Some("50"));
+        assert_eq!(config.limit(None), 10);
+        assert_eq!(config.limit(Some(25)), 25);
+        assert_eq!(config.limit(Some(51)), 50);
+        assert_eq!(config.limit(Some(10_000)), 50);
+        assert_eq!(config.limit(Some(0)), 1);
+        assert_eq!(config.limit(Some(-5)), 1);
+    }
+
+    #[test]
+    fn invalid_or_missing_values_fall_back_to_defaults() {
+        assert_eq!(PaginationConfig::from_values(None, None), PaginationConfig::default());
+        assert_eq!(
+            PaginationConfig::from_values(Some("abc"), Some("0")),
+            PaginationConfig::default()
+        );
+    }
+
+    #[test]
+    fn default_is_capped_by_max() {
+        let config = PaginationConfig::from_values(Some("500"), Some("200"));
+        assert_eq!(config.default_page_size, 200);
+        assert_eq!(config.limit(None), 200);
+    }
+}
@@ -1,25 +1,230 @@
 // api/src/popularity.rs
 // Popularity scoring engine with hourly batch recalculation
+//
+//   GET /api/admin/popularity-weights — current weights
+//   PUT /api/admin/popularity-weights — replace them (validated, persisted)
+//
+// Weights live in the single-row `popularity_weights` table. The hourly task
+// reads them at the start of every run, and an update triggers an immediate
+// recalculation so leaderboards reflect the change without waiting an hour.
 
-use sqlx::PgPool;
+use axum::{extract::State, Extension, Json};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
 use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    background_jobs,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// How often popularity scores are recalculated
+const POPULARITY_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Timeframe used by the scheduled recalculation
+const DEFAULT_TIMEFRAME: &str = "7d";
+
+/// Upper bound for any single weight
+const MAX_WEIGHT: f64 = 1.0;
+
+/// Multipliers applied to each popularity signal.
+///
+/// The defaults reproduce the original fixed formula; `uniqueness` is off
+/// unless an operator opts in.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, FromRow)]
+pub struct PopularityWeights {
+    /// Time-decayed deployments within the timeframe
+    pub deployments: f64,
+    /// Time-decayed interactions within the timeframe
+    pub interactions: f64,
+    /// Distinct interacting addresses within the timeframe
+    pub uniqueness: f64,
+    /// Age score: 100 for a brand new contract, decaying over a year
+    pub recency: f64,
+    /// Applied to a flat 100 points for verified contracts
+    pub verification: f64,
+}
+
+impl Default for PopularityWeights {
+    fn default() -> Self {
+        Self {
+            deployments: 0.4,
+            interactions: 0.3,
+            uniqueness: 0.0,
+            recency: 0.1,
+            verification: 0.2,
+        }
+    }
+}
+
+impl PopularityWeights {
+    fn fields(&self) -> [(&'static str, f64); 5] {
+        [
+            ("deployments", self.deployments),
+            ("interactions", self.interactions),
+            ("uniqueness", self.uniqueness),
+            ("recency", self.recency),
+            ("verification", self.verification),
+        ]
+    }
+
+    /// Every weight within 0–1, and at least one of them non-zero.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, weight) in self.fields() {
+            if !weight.is_finite() || !(0.0..=MAX_WEIGHT).contains(&weight) {
+                return Err(format!(
+                    "weight '{}' must be between 0 and {}, got {}",
+                    name, MAX_WEIGHT, weight
+                ));
+            }
+        }
+        if self.fields().iter().all(|(_, w)| *w == 0.0) {
+            return Err("at least one weight must be greater than 0".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Per-contract inputs to the score, as gathered by [`recalculate_scores`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, FromRow)]
+pub struct PopularitySignals {
+    pub decayed_deployments: f64,
+    pub decayed_interactions: f64,
+    pub unique_callers: f64,
+    pub is_verified: bool,
+    pub age_days: f64,
+}
+
+/// Score for one contract.
+pub fn score(signals: &PopularitySignals, weights: &PopularityWeights) -> f64 {
+    let verification = if signals.is_verified { 100.0 } else { 0.0 };
+    let age_score = 100.0 * (-signals.age_days / 365.0).exp();
+
+    signals.decayed_deployments * weights.deployments
+        + signals.decayed_interactions * weights.interactions
+        + signals.unique_callers * weights.uniqueness
+        + verification * weights.verification
+        + age_score * weights.recency
+}
+
+#[derive(Debug, FromRow)]
+struct SignalRow {
+    id: Uuid,
+    #[sqlx(flatten)]
+    signals: PopularitySignals,
+}
+
+/// Stored weights, or `None` when no override has been saved.
+pub async fn load_weights(pool: &PgPool) -> Result<Option<PopularityWeights>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT deployments, interactions, uniqueness, recency, verification
+         FROM popularity_weights WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+async fn save_weights(pool: &PgPool, weights: &PopularityWeights, updated_by: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO popularity_weights
+             (id, deployments, interactions, uniqueness, recency, verification, updated_by, updated_at)
+         VALUES (1, $1, $2, $3, $4, $5, $6, NOW())
+         ON CONFLICT (id) DO UPDATE SET
+             deployments = EXCLUDED.deployments,
+             interactions = EXCLUDED.interactions,
+             uniqueness = EXCLUDED.uniqueness,
+             recency = EXCLUDED.recency,
+             verification = EXCLUDED.verification,
+             updated_by = EXCLUDED.updated_by,
+             updated_at = NOW()",
+    )
+    .bind(weights.deployments)
+    .bind(weights.interactions)
+    .bind(weights.uniqueness)
+    .bind(weights.recency)
+    .bind(weights.verification)
+    .bind(updated_by)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Weights for a scheduled run: the stored override, else the defaults.
+async fn current_weights(pool: &PgPool) -> PopularityWeights {
+    match load_weights(pool).await {
+        Ok(Some(weights)) => weights,
+        Ok(None) => PopularityWeights::default(),
+        Err(e) => {
+            tracing::warn!("popularity: failed to load weights, using defaults: {}", e);
+            PopularityWeights::default()
+        }
+    }
+}
 
 /// Spawn a background task that recalculates popularity scores every hour.
+///
+/// Each run is recorded in `background_jobs` under `popularity`.
 pub fn spawn_popularity_task(pool: PgPool) {
     tokio::spawn(async move {
-        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        let mut interval = tokio::time::interval(POPULARITY_INTERVAL);
 
         loop {
             interval.tick().await;
             tracing::info!("popularity: starting hourly score recalculation");
 
-            if let Err(err) = recalculate_scores(&pool, "7d").await {
-                tracing::error!(error = ?err, "popularity: recalculation failed");
-            }
+            let weights = current_weights(&pool).await;
+            background_jobs::run_tracked(&pool, background_jobs::JOB_POPULARITY, POPULARITY_INTERVAL, || {
+                recalculate_scores(&pool, DEFAULT_TIMEFRAME, &weights)
+            })
+            .await;
         }
     });
 }
 
+/// GET /api/admin/popularity-weights
+pub async fn get_popularity_weights(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<PopularityWeights>> {
+    auth.require_admin()?;
+    let weights = load_weights(&state.db)
+        .await
+        .map_err(|err| crate::db_timeout::map_db_error("load popularity weights", err))?
+        .unwrap_or_default();
+    Ok(Json(weights))
+}
+
+/// PUT /api/admin/popularity-weights
+pub async fn update_popularity_weights(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(weights): Json<PopularityWeights>,
+) -> ApiResult<Json<PopularityWeights>> {
+    auth.require_admin()?;
+    weights
+        .validate()
+        .map_err(|msg| ApiError::bad_request("InvalidPopularityWeights", msg))?;
+
+    save_weights(&state.db, &weights, &auth.publisher_address)
+        .await
+        .map_err(|err| crate::db_timeout::map_db_error("save popularity weights", err))?;
+    tracing::info!(?weights, updated_by = %auth.publisher_address, "popularity weights updated");
+
+    // Recompute now rather than at the next scheduled run
+    let pool = state.db.clone();
+    tokio::spawn(async move {
+        background_jobs::run_tracked(&pool, background_jobs::JOB_POPULARITY, POPULARITY_INTERVAL, || {
+            recalculate_scores(&pool, DEFAULT_TIMEFRAME, &weights)
+        })
+        .await;
+    });
+
+    Ok(Json(weights))
+}
+
 /// Parse a timeframe string ("7d", "30d", "90d") into a PostgreSQL interval expression.
 fn timeframe_to_interval(timeframe: &str) -> &'static str {
     match timeframe {
@@ -40,67 +245,174 @@ fn timeframe_to_decay_days(timeframe: &str) -> f64 {
 
 /// Recalculate popularity scores for all contracts.
 ///
-/// Formula:
-///   score = (deployments * 0.4) + (interactions * 0.3) + (verification * 0.2) + (age_score * 0.1)
+/// Formula (see [`score`]):
+///   score = deployments * w.deployments + interactions * w.interactions
+///         + unique_callers * w.uniqueness + verification * w.verification
+///         + age_score * w.recency
 ///
 /// Where:
 ///   - deployments: time-decayed count of deployments within the timeframe
 ///   - interactions: time-decayed count of interactions within the timeframe
+///   - unique_callers: distinct interacting addresses within the timeframe
 ///   - verification: 100 if verified, 0 otherwise
 ///   - age_score: 100 * exp(-days_since_created / 365) — newer = higher
 ///
 /// Time decay: each event is weighted by exp(-days_since_event / decay_period)
-pub async fn recalculate_scores(pool: &PgPool, timeframe: &str) -> Result<(), sqlx::Error> {
+pub async fn recalculate_scores(
+    pool: &PgPool,
+    timeframe: &str,
+    weights: &PopularityWeights,
+) -> Result<u64, sqlx::Error> {
     let interval = timeframe_to_interval(timeframe);
     let decay_days = timeframe_to_decay_days(timeframe);
 
     let query = format!(
         r#"
-        UPDATE contracts c SET
-            popularity_score = COALESCE(scores.score, 0.0),
-            score_updated_at = NOW()
-        FROM (
-            SELECT
-                c2.id,
-                -- Weighted deployments (0.4)
-                COALESCE(dep.decayed_count, 0) * 0.4
-                -- Weighted interactions (0.3)
-                + COALESCE(inter.decayed_count, 0) * 0.3
-                -- Verification bonus (0.2)
-                + CASE WHEN c2.is_verified THEN 100.0 ELSE 0.0 END * 0.2
-                -- Age score (0.1): newer contracts score higher
-                + 100.0 * EXP(-EXTRACT(EPOCH FROM (NOW() - c2.created_at)) / 86400.0 / 365.0) * 0.1
-                AS score
-            FROM contracts c2
-            LEFT JOIN LATERAL (
-                SELECT SUM(
-                    EXP(-EXTRACT(EPOCH FROM (NOW() - cd.deployed_at)) / 86400.0 / {decay_days})
-                ) AS decayed_count
-                FROM contract_deployments cd
-                WHERE cd.contract_id = c2.id
-                  AND cd.deployed_at >= NOW() - INTERVAL '{interval}'
-            ) dep ON true
-            LEFT JOIN LATERAL (
-                SELECT SUM(
-                    EXP(-EXTRACT(EPOCH FROM (NOW() - ci.created_at)) / 86400.0 / {decay_days})
-                ) AS decayed_count
-                FROM contract_interactions ci
-                WHERE ci.contract_id = c2.id
-                  AND ci.created_at >= NOW() - INTERVAL '{interval}'
-            ) inter ON true
-        ) scores
-        WHERE c.id = scores.id
+        SELECT
+            c2.id,
+            COALESCE(dep.decayed_count, 0)::float8 AS decayed_deployments,
+            COALESCE(inter.decayed_count, 0)::float8 AS decayed_interactions,
+            COALESCE(inter.unique_callers, 0)::float8 AS unique_callers,
+            c2.is_verified,
+            (EXTRACT(EPOCH FROM (NOW() - c2.created_at)) / 86400.0)::float8 AS age_days
+        FROM contracts c2
+        LEFT JOIN LATERAL (
+            SELECT SUM(
+                EXP(-EXTRACT(EPOCH FROM (NOW() - cd.deployed_at)) / 86400.0 / {decay_days})
+            ) AS decayed_count
+            FROM contract_deployments cd
+            WHERE cd.contract_id = c2.id
+              AND cd.deployed_at >= NOW() - INTERVAL '{interval}'
+        ) dep ON true
+        LEFT JOIN LATERAL (
+            SELECT SUM(
+                EXP(-EXTRACT(EPOCH FROM (NOW() - ci.created_at)) / 86400.0 / {decay_days})
+            ) AS decayed_count,
+            COUNT(DISTINCT ci.user_address) AS unique_callers
+            FROM contract_interactions ci
+            WHERE ci.contract_id = c2.id
+              AND ci.created_at >= NOW() - INTERVAL '{interval}'
+        ) inter ON true
         "#,
         decay_days = decay_days,
         interval = interval,
     );
 
-    let result = sqlx::query(&query).execute(pool).await?;
+    let rows: Vec<SignalRow> = sqlx::query_as(&query).fetch_all(pool).await?;
+    let (ids, scores): (Vec<Uuid>, Vec<f64>) = rows
+        .iter()
+        .map(|row| (row.id, score(&row.signals, weights)))
+        .unzip();
+
+    let result = sqlx::query(
+        "UPDATE contracts c SET popularity_score = s.score, score_updated_at = NOW()
+         FROM UNNEST($1::uuid[], $2::float8[]) AS s(id, score)
+         WHERE c.id = s.id",
+    )
+    .bind(&ids)
+    .bind(&scores)
+    .execute(pool)
+    .await?;
     tracing::info!(
         rows_updated = result.rows_affected(),
         timeframe = timeframe,
         "popularity: scores recalculated"
     );
 
-    Ok(())
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A verified, month-old contract with some recent activity
+    fn fixture() -> PopularitySignals {
+        PopularitySignals {
+            decayed_deployments: 12.0,
+            decayed_interactions: 80.0,
+            unique_callers: 25.0,
+            is_verified: true,
+            age_days: 30.0,
+        }
+    }
+
+    #[test]
+    fn default_weights_match_the_original_formula() {
+        let s = fixture();
+        let expected = s.decayed_deployments * 0.4
+            + s.decayed_interactions * 0.3
+            + 100.0 * 0.2
+            + 100.0 * (-30.0f64 / 365.0).exp() * 0.1;
+        assert!((score(&s, &PopularityWeights::default()) - expected).abs() < 1e-9);
+        assert!(PopularityWeights::default().validate().is_ok());
+    }
+
+    #[test]
+    fn changing_a_weight_changes_the_score() {
+        let base = PopularityWeights::default();
+        let before = score(&fixture(), &base);
+
+        let uniqueness = PopularityWeights { uniqueness: 0.5, ..base };
+        assert!((score(&fixture(), &uniqueness) - (before + 25.0 * 0.5)).abs() < 1e-9);
+
+        let no_bonus = PopularityWeights { verification: 0.0, ..base };
+        assert!((score(&fixture(), &no_bonus) - (before - 20.0)).abs() < 1e-9);
+
+        // An unverified contract is unaffected by the verification weight
+        let unverified = PopularitySignals { is_verified: false, ..fixture() };
+        assert_eq!(score(&unverified, &base), score(&unverified, &no_bonus));
+    }
+
+    #[test]
+    fn weights_outside_the_range_are_rejected() {
+        let base = PopularityWeights::default();
+        for weights in [
+            PopularityWeights { interactions: -0.1, ..base },
+            PopularityWeights { recency: 1.5, ..base },
+            PopularityWeights { deployments: f64::NAN, ..base },
+        ] {
+            assert!(weights.validate().is_err(), "{:?} should be invalid", weights);
+        }
+
+        let all_zero = PopularityWeights {
+            deployments: 0.0,
+            interactions: 0.0,
+            uniqueness: 0.0,
+            recency: 0.0,
+            verification: 0.0,
+        };
+        assert!(all_zero.validate().unwrap_err().contains("at least one"));
+        assert!(PopularityWeights { verification: 1.0, ..all_zero }.validate().is_ok());
+    }
+
+    async fn stored_score(pool: &PgPool, id: Uuid) -> f64 {
+        sqlx::query_scalar("SELECT popularity_score FROM contracts WHERE id = $1")
+            .bind(id)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn recomputed_score_follows_the_weights() {
+        let pool = crate::fixtures::test_pool().await;
+        let contract = crate::fixtures::fixtures()
+            .contracts
+            .into_iter()
+            .find(|c| c.is_verified)
+            .unwrap();
+
+        let base = PopularityWeights::default();
+        recalculate_scores(&pool, DEFAULT_TIMEFRAME, &base).await.unwrap();
+        let before = stored_score(&pool, contract.id).await;
+
+        let boosted = PopularityWeights { verification: 1.0, ..base };
+        recalculate_scores(&pool, DEFAULT_TIMEFRAME, &boosted).await.unwrap();
+        let after = stored_score(&pool, contract.id).await;
+
+        assert!((after - before - 80.0).abs() < 1e-6, "before {}, after {}", before, after);
+        recalculate_scores(&pool, DEFAULT_TIMEFRAME, &base).await.unwrap();
+    }
 }
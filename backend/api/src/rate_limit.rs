@@ -1,5 +1,5 @@
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     env,
     net::{IpAddr, SocketAddr},
     sync::{Arc, Mutex},
@@ -11,12 +11,13 @@ use axum::{
     extract::{connect_info::ConnectInfo, MatchedPath, State},
     http::{
         header::{AUTHORIZATION, RETRY_AFTER},
-        HeaderName, HeaderValue, Method, Request, StatusCode,
+        HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode,
     },
     middleware::Next,
     response::{IntoResponse, Response},
     Json,
 };
+use prometheus::{opts, IntCounterVec, IntGauge, Registry};
 use serde_json::json;
 
 const DEFAULT_READ_LIMIT_PER_MINUTE: u32 = 100;
@@ -25,6 +26,13 @@ const DEFAULT_AUTH_LIMIT_PER_MINUTE: u32 = 1_000;
 const DEFAULT_HEALTH_LIMIT_PER_MINUTE: u32 = 10_000;
 const DEFAULT_WINDOW_SECONDS: u64 = 60;
 const ENDPOINT_LIMIT_ENV_PREFIX: &str = "RATE_LIMIT_ENDPOINT_";
+/// Comma-separated bearer tokens that are never rate limited
+const BYPASS_TOKENS_ENV: &str = "RATE_LIMIT_BYPASS_TOKENS";
+/// Comma-separated client IPs that are never rate limited
+const BYPASS_IPS_ENV: &str = "RATE_LIMIT_BYPASS_IPS";
+/// Comma-separated reverse proxy IPs whose `X-Forwarded-For` is believed
+/// when matching a caller against the bypass IPs
+const TRUSTED_PROXIES_ENV: &str = "RATE_LIMIT_TRUSTED_PROXIES";
 
 const HEADER_RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
 const HEADER_RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
@@ -34,6 +42,42 @@ const HEADER_RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit
 pub struct RateLimitState {
     config: Arc<RateLimitConfig>,
     buckets: Arc<Mutex<HashMap<BucketKey, BucketState>>>,
+    metrics: RateLimitMetrics,
+}
+
+/// Limiter decisions, labelled by the limit class that applied
+/// (`read`, `write`, `auth`, `health`, `endpoint` or `bypass`)
+#[derive(Clone)]
+pub struct RateLimitMetrics {
+    allowed: IntCounterVec,
+    throttled: IntCounterVec,
+    /// Live (client IP, endpoint) buckets
+    buckets: IntGauge,
+}
+
+impl RateLimitMetrics {
+    fn new() -> Self {
+        Self {
+            allowed: IntCounterVec::new(
+                opts!("rate_limit_requests_allowed_total", "Requests let through by the rate limiter"),
+                &["class"],
+            )
+            .unwrap(),
+            throttled: IntCounterVec::new(
+                opts!("rate_limit_requests_throttled_total", "Requests rejected with 429 by the rate limiter"),
+                &["class"],
+            )
+            .unwrap(),
+            buckets: IntGauge::new("rate_limit_buckets", "Rate limit buckets currently tracked").unwrap(),
+        }
+    }
+
+    fn register(&self, registry: &Registry) -> prometheus::Result<()> {
+        registry.register(Box::new(self.allowed.clone()))?;
+        registry.register(Box::new(self.throttled.clone()))?;
+        registry.register(Box::new(self.buckets.clone()))?;
+        Ok(())
+    }
 }
 
 impl RateLimitState {
@@ -45,16 +89,46 @@ impl RateLimitState {
         Self {
             config: Arc::new(config),
             buckets: Arc::new(Mutex::new(HashMap::new())),
+            metrics: RateLimitMetrics::new(),
+        }
+    }
+
+    /// Expose the limiter's metrics through `registry` (the app state's).
+    pub fn register_metrics(&self, registry: &Registry) -> prometheus::Result<()> {
+        self.metrics.register(registry)
+    }
+
+    /// Whether the caller is on the bypass allowlist, by bearer token or client IP.
+    fn is_bypassed<B>(&self, request: &Request<B>) -> bool {
+        let bypass = &self.config.bypass;
+        if bypass.is_empty() {
+            return false;
+        }
+
+        let token = request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(str::trim);
+        if token.is_some_and(|token| bypass.tokens.contains(token)) {
+            return true;
         }
+
+        bypass
+            .client_addr(request)
+            .is_some_and(|ip| bypass.ips.contains(&ip))
     }
 
     fn check_request<B>(&self, request: &Request<B>) -> RateLimitDecision {
-        let (limit, endpoint_key) = self.select_limit(request);
+        let (limit, class, endpoint_key) = self.select_limit(request);
         let ip = extract_client_ip(request);
         let key = BucketKey { ip, endpoint_key };
         let now = Instant::now();
 
         let mut buckets = self.buckets.lock().expect("rate limiter mutex poisoned");
+        let tracked = buckets.len() + usize::from(!buckets.contains_key(&key));
+        self.metrics.buckets.set(tracked as i64);
 
         let bucket = buckets.entry(key).or_insert_with(|| BucketState {
             window_start: now,
@@ -73,6 +147,7 @@ impl RateLimitState {
         let reset_seconds = ceil_duration_to_seconds(remaining_window).max(1);
 
         if bucket.count >= limit {
+            self.metrics.throttled.with_label_values(&[class]).inc();
             return RateLimitDecision {
                 allowed: false,
                 limit,
@@ -83,6 +158,7 @@ impl RateLimitState {
 
         bucket.count += 1;
         let remaining = limit.saturating_sub(bucket.count);
+        self.metrics.allowed.with_label_values(&[class]).inc();
 
         RateLimitDecision {
             allowed: true,
@@ -92,7 +168,8 @@ impl RateLimitState {
         }
     }
 
-    fn select_limit<B>(&self, request: &Request<B>) -> (u32, String) {
+    /// The limit that applies to `request`, its class (for metrics) and bucket key.
+    fn select_limit<B>(&self, request: &Request<B>) -> (u32, &'static str, String) {
         let method = request.method();
         let matched_path = request
             .extensions()
@@ -102,22 +179,22 @@ impl RateLimitState {
         let endpoint_key = endpoint_key(method, matched_path);
 
         if let Some(limit) = self.config.endpoint_limits.get(&endpoint_key) {
-            return (*limit, endpoint_key);
+            return (*limit, "endpoint", endpoint_key);
         }
 
         if matched_path == "/health" || method == Method::OPTIONS {
-            return (self.config.health_limit, endpoint_key);
+            return (self.config.health_limit, "health", endpoint_key);
         }
 
         if request.headers().contains_key(AUTHORIZATION) {
-            return (self.config.auth_limit, endpoint_key);
+            return (self.config.auth_limit, "auth", endpoint_key);
         }
 
         if is_write_method(method) {
-            return (self.config.write_limit, endpoint_key);
+            return (self.config.write_limit, "write", endpoint_key);
         }
 
-        (self.config.read_limit, endpoint_key)
+        (self.config.read_limit, "read", endpoint_key)
     }
 }
 
@@ -128,6 +205,87 @@ struct RateLimitConfig {
     health_limit: u32,
     window: Duration,
     endpoint_limits: HashMap<String, u32>,
+    bypass: BypassList,
+}
+
+/// Callers exempt from rate limiting (admin tooling, internal jobs)
+#[derive(Default)]
+struct BypassList {
+    tokens: HashSet<String>,
+    ips: HashSet<IpAddr>,
+    trusted_proxies: HashSet<IpAddr>,
+}
+
+impl BypassList {
+    fn from_env() -> Self {
+        Self::parse(
+            &env::var(BYPASS_TOKENS_ENV).unwrap_or_default(),
+            &env::var(BYPASS_IPS_ENV).unwrap_or_default(),
+            &env::var(TRUSTED_PROXIES_ENV).unwrap_or_default(),
+        )
+    }
+
+    fn parse(tokens: &str, ips: &str, trusted_proxies: &str) -> Self {
+        let tokens = tokens
+            .split(',')
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(str::to_string)
+            .collect();
+
+        Self {
+            tokens,
+            ips: parse_ip_list(ips, "bypass IP"),
+            trusted_proxies: parse_ip_list(trusted_proxies, "trusted proxy"),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.tokens.is_empty() && self.ips.is_empty()
+    }
+
+    /// The caller's address as far as the bypass is concerned: the socket
+    /// peer, unless the peer is a trusted proxy, in which case the nearest
+    /// `X-Forwarded-For` hop that is not itself a trusted proxy. Headers from
+    /// an untrusted peer are ignored, since any client can set them.
+    fn client_addr<B>(&self, request: &Request<B>) -> Option<IpAddr> {
+        let peer = request.extensions().get::<ConnectInfo<SocketAddr>>()?.0.ip();
+        if !self.trusted_proxies.contains(&peer) {
+            return Some(peer);
+        }
+
+        let forwarded = request
+            .headers()
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .map(str::trim)
+            .collect::<Vec<_>>();
+        for hop in forwarded.into_iter().rev() {
+            // An unparseable hop ends the chain we can vouch for
+            let ip = parse_ip_addr(hop)?;
+            if !self.trusted_proxies.contains(&ip) {
+                return Some(ip);
+            }
+        }
+        Some(peer)
+    }
+}
+
+/// Comma-separated IPs; blank entries are skipped and invalid ones logged.
+fn parse_ip_list(raw: &str, what: &str) -> HashSet<IpAddr> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|ip| !ip.is_empty())
+        .filter_map(|raw| {
+            let ip = parse_ip_addr(raw);
+            if ip.is_none() {
+                tracing::warn!("Ignoring invalid rate limit {what} `{raw}`");
+            }
+            ip
+        })
+        .collect()
 }
 
 impl RateLimitConfig {
@@ -162,6 +320,8 @@ impl RateLimitConfig {
             endpoint_limits.insert(endpoint_key.to_string(), limit);
         }
 
+        let bypass = BypassList::from_env();
+
         tracing::info!(
             read_limit,
             write_limit,
@@ -169,6 +329,9 @@ impl RateLimitConfig {
             health_limit,
             window_seconds,
             endpoint_overrides = endpoint_limits.len(),
+            bypass_tokens = bypass.tokens.len(),
+            bypass_ips = ?bypass.ips,
+            trusted_proxies = ?bypass.trusted_proxies,
             "Rate limiter configured"
         );
 
@@ -179,6 +342,7 @@ impl RateLimitConfig {
             health_limit,
             window: Duration::from_secs(window_seconds),
             endpoint_limits,
+            bypass,
         }
     }
 
@@ -191,6 +355,7 @@ impl RateLimitConfig {
             health_limit,
             window,
             endpoint_limits: HashMap::new(),
+            bypass: BypassList::default(),
         }
     }
 }
@@ -218,6 +383,11 @@ pub async fn rate_limit_middleware(
     request: Request<Body>,
     next: Next,
 ) -> Response {
+    if rate_limiter.is_bypassed(&request) {
+        rate_limiter.metrics.allowed.with_label_values(&["bypass"]).inc();
+        return next.run(request).await;
+    }
+
     let decision = rate_limiter.check_request(&request);
 
     if !decision.allowed {
@@ -265,8 +435,16 @@ fn attach_rate_limit_headers(response: &mut Response, decision: &RateLimitDecisi
 }
 
 fn extract_client_ip<B>(request: &Request<B>) -> String {
-    if let Some(ip) = request
-        .headers()
+    client_ip(
+        request.headers(),
+        request.extensions().get::<ConnectInfo<SocketAddr>>(),
+    )
+}
+
+/// Resolve the calling client's IP: `X-Forwarded-For`, then `X-Real-IP`,
+/// then the socket address. `"unknown"` when none is available.
+pub(crate) fn client_ip(headers: &HeaderMap, connect_info: Option<&ConnectInfo<SocketAddr>>) -> String {
+    if let Some(ip) = headers
         .get("x-forwarded-for")
         .and_then(|value| value.to_str().ok())
         .and_then(parse_x_forwarded_for)
@@ -274,8 +452,7 @@ fn extract_client_ip<B>(request: &Request<B>) -> String {
         return ip.to_string();
     }
 
-    if let Some(ip) = request
-        .headers()
+    if let Some(ip) = headers
         .get("x-real-ip")
         .and_then(|value| value.to_str().ok())
         .and_then(parse_ip_addr)
@@ -283,7 +460,7 @@ fn extract_client_ip<B>(request: &Request<B>) -> String {
         return ip.to_string();
     }
 
-    if let Some(connect_info) = request.extensions().get::<ConnectInfo<SocketAddr>>() {
+    if let Some(connect_info) = connect_info {
         return connect_info.0.ip().to_string();
     }
 
@@ -401,6 +578,17 @@ mod tests {
             ))
     }
 
+    fn read_request(ip: &str, token: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .uri("/read")
+            .method("GET")
+            .header("x-forwarded-for", ip);
+        if let Some(token) = token {
+            builder = builder.header(AUTHORIZATION, format!("Bearer {}", token));
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
     async fn call(app: &Router<()>, request: Request<Body>) -> Response {
         let mut svc = app.clone();
         svc.call(request).await.unwrap()
@@ -604,4 +792,100 @@ mod tests {
 
         assert_eq!(limited.status(), StatusCode::TOO_MANY_REQUESTS);
     }
+
+    /// `request` as if it arrived on a connection from `peer`
+    fn from_peer(mut request: Request<Body>, peer: &str) -> Request<Body> {
+        let addr = SocketAddr::new(peer.parse().unwrap(), 40_000);
+        request.extensions_mut().insert(ConnectInfo(addr));
+        request
+    }
+
+    #[tokio::test]
+    async fn allowlisted_callers_are_never_throttled() {
+        let mut config = RateLimitConfig::for_tests(1, 1, 10_000, Duration::from_secs(60));
+        config.bypass = BypassList::parse("internal-job-token, ", "10.0.0.5,not-an-ip", "");
+        let limiter = RateLimitState::new(config);
+        let app = Router::new()
+            .route("/read", get(|| async { "read" }))
+            .layer(middleware::from_fn_with_state(limiter, rate_limit_middleware));
+
+        for _ in 0..5 {
+            let by_token = call(&app, read_request("203.0.113.50", Some("internal-job-token"))).await;
+            assert_eq!(by_token.status(), StatusCode::OK);
+            assert!(!by_token.headers().contains_key(HEADER_RATE_LIMIT_LIMIT));
+
+            let by_ip = call(&app, from_peer(read_request("198.51.100.1", None), "10.0.0.5")).await;
+            assert_eq!(by_ip.status(), StatusCode::OK);
+        }
+
+        let other = "203.0.113.51";
+        assert_eq!(call(&app, read_request(other, None)).await.status(), StatusCode::OK);
+        assert_eq!(
+            call(&app, read_request(other, None)).await.status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+    }
+
+    #[tokio::test]
+    async fn throttled_requests_increment_the_throttled_counter() {
+        let limiter = RateLimitState::new(RateLimitConfig::for_tests(1, 1, 10_000, Duration::from_secs(60)));
+        let registry = Registry::new();
+        limiter.register_metrics(&registry).unwrap();
+        let app = Router::new()
+            .route("/read", get(|| async { "read" }))
+            .layer(middleware::from_fn_with_state(limiter.clone(), rate_limit_middleware));
+
+        let ip = "203.0.113.60";
+        assert_eq!(call(&app, read_request(ip, None)).await.status(), StatusCode::OK);
+        assert_eq!(
+            call(&app, read_request(ip, None)).await.status(),
+            StatusCode::TOO_MANY_REQUESTS
+        );
+
+        assert_eq!(limiter.metrics.allowed.with_label_values(&["read"]).get(), 1);
+        assert_eq!(limiter.metrics.throttled.with_label_values(&["read"]).get(), 1);
+        assert_eq!(limiter.metrics.buckets.get(), 1);
+
+        let names: Vec<String> = registry.gather().iter().map(|f| f.get_name().to_string()).collect();
+        assert!(names.contains(&"rate_limit_requests_throttled_total".to_string()));
+    }
+
+    #[tokio::test]
+    async fn forwarded_for_only_counts_behind_a_trusted_proxy() {
+        let app = || {
+            let mut config = RateLimitConfig::for_tests(1, 1, 10_000, Duration::from_secs(60));
+            config.bypass = BypassList::parse("", "10.0.0.5", "10.0.0.1");
+            Router::new()
+                .route("/read", get(|| async { "read" }))
+                .layer(middleware::from_fn_with_state(RateLimitState::new(config), rate_limit_middleware))
+        };
+
+        // A client claiming an allowlisted address is limited like anyone else,
+        // as is a request with no socket address to check
+        let spoofing = app();
+        let spoofed = || from_peer(read_request("10.0.0.5", None), "203.0.113.70");
+        assert_eq!(call(&spoofing, spoofed()).await.status(), StatusCode::OK);
+        assert_eq!(call(&spoofing, spoofed()).await.status(), StatusCode::TOO_MANY_REQUESTS);
+        let detached = read_request("10.0.0.5", None);
+        assert_eq!(call(&spoofing, detached).await.status(), StatusCode::TOO_MANY_REQUESTS);
+
+        // Behind the trusted proxy the nearest forwarded hop is the caller
+        let proxied = app();
+        for _ in 0..3 {
+            let request = from_peer(read_request("203.0.113.9, 10.0.0.5", None), "10.0.0.1");
+            assert_eq!(call(&proxied, request).await.status(), StatusCode::OK);
+        }
+        let prepended = || from_peer(read_request("10.0.0.5, 203.0.113.71", None), "10.0.0.1");
+        assert_eq!(call(&proxied, prepended()).await.status(), StatusCode::OK);
+        assert_eq!(call(&proxied, prepended()).await.status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[test]
+    fn bypass_list_ignores_blank_and_invalid_entries() {
+        let bypass = BypassList::parse(" a , ,b", "192.0.2.1, bogus, 2001:db8::1", "10.0.0.1, nope");
+        assert_eq!(bypass.tokens.len(), 2);
+        assert_eq!(bypass.ips.len(), 2);
+        assert_eq!(bypass.trusted_proxies.len(), 1);
+        assert!(BypassList::parse("", "", "10.0.0.1").is_empty());
+    }
 }
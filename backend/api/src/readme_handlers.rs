@@ -0,0 +1,204 @@
+// api/src/readme_handlers.rs
+// Publisher-supplied documentation for a contract.
+//
+//   PUT /api/contracts/:id/readme               — store markdown (publisher/admin)
+//   GET /api/contracts/:id/readme[?render=html] — markdown, or sanitized HTML
+//
+// Rendering happens on read; only the markdown is stored. Raw HTML in the
+// markdown is passed through ammonia, so scripts, event handlers and
+// `javascript:` links never reach the client.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use pulldown_cmark::{html, Options, Parser};
+use serde::{Deserialize, Serialize};
+use shared::Contract;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, ensure_contract_owner},
+    state::AppState,
+};
+
+/// Largest README accepted, in bytes
+pub const MAX_README_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Deserialize)]
+pub struct PutReadmeRequest {
+    pub markdown: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ReadmeQuery {
+    /// `html` to receive rendered, sanitized HTML
+    pub render: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ContractReadme {
+    pub contract_id: Uuid,
+    pub markdown: String,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReadmeResponse {
+    #[serde(flatten)]
+    pub readme: ContractReadme,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+}
+
+pub fn validate_readme(markdown: &str) -> ApiResult<()> {
+    if markdown.len() > MAX_README_BYTES {
+        return Err(ApiError::new(
+            StatusCode::PAYLOAD_TOO_LARGE,
+            "ReadmeTooLarge",
+            format!("README must be at most {} bytes", MAX_README_BYTES),
+        ));
+    }
+    Ok(())
+}
+
+/// Render markdown to HTML safe to embed in a page.
+pub fn render_readme_html(markdown: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let mut unsafe_html = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, Parser::new_ext(markdown, options));
+
+    ammonia::Builder::default()
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .clean(&unsafe_html)
+        .to_string()
+}
+
+async fn load_contract(state: &AppState, id: Uuid) -> ApiResult<Contract> {
+    sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for readme", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))
+}
+
+/// PUT /api/contracts/:id/readme
+pub async fn put_readme(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<PutReadmeRequest>,
+) -> ApiResult<Json<ContractReadme>> {
+    validate_readme(&req.markdown)?;
+    let contract = load_contract(&state, id).await?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+
+    let readme: ContractReadme = sqlx::query_as(
+        "INSERT INTO contract_readmes (contract_id, markdown, updated_by, updated_at)
+         VALUES ($1, $2, $3, NOW())
+         ON CONFLICT (contract_id) DO UPDATE SET
+             markdown = EXCLUDED.markdown,
+             updated_by = EXCLUDED.updated_by,
+             updated_at = NOW()
+         RETURNING contract_id, markdown, updated_by, updated_at",
+    )
+    .bind(id)
+    .bind(&req.markdown)
+    .bind(&auth.publisher_address)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("store contract readme", err))?;
+
+    Ok(Json(readme))
+}
+
+/// GET /api/contracts/:id/readme
+pub async fn get_readme(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ReadmeQuery>,
+) -> ApiResult<Json<ReadmeResponse>> {
+    let render_html = match query.render.as_deref() {
+        None => false,
+        Some(r) if r.eq_ignore_ascii_case("html") => true,
+        Some(other) => {
+            return Err(ApiError::bad_request(
+                "InvalidRender",
+                format!("unsupported render format '{}'; only 'html' is available", other),
+            ))
+        }
+    };
+
+    let readme: ContractReadme = sqlx::query_as(
+        "SELECT contract_id, markdown, updated_by, updated_at FROM contract_readmes WHERE contract_id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("get contract readme", err))?
+    .ok_or_else(|| ApiError::not_found("ReadmeNotFound", "This contract has no README"))?;
+
+    let html = render_html.then(|| render_readme_html(&readme.markdown));
+    Ok(Json(ReadmeResponse { readme, html }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    #[test]
+    fn markdown_round_trips_through_the_response() {
+        let readme = ContractReadme {
+            contract_id: Uuid::nil(),
+            markdown: "# Token\n\nTransfers `amount`.".into(),
+            updated_by: "GPUBLISHER".into(),
+            updated_at: Utc::now(),
+        };
+        let body = serde_json::to_value(ReadmeResponse {
+            readme: readme.clone(),
+            html: None,
+        })
+        .unwrap();
+
+        assert_eq!(body["markdown"], readme.markdown);
+        assert!(body.get("html").is_none());
+    }
+
+    #[test]
+    fn render_produces_html() {
+        let html = render_readme_html("# Token\n\n| a | b |\n|---|---|\n| 1 | 2 |\n\n[docs](https://example.com)");
+        assert!(html.contains("<h1>Token</h1>"));
+        assert!(html.contains("<table>"));
+        assert!(html.contains(r#"href="https://example.com""#));
+        assert!(html.contains("noopener"));
+    }
+
+    #[test]
+    fn render_strips_scripts_and_event_handlers() {
+        let html = render_readme_html(
+            "<script>alert(1)</script>\n\n<img src=x onerror=\"alert(2)\">\n\n[x](javascript:alert(3))",
+        );
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("onerror"));
+        assert!(!html.contains("javascript:"));
+    }
+
+    #[test]
+    fn oversized_readme_is_rejected() {
+        assert!(validate_readme(&"a".repeat(MAX_README_BYTES)).is_ok());
+        let err = validate_readme(&"a".repeat(MAX_README_BYTES + 1)).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
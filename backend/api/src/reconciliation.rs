@@ -0,0 +1,279 @@
+// api/src/reconciliation.rs
+// Background reconciliation of registry records against on-chain state.
+//
+// Periodically re-reads each published contract from its network's RPC and
+// flags drift: the instance disappeared, or its WASM hash no longer matches
+// the one registered (e.g. the contract was upgraded out-of-band). Lookups
+// are spaced out so a full pass never bursts the RPC endpoint.
+
+use indexer::{ContractLookup, OnChainContract, RpcError};
+use shared::Network;
+use sqlx::PgPool;
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+use crate::background_jobs::JOB_ONCHAIN_RECONCILIATION;
+use crate::metrics;
+use crate::onchain::{SharedContractLookup, PLACEHOLDER_WASM_HASH};
+
+/// How often a reconciliation pass starts
+const RECONCILE_EVERY: Duration = Duration::from_secs(3600);
+/// Contracts checked per pass, least recently reconciled first
+const RECONCILE_BATCH: i64 = 200;
+/// Default spacing between RPC lookups; override with `ONCHAIN_RECONCILE_RPC_INTERVAL_MS`
+const DEFAULT_RPC_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Result of comparing a registry record with the chain
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DriftStatus {
+    InSync,
+    /// The instance no longer exists on the network
+    Missing,
+    /// The deployed WASM differs from the registered hash
+    HashMismatch { on_chain_hash: String },
+}
+
+impl DriftStatus {
+    pub fn is_drift(&self) -> bool {
+        !matches!(self, DriftStatus::InSync)
+    }
+
+    fn on_chain_hash(&self) -> Option<&str> {
+        match self {
+            DriftStatus::HashMismatch { on_chain_hash } => Some(on_chain_hash),
+            _ => None,
+        }
+    }
+}
+
+/// Compare the registered WASM hash with what the chain reports.
+pub fn detect_drift(registered_hash: &str, on_chain: Option<&OnChainContract>) -> DriftStatus {
+    match on_chain {
+        None => DriftStatus::Missing,
+        Some(c) if c.wasm_hash.eq_ignore_ascii_case(registered_hash) => DriftStatus::InSync,
+        Some(c) => DriftStatus::HashMismatch {
+            on_chain_hash: c.wasm_hash.clone(),
+        },
+    }
+}
+
+/// Look one contract up and classify it.
+pub async fn reconcile_contract(
+    lookup: &dyn ContractLookup,
+    network: &Network,
+    contract_id: &str,
+    registered_hash: &str,
+) -> Result<DriftStatus, RpcError> {
+    let on_chain = lookup.get_contract(network, contract_id).await?;
+    Ok(detect_drift(registered_hash, on_chain.as_ref()))
+}
+
+/// Invalid or zero values fall back to the default; a zero interval would panic.
+fn rpc_interval_from_env() -> Duration {
+    std::env::var("ONCHAIN_RECONCILE_RPC_INTERVAL_MS")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|ms| *ms > 0)
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_RPC_INTERVAL)
+}
+
+/// Spawn the hourly reconciliation task.
+pub fn spawn_reconciliation_task(pool: PgPool, lookup: SharedContractLookup) {
+    let rpc_interval = rpc_interval_from_env();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RECONCILE_EVERY);
+
+        loop {
+            interval.tick().await;
+            let timer = Instant::now();
+            let result = run_reconciliation(&pool, lookup.as_ref(), rpc_interval).await;
+            metrics::observe_background_job(
+                JOB_ONCHAIN_RECONCILIATION,
+                timer.elapsed().as_secs_f64(),
+                result.is_ok(),
+            );
+            match result {
+                Ok(drifted) => {
+                    tracing::info!(drifted, "reconciliation: pass complete");
+                }
+                Err(err) => tracing::error!(error = ?err, "reconciliation: pass failed"),
+            }
+        }
+    });
+}
+
+/// Check one batch of contracts; returns how many were found drifted.
+async fn run_reconciliation(
+    pool: &PgPool,
+    lookup: &dyn ContractLookup,
+    rpc_interval: Duration,
+) -> Result<usize, sqlx::Error> {
+    let due: Vec<(Uuid, String, Network, String)> = sqlx::query_as(
+        r#"
+        SELECT id, contract_id, network, wasm_hash
+        FROM contracts
+        WHERE wasm_hash <> $1
+        ORDER BY last_reconciled_at NULLS FIRST
+        LIMIT $2
+        "#,
+    )
+    .bind(PLACEHOLDER_WASM_HASH)
+    .bind(RECONCILE_BATCH)
+    .fetch_all(pool)
+    .await?;
+
+    let mut throttle = tokio::time::interval(rpc_interval);
+    let mut drifted = 0;
+
+    for (id, contract_id, network, wasm_hash) in due {
+        throttle.tick().await;
+
+        let status = match reconcile_contract(lookup, &network, &contract_id, &wasm_hash).await {
+            Ok(status) => status,
+            Err(err) => {
+                // Keep the drift flags, but move the record to the back of the
+                // queue so a failing network cannot starve the others.
+                tracing::warn!(contract_id = %contract_id, error = %err, "reconciliation: lookup failed");
+                sqlx::query("UPDATE contracts SET last_reconciled_at = NOW() WHERE id = $1")
+                    .bind(id)
+                    .execute(pool)
+                    .await?;
+                continue;
+            }
+        };
+
+        if status.is_drift() {
+            drifted += 1;
+            tracing::warn!(contract_id = %contract_id, status = ?status, "reconciliation: drift detected");
+        }
+
+        sqlx::query(
+            "UPDATE contracts
+             SET drift_detected = $2, onchain_wasm_hash = $3, last_reconciled_at = NOW()
+             WHERE id = $1",
+        )
+        .bind(id)
+        .bind(status.is_drift())
+        .bind(status.on_chain_hash())
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(drifted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+
+    const CONTRACT: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+    /// RPC that reports a single contract deployed with `hash`.
+    struct MockRpc {
+        hash: Option<&'static str>,
+    }
+
+    #[async_trait]
+    impl ContractLookup for MockRpc {
+        async fn get_contract(
+            &self,
+            _network: &Network,
+            contract_id: &str,
+        ) -> Result<Option<OnChainContract>, RpcError> {
+            Ok(self.hash.map(|h| OnChainContract {
+                contract_id: contract_id.to_string(),
+                wasm_hash: h.to_string(),
+            }))
+        }
+    }
+
+    /// RPC whose every lookup times out.
+    struct DownRpc;
+
+    #[async_trait]
+    impl ContractLookup for DownRpc {
+        async fn get_contract(&self, _network: &Network, _contract_id: &str) -> Result<Option<OnChainContract>, RpcError> {
+            Err(RpcError::Timeout)
+        }
+    }
+
+    #[test]
+    fn zero_or_invalid_rpc_interval_falls_back_to_default() {
+        for raw in ["0", "-5", "soon"] {
+            std::env::set_var("ONCHAIN_RECONCILE_RPC_INTERVAL_MS", raw);
+            assert_eq!(rpc_interval_from_env(), DEFAULT_RPC_INTERVAL);
+        }
+        std::env::set_var("ONCHAIN_RECONCILE_RPC_INTERVAL_MS", "40");
+        assert_eq!(rpc_interval_from_env(), Duration::from_millis(40));
+        std::env::remove_var("ONCHAIN_RECONCILE_RPC_INTERVAL_MS");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn failed_lookups_move_to_the_back_of_the_queue() {
+        let pool = crate::fixtures::test_pool().await;
+        let id = crate::fixtures::fixtures().contracts[0].id;
+        sqlx::query("UPDATE contracts SET last_reconciled_at = NULL, drift_detected = TRUE WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let drifted = run_reconciliation(&pool, &DownRpc, Duration::from_millis(1)).await.unwrap();
+        assert_eq!(drifted, 0);
+
+        let (reconciled, drift): (Option<chrono::DateTime<chrono::Utc>>, bool) =
+            sqlx::query_as("SELECT last_reconciled_at, drift_detected FROM contracts WHERE id = $1")
+                .bind(id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert!(reconciled.is_some());
+        assert!(drift, "a failed lookup must not clear the drift flag");
+
+        sqlx::query("UPDATE contracts SET drift_detected = FALSE WHERE id = $1")
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn mismatched_hash_is_flagged_as_drift() {
+        let rpc = MockRpc { hash: Some("beef") };
+        let status = reconcile_contract(&rpc, &Network::Mainnet, CONTRACT, "cafe")
+            .await
+            .unwrap();
+
+        assert_eq!(
+            status,
+            DriftStatus::HashMismatch {
+                on_chain_hash: "beef".into()
+            }
+        );
+        assert!(status.is_drift());
+        assert_eq!(status.on_chain_hash(), Some("beef"));
+    }
+
+    #[tokio::test]
+    async fn matching_hash_is_in_sync() {
+        let rpc = MockRpc { hash: Some("CAFE") };
+        let status = reconcile_contract(&rpc, &Network::Mainnet, CONTRACT, "cafe")
+            .await
+            .unwrap();
+        assert_eq!(status, DriftStatus::InSync);
+        assert!(!status.is_drift());
+    }
+
+    #[tokio::test]
+    async fn vanished_contract_is_drift() {
+        let rpc = MockRpc { hash: None };
+        let status = reconcile_contract(&rpc, &Network::Mainnet, CONTRACT, "cafe")
+            .await
+            .unwrap();
+        assert_eq!(status, DriftStatus::Missing);
+        assert!(status.is_drift());
+    }
+}
@@ -0,0 +1,178 @@
+// api/src/registry_stats.rs
+// GET /api/stats/detailed
+//
+// Registry-wide counts beyond GET /api/stats: contracts by network and by
+// maturity, the share of verified contracts, total versions, active
+// deployments and governance proposals by status. Everything comes from a
+// single grouped query and is cached briefly.
+
+use axum::{extract::State, Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::{error::ApiResult, handlers::db_internal_error, state::AppState};
+
+/// How long computed stats are served from cache
+const STATS_CACHE_TTL: Duration = Duration::from_secs(30);
+const STATS_CACHE_NAMESPACE: &str = "stats";
+const STATS_CACHE_KEY: &str = "detailed";
+
+/// One `(dimension, key, count)` row of the grouped query
+pub type StatsRow = (String, String, i64);
+
+const DETAILED_STATS_SQL: &str = r#"
+    SELECT 'network', network::text, COUNT(*) FROM contracts GROUP BY network
+    UNION ALL
+    SELECT 'maturity', maturity::text, COUNT(*) FROM contracts GROUP BY maturity
+    UNION ALL
+    SELECT 'verified', is_verified::text, COUNT(*) FROM contracts GROUP BY is_verified
+    UNION ALL
+    SELECT 'versions', 'total', COUNT(*) FROM contract_versions
+    UNION ALL
+    SELECT 'deployments', 'active', COUNT(*) FROM contract_deployments WHERE status = 'active'
+    UNION ALL
+    SELECT 'proposals', status::text, COUNT(*) FROM governance_proposals GROUP BY status
+"#;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DetailedStats {
+    pub total_contracts: i64,
+    pub contracts_by_network: BTreeMap<String, i64>,
+    pub contracts_by_maturity: BTreeMap<String, i64>,
+    pub verified_contracts: i64,
+    /// Verified contracts as a fraction (0–1) of all contracts
+    pub verification_rate: f64,
+    pub total_versions: i64,
+    pub active_deployments: i64,
+    pub proposals_by_status: BTreeMap<String, i64>,
+    pub generated_at: DateTime<Utc>,
+}
+
+impl DetailedStats {
+    /// Fold the grouped rows into the response. Unknown dimensions are ignored.
+    pub fn from_rows(rows: Vec<StatsRow>, generated_at: DateTime<Utc>) -> Self {
+        let mut stats = Self {
+            generated_at,
+            ..Self::default()
+        };
+        for (dimension, key, count) in rows {
+            match dimension.as_str() {
+                "network" => {
+                    stats.total_contracts += count;
+                    stats.contracts_by_network.insert(key, count);
+                }
+                "maturity" => {
+                    stats.contracts_by_maturity.insert(key, count);
+                }
+                "verified" if key == "true" => stats.verified_contracts = count,
+                "versions" => stats.total_versions = count,
+                "deployments" => stats.active_deployments = count,
+                "proposals" => {
+                    stats.proposals_by_status.insert(key, count);
+                }
+                _ => {}
+            }
+        }
+        if stats.total_contracts > 0 {
+            stats.verification_rate = stats.verified_contracts as f64 / stats.total_contracts as f64;
+        }
+        stats
+    }
+}
+
+/// GET /api/stats/detailed
+pub async fn get_detailed_stats(State(state): State<AppState>) -> ApiResult<Json<DetailedStats>> {
+    if let (Some(cached), true) = state.cache.get(STATS_CACHE_NAMESPACE, STATS_CACHE_KEY).await {
+        if let Ok(stats) = serde_json::from_str::<DetailedStats>(&cached) {
+            return Ok(Json(stats));
+        }
+    }
+
+    let rows: Vec<StatsRow> = sqlx::query_as(DETAILED_STATS_SQL)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get detailed stats", err))?;
+    let stats = DetailedStats::from_rows(rows, Utc::now());
+
+    if let Ok(serialized) = serde_json::to_string(&stats) {
+        state
+            .cache
+            .put(STATS_CACHE_NAMESPACE, STATS_CACHE_KEY, serialized, Some(STATS_CACHE_TTL))
+            .await;
+    }
+
+    Ok(Json(stats))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    fn row(dimension: &str, key: &str, count: i64) -> StatsRow {
+        (dimension.into(), key.into(), count)
+    }
+
+    #[test]
+    fn rows_fold_into_grouped_counts() {
+        let rows = vec![
+            row("network", "mainnet", 3),
+            row("network", "testnet", 5),
+            row("maturity", "alpha", 6),
+            row("maturity", "stable", 2),
+            row("verified", "false", 6),
+            row("verified", "true", 2),
+            row("versions", "total", 21),
+            row("deployments", "active", 4),
+            row("proposals", "active", 1),
+            row("proposals", "executed", 2),
+            row("retired_dimension", "x", 99),
+        ];
+        let stats = DetailedStats::from_rows(rows, Utc::now());
+
+        assert_eq!(stats.total_contracts, 8);
+        assert_eq!(stats.contracts_by_network["testnet"], 5);
+        assert_eq!(stats.contracts_by_maturity.values().sum::<i64>(), 8);
+        assert_eq!(stats.verified_contracts, 2);
+        assert_eq!(stats.verification_rate, 0.25);
+        assert_eq!(stats.total_versions, 21);
+        assert_eq!(stats.active_deployments, 4);
+        assert_eq!(stats.proposals_by_status["executed"], 2);
+    }
+
+    #[test]
+    fn empty_registry_has_zero_verification_rate() {
+        let stats = DetailedStats::from_rows(vec![row("versions", "total", 0)], Utc::now());
+        assert_eq!(stats.total_contracts, 0);
+        assert_eq!(stats.verification_rate, 0.0);
+        assert!(stats.contracts_by_network.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn detailed_stats_count_the_seeded_dataset() {
+        let pool = fixtures::test_pool().await;
+
+        let rows: Vec<StatsRow> = sqlx::query_as(DETAILED_STATS_SQL).fetch_all(&pool).await.unwrap();
+        let stats = DetailedStats::from_rows(rows, Utc::now());
+
+        let data = fixtures::fixtures();
+        for network in ["testnet", "mainnet"] {
+            let seeded = data
+                .contracts
+                .iter()
+                .filter(|c| c.network.to_string() == network)
+                .count() as i64;
+            assert!(stats.contracts_by_network.get(network).copied().unwrap_or(0) >= seeded);
+        }
+        let seeded_verified = data.contracts.iter().filter(|c| c.is_verified).count() as i64;
+        assert!(stats.verified_contracts >= seeded_verified);
+        assert!(stats.total_versions >= data.versions.len() as i64);
+
+        assert_eq!(stats.contracts_by_maturity.values().sum::<i64>(), stats.total_contracts);
+        let exact: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts").fetch_one(&pool).await.unwrap();
+        assert_eq!(stats.total_contracts, exact);
+    }
+}
@@ -1,10 +1,24 @@
-use axum::extract::{Path, State};
+use axum::extract::{Extension, Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
+use serde::Serialize;
+use sqlx::{PgConnection, PgExecutor};
+use uuid::Uuid;
 
+use crate::auth_middleware::AuthContext;
+use crate::error::{ApiError, ApiResult};
+use crate::resource_tracking::{PublisherQuota, PublisherUsage, QuotaExceeded, QuotaResource};
 use crate::state::AppState;
 
+/// Response for GET /api/publishers/:id/usage
+#[derive(Debug, Serialize)]
+pub struct PublisherUsageReport {
+    pub publisher_id: Uuid,
+    pub usage: PublisherUsage,
+    pub quota: PublisherQuota,
+}
+
 pub async fn get_contract_resources(
     State(state): State<AppState>,
     Path(id): Path<String>,
@@ -22,10 +36,185 @@ pub async fn get_contract_resources(
     }
 }
 
+/// Quota for a publisher: cached in the resource manager, else loaded from
+/// `publisher_quotas`, else the defaults.
+pub async fn load_quota(state: &AppState, publisher_id: Uuid) -> ApiResult<PublisherQuota> {
+    let key = publisher_id.to_string();
+    if let Some(quota) = state.resource_mgr.read().unwrap().cached_quota(&key) {
+        return Ok(quota);
+    }
+
+    let row: Option<(i64, i64, i64)> = sqlx::query_as(
+        "SELECT max_contracts, max_storage_bytes, max_versions FROM publisher_quotas WHERE publisher_id = $1",
+    )
+    .bind(publisher_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|_| ApiError::db_error("Failed to load publisher quota"))?;
+
+    let quota = row
+        .map(|(max_contracts, max_storage_bytes, max_versions)| PublisherQuota {
+            max_contracts,
+            max_storage_bytes,
+            max_versions,
+        })
+        .unwrap_or_default();
+    state.resource_mgr.write().unwrap().set_quota(&key, quota.clone());
+    Ok(quota)
+}
+
+/// Current usage, all derived from persisted rows: contracts and versions
+/// counted, storage summed over the sizes of uploaded WASM binaries.
+pub async fn publisher_usage<'e>(db: impl PgExecutor<'e>, publisher_id: Uuid) -> ApiResult<PublisherUsage> {
+    let (contracts, storage_bytes, versions): (i64, i64, i64) = sqlx::query_as(
+        "SELECT \
+           (SELECT COUNT(*) FROM contracts WHERE publisher_id = $1), \
+           (SELECT COALESCE(SUM(wasm_size_bytes), 0)::BIGINT FROM contracts WHERE publisher_id = $1), \
+           (SELECT COUNT(*) FROM contract_versions cv JOIN contracts c ON c.id = cv.contract_id \
+             WHERE c.publisher_id = $1)",
+    )
+    .bind(publisher_id)
+    .fetch_one(db)
+    .await
+    .map_err(|_| ApiError::db_error("Failed to load publisher usage"))?;
+
+    Ok(PublisherUsage {
+        contracts,
+        storage_bytes,
+        versions,
+    })
+}
+
+fn quota_exceeded_error(err: QuotaExceeded) -> ApiError {
+    ApiError::new(
+        StatusCode::TOO_MANY_REQUESTS,
+        "QuotaExceeded",
+        format!(
+            "Publisher quota exceeded for {:?}: {} used of {} (requested {})",
+            err.resource, err.current, err.limit, err.requested
+        ),
+    )
+}
+
+/// Lock the publisher row for the rest of `tx`, so quota checks against it
+/// run one at a time, each against the usage the previous one left behind.
+async fn lock_publisher(tx: &mut PgConnection, publisher_id: Uuid) -> ApiResult<()> {
+    sqlx::query("SELECT id FROM publishers WHERE id = $1 FOR UPDATE")
+        .bind(publisher_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to lock publisher for quota check"))?;
+    Ok(())
+}
+
+/// Reject the operation with 429 if adding `requested` units of `resource`
+/// would exceed `quota`. The publisher row stays locked until `tx` ends, so
+/// insert the new rows in the same transaction.
+pub async fn enforce_publisher_quota(
+    tx: &mut PgConnection,
+    quota: &PublisherQuota,
+    publisher_id: Uuid,
+    resource: QuotaResource,
+    requested: i64,
+) -> ApiResult<()> {
+    lock_publisher(tx, publisher_id).await?;
+    let usage = publisher_usage(&mut *tx, publisher_id).await?;
+    quota
+        .check(&usage, resource, requested)
+        .map_err(quota_exceeded_error)
+}
+
+/// Record `bytes` as the stored WASM size of `contract_id`, rejecting with
+/// 429 if that takes its publisher over `quota`. A re-upload replaces the
+/// contract's previous size rather than adding to it. Like
+/// [`enforce_publisher_quota`], this locks the publisher row for the rest of `tx`.
+pub async fn reserve_wasm_storage(
+    tx: &mut PgConnection,
+    quota: &PublisherQuota,
+    publisher_id: Uuid,
+    contract_id: Uuid,
+    bytes: i64,
+) -> ApiResult<()> {
+    lock_publisher(tx, publisher_id).await?;
+
+    let mut usage = publisher_usage(&mut *tx, publisher_id).await?;
+    let previous: Option<i64> = sqlx::query_scalar("SELECT wasm_size_bytes FROM contracts WHERE id = $1")
+        .bind(contract_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to load stored WASM size"))?;
+    usage.storage_bytes -= previous.unwrap_or(0);
+    quota
+        .check(&usage, QuotaResource::StorageBytes, bytes)
+        .map_err(quota_exceeded_error)?;
+
+    sqlx::query("UPDATE contracts SET wasm_size_bytes = $2 WHERE id = $1")
+        .bind(contract_id)
+        .bind(bytes)
+        .execute(&mut *tx)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to record stored WASM size"))?;
+    Ok(())
+}
+
+pub async fn get_publisher_usage(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<PublisherUsageReport>> {
+    let quota = load_quota(&state, id).await?;
+    let usage = publisher_usage(&state.db, id).await?;
+    Ok(Json(PublisherUsageReport {
+        publisher_id: id,
+        usage,
+        quota,
+    }))
+}
+
+pub async fn set_publisher_quota(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    Json(quota): Json<PublisherQuota>,
+) -> ApiResult<Json<PublisherQuota>> {
+    auth.require_admin()?;
+    if quota.max_contracts < 0 || quota.max_storage_bytes < 0 || quota.max_versions < 0 {
+        return Err(ApiError::bad_request(
+            "InvalidQuota",
+            "Quota limits must be non-negative",
+        ));
+    }
+
+    sqlx::query(
+        "INSERT INTO publisher_quotas (publisher_id, max_contracts, max_storage_bytes, max_versions, updated_by) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (publisher_id) DO UPDATE SET \
+           max_contracts = EXCLUDED.max_contracts, \
+           max_storage_bytes = EXCLUDED.max_storage_bytes, \
+           max_versions = EXCLUDED.max_versions, \
+           updated_by = EXCLUDED.updated_by, \
+           updated_at = NOW()",
+    )
+    .bind(id)
+    .bind(quota.max_contracts)
+    .bind(quota.max_storage_bytes)
+    .bind(quota.max_versions)
+    .bind(&auth.publisher_address)
+    .execute(&state.db)
+    .await
+    .map_err(|_| ApiError::db_error("Failed to save publisher quota"))?;
+
+    state
+        .resource_mgr
+        .write()
+        .unwrap()
+        .set_quota(&id.to_string(), quota.clone());
+
+    Ok(Json(quota))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::auth::AuthManager;
     use crate::cache::{CacheConfig, CacheLayer};
     use crate::metrics;
     use crate::resource_tracking::{ResourceManager, ResourceUsage};
@@ -52,10 +241,49 @@ mod tests {
             cache: Arc::new(CacheLayer::new(CacheConfig::default())),
             registry,
             resource_mgr: Arc::new(RwLock::new(ResourceManager::new())),
-            auth_mgr: Arc::new(RwLock::new(AuthManager::new("test-secret".to_string()))),
+            onchain: None,
+            metadata_source: None,
+            objects: Arc::new(crate::object_store::LocalFsStore::new(std::env::temp_dir())),
+            trust_weights: Default::default(),
+            maturity_criteria: Default::default(),
+            views: Arc::new(crate::views::ViewTracker::from_env()),
+            pagination: Default::default(),
+            rpc: Arc::new(indexer::NetworkRpcClients::from_env()),
+            auth_mgr: Arc::new(RwLock::new(crate::auth::AuthManager::from_env())),
+            default_network: None,
+            notifier: Arc::new(crate::notifications::Notifier::new(create_test_pool())),
         }
     }
 
+    #[test]
+    fn quota_error_is_429() {
+        let err = quota_exceeded_error(QuotaExceeded {
+            resource: QuotaResource::Versions,
+            limit: 3,
+            current: 3,
+            requested: 1,
+        });
+        assert_eq!(err.into_response().status(), StatusCode::TOO_MANY_REQUESTS);
+    }
+
+    #[tokio::test]
+    async fn load_quota_prefers_cached_value() {
+        let state = test_state();
+        let publisher = Uuid::new_v4();
+        let quota = PublisherQuota {
+            max_contracts: 5,
+            max_storage_bytes: 1024,
+            max_versions: 10,
+        };
+        state
+            .resource_mgr
+            .write()
+            .unwrap()
+            .set_quota(&publisher.to_string(), quota.clone());
+
+        assert_eq!(load_quota(&state, publisher).await.unwrap(), quota);
+    }
+
     #[tokio::test]
     async fn returns_forecast_payload_for_alias_route() {
         let state = test_state();
@@ -90,4 +318,104 @@ mod tests {
         assert!(json["forecast"]["cpu_exhaustion_ts_p90"].is_string());
         assert!(json["forecast"]["mem_exhaustion_ts_p90"].is_string());
     }
+
+    #[tokio::test]
+    #[ignore]
+    async fn wasm_storage_is_reserved_against_persisted_usage() {
+        let pool = crate::fixtures::test_pool().await;
+        let data = crate::fixtures::fixtures();
+        let publisher = &data.publishers[0];
+        let owned: Vec<Uuid> = data
+            .contracts
+            .iter()
+            .filter(|c| c.publisher_address == publisher.stellar_address)
+            .map(|c| c.id)
+            .collect();
+        let (first, second) = (owned[0], owned[1]);
+        let reset = || {
+            sqlx::query("UPDATE contracts SET wasm_size_bytes = NULL WHERE publisher_id = $1")
+                .bind(publisher.id)
+                .execute(&pool)
+        };
+        reset().await.unwrap();
+
+        let quota = PublisherQuota {
+            max_storage_bytes: 10,
+            ..PublisherQuota::default()
+        };
+        let reserve = |contract: Uuid, bytes: i64| {
+            let pool = pool.clone();
+            let quota = quota.clone();
+            async move {
+                let mut tx = pool.begin().await.unwrap();
+                let result = reserve_wasm_storage(&mut tx, &quota, publisher.id, contract, bytes).await;
+                tx.commit().await.unwrap();
+                result
+            }
+        };
+
+        reserve(first, 6).await.unwrap();
+        let err = reserve(second, 6).await.unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::TOO_MANY_REQUESTS);
+        // Replacing the first binary frees its old size
+        reserve(first, 8).await.unwrap();
+        reserve(second, 2).await.unwrap();
+        assert_eq!(publisher_usage(&pool, publisher.id).await.unwrap().storage_bytes, 10);
+
+        reset().await.unwrap();
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn concurrent_version_checks_wait_for_the_publisher_lock() {
+        let pool = crate::fixtures::test_pool().await;
+        let data = crate::fixtures::fixtures();
+        let publisher = &data.publishers[1];
+        let contract = data
+            .contracts
+            .iter()
+            .find(|c| c.publisher_address == publisher.stellar_address)
+            .unwrap()
+            .id;
+        let current = publisher_usage(&pool, publisher.id).await.unwrap().versions;
+        let quota = PublisherQuota {
+            max_versions: current + 1,
+            ..PublisherQuota::default()
+        };
+
+        let mut first = pool.begin().await.unwrap();
+        enforce_publisher_quota(&mut first, &quota, publisher.id, QuotaResource::Versions, 1)
+            .await
+            .unwrap();
+        let version = format!("99.0.{}", Uuid::new_v4().as_u128() % 1_000_000);
+        sqlx::query("INSERT INTO contract_versions (contract_id, version, wasm_hash) VALUES ($1, $2, 'quota-lock')")
+            .bind(contract)
+            .bind(&version)
+            .execute(&mut *first)
+            .await
+            .unwrap();
+
+        // The second check blocks on the row lock until the first insert commits,
+        // then counts it
+        let second = tokio::spawn({
+            let (pool, quota, publisher_id) = (pool.clone(), quota.clone(), publisher.id);
+            async move {
+                let mut tx = pool.begin().await.unwrap();
+                enforce_publisher_quota(&mut tx, &quota, publisher_id, QuotaResource::Versions, 1).await
+            }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert!(!second.is_finished());
+        first.commit().await.unwrap();
+
+        let err = second.await.unwrap().unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::TOO_MANY_REQUESTS);
+
+        sqlx::query("DELETE FROM contract_versions WHERE contract_id = $1 AND version = $2")
+            .bind(contract)
+            .bind(&version)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
 }
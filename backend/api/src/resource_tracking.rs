@@ -11,6 +11,9 @@ const GAMMA: f64 = 0.2;
 const SEASON_LEN: usize = 24;
 const Z_P90: f64 = 1.2815515655446004;
 const EPS: f64 = 1e-9;
+const DEFAULT_MAX_CONTRACTS: i64 = 100;
+const DEFAULT_MAX_STORAGE_BYTES: i64 = 1024 * 1024 * 1024;
+const DEFAULT_MAX_VERSIONS: i64 = 1_000;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResourceUsage {
@@ -55,17 +58,95 @@ pub struct ResourceSummary {
     pub forecast: UsageForecast,
 }
 
+/// Per-publisher limits, adjustable by admins
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PublisherQuota {
+    pub max_contracts: i64,
+    pub max_storage_bytes: i64,
+    pub max_versions: i64,
+}
+
+impl Default for PublisherQuota {
+    fn default() -> Self {
+        Self {
+            max_contracts: DEFAULT_MAX_CONTRACTS,
+            max_storage_bytes: DEFAULT_MAX_STORAGE_BYTES,
+            max_versions: DEFAULT_MAX_VERSIONS,
+        }
+    }
+}
+
+/// Current consumption counted against a [`PublisherQuota`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PublisherUsage {
+    pub contracts: i64,
+    pub storage_bytes: i64,
+    pub versions: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuotaResource {
+    Contracts,
+    StorageBytes,
+    Versions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotaExceeded {
+    pub resource: QuotaResource,
+    pub limit: i64,
+    pub current: i64,
+    pub requested: i64,
+}
+
+impl PublisherQuota {
+    /// Check whether adding `requested` units of `resource` stays within the quota.
+    pub fn check(
+        &self,
+        usage: &PublisherUsage,
+        resource: QuotaResource,
+        requested: i64,
+    ) -> Result<(), QuotaExceeded> {
+        let (limit, current) = match resource {
+            QuotaResource::Contracts => (self.max_contracts, usage.contracts),
+            QuotaResource::StorageBytes => (self.max_storage_bytes, usage.storage_bytes),
+            QuotaResource::Versions => (self.max_versions, usage.versions),
+        };
+        if current.saturating_add(requested) > limit {
+            return Err(QuotaExceeded {
+                resource,
+                limit,
+                current,
+                requested,
+            });
+        }
+        Ok(())
+    }
+}
+
 pub struct ResourceManager {
     data: HashMap<String, Vec<ResourceUsage>>,
+    quotas: HashMap<String, PublisherQuota>,
 }
 
 impl ResourceManager {
     pub fn new() -> Self {
         Self {
             data: HashMap::new(),
+            quotas: HashMap::new(),
         }
     }
 
+    pub fn set_quota(&mut self, publisher_id: &str, quota: PublisherQuota) {
+        self.quotas.insert(publisher_id.to_string(), quota);
+    }
+
+    /// Cached quota for a publisher, if one has been loaded or set
+    pub fn cached_quota(&self, publisher_id: &str) -> Option<PublisherQuota> {
+        self.quotas.get(publisher_id).cloned()
+    }
+
     pub fn record_usage(&mut self, contract_id: &str, usage: ResourceUsage) -> Vec<ResourceAlert> {
         let alerts = Self::check_alerts(&usage);
         self.data
@@ -347,6 +428,24 @@ mod tests {
         assert_eq!(alerts[0].metric, "cpu_instructions");
     }
 
+    #[test]
+    fn quota_rejects_when_limit_reached() {
+        let quota = PublisherQuota {
+            max_contracts: 2,
+            ..PublisherQuota::default()
+        };
+        let usage = PublisherUsage {
+            contracts: 2,
+            ..PublisherUsage::default()
+        };
+        let err = quota
+            .check(&usage, QuotaResource::Contracts, 1)
+            .unwrap_err();
+        assert_eq!(err.resource, QuotaResource::Contracts);
+        assert_eq!(err.limit, 2);
+        assert!(quota.check(&usage, QuotaResource::Versions, 1).is_ok());
+    }
+
     #[test]
     fn seasonal_factor_tracks_peaks() {
         let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
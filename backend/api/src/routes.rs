@@ -1,12 +1,13 @@
 use axum::{
-    routing::{get, post},
+    middleware,
+    routing::{delete, get, patch, post, put},
     Router,
 };
 
 use crate::{
-    handlers, metrics_handler, custom_metrics_handlers,
-    handlers, metrics_handler, breaking_changes,
-    handlers, metrics_handler, deprecation_handlers,
+    auth_handlers, auth_middleware, background_jobs, badge_handlers, breaking_changes, bundle_handlers, changelog, contract_aliases, contract_export, contract_flags, contract_state, contract_tokens, custom_metrics_handlers,
+    deployment_health,
+    deprecation_handlers, event_ingest, feed, handlers, heatmap, importer, leaderboard, maintenance_calendar, method_usage, migration_preview, metrics_handler, network_lifecycle, notifications, popularity, readme_handlers, registry_stats, resource_handlers, search_analytics, spam, tag_handlers, trust_handlers, upgrade_check, verification_handlers, verification_queue, wasm_handlers,
     state::AppState,
 };
 
@@ -19,20 +20,59 @@ pub fn contract_routes() -> Router<AppState> {
         .route("/api/contracts", get(handlers::list_contracts))
         .route("/api/contracts", post(handlers::publish_contract))
         .route("/api/contracts/trending", get(handlers::get_trending_contracts))
+        .route("/api/leaderboard", get(leaderboard::get_leaderboard))
+        .route("/api/feed", get(feed::get_feed))
+        .route("/api/maintenance/upcoming", get(maintenance_calendar::get_upcoming_maintenance))
+        .route("/api/contracts/export.csv", get(contract_export::export_contracts_csv))
         .route("/api/contracts/graph", get(handlers::get_contract_graph))
+        .route("/api/contracts/import/preview", get(importer::preview_import))
         .route("/api/contracts/:id", get(handlers::get_contract))
         .route("/api/contracts/:id/abi", get(handlers::get_contract_abi))
+        .route("/api/contracts/:id/badge.svg", get(badge_handlers::get_contract_badge))
+        .route("/api/contracts/:id/export", get(bundle_handlers::export_contract))
+        .route("/api/contracts/:id/wasm", get(wasm_handlers::download_wasm))
+        .route("/api/contracts/:id/readme", get(readme_handlers::get_readme))
+        .route("/api/contracts/:id/flags", get(contract_flags::get_flags))
+        .route("/api/contracts/:id/changelog", get(changelog::get_changelog))
+        .route("/api/c/*alias", get(contract_aliases::get_contract_by_alias))
+        .route("/api/contracts/:id/upgrade-check", get(upgrade_check::check_upgrade))
         .route("/api/contracts/:id/versions", get(handlers::get_contract_versions).post(handlers::create_contract_version))
         .route("/api/contracts/breaking-changes", get(breaking_changes::get_breaking_changes))
         .route("/api/contracts/:id/versions", get(handlers::get_contract_versions))
         .route("/api/contracts/:id/deprecation-info", get(deprecation_handlers::get_deprecation_info))
         .route("/api/contracts/:id/deprecate", post(deprecation_handlers::deprecate_contract))
-        .route("/api/contracts/:id/state/:key", get(handlers::get_contract_state).post(handlers::update_contract_state))
+        .route(
+            "/api/contracts/:id/state/:key",
+            get(contract_state::get_contract_state),
+        )
+        .route(
+            "/api/contracts/:id/state/:key/history",
+            get(contract_state::get_contract_state_history),
+        )
         .route("/api/contracts/:id/analytics", get(handlers::get_contract_analytics))
-        .route("/api/contracts/:id/trust-score", get(handlers::get_trust_score))
+        .route("/api/contracts/:id/heatmap", get(heatmap::get_contract_heatmap))
+        .route("/api/contracts/:id/methods/usage", get(method_usage::get_method_usage))
+        .route(
+            "/api/analytics/searches/top",
+            get(search_analytics::get_top_searches),
+        )
+        .route("/api/contracts/:id/trust-score", get(trust_handlers::get_trust_score))
+        .route("/api/contracts/:id/trust-score/explain", get(trust_handlers::explain_trust))
         .route("/api/contracts/:id/dependencies", get(handlers::get_contract_dependencies))
         .route("/api/contracts/:id/dependents", get(handlers::get_contract_dependents))
-        .route("/api/contracts/verify", post(handlers::verify_contract))
+        .route("/api/contracts/verify", post(verification_handlers::verify_contract))
+        .route(
+            "/api/contracts/verification-status",
+            post(verification_handlers::bulk_verification_status),
+        )
+        .route(
+            "/api/contracts/:id/verification",
+            get(verification_handlers::get_latest_verification),
+        )
+        .route(
+            "/api/contracts/:id/verification/report",
+            get(verification_handlers::get_verification_report),
+        )
         .route(
             "/api/contracts/:id/performance",
             get(handlers::get_contract_performance),
@@ -59,8 +99,68 @@ pub fn contract_routes() -> Router<AppState> {
         //     "/api/contracts/:id/compatibility/export",
         //     get(compatibility_handlers::export_contract_compatibility),
         // )
+        .route(
+            "/api/contracts/:id/deployments",
+            get(deployment_health::get_deployment_summary),
+        )
+        .route("/api/contracts/:id/deployments/diff", get(deployment_health::get_deployment_diff))
         .route("/api/contracts/:id/deployments/status", get(handlers::get_deployment_status))
         .route("/api/deployments/green", post(handlers::deploy_green))
+        .route("/api/deployments/switch", post(deployment_health::switch_deployment))
+        .route(
+            "/api/deployments/health",
+            post(deployment_health::report_health_check),
+        )
+        .route(
+            "/api/deployments/health/batch",
+            post(deployment_health::report_health_check_batch),
+        )
+        .merge(
+            Router::new()
+                .route("/api/contracts/import", post(bundle_handlers::import_contract))
+                .route("/api/events/batch", post(event_ingest::ingest_events_batch))
+                .route(
+                    "/api/contracts/:id",
+                    patch(handlers::patch_contract).delete(handlers::delete_contract),
+                )
+                .route("/api/contracts/:id/wasm", put(wasm_handlers::upload_wasm))
+                .route("/api/contracts/:id/readme", put(readme_handlers::put_readme))
+                .route("/api/contracts/:id/flags", patch(contract_flags::patch_flags))
+                .route("/api/contracts/:id/alias", post(contract_aliases::create_alias))
+                .route(
+                    "/api/contracts/:id/promote-network",
+                    post(handlers::promote_contract_network),
+                )
+                .route(
+                    "/api/contracts/:id/tokens",
+                    get(contract_tokens::list_tokens).post(contract_tokens::mint_token),
+                )
+                .route(
+                    "/api/contracts/:id/tokens/:token_id",
+                    delete(contract_tokens::revoke_token),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
+}
+
+/// State writes accept a contract-scoped token as well as the owner's JWT,
+/// so they sit behind their own middleware, which needs the app state.
+pub fn contract_state_write_routes(state: AppState) -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/contracts/:id/state/:key",
+            post(contract_state::update_contract_state),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state,
+            contract_tokens::require_state_write_access,
+        ))
+}
+
+pub fn auth_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/auth/challenge", get(auth_handlers::get_challenge))
+        .route("/api/auth/verify", post(auth_handlers::verify_challenge))
 }
 
 pub fn publisher_routes() -> Router<AppState> {
@@ -71,18 +171,72 @@ pub fn publisher_routes() -> Router<AppState> {
             "/api/publishers/:id/contracts",
             get(handlers::get_publisher_contracts),
         )
+        .route(
+            "/api/publishers/:id/usage",
+            get(resource_handlers::get_publisher_usage),
+        )
+        .merge(
+            Router::new()
+                .route(
+                    "/api/publishers/:id/quota",
+                    put(resource_handlers::set_publisher_quota),
+                )
+                .route_layer(middleware::from_fn(auth_middleware::auth_middleware)),
+        )
+}
+
+/// The caller's notification inbox, live stream and channel preferences
+pub fn notification_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/notifications", get(notifications::list_inbox))
+        .route("/api/notifications/stream", get(notifications::stream_notifications))
+        .route(
+            "/api/notifications/preferences",
+            get(notifications::get_preferences).put(notifications::update_preferences),
+        )
+        .route_layer(middleware::from_fn(auth_middleware::auth_middleware))
 }
 
 pub fn health_routes() -> Router<AppState> {
     Router::new()
         .route("/health", get(handlers::health_check))
+        .route("/health/detailed", get(handlers::detailed_health_check))
         .route("/api/stats", get(handlers::get_stats))
+        .route("/api/stats/detailed", get(registry_stats::get_detailed_stats))
+        .route("/api/networks", get(network_lifecycle::list_networks))
+}
+
+/// `/api/admin/*`; mounted under its own CORS policy (see `cors`)
+pub fn admin_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/admin/jobs", get(background_jobs::list_jobs))
+        .route("/api/admin/tags/merge", post(tag_handlers::merge_tags_handler))
+        .route(
+            "/api/admin/trust-weights",
+            get(trust_handlers::get_trust_weights).put(trust_handlers::update_trust_weights),
+        )
+        .route(
+            "/api/admin/popularity-weights",
+            get(popularity::get_popularity_weights).put(popularity::update_popularity_weights),
+        )
+        .route(
+            "/api/admin/networks/:network",
+            put(network_lifecycle::update_network_lifecycle),
+        )
+        .route("/api/admin/review-queue", get(spam::list_review_queue))
+        .route("/api/admin/review-queue/:contract_id/resolve", post(spam::resolve_review))
+        .route("/api/admin/contracts/:id/flag", post(spam::flag_contract))
+        .route("/api/admin/reverify", post(verification_queue::reverify_contracts))
+        .route_layer(middleware::from_fn(auth_middleware::auth_middleware))
 }
 
 
 
 pub fn migration_routes() -> Router<AppState> {
-    Router::new()
+    Router::new().route(
+        "/api/contracts/:id/migration-preview",
+        post(migration_preview::preview_migration),
+    )
 }
 
 pub fn canary_routes() -> Router<AppState> { Router::new() }
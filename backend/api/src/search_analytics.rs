@@ -0,0 +1,183 @@
+// api/src/search_analytics.rs
+// What people search the registry for.
+//
+// Every first-page contract search records its normalized query and result
+// count in `search_queries`. Nothing that identifies the searcher (address,
+// IP, user agent) is stored. GET /api/analytics/searches/top reports the most
+// frequent queries and the ones that returned nothing.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// Longest query stored; longer input is cut at a character boundary
+pub const MAX_QUERY_CHARS: usize = 200;
+
+const DEFAULT_DAYS: i64 = 30;
+const MAX_DAYS: i64 = 365;
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+/// A row to be written to `search_queries`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchRecord {
+    pub query: String,
+    pub result_count: i64,
+    pub zero_result: bool,
+}
+
+/// Lowercase, collapse whitespace and truncate. `None` for blank input.
+pub fn normalize_query(raw: &str) -> Option<String> {
+    let normalized = raw
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+    if normalized.is_empty() {
+        return None;
+    }
+    Some(normalized.chars().take(MAX_QUERY_CHARS).collect())
+}
+
+pub fn search_record(raw_query: &str, result_count: i64) -> Option<SearchRecord> {
+    normalize_query(raw_query).map(|query| SearchRecord {
+        query,
+        result_count,
+        zero_result: result_count == 0,
+    })
+}
+
+pub async fn record_search(pool: &PgPool, record: &SearchRecord) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT INTO search_queries (query, result_count, zero_result) VALUES ($1, $2, $3)")
+        .bind(&record.query)
+        .bind(record.result_count)
+        .bind(record.zero_result)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Record a search without holding up the response.
+pub fn spawn_record_search(pool: PgPool, raw_query: &str, result_count: i64) {
+    let Some(record) = search_record(raw_query, result_count) else {
+        return;
+    };
+    tokio::spawn(async move {
+        if let Err(err) = record_search(&pool, &record).await {
+            tracing::warn!(error = ?err, "failed to record search query");
+        }
+    });
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TopSearchesQuery {
+    pub days: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct QueryFrequency {
+    pub query: String,
+    pub searches: i64,
+    pub avg_results: f64,
+    pub last_searched_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TopSearchesResponse {
+    pub days: i64,
+    pub top: Vec<QueryFrequency>,
+    pub zero_result: Vec<QueryFrequency>,
+}
+
+async fn top_queries(
+    pool: &PgPool,
+    days: i64,
+    limit: i64,
+    zero_result_only: bool,
+) -> Result<Vec<QueryFrequency>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        SELECT query,
+               COUNT(*) AS searches,
+               AVG(result_count)::float8 AS avg_results,
+               MAX(searched_at) AS last_searched_at
+        FROM search_queries
+        WHERE searched_at >= NOW() - make_interval(days => $1::int)
+          AND (NOT $2 OR zero_result)
+        GROUP BY query
+        ORDER BY searches DESC, last_searched_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(days)
+    .bind(zero_result_only)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// GET /api/analytics/searches/top?days=&limit=
+pub async fn get_top_searches(
+    State(state): State<AppState>,
+    Query(query): Query<TopSearchesQuery>,
+) -> ApiResult<Json<TopSearchesResponse>> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS).clamp(1, MAX_DAYS);
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let db_err = |err: sqlx::Error| {
+        tracing::error!(error = ?err, "failed to load search analytics");
+        ApiError::internal("An unexpected database error occurred")
+    };
+    let top = top_queries(&state.db, days, limit, false).await.map_err(db_err)?;
+    let zero_result = top_queries(&state.db, days, limit, true).await.map_err(db_err)?;
+
+    Ok(Json(TopSearchesResponse {
+        days,
+        top,
+        zero_result,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_produces_a_normalized_record() {
+        let record = search_record("  Token   Vault ", 7).unwrap();
+        assert_eq!(
+            record,
+            SearchRecord {
+                query: "token vault".into(),
+                result_count: 7,
+                zero_result: false,
+            }
+        );
+    }
+
+    #[test]
+    fn zero_result_queries_are_flagged() {
+        let record = search_record("nonexistent", 0).unwrap();
+        assert!(record.zero_result);
+        assert_eq!(record.result_count, 0);
+    }
+
+    #[test]
+    fn blank_queries_are_not_recorded_and_long_ones_are_truncated() {
+        assert_eq!(search_record("   ", 3), None);
+
+        let long = "é".repeat(MAX_QUERY_CHARS + 50);
+        let record = search_record(&long, 1).unwrap();
+        assert_eq!(record.query.chars().count(), MAX_QUERY_CHARS);
+    }
+}
@@ -0,0 +1,202 @@
+// api/src/search_facets.rs
+// Facet counts for contract search.
+//
+// Alongside a page of results, GET /api/contracts reports how many of the
+// filtered contracts fall under each tag, category, network and maturity
+// level, so a UI can build its filter sidebar without extra requests. The
+// counts come from one grouped aggregate over the same filter clause as the
+// listing, read in the listing's snapshot so they always agree with `total`.
+
+use serde::Serialize;
+use shared::{ContractSearchParams, PaginatedResponse};
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::handlers::push_contract_filters;
+
+/// Most tag values reported; tags are open-ended, the other facets are not
+pub const MAX_TAG_FACETS: usize = 50;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: i64,
+}
+
+/// Per-dimension counts, each sorted by count (descending) then value
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct SearchFacets {
+    pub tags: Vec<FacetCount>,
+    pub categories: Vec<FacetCount>,
+    pub networks: Vec<FacetCount>,
+    pub maturity: Vec<FacetCount>,
+}
+
+/// A listing page with its facet counts
+#[derive(Debug, Serialize)]
+pub struct FacetedPage<T> {
+    #[serde(flatten)]
+    pub page: PaginatedResponse<T>,
+    pub facets: SearchFacets,
+}
+
+/// One `(facet, value, count)` row of the facet query
+pub type FacetRow = (String, String, i64);
+
+/// The grouped aggregate over contracts matching `params`, filtered by
+/// `handlers::push_contract_filters`. Tags are counted once per contract.
+pub fn facet_query(params: &ContractSearchParams) -> QueryBuilder<'static, Postgres> {
+    let mut qb = QueryBuilder::new(
+        "SELECT facet, value, COUNT(*) AS count FROM (
+             SELECT 'tag' AS facet, t.tag AS value
+             FROM contracts c, LATERAL (SELECT DISTINCT unnest(c.tags) AS tag) t
+             WHERE 1=1",
+    );
+    push_contract_filters(&mut qb, params);
+    qb.push(
+        "
+             UNION ALL
+             SELECT 'category', c.category FROM contracts c WHERE c.category IS NOT NULL",
+    );
+    push_contract_filters(&mut qb, params);
+    qb.push(
+        "
+             UNION ALL
+             SELECT 'network', c.network::text FROM contracts c WHERE 1=1",
+    );
+    push_contract_filters(&mut qb, params);
+    qb.push(
+        "
+             UNION ALL
+             SELECT 'maturity', c.maturity::text FROM contracts c WHERE 1=1",
+    );
+    push_contract_filters(&mut qb, params);
+    qb.push(
+        "
+         ) f
+         GROUP BY facet, value",
+    );
+    qb
+}
+
+/// Sort the rows into their dimensions; unknown facet names are ignored.
+pub fn build_facets(rows: Vec<FacetRow>) -> SearchFacets {
+    let mut facets = SearchFacets::default();
+    for (facet, value, count) in rows {
+        let bucket = match facet.as_str() {
+            "tag" => &mut facets.tags,
+            "category" => &mut facets.categories,
+            "network" => &mut facets.networks,
+            "maturity" => &mut facets.maturity,
+            _ => continue,
+        };
+        bucket.push(FacetCount { value, count });
+    }
+
+    for bucket in [
+        &mut facets.tags,
+        &mut facets.categories,
+        &mut facets.networks,
+        &mut facets.maturity,
+    ] {
+        bucket.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    }
+    facets.tags.truncate(MAX_TAG_FACETS);
+    facets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::Network;
+
+    fn row(facet: &str, value: &str, count: i64) -> FacetRow {
+        (facet.to_string(), value.to_string(), count)
+    }
+
+    #[test]
+    fn rows_are_grouped_and_sorted_per_facet() {
+        let facets = build_facets(vec![
+            row("network", "testnet", 2),
+            row("tag", "token", 1),
+            row("network", "mainnet", 5),
+            row("tag", "amm", 1),
+            row("tag", "defi", 3),
+            row("category", "DeFi", 4),
+            row("maturity", "alpha", 7),
+            row("unknown", "x", 9),
+        ]);
+
+        let values = |bucket: &[FacetCount]| bucket.iter().map(|f| f.value.clone()).collect::<Vec<_>>();
+        assert_eq!(values(&facets.networks), vec!["mainnet", "testnet"]);
+        assert_eq!(values(&facets.tags), vec!["defi", "amm", "token"]);
+        assert_eq!(facets.categories, vec![FacetCount { value: "DeFi".into(), count: 4 }]);
+        assert_eq!(facets.maturity[0].count, 7);
+    }
+
+    #[test]
+    fn tag_facets_are_capped() {
+        let rows = (0..MAX_TAG_FACETS + 10).map(|i| row("tag", &format!("t{i:03}"), 1)).collect();
+        assert_eq!(build_facets(rows).tags.len(), MAX_TAG_FACETS);
+    }
+
+    #[test]
+    fn facets_are_served_next_to_the_page() {
+        let body = serde_json::to_value(FacetedPage {
+            page: PaginatedResponse::new(vec![1, 2], 2, 1, 20),
+            facets: build_facets(vec![row("network", "testnet", 2)]),
+        })
+        .unwrap();
+        assert_eq!(body["contracts"], serde_json::json!([1, 2]));
+        assert_eq!(body["facets"]["networks"][0]["count"], 2);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn facet_counts_follow_the_active_filters() {
+        let pool = crate::fixtures::test_pool().await;
+
+        let facets_for = |params: ContractSearchParams| {
+            let pool = pool.clone();
+            async move {
+                let rows: Vec<FacetRow> = facet_query(&params)
+                    .build_query_as()
+                    .fetch_all(&pool)
+                    .await
+                    .unwrap();
+                build_facets(rows)
+            }
+        };
+        let count = |bucket: &[FacetCount], value: &str| {
+            bucket.iter().find(|f| f.value == value).map_or(0, |f| f.count)
+        };
+
+        let fixtures = || ContractSearchParams {
+            query: Some("Fixture Contract".into()),
+            ..Default::default()
+        };
+        let all = facets_for(fixtures()).await;
+        assert!(count(&all.categories, "DeFi") > 0);
+        assert!(count(&all.categories, "NFT") > 0);
+        assert!(count(&all.tags, "fixture") > 0);
+
+        let defi_testnet = facets_for(ContractSearchParams {
+            category: Some("DeFi".into()),
+            network: Some(Network::Testnet),
+            ..fixtures()
+        })
+        .await;
+        assert!(defi_testnet.categories.iter().all(|f| f.value == "DeFi"));
+        assert!(defi_testnet.networks.iter().all(|f| f.value == "testnet"));
+        let matching = count(&defi_testnet.networks, "testnet");
+        assert!(matching > 0 && matching < count(&all.categories, "DeFi") + count(&all.categories, "NFT"));
+        assert_eq!(count(&defi_testnet.categories, "DeFi"), matching);
+        assert_eq!(defi_testnet.maturity.iter().map(|f| f.count).sum::<i64>(), matching);
+
+        let without_fixture_tag = facets_for(ContractSearchParams {
+            exclude_tags: Some("fixture".into()),
+            ..fixtures()
+        })
+        .await;
+        assert_eq!(count(&without_fixture_tag.tags, "fixture"), 0);
+    }
+}
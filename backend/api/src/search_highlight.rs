@@ -0,0 +1,174 @@
+// api/src/search_highlight.rs
+// Match highlighting for contract search results.
+//
+// Full-text matches are marked by Postgres `ts_headline` against the same
+// tsquery the FTS migration defines. Plain substring (ILIKE) matches that
+// the text-search parser does not recognise are marked in Rust.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use shared::Contract;
+
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_STOP: &str = "</mark>";
+
+/// Highlighted copies of the searchable fields; `None` when the field did not match
+#[derive(Debug, Clone, Default, Serialize, PartialEq)]
+pub struct SearchHighlights {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `ts_headline` output for one contract: (name, description)
+pub type Headline = (String, Option<String>);
+
+/// Wrap every ASCII-case-insensitive occurrence of `query` in `text`.
+/// Returns `None` when there is no occurrence.
+pub fn highlight_substring(text: &str, query: &str) -> Option<String> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+
+    let mut out = String::with_capacity(text.len() + 16);
+    let mut matched = false;
+    let mut i = 0;
+    while i < text.len() {
+        let candidate = text.get(i..i + query.len());
+        if candidate.is_some_and(|c| c.eq_ignore_ascii_case(query)) {
+            out.push_str(HIGHLIGHT_START);
+            out.push_str(&text[i..i + query.len()]);
+            out.push_str(HIGHLIGHT_STOP);
+            i += query.len();
+            matched = true;
+        } else {
+            let ch = text[i..].chars().next().expect("index is on a char boundary");
+            out.push(ch);
+            i += ch.len_utf8();
+        }
+    }
+
+    matched.then_some(out)
+}
+
+fn pick(headline: Option<String>, text: &str, query: &str) -> Option<String> {
+    headline
+        .filter(|h| h.contains(HIGHLIGHT_START))
+        .or_else(|| highlight_substring(text, query))
+}
+
+/// Combine the FTS headline (if any) with substring highlighting.
+pub fn build_highlights(contract: &Contract, query: &str, headline: Option<Headline>) -> SearchHighlights {
+    let (name_hl, desc_hl) = match headline {
+        Some((name, description)) => (Some(name), description),
+        None => (None, None),
+    };
+
+    SearchHighlights {
+        name: pick(name_hl, &contract.name, query),
+        description: contract
+            .description
+            .as_deref()
+            .and_then(|d| pick(desc_hl, d, query)),
+    }
+}
+
+/// Run `ts_headline` over the given contracts for `query`.
+pub async fn fetch_headlines(
+    db: &PgPool,
+    query: &str,
+    ids: &[Uuid],
+) -> Result<HashMap<Uuid, Headline>, sqlx::Error> {
+    let options = format!("StartSel={HIGHLIGHT_START}, StopSel={HIGHLIGHT_STOP}, HighlightAll=true");
+
+    let rows: Vec<(Uuid, String, Option<String>)> = sqlx::query_as(
+        "SELECT id,
+                ts_headline('english', name, contracts_build_tsquery($1), $3),
+                CASE WHEN description IS NULL THEN NULL
+                     ELSE ts_headline('english', description, contracts_build_tsquery($1), $3)
+                END
+         FROM contracts
+         WHERE id = ANY($2)",
+    )
+    .bind(query)
+    .bind(ids)
+    .bind(&options)
+    .fetch_all(db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(id, name, description)| (id, (name, description)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use shared::{MaturityLevel, Network};
+
+    fn contract(name: &str, description: Option<&str>) -> Contract {
+        Contract {
+            id: Uuid::new_v4(),
+            contract_id: "C".repeat(56),
+            wasm_hash: "hash".into(),
+            name: name.into(),
+            description: description.map(str::to_string),
+            publisher_id: Uuid::new_v4(),
+            network: Network::Testnet,
+            is_verified: false,
+            category: None,
+            tags: vec![],
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            is_maintenance: false,
+            maturity: MaturityLevel::Alpha,
+            logical_id: None,
+            network_configs: None,
+            origin_contract_id: None,
+            drift_detected: false,
+            view_count: 0,
+            first_seen_at: None,
+            license: None,
+        }
+    }
+
+    #[test]
+    fn substring_matches_are_wrapped() {
+        assert_eq!(
+            highlight_substring("My Token vault for tokens", "token").as_deref(),
+            Some("My <mark>Token</mark> vault for <mark>token</mark>s")
+        );
+        assert_eq!(highlight_substring("Swap", "token"), None);
+        assert_eq!(highlight_substring("Ünïcode token", "token").as_deref(), Some("Ünïcode <mark>token</mark>"));
+    }
+
+    #[test]
+    fn headline_is_preferred_when_it_marks_a_match() {
+        let c = contract("Tokenization engine", Some("Handles tokens"));
+        let headline = (
+            "<mark>Tokenization</mark> engine".to_string(),
+            Some("Handles <mark>tokens</mark>".to_string()),
+        );
+
+        let hl = build_highlights(&c, "tokenize", Some(headline));
+        assert_eq!(hl.name.as_deref(), Some("<mark>Tokenization</mark> engine"));
+        assert_eq!(hl.description.as_deref(), Some("Handles <mark>tokens</mark>"));
+    }
+
+    #[test]
+    fn falls_back_to_substring_and_skips_unmatched_fields() {
+        let c = contract("dexter", Some("An AMM"));
+        let headline = ("dexter".to_string(), Some("An AMM".to_string()));
+
+        let hl = build_highlights(&c, "dex", Some(headline));
+        assert_eq!(hl.name.as_deref(), Some("<mark>dex</mark>ter"));
+        assert_eq!(hl.description, None);
+    }
+}
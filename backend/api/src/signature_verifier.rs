@@ -0,0 +1,139 @@
+// api/src/signature_verifier.rs
+// Pluggable verification of multisig proposal signatures.
+//
+// Each supported scheme implements `SignatureVerifier`; `verifier_for` maps
+// the scheme named in a sign request to its implementation. Ed25519 (the key
+// inside the signer's Stellar account address, hex signature) is the default.
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use shared::{DeployProposal, SignatureScheme};
+
+use crate::stellar;
+
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SignatureError {
+    #[error("malformed public key")]
+    InvalidPublicKey,
+    #[error("malformed signature")]
+    MalformedSignature,
+    #[error("signature does not match")]
+    Mismatch,
+}
+
+pub trait SignatureVerifier: Send + Sync {
+    /// Check `signature` over `message` was made by the holder of `signer_address`.
+    fn verify(&self, signer_address: &str, message: &[u8], signature: &str) -> Result<(), SignatureError>;
+}
+
+/// Ed25519 keyed by `G...` account addresses, with hex-encoded 64-byte signatures
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Ed25519Verifier;
+
+impl SignatureVerifier for Ed25519Verifier {
+    fn verify(&self, signer_address: &str, message: &[u8], signature: &str) -> Result<(), SignatureError> {
+        let key_bytes =
+            stellar::decode_account_id(signer_address).map_err(|_| SignatureError::InvalidPublicKey)?;
+        let sig_bytes: [u8; 64] = hex::decode(signature)
+            .ok()
+            .and_then(|b| b.try_into().ok())
+            .ok_or(SignatureError::MalformedSignature)?;
+
+        let key = VerifyingKey::from_bytes(&key_bytes).map_err(|_| SignatureError::InvalidPublicKey)?;
+        key.verify(message, &Signature::from_bytes(&sig_bytes))
+            .map_err(|_| SignatureError::Mismatch)
+    }
+}
+
+/// The verifier for `scheme`.
+pub fn verifier_for(scheme: SignatureScheme) -> Box<dyn SignatureVerifier> {
+    match scheme {
+        SignatureScheme::Ed25519 => Box::new(Ed25519Verifier),
+    }
+}
+
+/// The bytes a signer signs to approve `proposal`: binds the approval to the
+/// exact contract and WASM being deployed.
+pub fn proposal_signing_message(proposal: &DeployProposal) -> Vec<u8> {
+    format!(
+        "soroban-registry:deploy-proposal:{}:{}:{}:{}",
+        proposal.id, proposal.network, proposal.contract_id, proposal.wasm_hash
+    )
+    .into_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn keypair() -> (SigningKey, String) {
+        let signing = SigningKey::from_bytes(&[7u8; 32]);
+        let address = stellar::encode_account_id(signing.verifying_key().as_bytes());
+        (signing, address)
+    }
+
+    #[test]
+    fn ed25519_accepts_valid_signature() {
+        let (signing, public) = keypair();
+        let sig = hex::encode(signing.sign(b"approve").to_bytes());
+
+        assert_eq!(Ed25519Verifier.verify(&public, b"approve", &sig), Ok(()));
+        assert_eq!(verifier_for(SignatureScheme::Ed25519).verify(&public, b"approve", &sig), Ok(()));
+    }
+
+    #[test]
+    fn ed25519_rejects_wrong_message_and_bad_encoding() {
+        let (signing, public) = keypair();
+        let sig = hex::encode(signing.sign(b"approve").to_bytes());
+
+        assert_eq!(
+            Ed25519Verifier.verify(&public, b"tampered", &sig),
+            Err(SignatureError::Mismatch)
+        );
+        assert_eq!(
+            Ed25519Verifier.verify(&public, b"approve", "zz"),
+            Err(SignatureError::MalformedSignature)
+        );
+        assert_eq!(
+            Ed25519Verifier.verify("GABC", b"approve", &sig),
+            Err(SignatureError::InvalidPublicKey)
+        );
+        // A bare hex key is not an account address
+        let (signing, _) = keypair();
+        assert_eq!(
+            Ed25519Verifier.verify(&hex::encode(signing.verifying_key().to_bytes()), b"approve", &sig),
+            Err(SignatureError::InvalidPublicKey)
+        );
+    }
+
+    /// Accepts exactly one (key, signature) pair; stands in for a future scheme.
+    struct MockVerifier {
+        key: &'static str,
+        signature: &'static str,
+    }
+
+    impl SignatureVerifier for MockVerifier {
+        fn verify(&self, public_key: &str, _message: &[u8], signature: &str) -> Result<(), SignatureError> {
+            if public_key == self.key && signature == self.signature {
+                Ok(())
+            } else {
+                Err(SignatureError::Mismatch)
+            }
+        }
+    }
+
+    #[test]
+    fn callers_work_through_the_trait() {
+        let verifiers: Vec<Box<dyn SignatureVerifier>> = vec![
+            Box::new(MockVerifier {
+                key: "signer",
+                signature: "sig",
+            }),
+            Box::new(Ed25519Verifier),
+        ];
+
+        assert!(verifiers[0].verify("signer", b"m", "sig").is_ok());
+        assert!(verifiers[0].verify("other", b"m", "sig").is_err());
+        assert!(verifiers[1].verify("signer", b"m", "sig").is_err());
+    }
+}
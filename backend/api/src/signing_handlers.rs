@@ -9,8 +9,8 @@ use serde::Deserialize;
 use sha2::{Digest, Sha256};
 use shared::{
     ChainOfCustodyEntry, ChainOfCustodyResponse, PackageSignature, RevokeSignatureRequest,
-    SignatureStatus, SignPackageRequest, TransparencyEntryType, TransparencyLogEntry,
-    TransparencyLogQueryParams, VerifySignatureRequest, VerifySignatureResponse,
+    SignatureStatus, TransparencyEntryType, TransparencyLogEntry, TransparencyLogQueryParams,
+    VerifySignatureResponse,
 };
 use uuid::Uuid;
 
@@ -27,7 +27,7 @@ fn map_json_rejection(err: axum::extract::rejection::JsonRejection) -> ApiError
     )
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, serde::Serialize)]
 pub struct SignRequest {
     pub contract_id: String,
     pub version: String,
@@ -44,18 +44,19 @@ pub async fn sign_package(
     State(state): State<AppState>,
     payload: Result<Json<SignRequest>, axum::extract::rejection::JsonRejection>,
 ) -> ApiResult<Json<PackageSignature>> {
-    let Json(req) = payload.map_err(map_json_rejection)?;
+    let Json(mut req) = payload.map_err(map_json_rejection)?;
 
     if req.contract_id.is_empty() {
         return Err(ApiError::bad_request("MissingContractId", "contract_id is required"));
     }
+    req.wasm_hash = crate::validation::parse_wasm_hash(&req.wasm_hash)?;
     if req.signature.is_empty() {
         return Err(ApiError::bad_request("MissingSignature", "signature is required"));
     }
 
     let contract_uuid = parse_contract_uuid(&state, &req.contract_id).await?;
 
-    let algorithm = req.algorithm.unwrap_or_else(|| "ed25519".to_string());
+    let algorithm = req.algorithm.clone().unwrap_or_else(|| "ed25519".to_string());
 
     let signature: PackageSignature = sqlx::query_as(
         r#"
@@ -73,7 +74,7 @@ pub async fn sign_package(
     .bind(&req.public_key)
     .bind(&algorithm)
     .bind(req.expires_at)
-    .bind(req.metadata)
+    .bind(&req.metadata)
     .fetch_one(&state.db)
     .await
     .map_err(|err| db_internal_error("create package signature", err))?;
@@ -99,7 +100,7 @@ pub async fn sign_package(
         VALUES ($1, $2, $3, $4, $5, $6, $7)
         "#,
     )
-    .bind(TransparencyEntryType::PackageSigned.to_string())
+    .bind(TransparencyEntryType::PackageSigned)
     .bind(contract_uuid)
     .bind(signature.id)
     .bind(&req.signing_address)
@@ -133,7 +134,8 @@ pub async fn verify_signature(
     State(state): State<AppState>,
     payload: Result<Json<VerifyRequestInternal>, axum::extract::rejection::JsonRejection>,
 ) -> ApiResult<Json<VerifySignatureResponse>> {
-    let Json(req) = payload.map_err(map_json_rejection)?;
+    let Json(mut req) = payload.map_err(map_json_rejection)?;
+    req.wasm_hash = crate::validation::parse_wasm_hash(&req.wasm_hash)?;
 
     if let (Some(sig_b64), Some(signing_addr)) = (&req.signature, &req.signing_address) {
         verify_signature_locally(&state, &req, sig_b64, signing_addr).await
@@ -209,7 +211,7 @@ async fn verify_signature_locally(
                     VALUES ($1, $2, $3, $4, $5)
                     "#,
                 )
-                .bind(TransparencyEntryType::SignatureVerified.to_string())
+                .bind(TransparencyEntryType::SignatureVerified)
                 .bind(contract_uuid)
                 .bind(db_sig.id)
                 .bind(&db_sig.signing_address)
@@ -228,7 +230,7 @@ async fn verify_signature_locally(
                 signature_id: Some(db_sig.id),
                 signing_address: db_sig.signing_address,
                 signed_at: Some(db_sig.signed_at),
-                status: db_sig.status,
+                status: db_sig.status.clone(),
                 message: if valid {
                     "Signature is valid".to_string()
                 } else if !status_valid {
@@ -363,7 +365,7 @@ pub async fn revoke_signature(
         VALUES ($1, $2, $3, $4, $5, $6)
         "#,
     )
-    .bind(TransparencyEntryType::SignatureRevoked.to_string())
+    .bind(TransparencyEntryType::SignatureRevoked)
     .bind(existing.contract_id)
     .bind(sig_uuid)
     .bind(&req.revoked_by)
@@ -488,19 +490,19 @@ pub async fn get_transparency_log(
         None
     };
 
-    if let Some(et) = &query.entry_type {
+    if query.entry_type.is_some() {
         conditions.push(format!("entry_type = ${}", param_count));
         param_count += 1;
     }
-    if let Some(addr) = &query.actor_address {
+    if query.actor_address.is_some() {
         conditions.push(format!("actor_address = ${}", param_count));
         param_count += 1;
     }
-    if let Some(from) = &query.from_timestamp {
+    if query.from_timestamp.is_some() {
         conditions.push(format!("timestamp >= ${}", param_count));
         param_count += 1;
     }
-    if let Some(to) = &query.to_timestamp {
+    if query.to_timestamp.is_some() {
         conditions.push(format!("timestamp <= ${}", param_count));
         param_count += 1;
     }
@@ -525,8 +527,8 @@ pub async fn get_transparency_log(
         select_sql = select_sql.bind(u);
     }
     if let Some(et) = &query.entry_type {
-        count_sql = count_sql.bind(et.to_string());
-        select_sql = select_sql.bind(et.to_string());
+        count_sql = count_sql.bind(et);
+        select_sql = select_sql.bind(et);
     }
     if let Some(addr) = &query.actor_address {
         count_sql = count_sql.bind(addr);
@@ -29,3 +29,97 @@ pub fn signing_routes() -> Router<AppState> {
             get(signing_handlers::get_transparency_log),
         )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use serde_json::{json, Value};
+    use tower::ServiceExt;
+
+    fn app(db: sqlx::PgPool) -> Router {
+        signing_routes().with_state(fixtures::state_for(db))
+    }
+
+    fn post_json(uri: &str, body: Value) -> Request<Body> {
+        Request::post(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn malformed_wasm_hash_is_rejected_before_any_lookup() {
+        let db = sqlx::pool::PoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap();
+        let body = json!({ "contract_id": "CABC", "wasm_hash": "not-a-hash" });
+        let response = app(db)
+            .oneshot(post_json("/api/signatures/verify", body))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn signatures_match_whatever_case_the_hash_arrives_in() {
+        let pool = fixtures::test_pool().await;
+        let contract = &fixtures::fixtures().contracts[0];
+        let hash = "ab".repeat(32);
+        let version = format!("sig-test-{}", uuid::Uuid::new_v4().simple());
+
+        let response = app(pool.clone())
+            .oneshot(post_json(
+                "/api/signatures",
+                json!({
+                    "contract_id": contract.id.to_string(),
+                    "version": version,
+                    "wasm_hash": format!("  {}  ", hash.to_uppercase()),
+                    "signature": "c2ln",
+                    "signing_address": contract.publisher_address,
+                    "public_key": "a2V5",
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let stored: String = sqlx::query_scalar(
+            "SELECT wasm_hash FROM package_signatures WHERE contract_id = $1 AND version = $2",
+        )
+        .bind(contract.id)
+        .bind(&version)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(stored, hash);
+
+        let response = app(pool.clone())
+            .oneshot(post_json(
+                "/api/signatures/verify",
+                json!({
+                    "contract_id": contract.id.to_string(),
+                    "version": version,
+                    "wasm_hash": hash.to_uppercase(),
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(body["valid"], true);
+
+        sqlx::query("DELETE FROM package_signatures WHERE contract_id = $1 AND version = $2")
+            .bind(contract.id)
+            .bind(&version)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,244 @@
+// api/src/soft_delete_purge.rs
+// Permanent removal of soft-deleted contracts and publishers.
+//
+// A contract or publisher is soft-deleted by setting `deleted_at`. Once that is
+// older than `SOFT_DELETE_RETENTION_DAYS` (default 30) the purge task deletes
+// the row for good; dependent rows go with it through `ON DELETE CASCADE`.
+// References that do not cascade are handled per row:
+//   - contracts named as a deprecation replacement or as a dependency are
+//     unlinked (the referencing column is set to NULL) before the delete;
+//   - a publisher still referenced by governance, maintenance, maturity or
+//     restore history is kept, and the skip is logged, so audit history is
+//     never broken.
+//
+// Purging is opt-in: the task only runs when `SOFT_DELETE_PURGE` is set. Each
+// row is deleted in its own transaction and every purge is logged.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{background_jobs, onchain::env_flag};
+
+/// Environment flag that enables the purge task
+pub const PURGE_ENABLED_ENV: &str = "SOFT_DELETE_PURGE";
+
+/// How often the purge task runs
+const PURGE_INTERVAL: Duration = Duration::from_secs(24 * 3600);
+
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+/// Postgres SQLSTATE for a foreign key violation
+const FOREIGN_KEY_VIOLATION: &str = "23503";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PurgePolicy {
+    pub retention_days: i64,
+}
+
+impl Default for PurgePolicy {
+    fn default() -> Self {
+        Self {
+            retention_days: DEFAULT_RETENTION_DAYS,
+        }
+    }
+}
+
+impl PurgePolicy {
+    /// Read `SOFT_DELETE_RETENTION_DAYS`; invalid or negative values fall back to the default.
+    pub fn from_env() -> Self {
+        let retention_days = std::env::var("SOFT_DELETE_RETENTION_DAYS")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i64>().ok())
+            .filter(|days| *days >= 0)
+            .unwrap_or(DEFAULT_RETENTION_DAYS);
+        Self { retention_days }
+    }
+
+    /// Rows soft-deleted before this instant are purged.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        now - ChronoDuration::days(self.retention_days)
+    }
+}
+
+/// Spawn the daily purge task when `SOFT_DELETE_PURGE` is set.
+///
+/// Each run is recorded in `background_jobs` under `soft_delete_purge`.
+pub fn spawn_purge_task(pool: PgPool) {
+    if !env_flag(PURGE_ENABLED_ENV) {
+        tracing::info!("soft-delete purge: disabled (set {} to enable)", PURGE_ENABLED_ENV);
+        return;
+    }
+    let policy = PurgePolicy::from_env();
+    tracing::info!(retention_days = policy.retention_days, "soft-delete purge: policy loaded");
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(PURGE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            background_jobs::run_tracked(&pool, background_jobs::JOB_SOFT_DELETE_PURGE, PURGE_INTERVAL, || {
+                purge_deleted(&pool, policy, Utc::now())
+            })
+            .await;
+        }
+    });
+}
+
+/// Purge contracts, then publishers, soft-deleted before the cutoff; returns
+/// the rows purged.
+pub async fn purge_deleted(pool: &PgPool, policy: PurgePolicy, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let cutoff = policy.cutoff(now);
+    let mut purged = 0;
+
+    let contracts: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM contracts WHERE deleted_at < $1 ORDER BY deleted_at")
+            .bind(cutoff)
+            .fetch_all(pool)
+            .await?;
+    for id in contracts {
+        if purge_row(pool, "contracts", id).await? {
+            purged += 1;
+        }
+    }
+
+    let publishers: Vec<Uuid> =
+        sqlx::query_scalar("SELECT id FROM publishers WHERE deleted_at < $1 ORDER BY deleted_at")
+            .bind(cutoff)
+            .fetch_all(pool)
+            .await?;
+    for id in publishers {
+        if purge_row(pool, "publishers", id).await? {
+            purged += 1;
+        }
+    }
+
+    if purged > 0 {
+        tracing::info!(purged, retention_days = policy.retention_days, "soft-delete purge: run complete");
+    }
+    Ok(purged)
+}
+
+/// Delete one contract or publisher (and everything that cascades from it).
+///
+/// Returns `Ok(false)` when the row is still referenced and was kept.
+async fn purge_row(pool: &PgPool, table: &'static str, id: Uuid) -> Result<bool, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    // Contracts being removed: the row itself, or every contract of the publisher
+    let owned = match table {
+        "publishers" => "SELECT id FROM contracts WHERE publisher_id = $1",
+        _ => "SELECT $1::uuid",
+    };
+    sqlx::query(&format!(
+        "UPDATE contract_deprecations SET replacement_contract_id = NULL WHERE replacement_contract_id IN ({owned})"
+    ))
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query(&format!(
+        "UPDATE contract_dependencies SET dependency_contract_id = NULL WHERE dependency_contract_id IN ({owned})"
+    ))
+    .bind(id)
+    .execute(&mut *tx)
+    .await?;
+
+    let deleted = sqlx::query(&format!("DELETE FROM {table} WHERE id = $1 AND deleted_at IS NOT NULL"))
+        .bind(id)
+        .execute(&mut *tx)
+        .await;
+    match deleted {
+        Ok(result) => {
+            tx.commit().await?;
+            let purged = result.rows_affected() > 0;
+            if purged {
+                tracing::info!(table, %id, "soft-delete purge: purged");
+            }
+            Ok(purged)
+        }
+        Err(sqlx::Error::Database(err)) if err.code().as_deref() == Some(FOREIGN_KEY_VIOLATION) => {
+            tx.rollback().await?;
+            tracing::warn!(table, %id, constraint = ?err.constraint(), "soft-delete purge: still referenced, kept");
+            Ok(false)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn retention_falls_back_to_default_on_bad_input() {
+        std::env::set_var("SOFT_DELETE_RETENTION_DAYS", "-4");
+        assert_eq!(PurgePolicy::from_env(), PurgePolicy::default());
+        std::env::set_var("SOFT_DELETE_RETENTION_DAYS", "7");
+        assert_eq!(PurgePolicy::from_env().retention_days, 7);
+        std::env::remove_var("SOFT_DELETE_RETENTION_DAYS");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn soft_deleted_rows_are_purged_only_past_retention() {
+        let pool = crate::fixtures::test_pool().await;
+        let policy = PurgePolicy { retention_days: 30 };
+
+        let publisher = Uuid::new_v4();
+        sqlx::query("INSERT INTO publishers (id, stellar_address) VALUES ($1, $2)")
+            .bind(publisher)
+            .bind(format!("G{}", publisher.simple()).to_uppercase())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let insert_contract = |deleted_days_ago: i64| {
+            let pool = pool.clone();
+            async move {
+                let id = Uuid::new_v4();
+                sqlx::query(
+                    "INSERT INTO contracts (id, contract_id, wasm_hash, name, publisher_id, network, deleted_at)
+                     VALUES ($1, $2, $3, 'purge test', $4, 'testnet', $5)",
+                )
+                .bind(id)
+                .bind(format!("C{}", id.simple()).to_uppercase())
+                .bind(format!("{:0>64}", id.simple()))
+                .bind(publisher)
+                .bind(now() - ChronoDuration::days(deleted_days_ago))
+                .execute(&pool)
+                .await
+                .unwrap();
+                id
+            }
+        };
+        let expired = insert_contract(45).await;
+        let recent = insert_contract(3).await;
+
+        purge_deleted(&pool, policy, now()).await.unwrap();
+
+        let exists = |id: Uuid| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, bool>("SELECT EXISTS (SELECT 1 FROM contracts WHERE id = $1)")
+                    .bind(id)
+                    .fetch_one(&pool)
+                    .await
+                    .unwrap()
+            }
+        };
+        assert!(!exists(expired).await);
+        assert!(exists(recent).await);
+
+        sqlx::query("DELETE FROM publishers WHERE id = $1")
+            .bind(publisher)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,474 @@
+// api/src/spam.rs
+// Spam heuristics for newly published contracts, and the review queue.
+//
+//   GET  /api/admin/review-queue                      — unresolved entries (admin)
+//   POST /api/admin/contracts/:id/flag                — hold a contract for review (admin)
+//   POST /api/admin/review-queue/:contract_id/resolve — approve or reject (admin)
+//
+// Each publish is scored from cheap signals: a name with no real words, a
+// stock template name, a name already used many times, a missing description,
+// and a burst of publishes from one publisher. When the score reaches
+// `SPAM_REVIEW_THRESHOLD` the contract is stored with `pending_review` set,
+// which keeps it out of listings, and queued for an admin instead of going
+// live. Publishing itself still succeeds, with a warning.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgExecutor};
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const DEFAULT_THRESHOLD: f64 = 0.6;
+const DEFAULT_BURST_LIMIT: i64 = 10;
+/// `flagged_by` for entries queued by the publish-time heuristic
+pub const HEURISTIC_FLAGGER: &str = "heuristic";
+/// Window for the burst signal
+pub const BURST_WINDOW_MINUTES: i64 = 60;
+/// A name already shared by this many contracts counts as a template
+const DUPLICATE_NAME_LIMIT: i64 = 3;
+
+const WEIGHT_NO_WORDS: f64 = 0.5;
+const WEIGHT_GIBBERISH: f64 = 0.4;
+const WEIGHT_TEMPLATE_NAME: f64 = 0.3;
+const WEIGHT_DUPLICATE_NAME: f64 = 0.3;
+const WEIGHT_NO_DESCRIPTION: f64 = 0.2;
+const WEIGHT_BURST: f64 = 0.5;
+
+/// Names that scaffolding tools and tutorials leave behind
+const TEMPLATE_NAMES: &[&str] = &[
+    "contract",
+    "my contract",
+    "hello world",
+    "hello",
+    "test",
+    "test contract",
+    "token",
+    "untitled",
+    "example",
+    "new contract",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpamPolicy {
+    /// Scores at or above this are held for review
+    pub threshold: f64,
+    /// Publishes per publisher within `BURST_WINDOW_MINUTES` before it counts as a burst
+    pub burst_limit: i64,
+}
+
+impl Default for SpamPolicy {
+    fn default() -> Self {
+        Self {
+            threshold: DEFAULT_THRESHOLD,
+            burst_limit: DEFAULT_BURST_LIMIT,
+        }
+    }
+}
+
+impl SpamPolicy {
+    /// Read `SPAM_REVIEW_THRESHOLD` and `SPAM_BURST_LIMIT`; invalid values fall back to the defaults.
+    pub fn from_env() -> Self {
+        let threshold = std::env::var("SPAM_REVIEW_THRESHOLD")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<f64>().ok())
+            .filter(|t| t.is_finite() && *t > 0.0)
+            .unwrap_or(DEFAULT_THRESHOLD);
+        let burst_limit = std::env::var("SPAM_BURST_LIMIT")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i64>().ok())
+            .filter(|n| *n > 0)
+            .unwrap_or(DEFAULT_BURST_LIMIT);
+        Self { threshold, burst_limit }
+    }
+}
+
+/// What the heuristic looks at for one publish
+#[derive(Debug, Clone)]
+pub struct SpamSignals<'a> {
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    /// Existing contracts with the same name (case-insensitive)
+    pub same_name_count: i64,
+    /// The publisher's publishes within the burst window, excluding this one
+    pub recent_publishes: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpamAssessment {
+    pub score: f64,
+    pub reasons: Vec<String>,
+}
+
+impl SpamAssessment {
+    pub fn needs_review(&self, policy: &SpamPolicy) -> bool {
+        self.score >= policy.threshold
+    }
+}
+
+/// Whether `word` looks like a real word: it has a vowel and no long
+/// consonant run ("qzxkwvb" does not).
+fn is_wordlike(word: &str) -> bool {
+    let lower = word.to_ascii_lowercase();
+    let has_vowel = lower.chars().any(|c| "aeiouy".contains(c));
+    let mut run = 0;
+    let mut longest_run = 0;
+    for c in lower.chars() {
+        if c.is_ascii_alphabetic() && !"aeiouy".contains(c) {
+            run += 1;
+            longest_run = longest_run.max(run);
+        } else {
+            run = 0;
+        }
+    }
+    has_vowel && longest_run < 5
+}
+
+pub fn assess(signals: &SpamSignals, policy: &SpamPolicy) -> SpamAssessment {
+    let mut score = 0.0;
+    let mut reasons = Vec::new();
+    let mut flag = |weight: f64, reason: String| {
+        score += weight;
+        reasons.push(reason);
+    };
+
+    let name = signals.name.trim();
+    let words: Vec<&str> = name
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().filter(|c| c.is_alphabetic()).count() >= 2)
+        .collect();
+
+    if words.is_empty() {
+        flag(WEIGHT_NO_WORDS, "name has no words".to_string());
+    } else if !words.iter().any(|w| is_wordlike(w)) {
+        flag(WEIGHT_GIBBERISH, "name looks like random characters".to_string());
+    }
+    if TEMPLATE_NAMES.contains(&name.to_lowercase().as_str()) {
+        flag(WEIGHT_TEMPLATE_NAME, format!("'{}' is a template name", name));
+    }
+    if signals.same_name_count >= DUPLICATE_NAME_LIMIT {
+        flag(
+            WEIGHT_DUPLICATE_NAME,
+            format!("name is already used by {} contracts", signals.same_name_count),
+        );
+    }
+    if signals.description.is_none_or(|d| d.trim().is_empty()) {
+        flag(WEIGHT_NO_DESCRIPTION, "no description".to_string());
+    }
+    if signals.recent_publishes >= policy.burst_limit {
+        flag(
+            WEIGHT_BURST,
+            format!(
+                "publisher published {} contracts in the last {} minutes",
+                signals.recent_publishes, BURST_WINDOW_MINUTES
+            ),
+        );
+    }
+
+    SpamAssessment {
+        score: (score * 100.0).round() / 100.0,
+        reasons,
+    }
+}
+
+/// Warning attached to the publish response for a held contract
+pub fn review_warning(assessment: &SpamAssessment) -> String {
+    format!(
+        "Contract is held for review before it appears in listings ({})",
+        assessment.reasons.join("; ")
+    )
+}
+
+/// Gather the database-backed signals for a publish by `publisher_id`.
+pub async fn load_signals<'a>(
+    pool: &sqlx::PgPool,
+    publisher_id: Uuid,
+    name: &'a str,
+    description: Option<&'a str>,
+) -> Result<SpamSignals<'a>, sqlx::Error> {
+    let (same_name_count, recent_publishes): (i64, i64) = sqlx::query_as(
+        "SELECT
+             (SELECT COUNT(*) FROM contracts WHERE LOWER(name) = LOWER($1)),
+             (SELECT COUNT(*) FROM contracts
+               WHERE publisher_id = $2
+                 AND created_at > NOW() - make_interval(mins => $3::int))",
+    )
+    .bind(name)
+    .bind(publisher_id)
+    .bind(BURST_WINDOW_MINUTES as i32)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(SpamSignals {
+        name,
+        description,
+        same_name_count,
+        recent_publishes,
+    })
+}
+
+/// One row in `contract_review_queue`
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ReviewEntry {
+    pub contract_id: Uuid,
+    pub score: f64,
+    pub reasons: Vec<String>,
+    /// `heuristic`, or the admin address for manual flags
+    pub flagged_by: String,
+    pub created_at: DateTime<Utc>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub resolution: Option<String>,
+    pub resolved_by: Option<String>,
+}
+
+/// Hold `contract_id` out of listings and add it to the review queue.
+pub async fn enqueue<'e, E>(
+    executor: E,
+    contract_id: Uuid,
+    assessment: &SpamAssessment,
+    flagged_by: &str,
+) -> Result<ReviewEntry, sqlx::Error>
+where
+    E: PgExecutor<'e>,
+{
+    sqlx::query_as(
+        "WITH held AS (
+             UPDATE contracts SET pending_review = true WHERE id = $1
+         )
+         INSERT INTO contract_review_queue (contract_id, score, reasons, flagged_by)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (contract_id) DO UPDATE SET
+             score = EXCLUDED.score,
+             reasons = EXCLUDED.reasons,
+             flagged_by = EXCLUDED.flagged_by,
+             created_at = NOW(),
+             resolved_at = NULL,
+             resolution = NULL,
+             resolved_by = NULL
+         RETURNING *",
+    )
+    .bind(contract_id)
+    .bind(assessment.score)
+    .bind(&assessment.reasons)
+    .bind(flagged_by)
+    .fetch_one(executor)
+    .await
+}
+
+#[derive(Debug, Deserialize)]
+pub struct FlagContractRequest {
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReviewDecision {
+    /// Release the contract into listings
+    Approve,
+    /// Keep it hidden
+    Reject,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ResolveReviewRequest {
+    pub decision: ReviewDecision,
+}
+
+/// GET /api/admin/review-queue
+pub async fn list_review_queue(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<Vec<ReviewEntry>>> {
+    auth.require_admin()?;
+    let entries: Vec<ReviewEntry> = sqlx::query_as(
+        "SELECT * FROM contract_review_queue WHERE resolved_at IS NULL ORDER BY score DESC, created_at",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list review queue", err))?;
+    Ok(Json(entries))
+}
+
+/// POST /api/admin/contracts/:id/flag
+pub async fn flag_contract(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<FlagContractRequest>,
+) -> ApiResult<(StatusCode, Json<ReviewEntry>)> {
+    auth.require_admin()?;
+    let reason = req.reason.trim();
+    if reason.is_empty() {
+        return Err(ApiError::bad_request("InvalidReason", "reason is required"));
+    }
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("check contract for flag", err))?;
+    if !exists {
+        return Err(ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)));
+    }
+
+    let assessment = SpamAssessment {
+        score: 1.0,
+        reasons: vec![reason.to_string()],
+    };
+    let entry = enqueue(&state.db, id, &assessment, &auth.publisher_address)
+        .await
+        .map_err(|err| db_internal_error("flag contract", err))?;
+
+    tracing::info!(contract_id = %id, flagged_by = %auth.publisher_address, "contract flagged for review");
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+/// POST /api/admin/review-queue/:contract_id/resolve
+pub async fn resolve_review(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(contract_id): Path<Uuid>,
+    Json(req): Json<ResolveReviewRequest>,
+) -> ApiResult<Json<ReviewEntry>> {
+    auth.require_admin()?;
+    let (resolution, pending_review) = match req.decision {
+        ReviewDecision::Approve => ("approved", false),
+        ReviewDecision::Reject => ("rejected", true),
+    };
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin review resolution", err))?;
+
+    let entry: ReviewEntry = sqlx::query_as(
+        "UPDATE contract_review_queue
+         SET resolved_at = NOW(), resolution = $2, resolved_by = $3
+         WHERE contract_id = $1 AND resolved_at IS NULL
+         RETURNING *",
+    )
+    .bind(contract_id)
+    .bind(resolution)
+    .bind(&auth.publisher_address)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("resolve review", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "ReviewNotFound",
+            format!("Contract {} has no open review", contract_id),
+        )
+    })?;
+
+    sqlx::query("UPDATE contracts SET pending_review = $2 WHERE id = $1")
+        .bind(contract_id)
+        .bind(pending_review)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("release reviewed contract", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit review resolution", err))?;
+    Ok(Json(entry))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signals(name: &'static str, description: Option<&'static str>) -> SpamSignals<'static> {
+        SpamSignals {
+            name,
+            description,
+            same_name_count: 0,
+            recent_publishes: 0,
+        }
+    }
+
+    #[test]
+    fn ordinary_contract_is_published() {
+        let policy = SpamPolicy::default();
+        let a = assess(&signals("Soroswap Router", Some("AMM router for Soroban")), &policy);
+        assert!(a.reasons.is_empty());
+        assert!(!a.needs_review(&policy));
+
+        // Missing a description alone is not enough to hold a contract
+        let a = assess(&signals("Lending Pool", None), &policy);
+        assert_eq!(a.reasons, vec!["no description"]);
+        assert!(!a.needs_review(&policy));
+    }
+
+    #[test]
+    fn gibberish_or_empty_names_fire() {
+        let policy = SpamPolicy::default();
+        for name in ["qzxkwvbt", "---", "x1 y2", "BCDFGHJ KLMNPQR"] {
+            let a = assess(&signals(name, None), &policy);
+            assert!(a.needs_review(&policy), "{} should be held (score {})", name, a.score);
+        }
+    }
+
+    #[test]
+    fn template_names_fire_when_repeated_without_description() {
+        let policy = SpamPolicy::default();
+        let mut s = signals("Hello World", None);
+        s.same_name_count = 12;
+        let a = assess(&s, &policy);
+        assert!(a.needs_review(&policy));
+        assert_eq!(a.reasons.len(), 3);
+        assert!(a.reasons[0].contains("template"));
+
+        // A described template name used once is fine
+        let a = assess(&signals("Token", Some("SEP-41 token for our DAO")), &policy);
+        assert!(!a.needs_review(&policy));
+    }
+
+    #[test]
+    fn publish_bursts_fire() {
+        let policy = SpamPolicy {
+            threshold: 0.6,
+            burst_limit: 5,
+        };
+        let mut s = signals("Vault", None);
+        s.recent_publishes = 5;
+        let a = assess(&s, &policy);
+        assert!(a.needs_review(&policy));
+        assert!(a.reasons.iter().any(|r| r.contains("5 contracts in the last 60 minutes")));
+
+        s.recent_publishes = 4;
+        assert!(!assess(&s, &policy).needs_review(&policy));
+    }
+
+    #[test]
+    fn threshold_routes_to_the_review_queue() {
+        let a = assess(&signals("Lending Pool", None), &SpamPolicy::default());
+        let strict = SpamPolicy {
+            threshold: 0.2,
+            ..SpamPolicy::default()
+        };
+        assert!(!a.needs_review(&SpamPolicy::default()));
+        assert!(a.needs_review(&strict));
+        assert!(review_warning(&a).contains("held for review"));
+        assert!(review_warning(&a).contains("no description"));
+    }
+
+    #[test]
+    fn invalid_env_falls_back_to_defaults() {
+        std::env::set_var("SPAM_REVIEW_THRESHOLD", "nope");
+        std::env::set_var("SPAM_BURST_LIMIT", "0");
+        assert_eq!(SpamPolicy::from_env(), SpamPolicy::default());
+        std::env::remove_var("SPAM_REVIEW_THRESHOLD");
+        std::env::remove_var("SPAM_BURST_LIMIT");
+    }
+}
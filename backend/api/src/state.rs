@@ -1,7 +1,20 @@
+use crate::auth::AuthManager;
 use crate::cache::{CacheConfig, CacheLayer};
+use crate::importer::{self, SharedMetadataSource};
+use crate::maturity_criteria::MaturityCriteriaConfig;
+use crate::notifications::Notifier;
+use crate::views::{SharedViewTracker, ViewTracker};
+use crate::object_store::SharedObjectStore;
+use crate::onchain::{self, SharedContractLookup};
+use crate::pagination::PaginationConfig;
+use crate::resource_tracking::ResourceManager;
+use crate::trust::TrustWeights;
+use crate::handlers;
+use shared::Network;
+use indexer::NetworkRpcClients;
 use prometheus::Registry;
 use sqlx::PgPool;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
 use std::time::Instant;
 
 /// Application state shared across handlers
@@ -11,16 +24,81 @@ pub struct AppState {
     pub started_at: Instant,
     pub cache: Arc<CacheLayer>,
     pub registry: Registry,
+    pub resource_mgr: Arc<RwLock<ResourceManager>>,
+    /// On-chain existence check for publish; `None` when disabled
+    pub onchain: Option<SharedContractLookup>,
+    /// External metadata source for contract import; `None` when disabled
+    pub metadata_source: Option<SharedMetadataSource>,
+    /// Blob storage for WASM binaries and backup bundles
+    pub objects: SharedObjectStore,
+    /// Trust score weights, loaded at startup and replaced by the admin API
+    pub trust_weights: Arc<RwLock<TrustWeights>>,
+    /// Maturity promotion criteria, loaded from `maturity_criteria` at startup
+    pub maturity_criteria: Arc<RwLock<MaturityCriteriaConfig>>,
+    /// Debounced contract view counts awaiting flush
+    pub views: SharedViewTracker,
+    /// Default and maximum page sizes for list endpoints
+    pub pagination: PaginationConfig,
+    /// Rate-limited Stellar RPC clients, one per network, shared by every RPC caller
+    pub rpc: Arc<NetworkRpcClients>,
+    /// Pending auth challenges and JWT keys
+    pub auth_mgr: Arc<RwLock<AuthManager>>,
+    /// Network chosen for contract_id lookups that match several networks
+    pub default_network: Option<Network>,
+    /// Fans events out to webhook, SSE, in-app and email channels
+    pub notifier: Arc<Notifier>,
 }
 
 impl AppState {
-    pub fn new(db: PgPool, registry: Registry) -> Self {
+    pub fn new(db: PgPool, registry: Registry, objects: SharedObjectStore) -> Self {
         let config = CacheConfig::from_env();
+        let rpc = Arc::new(NetworkRpcClients::from_env());
+        let notifier = Arc::new(Notifier::new(db.clone()));
         Self {
             db,
             started_at: Instant::now(),
             cache: Arc::new(CacheLayer::new(config)),
             registry,
+            resource_mgr: Arc::new(RwLock::new(ResourceManager::new())),
+            onchain: onchain::lookup_from_env(&rpc),
+            metadata_source: importer::source_from_env(),
+            objects,
+            trust_weights: Arc::new(RwLock::new(TrustWeights::default())),
+            maturity_criteria: Arc::new(RwLock::new(MaturityCriteriaConfig::default())),
+            views: Arc::new(ViewTracker::from_env()),
+            pagination: PaginationConfig::from_env(),
+            rpc,
+            auth_mgr: Arc::new(RwLock::new(AuthManager::from_env())),
+            default_network: handlers::default_network_from_env(),
+            notifier,
         }
     }
+
+    pub fn trust_weights(&self) -> TrustWeights {
+        *self
+            .trust_weights
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn set_trust_weights(&self, weights: TrustWeights) {
+        *self
+            .trust_weights
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = weights;
+    }
+
+    pub fn maturity_criteria(&self) -> MaturityCriteriaConfig {
+        self.maturity_criteria
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    pub fn set_maturity_criteria(&self, config: MaturityCriteriaConfig) {
+        *self
+            .maturity_criteria
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = config;
+    }
 }
@@ -0,0 +1,174 @@
+// api/src/stellar.rs
+// Stellar strkey encoding (SEP-23) for contract ids and account addresses.
+//
+// A strkey is base32 (RFC 4648, no padding) over
+//   version byte | 32-byte payload | CRC16-XModem checksum (little-endian)
+// The version byte fixes the leading character: `G` for accounts, `C` for
+// contracts. Decoding checks all three parts, so a typo or an account address
+// pasted where a contract id belongs is caught before it reaches the database.
+
+use thiserror::Error;
+
+/// Encoded length of a 32-byte strkey
+pub const STRKEY_LEN: usize = 56;
+
+const VERSION_ACCOUNT: u8 = 6 << 3;
+const VERSION_CONTRACT: u8 = 2 << 3;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum StrkeyError {
+    #[error("must be {STRKEY_LEN} characters, got {0}")]
+    InvalidLength(usize),
+    #[error("contains characters outside the Stellar base32 alphabet")]
+    InvalidCharacter,
+    #[error("is an account address (G...); a contract id (C...) is required")]
+    AccountNotContract,
+    #[error("is not a contract id (must start with 'C')")]
+    WrongKind,
+    #[error("is not an account address (must start with 'G')")]
+    NotAnAccount,
+    #[error("checksum does not match; check for typos")]
+    InvalidChecksum,
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x1021 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+fn base32_decode(input: &str) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in input.bytes() {
+        let value = BASE32_ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    Some(out)
+}
+
+fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() * 8).div_ceil(5));
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for &byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+/// Decode a 32-byte strkey, returning its version byte and payload.
+fn decode(strkey: &str) -> Result<(u8, [u8; 32]), StrkeyError> {
+    if strkey.len() != STRKEY_LEN {
+        return Err(StrkeyError::InvalidLength(strkey.len()));
+    }
+    let raw = base32_decode(strkey).ok_or(StrkeyError::InvalidCharacter)?;
+    let (body, checksum) = raw.split_at(raw.len() - 2);
+    if crc16_xmodem(body).to_le_bytes() != checksum {
+        return Err(StrkeyError::InvalidChecksum);
+    }
+    let mut payload = [0u8; 32];
+    payload.copy_from_slice(&body[1..]);
+    Ok((body[0], payload))
+}
+
+fn encode(version: u8, payload: &[u8; 32]) -> String {
+    let mut raw = Vec::with_capacity(35);
+    raw.push(version);
+    raw.extend_from_slice(payload);
+    let checksum = crc16_xmodem(&raw).to_le_bytes();
+    raw.extend_from_slice(&checksum);
+    base32_encode(&raw)
+}
+
+/// Check `contract_id` is a well-formed `C...` strkey and return its 32-byte
+/// contract hash.
+pub fn validate_contract_id(contract_id: &str) -> Result<[u8; 32], StrkeyError> {
+    let (version, payload) = decode(contract_id)?;
+    match version {
+        VERSION_CONTRACT => Ok(payload),
+        VERSION_ACCOUNT => Err(StrkeyError::AccountNotContract),
+        _ => Err(StrkeyError::WrongKind),
+    }
+}
+
+/// Check `address` is a well-formed `G...` strkey and return its ed25519
+/// public key.
+pub fn decode_account_id(address: &str) -> Result<[u8; 32], StrkeyError> {
+    match decode(address)? {
+        (VERSION_ACCOUNT, payload) => Ok(payload),
+        _ => Err(StrkeyError::NotAnAccount),
+    }
+}
+
+pub fn encode_contract_id(payload: &[u8; 32]) -> String {
+    encode(VERSION_CONTRACT, payload)
+}
+
+pub fn encode_account_id(payload: &[u8; 32]) -> String {
+    encode(VERSION_ACCOUNT, payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Native XLM asset contract on testnet
+    const CONTRACT: &str = "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+    #[test]
+    fn valid_contract_ids_decode() {
+        let payload = validate_contract_id(CONTRACT).unwrap();
+        assert_eq!(encode_contract_id(&payload), CONTRACT);
+
+        let generated = encode_contract_id(&[7u8; 32]);
+        assert!(generated.starts_with('C'));
+        assert_eq!(validate_contract_id(&generated), Ok([7u8; 32]));
+    }
+
+    #[test]
+    fn account_address_is_not_a_contract_id() {
+        let payload = validate_contract_id(CONTRACT).unwrap();
+        let account = encode_account_id(&payload);
+        assert!(account.starts_with('G'));
+        assert_eq!(validate_contract_id(&account), Err(StrkeyError::AccountNotContract));
+
+        // Swapping only the first letter leaves a broken checksum, not a valid account
+        let swapped = format!("G{}", &CONTRACT[1..]);
+        assert!(validate_contract_id(&swapped).is_err());
+    }
+
+    #[test]
+    fn malformed_ids_are_rejected() {
+        assert_eq!(validate_contract_id("CABC123"), Err(StrkeyError::InvalidLength(7)));
+        assert_eq!(validate_contract_id(""), Err(StrkeyError::InvalidLength(0)));
+
+        let lowercase = CONTRACT.to_lowercase();
+        assert_eq!(validate_contract_id(&lowercase), Err(StrkeyError::InvalidCharacter));
+
+        let mut typo = CONTRACT.to_string();
+        typo.replace_range(10..11, if &CONTRACT[10..11] == "A" { "B" } else { "A" });
+        assert_eq!(validate_contract_id(&typo), Err(StrkeyError::InvalidChecksum));
+
+        let placeholder = format!("C{}", "A".repeat(55));
+        assert!(validate_contract_id(&placeholder).is_err());
+    }
+}
@@ -0,0 +1,177 @@
+// api/src/tag_handlers.rs
+// Admin tag maintenance: POST /api/admin/tags/merge
+//
+// Rewrites every contract tagged with any of the source tags to use the
+// target tag instead, deduplicating within each contract's tag list. Each
+// rewritten contract gets a `metadata_updated` audit entry.
+
+use axum::{extract::State, Extension, Json};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    audit::{self, AuditEntry},
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct MergeTagsRequest {
+    pub sources: Vec<String>,
+    pub target: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MergeTagsResponse {
+    pub sources: Vec<String>,
+    pub target: String,
+    pub contracts_updated: usize,
+}
+
+/// Replace any of `sources` in `tags` with `target`, keeping first-seen order
+/// and dropping duplicates. `None` when nothing changes.
+pub fn merge_tags(tags: &[String], sources: &[String], target: &str) -> Option<Vec<String>> {
+    if !tags.iter().any(|t| sources.contains(t)) {
+        return None;
+    }
+
+    let mut merged: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = if sources.contains(tag) { target } else { tag.as_str() };
+        if !merged.iter().any(|t| t == tag) {
+            merged.push(tag.to_string());
+        }
+    }
+    (merged != tags).then_some(merged)
+}
+
+/// Trim and validate the request; sources equal to the target are dropped.
+fn normalize_request(req: MergeTagsRequest) -> ApiResult<(Vec<String>, String)> {
+    let target = req.target.trim().to_string();
+    if target.is_empty() {
+        return Err(ApiError::bad_request("InvalidTarget", "target tag must not be empty"));
+    }
+
+    let mut sources: Vec<String> = Vec::new();
+    for source in req.sources.iter().map(|s| s.trim()) {
+        if !source.is_empty() && source != target && !sources.iter().any(|s| s == source) {
+            sources.push(source.to_string());
+        }
+    }
+    if sources.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidSources",
+            "at least one source tag different from the target is required",
+        ));
+    }
+
+    Ok((sources, target))
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(op, err)
+}
+
+/// POST /api/admin/tags/merge
+pub async fn merge_tags_handler(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(req): Json<MergeTagsRequest>,
+) -> ApiResult<Json<MergeTagsResponse>> {
+    auth.require_admin()?;
+    let (sources, target) = normalize_request(req)?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin tag merge", err))?;
+
+    let tagged: Vec<(Uuid, Vec<String>)> =
+        sqlx::query_as("SELECT id, tags FROM contracts WHERE tags && $1 FOR UPDATE")
+            .bind(&sources)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|err| db_err("load contracts for tag merge", err))?;
+
+    let mut contracts_updated = 0;
+    for (id, tags) in tagged {
+        let Some(merged) = merge_tags(&tags, &sources, &target) else {
+            continue;
+        };
+
+        sqlx::query("UPDATE contracts SET tags = $2, updated_at = NOW() WHERE id = $1")
+            .bind(id)
+            .bind(&merged)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| db_err("rewrite contract tags", err))?;
+
+        audit::record(
+            &mut *tx,
+            &AuditEntry::tags_merged(id, &auth.publisher_address, &tags, &merged, &sources, &target),
+        )
+        .await
+        .map_err(|err| db_err("record tag merge audit", err))?;
+
+        contracts_updated += 1;
+    }
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit tag merge", err))?;
+
+    tracing::info!(?sources, %target, contracts_updated, "tags merged");
+
+    Ok(Json(MergeTagsResponse {
+        sources,
+        target,
+        contracts_updated,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(list: &[&str]) -> Vec<String> {
+        list.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[test]
+    fn merging_two_tags_collapses_them_without_duplicates() {
+        let sources = tags(&["defi", "De-Fi"]);
+
+        let both = merge_tags(&tags(&["De-Fi", "amm", "defi"]), &sources, "DeFi").unwrap();
+        assert_eq!(both, tags(&["DeFi", "amm"]));
+
+        let with_target = merge_tags(&tags(&["DeFi", "defi"]), &sources, "DeFi").unwrap();
+        assert_eq!(with_target, tags(&["DeFi"]));
+
+        let single = merge_tags(&tags(&["amm", "defi"]), &sources, "DeFi").unwrap();
+        assert_eq!(single, tags(&["amm", "DeFi"]));
+    }
+
+    #[test]
+    fn contracts_without_source_tags_are_untouched() {
+        assert_eq!(merge_tags(&tags(&["nft", "DeFi"]), &tags(&["defi"]), "DeFi"), None);
+    }
+
+    #[test]
+    fn request_normalization_drops_target_and_blank_sources() {
+        let (sources, target) = normalize_request(MergeTagsRequest {
+            sources: tags(&[" defi ", "DeFi", "", "defi"]),
+            target: " DeFi ".into(),
+        })
+        .unwrap();
+        assert_eq!(sources, tags(&["defi"]));
+        assert_eq!(target, "DeFi");
+
+        assert!(normalize_request(MergeTagsRequest {
+            sources: tags(&["DeFi"]),
+            target: "DeFi".into(),
+        })
+        .is_err());
+    }
+}
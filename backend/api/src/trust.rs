@@ -21,10 +21,12 @@
 //  50–74    Silver
 //   0–49    Bronze
 //
-// All weights are defined as constants so they are easy to audit and adjust.
+// The weights above are the defaults. Operators can override them at runtime
+// through PUT /api/admin/trust-weights (see trust_handlers.rs); overrides must
+// still sum to 100 so scores stay on the same scale.
 
 use chrono::Utc;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 // ── Weight constants ──────────────────────────────────────────────────────────
 
@@ -43,6 +45,57 @@ pub const WEIGHT_AGE: f64 = 10.0;
 /// Maximum points from having no critical vulnerabilities
 pub const WEIGHT_NO_VULNS: f64 = 10.0;
 
+/// Tolerance when checking that weights sum to 100
+const WEIGHT_SUM_EPSILON: f64 = 0.01;
+
+/// Maximum points per factor. Must sum to 100.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TrustWeights {
+    pub verified: f64,
+    pub audit: f64,
+    pub usage: f64,
+    pub age: f64,
+    pub no_vulns: f64,
+}
+
+impl Default for TrustWeights {
+    fn default() -> Self {
+        Self {
+            verified: WEIGHT_VERIFIED,
+            audit: WEIGHT_AUDIT,
+            usage: WEIGHT_USAGE,
+            age: WEIGHT_AGE,
+            no_vulns: WEIGHT_NO_VULNS,
+        }
+    }
+}
+
+impl TrustWeights {
+    fn fields(&self) -> [(&'static str, f64); 5] {
+        [
+            ("verified", self.verified),
+            ("audit", self.audit),
+            ("usage", self.usage),
+            ("age", self.age),
+            ("no_vulns", self.no_vulns),
+        ]
+    }
+
+    /// Every weight within 0–100 and the total exactly 100.
+    pub fn validate(&self) -> Result<(), String> {
+        for (name, weight) in self.fields() {
+            if !weight.is_finite() || !(0.0..=100.0).contains(&weight) {
+                return Err(format!("weight '{}' must be between 0 and 100, got {}", name, weight));
+            }
+        }
+        let sum: f64 = self.fields().iter().map(|(_, w)| w).sum();
+        if (sum - 100.0).abs() > WEIGHT_SUM_EPSILON {
+            return Err(format!("weights must sum to 100, got {}", sum));
+        }
+        Ok(())
+    }
+}
+
 /// Number of deployments needed to earn full usage points
 const USAGE_DEPLOYMENT_CAP: f64 = 50.0;
 
@@ -52,6 +105,12 @@ const USAGE_INTERACTION_CAP: f64 = 500.0;
 /// Days of age needed to earn full age points
 const AGE_DAYS_CAP: f64 = 180.0;
 
+const FACTOR_VERIFIED: &str = "Verification Status";
+const FACTOR_AUDIT: &str = "Audit Quality";
+const FACTOR_USAGE: &str = "Usage & Adoption";
+const FACTOR_AGE: &str = "Contract Age";
+const FACTOR_NO_VULNS: &str = "Vulnerability Status";
+
 // ── Input data ────────────────────────────────────────────────────────────────
 
 /// Raw data collected from the DB before scoring
@@ -125,20 +184,25 @@ pub fn trust_badge(score: f64) -> (&'static str, &'static str) {
 
 // ── Scoring engine ────────────────────────────────────────────────────────────
 
+/// Compute the composite trust score using the default weights.
+pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
+    compute_trust_score_with(input, &TrustWeights::default())
+}
+
 /// Compute the composite trust score from the collected input signals.
 ///
 /// Returns a fully-populated [`TrustScore`] with per-factor breakdown.
-pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
+pub fn compute_trust_score_with(input: &TrustInput, weights: &TrustWeights) -> TrustScore {
     let mut factors: Vec<TrustFactor> = Vec::with_capacity(5);
     let mut total = 0.0f64;
 
     // ── Factor 1: Verification status ────────────────────────────────────────
-    let verification_points = if input.is_verified { WEIGHT_VERIFIED } else { 0.0 };
+    let verification_points = if input.is_verified { weights.verified } else { 0.0 };
     total += verification_points;
     factors.push(TrustFactor {
-        name: "Verification Status",
+        name: FACTOR_VERIFIED,
         points_earned: verification_points,
-        points_max: WEIGHT_VERIFIED,
+        points_max: weights.verified,
         explanation: if input.is_verified {
             "Contract source code has been verified on-chain.".into()
         } else {
@@ -148,20 +212,23 @@ pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
 
     // ── Factor 2: Audit quality ───────────────────────────────────────────────
     let audit_points = match input.latest_audit_score {
-        Some(s) => (s / 100.0) * WEIGHT_AUDIT,
+        Some(s) => (s / 100.0) * weights.audit,
         None    => 0.0,
     };
     total += audit_points;
     factors.push(TrustFactor {
-        name: "Audit Quality",
+        name: FACTOR_AUDIT,
         points_earned: audit_points,
-        points_max: WEIGHT_AUDIT,
+        points_max: weights.audit,
         explanation: match input.latest_audit_score {
             Some(s) => format!(
                 "Latest security audit scored {:.1}/100. Audit score contributes up to {:.0} trust points.",
-                s, WEIGHT_AUDIT
+                s, weights.audit
+            ),
+            None => format!(
+                "No security audit found. Complete an audit to earn up to {:.0} points.",
+                weights.audit
             ),
-            None => "No security audit found. Complete an audit to earn up to 35 points.".into(),
         },
     });
 
@@ -169,12 +236,12 @@ pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
     // Blend deployments (weighted 60%) and interactions (weighted 40%), each capped
     let deploy_ratio  = (input.total_deployments  as f64 / USAGE_DEPLOYMENT_CAP).min(1.0);
     let interact_ratio = (input.total_interactions as f64 / USAGE_INTERACTION_CAP).min(1.0);
-    let usage_points  = (deploy_ratio * 0.6 + interact_ratio * 0.4) * WEIGHT_USAGE;
+    let usage_points  = (deploy_ratio * 0.6 + interact_ratio * 0.4) * weights.usage;
     total += usage_points;
     factors.push(TrustFactor {
-        name: "Usage & Adoption",
+        name: FACTOR_USAGE,
         points_earned: usage_points,
-        points_max: WEIGHT_USAGE,
+        points_max: weights.usage,
         explanation: format!(
             "{} deployments and {} interactions recorded. Full marks at {} deployments / {} interactions.",
             input.total_deployments,
@@ -186,12 +253,12 @@ pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
 
     // ── Factor 4: Contract age ────────────────────────────────────────────────
     let age_days = (Utc::now() - input.created_at).num_days().max(0) as f64;
-    let age_points = (age_days / AGE_DAYS_CAP).min(1.0) * WEIGHT_AGE;
+    let age_points = (age_days / AGE_DAYS_CAP).min(1.0) * weights.age;
     total += age_points;
     factors.push(TrustFactor {
-        name: "Contract Age",
+        name: FACTOR_AGE,
         points_earned: age_points,
-        points_max: WEIGHT_AGE,
+        points_max: weights.age,
         explanation: format!(
             "Contract is {:.0} days old. Full age points awarded after {} days.",
             age_days, AGE_DAYS_CAP as i64,
@@ -200,13 +267,13 @@ pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
 
     // ── Factor 5: No critical vulnerabilities ─────────────────────────────────
     // Each unresolved critical vuln deducts from this factor (floored at 0)
-    let vuln_penalty = (input.unresolved_critical_vulns as f64 * 5.0).min(WEIGHT_NO_VULNS);
-    let vuln_points  = (WEIGHT_NO_VULNS - vuln_penalty).max(0.0);
+    let vuln_penalty = (input.unresolved_critical_vulns as f64 * 5.0).min(weights.no_vulns);
+    let vuln_points  = (weights.no_vulns - vuln_penalty).max(0.0);
     total += vuln_points;
     factors.push(TrustFactor {
-        name: "Vulnerability Status",
+        name: FACTOR_NO_VULNS,
         points_earned: vuln_points,
-        points_max: WEIGHT_NO_VULNS,
+        points_max: weights.no_vulns,
         explanation: if input.unresolved_critical_vulns == 0 {
             "No unresolved critical vulnerabilities detected.".into()
         } else {
@@ -237,6 +304,113 @@ pub fn compute_trust_score(input: &TrustInput) -> TrustScore {
     TrustScore { score, badge, badge_icon, factors, summary }
 }
 
+// ── Explanation ───────────────────────────────────────────────────────────────
+
+/// One scored signal, with the raw value it was computed from
+#[derive(Debug, Serialize)]
+pub struct ExplainedSignal {
+    pub name: &'static str,
+    /// Input the factor was computed from (boolean, score, counts, ...)
+    pub raw_value: serde_json::Value,
+    /// Maximum points this factor can contribute
+    pub weight: f64,
+    /// Points this factor contributed to the score
+    pub contribution: f64,
+    pub explanation: String,
+}
+
+/// Something the publisher can do to raise the score
+#[derive(Debug, Serialize)]
+pub struct TrustSuggestion {
+    pub signal: &'static str,
+    /// Points available by fully satisfying the signal
+    pub potential_gain: f64,
+    /// e.g. "get verified: +25"
+    pub message: String,
+}
+
+/// Response for GET /api/contracts/:id/trust-score/explain
+#[derive(Debug, Serialize)]
+pub struct TrustExplanation {
+    pub score: f64,
+    pub badge: &'static str,
+    pub summary: String,
+    pub signals: Vec<ExplainedSignal>,
+    /// Largest gains first
+    pub suggestions: Vec<TrustSuggestion>,
+}
+
+/// Smallest shortfall worth suggesting an action for
+const SUGGESTION_MIN_GAIN: f64 = 0.5;
+
+fn raw_signal(name: &str, input: &TrustInput) -> serde_json::Value {
+    match name {
+        FACTOR_VERIFIED => serde_json::json!(input.is_verified),
+        FACTOR_AUDIT => serde_json::json!(input.latest_audit_score),
+        FACTOR_USAGE => serde_json::json!({
+            "deployments": input.total_deployments,
+            "interactions": input.total_interactions,
+        }),
+        FACTOR_AGE => serde_json::json!({ "days": (Utc::now() - input.created_at).num_days().max(0) }),
+        FACTOR_NO_VULNS => serde_json::json!({ "unresolved_critical": input.unresolved_critical_vulns }),
+        _ => serde_json::Value::Null,
+    }
+}
+
+/// What to do about a factor that is short of full points. Age is not
+/// actionable, so it never produces a suggestion.
+fn suggestion_action(name: &str, input: &TrustInput) -> Option<&'static str> {
+    match name {
+        FACTOR_VERIFIED => Some("get verified"),
+        FACTOR_AUDIT if input.latest_audit_score.is_none() => Some("complete a security audit"),
+        FACTOR_AUDIT => Some("address findings to raise the audit score"),
+        FACTOR_USAGE => Some("grow adoption (deployments and interactions)"),
+        FACTOR_NO_VULNS => Some("resolve critical vulnerabilities"),
+        _ => None,
+    }
+}
+
+/// Explain how the score for `input` is built, reusing the factor breakdown
+/// from [`compute_trust_score_with`].
+pub fn explain_trust_score(input: &TrustInput, weights: &TrustWeights) -> TrustExplanation {
+    let score = compute_trust_score_with(input, weights);
+
+    let mut suggestions: Vec<TrustSuggestion> = score
+        .factors
+        .iter()
+        .filter_map(|f| {
+            let gain = f.points_max - f.points_earned;
+            let action = suggestion_action(f.name, input)?;
+            (gain >= SUGGESTION_MIN_GAIN).then(|| TrustSuggestion {
+                signal: f.name,
+                potential_gain: gain,
+                message: format!("{}: +{:.0}", action, gain),
+            })
+        })
+        .collect();
+    suggestions.sort_by(|a, b| b.potential_gain.total_cmp(&a.potential_gain));
+
+    let signals = score
+        .factors
+        .into_iter()
+        .map(|f| ExplainedSignal {
+            name: f.name,
+            raw_value: raw_signal(f.name, input),
+            weight: f.points_max,
+            contribution: f.points_earned,
+            explanation: f.explanation,
+        })
+        .collect();
+
+    TrustExplanation {
+        score: score.score,
+        badge: score.badge,
+        summary: score.summary,
+        signals,
+        suggestions,
+    }
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
@@ -255,10 +429,11 @@ mod tests {
     }
 
     #[test]
-    fn zero_input_scores_zero_plus_age() {
+    fn zero_input_scores_only_the_vuln_free_points() {
         let score = compute_trust_score(&base_input());
-        // Only age can be > 0 when created_at is now — but it rounds to ~0
-        assert!(score.score < 5.0);
+        // No critical vulns earns the full factor; age rounds to ~0 when created_at is now
+        assert!(score.score >= WEIGHT_NO_VULNS);
+        assert!(score.score < WEIGHT_NO_VULNS + 1.0);
     }
 
     #[test]
@@ -316,4 +491,76 @@ mod tests {
         let score = compute_trust_score(&base_input());
         assert_eq!(score.factors.len(), 5);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn default_weights_are_valid() {
+        assert_eq!(TrustWeights::default().validate(), Ok(()));
+    }
+
+    #[test]
+    fn weights_must_sum_to_100_and_stay_in_range() {
+        let short = TrustWeights { verified: 20.0, ..TrustWeights::default() };
+        assert!(short.validate().unwrap_err().contains("sum to 100"));
+
+        let negative = TrustWeights { verified: -5.0, audit: 65.0, ..TrustWeights::default() };
+        assert!(negative.validate().unwrap_err().contains("verified"));
+    }
+
+    #[test]
+    fn updated_weights_change_the_recomputed_score() {
+        let input = TrustInput { is_verified: true, ..base_input() };
+        let before = compute_trust_score_with(&input, &TrustWeights::default());
+
+        let tuned = TrustWeights { verified: 40.0, audit: 20.0, ..TrustWeights::default() };
+        assert_eq!(tuned.validate(), Ok(()));
+        let after = compute_trust_score_with(&input, &tuned);
+
+        assert!((after.score - before.score - 15.0).abs() < 0.5);
+        let v = after.factors.iter().find(|f| f.name == "Verification Status").unwrap();
+        assert_eq!(v.points_earned, 40.0);
+        assert_eq!(v.points_max, 40.0);
+    }
+
+    #[test]
+    fn explanation_contributions_sum_to_the_score() {
+        let input = TrustInput {
+            is_verified: false,
+            latest_audit_score: Some(80.0),
+            total_deployments: 10,
+            total_interactions: 100,
+            created_at: Utc::now() - chrono::Duration::days(90),
+            unresolved_critical_vulns: 1,
+        };
+        let explanation = explain_trust_score(&input, &TrustWeights::default());
+
+        let sum: f64 = explanation.signals.iter().map(|s| s.contribution).sum();
+        assert!((sum - explanation.score).abs() < 1e-9);
+        assert_eq!(explanation.signals.len(), 5);
+        assert_eq!(explanation.signals[0].raw_value, serde_json::json!(false));
+        assert_eq!(explanation.signals[4].raw_value["unresolved_critical"], 1);
+    }
+
+    #[test]
+    fn explanation_suggests_the_biggest_gains_first() {
+        let explanation = explain_trust_score(&base_input(), &TrustWeights::default());
+
+        assert_eq!(explanation.suggestions[0].message, "complete a security audit: +35");
+        assert_eq!(explanation.suggestions[1].message, "get verified: +25");
+        assert!(explanation.suggestions.iter().all(|s| s.signal != FACTOR_AGE));
+        // Nothing to fix for vulnerabilities
+        assert!(explanation.suggestions.iter().all(|s| s.signal != FACTOR_NO_VULNS));
+    }
+
+    #[test]
+    fn fully_satisfied_signals_have_no_suggestions() {
+        let input = TrustInput {
+            is_verified: true,
+            latest_audit_score: Some(100.0),
+            total_deployments: 1000,
+            total_interactions: 10000,
+            created_at: Utc::now() - chrono::Duration::days(365),
+            unresolved_critical_vulns: 0,
+        };
+        assert!(explain_trust_score(&input, &TrustWeights::default()).suggestions.is_empty());
+    }
+}
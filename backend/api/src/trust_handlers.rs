@@ -0,0 +1,146 @@
+// api/src/trust_handlers.rs
+// Trust scores and their runtime-tunable weights.
+//
+//   GET /api/contracts/:id/trust-score         — score with per-factor breakdown
+//   GET /api/contracts/:id/trust-score/explain — raw signals, contributions, suggestions
+//   GET /api/admin/trust-weights               — current weights
+//   PUT /api/admin/trust-weights               — replace them (validated, persisted)
+//
+// Weights live in the single-row `trust_score_weights` table and are cached
+// in `AppState`. Scores are computed on read, so the next request after an
+// update uses the new weights; cached leaderboards catch up when they expire.
+
+use axum::{
+    extract::{Path, State},
+    Extension, Json,
+};
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    trust::{compute_trust_score_with, explain_trust_score, TrustExplanation, TrustInput, TrustScore, TrustWeights},
+};
+
+#[derive(Debug, FromRow)]
+struct TrustSignals {
+    is_verified: bool,
+    created_at: DateTime<Utc>,
+    latest_audit_score: Option<f64>,
+    total_deployments: i64,
+    total_interactions: i64,
+}
+
+/// Stored weights, or `None` when no override has been saved.
+pub async fn load_weights(pool: &PgPool) -> Result<Option<TrustWeights>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT verified, audit, usage, age, no_vulns FROM trust_score_weights WHERE id = 1",
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+async fn save_weights(pool: &PgPool, weights: &TrustWeights, updated_by: &str) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO trust_score_weights (id, verified, audit, usage, age, no_vulns, updated_by, updated_at)
+         VALUES (1, $1, $2, $3, $4, $5, $6, NOW())
+         ON CONFLICT (id) DO UPDATE SET
+             verified = EXCLUDED.verified,
+             audit = EXCLUDED.audit,
+             usage = EXCLUDED.usage,
+             age = EXCLUDED.age,
+             no_vulns = EXCLUDED.no_vulns,
+             updated_by = EXCLUDED.updated_by,
+             updated_at = NOW()",
+    )
+    .bind(weights.verified)
+    .bind(weights.audit)
+    .bind(weights.usage)
+    .bind(weights.age)
+    .bind(weights.no_vulns)
+    .bind(updated_by)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Collect the scoring inputs for one contract.
+async fn load_trust_input(pool: &PgPool, id: Uuid) -> ApiResult<TrustInput> {
+    let signals: TrustSignals = sqlx::query_as(
+        r#"
+        SELECT c.is_verified, c.created_at,
+               (SELECT sa.overall_score FROM security_audits sa
+                 WHERE sa.contract_id = c.id
+                 ORDER BY sa.audit_date DESC LIMIT 1) AS latest_audit_score,
+               (SELECT COUNT(*) FROM analytics_events ae
+                 WHERE ae.contract_id = c.id AND ae.event_type = 'contract_deployed') AS total_deployments,
+               (SELECT COUNT(*) FROM contract_interactions ci
+                 WHERE ci.contract_id = c.id) AS total_interactions
+        FROM contracts c
+        WHERE c.id = $1
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|err| db_internal_error("load trust signals", err))?
+    .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))?;
+
+    Ok(TrustInput {
+        is_verified: signals.is_verified,
+        latest_audit_score: signals.latest_audit_score,
+        total_deployments: signals.total_deployments,
+        total_interactions: signals.total_interactions,
+        created_at: signals.created_at,
+        // Finding severities live in the static checklist, not the database.
+        unresolved_critical_vulns: 0,
+    })
+}
+
+/// GET /api/contracts/:id/trust-score
+pub async fn get_trust_score(State(state): State<AppState>, Path(id): Path<Uuid>) -> ApiResult<Json<TrustScore>> {
+    let input = load_trust_input(&state.db, id).await?;
+    Ok(Json(compute_trust_score_with(&input, &state.trust_weights())))
+}
+
+/// GET /api/contracts/:id/trust-score/explain
+pub async fn explain_trust(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<TrustExplanation>> {
+    let input = load_trust_input(&state.db, id).await?;
+    Ok(Json(explain_trust_score(&input, &state.trust_weights())))
+}
+
+/// GET /api/admin/trust-weights
+pub async fn get_trust_weights(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<TrustWeights>> {
+    auth.require_admin()?;
+    Ok(Json(state.trust_weights()))
+}
+
+/// PUT /api/admin/trust-weights
+pub async fn update_trust_weights(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(weights): Json<TrustWeights>,
+) -> ApiResult<Json<TrustWeights>> {
+    auth.require_admin()?;
+    weights
+        .validate()
+        .map_err(|msg| ApiError::bad_request("InvalidTrustWeights", msg))?;
+
+    save_weights(&state.db, &weights, &auth.publisher_address)
+        .await
+        .map_err(|err| crate::db_timeout::map_db_error("save trust weights", err))?;
+    state.set_trust_weights(weights);
+
+    tracing::info!(?weights, updated_by = %auth.publisher_address, "trust score weights updated");
+    Ok(Json(weights))
+}
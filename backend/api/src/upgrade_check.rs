@@ -0,0 +1,239 @@
+// api/src/upgrade_check.rs
+// Dependency-aware upgrade safety check.
+//
+//   GET /api/contracts/:id/upgrade-check?to_version=2.0.0
+//
+// For every registered contract that depends on :id, decide whether moving
+// :id to `to_version` would break it:
+//   1. An explicit entry in the compatibility matrix (either direction) is
+//      authoritative — it records what was actually tested.
+//   2. Otherwise the dependent's declared version constraint decides.
+//   3. A constraint that cannot be parsed leaves the dependent `unknown`.
+// The upgrade is `safe` only when no dependent is incompatible; unknown
+// dependents are listed so maintainers can check them by hand.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use shared::{SemVer, VersionConstraint};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct UpgradeCheckQuery {
+    pub to_version: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependentStatus {
+    Compatible,
+    Incompatible,
+    Unknown,
+}
+
+/// A contract that depends on the one being upgraded
+#[derive(Debug, Clone, FromRow)]
+pub struct DependentRow {
+    pub contract_id: Uuid,
+    pub name: String,
+    pub version_constraint: String,
+    /// Matrix verdict for the target version, when one was recorded
+    pub matrix_compatible: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependentVerdict {
+    pub contract_id: Uuid,
+    pub name: String,
+    pub version_constraint: String,
+    pub status: DependentStatus,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UpgradeCheckResponse {
+    pub contract_id: Uuid,
+    pub to_version: String,
+    pub safe: bool,
+    /// Dependents that would break
+    pub affected: Vec<DependentVerdict>,
+    /// Every known dependent, including compatible and unknown ones
+    pub dependents: Vec<DependentVerdict>,
+}
+
+pub fn assess_dependent(row: DependentRow, target: &SemVer) -> DependentVerdict {
+    let (status, reason) = match row.matrix_compatible {
+        Some(false) => (
+            DependentStatus::Incompatible,
+            format!("marked incompatible with {} in the compatibility matrix", target),
+        ),
+        Some(true) => (
+            DependentStatus::Compatible,
+            format!("marked compatible with {} in the compatibility matrix", target),
+        ),
+        None => match VersionConstraint::parse(&row.version_constraint) {
+            Some(constraint) if constraint.matches(target) => (
+                DependentStatus::Compatible,
+                format!("{} satisfies constraint {}", target, row.version_constraint),
+            ),
+            Some(_) => (
+                DependentStatus::Incompatible,
+                format!("{} is outside constraint {}", target, row.version_constraint),
+            ),
+            None => (
+                DependentStatus::Unknown,
+                format!("constraint {:?} could not be parsed", row.version_constraint),
+            ),
+        },
+    };
+
+    DependentVerdict {
+        contract_id: row.contract_id,
+        name: row.name,
+        version_constraint: row.version_constraint,
+        status,
+        reason,
+    }
+}
+
+pub fn build_report(contract_id: Uuid, target: &SemVer, to_version: String, rows: Vec<DependentRow>) -> UpgradeCheckResponse {
+    let dependents: Vec<DependentVerdict> = rows.into_iter().map(|row| assess_dependent(row, target)).collect();
+    let affected: Vec<DependentVerdict> = dependents
+        .iter()
+        .filter(|d| d.status == DependentStatus::Incompatible)
+        .cloned()
+        .collect();
+
+    UpgradeCheckResponse {
+        contract_id,
+        to_version,
+        safe: affected.is_empty(),
+        affected,
+        dependents,
+    }
+}
+
+/// GET /api/contracts/:id/upgrade-check
+pub async fn check_upgrade(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<UpgradeCheckQuery>,
+) -> ApiResult<Json<UpgradeCheckResponse>> {
+    let to_version = query.to_version.trim().to_string();
+    let target = SemVer::parse(&to_version)
+        .ok_or_else(|| ApiError::bad_request("InvalidVersion", "to_version must be valid semver (e.g. 1.2.3)"))?;
+
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("check contract for upgrade", err))?;
+    if !exists {
+        return Err(ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)));
+    }
+
+    // An incompatible entry in either direction outweighs a compatible one
+    let rows: Vec<DependentRow> = sqlx::query_as(
+        r#"
+        SELECT d.contract_id, c.name, d.version_constraint,
+               (SELECT bool_and(m.is_compatible)
+                  FROM contract_version_compatibility m
+                 WHERE (m.source_contract_id = d.contract_id
+                        AND m.target_contract_id = $1 AND m.target_version = $2)
+                    OR (m.source_contract_id = $1 AND m.source_version = $2
+                        AND m.target_contract_id = d.contract_id)) AS matrix_compatible
+        FROM contract_dependencies d
+        JOIN contracts c ON c.id = d.contract_id
+        WHERE d.dependency_contract_id = $1
+        ORDER BY c.name, d.contract_id
+        "#,
+    )
+    .bind(id)
+    .bind(&to_version)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list dependents for upgrade check", err))?;
+
+    Ok(Json(build_report(id, &target, to_version, rows)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dependent(n: u128, constraint: &str, matrix: Option<bool>) -> DependentRow {
+        DependentRow {
+            contract_id: Uuid::from_u128(n),
+            name: format!("dependent-{}", n),
+            version_constraint: constraint.into(),
+            matrix_compatible: matrix,
+        }
+    }
+
+    fn target() -> SemVer {
+        SemVer::parse("2.0.0").unwrap()
+    }
+
+    #[test]
+    fn dependent_marked_incompatible_is_flagged() {
+        let report = build_report(
+            Uuid::nil(),
+            &target(),
+            "2.0.0".into(),
+            vec![dependent(1, "^2.0.0", Some(false)), dependent(2, "^2.0.0", None)],
+        );
+
+        assert!(!report.safe);
+        assert_eq!(report.affected.len(), 1);
+        assert_eq!(report.affected[0].contract_id, Uuid::from_u128(1));
+        assert!(report.affected[0].reason.contains("compatibility matrix"));
+        assert_eq!(report.dependents.len(), 2);
+        assert_eq!(report.dependents[1].status, DependentStatus::Compatible);
+    }
+
+    #[test]
+    fn matrix_verdict_outranks_the_constraint() {
+        // Constraint excludes 2.0.0, but the pair was tested and works
+        let verdict = assess_dependent(dependent(1, "^1.4.0", Some(true)), &target());
+        assert_eq!(verdict.status, DependentStatus::Compatible);
+    }
+
+    #[test]
+    fn constraints_decide_without_a_matrix_entry() {
+        assert_eq!(
+            assess_dependent(dependent(1, "^1.4.0", None), &target()).status,
+            DependentStatus::Incompatible
+        );
+        assert_eq!(
+            assess_dependent(dependent(2, "~2.0.0", None), &target()).status,
+            DependentStatus::Compatible
+        );
+        assert_eq!(
+            assess_dependent(dependent(3, ">=1 <3", None), &target()).status,
+            DependentStatus::Unknown
+        );
+    }
+
+    #[test]
+    fn no_dependents_is_safe() {
+        let report = build_report(Uuid::nil(), &target(), "2.0.0".into(), vec![]);
+        assert!(report.safe);
+        assert!(report.affected.is_empty());
+    }
+
+    #[test]
+    fn unknown_dependents_do_not_block_but_are_listed() {
+        let report = build_report(Uuid::nil(), &target(), "2.0.0".into(), vec![dependent(1, "latest", None)]);
+        assert!(report.safe);
+        assert_eq!(report.dependents[0].status, DependentStatus::Unknown);
+    }
+}
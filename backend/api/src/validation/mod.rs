@@ -60,18 +60,29 @@
 pub mod extractors;
 pub mod requests;
 pub mod sanitizers;
+pub mod spdx;
 pub mod validators;
 
 // Re-export commonly used items
 pub use extractors::{FieldError, Validatable, ValidatedJson, ValidationBuilder, ValidationError};
 pub use sanitizers::{
-    normalize_contract_id, normalize_stellar_address, sanitize_description,
+    normalize_contract_id, normalize_stellar_address, normalize_wasm_hash, sanitize_description,
     sanitize_description_optional, sanitize_name, sanitize_tags, sanitize_url_optional, strip_html,
     trim, trim_optional,
 };
+pub use requests::{parse_publish_request, publish_warnings};
+pub use spdx::{canonical_license_id, normalize_license};
 pub use validators::{
     validate_contract_id, validate_length, validate_network_config_versions, validate_no_html,
     validate_no_xss, validate_required, validate_semver, validate_source_code_size,
     validate_stellar_address, validate_stellar_address_optional, validate_tags, validate_url,
-    validate_url_optional,
+    validate_url_optional, validate_wasm_hash,
 };
+
+/// Normalize and check a `wasm_hash` in handlers that don't take
+/// `ValidatedJson`; a bad hash becomes the usual 400 validation error.
+pub fn parse_wasm_hash(raw: &str) -> Result<String, ValidationError> {
+    let hash = normalize_wasm_hash(raw);
+    validate_wasm_hash(&hash).map_err(|message| ValidationError::single("wasm_hash", message))?;
+    Ok(hash)
+}
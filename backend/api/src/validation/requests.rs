@@ -3,19 +3,22 @@
 //! This module implements the `Validatable` trait for all request types
 //! that need validation when received from clients.
 
+use serde::Deserialize;
 use shared::models::{
-    CreateMigrationRequest, DependencyDeclaration, PublishRequest, UpdateMigrationStatusRequest,
-    VerifyRequest,
+    CreateMigrationRequest, DependencyDeclaration, MaturityLevel, Network, PublishRequest,
+    UpdateMigrationStatusRequest, VerifyRequest,
 };
 
-use super::extractors::{FieldError, Validatable, ValidationBuilder};
+use super::extractors::{FieldError, Validatable, ValidationBuilder, ValidationError};
+use super::spdx::normalize_license;
 use super::sanitizers::{
-    normalize_contract_id, normalize_stellar_address, sanitize_description_optional, sanitize_name,
-    sanitize_tags, sanitize_url_optional, trim,
+    normalize_contract_id, normalize_stellar_address, normalize_wasm_hash,
+    sanitize_description_optional, sanitize_name, sanitize_tags, sanitize_url_optional, trim,
 };
 use super::validators::{
     validate_contract_id, validate_json_depth, validate_length, validate_no_xss, validate_semver,
     validate_source_code_size, validate_stellar_address, validate_tags, validate_url_optional,
+    validate_wasm_hash,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -38,8 +41,6 @@ const MAX_SOURCE_CODE_BYTES: usize = 1024 * 1024;
 const MAX_JSON_DEPTH: usize = 10;
 /// Maximum length for category
 const MAX_CATEGORY_LENGTH: usize = 100;
-/// Maximum length for wasm hash
-const MAX_WASM_HASH_LENGTH: usize = 64;
 /// Maximum length for dependency name
 const MAX_DEPENDENCY_NAME_LENGTH: usize = 255;
 /// Maximum length for version constraint
@@ -79,6 +80,16 @@ impl Validatable for PublishRequest {
         // Sanitize tags
         self.tags = sanitize_tags(&self.tags);
 
+        // Canonical SPDX spelling; unknown ids are left for validate() to report
+        if let Some(ref mut license) = self.license {
+            *license = trim(license);
+            if license.is_empty() {
+                self.license = None;
+            } else if let Ok(canonical) = normalize_license(license) {
+                *license = canonical;
+            }
+        }
+
         // Sanitize dependencies
         for dep in &mut self.dependencies {
             dep.name = trim(&dep.name);
@@ -130,6 +141,11 @@ impl Validatable for PublishRequest {
             validate_tags(&self.tags, MAX_TAGS_COUNT, MAX_TAG_LENGTH)
         });
 
+        // license: optional, known SPDX identifiers only
+        if let Some(ref license) = self.license {
+            builder.check("license", || normalize_license(license).map(|_| ()));
+        }
+
         // dependencies: validate each
         builder.check("dependencies", || {
             if self.dependencies.len() > MAX_DEPENDENCIES_COUNT {
@@ -170,6 +186,84 @@ impl Validatable for PublishRequest {
     }
 }
 
+/// Publish body read leniently, so that a bad `network` or a missing field
+/// is reported alongside every other problem instead of aborting the parse.
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct PublishDraft {
+    contract_id: String,
+    name: String,
+    description: Option<String>,
+    network: Option<String>,
+    category: Option<String>,
+    tags: Vec<String>,
+    source_url: Option<String>,
+    publisher_address: String,
+    dependencies: Vec<DependencyDeclaration>,
+    license: Option<String>,
+}
+
+fn parse_network(raw: Option<&str>) -> Result<Network, String> {
+    let raw = raw.map(str::trim).filter(|n| !n.is_empty()).ok_or("network is required")?;
+    serde_json::from_value(serde_json::Value::String(raw.to_ascii_lowercase()))
+        .map_err(|_| format!("unknown network '{}'; expected mainnet, testnet or futurenet", raw))
+}
+
+/// Parse, sanitize and validate a publish body, reporting every field error
+/// at once.
+pub fn parse_publish_request(body: serde_json::Value) -> Result<PublishRequest, ValidationError> {
+    let draft: PublishDraft = serde_json::from_value(body)
+        .map_err(|err| ValidationError::single("body", format!("Invalid JSON data: {}", err)))?;
+
+    let network = parse_network(draft.network.as_deref());
+    let mut req = PublishRequest {
+        contract_id: draft.contract_id,
+        name: draft.name,
+        description: draft.description,
+        // Placeholder only; the request is rejected below when network is invalid
+        network: network.clone().unwrap_or(Network::Testnet),
+        category: draft.category,
+        tags: draft.tags,
+        source_url: draft.source_url,
+        publisher_address: draft.publisher_address,
+        dependencies: draft.dependencies,
+        license: draft.license,
+    };
+    req.sanitize();
+
+    let mut errors = req.validate().err().unwrap_or_default();
+    if let Err(message) = network {
+        errors.push(FieldError::new("network", message));
+    }
+    if errors.is_empty() {
+        Ok(req)
+    } else {
+        Err(ValidationError::new(errors))
+    }
+}
+
+/// Non-fatal issues with a publish that passed validation. The contract is
+/// still created; these are returned in the response's `warnings`.
+pub fn publish_warnings(req: &PublishRequest, maturity: &MaturityLevel) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if req.description.as_deref().map_or(true, |d| d.trim().is_empty()) {
+        warnings.push("No description provided; contracts without one rank lower in search".to_string());
+    }
+    if req.category.is_none() {
+        warnings.push("No category set; the contract will not appear in category listings".to_string());
+    }
+    if req.tags.is_empty() {
+        warnings.push("No tags set; add tags to make the contract easier to discover".to_string());
+    }
+    if req.source_url.is_none() {
+        warnings.push("No source_url provided; the contract cannot be source-verified until one is added".to_string());
+    }
+    if *maturity == MaturityLevel::Alpha {
+        warnings.push("Contract is at alpha maturity; promote it once it is ready for production use".to_string());
+    }
+    warnings
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // VerifyRequest validation
 // ─────────────────────────────────────────────────────────────────────────────
@@ -227,7 +321,7 @@ impl Validatable for VerifyRequest {
 impl Validatable for CreateMigrationRequest {
     fn sanitize(&mut self) {
         self.contract_id = normalize_contract_id(&self.contract_id);
-        self.wasm_hash = trim(&self.wasm_hash);
+        self.wasm_hash = normalize_wasm_hash(&self.wasm_hash);
     }
 
     fn validate(&self) -> Result<(), Vec<FieldError>> {
@@ -235,12 +329,7 @@ impl Validatable for CreateMigrationRequest {
 
         builder.check("contract_id", || validate_contract_id(&self.contract_id));
 
-        builder.check("wasm_hash", || {
-            if self.wasm_hash.is_empty() {
-                return Err("wasm_hash is required".to_string());
-            }
-            validate_length(&self.wasm_hash, 1, MAX_WASM_HASH_LENGTH)
-        });
+        builder.check("wasm_hash", || validate_wasm_hash(&self.wasm_hash));
 
         builder.build()
     }
@@ -301,7 +390,6 @@ impl Validatable for DependencyDeclaration {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use shared::models::Network;
 
     fn valid_contract_id() -> String {
         "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".to_string()
@@ -311,6 +399,46 @@ mod tests {
         "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".to_string()
     }
 
+    #[test]
+    fn publish_without_description_is_valid_but_warned() {
+        let req = parse_publish_request(serde_json::json!({
+            "contract_id": valid_contract_id(),
+            "name": "My Contract",
+            "description": "   ",
+            "network": "testnet",
+            "category": "DeFi",
+            "tags": ["token"],
+            "source_url": "https://github.com/user/repo",
+            "publisher_address": valid_stellar_address(),
+        }))
+        .expect("a missing description is not a hard error");
+
+        let warnings = publish_warnings(&req, &MaturityLevel::Beta);
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("description"));
+    }
+
+    #[test]
+    fn complete_publish_only_warns_about_alpha_maturity() {
+        let req = PublishRequest {
+            contract_id: valid_contract_id(),
+            name: "My Contract".to_string(),
+            description: Some("A test contract".to_string()),
+            network: Network::Testnet,
+            category: Some("DeFi".to_string()),
+            tags: vec!["token".to_string()],
+            source_url: Some("https://github.com/user/repo".to_string()),
+            publisher_address: valid_stellar_address(),
+            dependencies: vec![],
+            license: None,
+        };
+
+        assert!(publish_warnings(&req, &MaturityLevel::Stable).is_empty());
+        let alpha = publish_warnings(&req, &MaturityLevel::Alpha);
+        assert_eq!(alpha.len(), 1);
+        assert!(alpha[0].contains("alpha"));
+    }
+
     #[test]
     fn test_publish_request_valid() {
         let req = PublishRequest {
@@ -323,6 +451,7 @@ mod tests {
             source_url: Some("https://github.com/user/repo".to_string()),
             publisher_address: valid_stellar_address(),
             dependencies: vec![],
+            license: None,
         };
 
         assert!(req.validate().is_ok());
@@ -340,6 +469,7 @@ mod tests {
             source_url: None,
             publisher_address: valid_stellar_address(),
             dependencies: vec![],
+            license: None,
         };
 
         let result = req.validate();
@@ -360,6 +490,7 @@ mod tests {
             source_url: None,
             publisher_address: valid_stellar_address(),
             dependencies: vec![],
+            license: None,
         };
 
         let result = req.validate();
@@ -381,6 +512,7 @@ mod tests {
             publisher_address: "  gdlzfc3syjydzt7k67vz75hpjvieuvnixf47zg2fb2rmqqvu2hhgcysc  "
                 .to_string(),
             dependencies: vec![],
+            license: None,
         };
 
         req.sanitize();
@@ -461,6 +593,7 @@ mod tests {
             source_url: None,
             publisher_address: valid_stellar_address(),
             dependencies: vec![],
+            license: None,
         };
 
         let result = req.validate();
@@ -468,4 +601,90 @@ mod tests {
         let errors = result.unwrap_err();
         assert!(errors.iter().any(|e| e.field == "tags"));
     }
+
+    #[test]
+    fn publish_license_is_canonicalized_or_rejected() {
+        let body = |license: &str| {
+            serde_json::json!({
+                "contract_id": valid_contract_id(),
+                "name": "My Contract",
+                "network": "testnet",
+                "publisher_address": valid_stellar_address(),
+                "license": license,
+            })
+        };
+
+        let req = parse_publish_request(body(" mit or apache-2.0 ")).unwrap();
+        assert_eq!(req.license.as_deref(), Some("MIT OR Apache-2.0"));
+        assert_eq!(parse_publish_request(body("")).unwrap().license, None);
+
+        let errors = parse_publish_request(body("Totally-Free-1.0")).unwrap_err().errors;
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field, "license");
+        assert!(errors[0].message.contains("Totally-Free-1.0"));
+    }
+
+    #[test]
+    fn test_parse_publish_request_reports_all_errors() {
+        let body = serde_json::json!({
+            "contract_id": "not-a-contract",
+            "name": "x".repeat(MAX_NAME_LENGTH + 1),
+            "network": "devnet",
+            "tags": (0..15).map(|i| format!("tag{}", i)).collect::<Vec<_>>(),
+            "publisher_address": "GSHORT",
+        });
+
+        let errors = parse_publish_request(body).unwrap_err().errors;
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        for field in ["contract_id", "name", "network", "tags", "publisher_address"] {
+            assert!(fields.contains(&field), "missing error for {}: {:?}", field, fields);
+        }
+    }
+
+    #[test]
+    fn test_parse_publish_request_reports_missing_fields() {
+        let errors = parse_publish_request(serde_json::json!({})).unwrap_err().errors;
+        let fields: Vec<&str> = errors.iter().map(|e| e.field.as_str()).collect();
+        assert!(fields.contains(&"contract_id"));
+        assert!(fields.contains(&"name"));
+        assert!(fields.contains(&"network"));
+        assert!(fields.contains(&"publisher_address"));
+    }
+
+    #[test]
+    fn test_parse_publish_request_accepts_valid_body() {
+        let req = parse_publish_request(serde_json::json!({
+            "contract_id": valid_contract_id().to_lowercase(),
+            "name": " My Contract ",
+            "network": "Mainnet",
+            "tags": ["token"],
+            "publisher_address": valid_stellar_address(),
+        }))
+        .unwrap();
+
+        assert_eq!(req.contract_id, valid_contract_id());
+        assert_eq!(req.name, "My Contract");
+        assert!(matches!(req.network, Network::Mainnet));
+    }
+
+    #[test]
+    fn migration_wasm_hash_is_normalized_and_checked() {
+        let mut req = CreateMigrationRequest {
+            contract_id: valid_contract_id(),
+            wasm_hash: format!("  {}  ", "AB".repeat(32)),
+        };
+        req.sanitize();
+        assert!(req.validate().is_ok());
+        assert_eq!(req.wasm_hash, "ab".repeat(32));
+
+        for bad in ["placeholder_hash".to_string(), "ab".repeat(40)] {
+            let mut req = CreateMigrationRequest {
+                contract_id: valid_contract_id(),
+                wasm_hash: bad,
+            };
+            req.sanitize();
+            let errors = req.validate().unwrap_err();
+            assert_eq!(errors[0].field, "wasm_hash");
+        }
+    }
 }
@@ -67,6 +67,11 @@ pub fn normalize_contract_id(contract_id: &str) -> String {
     contract_id.trim().to_uppercase()
 }
 
+/// Normalize a WASM hash: lowercase and trim
+pub fn normalize_wasm_hash(hash: &str) -> String {
+    hash.trim().to_ascii_lowercase()
+}
+
 /// Sanitize a name field: trim, remove control chars, strip HTML
 pub fn sanitize_name(name: &str) -> String {
     let trimmed = trim(name);
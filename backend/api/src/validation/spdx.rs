@@ -0,0 +1,216 @@
+//! SPDX license identifiers
+//!
+//! Contracts declare their license as an SPDX identifier (`MIT`) or a simple
+//! expression combining identifiers with `AND` / `OR` / `WITH`
+//! (`MIT OR Apache-2.0`, `GPL-2.0-or-later WITH Classpath-exception-2.0`).
+//! Identifiers are matched case-insensitively and stored in their canonical
+//! SPDX spelling, so `?license=` filters compare like with like.
+//! `LicenseRef-*` identifiers are accepted for proprietary or custom terms.
+
+/// Licenses from the SPDX License List accepted by the registry
+pub const SPDX_LICENSE_IDS: &[&str] = &[
+    "0BSD",
+    "AFL-3.0",
+    "AGPL-3.0-only",
+    "AGPL-3.0-or-later",
+    "Apache-1.1",
+    "Apache-2.0",
+    "APSL-2.0",
+    "Artistic-2.0",
+    "BlueOak-1.0.0",
+    "BSD-1-Clause",
+    "BSD-2-Clause",
+    "BSD-2-Clause-Patent",
+    "BSD-3-Clause",
+    "BSD-3-Clause-Clear",
+    "BSD-4-Clause",
+    "BSL-1.0",
+    "BUSL-1.1",
+    "CAL-1.0",
+    "CC-BY-4.0",
+    "CC-BY-SA-4.0",
+    "CC-BY-NC-4.0",
+    "CC-BY-NC-SA-4.0",
+    "CC0-1.0",
+    "CDDL-1.0",
+    "CDDL-1.1",
+    "CECILL-2.1",
+    "ECL-2.0",
+    "EFL-2.0",
+    "Elastic-2.0",
+    "EPL-1.0",
+    "EPL-2.0",
+    "EUPL-1.1",
+    "EUPL-1.2",
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+    "ISC",
+    "LGPL-2.0-only",
+    "LGPL-2.0-or-later",
+    "LGPL-2.1-only",
+    "LGPL-2.1-or-later",
+    "LGPL-3.0-only",
+    "LGPL-3.0-or-later",
+    "LPPL-1.3c",
+    "MirOS",
+    "MIT",
+    "MIT-0",
+    "MPL-1.1",
+    "MPL-2.0",
+    "MPL-2.0-no-copyleft-exception",
+    "MS-PL",
+    "MS-RL",
+    "MulanPSL-2.0",
+    "NCSA",
+    "ODbL-1.0",
+    "OFL-1.1",
+    "OSL-3.0",
+    "PolyForm-Noncommercial-1.0.0",
+    "PolyForm-Small-Business-1.0.0",
+    "PostgreSQL",
+    "Python-2.0",
+    "SSPL-1.0",
+    "Unicode-3.0",
+    "Unicode-DFS-2016",
+    "Unlicense",
+    "UPL-1.0",
+    "W3C",
+    "WTFPL",
+    "Zlib",
+    "ZPL-2.1",
+];
+
+/// License exceptions allowed after `WITH`
+pub const SPDX_EXCEPTION_IDS: &[&str] = &[
+    "Classpath-exception-2.0",
+    "GCC-exception-3.1",
+    "LLVM-exception",
+    "OpenJDK-assembly-exception-1.0",
+];
+
+const LICENSE_REF_PREFIX: &str = "LicenseRef-";
+
+/// Canonical spelling of a known license identifier
+pub fn canonical_license_id(id: &str) -> Option<String> {
+    let prefix = id.get(..LICENSE_REF_PREFIX.len());
+    if prefix.is_some_and(|p| p.eq_ignore_ascii_case(LICENSE_REF_PREFIX)) {
+        let suffix = &id[LICENSE_REF_PREFIX.len()..];
+        let valid = !suffix.is_empty()
+            && suffix.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.');
+        return valid.then(|| format!("{}{}", LICENSE_REF_PREFIX, suffix));
+    }
+    SPDX_LICENSE_IDS
+        .iter()
+        .find(|known| known.eq_ignore_ascii_case(id))
+        .map(|known| known.to_string())
+}
+
+fn canonical_exception_id(id: &str) -> Option<&'static str> {
+    SPDX_EXCEPTION_IDS.iter().copied().find(|known| known.eq_ignore_ascii_case(id))
+}
+
+/// Check a license expression and return it in canonical form: canonical
+/// identifiers, upper-case operators, single spaces.
+pub fn normalize_license(expression: &str) -> Result<String, String> {
+    let spaced = expression.replace('(', " ( ").replace(')', " ) ");
+    let tokens: Vec<&str> = spaced.split_whitespace().collect();
+    if tokens.is_empty() {
+        return Err("license must not be empty".to_string());
+    }
+
+    let mut out: Vec<String> = Vec::with_capacity(tokens.len());
+    let mut depth = 0usize;
+    // true when the next token must be a license (or an opening parenthesis)
+    let mut expect_operand = true;
+    let mut after_with = false;
+
+    for token in tokens {
+        match token {
+            "(" if expect_operand => {
+                depth += 1;
+                out.push(token.to_string());
+            }
+            ")" if !expect_operand && depth > 0 => {
+                depth -= 1;
+                out.push(token.to_string());
+            }
+            "(" | ")" => return Err(format!("unexpected '{}' in license expression", token)),
+            _ if expect_operand => {
+                let canonical = if after_with {
+                    canonical_exception_id(token).map(str::to_string)
+                } else {
+                    // A trailing `+` means "this version or later"
+                    canonical_license_id(token.trim_end_matches('+'))
+                        .map(|id| if token.ends_with('+') { id + "+" } else { id })
+                };
+                let Some(canonical) = canonical else {
+                    let kind = if after_with { "license exception" } else { "SPDX license identifier" };
+                    return Err(format!("'{}' is not a known {}", token, kind));
+                };
+                out.push(canonical);
+                expect_operand = false;
+                after_with = false;
+            }
+            _ => {
+                let operator = token.to_ascii_uppercase();
+                if !matches!(operator.as_str(), "AND" | "OR" | "WITH") {
+                    return Err(format!("expected AND, OR or WITH, found '{}'", token));
+                }
+                after_with = operator == "WITH";
+                out.push(operator);
+                expect_operand = true;
+            }
+        }
+    }
+
+    if expect_operand {
+        return Err("license expression is incomplete".to_string());
+    }
+    if depth > 0 {
+        return Err("unbalanced parentheses in license expression".to_string());
+    }
+    Ok(out.join(" ").replace("( ", "(").replace(" )", ")"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_identifiers_are_canonicalized() {
+        assert_eq!(normalize_license("mit").unwrap(), "MIT");
+        assert_eq!(normalize_license(" apache-2.0 ").unwrap(), "Apache-2.0");
+        assert_eq!(normalize_license("licenseref-acme-eula").unwrap(), "LicenseRef-acme-eula");
+    }
+
+    #[test]
+    fn expressions_are_validated_per_identifier() {
+        assert_eq!(
+            normalize_license("mit or Apache-2.0").unwrap(),
+            "MIT OR Apache-2.0"
+        );
+        assert_eq!(
+            normalize_license("(MIT OR Apache-2.0) and BSD-3-Clause").unwrap(),
+            "(MIT OR Apache-2.0) AND BSD-3-Clause"
+        );
+        assert_eq!(
+            normalize_license("GPL-2.0-or-later WITH classpath-exception-2.0").unwrap(),
+            "GPL-2.0-or-later WITH Classpath-exception-2.0"
+        );
+    }
+
+    #[test]
+    fn unknown_or_malformed_licenses_are_rejected() {
+        assert!(normalize_license("").is_err());
+        assert!(normalize_license("MIT-ish").unwrap_err().contains("not a known SPDX"));
+        assert!(normalize_license("MIT OR Proprietary").is_err());
+        assert!(normalize_license("MIT WITH Apache-2.0").unwrap_err().contains("exception"));
+        assert!(normalize_license("MIT Apache-2.0").is_err());
+        assert!(normalize_license("MIT OR").is_err());
+        assert!(normalize_license("(MIT OR Apache-2.0").is_err());
+        assert!(normalize_license("MIT)").is_err());
+        assert!(normalize_license("LicenseRef-").is_err());
+    }
+}
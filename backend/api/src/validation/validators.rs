@@ -7,9 +7,6 @@ use lazy_static::lazy_static;
 use regex::Regex;
 
 lazy_static! {
-    /// Stellar contract ID pattern: 56 characters starting with 'C'
-    static ref CONTRACT_ID_REGEX: Regex = Regex::new(r"^C[A-Z0-9]{55}$").unwrap();
-    
     /// Stellar address pattern: 56 characters starting with 'G'
     static ref STELLAR_ADDRESS_REGEX: Regex = Regex::new(r"^G[A-Z0-9]{55}$").unwrap();
     
@@ -70,7 +67,7 @@ pub fn validate_length_with_field(
 }
 
 /// Validate Stellar contract ID format
-/// Must be 56 characters starting with 'C'
+/// Must be a `C...` strkey with a valid checksum; account (`G...`) addresses are rejected
 pub fn validate_contract_id(contract_id: &str) -> Result<(), String> {
     let trimmed = contract_id.trim();
     
@@ -78,12 +75,28 @@ pub fn validate_contract_id(contract_id: &str) -> Result<(), String> {
         return Err("contract_id is required".to_string());
     }
     
-    if !CONTRACT_ID_REGEX.is_match(trimmed) {
-        return Err(
-            "must be a valid Stellar contract ID (56 characters starting with 'C')".to_string()
-        );
+    crate::stellar::validate_contract_id(trimmed)
+        .map(|_| ())
+        .map_err(|err| format!("must be a valid Stellar contract ID: {}", err))
+}
+
+/// Length of a hex-encoded SHA-256 digest
+pub const WASM_HASH_HEX_LENGTH: usize = 64;
+
+/// Validate a WASM hash: a SHA-256 digest as 64 lowercase hex characters.
+/// Normalize with `normalize_wasm_hash` first to accept uppercase input.
+pub fn validate_wasm_hash(hash: &str) -> Result<(), String> {
+    if hash.is_empty() {
+        return Err("wasm_hash is required".to_string());
+    }
+    if hash.len() != WASM_HASH_HEX_LENGTH
+        || !hash.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    {
+        return Err(format!(
+            "must be a SHA-256 hash ({} lowercase hex characters)",
+            WASM_HASH_HEX_LENGTH
+        ));
     }
-    
     Ok(())
 }
 
@@ -279,6 +292,21 @@ mod tests {
         assert!(validate_contract_id("").is_err());
     }
 
+    #[test]
+    fn test_validate_wasm_hash() {
+        let valid = "ab".repeat(32);
+        assert!(validate_wasm_hash(&valid).is_ok());
+
+        // Not hex
+        assert!(validate_wasm_hash(&"zz".repeat(32)).is_err());
+        // Oversized
+        assert!(validate_wasm_hash(&"ab".repeat(33)).is_err());
+        // Too short, empty, and not yet normalized
+        assert!(validate_wasm_hash("abc123").is_err());
+        assert!(validate_wasm_hash("").is_err());
+        assert!(validate_wasm_hash(&"AB".repeat(32)).is_err());
+    }
+
     #[test]
     fn test_validate_stellar_address() {
         // Valid address
@@ -0,0 +1,573 @@
+// api/src/verification_handlers.rs
+// Source verification requests and the latest verification result.
+//
+// Failed attempts keep their build log so publishers can see why the build
+// did not reproduce the deployed bytecode. `build_params` is stored as
+// submitted and redacted on the way out.
+//
+// POST /api/contracts/verification-status answers "is it verified?" for many
+// contracts at once, for dashboards.
+//
+// A successful attempt also stores a reproducibility report (compiler version,
+// build flags, source hash and the resulting wasm hash), served at
+// GET /api/contracts/:id/verification/report.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use chrono::{DateTime, Utc};
+use sha2::{Digest, Sha256};
+use shared::{Verification, VerificationStatus, VerifyRequest};
+use sqlx::FromRow;
+use std::collections::HashSet;
+use uuid::Uuid;
+
+use crate::{
+    audit::{self, AuditEntry},
+    error::{ApiError, ApiResult},
+    state::AppState,
+    validation::ValidatedJson,
+};
+
+/// Upper bound on the stored build log; the tail is kept since that is
+/// where compiler errors end up.
+pub const MAX_BUILD_LOG_BYTES: usize = 64 * 1024;
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Most ids accepted by one bulk status request
+pub const MAX_BULK_STATUS_IDS: usize = 100;
+
+/// Substrings that mark a build parameter as sensitive
+const SECRET_KEY_MARKERS: &[&str] = &[
+    "secret",
+    "token",
+    "password",
+    "passwd",
+    "private_key",
+    "api_key",
+    "apikey",
+    "credential",
+    "mnemonic",
+    "seed",
+    "authorization",
+];
+
+/// Why a verification attempt failed
+#[derive(Debug, Clone)]
+pub struct VerificationFailure {
+    pub error_message: String,
+    pub build_log: Option<String>,
+}
+
+/// Keep at most `MAX_BUILD_LOG_BYTES` of `log`, dropping the oldest output.
+pub fn truncate_build_log(log: &str) -> String {
+    if log.len() <= MAX_BUILD_LOG_BYTES {
+        return log.to_string();
+    }
+
+    let mut start = log.len() - MAX_BUILD_LOG_BYTES;
+    while !log.is_char_boundary(start) {
+        start += 1;
+    }
+    format!("[truncated {} bytes]\n{}", start, &log[start..])
+}
+
+fn is_secret_key(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    SECRET_KEY_MARKERS.iter().any(|marker| key.contains(marker))
+}
+
+/// Replace the values of sensitive-looking keys, at any depth.
+pub fn redact_build_params(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(k, v)| {
+                    let v = if is_secret_key(&k) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_build_params(v)
+                    };
+                    (k, v)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(redact_build_params).collect()),
+        other => other,
+    }
+}
+
+/// Prepare a verification row for an API response.
+pub fn redact_verification(mut verification: Verification) -> Verification {
+    verification.build_params = verification.build_params.map(redact_build_params);
+    verification
+}
+
+/// Everything needed to rebuild a verified contract and check the result
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ReproducibilityReport {
+    pub verification_id: Uuid,
+    pub contract_id: Uuid,
+    pub compiler_version: Option<String>,
+    pub build_params: Option<Value>,
+    /// Hex SHA-256 of the submitted source
+    pub source_hash: String,
+    /// Hash of the bytecode the build reproduced
+    pub wasm_hash: String,
+    pub verified_at: DateTime<Utc>,
+}
+
+impl ReproducibilityReport {
+    /// The report for a successful attempt; `None` for any other status.
+    pub fn for_verification(verification: &Verification, wasm_hash: &str) -> Option<Self> {
+        if !matches!(verification.status, VerificationStatus::Verified) {
+            return None;
+        }
+        Some(Self {
+            verification_id: verification.id,
+            contract_id: verification.contract_id,
+            compiler_version: verification.compiler_version.clone(),
+            build_params: verification.build_params.clone(),
+            source_hash: source_hash(verification.source_code.as_deref().unwrap_or_default()),
+            wasm_hash: wasm_hash.to_string(),
+            verified_at: verification.verified_at.unwrap_or(verification.created_at),
+        })
+    }
+}
+
+pub fn source_hash(source: &str) -> String {
+    hex::encode(Sha256::digest(source.as_bytes()))
+}
+
+pub(crate) async fn store_report<'e, E>(db: E, report: &ReproducibilityReport) -> Result<(), sqlx::Error>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        "INSERT INTO verification_reports (verification_id, contract_id, compiler_version, build_params,
+                                           source_hash, wasm_hash, verified_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         ON CONFLICT (verification_id) DO NOTHING",
+    )
+    .bind(report.verification_id)
+    .bind(report.contract_id)
+    .bind(&report.compiler_version)
+    .bind(&report.build_params)
+    .bind(&report.source_hash)
+    .bind(&report.wasm_hash)
+    .bind(report.verified_at)
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Build `source` and compare the result against the deployed `wasm_hash`.
+pub(crate) async fn run_verifier(source: &str, wasm_hash: &str) -> Result<(), VerificationFailure> {
+    match verifier::verify_contract(source, wasm_hash).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(VerificationFailure {
+            error_message: "Compiled bytecode does not match the deployed WASM hash".to_string(),
+            build_log: None,
+        }),
+        Err(err) => Err(VerificationFailure {
+            error_message: "Build failed".to_string(),
+            build_log: Some(err.to_string()),
+        }),
+    }
+}
+
+/// Status, `verified_at`, error message and build log recorded for an outcome
+pub(crate) fn outcome_columns(
+    outcome: Result<(), VerificationFailure>,
+) -> (VerificationStatus, Option<DateTime<Utc>>, Option<String>, Option<String>) {
+    match outcome {
+        Ok(()) => (VerificationStatus::Verified, Some(Utc::now()), None, None),
+        Err(failure) => (
+            VerificationStatus::Failed,
+            None,
+            Some(failure.error_message),
+            failure.build_log.as_deref().map(truncate_build_log),
+        ),
+    }
+}
+
+/// Persist the outcome of one verification attempt.
+pub async fn record_verification_attempt(
+    db: &sqlx::PgPool,
+    contract_id: Uuid,
+    req: &VerifyRequest,
+    outcome: Result<(), VerificationFailure>,
+) -> Result<Verification, sqlx::Error> {
+    let (status, verified_at, error_message, build_log) = outcome_columns(outcome);
+
+    sqlx::query_as(
+        "INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version,
+                                    verified_at, error_message, build_log)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING *",
+    )
+    .bind(contract_id)
+    .bind(status)
+    .bind(&req.source_code)
+    .bind(&req.build_params)
+    .bind(&req.compiler_version)
+    .bind(verified_at)
+    .bind(error_message)
+    .bind(build_log)
+    .fetch_one(db)
+    .await
+}
+
+/// POST /api/contracts/verify
+pub async fn verify_contract(
+    State(state): State<AppState>,
+    ValidatedJson(req): ValidatedJson<VerifyRequest>,
+) -> ApiResult<Json<Verification>> {
+    let (contract_uuid, wasm_hash, was_verified): (Uuid, String, bool) =
+        sqlx::query_as("SELECT id, wasm_hash, is_verified FROM contracts WHERE contract_id = $1 LIMIT 1")
+            .bind(&req.contract_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| db_err("look up contract for verification", err))?
+            .ok_or_else(|| {
+                ApiError::not_found(
+                    "ContractNotFound",
+                    format!("No contract found with ID: {}", req.contract_id),
+                )
+            })?;
+
+    let outcome = run_verifier(&req.source_code, &wasm_hash).await;
+    let verified = outcome.is_ok();
+
+    let verification = record_verification_attempt(&state.db, contract_uuid, &req, outcome)
+        .await
+        .map_err(|err| db_err("record verification", err))?;
+
+    if let Some(report) = ReproducibilityReport::for_verification(&verification, &wasm_hash) {
+        store_report(&state.db, &report)
+            .await
+            .map_err(|err| db_err("store reproducibility report", err))?;
+    }
+
+    if verified {
+        sqlx::query("UPDATE contracts SET is_verified = TRUE, updated_at = NOW() WHERE id = $1")
+            .bind(contract_uuid)
+            .execute(&state.db)
+            .await
+            .map_err(|err| db_err("mark contract verified", err))?;
+    }
+
+    audit::record(&state.db, &AuditEntry::verification_changed(was_verified, &verification))
+        .await
+        .map_err(|err| db_err("record verification audit", err))?;
+
+    Ok(Json(redact_verification(verification)))
+}
+
+/// GET /api/contracts/:id/verification — the most recent attempt, including
+/// the build log when it failed.
+pub async fn get_latest_verification(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+) -> ApiResult<Json<Verification>> {
+    let verification: Verification = sqlx::query_as(
+        "SELECT * FROM verifications WHERE contract_id = $1 ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("get latest verification", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "VerificationNotFound",
+            format!("No verification found for contract {}", contract_id),
+        )
+    })?;
+
+    Ok(Json(redact_verification(verification)))
+}
+
+/// GET /api/contracts/:id/verification/report — inputs and output of the most
+/// recent successful verification. Build params are redacted like everywhere else.
+pub async fn get_verification_report(
+    State(state): State<AppState>,
+    Path(contract_id): Path<Uuid>,
+) -> ApiResult<Json<ReproducibilityReport>> {
+    let mut report: ReproducibilityReport = sqlx::query_as(
+        "SELECT * FROM verification_reports WHERE contract_id = $1 ORDER BY verified_at DESC LIMIT 1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("get verification report", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "ReportNotFound",
+            format!("No successful verification recorded for contract {}", contract_id),
+        )
+    })?;
+
+    report.build_params = report.build_params.map(redact_build_params);
+    Ok(Json(report))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct BulkVerificationStatusRequest {
+    /// Stellar contract ids or registry UUIDs
+    pub contract_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ContractVerificationStatus {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub network: shared::Network,
+    pub is_verified: bool,
+    /// Status of the most recent attempt; `None` if never submitted
+    pub latest_status: Option<VerificationStatus>,
+    pub verified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RequestedStatus {
+    /// The id as it appeared in the request
+    pub requested_id: String,
+    /// One entry per network the contract is registered on
+    pub contracts: Vec<ContractVerificationStatus>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BulkVerificationStatusResponse {
+    pub statuses: Vec<RequestedStatus>,
+    /// Requested ids that match no registered contract
+    pub unknown: Vec<String>,
+}
+
+/// Trim, drop blanks and de-duplicate, keeping request order.
+pub fn normalize_status_ids(ids: Vec<String>) -> ApiResult<Vec<String>> {
+    let mut seen = HashSet::new();
+    let ids: Vec<String> = ids
+        .into_iter()
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty() && seen.insert(id.clone()))
+        .collect();
+
+    if ids.is_empty() {
+        return Err(ApiError::bad_request("EmptyIdList", "contract_ids must not be empty"));
+    }
+    if ids.len() > MAX_BULK_STATUS_IDS {
+        return Err(ApiError::bad_request(
+            "TooManyIds",
+            format!("at most {} contract ids per request", MAX_BULK_STATUS_IDS),
+        ));
+    }
+    Ok(ids)
+}
+
+/// Match rows back to the ids they were requested by.
+pub fn build_status_report(
+    requested: Vec<String>,
+    rows: Vec<ContractVerificationStatus>,
+) -> BulkVerificationStatusResponse {
+    let mut statuses = Vec::new();
+    let mut unknown = Vec::new();
+
+    for requested_id in requested {
+        let contracts: Vec<ContractVerificationStatus> = rows
+            .iter()
+            .filter(|row| row.contract_id == requested_id || row.id.to_string() == requested_id)
+            .cloned()
+            .collect();
+        if contracts.is_empty() {
+            unknown.push(requested_id);
+        } else {
+            statuses.push(RequestedStatus {
+                requested_id,
+                contracts,
+            });
+        }
+    }
+
+    BulkVerificationStatusResponse { statuses, unknown }
+}
+
+/// POST /api/contracts/verification-status
+pub async fn bulk_verification_status(
+    State(state): State<AppState>,
+    Json(req): Json<BulkVerificationStatusRequest>,
+) -> ApiResult<Json<BulkVerificationStatusResponse>> {
+    let ids = normalize_status_ids(req.contract_ids)?;
+
+    let rows: Vec<ContractVerificationStatus> = sqlx::query_as(
+        "SELECT c.id, c.contract_id, c.network, c.is_verified,
+                v.status AS latest_status, v.verified_at
+         FROM contracts c
+         LEFT JOIN LATERAL (
+             SELECT status, verified_at FROM verifications
+             WHERE contract_id = c.id
+             ORDER BY created_at DESC
+             LIMIT 1
+         ) v ON TRUE
+         WHERE c.contract_id = ANY($1) OR c.id::text = ANY($1)
+         ORDER BY c.contract_id, c.network",
+    )
+    .bind(&ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("bulk verification status", err))?;
+
+    Ok(Json(build_status_report(ids, rows)))
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    crate::db_timeout::map_db_error(op, err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn failed_verification(build_log: &str) -> Verification {
+        Verification {
+            id: Uuid::new_v4(),
+            contract_id: Uuid::new_v4(),
+            status: VerificationStatus::Failed,
+            source_code: None,
+            build_params: Some(json!({
+                "profile": "release",
+                "env": { "GITHUB_TOKEN": "ghp_abc", "RUSTFLAGS": "-C opt-level=z" },
+                "registries": [{ "name": "crates", "api_key": "k-123" }]
+            })),
+            compiler_version: Some("1.75.0".into()),
+            verified_at: None,
+            error_message: Some("Build failed".into()),
+            build_log: Some(build_log.to_string()),
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn failed_verification_exposes_log_and_error() {
+        let log = "error[E0425]: cannot find value `x` in this scope";
+        let body = json!(redact_verification(failed_verification(log)));
+
+        assert_eq!(body["status"], json!("Failed"));
+        assert_eq!(body["error_message"], json!("Build failed"));
+        assert_eq!(body["compiler_version"], json!("1.75.0"));
+        assert_eq!(body["build_log"], json!(log));
+    }
+
+    #[test]
+    fn secrets_in_build_params_are_redacted() {
+        let v = redact_verification(failed_verification(""));
+        let params = v.build_params.unwrap();
+
+        assert_eq!(params["profile"], json!("release"));
+        assert_eq!(params["env"]["GITHUB_TOKEN"], json!(REDACTED));
+        assert_eq!(params["env"]["RUSTFLAGS"], json!("-C opt-level=z"));
+        assert_eq!(params["registries"][0]["api_key"], json!(REDACTED));
+        assert_eq!(params["registries"][0]["name"], json!("crates"));
+    }
+
+    #[test]
+    fn report_records_the_build_inputs() {
+        let verified_at = Utc::now();
+        let verification = Verification {
+            status: VerificationStatus::Verified,
+            source_code: Some("#![no_std]\npub struct Token;".into()),
+            verified_at: Some(verified_at),
+            error_message: None,
+            build_log: None,
+            ..failed_verification("")
+        };
+        let wasm_hash = "ab".repeat(32);
+
+        let report = ReproducibilityReport::for_verification(&verification, &wasm_hash).unwrap();
+        assert_eq!(report.verification_id, verification.id);
+        assert_eq!(report.contract_id, verification.contract_id);
+        assert_eq!(report.compiler_version.as_deref(), Some("1.75.0"));
+        assert_eq!(report.build_params, verification.build_params);
+        assert_eq!(report.source_hash, source_hash("#![no_std]\npub struct Token;"));
+        assert_eq!(report.source_hash.len(), 64);
+        assert_eq!(report.wasm_hash, wasm_hash);
+        assert_eq!(report.verified_at, verified_at);
+    }
+
+    #[test]
+    fn failed_attempts_have_no_report() {
+        assert!(ReproducibilityReport::for_verification(&failed_verification("boom"), "ab").is_none());
+    }
+
+    #[test]
+    fn long_build_logs_keep_the_tail() {
+        let log = format!("{}final error", "x".repeat(MAX_BUILD_LOG_BYTES * 2));
+        let stored = truncate_build_log(&log);
+
+        assert!(stored.starts_with("[truncated "));
+        assert!(stored.ends_with("final error"));
+        assert!(stored.len() < MAX_BUILD_LOG_BYTES + 64);
+        assert_eq!(truncate_build_log("short"), "short");
+    }
+
+    fn status_row(contract_id: &str, is_verified: bool) -> ContractVerificationStatus {
+        ContractVerificationStatus {
+            id: Uuid::new_v4(),
+            contract_id: contract_id.to_string(),
+            network: shared::Network::Testnet,
+            is_verified,
+            latest_status: Some(if is_verified {
+                VerificationStatus::Verified
+            } else {
+                VerificationStatus::Failed
+            }),
+            verified_at: is_verified.then(Utc::now),
+        }
+    }
+
+    #[test]
+    fn batch_lookup_reports_each_id_in_request_order() {
+        let verified = status_row("CVERIFIED", true);
+        let failed = status_row("CFAILED", false);
+        let by_uuid = verified.id.to_string();
+
+        let report = build_status_report(
+            vec!["CFAILED".into(), "CVERIFIED".into(), by_uuid.clone()],
+            vec![verified.clone(), failed],
+        );
+        let body = json!(report);
+
+        assert_eq!(body["statuses"].as_array().unwrap().len(), 3);
+        assert_eq!(body["statuses"][0]["requested_id"], "CFAILED");
+        assert_eq!(body["statuses"][0]["contracts"][0]["is_verified"], false);
+        assert_eq!(body["statuses"][0]["contracts"][0]["latest_status"], "Failed");
+        assert_eq!(body["statuses"][1]["contracts"][0]["is_verified"], true);
+        assert_eq!(body["statuses"][2]["requested_id"], by_uuid);
+        assert_eq!(body["statuses"][2]["contracts"][0]["contract_id"], "CVERIFIED");
+        assert!(report.unknown.is_empty());
+    }
+
+    #[test]
+    fn unknown_ids_are_reported_explicitly() {
+        let report = build_status_report(
+            vec!["CKNOWN".into(), "CMISSING".into()],
+            vec![status_row("CKNOWN", true)],
+        );
+        assert_eq!(report.statuses.len(), 1);
+        assert_eq!(report.unknown, vec!["CMISSING".to_string()]);
+    }
+
+    #[test]
+    fn status_ids_are_deduplicated_and_bounded() {
+        let ids = normalize_status_ids(vec![" CA ".into(), "CA".into(), "".into(), "CB".into()]).unwrap();
+        assert_eq!(ids, vec!["CA".to_string(), "CB".to_string()]);
+
+        assert!(normalize_status_ids(vec!["  ".into()]).is_err());
+        let too_many = (0..=MAX_BULK_STATUS_IDS).map(|i| format!("C{}", i)).collect();
+        assert!(normalize_status_ids(too_many).is_err());
+    }
+}
@@ -0,0 +1,405 @@
+// api/src/verification_queue.rs
+// Asynchronous (re-)verification.
+//
+// A `verifications` row with status `pending` is a queued job: it carries the
+// source and build params to verify, and the background worker builds it and
+// records the outcome on the same row, exactly as POST /api/contracts/verify
+// does inline.
+//
+// POST /api/admin/reverify fills the queue in bulk after a compiler or
+// verifier change: every contract matching the network/maturity filter gets a
+// pending attempt built from the source of its most recent attempt.
+// Contracts never verified from source, or already queued, are skipped.
+//
+// The worker takes turns between publishers: each claim goes to the
+// publisher whose last claim is oldest (or who has never had one), then to
+// their oldest pending attempt, so one publisher's large batch interleaves
+// with everyone else's instead of draining first. Attempts flagged
+// `priority` (paid or urgent) are claimed before all others, taking turns
+// among themselves the same way.
+
+use axum::{extract::State, Extension, Json};
+use serde::{Deserialize, Serialize};
+use shared::{MaturityLevel, Network, Verification};
+use sqlx::{FromRow, PgConnection, PgPool};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{
+    audit::{self, AuditEntry},
+    auth_middleware::AuthContext,
+    background_jobs,
+    error::ApiResult,
+    handlers::db_internal_error,
+    state::AppState,
+    verification_handlers::{
+        outcome_columns, run_verifier, store_report, ReproducibilityReport, VerificationFailure,
+    },
+};
+
+/// How often the worker drains the queue
+const QUEUE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Most jobs one worker run takes, so a large re-verification spreads out
+const QUEUE_BATCH_SIZE: u64 = 20;
+
+/// Which contracts to re-verify; unset fields match everything
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ReverifyFilter {
+    pub network: Option<Network>,
+    pub maturity: Option<MaturityLevel>,
+    /// Queue the attempts ahead of non-priority ones
+    #[serde(default)]
+    pub priority: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, FromRow)]
+pub struct ReverifyResponse {
+    /// Contracts matching the filter
+    pub matched: i64,
+    /// Pending attempts queued; the rest had no source or were already queued
+    pub enqueued: i64,
+}
+
+/// Queue a pending attempt for every live contract matching `filter`, reusing
+/// the source, build params and compiler version of its latest attempt.
+pub async fn enqueue_reverification(
+    pool: &PgPool,
+    filter: &ReverifyFilter,
+) -> Result<ReverifyResponse, sqlx::Error> {
+    sqlx::query_as(
+        "WITH matched AS (
+             SELECT c.id FROM contracts c
+             WHERE c.deleted_at IS NULL
+               AND ($1::network_type IS NULL OR c.network = $1)
+               AND ($2::maturity_level IS NULL OR c.maturity = $2)
+         ),
+         queued AS (
+             INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version, priority)
+             SELECT m.id, 'pending', v.source_code, v.build_params, v.compiler_version, $3
+             FROM matched m
+             JOIN LATERAL (
+                 SELECT source_code, build_params, compiler_version FROM verifications
+                 WHERE contract_id = m.id AND source_code IS NOT NULL
+                 ORDER BY created_at DESC
+                 LIMIT 1
+             ) v ON TRUE
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM verifications p WHERE p.contract_id = m.id AND p.status = 'pending'
+             )
+             RETURNING contract_id
+         )
+         SELECT (SELECT COUNT(*) FROM matched) AS matched,
+                (SELECT COUNT(*) FROM queued) AS enqueued",
+    )
+    .bind(&filter.network)
+    .bind(&filter.maturity)
+    .bind(filter.priority)
+    .fetch_one(pool)
+    .await
+}
+
+/// POST /api/admin/reverify
+pub async fn reverify_contracts(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(filter): Json<ReverifyFilter>,
+) -> ApiResult<Json<ReverifyResponse>> {
+    auth.require_admin()?;
+
+    let response = enqueue_reverification(&state.db, &filter)
+        .await
+        .map_err(|err| db_internal_error("queue re-verification", err))?;
+
+    tracing::info!(
+        network = ?filter.network,
+        maturity = ?filter.maturity,
+        priority = filter.priority,
+        matched = response.matched,
+        enqueued = response.enqueued,
+        requested_by = %auth.publisher_address,
+        "re-verification queued"
+    );
+    Ok(Json(response))
+}
+
+pub fn spawn_verification_worker(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(QUEUE_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            background_jobs::run_tracked(&pool, background_jobs::JOB_VERIFICATION_QUEUE, QUEUE_INTERVAL, || {
+                process_queue(&pool)
+            })
+            .await;
+        }
+    });
+}
+
+#[derive(Debug, FromRow)]
+struct QueuedVerification {
+    id: Uuid,
+    source_code: Option<String>,
+    wasm_hash: String,
+    is_verified: bool,
+    publisher_id: Uuid,
+}
+
+/// Lock the next pending attempt — priority first, then the publisher
+/// waiting longest, then oldest — and record the publisher's turn. The turn
+/// commits with the rest of the caller's transaction.
+async fn claim_next(conn: &mut PgConnection) -> Result<Option<QueuedVerification>, sqlx::Error> {
+    let job: Option<QueuedVerification> = sqlx::query_as(
+        "SELECT v.id, v.source_code, c.wasm_hash, c.is_verified, c.publisher_id
+         FROM verifications v
+         JOIN contracts c ON c.id = v.contract_id
+         LEFT JOIN verification_queue_turns t ON t.publisher_id = c.publisher_id
+         WHERE v.status = 'pending'
+         ORDER BY v.priority DESC, t.last_claimed_at NULLS FIRST, v.created_at
+         LIMIT 1
+         FOR UPDATE OF v SKIP LOCKED",
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    if let Some(job) = &job {
+        // clock_timestamp, not NOW: several claims can share a transaction
+        sqlx::query(
+            "INSERT INTO verification_queue_turns (publisher_id, last_claimed_at)
+             VALUES ($1, clock_timestamp())
+             ON CONFLICT (publisher_id) DO UPDATE SET last_claimed_at = EXCLUDED.last_claimed_at",
+        )
+        .bind(job.publisher_id)
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(job)
+}
+
+/// Run up to `QUEUE_BATCH_SIZE` pending attempts in `claim_next` order;
+/// returns the number completed. Each job is claimed with `SKIP LOCKED` and
+/// finished in its own transaction, so concurrent workers never build the
+/// same attempt.
+pub async fn process_queue(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let mut processed = 0;
+
+    while processed < QUEUE_BATCH_SIZE {
+        let mut tx = pool.begin().await?;
+        let Some(job) = claim_next(&mut tx).await? else {
+            break;
+        };
+
+        let outcome = match job.source_code.as_deref() {
+            Some(source) => run_verifier(source, &job.wasm_hash).await,
+            None => Err(VerificationFailure {
+                error_message: "No source code to verify".to_string(),
+                build_log: None,
+            }),
+        };
+        let (status, verified_at, error_message, build_log) = outcome_columns(outcome);
+
+        let verification: Verification = sqlx::query_as(
+            "UPDATE verifications
+             SET status = $2, verified_at = $3, error_message = $4, build_log = $5
+             WHERE id = $1
+             RETURNING *",
+        )
+        .bind(job.id)
+        .bind(status)
+        .bind(verified_at)
+        .bind(error_message)
+        .bind(build_log)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        if let Some(report) = ReproducibilityReport::for_verification(&verification, &job.wasm_hash) {
+            store_report(&mut *tx, &report).await?;
+            sqlx::query("UPDATE contracts SET is_verified = TRUE, updated_at = NOW() WHERE id = $1")
+                .bind(verification.contract_id)
+                .execute(&mut *tx)
+                .await?;
+        }
+        audit::record(&mut *tx, &AuditEntry::verification_changed(job.is_verified, &verification)).await?;
+
+        tx.commit().await?;
+        processed += 1;
+    }
+
+    if processed > 0 {
+        tracing::info!(processed, "verification queue: attempts completed");
+    }
+    Ok(processed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures;
+
+    const TEST_SOURCE: &str = "// reverify test source";
+
+    async fn pending_for(pool: &PgPool, contract: Uuid) -> i64 {
+        sqlx::query_scalar("SELECT COUNT(*) FROM verifications WHERE contract_id = $1 AND status = 'pending'")
+            .bind(contract)
+            .fetch_one(pool)
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn empty_filter_matches_everything() {
+        let filter: ReverifyFilter = serde_json::from_str("{}").unwrap();
+        assert!(filter.network.is_none() && filter.maturity.is_none());
+
+        let filter: ReverifyFilter =
+            serde_json::from_str(r#"{"network":"testnet","maturity":"stable"}"#).unwrap();
+        assert!(matches!(filter.network, Some(Network::Testnet)));
+        assert_eq!(filter.maturity, Some(MaturityLevel::Stable));
+        assert!(!filter.priority);
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn claims_take_turns_between_publishers_after_priority_jobs() {
+        let pool = fixtures::test_pool().await;
+        let data = fixtures::fixtures();
+        let publisher_of = |address: &str| {
+            data.publishers
+                .iter()
+                .find(|p| p.stellar_address == address)
+                .unwrap()
+                .id
+        };
+        let a = &data.contracts[0];
+        let b = data
+            .contracts
+            .iter()
+            .find(|c| c.publisher_address != a.publisher_address)
+            .unwrap();
+        let (publisher_a, publisher_b) = (publisher_of(&a.publisher_address), publisher_of(&b.publisher_address));
+
+        // Everything below is rolled back
+        let mut tx = pool.begin().await.unwrap();
+        sqlx::query("UPDATE verifications SET status = 'failed' WHERE status = 'pending'")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        sqlx::query("DELETE FROM verification_queue_turns")
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+
+        // A queues three attempts before B queues three, then B one urgent one
+        let jobs = [
+            (a.id, 10, false),
+            (a.id, 9, false),
+            (a.id, 8, false),
+            (b.id, 5, false),
+            (b.id, 4, false),
+            (b.id, 3, false),
+            (b.id, 1, true),
+        ];
+        for (contract, minutes_ago, priority) in jobs {
+            sqlx::query(
+                "INSERT INTO verifications (contract_id, status, source_code, priority, created_at)
+                 VALUES ($1, 'pending', $2, $3, NOW() - make_interval(mins => $4))",
+            )
+            .bind(contract)
+            .bind(TEST_SOURCE)
+            .bind(priority)
+            .bind(minutes_ago)
+            .execute(&mut *tx)
+            .await
+            .unwrap();
+        }
+
+        let mut order = Vec::new();
+        while let Some(job) = claim_next(&mut tx).await.unwrap() {
+            sqlx::query("UPDATE verifications SET status = 'failed' WHERE id = $1")
+                .bind(job.id)
+                .execute(&mut *tx)
+                .await
+                .unwrap();
+            order.push(if job.publisher_id == publisher_a { 'A' } else { 'B' });
+        }
+        tx.rollback().await.unwrap();
+
+        assert_ne!(publisher_a, publisher_b);
+        assert_eq!(order.into_iter().collect::<String>(), "BABABAB");
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn reverify_queues_only_matching_contracts_with_source() {
+        let pool = fixtures::test_pool().await;
+        let contracts = fixtures::fixtures().contracts;
+        let ids: Vec<Uuid> = contracts.iter().map(|c| c.id).collect();
+
+        sqlx::query("DELETE FROM verifications WHERE contract_id = ANY($1) AND status = 'pending'")
+            .bind(&ids)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE contracts SET maturity = 'alpha' WHERE id = ANY($1)")
+            .bind(&ids)
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let testnet: Vec<Uuid> = contracts
+            .iter()
+            .filter(|c| matches!(c.network, Network::Testnet))
+            .map(|c| c.id)
+            .collect();
+        let mainnet = contracts.iter().find(|c| matches!(c.network, Network::Mainnet)).unwrap().id;
+        let (with_source, without_source, other_maturity) = (testnet[0], testnet[1], testnet[2]);
+
+        for id in [with_source, other_maturity, mainnet] {
+            sqlx::query(
+                "INSERT INTO verifications (contract_id, status, source_code, compiler_version)
+                 VALUES ($1, 'failed', $2, '1.75.0')",
+            )
+            .bind(id)
+            .bind(TEST_SOURCE)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query("UPDATE contracts SET maturity = 'stable' WHERE id = ANY($1)")
+            .bind(vec![with_source, without_source, mainnet])
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let filter = ReverifyFilter {
+            network: Some(Network::Testnet),
+            maturity: Some(MaturityLevel::Stable),
+            priority: false,
+        };
+        let response = enqueue_reverification(&pool, &filter).await.unwrap();
+        assert!(response.matched >= 2);
+        assert!(response.enqueued >= 1);
+
+        assert_eq!(pending_for(&pool, with_source).await, 1);
+        assert_eq!(pending_for(&pool, without_source).await, 0);
+        assert_eq!(pending_for(&pool, other_maturity).await, 0);
+        assert_eq!(pending_for(&pool, mainnet).await, 0);
+
+        // Queuing again does not stack a second pending attempt
+        enqueue_reverification(&pool, &filter).await.unwrap();
+        assert_eq!(pending_for(&pool, with_source).await, 1);
+
+        sqlx::query("DELETE FROM verifications WHERE contract_id = ANY($1) AND source_code = $2")
+            .bind(&ids)
+            .bind(TEST_SOURCE)
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE contracts SET maturity = 'alpha' WHERE id = ANY($1)")
+            .bind(&ids)
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,230 @@
+// api/src/views.rs
+// Contract detail view counting.
+//
+// GET /api/contracts/:id records a view for the calling client (its IP, as
+// resolved for rate limiting). Repeat views from the same client within
+// `VIEW_DEBOUNCE_SECONDS` (default 1800) are ignored so refreshes and polling
+// do not inflate the count.
+//
+// Views are buffered in memory and added to `contracts.view_count` by a
+// background flush every minute. Responses add the unflushed views on top of
+// the stored count, so a client sees its own view immediately. If a flush
+// fails the buffered counts are put back and retried on the next run.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::background_jobs;
+
+/// How often buffered views are written to the database
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+const DEFAULT_DEBOUNCE_SECONDS: i64 = 1800;
+
+#[derive(Debug, Default)]
+struct Buffers {
+    /// Last counted view per (contract, client)
+    last_counted: HashMap<(Uuid, String), DateTime<Utc>>,
+    /// Views not yet written to `contracts.view_count`
+    pending: HashMap<Uuid, i64>,
+}
+
+#[derive(Debug)]
+pub struct ViewTracker {
+    debounce: chrono::Duration,
+    buffers: Mutex<Buffers>,
+}
+
+pub type SharedViewTracker = Arc<ViewTracker>;
+
+impl ViewTracker {
+    pub fn new(debounce: chrono::Duration) -> Self {
+        Self {
+            debounce,
+            buffers: Mutex::new(Buffers::default()),
+        }
+    }
+
+    /// Read `VIEW_DEBOUNCE_SECONDS`; invalid or negative values fall back to the default.
+    pub fn from_env() -> Self {
+        let seconds = std::env::var("VIEW_DEBOUNCE_SECONDS")
+            .ok()
+            .and_then(|raw| raw.trim().parse::<i64>().ok())
+            .filter(|secs| *secs >= 0)
+            .unwrap_or(DEFAULT_DEBOUNCE_SECONDS);
+        Self::new(chrono::Duration::seconds(seconds))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Buffers> {
+        self.buffers.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Count a view unless `client` already viewed `contract_id` within the
+    /// debounce window. Returns whether the view was counted.
+    pub fn record(&self, contract_id: Uuid, client: &str, now: DateTime<Utc>) -> bool {
+        let mut buffers = self.lock();
+        let key = (contract_id, client.to_string());
+        if let Some(last) = buffers.last_counted.get(&key) {
+            if now - *last < self.debounce {
+                return false;
+            }
+        }
+        buffers.last_counted.insert(key, now);
+        *buffers.pending.entry(contract_id).or_insert(0) += 1;
+        true
+    }
+
+    /// Views counted for `contract_id` but not flushed yet
+    pub fn pending(&self, contract_id: Uuid) -> i64 {
+        self.lock().pending.get(&contract_id).copied().unwrap_or(0)
+    }
+
+    /// Drain the pending counts and forget debounce entries that have expired.
+    fn take_pending(&self, now: DateTime<Utc>) -> HashMap<Uuid, i64> {
+        let mut buffers = self.lock();
+        let debounce = self.debounce;
+        buffers.last_counted.retain(|_, last| now - *last < debounce);
+        std::mem::take(&mut buffers.pending)
+    }
+
+    /// Put back counts from a failed flush.
+    fn restore(&self, counts: HashMap<Uuid, i64>) {
+        let mut buffers = self.lock();
+        for (contract_id, views) in counts {
+            *buffers.pending.entry(contract_id).or_insert(0) += views;
+        }
+    }
+}
+
+/// Spawn the background flush task.
+///
+/// Each run is recorded in `background_jobs` under `view_flush`.
+pub fn spawn_flush_task(pool: PgPool, tracker: SharedViewTracker) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(FLUSH_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            background_jobs::run_tracked(&pool, background_jobs::JOB_VIEW_FLUSH, FLUSH_INTERVAL, || {
+                flush(&pool, &tracker, Utc::now())
+            })
+            .await;
+        }
+    });
+}
+
+/// Add buffered views to `contracts.view_count`; returns the contracts updated.
+pub async fn flush(pool: &PgPool, tracker: &ViewTracker, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let counts = tracker.take_pending(now);
+    if counts.is_empty() {
+        return Ok(0);
+    }
+
+    let (ids, views): (Vec<Uuid>, Vec<i64>) = counts.iter().map(|(id, n)| (*id, *n)).unzip();
+    let result = sqlx::query(
+        "UPDATE contracts c SET view_count = c.view_count + v.views
+         FROM UNNEST($1::uuid[], $2::bigint[]) AS v(id, views)
+         WHERE c.id = v.id",
+    )
+    .bind(&ids)
+    .bind(&views)
+    .execute(pool)
+    .await;
+
+    match result {
+        Ok(done) => Ok(done.rows_affected()),
+        Err(err) => {
+            tracker.restore(counts);
+            Err(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 16, 12, 0, 0).unwrap()
+    }
+
+    fn tracker() -> ViewTracker {
+        ViewTracker::new(chrono::Duration::minutes(30))
+    }
+
+    #[test]
+    fn rapid_repeat_views_are_counted_once() {
+        let tracker = tracker();
+        let contract = Uuid::from_u128(1);
+
+        assert!(tracker.record(contract, "203.0.113.7", now()));
+        for secs in [1, 5, 60, 29 * 60] {
+            assert!(!tracker.record(contract, "203.0.113.7", now() + chrono::Duration::seconds(secs)));
+        }
+        assert_eq!(tracker.pending(contract), 1);
+    }
+
+    #[test]
+    fn views_count_again_after_the_window() {
+        let tracker = tracker();
+        let contract = Uuid::from_u128(1);
+
+        assert!(tracker.record(contract, "203.0.113.7", now()));
+        assert!(tracker.record(contract, "203.0.113.7", now() + chrono::Duration::minutes(30)));
+        assert_eq!(tracker.pending(contract), 2);
+    }
+
+    #[test]
+    fn debounce_is_per_client_and_per_contract() {
+        let tracker = tracker();
+        let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+
+        assert!(tracker.record(a, "203.0.113.7", now()));
+        assert!(tracker.record(a, "198.51.100.2", now()));
+        assert!(tracker.record(b, "203.0.113.7", now()));
+        assert_eq!(tracker.pending(a), 2);
+        assert_eq!(tracker.pending(b), 1);
+    }
+
+    #[test]
+    fn draining_keeps_the_debounce_until_it_expires() {
+        let tracker = tracker();
+        let contract = Uuid::from_u128(1);
+        tracker.record(contract, "203.0.113.7", now());
+
+        let drained = tracker.take_pending(now() + chrono::Duration::minutes(1));
+        assert_eq!(drained.get(&contract), Some(&1));
+        assert_eq!(tracker.pending(contract), 0);
+        // A flush in between must not reset the window
+        assert!(!tracker.record(contract, "203.0.113.7", now() + chrono::Duration::minutes(2)));
+
+        tracker.take_pending(now() + chrono::Duration::hours(1));
+        assert!(tracker.lock().last_counted.is_empty());
+    }
+
+    #[test]
+    fn failed_flushes_are_restored() {
+        let tracker = tracker();
+        let contract = Uuid::from_u128(1);
+        tracker.record(contract, "203.0.113.7", now());
+
+        let drained = tracker.take_pending(now());
+        tracker.record(contract, "198.51.100.2", now());
+        tracker.restore(drained);
+        assert_eq!(tracker.pending(contract), 2);
+    }
+
+    #[test]
+    fn invalid_env_falls_back_to_default() {
+        std::env::set_var("VIEW_DEBOUNCE_SECONDS", "soon");
+        assert_eq!(ViewTracker::from_env().debounce, chrono::Duration::seconds(DEFAULT_DEBOUNCE_SECONDS));
+        std::env::set_var("VIEW_DEBOUNCE_SECONDS", "60");
+        assert_eq!(ViewTracker::from_env().debounce, chrono::Duration::seconds(60));
+        std::env::remove_var("VIEW_DEBOUNCE_SECONDS");
+    }
+}
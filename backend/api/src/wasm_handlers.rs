@@ -0,0 +1,159 @@
+// api/src/wasm_handlers.rs
+// Contract WASM binaries, kept in the object store.
+//
+//   PUT /api/contracts/:id/wasm  — upload; must hash to the contract's wasm_hash
+//   GET /api/contracts/:id/wasm  — download as application/wasm, or a redirect
+//                                  to a signed URL when the store supports it
+//
+// Binaries are content-addressed, so contracts sharing a hash share one object.
+
+use axum::{
+    body::Bytes,
+    extract::{Path, State},
+    response::Response,
+    Extension, Json,
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use shared::Contract;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, ensure_contract_owner},
+    object_store::{self, ObjectStoreError},
+    resource_handlers::{load_quota, reserve_wasm_storage},
+    state::AppState,
+};
+
+/// Soroban's own limit on contract code size
+pub const MAX_WASM_BYTES: usize = 256 * 1024;
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+
+#[derive(Debug, Serialize)]
+pub struct WasmUploadResponse {
+    pub contract_id: Uuid,
+    pub wasm_hash: String,
+    pub size_bytes: usize,
+}
+
+/// Check `bytes` is a WASM module whose SHA-256 is `expected_hash`.
+pub fn validate_wasm(bytes: &[u8], expected_hash: &str) -> ApiResult<()> {
+    if bytes.is_empty() || bytes.len() > MAX_WASM_BYTES {
+        return Err(ApiError::bad_request(
+            "InvalidWasmSize",
+            format!("WASM must be between 1 and {} bytes", MAX_WASM_BYTES),
+        ));
+    }
+    if !bytes.starts_with(WASM_MAGIC) {
+        return Err(ApiError::bad_request("InvalidWasm", "body is not a WASM module"));
+    }
+    let actual = hex::encode(Sha256::digest(bytes));
+    if !actual.eq_ignore_ascii_case(expected_hash) {
+        return Err(ApiError::unprocessable(
+            "WasmHashMismatch",
+            format!("uploaded WASM hashes to {}, contract expects {}", actual, expected_hash),
+        ));
+    }
+    Ok(())
+}
+
+fn store_err(op: &str, err: ObjectStoreError) -> ApiError {
+    tracing::error!(operation = op, error = %err, "object store error");
+    ApiError::internal("WASM storage is unavailable")
+}
+
+async fn load_contract(state: &AppState, id: Uuid) -> ApiResult<Contract> {
+    sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract for wasm", err))?
+        .ok_or_else(|| ApiError::not_found("ContractNotFound", format!("No contract found with ID: {}", id)))
+}
+
+/// PUT /api/contracts/:id/wasm
+pub async fn upload_wasm(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+    body: Bytes,
+) -> ApiResult<Json<WasmUploadResponse>> {
+    let contract = load_contract(&state, id).await?;
+    ensure_contract_owner(&state, &contract, &auth).await?;
+    validate_wasm(&body, &contract.wasm_hash)?;
+
+    // The size is recorded against the storage quota in the same transaction
+    // that holds the publisher lock, and only committed once the binary is stored
+    let quota = load_quota(&state, contract.publisher_id).await?;
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin wasm upload", err))?;
+    reserve_wasm_storage(&mut tx, &quota, contract.publisher_id, id, body.len() as i64).await?;
+
+    state
+        .objects
+        .put(&object_store::wasm_key(&contract.wasm_hash), body.to_vec())
+        .await
+        .map_err(|err| store_err("store wasm", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit wasm upload", err))?;
+
+    Ok(Json(WasmUploadResponse {
+        contract_id: id,
+        wasm_hash: contract.wasm_hash,
+        size_bytes: body.len(),
+    }))
+}
+
+/// GET /api/contracts/:id/wasm
+pub async fn download_wasm(State(state): State<AppState>, Path(id): Path<Uuid>) -> ApiResult<Response> {
+    let contract = load_contract(&state, id).await?;
+    let download = object_store::resolve_download(
+        state.objects.as_ref(),
+        &object_store::wasm_key(&contract.wasm_hash),
+        object_store::download_url_ttl(),
+    )
+    .await
+    .map_err(|err| store_err("load wasm", err))?
+    .ok_or_else(|| ApiError::not_found("WasmNotFound", "No WASM has been uploaded for this contract"))?;
+
+    Ok(download.into_response("application/wasm"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::StatusCode, response::IntoResponse};
+
+    fn module() -> (Vec<u8>, String) {
+        let bytes = [WASM_MAGIC, &[1, 0, 0, 0]].concat();
+        let hash = hex::encode(Sha256::digest(&bytes));
+        (bytes, hash)
+    }
+
+    fn status(result: ApiResult<()>) -> StatusCode {
+        result.unwrap_err().into_response().status()
+    }
+
+    #[test]
+    fn matching_module_is_accepted() {
+        let (bytes, hash) = module();
+        assert!(validate_wasm(&bytes, &hash).is_ok());
+        assert!(validate_wasm(&bytes, &hash.to_uppercase()).is_ok());
+    }
+
+    #[test]
+    fn hash_mismatch_and_non_wasm_are_rejected() {
+        let (bytes, _) = module();
+        assert_eq!(status(validate_wasm(&bytes, &"0".repeat(64))), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(status(validate_wasm(b"not wasm", &"0".repeat(64))), StatusCode::BAD_REQUEST);
+        assert_eq!(status(validate_wasm(&[], &"0".repeat(64))), StatusCode::BAD_REQUEST);
+    }
+}
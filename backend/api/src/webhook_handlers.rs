@@ -0,0 +1,334 @@
+// api/src/webhook_handlers.rs
+// Axum handlers for managing webhook subscriptions.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    auth_middleware::AuthContext,
+    error::{ApiError, ApiResult},
+    state::AppState,
+    webhooks::{self, DeliveryMode, ReplayError, ReplayResult, Webhook, WebhookDeadLetter},
+};
+
+/// Body for POST /api/webhooks
+#[derive(Debug, Deserialize)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub secret: Option<String>,
+    pub event_types: Vec<String>,
+    /// Only deliver events about these contracts
+    #[serde(default)]
+    pub contract_ids: Vec<Uuid>,
+    /// Only deliver events about these publishers' contracts
+    #[serde(default)]
+    pub publisher_ids: Vec<Uuid>,
+    /// Only deliver events on these networks
+    #[serde(default)]
+    pub networks: Vec<Network>,
+    /// `daily_digest` batches the day's events into one delivery
+    #[serde(default)]
+    pub delivery_mode: DeliveryMode,
+}
+
+/// Most ids a single scope list may hold
+const MAX_SCOPE_ENTRIES: usize = 100;
+
+fn validate_scope(req: &CreateWebhookRequest) -> ApiResult<()> {
+    for (field, len) in [
+        ("contract_ids", req.contract_ids.len()),
+        ("publisher_ids", req.publisher_ids.len()),
+    ] {
+        if len > MAX_SCOPE_ENTRIES {
+            return Err(ApiError::bad_request(
+                "InvalidWebhookScope",
+                format!("{} may list at most {} entries", field, MAX_SCOPE_ENTRIES),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Reject URLs the registry should not be made to call: anything but https,
+/// and hosts that name this machine or a private network.
+fn validate_target_url(raw: &str) -> ApiResult<()> {
+    let invalid = |message: &str| ApiError::bad_request("InvalidWebhookUrl", message.to_string());
+
+    let url = reqwest::Url::parse(raw).map_err(|_| invalid("Webhook URL is not a valid URL"))?;
+    if url.scheme() != "https" {
+        return Err(invalid("Webhook URL must use https"));
+    }
+    let host = url.host_str().ok_or_else(|| invalid("Webhook URL must name a host"))?;
+    let internal = match host.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>() {
+        Ok(ip) => is_internal_ip(ip),
+        Err(_) => is_internal_name(host),
+    };
+    if internal {
+        return Err(invalid("Webhook URL must not point at a private or loopback address"));
+    }
+    Ok(())
+}
+
+fn is_internal_name(host: &str) -> bool {
+    let host = host.trim_end_matches('.');
+    host == "localhost"
+        || [".localhost", ".local", ".internal"]
+            .iter()
+            .any(|suffix| host.ends_with(suffix))
+}
+
+fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_internal_ipv4(ip),
+        IpAddr::V6(ip) => is_internal_ipv6(ip),
+    }
+}
+
+fn is_internal_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || a == 0
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (a == 100 && (b & 0xc0) == 64)
+}
+
+fn is_internal_ipv6(ip: Ipv6Addr) -> bool {
+    if let Some(v4) = ip.to_ipv4_mapped() {
+        return is_internal_ipv4(v4);
+    }
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local, fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // Link-local, fe80::/10
+        || (first & 0xffc0) == 0xfe80
+}
+
+/// The caller's publisher id; only registered publishers may own webhooks.
+async fn caller_publisher_id(state: &AppState, auth: &AuthContext) -> ApiResult<Uuid> {
+    sqlx::query_scalar("SELECT id FROM publishers WHERE stellar_address = $1")
+        .bind(&auth.publisher_address)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to look up publisher"))?
+        .ok_or_else(|| {
+            ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Forbidden",
+                "Only registered publishers can manage webhooks",
+            )
+        })
+}
+
+/// Sort and de-duplicate a scope list so stored scopes are canonical.
+fn normalize<T: Ord>(mut values: Vec<T>) -> Vec<T> {
+    values.sort();
+    values.dedup();
+    values
+}
+
+// ─────────────────────────────────────────────────────────
+// POST /api/webhooks
+// Admins may subscribe to anything; a publisher's webhooks only see events
+// about their own contracts.
+// ─────────────────────────────────────────────────────────
+pub async fn create_webhook(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Json(req): Json<CreateWebhookRequest>,
+) -> ApiResult<(StatusCode, Json<Webhook>)> {
+    validate_target_url(&req.url)?;
+    if req.event_types.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidEventTypes",
+            "At least one event type is required",
+        ));
+    }
+
+    validate_scope(&req)?;
+    let networks = normalize(req.networks.iter().map(|n| n.to_string()).collect());
+    let mut publisher_ids = normalize(req.publisher_ids.clone());
+    if !auth.is_admin() {
+        let own = caller_publisher_id(&state, &auth).await?;
+        if publisher_ids.iter().any(|id| *id != own) {
+            return Err(ApiError::new(
+                StatusCode::FORBIDDEN,
+                "Forbidden",
+                "Publishers may only subscribe to their own contracts",
+            ));
+        }
+        publisher_ids = vec![own];
+    }
+
+    let webhook: Webhook = sqlx::query_as(
+        "INSERT INTO webhooks (url, secret, event_types, created_by, contract_ids, publisher_ids, networks, \
+                               delivery_mode) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *",
+    )
+    .bind(&req.url)
+    .bind(&req.secret)
+    .bind(&req.event_types)
+    .bind(&auth.publisher_address)
+    .bind(normalize(req.contract_ids.clone()))
+    .bind(&publisher_ids)
+    .bind(&networks)
+    .bind(req.delivery_mode)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|_| ApiError::db_error("Failed to create webhook"))?;
+
+    Ok((StatusCode::CREATED, Json(webhook)))
+}
+
+// ─────────────────────────────────────────────────────────
+// GET /api/webhooks
+// Admins see every webhook; publishers see the ones they created.
+// ─────────────────────────────────────────────────────────
+pub async fn list_webhooks(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<Vec<Webhook>>> {
+    let owner = (!auth.is_admin()).then_some(&auth.publisher_address);
+    let webhooks: Vec<Webhook> = sqlx::query_as(
+        "SELECT * FROM webhooks WHERE active = TRUE AND ($1::text IS NULL OR created_by = $1) \
+         ORDER BY created_at DESC",
+    )
+    .bind(owner)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|_| ApiError::db_error("Failed to list webhooks"))?;
+
+    Ok(Json(webhooks))
+}
+
+// ─────────────────────────────────────────────────────────
+// DELETE /api/webhooks/:id
+// ─────────────────────────────────────────────────────────
+pub async fn delete_webhook(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let owner: Option<Option<String>> =
+        sqlx::query_scalar("SELECT created_by FROM webhooks WHERE id = $1 AND active = TRUE")
+            .bind(id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|_| ApiError::db_error("Failed to look up webhook"))?;
+    let owner = owner.ok_or_else(|| {
+        ApiError::not_found(
+            "WebhookNotFound",
+            format!("No webhook found with ID: {}", id),
+        )
+    })?;
+    if owner.as_deref() != Some(auth.publisher_address.as_str()) && !auth.is_admin() {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "Forbidden",
+            "Only the webhook's creator may delete it",
+        ));
+    }
+
+    sqlx::query("UPDATE webhooks SET active = FALSE WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to delete webhook"))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// ─────────────────────────────────────────────────────────
+// GET /api/webhooks/dead-letters
+// ─────────────────────────────────────────────────────────
+pub async fn list_dead_letters(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+) -> ApiResult<Json<Vec<WebhookDeadLetter>>> {
+    auth.require_admin()?;
+    let parked = webhooks::list_dead_letters(&state.db)
+        .await
+        .map_err(|_| ApiError::db_error("Failed to list dead-lettered deliveries"))?;
+
+    Ok(Json(parked))
+}
+
+// ─────────────────────────────────────────────────────────
+// POST /api/webhooks/deliveries/:id/replay
+// ─────────────────────────────────────────────────────────
+pub async fn replay_delivery(
+    State(state): State<AppState>,
+    Extension(auth): Extension<AuthContext>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<(StatusCode, Json<ReplayResult>)> {
+    auth.require_admin()?;
+    let result = webhooks::replay_dead_letter(&state.db, &webhooks::delivery_client(), id)
+        .await
+        .map_err(|err| match err {
+            ReplayError::NotParked(id) => ApiError::not_found(
+                "DeliveryNotParked",
+                format!("No dead-lettered delivery found with ID: {}", id),
+            ),
+            ReplayError::Db(_) => ApiError::db_error("Failed to replay delivery"),
+        })?;
+
+    let status = if result.delivered {
+        StatusCode::OK
+    } else {
+        StatusCode::BAD_GATEWAY
+    };
+    Ok((status, Json(result)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rejected(url: &str) -> bool {
+        validate_target_url(url).is_err()
+    }
+
+    #[test]
+    fn only_public_https_targets_are_accepted() {
+        assert!(!rejected("https://hooks.example.com/registry"));
+        assert!(!rejected("https://203.0.113.7:8443/hook"));
+
+        for url in [
+            "http://hooks.example.com/registry",
+            "ftp://hooks.example.com/registry",
+            "not a url",
+            "https://localhost/hook",
+            "https://api.localhost/hook",
+            "https://printer.local/hook",
+            "https://metadata.google.internal/hook",
+            "https://127.0.0.1/hook",
+            "https://10.0.0.5/hook",
+            "https://172.16.0.1/hook",
+            "https://192.168.1.1/hook",
+            "https://169.254.169.254/latest/meta-data",
+            "https://100.64.0.1/hook",
+            "https://0.0.0.0/hook",
+            "https://[::1]/hook",
+            "https://[fd00::1]/hook",
+            "https://[fe80::1]/hook",
+            "https://[::ffff:127.0.0.1]/hook",
+        ] {
+            assert!(rejected(url), "{url} should be rejected");
+        }
+    }
+}
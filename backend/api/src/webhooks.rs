@@ -0,0 +1,836 @@
+// api/src/webhooks.rs
+// Outbound webhook subscriptions and the delivery queue.
+//
+// Producers call `enqueue_event`, which fans an event out into one
+// `webhook_deliveries` row per matching subscription. Besides event types, a
+// subscription can be scoped to specific contracts, publishers or networks;
+// an empty scope list means "any". When an event names a contract, its
+// publisher and network are resolved from the registry, so a publisher- or
+// network-scoped webhook also sees that contract's events. A background task
+// drains the queue and POSTs each payload, retrying with backoff. Deliveries
+// that exhaust their retries are parked in `webhook_dead_letters` until an
+// operator replays them. When a webhook has a secret, every POST carries an
+// HMAC-SHA256 of the body in `X-Registry-Signature`.
+//
+// A webhook in `daily_digest` mode does not get one POST per event. Its
+// deliveries are held as `digest_pending`, and once a day the digest task folds
+// them into a single `digest.daily` delivery, which then goes through the
+// normal queue (retries, dead letters) like any other.
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::Sha256;
+use shared::Network;
+use sqlx::{FromRow, PgPool};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::background_jobs;
+
+/// Fired when a benchmark run regresses beyond its alert threshold
+pub const EVENT_BENCHMARK_REGRESSION: &str = "benchmark.regression";
+
+/// Fired when a multisig deployment proposal collects a signature
+pub const EVENT_MULTISIG_SIGNATURE_ADDED: &str = "multisig.signature_added";
+
+/// Fired when a multisig deployment proposal reaches its signature threshold
+pub const EVENT_MULTISIG_PROPOSAL_APPROVED: &str = "multisig.proposal_approved";
+
+/// The batched summary sent to `daily_digest` webhooks
+pub const EVENT_DAILY_DIGEST: &str = "digest.daily";
+
+/// How often held events are folded into digests
+const DIGEST_INTERVAL: Duration = Duration::from_secs(24 * 3600);
+
+/// How a webhook receives its events
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryMode {
+    /// One delivery per event, as it happens
+    #[default]
+    Immediate,
+    /// One `digest.daily` delivery summarising the day's events
+    DailyDigest,
+}
+
+/// Header carrying `sha256=<hex HMAC of the body>` for webhooks with a secret
+pub const SIGNATURE_HEADER: &str = "X-Registry-Signature";
+
+/// Attempts made before a delivery is marked as failed
+pub const MAX_DELIVERY_ATTEMPTS: i32 = 5;
+
+/// One row in `webhooks`. The signing secret is left out; only the delivery
+/// queue reads it.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: String,
+    pub event_types: Vec<String>,
+    pub active: bool,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    /// Registry contract ids; empty means any contract
+    pub contract_ids: Vec<Uuid>,
+    /// Publisher ids; empty means any publisher
+    pub publisher_ids: Vec<Uuid>,
+    /// Network names; empty means any network
+    pub networks: Vec<String>,
+    pub delivery_mode: DeliveryMode,
+}
+
+/// What an event is about, for scoped subscriptions
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventScope {
+    pub contract_id: Option<Uuid>,
+    pub publisher_id: Option<Uuid>,
+    pub network: Option<Network>,
+}
+
+impl EventScope {
+    /// An event about a registry contract; publisher and network are looked
+    /// up when the event is enqueued.
+    pub fn contract(contract_id: Uuid) -> Self {
+        Self {
+            contract_id: Some(contract_id),
+            ..Self::default()
+        }
+    }
+
+    pub fn network(network: Network) -> Self {
+        Self {
+            network: Some(network),
+            ..Self::default()
+        }
+    }
+}
+
+/// One row in `webhook_deliveries`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub next_attempt_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+}
+
+/// Queue `payload` for every active webhook subscribed to `event_type` (or
+/// to `*`) whose scope matches. Deliveries to `daily_digest` webhooks are held for the
+/// next digest instead.
+///
+/// Returns the number of deliveries enqueued.
+pub async fn enqueue_event(
+    pool: &PgPool,
+    event_type: &str,
+    scope: &EventScope,
+    payload: &serde_json::Value,
+) -> Result<u64, sqlx::Error> {
+    // Scope filters use array containment so the GIN indexes apply
+    let result = sqlx::query(
+        r#"
+        WITH ev AS (
+            SELECT $3::uuid AS contract_id,
+                   COALESCE($4::uuid, c.publisher_id) AS publisher_id,
+                   COALESCE($5::text, c.network::text) AS network
+            FROM (SELECT 1) AS one
+            LEFT JOIN contracts c ON c.id = $3
+        )
+        INSERT INTO webhook_deliveries (webhook_id, event_type, payload, status)
+        SELECT w.id, $1, $2,
+               CASE WHEN w.delivery_mode = 'daily_digest' THEN 'digest_pending' ELSE 'pending' END
+        FROM webhooks w, ev
+        WHERE w.active = TRUE
+          AND ($1 = ANY(w.event_types) OR '*' = ANY(w.event_types))
+          AND (w.contract_ids = '{}' OR w.contract_ids @> ARRAY[ev.contract_id])
+          AND (w.publisher_ids = '{}' OR w.publisher_ids @> ARRAY[ev.publisher_id])
+          AND (w.networks = '{}' OR w.networks @> ARRAY[ev.network])
+        "#,
+    )
+    .bind(event_type)
+    .bind(payload)
+    .bind(scope.contract_id)
+    .bind(scope.publisher_id)
+    .bind(scope.network.as_ref().map(|n| n.to_string()))
+    .execute(pool)
+    .await?;
+
+    Ok(result.rows_affected())
+}
+
+/// Exponential backoff between delivery attempts, capped at one hour.
+pub fn retry_delay(attempts: i32) -> chrono::Duration {
+    let secs = 30i64.saturating_mul(1i64 << attempts.clamp(0, 7));
+    chrono::Duration::seconds(secs.min(3600))
+}
+
+/// Client used for outbound deliveries
+pub fn delivery_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap_or_default()
+}
+
+/// Spawn the background task that drains the delivery queue every 15 seconds.
+pub fn spawn_delivery_task(pool: PgPool) {
+    tokio::spawn(async move {
+        let client = delivery_client();
+        let mut interval = tokio::time::interval(Duration::from_secs(15));
+
+        loop {
+            interval.tick().await;
+            if let Err(err) = deliver_pending(&pool, &client).await {
+                tracing::error!(error = ?err, "webhooks: delivery run failed");
+            }
+        }
+    });
+}
+
+/// A held event awaiting its webhook's next digest
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DigestEvent {
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Fold a webhook's held events into one `digest.daily` payload: per-type
+/// counts plus every event in the order it happened.
+pub fn build_digest(
+    webhook_id: Uuid,
+    period_start: DateTime<Utc>,
+    period_end: DateTime<Utc>,
+    mut events: Vec<DigestEvent>,
+) -> serde_json::Value {
+    events.sort_by_key(|e| e.created_at);
+
+    let mut counts: BTreeMap<&str, u64> = BTreeMap::new();
+    for event in &events {
+        *counts.entry(event.event_type.as_str()).or_default() += 1;
+    }
+
+    json!({
+        "event": EVENT_DAILY_DIGEST,
+        "webhook_id": webhook_id,
+        "period_start": period_start,
+        "period_end": period_end,
+        "total_events": events.len(),
+        "counts_by_type": counts,
+        "events": events
+            .iter()
+            .map(|e| json!({
+                "event_type": e.event_type,
+                "occurred_at": e.created_at,
+                "payload": e.payload,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Spawn the daily task that assembles digests for `daily_digest` webhooks.
+///
+/// Each run is recorded in `background_jobs` under `webhook_digest`.
+pub fn spawn_digest_task(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(DIGEST_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            background_jobs::run_tracked(&pool, background_jobs::JOB_WEBHOOK_DIGEST, DIGEST_INTERVAL, || {
+                assemble_digests(&pool, Utc::now())
+            })
+            .await;
+        }
+    });
+}
+
+/// Queue one digest delivery per `daily_digest` webhook with events held up
+/// to `now`; returns the number of digests queued. Each webhook is handled in
+/// its own transaction, so its held events are folded exactly once.
+pub async fn assemble_digests(pool: &PgPool, now: DateTime<Utc>) -> Result<u64, sqlx::Error> {
+    let webhook_ids: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM webhooks WHERE active = TRUE AND delivery_mode = 'daily_digest'",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut queued = 0;
+    for webhook_id in webhook_ids {
+        let mut tx = pool.begin().await?;
+        let held: Vec<(Uuid, String, serde_json::Value, DateTime<Utc>)> = sqlx::query_as(
+            "SELECT id, event_type, payload, created_at FROM webhook_deliveries \
+             WHERE webhook_id = $1 AND status = 'digest_pending' AND created_at <= $2 \
+             ORDER BY created_at FOR UPDATE SKIP LOCKED",
+        )
+        .bind(webhook_id)
+        .bind(now)
+        .fetch_all(&mut *tx)
+        .await?;
+        if held.is_empty() {
+            continue;
+        }
+
+        let ids: Vec<Uuid> = held.iter().map(|(id, ..)| *id).collect();
+        let period_start = held[0].3;
+        let events = held
+            .into_iter()
+            .map(|(_, event_type, payload, created_at)| DigestEvent {
+                event_type,
+                payload,
+                created_at,
+            })
+            .collect();
+
+        sqlx::query("INSERT INTO webhook_deliveries (webhook_id, event_type, payload) VALUES ($1, $2, $3)")
+            .bind(webhook_id)
+            .bind(EVENT_DAILY_DIGEST)
+            .bind(build_digest(webhook_id, period_start, now, events))
+            .execute(&mut *tx)
+            .await?;
+        sqlx::query("UPDATE webhook_deliveries SET status = 'digested' WHERE id = ANY($1)")
+            .bind(&ids)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        queued += 1;
+    }
+
+    if queued > 0 {
+        tracing::info!(queued, "webhooks: daily digests queued");
+    }
+    Ok(queued)
+}
+
+/// What happens to a delivery after an attempt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeliveryTransition {
+    Delivered { attempts: i32 },
+    Retry { attempts: i32 },
+    /// Retries exhausted; park the delivery in the dead-letter store
+    DeadLetter { attempts: i32 },
+}
+
+/// Decide the next state given the attempts made before this one.
+pub fn transition_after_attempt(previous_attempts: i32, outcome: &Result<(), String>) -> DeliveryTransition {
+    let attempts = previous_attempts + 1;
+    match outcome {
+        Ok(()) => DeliveryTransition::Delivered { attempts },
+        Err(_) if attempts >= MAX_DELIVERY_ATTEMPTS => DeliveryTransition::DeadLetter { attempts },
+        Err(_) => DeliveryTransition::Retry { attempts },
+    }
+}
+
+/// The `X-Registry-Signature` value for `body`: receivers recompute the
+/// HMAC-SHA256 with their secret and compare.
+pub fn sign_payload(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// POST one payload to `url`, signed with `secret` when the webhook has one.
+pub async fn attempt_delivery(
+    client: &reqwest::Client,
+    url: &str,
+    secret: Option<&str>,
+    delivery_id: Uuid,
+    event_type: &str,
+    payload: &serde_json::Value,
+) -> Result<(), String> {
+    let body = serde_json::to_vec(payload).map_err(|e| e.to_string())?;
+    let mut request = client
+        .post(url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Registry-Event", event_type)
+        .header("X-Registry-Delivery", delivery_id.to_string());
+    if let Some(secret) = secret {
+        request = request.header(SIGNATURE_HEADER, sign_payload(secret, &body));
+    }
+
+    request
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| e.to_string())
+        .and_then(|resp| {
+            if resp.status().is_success() {
+                Ok(())
+            } else {
+                Err(format!("endpoint responded with {}", resp.status()))
+            }
+        })
+}
+
+async fn deliver_pending(pool: &PgPool, client: &reqwest::Client) -> Result<(), sqlx::Error> {
+    let due: Vec<(Uuid, String, Option<String>, String, serde_json::Value, i32)> = sqlx::query_as(
+        r#"
+        SELECT d.id, w.url, w.secret, d.event_type, d.payload, d.attempts
+        FROM webhook_deliveries d
+        JOIN webhooks w ON w.id = d.webhook_id
+        WHERE d.status IN ('pending', 'retrying')
+          AND d.next_attempt_at <= NOW()
+          AND w.active = TRUE
+        ORDER BY d.created_at
+        LIMIT 100
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for (delivery_id, url, secret, event_type, payload, attempts) in due {
+        let outcome = attempt_delivery(client, &url, secret.as_deref(), delivery_id, &event_type, &payload).await;
+        let transition = transition_after_attempt(attempts, &outcome);
+        if let Err(ref error) = outcome {
+            tracing::warn!(%delivery_id, ?transition, %error, "webhooks: delivery attempt failed");
+        }
+        apply_transition(pool, delivery_id, &transition, outcome.err()).await?;
+    }
+
+    Ok(())
+}
+
+/// Persist a delivery's new state, moving it to the dead-letter store when
+/// its retries are exhausted.
+async fn apply_transition(
+    pool: &PgPool,
+    delivery_id: Uuid,
+    transition: &DeliveryTransition,
+    error: Option<String>,
+) -> Result<(), sqlx::Error> {
+    match *transition {
+        DeliveryTransition::Delivered { attempts } => {
+            let mut tx = pool.begin().await?;
+            sqlx::query(
+                "UPDATE webhook_deliveries SET status = 'delivered', attempts = $1, \
+                 delivered_at = NOW(), last_error = NULL WHERE id = $2",
+            )
+            .bind(attempts)
+            .bind(delivery_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                "UPDATE webhook_dead_letters SET replayed_at = NOW() \
+                 WHERE delivery_id = $1 AND replayed_at IS NULL",
+            )
+            .bind(delivery_id)
+            .execute(&mut *tx)
+            .await?;
+            tx.commit().await?;
+        }
+        DeliveryTransition::Retry { attempts } => {
+            sqlx::query(
+                "UPDATE webhook_deliveries SET status = 'retrying', attempts = $1, last_error = $2, \
+                 next_attempt_at = $3 WHERE id = $4",
+            )
+            .bind(attempts)
+            .bind(&error)
+            .bind(Utc::now() + retry_delay(attempts))
+            .bind(delivery_id)
+            .execute(pool)
+            .await?;
+        }
+        DeliveryTransition::DeadLetter { attempts } => {
+            let mut tx = pool.begin().await?;
+            sqlx::query(
+                "UPDATE webhook_deliveries SET status = 'dead_lettered', attempts = $1, last_error = $2 \
+                 WHERE id = $3",
+            )
+            .bind(attempts)
+            .bind(&error)
+            .bind(delivery_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(
+                r#"
+                INSERT INTO webhook_dead_letters (delivery_id, webhook_id, event_type, payload, attempts, last_error)
+                SELECT id, webhook_id, event_type, payload, attempts, last_error
+                FROM webhook_deliveries
+                WHERE id = $1
+                ON CONFLICT (delivery_id) DO UPDATE SET
+                    attempts = EXCLUDED.attempts,
+                    last_error = EXCLUDED.last_error,
+                    dead_lettered_at = NOW(),
+                    replayed_at = NULL
+                "#,
+            )
+            .bind(delivery_id)
+            .execute(&mut *tx)
+            .await?;
+            tx.commit().await?;
+        }
+    }
+    Ok(())
+}
+
+/// One row in `webhook_dead_letters`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct WebhookDeadLetter {
+    pub id: Uuid,
+    pub delivery_id: Uuid,
+    pub webhook_id: Uuid,
+    pub event_type: String,
+    pub payload: serde_json::Value,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    pub dead_lettered_at: DateTime<Utc>,
+    pub replay_count: i32,
+    pub replayed_at: Option<DateTime<Utc>>,
+}
+
+/// Result of replaying a parked delivery
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayResult {
+    pub delivery_id: Uuid,
+    pub delivered: bool,
+    pub attempts: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayError {
+    #[error("no parked delivery with id {0}")]
+    NotParked(Uuid),
+    #[error(transparent)]
+    Db(#[from] sqlx::Error),
+}
+
+/// Re-attempt a dead-lettered delivery once. On failure it stays parked with
+/// the new error recorded.
+pub async fn replay_dead_letter(
+    pool: &PgPool,
+    client: &reqwest::Client,
+    delivery_id: Uuid,
+) -> Result<ReplayResult, ReplayError> {
+    let parked: Option<(String, Option<String>, String, serde_json::Value, i32)> = sqlx::query_as(
+        r#"
+        SELECT w.url, w.secret, d.event_type, d.payload, d.attempts
+        FROM webhook_dead_letters dl
+        JOIN webhook_deliveries d ON d.id = dl.delivery_id
+        JOIN webhooks w ON w.id = d.webhook_id
+        WHERE dl.delivery_id = $1 AND dl.replayed_at IS NULL
+        "#,
+    )
+    .bind(delivery_id)
+    .fetch_optional(pool)
+    .await?;
+    let (url, secret, event_type, payload, attempts) = parked.ok_or(ReplayError::NotParked(delivery_id))?;
+
+    let outcome = attempt_delivery(client, &url, secret.as_deref(), delivery_id, &event_type, &payload).await;
+    let attempts = attempts + 1;
+
+    sqlx::query(
+        "UPDATE webhook_dead_letters SET replay_count = replay_count + 1, attempts = $2, \
+         last_error = COALESCE($3, last_error) WHERE delivery_id = $1",
+    )
+    .bind(delivery_id)
+    .bind(attempts)
+    .bind(outcome.as_ref().err())
+    .execute(pool)
+    .await?;
+
+    match outcome {
+        Ok(()) => {
+            apply_transition(pool, delivery_id, &DeliveryTransition::Delivered { attempts }, None).await?;
+            Ok(ReplayResult {
+                delivery_id,
+                delivered: true,
+                attempts,
+                error: None,
+            })
+        }
+        Err(error) => {
+            sqlx::query("UPDATE webhook_deliveries SET attempts = $1, last_error = $2 WHERE id = $3")
+                .bind(attempts)
+                .bind(&error)
+                .bind(delivery_id)
+                .execute(pool)
+                .await?;
+            Ok(ReplayResult {
+                delivery_id,
+                delivered: false,
+                attempts,
+                error: Some(error),
+            })
+        }
+    }
+}
+
+pub async fn list_dead_letters(pool: &PgPool) -> Result<Vec<WebhookDeadLetter>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT * FROM webhook_dead_letters WHERE replayed_at IS NULL ORDER BY dead_lettered_at DESC LIMIT 500",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn insert_webhook(
+        pool: &PgPool,
+        event_types: &[&str],
+        contract_ids: Vec<Uuid>,
+        publisher_ids: Vec<Uuid>,
+        networks: &[&Network],
+        delivery_mode: DeliveryMode,
+    ) -> Uuid {
+        sqlx::query_scalar(
+            "INSERT INTO webhooks (url, event_types, contract_ids, publisher_ids, networks, delivery_mode) \
+             VALUES ('https://hooks.example.com/registry', $1, $2, $3, $4, $5) RETURNING id",
+        )
+        .bind(event_types)
+        .bind(contract_ids)
+        .bind(publisher_ids)
+        .bind(networks.iter().map(|n| n.to_string()).collect::<Vec<_>>())
+        .bind(delivery_mode)
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    #[ignore]
+    async fn events_only_reach_webhooks_whose_scope_matches() {
+        let pool = crate::fixtures::test_pool().await;
+        let data = crate::fixtures::fixtures();
+        let contract = &data.contracts[0];
+        let publisher = data
+            .publishers
+            .iter()
+            .find(|p| p.stellar_address == contract.publisher_address)
+            .unwrap();
+        let other_publisher = data.publishers.iter().find(|p| p.id != publisher.id).unwrap();
+        let other_network = if contract.network == Network::Mainnet {
+            Network::Testnet
+        } else {
+            Network::Mainnet
+        };
+        // A fresh event type keeps other webhooks in the database out of the way
+        let event = format!("test.scope.{}", Uuid::new_v4().simple());
+        let immediate = DeliveryMode::Immediate;
+
+        let unscoped = insert_webhook(&pool, &[&event], vec![], vec![], &[], immediate).await;
+        let by_contract = insert_webhook(&pool, &[&event], vec![contract.id], vec![], &[], immediate).await;
+        let other_contract = insert_webhook(&pool, &[&event], vec![Uuid::new_v4()], vec![], &[], immediate).await;
+        let by_publisher = insert_webhook(&pool, &[&event], vec![], vec![publisher.id], &[], immediate).await;
+        let other_by_publisher =
+            insert_webhook(&pool, &[&event], vec![], vec![other_publisher.id], &[], immediate).await;
+        let by_network = insert_webhook(&pool, &[&event], vec![], vec![], &[&contract.network], immediate).await;
+        let publisher_elsewhere =
+            insert_webhook(&pool, &[&event], vec![], vec![publisher.id], &[&other_network], immediate).await;
+        let wildcard = insert_webhook(&pool, &["*"], vec![contract.id], vec![], &[], immediate).await;
+        let other_event = insert_webhook(&pool, &["test.other"], vec![contract.id], vec![], &[], immediate).await;
+        let digest = insert_webhook(&pool, &[&event], vec![contract.id], vec![], &[], DeliveryMode::DailyDigest).await;
+        let inactive = insert_webhook(&pool, &[&event], vec![], vec![], &[], immediate).await;
+        sqlx::query("UPDATE webhooks SET active = FALSE WHERE id = $1")
+            .bind(inactive)
+            .execute(&pool)
+            .await
+            .unwrap();
+        let all = [
+            unscoped,
+            by_contract,
+            other_contract,
+            by_publisher,
+            other_by_publisher,
+            by_network,
+            publisher_elsewhere,
+            wildcard,
+            other_event,
+            digest,
+            inactive,
+        ];
+
+        let delivered = |scope: EventScope| {
+            let (pool, event) = (pool.clone(), event.clone());
+            async move {
+                let batch = Uuid::new_v4();
+                let queued = enqueue_event(&pool, &event, &scope, &json!({ "batch": batch }))
+                    .await
+                    .unwrap();
+                let rows: Vec<(Uuid, String)> = sqlx::query_as(
+                    "SELECT webhook_id, status FROM webhook_deliveries \
+                     WHERE webhook_id = ANY($1) AND payload->>'batch' = $2",
+                )
+                .bind(&all[..])
+                .bind(batch.to_string())
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+                assert_eq!(queued as usize, rows.len());
+                rows
+            }
+        };
+
+        // Publisher and network come from the contract row
+        let rows = delivered(EventScope::contract(contract.id)).await;
+        let mut reached: Vec<Uuid> = rows.iter().map(|(id, _)| *id).collect();
+        reached.sort();
+        let mut expected = vec![unscoped, by_contract, by_publisher, by_network, wildcard, digest];
+        expected.sort();
+        assert_eq!(reached, expected);
+        let held: Vec<Uuid> = rows
+            .iter()
+            .filter(|(_, status)| status == "digest_pending")
+            .map(|(id, _)| *id)
+            .collect();
+        assert_eq!(held, vec![digest]);
+
+        // Without a contract, only unscoped and network-scoped webhooks match
+        let mut reached: Vec<Uuid> = delivered(EventScope::network(contract.network.clone()))
+            .await
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        reached.sort();
+        let mut expected = vec![unscoped, by_network];
+        expected.sort();
+        assert_eq!(reached, expected);
+
+        sqlx::query("DELETE FROM webhooks WHERE id = ANY($1)")
+            .bind(&all[..])
+            .execute(&pool)
+            .await
+            .unwrap();
+    }
+
+    #[test]
+    fn exhausting_retries_moves_delivery_to_dead_letter() {
+        let failed: Result<(), String> = Err("endpoint responded with 500".into());
+
+        let mut attempts = 0;
+        let mut transition = transition_after_attempt(attempts, &failed);
+        while let DeliveryTransition::Retry { attempts: n } = transition {
+            attempts = n;
+            transition = transition_after_attempt(attempts, &failed);
+        }
+
+        assert_eq!(
+            transition,
+            DeliveryTransition::DeadLetter {
+                attempts: MAX_DELIVERY_ATTEMPTS
+            }
+        );
+        assert_eq!(
+            transition_after_attempt(MAX_DELIVERY_ATTEMPTS, &Ok(())),
+            DeliveryTransition::Delivered {
+                attempts: MAX_DELIVERY_ATTEMPTS + 1
+            }
+        );
+    }
+
+    #[test]
+    fn signature_is_hmac_sha256_of_the_body() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            sign_payload("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[tokio::test]
+    async fn deliveries_are_signed_only_when_the_webhook_has_a_secret() {
+        use axum::{body::Bytes, http::HeaderMap, routing::post, Router};
+        use tokio::sync::mpsc;
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: Bytes| {
+                let tx = tx.clone();
+                async move {
+                    let signature = headers
+                        .get(SIGNATURE_HEADER)
+                        .map(|v| v.to_str().unwrap().to_string());
+                    tx.send((signature, body)).unwrap();
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let client = delivery_client();
+        let url = format!("http://{addr}/hook");
+        let payload = serde_json::json!({ "event": "test" });
+
+        attempt_delivery(&client, &url, Some("s3cret"), Uuid::new_v4(), "test", &payload)
+            .await
+            .unwrap();
+        let (signature, body) = rx.recv().await.unwrap();
+        assert_eq!(signature, Some(sign_payload("s3cret", &body)));
+
+        attempt_delivery(&client, &url, None, Uuid::new_v4(), "test", &payload)
+            .await
+            .unwrap();
+        assert_eq!(rx.recv().await.unwrap().0, None);
+    }
+
+    fn held(event_type: &str, hour: u32, contract: &str) -> DigestEvent {
+        DigestEvent {
+            event_type: event_type.into(),
+            payload: serde_json::json!({ "contract_id": contract }),
+            created_at: chrono::TimeZone::with_ymd_and_hms(&Utc, 2026, 10, 16, hour, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn digest_aggregates_a_days_events_into_one_payload() {
+        let webhook_id = Uuid::new_v4();
+        let start = chrono::TimeZone::with_ymd_and_hms(&Utc, 2026, 10, 16, 0, 0, 0).unwrap();
+        let end = start + chrono::Duration::days(1);
+        let events = vec![
+            held(EVENT_MULTISIG_PROPOSAL_APPROVED, 15, "CB"),
+            held(EVENT_BENCHMARK_REGRESSION, 9, "CA"),
+            held(EVENT_MULTISIG_SIGNATURE_ADDED, 14, "CB"),
+            held(EVENT_BENCHMARK_REGRESSION, 20, "CA"),
+        ];
+
+        let digest = build_digest(webhook_id, start, end, events);
+
+        assert_eq!(digest["event"], EVENT_DAILY_DIGEST);
+        assert_eq!(digest["webhook_id"], serde_json::json!(webhook_id));
+        assert_eq!(digest["total_events"], 4);
+        assert_eq!(digest["counts_by_type"][EVENT_BENCHMARK_REGRESSION], 2);
+        assert_eq!(digest["counts_by_type"][EVENT_MULTISIG_SIGNATURE_ADDED], 1);
+        assert_eq!(digest["counts_by_type"][EVENT_MULTISIG_PROPOSAL_APPROVED], 1);
+
+        let events = digest["events"].as_array().unwrap();
+        let order: Vec<&str> = events.iter().map(|e| e["event_type"].as_str().unwrap()).collect();
+        assert_eq!(
+            order,
+            vec![
+                EVENT_BENCHMARK_REGRESSION,
+                EVENT_MULTISIG_SIGNATURE_ADDED,
+                EVENT_MULTISIG_PROPOSAL_APPROVED,
+                EVENT_BENCHMARK_REGRESSION,
+            ]
+        );
+        assert_eq!(events[0]["payload"]["contract_id"], "CA");
+    }
+
+    #[test]
+    fn delivery_mode_defaults_to_immediate() {
+        assert_eq!(DeliveryMode::default(), DeliveryMode::Immediate);
+        let mode: DeliveryMode = serde_json::from_value(serde_json::json!("daily_digest")).unwrap();
+        assert_eq!(mode, DeliveryMode::DailyDigest);
+    }
+
+    #[test]
+    fn retry_delay_grows_and_caps() {
+        assert_eq!(retry_delay(0).num_seconds(), 30);
+        assert_eq!(retry_delay(1).num_seconds(), 60);
+        assert_eq!(retry_delay(10).num_seconds(), 3600);
+    }
+}
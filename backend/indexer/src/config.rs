@@ -16,6 +16,18 @@ pub enum ConfigError {
     InvalidConfig(String),
 }
 
+/// RPC endpoint for `network`, from `STELLAR_RPC_<NETWORK>` or the public default
+pub fn rpc_endpoint_for(network: &Network) -> String {
+    match network {
+        Network::Mainnet => env::var("STELLAR_RPC_MAINNET")
+            .unwrap_or_else(|_| "https://rpc-mainnet.stellar.org".to_string()),
+        Network::Testnet => env::var("STELLAR_RPC_TESTNET")
+            .unwrap_or_else(|_| "https://rpc-testnet.stellar.org".to_string()),
+        Network::Futurenet => env::var("STELLAR_RPC_FUTURENET")
+            .unwrap_or_else(|_| "https://rpc-futurenet.stellar.org".to_string()),
+    }
+}
+
 /// Network configuration
 #[derive(Debug, Clone)]
 pub struct NetworkConfig {
@@ -24,34 +36,56 @@ pub struct NetworkConfig {
     pub poll_interval_secs: u64,
 }
 
+/// Parse a network name, case-insensitively
+pub fn parse_network(name: &str) -> Result<Network, ConfigError> {
+    match name.trim().to_lowercase().as_str() {
+        "mainnet" => Ok(Network::Mainnet),
+        "testnet" => Ok(Network::Testnet),
+        "futurenet" => Ok(Network::Futurenet),
+        s => Err(ConfigError::InvalidNetwork(s.to_string())),
+    }
+}
+
+/// Parse a comma-separated network list such as `mainnet,testnet`, dropping
+/// blanks and repeats. An empty list is an error.
+pub fn parse_network_list(raw: &str) -> Result<Vec<Network>, ConfigError> {
+    let mut networks: Vec<Network> = Vec::new();
+    for name in raw.split(',').filter(|name| !name.trim().is_empty()) {
+        let network = parse_network(name)?;
+        if !networks.iter().any(|n| n.to_string() == network.to_string()) {
+            networks.push(network);
+        }
+    }
+    if networks.is_empty() {
+        return Err(ConfigError::InvalidConfig("No networks configured".to_string()));
+    }
+    Ok(networks)
+}
+
 impl NetworkConfig {
     /// Load configuration from environment variables
     pub fn from_env() -> Result<Self, ConfigError> {
-        let network_str = env::var("STELLAR_NETWORK")
-            .unwrap_or_else(|_| "testnet".to_string())
-            .to_lowercase();
-
-        let network = match network_str.as_str() {
-            "mainnet" => Network::Mainnet,
-            "testnet" => Network::Testnet,
-            "futurenet" => Network::Futurenet,
-            s => return Err(ConfigError::InvalidNetwork(s.to_string())),
-        };
+        let network_str = env::var("STELLAR_NETWORK").unwrap_or_else(|_| "testnet".to_string());
+        Self::for_network(parse_network(&network_str)?)
+    }
 
-        let rpc_endpoint = match network {
-            Network::Mainnet => {
-                env::var("STELLAR_RPC_MAINNET")
-                    .unwrap_or_else(|_| "https://rpc-mainnet.stellar.org".to_string())
-            }
-            Network::Testnet => {
-                env::var("STELLAR_RPC_TESTNET")
-                    .unwrap_or_else(|_| "https://rpc-testnet.stellar.org".to_string())
-            }
-            Network::Futurenet => {
-                env::var("STELLAR_RPC_FUTURENET")
-                    .unwrap_or_else(|_| "https://rpc-futurenet.stellar.org".to_string())
-            }
-        };
+    /// One configuration per network to index: `STELLAR_NETWORKS` (comma
+    /// separated) if set, otherwise the single `STELLAR_NETWORK`. Each network
+    /// reads its own `STELLAR_RPC_<NETWORK>` endpoint.
+    pub fn all_from_env() -> Result<Vec<Self>, ConfigError> {
+        match env::var("STELLAR_NETWORKS") {
+            Ok(raw) => parse_network_list(&raw)?
+                .into_iter()
+                .map(Self::for_network)
+                .collect(),
+            Err(_) => Ok(vec![Self::from_env()?]),
+        }
+    }
+
+    /// Configuration for `network`, with the shared poll interval
+    pub fn for_network(network: Network) -> Result<Self, ConfigError> {
+        let network_str = network.to_string();
+        let rpc_endpoint = rpc_endpoint_for(&network);
 
         let poll_interval_secs = env::var("STELLAR_POLL_INTERVAL_SECS")
             .unwrap_or_else(|_| "30".to_string())
@@ -129,7 +163,8 @@ impl DatabaseConfig {
 /// Service configuration combining all settings
 #[derive(Debug, Clone)]
 pub struct ServiceConfig {
-    pub network: NetworkConfig,
+    /// Networks to index, one polling task each
+    pub networks: Vec<NetworkConfig>,
     pub database: DatabaseConfig,
     pub backoff_max_interval_secs: u64,
     pub backoff_base_interval_secs: u64,
@@ -139,7 +174,7 @@ pub struct ServiceConfig {
 impl ServiceConfig {
     /// Load full service configuration
     pub fn from_env() -> Result<Self, ConfigError> {
-        let network = NetworkConfig::from_env()?;
+        let networks = NetworkConfig::all_from_env()?;
         let database = DatabaseConfig::from_env()?;
 
         let backoff_max_interval_secs = env::var("INDEXER_BACKOFF_MAX_SECS")
@@ -178,7 +213,7 @@ impl ServiceConfig {
         );
 
         Ok(ServiceConfig {
-            network,
+            networks,
             database,
             backoff_max_interval_secs,
             backoff_base_interval_secs,
@@ -217,4 +252,14 @@ mod tests {
         assert_eq!(config.network_name(), "testnet");
         assert_eq!(config.poll_interval_secs, 30);
     }
+
+    #[test]
+    fn test_parse_network_list() {
+        let networks = parse_network_list(" Mainnet,testnet,,futurenet,testnet ").unwrap();
+        let names: Vec<String> = networks.iter().map(|n| n.to_string()).collect();
+        assert_eq!(names, vec!["mainnet", "testnet", "futurenet"]);
+
+        assert!(matches!(parse_network_list("testnet,devnet"), Err(ConfigError::InvalidNetwork(n)) if n == "devnet"));
+        assert!(matches!(parse_network_list(" , "), Err(ConfigError::InvalidConfig(_))));
+    }
 }
@@ -6,7 +6,7 @@ use sqlx::{PgPool, Row};
 use thiserror::Error;
 use uuid::Uuid;
 use tracing::{debug, error, info};
-use crate::rpc::ContractDeployment;
+use crate::rpc::{ContractDeployment, ContractInvocation};
 
 #[derive(Error, Debug)]
 pub enum DatabaseError {
@@ -27,7 +27,8 @@ impl DatabaseWriter {
         DatabaseWriter { pool }
     }
 
-    /// Write discovered contract to database
+    /// Write discovered contract to database and record its deployment in
+    /// analytics (once per on-chain operation).
     /// Returns true if new contract was inserted, false if already existed
     pub async fn write_contract(
         &self,
@@ -42,7 +43,7 @@ impl DatabaseWriter {
         let network_str = network_to_str(network);
 
         // Check if contract already exists
-        let existing = sqlx::query(
+        let existing: Option<Uuid> = sqlx::query_scalar(
             r#"
             SELECT id FROM contracts
             WHERE contract_id = $1 AND network = $2::network_type
@@ -58,11 +59,13 @@ impl DatabaseWriter {
             DatabaseError::SqlError(e.to_string())
         })?;
 
-        if existing.is_some() {
+        if let Some(id) = existing {
             debug!(
                 "Contract already exists in database: {}",
                 deployment.contract_id
             );
+            self.record_deployment_event(id, deployment, network).await?;
+            self.update_first_seen(id, deployment).await?;
             return Ok(false);
         }
 
@@ -85,8 +88,9 @@ impl DatabaseWriter {
                 network,
                 is_verified,
                 created_at,
-                updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6::network_type, $7, $8, $9)
+                updated_at,
+                first_seen_at
+            ) VALUES ($1, $2, $3, $4, $5, $6::network_type, $7, $8, $9, $10)
         "#)
             .bind(contract_id)
             .bind(&deployment.contract_id)
@@ -97,6 +101,7 @@ impl DatabaseWriter {
             .bind(false)
             .bind(now)
             .bind(now)
+            .bind(deployment.deployed_at)
             .execute(&self.pool)
             .await
             .map_err(|e| {
@@ -112,9 +117,95 @@ impl DatabaseWriter {
             deployment.contract_id, network_str, deployment.deployer
         );
 
+        self.record_deployment_event(contract_id, deployment, network)
+            .await?;
+
         Ok(true)
     }
 
+    /// Record a `contract_deployed` analytics event for a discovered deployment.
+    /// Keyed by the source operation, so seeing the same ledger again is a no-op.
+    /// Returns true if the event was new.
+    async fn record_deployment_event(
+        &self,
+        contract_uuid: Uuid,
+        deployment: &ContractDeployment,
+        network: &Network,
+    ) -> Result<bool, DatabaseError> {
+        let metadata = serde_json::json!({
+            "source": "indexer",
+            "ledger": deployment.ledger_sequence,
+            "tx_id": deployment.tx_id,
+            "op_id": deployment.op_id,
+            "deployed_at": deployment.deployed_at,
+        });
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO analytics_events (
+                event_type, contract_id, user_address, network, metadata, source_event_id
+            ) VALUES ('contract_deployed', $1, $2, $3::network_type, $4, $5)
+            ON CONFLICT (source_event_id) WHERE source_event_id IS NOT NULL DO NOTHING
+            "#,
+        )
+        .bind(contract_uuid)
+        .bind(&deployment.deployer)
+        .bind(network_to_str(network))
+        .bind(metadata)
+        .bind(deployment_event_key(deployment, network))
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                "Failed to record deployment analytics event: {} ({})",
+                deployment.contract_id, e
+            );
+            DatabaseError::SqlError(e.to_string())
+        })?;
+
+        let recorded = result.rows_affected() > 0;
+        if !recorded {
+            debug!(
+                "Deployment event already recorded: op_id={}",
+                deployment.op_id
+            );
+        }
+        Ok(recorded)
+    }
+
+    /// Move `first_seen_at` back to this deployment's close time if it is
+    /// earlier than what is stored, so ledgers processed out of order still
+    /// leave the earliest sighting.
+    async fn update_first_seen(
+        &self,
+        contract_uuid: Uuid,
+        deployment: &ContractDeployment,
+    ) -> Result<(), DatabaseError> {
+        let Some(deployed_at) = deployment.deployed_at else {
+            return Ok(());
+        };
+
+        sqlx::query(
+            r#"
+            UPDATE contracts
+            SET first_seen_at = $2
+            WHERE id = $1 AND (first_seen_at IS NULL OR first_seen_at > $2)
+            "#,
+        )
+        .bind(contract_uuid)
+        .bind(deployed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                "Failed to update first_seen_at: {} ({})",
+                deployment.contract_id, e
+            );
+            DatabaseError::SqlError(e.to_string())
+        })?;
+        Ok(())
+    }
+
     /// Write multiple contracts in a single transaction
     pub async fn write_contracts_batch(
         &self,
@@ -143,6 +234,55 @@ impl DatabaseWriter {
         Ok((new_count, duplicate_count))
     }
 
+    /// Record contract method calls in `contract_interactions`, once per
+    /// on-chain operation. Calls to contracts the registry does not know are
+    /// dropped. Returns the number of new interactions recorded.
+    pub async fn write_invocations(
+        &self,
+        invocations: &[ContractInvocation],
+        network: &Network,
+    ) -> Result<usize, DatabaseError> {
+        let mut recorded = 0;
+
+        for invocation in invocations {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO contract_interactions (
+                    contract_id, user_address, interaction_type, transaction_hash, method_name, source_event_id
+                )
+                SELECT id, $3, 'invoke', $4, $5, $6
+                FROM contracts
+                WHERE contract_id = $1 AND network = $2::network_type
+                LIMIT 1
+                ON CONFLICT (source_event_id) WHERE source_event_id IS NOT NULL DO NOTHING
+                "#,
+            )
+            .bind(&invocation.contract_id)
+            .bind(network_to_str(network))
+            .bind(&invocation.caller)
+            .bind(&invocation.tx_id)
+            .bind(&invocation.method)
+            .bind(invocation_event_key(invocation, network))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| {
+                error!(
+                    "Failed to record contract invocation: {}::{} ({})",
+                    invocation.contract_id, invocation.method, e
+                );
+                DatabaseError::SqlError(e.to_string())
+            })?;
+            recorded += result.rows_affected() as usize;
+        }
+
+        debug!(
+            "Invocations recorded: new={}, seen={}",
+            recorded,
+            invocations.len()
+        );
+        Ok(recorded)
+    }
+
     /// Get or create a publisher record for a deployer address
     async fn get_or_create_publisher(&self, address: &str) -> Result<Uuid, DatabaseError> {
         debug!("Getting or creating publisher for address: {}", address);
@@ -263,6 +403,16 @@ impl DatabaseWriter {
     }
 }
 
+/// Identity of the on-chain operation behind a deployment event
+fn deployment_event_key(deployment: &ContractDeployment, network: &Network) -> String {
+    format!("indexer:{}:{}", network_to_str(network), deployment.op_id)
+}
+
+/// Dedup key of the interaction recorded for one invocation
+fn invocation_event_key(invocation: &ContractInvocation, network: &Network) -> String {
+    format!("indexer:{}:{}", network_to_str(network), invocation.op_id)
+}
+
 /// Convert Network enum to string for database queries
 fn network_to_str(network: &Network) -> &str {
     match network {
@@ -282,4 +432,106 @@ mod tests {
         assert_eq!(network_to_str(&Network::Testnet), "testnet");
         assert_eq!(network_to_str(&Network::Futurenet), "futurenet");
     }
+
+    fn deployment(contract_id: &str, op_id: &str) -> ContractDeployment {
+        ContractDeployment {
+            contract_id: contract_id.to_string(),
+            deployer: "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC".to_string(),
+            op_id: op_id.to_string(),
+            tx_id: "tx-1".to_string(),
+            ledger_sequence: 812,
+            deployed_at: None,
+        }
+    }
+
+    #[test]
+    fn event_key_identifies_the_operation_per_network() {
+        let d = deployment("CABC", "op-1");
+        assert_eq!(deployment_event_key(&d, &Network::Testnet), "indexer:testnet:op-1");
+        assert_ne!(
+            deployment_event_key(&d, &Network::Testnet),
+            deployment_event_key(&d, &Network::Mainnet)
+        );
+    }
+
+    /// Needs a migrated database: `DATABASE_URL=... cargo test -p indexer same_event -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn same_event_processed_twice_records_one_analytics_event() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url).await.unwrap();
+        let writer = DatabaseWriter::new(pool.clone());
+
+        let contract_id = format!("C{}", Uuid::new_v4().simple()).to_uppercase();
+        let op_id = format!("op-{}", Uuid::new_v4());
+        let d = deployment(&contract_id, &op_id);
+
+        assert!(writer.write_contract(&d, &Network::Testnet).await.unwrap());
+        assert!(!writer.write_contract(&d, &Network::Testnet).await.unwrap());
+
+        let events: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM analytics_events WHERE source_event_id = $1 AND event_type = 'contract_deployed'",
+        )
+        .bind(deployment_event_key(&d, &Network::Testnet))
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(events, 1);
+
+        // A later deployment operation for the same contract is a new event
+        let redeploy = deployment(&contract_id, &format!("{}-2", op_id));
+        writer.write_contract(&redeploy, &Network::Testnet).await.unwrap();
+        let total: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM analytics_events a JOIN contracts c ON c.id = a.contract_id
+             WHERE c.contract_id = $1 AND a.event_type = 'contract_deployed'",
+        )
+        .bind(&contract_id)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!(total, 2);
+    }
+
+    /// Needs a migrated database: `DATABASE_URL=... cargo test -p indexer first_seen -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn indexed_contract_is_first_seen_before_it_is_registered() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url).await.unwrap();
+        let writer = DatabaseWriter::new(pool.clone());
+
+        let contract_id = format!("C{}", Uuid::new_v4().simple()).to_uppercase();
+        let deployed_at = chrono::Utc::now() - chrono::Duration::hours(6);
+        let mut d = deployment(&contract_id, &format!("op-{}", Uuid::new_v4()));
+        d.deployed_at = Some(deployed_at);
+        assert!(writer.write_contract(&d, &Network::Testnet).await.unwrap());
+
+        let stored: Contract = sqlx::query_as("SELECT * FROM contracts WHERE contract_id = $1")
+            .bind(&contract_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        let first_seen_at = stored.first_seen_at.expect("indexer sets first_seen_at");
+        assert!(first_seen_at < stored.created_at);
+        assert_eq!(first_seen_at.timestamp(), deployed_at.timestamp());
+
+        // A later sighting does not move it forward; an earlier one moves it back
+        let mut later = deployment(&contract_id, &format!("op-{}", Uuid::new_v4()));
+        later.deployed_at = Some(deployed_at + chrono::Duration::hours(1));
+        writer.write_contract(&later, &Network::Testnet).await.unwrap();
+        let mut earlier = deployment(&contract_id, &format!("op-{}", Uuid::new_v4()));
+        earlier.deployed_at = Some(deployed_at - chrono::Duration::hours(1));
+        writer.write_contract(&earlier, &Network::Testnet).await.unwrap();
+
+        let first_seen_at: Option<chrono::DateTime<chrono::Utc>> =
+            sqlx::query_scalar("SELECT first_seen_at FROM contracts WHERE contract_id = $1")
+                .bind(&contract_id)
+                .fetch_one(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            first_seen_at.map(|t| t.timestamp()),
+            Some((deployed_at - chrono::Duration::hours(1)).timestamp())
+        );
+    }
 }
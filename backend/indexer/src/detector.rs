@@ -1,7 +1,9 @@
 /// Contract detection module
-/// Identifies createContract operations and extracts contract metadata
+/// Identifies createContract operations and extracts contract metadata,
+/// and invokeHostFunction operations and the contract method they call
 
-use crate::rpc::{ContractDeployment, Operation};
+use crate::rpc::{ContractDeployment, ContractInvocation, Operation};
+use chrono::{DateTime, Utc};
 use tracing::{debug, error};
 
 /// Detect createContract operations in a list of operations
@@ -77,6 +79,70 @@ fn extract_contract_deployment(
         op_id: op.id.clone(),
         tx_id: op.tx_id.clone(),
         ledger_sequence,
+        deployed_at: op.created_at.as_deref().and_then(parse_close_time),
+    })
+}
+
+/// Parse an operation's RFC 3339 close time; a malformed value is logged and
+/// dropped rather than failing the deployment.
+fn parse_close_time(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| debug!("Ignoring unparseable operation close time {:?}: {}", raw, e))
+        .ok()
+}
+
+/// Detect contract method calls (invokeHostFunction operations)
+pub fn detect_contract_invocations(
+    operations: &[Operation],
+    ledger_sequence: u64,
+) -> Vec<ContractInvocation> {
+    operations
+        .iter()
+        // invokeHostFunction has type_code 24 in Stellar operations
+        .filter(|op| op.type_code == 24)
+        .filter_map(|op| match extract_contract_invocation(op, ledger_sequence) {
+            Ok(invocation) => Some(invocation),
+            Err(e) => {
+                debug!("Skipping invokeHostFunction operation {}: {}", op.id, e);
+                None
+            }
+        })
+        .collect()
+}
+
+/// Extract the called contract and method from an invokeHostFunction operation
+fn extract_contract_invocation(
+    op: &Operation,
+    ledger_sequence: u64,
+) -> Result<ContractInvocation, String> {
+    let body = &op.body;
+
+    let contract_id = extract_field_string(body, "contract")
+        .or_else(|_| extract_field_string(body, "contract_id"))
+        .or_else(|_| extract_field_string(body, "address"))
+        .map_err(|_| "Missing contract_id in operation body".to_string())?;
+    if !contract_id.starts_with('C') {
+        return Err(format!("Not a contract invocation: {}", contract_id));
+    }
+
+    // Uploads and deployments are also invokeHostFunction ops but name no method
+    let method = extract_field_string(body, "function_name")
+        .or_else(|_| extract_field_string(body, "method"))
+        .or_else(|_| extract_field_string(body, "function"))
+        .map(|name| name.trim().to_string())
+        .map_err(|_| "Missing method name in operation body".to_string())?;
+    if method.is_empty() || method.starts_with("HostFunctionType") {
+        return Err(format!("Not a method call: {:?}", method));
+    }
+
+    Ok(ContractInvocation {
+        contract_id,
+        method,
+        caller: extract_field_string(body, "source_account").ok(),
+        op_id: op.id.clone(),
+        tx_id: op.tx_id.clone(),
+        ledger_sequence,
     })
 }
 
@@ -106,6 +172,7 @@ mod tests {
             type_code: 1,
             type_name: "payment".to_string(),
             body: serde_json::json!({}),
+            created_at: None,
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
@@ -126,6 +193,7 @@ mod tests {
                 "contract": contract_id.clone(),
                 "source_account": deployer.clone(),
             }),
+            created_at: Some("2026-10-16T12:00:00Z".to_string()),
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
@@ -133,6 +201,10 @@ mod tests {
         assert_eq!(deployments[0].contract_id, contract_id);
         assert_eq!(deployments[0].deployer, deployer);
         assert_eq!(deployments[0].ledger_sequence, 100);
+        assert_eq!(
+            deployments[0].deployed_at.map(|t| t.to_rfc3339()),
+            Some("2026-10-16T12:00:00+00:00".to_string())
+        );
     }
 
     #[test]
@@ -146,6 +218,7 @@ mod tests {
                 "contract": "INVALID_FORMAT",
                 "source_account": "GBRPYHIL2CI3WHZDTOOQFC6EB4RRJC3D5NZ4FJHSVOBXUXVLCJGXI2V",
             }),
+            created_at: None,
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
@@ -162,12 +235,60 @@ mod tests {
             body: serde_json::json!({
                 "source_account": "GBRPYHIL2CI3WHZDTOOQFC6EB4RRJC3D5NZ4FJHSVOBXUXVLCJGXI2V",
             }),
+            created_at: None,
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
         assert_eq!(deployments.len(), 0);
     }
 
+    fn invoke(body: serde_json::Value) -> Operation {
+        Operation {
+            id: "op9".to_string(),
+            tx_id: "tx9".to_string(),
+            type_code: 24,
+            type_name: "invoke_host_function".to_string(),
+            body,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn test_detect_contract_invocation_method() {
+        let contract_id = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+        let ops = vec![invoke(serde_json::json!({
+            "contract": contract_id,
+            "function_name": "transfer",
+            "source_account": "GBRPYHIL2CI3WHZDTOOQFC6EB4RRJC3D5NZ4FJHSVOBXUXVLCJGXI2V",
+        }))];
+
+        let invocations = detect_contract_invocations(&ops, 100);
+        assert_eq!(invocations.len(), 1);
+        assert_eq!(invocations[0].contract_id, contract_id);
+        assert_eq!(invocations[0].method, "transfer");
+        assert_eq!(invocations[0].op_id, "op9");
+        assert_eq!(invocations[0].ledger_sequence, 100);
+    }
+
+    #[test]
+    fn test_invocations_without_a_method_are_skipped() {
+        let contract_id = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+        let ops = vec![
+            invoke(serde_json::json!({ "contract": contract_id })),
+            invoke(serde_json::json!({
+                "contract": contract_id,
+                "function": "HostFunctionTypeHostFunctionTypeUploadContractWasm",
+            })),
+            invoke(serde_json::json!({ "function_name": "transfer" })),
+        ];
+        assert!(detect_contract_invocations(&ops, 100).is_empty());
+
+        // createContract operations are not method calls
+        let mut deploy = invoke(serde_json::json!({ "contract": contract_id, "function_name": "init" }));
+        deploy.type_code = 110;
+        assert!(detect_contract_invocations(&[deploy], 100).is_empty());
+    }
+
     #[test]
     fn test_verify_ledger_hash() {
         assert!(verify_ledger_hash("abc123", "abc123"));
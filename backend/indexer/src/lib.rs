@@ -3,6 +3,7 @@ pub mod backoff;
 pub mod config;
 pub mod db;
 pub mod detector;
+pub mod network_indexer;
 pub mod reorg;
 pub mod rpc;
 pub mod state;
@@ -10,7 +11,12 @@ pub mod state;
 pub use backoff::ExponentialBackoff;
 pub use config::{DatabaseConfig, NetworkConfig, ServiceConfig};
 pub use db::DatabaseWriter;
-pub use detector::detect_contract_deployments;
+pub use detector::{detect_contract_deployments, detect_contract_invocations};
+pub use network_indexer::NetworkIndexer;
 pub use reorg::ReorgHandler;
-pub use rpc::{ContractDeployment, Ledger, Operation, StellarRpcClient};
+pub use rpc::{
+    ContractData, ContractDeployment, ContractEvent, ContractInvocation, ContractLookup, HttpTransport, Ledger,
+    NetworkRpcClients, OnChainContract, Operation, RateLimiter, RpcClientConfig, RpcError,
+    RpcTransport, StellarRpcClient, TransportResponse,
+};
 pub use state::{IndexerState, StateManager};
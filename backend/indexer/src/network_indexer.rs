@@ -0,0 +1,396 @@
+/// Per-network indexing task
+/// Polls one network's RPC endpoint from that network's own cursor and writes
+/// what it finds tagged with that network. The service runs one of these per
+/// configured network, concurrently.
+use crate::backoff::ExponentialBackoff;
+use crate::config::{NetworkConfig, ServiceConfig};
+use crate::db::DatabaseWriter;
+use crate::detector;
+use crate::reorg::ReorgHandler;
+use crate::rpc::StellarRpcClient;
+use crate::state::{IndexerState, StateManager};
+use anyhow::Result;
+use sqlx::PgPool;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// Most ledgers processed in one poll cycle, so one network catching up
+/// does not hold a cycle open indefinitely
+const MAX_LEDGERS_PER_CYCLE: u64 = 10;
+
+pub struct NetworkIndexer {
+    config: NetworkConfig,
+    rpc_client: StellarRpcClient,
+    db_writer: DatabaseWriter,
+    state_manager: StateManager,
+    reorg_handler: ReorgHandler,
+    backoff: ExponentialBackoff,
+}
+
+impl NetworkIndexer {
+    pub fn new(
+        config: NetworkConfig,
+        rpc_client: StellarRpcClient,
+        pool: PgPool,
+        service: &ServiceConfig,
+    ) -> Self {
+        NetworkIndexer {
+            config,
+            rpc_client,
+            db_writer: DatabaseWriter::new(pool.clone()),
+            state_manager: StateManager::new(pool),
+            reorg_handler: ReorgHandler::new(service.reorg_checkpoint_depth),
+            backoff: ExponentialBackoff::new(
+                service.backoff_base_interval_secs,
+                service.backoff_max_interval_secs,
+            ),
+        }
+    }
+
+    pub fn network_name(&self) -> &str {
+        self.config.network_name()
+    }
+
+    /// Run the polling loop for this network
+    pub async fn run(&mut self) -> Result<()> {
+        let network_name = self.config.network_name().to_string();
+        info!(network = %network_name, "Starting indexer for network");
+
+        // Load initial state
+        let mut state = match self.state_manager.load_state(&self.config.network).await {
+            Ok(s) => {
+                info!(
+                    network = %network_name,
+                    "Loaded indexer state: last_indexed_ledger={}",
+                    s.last_indexed_ledger_height
+                );
+                s
+            }
+            Err(e) => {
+                error!(
+                    network = %network_name,
+                    "Failed to load indexer state: {}, initializing with defaults",
+                    e
+                );
+                IndexerState {
+                    network: self.config.network.clone(),
+                    last_indexed_ledger_height: 0,
+                    last_checkpoint_ledger_height: 0,
+                    consecutive_failures: 0,
+                }
+            }
+        };
+
+        // Health check before starting
+        match self.rpc_client.health_check().await {
+            Ok(_) => info!(network = %network_name, "RPC endpoint health check passed"),
+            Err(e) => warn!(network = %network_name, "Initial RPC health check failed: {}, will retry", e),
+        }
+
+        // Main polling loop
+        loop {
+            let poll_duration = Duration::from_secs(self.config.poll_interval_secs);
+
+            match self.poll_and_index(&mut state).await {
+                Ok(_) => {
+                    self.backoff.on_success();
+                }
+                Err(e) => {
+                    error!(network = %network_name, "Error during polling cycle: {}", e);
+                    state.record_failure();
+
+                    let backoff_duration = self.backoff.on_failure(&e.to_string());
+                    let backoff_secs = backoff_duration.as_secs();
+
+                    // Record error in state manager
+                    let _ = self
+                        .state_manager
+                        .record_error(&self.config.network, &e.to_string())
+                        .await;
+
+                    warn!(
+                        network = %network_name,
+                        attempt = self.backoff.attempts(),
+                        backoff_secs = backoff_secs,
+                        "Backing off before retry"
+                    );
+
+                    tokio::time::sleep(backoff_duration).await;
+                    continue;
+                }
+            }
+
+            // Wait for next poll cycle
+            tokio::time::sleep(poll_duration).await;
+        }
+    }
+
+    /// Single polling and indexing cycle
+    async fn poll_and_index(&mut self, state: &mut IndexerState) -> Result<()> {
+        let network_name = self.config.network_name();
+
+        // Get latest ledger
+        let latest_ledger = self.rpc_client.get_latest_ledger().await?;
+        let next_ledger = state.next_ledger_to_process();
+
+        info!(
+            network = network_name,
+            latest_ledger = latest_ledger.sequence,
+            next_ledger = next_ledger,
+            lag = latest_ledger.sequence.saturating_sub(next_ledger),
+            "Poll cycle started"
+        );
+
+        // Check for reorg
+        if self
+            .reorg_handler
+            .check_for_reorg(&self.rpc_client, state)
+            .await?
+        {
+            warn!(
+                network = network_name,
+                "Reorg detected, recovering to checkpoint"
+            );
+            self.reorg_handler
+                .recover_from_reorg(state, &self.state_manager)
+                .await?;
+            return Ok(());
+        }
+
+        // Process ledgers up to latest (but limit to prevent long processing cycles)
+        let ledgers_to_process = std::cmp::min(
+            latest_ledger.sequence.saturating_sub(next_ledger) + 1,
+            MAX_LEDGERS_PER_CYCLE,
+        );
+
+        let mut total_contracts = 0;
+
+        for i in 0..ledgers_to_process {
+            let ledger_height = next_ledger + i;
+            total_contracts += self.index_ledger(ledger_height).await?;
+
+            // Update state
+            state.last_indexed_ledger_height = ledger_height;
+            state.clear_failures();
+
+            // Check if we should update checkpoint
+            if self.reorg_handler.should_update_checkpoint(
+                ledger_height,
+                state.last_checkpoint_ledger_height,
+            ) {
+                state.update_checkpoint(ledger_height);
+                self.state_manager
+                    .update_checkpoint(&self.config.network, ledger_height)
+                    .await?;
+            }
+        }
+
+        // Persist state after successful cycle
+        self.state_manager.update_state(state).await?;
+
+        info!(
+            network = network_name,
+            processed = ledgers_to_process,
+            new_contracts = total_contracts,
+            "Poll cycle completed successfully"
+        );
+
+        Ok(())
+    }
+
+    /// Fetch one ledger's operations and record its contract deployments and
+    /// method calls under this indexer's network. Returns the number of new
+    /// contracts.
+    pub async fn index_ledger(&self, ledger_height: u64) -> Result<usize> {
+        let network_name = self.config.network_name();
+
+        let operations = match self.rpc_client.get_ledger_operations(ledger_height).await {
+            Ok(operations) => operations,
+            Err(e) => {
+                error!(
+                    network = network_name,
+                    ledger = ledger_height,
+                    error = %e,
+                    "Failed to fetch ledger operations"
+                );
+                return Err(e.into());
+            }
+        };
+        info!(
+            network = network_name,
+            ledger = ledger_height,
+            operations = operations.len(),
+            "Fetched ledger operations"
+        );
+
+        let mut new_contracts = 0;
+
+        // Detect contract deployments
+        let deployments = detector::detect_contract_deployments(&operations, ledger_height);
+        if !deployments.is_empty() {
+            info!(
+                network = network_name,
+                ledger = ledger_height,
+                contracts = deployments.len(),
+                "Found contract deployments"
+            );
+
+            // Write to database
+            match self
+                .db_writer
+                .write_contracts_batch(&deployments, &self.config.network)
+                .await
+            {
+                Ok((new_count, duplicate_count)) => {
+                    info!(
+                        network = network_name,
+                        ledger = ledger_height,
+                        new = new_count,
+                        duplicates = duplicate_count,
+                        "Contracts written to database"
+                    );
+                    new_contracts = new_count;
+                }
+                Err(e) => {
+                    error!(
+                        network = network_name,
+                        ledger = ledger_height,
+                        error = %e,
+                        "Failed to write contracts"
+                    );
+                    return Err(e.into());
+                }
+            }
+        }
+
+        // Record contract method calls; failures do not hold back indexing
+        let invocations = detector::detect_contract_invocations(&operations, ledger_height);
+        if !invocations.is_empty() {
+            if let Err(e) = self
+                .db_writer
+                .write_invocations(&invocations, &self.config.network)
+                .await
+            {
+                warn!(
+                    network = network_name,
+                    ledger = ledger_height,
+                    error = %e,
+                    "Failed to record contract invocations"
+                );
+            }
+        }
+
+        Ok(new_contracts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DatabaseConfig;
+    use crate::rpc::{RpcClientConfig, RpcError, RpcTransport, TransportResponse};
+    use async_trait::async_trait;
+    use shared::Network;
+    use std::sync::Arc;
+    use uuid::Uuid;
+
+    /// Answers every request with the same operations page
+    struct OperationsTransport {
+        body: String,
+    }
+
+    #[async_trait]
+    impl RpcTransport for OperationsTransport {
+        async fn get(&self, _url: &str, _timeout: Duration) -> Result<TransportResponse, RpcError> {
+            Ok(TransportResponse {
+                status: 200,
+                body: self.body.clone(),
+            })
+        }
+    }
+
+    fn create_contract_page(contract_id: &str) -> String {
+        serde_json::json!({
+            "records": [{
+                "id": format!("op-{}", Uuid::new_v4()),
+                "transaction_hash": format!("tx-{}", Uuid::new_v4()),
+                "type_code": 110,
+                "type_name": "createContract",
+                "created_at": "2026-10-16T12:00:00Z",
+                "body": {
+                    "contract": contract_id,
+                    "source_account": "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC",
+                },
+            }]
+        })
+        .to_string()
+    }
+
+    /// A fresh 56-character contract id
+    fn fresh_contract_id() -> String {
+        let id = format!("C{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple()).to_uppercase();
+        id[..56].to_string()
+    }
+
+    fn indexer_for(network: Network, contract_id: &str, pool: PgPool) -> NetworkIndexer {
+        let endpoint = format!("https://rpc-{}.test", network);
+        let rpc = StellarRpcClient::with_transport(
+            endpoint.clone(),
+            RpcClientConfig {
+                requests_per_second: 0,
+                ..RpcClientConfig::default()
+            },
+            Arc::new(OperationsTransport {
+                body: create_contract_page(contract_id),
+            }),
+        );
+        let service = ServiceConfig {
+            networks: vec![],
+            database: DatabaseConfig {
+                connection_string: String::new(),
+                max_connections: 1,
+            },
+            backoff_max_interval_secs: 1,
+            backoff_base_interval_secs: 1,
+            reorg_checkpoint_depth: 100,
+        };
+        let config = NetworkConfig {
+            network,
+            rpc_endpoint: endpoint,
+            poll_interval_secs: 1,
+        };
+        NetworkIndexer::new(config, rpc, pool, &service)
+    }
+
+    /// Needs a migrated database: `DATABASE_URL=... cargo test -p indexer per_network -- --ignored`
+    #[tokio::test]
+    #[ignore]
+    async fn per_network_indexers_tag_contracts_with_their_network() {
+        let url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
+        let pool = PgPool::connect(&url).await.unwrap();
+
+        let on_mainnet = fresh_contract_id();
+        let on_testnet = fresh_contract_id();
+        let mainnet = indexer_for(Network::Mainnet, &on_mainnet, pool.clone());
+        let testnet = indexer_for(Network::Testnet, &on_testnet, pool.clone());
+
+        let (m, t) = tokio::join!(mainnet.index_ledger(812), testnet.index_ledger(812));
+        assert_eq!(m.unwrap(), 1);
+        assert_eq!(t.unwrap(), 1);
+
+        let network_of = |contract_id: String| {
+            let pool = pool.clone();
+            async move {
+                sqlx::query_scalar::<_, String>(
+                    "SELECT network::text FROM contracts WHERE contract_id = $1",
+                )
+                .bind(contract_id)
+                .fetch_all(&pool)
+                .await
+                .unwrap()
+            }
+        };
+        assert_eq!(network_of(on_mainnet).await, vec!["mainnet"]);
+        assert_eq!(network_of(on_testnet).await, vec!["testnet"]);
+    }
+}
@@ -1,8 +1,17 @@
 /// RPC client for polling Stellar network ledgers
 /// Handles HTTP requests to Stellar RPC endpoints and deserializes ledger/operation data
-
+///
+/// Every request goes through a per-endpoint rate limiter and is retried with
+/// exponential backoff on timeouts, connection failures, 429 and 5xx
+/// responses. The HTTP layer sits behind [`RpcTransport`] so tests can script
+/// responses.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::time::Duration;
+use shared::Network;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tracing::{debug, error, warn};
 
@@ -18,11 +27,168 @@ pub enum RpcError {
     Timeout,
 }
 
+/// Timeouts, retries and rate limit for one RPC endpoint
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RpcClientConfig {
+    pub request_timeout: Duration,
+    /// Retries after the first attempt, for transient failures only
+    pub max_retries: u32,
+    /// Delay before the first retry; doubles on each further retry
+    pub retry_base_delay: Duration,
+    /// Requests per second sent to the endpoint; 0 disables the limit
+    pub requests_per_second: u32,
+}
+
+impl Default for RpcClientConfig {
+    fn default() -> Self {
+        RpcClientConfig {
+            request_timeout: Duration::from_secs(30),
+            max_retries: 3,
+            retry_base_delay: Duration::from_millis(500),
+            requests_per_second: 10,
+        }
+    }
+}
+
+fn env_parse<T: std::str::FromStr>(name: &str) -> Option<T> {
+    std::env::var(name).ok().and_then(|raw| raw.trim().parse().ok())
+}
+
+impl RpcClientConfig {
+    /// Read `STELLAR_RPC_TIMEOUT_SECS`, `STELLAR_RPC_MAX_RETRIES`,
+    /// `STELLAR_RPC_RETRY_BASE_MS` and `STELLAR_RPC_RATE_LIMIT`; unset or
+    /// invalid values keep the defaults.
+    pub fn from_env() -> Self {
+        let defaults = RpcClientConfig::default();
+        RpcClientConfig {
+            request_timeout: env_parse::<u64>("STELLAR_RPC_TIMEOUT_SECS")
+                .filter(|secs| *secs > 0)
+                .map(Duration::from_secs)
+                .unwrap_or(defaults.request_timeout),
+            max_retries: env_parse("STELLAR_RPC_MAX_RETRIES").unwrap_or(defaults.max_retries),
+            retry_base_delay: env_parse::<u64>("STELLAR_RPC_RETRY_BASE_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.retry_base_delay),
+            requests_per_second: env_parse("STELLAR_RPC_RATE_LIMIT").unwrap_or(defaults.requests_per_second),
+        }
+    }
+}
+
+/// Spaces requests evenly so an endpoint never sees more than the configured rate
+#[derive(Debug)]
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn per_second(requests: u32) -> Self {
+        let interval = if requests == 0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs(1) / requests
+        };
+        RateLimiter {
+            interval,
+            next_slot: Mutex::new(None),
+        }
+    }
+
+    /// Claim the next free slot; returns how long to wait before sending.
+    pub fn reserve(&self, now: Instant) -> Duration {
+        if self.interval.is_zero() {
+            return Duration::ZERO;
+        }
+        let mut next_slot = self.next_slot.lock().unwrap_or_else(|p| p.into_inner());
+        let slot = match *next_slot {
+            Some(slot) if slot > now => slot,
+            _ => now,
+        };
+        *next_slot = Some(slot + self.interval);
+        slot - now
+    }
+
+    pub async fn acquire(&self) {
+        let wait = self.reserve(Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// Status and body of a completed HTTP exchange
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+impl TransportResponse {
+    fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Rate limited or a server-side failure; worth another try
+    fn is_retryable(&self) -> bool {
+        self.status == 429 || self.status >= 500
+    }
+
+    fn into_body(self) -> Result<String, RpcError> {
+        if self.is_success() {
+            Ok(self.body)
+        } else {
+            Err(RpcError::RpcError(format!("HTTP {}: {}", self.status, self.body)))
+        }
+    }
+}
+
+/// The HTTP layer under [`StellarRpcClient`]
+#[async_trait]
+pub trait RpcTransport: Send + Sync {
+    async fn get(&self, url: &str, timeout: Duration) -> Result<TransportResponse, RpcError>;
+}
+
+/// [`RpcTransport`] over reqwest
+pub struct HttpTransport {
+    client: reqwest::Client,
+}
+
+impl Default for HttpTransport {
+    fn default() -> Self {
+        HttpTransport {
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl RpcTransport for HttpTransport {
+    async fn get(&self, url: &str, timeout: Duration) -> Result<TransportResponse, RpcError> {
+        let response = self.client.get(url).timeout(timeout).send().await.map_err(|e| {
+            if e.is_timeout() {
+                RpcError::Timeout
+            } else {
+                RpcError::RequestFailed(e.to_string())
+            }
+        })?;
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(|e| {
+            if e.is_timeout() {
+                RpcError::Timeout
+            } else {
+                RpcError::InvalidResponse(format!("Failed to read response: {}", e))
+            }
+        })?;
+        Ok(TransportResponse { status, body })
+    }
+}
+
 /// Stellar RPC client
 pub struct StellarRpcClient {
     endpoint: String,
-    client: reqwest::Client,
-    request_timeout: Duration,
+    transport: Arc<dyn RpcTransport>,
+    config: RpcClientConfig,
+    limiter: RateLimiter,
 }
 
 /// Ledger information from RPC response
@@ -45,6 +211,9 @@ pub struct Operation {
     pub type_name: String,
     #[serde(default)]
     pub body: serde_json::Value,
+    /// Close time of the ledger the operation was applied in (RFC 3339)
+    #[serde(default)]
+    pub created_at: Option<String>,
 }
 
 /// Contract deployment operation details
@@ -55,6 +224,61 @@ pub struct ContractDeployment {
     pub op_id: String,
     pub tx_id: String,
     pub ledger_sequence: u64,
+    /// When the deployment landed on chain, if the operation carried a close time
+    pub deployed_at: Option<DateTime<Utc>>,
+}
+
+/// A contract method call from an invokeHostFunction operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractInvocation {
+    pub contract_id: String,
+    pub method: String,
+    pub caller: Option<String>,
+    pub op_id: String,
+    pub tx_id: String,
+    pub ledger_sequence: u64,
+}
+
+/// A contract instance as it currently exists on-chain
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OnChainContract {
+    pub contract_id: String,
+    pub wasm_hash: String,
+}
+
+/// Look up contract instances on a given network.
+///
+/// Implemented by [`NetworkRpcClients`]; tests substitute an in-memory mock.
+#[async_trait]
+pub trait ContractLookup: Send + Sync {
+    /// `Ok(None)` when the network has no contract with this id.
+    async fn get_contract(
+        &self,
+        network: &Network,
+        contract_id: &str,
+    ) -> Result<Option<OnChainContract>, RpcError>;
+}
+
+/// A contract event emitted in a ledger
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractEvent {
+    pub id: String,
+    pub ledger: u64,
+    pub contract_id: String,
+    #[serde(default)]
+    pub topics: Vec<serde_json::Value>,
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+/// One storage entry of a contract instance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContractData {
+    pub contract_id: String,
+    pub key: String,
+    pub value: serde_json::Value,
+    #[serde(default)]
+    pub last_modified_ledger: Option<u64>,
 }
 
 /// RPC response for ledgers
@@ -79,6 +303,11 @@ struct OperationsResponse {
     records: Vec<OperationRecord>,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+struct EventsResponse {
+    events: Vec<ContractEvent>,
+}
+
 #[derive(Debug, Clone, Deserialize)]
 struct OperationRecord {
     id: String,
@@ -87,53 +316,69 @@ struct OperationRecord {
     type_name: String,
     #[serde(default)]
     body: serde_json::Value,
+    #[serde(default)]
+    created_at: Option<String>,
 }
 
 impl StellarRpcClient {
-    /// Create new Stellar RPC client
+    /// Create new Stellar RPC client with the default configuration
     pub fn new(endpoint: String) -> Self {
-        let client = reqwest::ClientBuilder::new()
-            .timeout(Duration::from_secs(30))
-            .build()
-            .unwrap_or_else(|_| reqwest::Client::new());
+        Self::with_config(endpoint, RpcClientConfig::default())
+    }
+
+    pub fn with_config(endpoint: String, config: RpcClientConfig) -> Self {
+        Self::with_transport(endpoint, config, Arc::new(HttpTransport::default()))
+    }
 
+    pub fn with_transport(endpoint: String, config: RpcClientConfig, transport: Arc<dyn RpcTransport>) -> Self {
         StellarRpcClient {
-            endpoint,
-            client,
-            request_timeout: Duration::from_secs(30),
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            transport,
+            limiter: RateLimiter::per_second(config.requests_per_second),
+            config,
         }
     }
 
-    /// Fetch ledger by sequence number
-    pub async fn get_ledger(&self, sequence: u64) -> Result<Ledger, RpcError> {
-        let url = format!("{}/ledgers/{}", self.endpoint, sequence);
-        debug!("Fetching ledger from {}", url);
-
-        let response = self
-            .client
-            .get(&url)
-            .timeout(self.request_timeout)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    RpcError::Timeout
-                } else {
-                    RpcError::RequestFailed(e.to_string())
-                }
-            })?;
-
-        if !response.status().is_success() {
-            return Err(RpcError::RpcError(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
+    /// GET `path` under the endpoint, rate limited and retried on transient failures.
+    async fn fetch(&self, path: &str) -> Result<TransportResponse, RpcError> {
+        let url = format!("{}{}", self.endpoint, path);
+        let mut attempt = 0u32;
+        loop {
+            self.limiter.acquire().await;
+            debug!(url = %url, attempt, "RPC request");
+            let result = self.transport.get(&url, self.config.request_timeout).await;
+
+            let retryable = match &result {
+                Ok(response) => response.is_retryable(),
+                Err(RpcError::Timeout) | Err(RpcError::RequestFailed(_)) => true,
+                Err(_) => false,
+            };
+            if !retryable || attempt >= self.config.max_retries {
+                return result;
+            }
+
+            let delay = self
+                .config
+                .retry_base_delay
+                .saturating_mul(2u32.saturating_pow(attempt));
+            attempt += 1;
+            warn!(url = %url, attempt, ?delay, "RPC request failed, retrying");
+            tokio::time::sleep(delay).await;
         }
+    }
 
-        let data: LedgerResponse = response.json().await.map_err(|e| {
-            RpcError::InvalidResponse(format!("Failed to parse ledger response: {}", e))
-        })?;
+    /// Fetch `path` and parse a successful JSON body.
+    async fn fetch_json<T: serde::de::DeserializeOwned>(&self, path: &str, what: &str) -> Result<T, RpcError> {
+        let body = self.fetch(path).await?.into_body()?;
+        serde_json::from_str(&body)
+            .map_err(|e| RpcError::InvalidResponse(format!("Failed to parse {} response: {}", what, e)))
+    }
+
+    /// Fetch ledger by sequence number
+    pub async fn get_ledger(&self, sequence: u64) -> Result<Ledger, RpcError> {
+        let data: LedgerResponse = self
+            .fetch_json(&format!("/ledgers/{}", sequence), "ledger")
+            .await?;
 
         Ok(Ledger {
             sequence: data.sequence,
@@ -146,37 +391,12 @@ impl StellarRpcClient {
 
     /// Fetch operations for a ledger
     pub async fn get_ledger_operations(&self, sequence: u64) -> Result<Vec<Operation>, RpcError> {
-        let url = format!(
-            "{}/ledgers/{}/operations?order=asc&limit=200",
-            self.endpoint, sequence
-        );
-        debug!("Fetching operations for ledger {} from {}", sequence, url);
-
-        let response = self
-            .client
-            .get(&url)
-            .timeout(self.request_timeout)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    RpcError::Timeout
-                } else {
-                    RpcError::RequestFailed(e.to_string())
-                }
-            })?;
-
-        if !response.status().is_success() {
-            return Err(RpcError::RpcError(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
-        }
-
-        let data: OperationsResponse = response.json().await.map_err(|e| {
-            RpcError::InvalidResponse(format!("Failed to parse operations response: {}", e))
-        })?;
+        let data: OperationsResponse = self
+            .fetch_json(
+                &format!("/ledgers/{}/operations?order=asc&limit=200", sequence),
+                "operations",
+            )
+            .await?;
 
         Ok(data
             .records
@@ -187,145 +407,381 @@ impl StellarRpcClient {
                 type_code: op.type_code,
                 type_name: op.type_name,
                 body: op.body,
+                created_at: op.created_at,
             })
             .collect())
     }
 
     /// Get the latest ledger
     pub async fn get_latest_ledger(&self) -> Result<Ledger, RpcError> {
-        let url = format!("{}/ledgers?order=desc&limit=1", self.endpoint);
-        debug!("Fetching latest ledger from {}", url);
+        let response_text = self.fetch("/ledgers?order=desc&limit=1").await?.into_body()?;
+        parse_latest_ledger(&response_text)
+    }
 
-        let response = self
-            .client
-            .get(&url)
-            .timeout(self.request_timeout)
-            .send()
-            .await
-            .map_err(|e| {
-                if e.is_timeout() {
-                    RpcError::Timeout
-                } else {
-                    RpcError::RequestFailed(e.to_string())
-                }
-            })?;
-
-        if !response.status().is_success() {
-            return Err(RpcError::RpcError(format!(
-                "HTTP {}: {}",
-                response.status(),
-                response.text().await.unwrap_or_default()
-            )));
+    /// Events from `start_ledger` onwards, optionally for one contract only
+    pub async fn get_events(
+        &self,
+        start_ledger: u64,
+        contract_id: Option<&str>,
+        limit: u32,
+    ) -> Result<Vec<ContractEvent>, RpcError> {
+        let mut path = format!("/events?start_ledger={}&limit={}", start_ledger, limit);
+        if let Some(contract_id) = contract_id {
+            path.push_str(&format!("&contract_id={}", encode_component(contract_id)));
         }
+        let data: EventsResponse = self.fetch_json(&path, "events").await?;
+        Ok(data.events)
+    }
 
-        // Parse the response - it returns an array
-        let response_text = response.text().await.map_err(|e| {
-            RpcError::InvalidResponse(format!("Failed to read response: {}", e))
-        })?;
+    /// One storage entry of a contract; `Ok(None)` if the endpoint reports 404
+    pub async fn get_contract_data(&self, contract_id: &str, key: &str) -> Result<Option<ContractData>, RpcError> {
+        let path = format!(
+            "/contracts/{}/data/{}",
+            encode_component(contract_id),
+            encode_component(key)
+        );
+        let response = self.fetch(&path).await?;
+        if response.status == 404 {
+            return Ok(None);
+        }
+        let body = response.into_body()?;
+        serde_json::from_str(&body)
+            .map(Some)
+            .map_err(|e| RpcError::InvalidResponse(format!("Failed to parse contract data response: {}", e)))
+    }
 
-        let data: serde_json::Value = serde_json::from_str(&response_text)
-            .map_err(|e| {
-                error!("Invalid JSON in ledger response: {}", e);
-                RpcError::InvalidResponse(format!("Invalid JSON: {}", e))
-            })?;
-
-        // Extract first ledger from _embedded records
-        let ledgers = data
-            .get("_embedded")
-            .and_then(|e| e.get("records"))
-            .and_then(|r| r.as_array())
-            .ok_or_else(|| {
-                error!("No records found in latest ledger response");
-                RpcError::InvalidResponse("No records in response".to_string())
-            })?;
-
-        let ledger = ledgers.first().ok_or_else(|| {
-            error!("Empty records array in latest ledger response");
-            RpcError::InvalidResponse("Empty records array".to_string())
+    /// Fetch a deployed contract instance; `Ok(None)` if the endpoint reports 404
+    pub async fn get_contract(&self, contract_id: &str) -> Result<Option<OnChainContract>, RpcError> {
+        let response = self
+            .fetch(&format!("/contracts/{}", encode_component(contract_id)))
+            .await?;
+        if response.status == 404 {
+            return Ok(None);
+        }
+        let body = response.into_body()?;
+        let contract: OnChainContract = serde_json::from_str(&body).map_err(|e| {
+            RpcError::InvalidResponse(format!("Failed to parse contract response: {}", e))
         })?;
 
-        let sequence = ledger
-            .get("sequence")
-            .and_then(|v| v.as_u64())
-            .ok_or_else(|| {
-                error!("Missing or invalid sequence in ledger: {:?}", ledger);
-                RpcError::InvalidResponse("Missing sequence".to_string())
-            })?;
-
-        let hash = ledger
-            .get("hash")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .ok_or_else(|| {
-                error!("Missing hash in ledger");
-                RpcError::InvalidResponse("Missing hash".to_string())
-            })?;
-
-        let prev_hash = ledger
-            .get("prev_hash")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_default();
-
-        let id = ledger
-            .get("id")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_else(|| hash.clone());
-
-        let timestamp = ledger
-            .get("closed_at")
-            .and_then(|v| v.as_str())
-            .map(|s| s.to_string())
-            .unwrap_or_default();
-
-        Ok(Ledger {
-            sequence,
-            id,
-            hash,
-            prev_hash,
-            timestamp,
-        })
+        Ok(Some(contract))
     }
 
     /// Check endpoint health
     pub async fn health_check(&self) -> Result<(), RpcError> {
-        let url = format!("{}/health", self.endpoint);
-        debug!("Checking RPC health at {}", url);
+        let response = self.fetch("/health").await.map_err(|e| {
+            warn!("Health check failed: {}", e);
+            e
+        })?;
 
-        let response = self
-            .client
-            .get(&url)
-            .timeout(self.request_timeout)
-            .send()
-            .await
-            .map_err(|e| {
-                warn!("Health check failed: {}", e);
-                if e.is_timeout() {
-                    RpcError::Timeout
-                } else {
-                    RpcError::RequestFailed(e.to_string())
-                }
-            })?;
-
-        if response.status().is_success() {
+        if response.is_success() {
             Ok(())
         } else {
             Err(RpcError::RpcError(format!(
                 "Health check failed with status {}",
-                response.status()
+                response.status
             )))
         }
     }
 }
 
+/// Percent-encode a path segment or query value.
+fn encode_component(raw: &str) -> String {
+    let mut encoded = String::with_capacity(raw.len());
+    for byte in raw.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Parse the newest ledger out of a `/ledgers?order=desc&limit=1` response.
+fn parse_latest_ledger(response_text: &str) -> Result<Ledger, RpcError> {
+    let data: serde_json::Value = serde_json::from_str(response_text)
+        .map_err(|e| {
+            error!("Invalid JSON in ledger response: {}", e);
+            RpcError::InvalidResponse(format!("Invalid JSON: {}", e))
+        })?;
+
+    // Extract first ledger from _embedded records
+    let ledgers = data
+        .get("_embedded")
+        .and_then(|e| e.get("records"))
+        .and_then(|r| r.as_array())
+        .ok_or_else(|| {
+            error!("No records found in latest ledger response");
+            RpcError::InvalidResponse("No records in response".to_string())
+        })?;
+
+    let ledger = ledgers.first().ok_or_else(|| {
+        error!("Empty records array in latest ledger response");
+        RpcError::InvalidResponse("Empty records array".to_string())
+    })?;
+
+    let sequence = ledger
+        .get("sequence")
+        .and_then(|v| v.as_u64())
+        .ok_or_else(|| {
+            error!("Missing or invalid sequence in ledger: {:?}", ledger);
+            RpcError::InvalidResponse("Missing sequence".to_string())
+        })?;
+
+    let hash = ledger
+        .get("hash")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .ok_or_else(|| {
+            error!("Missing hash in ledger");
+            RpcError::InvalidResponse("Missing hash".to_string())
+        })?;
+
+    let prev_hash = ledger
+        .get("prev_hash")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    let id = ledger
+        .get("id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_else(|| hash.clone());
+
+    let timestamp = ledger
+        .get("closed_at")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+        .unwrap_or_default();
+
+    Ok(Ledger {
+        sequence,
+        id,
+        hash,
+        prev_hash,
+        timestamp,
+    })
+}
+
+/// One RPC client per network, each pointed at its configured endpoint
+pub struct NetworkRpcClients {
+    mainnet: StellarRpcClient,
+    testnet: StellarRpcClient,
+    futurenet: StellarRpcClient,
+}
+
+impl NetworkRpcClients {
+    /// Endpoints from `STELLAR_RPC_<NETWORK>`, limits from [`RpcClientConfig::from_env`].
+    /// Each network gets its own rate limit.
+    pub fn from_env() -> Self {
+        use crate::config::rpc_endpoint_for;
+        let config = RpcClientConfig::from_env();
+        NetworkRpcClients {
+            mainnet: StellarRpcClient::with_config(rpc_endpoint_for(&Network::Mainnet), config),
+            testnet: StellarRpcClient::with_config(rpc_endpoint_for(&Network::Testnet), config),
+            futurenet: StellarRpcClient::with_config(rpc_endpoint_for(&Network::Futurenet), config),
+        }
+    }
+
+    pub fn for_network(&self, network: &Network) -> &StellarRpcClient {
+        match network {
+            Network::Mainnet => &self.mainnet,
+            Network::Testnet => &self.testnet,
+            Network::Futurenet => &self.futurenet,
+        }
+    }
+}
+
+#[async_trait]
+impl ContractLookup for NetworkRpcClients {
+    async fn get_contract(
+        &self,
+        network: &Network,
+        contract_id: &str,
+    ) -> Result<Option<OnChainContract>, RpcError> {
+        self.for_network(network).get_contract(contract_id).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use std::collections::VecDeque;
+
     #[test]
     fn test_rpc_client_creation() {
         let client = StellarRpcClient::new("https://rpc-futurenet.stellar.org".to_string());
         assert_eq!(client.endpoint, "https://rpc-futurenet.stellar.org");
     }
+
+    enum Reply {
+        Status(u16, &'static str),
+        Timeout,
+    }
+
+    /// Replays scripted replies and records every requested URL
+    #[derive(Default)]
+    struct MockTransport {
+        replies: Mutex<VecDeque<Reply>>,
+        urls: Mutex<Vec<String>>,
+    }
+
+    impl MockTransport {
+        fn scripted(replies: Vec<Reply>) -> Arc<Self> {
+            Arc::new(MockTransport {
+                replies: Mutex::new(replies.into()),
+                urls: Mutex::default(),
+            })
+        }
+
+        fn urls(&self) -> Vec<String> {
+            self.urls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl RpcTransport for MockTransport {
+        async fn get(&self, url: &str, _timeout: Duration) -> Result<TransportResponse, RpcError> {
+            self.urls.lock().unwrap().push(url.to_string());
+            match self.replies.lock().unwrap().pop_front() {
+                Some(Reply::Status(status, body)) => Ok(TransportResponse {
+                    status,
+                    body: body.to_string(),
+                }),
+                Some(Reply::Timeout) => Err(RpcError::Timeout),
+                None => panic!("unexpected request to {}", url),
+            }
+        }
+    }
+
+    fn no_waits() -> RpcClientConfig {
+        RpcClientConfig {
+            request_timeout: Duration::from_secs(1),
+            max_retries: 3,
+            retry_base_delay: Duration::ZERO,
+            requests_per_second: 0,
+        }
+    }
+
+    fn client(config: RpcClientConfig, transport: Arc<MockTransport>) -> StellarRpcClient {
+        StellarRpcClient::with_transport("https://rpc.test/".to_string(), config, transport)
+    }
+
+    const LATEST: &str = r#"{"_embedded": {"records": [{"sequence": 812, "hash": "abc", "closed_at": "2026-10-16T12:00:00Z"}]}}"#;
+
+    #[tokio::test]
+    async fn transient_failures_are_retried() {
+        let transport = MockTransport::scripted(vec![
+            Reply::Status(503, "busy"),
+            Reply::Timeout,
+            Reply::Status(429, "slow down"),
+            Reply::Status(200, LATEST),
+        ]);
+        let rpc = client(no_waits(), transport.clone());
+
+        let ledger = rpc.get_latest_ledger().await.unwrap();
+        assert_eq!(ledger.sequence, 812);
+        assert_eq!(transport.urls().len(), 4);
+        assert_eq!(transport.urls()[0], "https://rpc.test/ledgers?order=desc&limit=1");
+    }
+
+    #[tokio::test]
+    async fn retries_stop_at_the_configured_limit() {
+        let transport = MockTransport::scripted(vec![
+            Reply::Status(502, "bad gateway"),
+            Reply::Status(502, "bad gateway"),
+            Reply::Status(502, "bad gateway"),
+        ]);
+        let config = RpcClientConfig {
+            max_retries: 2,
+            ..no_waits()
+        };
+
+        let err = client(config, transport.clone()).get_latest_ledger().await.unwrap_err();
+        assert!(matches!(err, RpcError::RpcError(ref msg) if msg.starts_with("HTTP 502")));
+        assert_eq!(transport.urls().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let transport = MockTransport::scripted(vec![Reply::Status(400, "bad request"), Reply::Status(404, "")]);
+        let rpc = client(no_waits(), transport.clone());
+
+        assert!(rpc.get_events(10, None, 50).await.is_err());
+        assert_eq!(rpc.get_contract_data("CABC", "Balance(GA)").await.unwrap(), None);
+        assert_eq!(
+            transport.urls(),
+            vec![
+                "https://rpc.test/events?start_ledger=10&limit=50",
+                "https://rpc.test/contracts/CABC/data/Balance%28GA%29",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn events_and_contract_data_are_parsed() {
+        let transport = MockTransport::scripted(vec![
+            Reply::Status(
+                200,
+                r#"{"events": [{"id": "e1", "ledger": 900, "contract_id": "CABC", "topics": ["transfer"], "value": 5}]}"#,
+            ),
+            Reply::Status(200, r#"{"contract_id": "CABC", "key": "Admin", "value": "GADMIN"}"#),
+        ]);
+        let rpc = client(no_waits(), transport.clone());
+
+        let events = rpc.get_events(900, Some("CABC"), 10).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].topics, vec![serde_json::json!("transfer")]);
+        assert!(transport.urls()[0].ends_with("&contract_id=CABC"));
+
+        let data = rpc.get_contract_data("CABC", "Admin").await.unwrap().unwrap();
+        assert_eq!(data.value, serde_json::json!("GADMIN"));
+        assert_eq!(data.last_modified_ledger, None);
+    }
+
+    #[test]
+    fn rate_limiter_spaces_out_bursts() {
+        let limiter = RateLimiter::per_second(4);
+        let start = Instant::now();
+
+        let waits: Vec<Duration> = (0..3).map(|_| limiter.reserve(start)).collect();
+        assert_eq!(
+            waits,
+            vec![Duration::ZERO, Duration::from_millis(250), Duration::from_millis(500)]
+        );
+
+        // Once the backlog has drained, requests go straight out again
+        assert_eq!(limiter.reserve(start + Duration::from_secs(2)), Duration::ZERO);
+        assert_eq!(RateLimiter::per_second(0).reserve(start), Duration::ZERO);
+    }
+
+    #[tokio::test]
+    async fn requests_through_the_client_are_rate_limited() {
+        let transport = MockTransport::scripted((0..4).map(|_| Reply::Status(200, "")).collect());
+        let config = RpcClientConfig {
+            requests_per_second: 50,
+            ..no_waits()
+        };
+        let rpc = client(config, transport.clone());
+
+        let started = Instant::now();
+        for _ in 0..4 {
+            rpc.health_check().await.unwrap();
+        }
+        // Four requests at 50/s need at least three 20ms gaps
+        assert!(started.elapsed() >= Duration::from_millis(60));
+        assert_eq!(transport.urls().len(), 4);
+    }
+
+    #[test]
+    fn invalid_env_values_keep_the_defaults() {
+        std::env::set_var("STELLAR_RPC_MAX_RETRIES", "lots");
+        std::env::set_var("STELLAR_RPC_RATE_LIMIT", "25");
+        let config = RpcClientConfig::from_env();
+        assert_eq!(config.max_retries, RpcClientConfig::default().max_retries);
+        assert_eq!(config.requests_per_second, 25);
+        std::env::remove_var("STELLAR_RPC_MAX_RETRIES");
+        std::env::remove_var("STELLAR_RPC_RATE_LIMIT");
+    }
 }
@@ -65,6 +65,7 @@ mod tests {
                 "contract": contract_id.clone(),
                 "source_account": deployer.clone(),
             }),
+            created_at: None,
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
@@ -83,6 +84,7 @@ mod tests {
                 type_code: 1, // payment
                 type_name: "payment".to_string(),
                 body: json!({}),
+                created_at: None,
             },
             Operation {
                 id: "op2".to_string(),
@@ -90,6 +92,7 @@ mod tests {
                 type_code: 4, // path_payment
                 type_name: "path_payment".to_string(),
                 body: json!({}),
+                created_at: None,
             },
         ];
 
@@ -158,6 +161,7 @@ mod tests {
                     "contract": c1.clone(),
                     "source_account": "G111111111111111111111111111111111111111111111111111WHSRQ",
                 }),
+                created_at: None,
             },
             Operation {
                 id: "op2".to_string(),
@@ -168,6 +172,7 @@ mod tests {
                     "contract": c2.clone(),
                     "source_account": "G222222222222222222222222222222222222222222222222222WHSRQ",
                 }),
+                created_at: None,
             },
         ];
 
@@ -188,6 +193,7 @@ mod tests {
                 "contract": "INVALID",
                 "source_account": "GBRPYHIL2CI3WHZDTOOQFC6EB4RRJC3D5NZ4FJHSVOBXUXVLCJGXI2V",
             }),
+            created_at: None,
         }];
 
         let deployments = detect_contract_deployments(&ops, 100);
@@ -0,0 +1,302 @@
+//! Contract state schema migration
+//!
+//! A schema maps each state field to a type name (`string`, `number`/`float`,
+//! `integer`/`int`, `boolean`/`bool`, `array`, `object`/`map`; anything else
+//! is passed through untouched). Migrating between two versions is:
+//!
+//! - [`analyze`]: which fields were added, removed or changed type
+//! - [`validate`]: which of those changes would lose or mangle existing state
+//! - [`dry_run`]: the state as it would look under the new schema
+//!
+//! Shared by the `soroban-registry migrate` CLI commands and the API's
+//! migration preview endpoint.
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// Field name -> type name
+pub type Schema = BTreeMap<String, String>;
+
+/// Field name -> value
+pub type State = Map<String, Value>;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDiff {
+    pub added_fields: Vec<String>,
+    pub removed_fields: Vec<String>,
+    pub changed_types: Vec<TypeChange>,
+}
+
+impl SchemaDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_fields.is_empty() && self.removed_fields.is_empty() && self.changed_types.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TypeChange {
+    pub field: String,
+    pub old_type: String,
+    pub new_type: String,
+}
+
+/// Fields added, removed or retyped going from `old` to `new`
+pub fn analyze(old: &Schema, new: &Schema) -> SchemaDiff {
+    let mut added_fields = Vec::new();
+    let mut removed_fields = Vec::new();
+    let mut changed_types = Vec::new();
+
+    for (field, new_ty) in new {
+        match old.get(field) {
+            Some(old_ty) if old_ty != new_ty => changed_types.push(TypeChange {
+                field: field.clone(),
+                old_type: old_ty.clone(),
+                new_type: new_ty.clone(),
+            }),
+            None => added_fields.push(field.clone()),
+            _ => {}
+        }
+    }
+
+    for field in old.keys() {
+        if !new.contains_key(field) {
+            removed_fields.push(field.clone());
+        }
+    }
+
+    SchemaDiff {
+        added_fields,
+        removed_fields,
+        changed_types,
+    }
+}
+
+/// Problems migrating `old_state` to `new_schema` would cause: data in
+/// removed fields, and values that cannot be converted to their new type.
+pub fn validate(old_state: &State, new_schema: &Schema, diff: &SchemaDiff) -> Vec<String> {
+    let mut issues = Vec::new();
+
+    for field in &diff.removed_fields {
+        if let Some(value) = old_state.get(field) {
+            if !value.is_null() {
+                issues.push(format!(
+                    "Field '{}' is removed but currently contains data; migration would drop value {}",
+                    field, value
+                ));
+            }
+        }
+    }
+
+    for change in &diff.changed_types {
+        if let Some(value) = old_state.get(&change.field) {
+            if convert_value(value, &change.new_type).is_none() {
+                issues.push(format!(
+                    "Field '{}' type change {} -> {} is not safely convertible for value {}",
+                    change.field, change.old_type, change.new_type, value
+                ));
+            }
+        }
+    }
+
+    for (field, new_ty) in new_schema {
+        if let Some(value) = old_state.get(field) {
+            if convert_value(value, new_ty).is_none() {
+                issues.push(format!(
+                    "Field '{}' cannot be represented as target type '{}'",
+                    field, new_ty
+                ));
+            }
+        }
+    }
+
+    issues
+}
+
+/// `old_state` migrated to `new_schema`, with notes on anything defaulted or
+/// dropped. Fields that are new or fail to convert get their type's default.
+pub fn dry_run(old_state: &State, new_schema: &Schema, diff: &SchemaDiff) -> (State, Vec<String>) {
+    let mut migrated = Map::new();
+    let mut warnings = Vec::new();
+
+    for (field, new_ty) in new_schema {
+        let value = match old_state.get(field) {
+            Some(existing) => match convert_value(existing, new_ty) {
+                Some(converted) => converted,
+                None => {
+                    warnings.push(format!(
+                        "Field '{}' could not convert to '{}'; using default value",
+                        field, new_ty
+                    ));
+                    default_for_type(new_ty)
+                }
+            },
+            None => default_for_type(new_ty),
+        };
+
+        migrated.insert(field.clone(), value);
+    }
+
+    for field in &diff.removed_fields {
+        if old_state.contains_key(field) {
+            warnings.push(format!(
+                "Field '{}' removed in new schema and omitted from migrated state",
+                field
+            ));
+        }
+    }
+
+    (migrated, warnings)
+}
+
+/// `value` as `target_type`, or `None` when it has no faithful representation
+pub fn convert_value(value: &Value, target_type: &str) -> Option<Value> {
+    match normalize_type(target_type).as_str() {
+        "string" => Some(Value::String(match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        })),
+        "number" | "float" => match value {
+            Value::Number(_) => Some(value.clone()),
+            Value::String(s) => s
+                .parse::<f64>()
+                .ok()
+                .and_then(serde_json::Number::from_f64)
+                .map(Value::Number),
+            Value::Bool(b) => Some(Value::Number(serde_json::Number::from(if *b {
+                1
+            } else {
+                0
+            }))),
+            _ => None,
+        },
+        "integer" | "int" => match value {
+            Value::Number(n) => n
+                .as_i64()
+                .map(|i| Value::Number(serde_json::Number::from(i))),
+            Value::String(s) => s
+                .parse::<i64>()
+                .ok()
+                .map(|i| Value::Number(serde_json::Number::from(i))),
+            Value::Bool(b) => Some(Value::Number(serde_json::Number::from(if *b {
+                1
+            } else {
+                0
+            }))),
+            _ => None,
+        },
+        "boolean" | "bool" => match value {
+            Value::Bool(_) => Some(value.clone()),
+            Value::Number(n) => n.as_i64().map(|i| Value::Bool(i != 0)),
+            Value::String(s) => match s.to_ascii_lowercase().as_str() {
+                "true" | "1" => Some(Value::Bool(true)),
+                "false" | "0" => Some(Value::Bool(false)),
+                _ => None,
+            },
+            _ => None,
+        },
+        "array" => value.as_array().map(|_| value.clone()),
+        "object" | "map" => value.as_object().map(|_| value.clone()),
+        _ => Some(value.clone()),
+    }
+}
+
+/// Value given to fields that are new or could not be converted
+pub fn default_for_type(target_type: &str) -> Value {
+    match normalize_type(target_type).as_str() {
+        "string" => Value::String(String::new()),
+        "number" | "float" => Value::Number(serde_json::Number::from(0)),
+        "integer" | "int" => Value::Number(serde_json::Number::from(0)),
+        "boolean" | "bool" => Value::Bool(false),
+        "array" => Value::Array(Vec::new()),
+        "object" | "map" => Value::Object(Map::new()),
+        _ => Value::Null,
+    }
+}
+
+fn normalize_type(raw: &str) -> String {
+    raw.trim().to_ascii_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn schema(fields: &[(&str, &str)]) -> Schema {
+        fields
+            .iter()
+            .map(|(f, t)| (f.to_string(), t.to_string()))
+            .collect()
+    }
+
+    fn state(value: Value) -> State {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn analyze_reports_added_removed_and_retyped_fields() {
+        let old = schema(&[("owner", "string"), ("balance", "number"), ("legacy", "bool")]);
+        let new = schema(&[("owner", "string"), ("balance", "string"), ("nonce", "integer")]);
+
+        let diff = analyze(&old, &new);
+        assert_eq!(diff.added_fields, vec!["nonce"]);
+        assert_eq!(diff.removed_fields, vec!["legacy"]);
+        assert_eq!(
+            diff.changed_types,
+            vec![TypeChange {
+                field: "balance".into(),
+                old_type: "number".into(),
+                new_type: "string".into(),
+            }]
+        );
+        assert!(analyze(&old, &old).is_empty());
+    }
+
+    #[test]
+    fn validate_flags_dropped_data_and_lossy_conversions() {
+        let old = schema(&[("owner", "string"), ("count", "string"), ("legacy", "string")]);
+        let new = schema(&[("owner", "string"), ("count", "integer")]);
+        let diff = analyze(&old, &new);
+
+        let clean = state(json!({ "owner": "GA", "count": "3", "legacy": null }));
+        assert!(validate(&clean, &new, &diff).is_empty());
+
+        let lossy = state(json!({ "owner": "GA", "count": "many", "legacy": "x" }));
+        let issues = validate(&lossy, &new, &diff);
+        assert!(issues.iter().any(|i| i.contains("'legacy' is removed")));
+        assert!(issues.iter().any(|i| i.contains("'count' type change string -> integer")));
+    }
+
+    #[test]
+    fn dry_run_converts_defaults_and_drops() {
+        let old = schema(&[("owner", "string"), ("count", "number"), ("legacy", "string")]);
+        let new = schema(&[("owner", "string"), ("count", "string"), ("active", "boolean"), ("ratio", "int")]);
+        let diff = analyze(&old, &new);
+        let before = state(json!({ "owner": "alice", "count": 3, "legacy": "x", "ratio": 0.5 }));
+
+        let (migrated, warnings) = dry_run(&before, &new, &diff);
+        assert_eq!(
+            Value::Object(migrated),
+            json!({ "owner": "alice", "count": "3", "active": false, "ratio": 0 })
+        );
+        assert_eq!(warnings.len(), 2, "{warnings:?}");
+        assert!(warnings[0].contains("'ratio' could not convert"));
+        assert!(warnings[1].contains("'legacy' removed"));
+    }
+
+    #[test]
+    fn conversions_between_scalar_types() {
+        assert_eq!(convert_value(&json!(7), "String"), Some(json!("7")));
+        assert_eq!(convert_value(&json!("2.5"), "float"), Some(json!(2.5)));
+        assert_eq!(convert_value(&json!(true), "integer"), Some(json!(1)));
+        assert_eq!(convert_value(&json!("FALSE"), " bool "), Some(json!(false)));
+        assert_eq!(convert_value(&json!("yes"), "bool"), None);
+        assert_eq!(convert_value(&json!([1]), "object"), None);
+        assert_eq!(convert_value(&json!({ "a": 1 }), "Address"), Some(json!({ "a": 1 })));
+
+        assert_eq!(default_for_type("map"), json!({}));
+        assert_eq!(default_for_type("array"), json!([]));
+        assert_eq!(default_for_type("Address"), Value::Null);
+    }
+}
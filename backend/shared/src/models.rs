@@ -25,12 +25,31 @@ pub struct Contract {
     pub updated_at: DateTime<Utc>,
     #[serde(default)]
     pub is_maintenance: bool,
+    #[serde(default)]
+    pub maturity: MaturityLevel,
     /// Groups rows that represent the same logical contract across networks (Issue #43)
     #[serde(default)]
     pub logical_id: Option<Uuid>,
     /// Per-network config: { "mainnet": { contract_id, is_verified, min_version, max_version }, ... }
     #[serde(default)]
     pub network_configs: Option<serde_json::Value>,
+    /// Registry row this contract was promoted from on another network
+    #[serde(default)]
+    pub origin_contract_id: Option<Uuid>,
+    /// Set by reconciliation when the on-chain contract is gone or its WASM no longer matches
+    #[serde(default)]
+    pub drift_detected: bool,
+    /// Debounced detail-page views; see api/src/views.rs
+    #[serde(default)]
+    pub view_count: i64,
+    /// Close time of the earliest on-chain deployment the indexer saw.
+    /// Unlike `created_at` (registry insert), this is when the contract
+    /// appeared on the network; `None` for contracts never indexed.
+    #[serde(default)]
+    pub first_seen_at: Option<DateTime<Utc>>,
+    /// SPDX license identifier or expression, e.g. `MIT OR Apache-2.0`
+    #[serde(default)]
+    pub license: Option<String>,
 }
 
 /// Response for GET /contracts/:id with optional network-specific slice (Issue #43)
@@ -58,7 +77,7 @@ pub struct NetworkConfig {
 }
 
 /// Network where the contract is deployed
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, sqlx::Type)]
 #[sqlx(type_name = "network_type", rename_all = "lowercase")]
 #[serde(rename_all = "lowercase")]
 pub enum Network {
@@ -114,6 +133,9 @@ pub struct Verification {
     pub compiler_version: Option<String>,
     pub verified_at: Option<DateTime<Utc>>,
     pub error_message: Option<String>,
+    /// Build output kept for failed attempts (tail-truncated)
+    #[serde(default)]
+    pub build_log: Option<String>,
     pub created_at: DateTime<Utc>,
 }
 
@@ -127,12 +149,79 @@ pub enum VerificationStatus {
 }
 
 /// Contract maturity level - indicates stability and production readiness
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, sqlx::Type, PartialEq, Eq, PartialOrd, Ord)]
+#[sqlx(type_name = "maturity_level", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
 pub enum MaturityLevel {
-    Experimental,
+    #[default]
+    Alpha,
     Beta,
     Stable,
-    Production,
+    Mature,
+    Legacy,
+}
+
+/// One row in `maturity_changes`
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaturityChange {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub from_level: Option<MaturityLevel>,
+    pub to_level: MaturityLevel,
+    pub reason: Option<String>,
+    pub changed_by: Uuid,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Request body for PUT /api/contracts/:id/maturity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UpdateMaturityRequest {
+    pub maturity: MaturityLevel,
+    pub reason: Option<String>,
+}
+
+/// A single requirement that must hold before promoting to a maturity level
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaturityCriterion {
+    pub name: String,
+    pub required: bool,
+    pub met: bool,
+    pub description: String,
+}
+
+/// Requirements for reaching a maturity level and whether they are all met
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaturityRequirements {
+    pub level: MaturityLevel,
+    pub criteria: Vec<MaturityCriterion>,
+    pub met: bool,
+}
+
+/// One row in `maintenance_windows`; `ended_at` is unset while it is open
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MaintenanceWindow {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub message: String,
+    pub started_at: DateTime<Utc>,
+    pub scheduled_end_at: Option<DateTime<Utc>>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Request body for POST /api/contracts/:id/maintenance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartMaintenanceRequest {
+    pub message: String,
+    /// Maintenance ends on its own at this time when set
+    pub scheduled_end_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MaintenanceStatusResponse {
+    pub is_maintenance: bool,
+    pub current_window: Option<MaintenanceWindow>,
 }
 
 /// Publisher/developer information
@@ -198,6 +287,53 @@ pub struct PublishRequest {
     // Dependencies (new field)
     #[serde(default)]
     pub dependencies: Vec<DependencyDeclaration>,
+    /// SPDX license identifier or expression
+    #[serde(default)]
+    pub license: Option<String>,
+}
+
+/// Response for a successful publish: the created contract plus any
+/// non-fatal issues the publisher should know about
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublishResponse {
+    #[serde(flatten)]
+    pub contract: Contract,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+}
+
+/// Partial update for PATCH /api/contracts/:id; omitted fields are left unchanged.
+/// Maturity is not patchable: it only moves through the promotion gate.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PatchContractRequest {
+    pub name: Option<String>,
+    /// `null` clears the description; omitting it leaves it unchanged
+    #[serde(default, deserialize_with = "nullable_field", skip_serializing_if = "Option::is_none")]
+    pub description: Option<Option<String>>,
+    /// `null` clears the category; omitting it leaves it unchanged
+    #[serde(default, deserialize_with = "nullable_field", skip_serializing_if = "Option::is_none")]
+    pub category: Option<Option<String>>,
+    pub tags: Option<Vec<String>>,
+    pub license: Option<String>,
+}
+
+/// Deserialize a present field, keeping an explicit `null` as `Some(None)`;
+/// paired with `#[serde(default)]` an absent field stays `None`.
+fn nullable_field<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+/// Request body for POST /api/contracts/:id/promote-network
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PromoteNetworkRequest {
+    pub target_network: Network,
+    /// On-chain address on the target network; defaults to the origin's address
+    pub contract_id: Option<String>,
 }
 
 /// Request to create a new contract version with ABI
@@ -323,7 +459,7 @@ pub enum SortOrder {
 }
 
 /// Search/filter parameters for contracts
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ContractSearchParams {
     pub query: Option<String>,
     pub network: Option<Network>,
@@ -332,12 +468,20 @@ pub struct ContractSearchParams {
     pub verified_only: Option<bool>,
     pub category: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Comma-separated tags; contracts carrying any of them are left out
+    pub exclude_tags: Option<String>,
+    /// Comma-separated categories to leave out
+    pub exclude_category: Option<String>,
     pub maturity: Option<MaturityLevel>,
+    /// Comma-separated SPDX ids; matches contracts whose license expression names any of them
+    pub license: Option<String>,
     pub page: Option<i64>,
     #[serde(alias = "page_size")]
     pub limit: Option<i64>,
     pub sort_by: Option<SortBy>,
     pub sort_order: Option<SortOrder>,
+    /// Comma-separated field projection, e.g. `?fields=id,name,network`
+    pub fields: Option<String>,
 }
 
 /// Pagination params for contract versions (limit/offset style)
@@ -462,6 +606,9 @@ pub struct ContractDeployment {
     pub health_checks_failed: i32,
     pub last_health_check_at: Option<DateTime<Utc>>,
     pub error_message: Option<String>,
+    /// Passing health checks in a row since the last failure
+    #[serde(default)]
+    pub consecutive_passes: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -922,6 +1069,10 @@ pub struct ContractAnalyticsResponse {
     pub deployments: DeploymentStats,
     pub interactors: InteractorStats,
     pub timeline: Vec<TimelineEntry>,
+    /// Pass as `?cursor=` to fetch the window before this one; `None` once
+    /// no older events exist
+    #[serde(default)]
+    pub next_cursor: Option<String>,
 }
 
 /// Deployment statistics
@@ -946,13 +1097,65 @@ pub struct TopUser {
     pub count: i64,
 }
 
-/// One data-point in the 30-day timeline
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// One data-point in the analytics timeline; `date` is the start of the bucket
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TimelineEntry {
     pub date: chrono::NaiveDate,
     pub count: i64,
 }
 
+/// Bucket size for the analytics timeline (`?interval=`)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineInterval {
+    #[default]
+    Day,
+    Week,
+    Month,
+}
+
+impl TimelineInterval {
+    /// The `date_trunc` field name for this interval
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            Self::Day => "day",
+            Self::Week => "week",
+            Self::Month => "month",
+        }
+    }
+
+    /// Start of the bucket containing `date`, matching Postgres `date_trunc`
+    /// (weeks start on Monday).
+    pub fn truncate(&self, date: chrono::NaiveDate) -> chrono::NaiveDate {
+        use chrono::Datelike;
+        match self {
+            Self::Day => date,
+            Self::Week => date - chrono::Duration::days(date.weekday().num_days_from_monday() as i64),
+            Self::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// Start of the bucket following the one that starts at `bucket`.
+    pub fn next(&self, bucket: chrono::NaiveDate) -> chrono::NaiveDate {
+        match self {
+            Self::Day => bucket + chrono::Duration::days(1),
+            Self::Week => bucket + chrono::Duration::days(7),
+            Self::Month => bucket
+                .checked_add_months(chrono::Months::new(1))
+                .unwrap_or(bucket),
+        }
+    }
+
+    /// Default analytics window, in days, for this interval
+    pub fn default_window_days(&self) -> i64 {
+        match self {
+            Self::Day => 30,
+            Self::Week => 12 * 7,
+            Self::Month => 365,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeployGreenRequest {
     pub contract_id: String,
@@ -1020,6 +1223,12 @@ pub enum AuditActionType {
     PublisherChanged,
     VersionCreated,
     Rollback,
+    MaturityChanged,
+    MaintenanceChanged,
+    /// Contract promoted or deployed onto another network
+    Deployed,
+    /// Contract soft-deleted by its publisher or an admin
+    ContractDeleted,
 }
 
 impl std::fmt::Display for AuditActionType {
@@ -1031,6 +1240,10 @@ impl std::fmt::Display for AuditActionType {
             Self::PublisherChanged => "publisher_changed",
             Self::VersionCreated => "version_created",
             Self::Rollback => "rollback",
+            Self::MaturityChanged => "maturity_changed",
+            Self::MaintenanceChanged => "maintenance_changed",
+            Self::Deployed => "deployed",
+            Self::ContractDeleted => "contract_deleted",
         };
         write!(f, "{}", s)
     }
@@ -1097,24 +1310,52 @@ pub struct MultisigPolicy {
     pub id: Uuid,
     pub name: String,
     pub threshold: i32,
-    pub required_signatures: i32,
     pub signer_addresses: Vec<String>,
     pub expiry_seconds: i32,
     pub created_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Lifecycle of a multisig deployment proposal
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "proposal_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum MultisigProposalStatus {
+    Pending,
+    Approved,
+    Executed,
+    Expired,
+    Rejected,
+}
+
+impl std::fmt::Display for MultisigProposalStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::Pending => "pending",
+            Self::Approved => "approved",
+            Self::Executed => "executed",
+            Self::Expired => "expired",
+            Self::Rejected => "rejected",
+        };
+        write!(f, "{}", s)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct DeployProposal {
     pub id: Uuid,
     pub contract_name: String,
-    pub contract_id: Uuid,
+    pub contract_id: String,
     pub wasm_hash: String,
-    pub network: String,
+    pub network: Network,
     pub description: Option<String>,
     pub policy_id: Uuid,
-    pub status: String,
+    pub status: MultisigProposalStatus,
     pub expires_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
     pub proposer: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -1122,17 +1363,118 @@ pub struct ProposalSignature {
     pub id: Uuid,
     pub proposal_id: Uuid,
     pub signer_address: String,
+    pub signature_data: Option<String>,
+    pub signed_at: DateTime<Utc>,
 }
 
-/// Paginated response for audit log
+/// Request body for POST /api/multisig/policies
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreatePolicyRequest {
+    pub name: String,
+    pub threshold: i32,
+    pub signer_addresses: Vec<String>,
+    /// Lifetime of proposals under this policy; defaults to one day
+    pub expiry_seconds: Option<i32>,
+    pub created_by: String,
+}
+
+/// Request body for POST /api/contracts/deploy-proposal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateDeployProposalRequest {
+    pub contract_name: String,
+    pub contract_id: String,
+    pub wasm_hash: String,
+    pub network: Network,
+    pub description: Option<String>,
+    pub policy_id: Uuid,
+    pub proposer: String,
+}
+
+/// Signature scheme a proposal signature is produced with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SignatureScheme {
+    #[default]
+    Ed25519,
+}
+
+impl std::fmt::Display for SignatureScheme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SignatureScheme::Ed25519 => write!(f, "ed25519"),
+        }
+    }
+}
+
+/// Request body for POST /api/contracts/:id/sign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignProposalRequest {
+    pub signer_address: String,
+    /// Signature over the proposal's signing message, proving the signer
+    /// controls `signer_address`
+    pub signature_data: String,
+    /// Scheme `signature_data` was produced with; defaults to Ed25519
+    #[serde(default)]
+    pub scheme: SignatureScheme,
+}
+
+/// A deployment proposal with its policy and collected signatures.
+///
+/// `signatures` holds at most the first page of signatures (oldest first);
+/// the full list is served by GET /api/contracts/:id/signatures.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProposalWithSignatures {
     pub proposal: DeployProposal,
     pub policy: MultisigPolicy,
     pub signatures: Vec<ProposalSignature>,
+    /// Signatures collected in total, including any not embedded
+    #[serde(default)]
+    pub signatures_total: i64,
+    /// Whether `signatures` was capped
+    #[serde(default)]
+    pub signatures_truncated: bool,
     pub signatures_needed: i32,
 }
 
+/// One page of a proposal's signatures, oldest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalSignaturePage {
+    pub items: Vec<ProposalSignature>,
+    pub total: i64,
+    pub page: i64,
+    pub total_pages: i64,
+}
+
+/// A recorded status transition of a proposal
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ProposalStatusChange {
+    pub from_status: MultisigProposalStatus,
+    pub to_status: MultisigProposalStatus,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// What happened at one point of a proposal's lifecycle
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ProposalHistoryEvent {
+    Created { proposer: String },
+    Signed { signer_address: String },
+    StatusChanged {
+        from: MultisigProposalStatus,
+        to: MultisigProposalStatus,
+    },
+    Executed,
+}
+
+/// One entry of GET /api/contracts/:id/proposal/:pid/history
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ProposalHistoryEntry {
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: ProposalHistoryEvent,
+}
+
+/// Paginated response for audit log
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditLogPage {
     pub items: Vec<ContractAuditLog>,
@@ -1518,3 +1860,284 @@ pub struct TransparencyLogQueryParams {
     pub limit: Option<i64>,
     pub offset: Option<i64>,
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// COST ESTIMATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Request body for the cost estimation endpoints
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostEstimateRequest {
+    pub method_name: String,
+    pub invocations: Option<i64>,
+    pub storage_growth_kb: Option<i64>,
+}
+
+/// Estimated cost of invoking one contract method, in stroops
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostEstimate {
+    pub method_name: String,
+    pub gas_cost: i64,
+    pub storage_cost: i64,
+    pub bandwidth_cost: i64,
+    pub total_stroops: i64,
+    pub total_xlm: f64,
+    pub invocations: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchCostEstimate {
+    pub estimates: Vec<CostEstimate>,
+    pub total_stroops: i64,
+    pub total_xlm: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostOptimization {
+    pub current_cost: i64,
+    pub optimized_cost: i64,
+    pub savings_percent: f64,
+    pub suggestions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CostForecast {
+    pub daily_cost_xlm: f64,
+    pub monthly_cost_xlm: f64,
+    pub yearly_cost_xlm: f64,
+    pub usage_pattern: String,
+}
+
+/// Fee constants the cost estimator applies for one network, in stroops
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct FeeSchedule {
+    pub network: Network,
+    pub base_gas_cost: i64,
+    pub storage_cost_per_kb: i64,
+    pub bandwidth_cost_per_kb: i64,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// GOVERNANCE FRAMEWORK
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "governance_model", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum GovernanceModel {
+    TokenWeighted,
+    Quadratic,
+    Multisig,
+    Timelock,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "governance_proposal_status", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum ProposalStatus {
+    Pending,
+    Active,
+    Passed,
+    Rejected,
+    Executed,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq)]
+#[sqlx(type_name = "vote_choice", rename_all = "lowercase")]
+#[serde(rename_all = "lowercase")]
+pub enum VoteChoice {
+    For,
+    Against,
+    Abstain,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GovernanceProposal {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub title: String,
+    pub description: String,
+    pub governance_model: GovernanceModel,
+    pub proposer: Uuid,
+    pub status: ProposalStatus,
+    pub voting_starts_at: DateTime<Utc>,
+    pub voting_ends_at: DateTime<Utc>,
+    pub execution_delay_hours: Option<i32>,
+    /// Minimum total voting power that must be cast (absolute, not a percentage)
+    pub quorum_required: i32,
+    /// Percentage (0–100) of cast voting power that must vote "for"
+    pub approval_threshold: i32,
+    pub created_at: DateTime<Utc>,
+    pub executed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct GovernanceVote {
+    pub id: Uuid,
+    pub proposal_id: Uuid,
+    pub voter: Uuid,
+    pub vote_choice: VoteChoice,
+    pub voting_power: i64,
+    pub delegated_from: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct VoteDelegation {
+    pub id: Uuid,
+    pub delegator: Uuid,
+    pub delegate: Uuid,
+    pub contract_id: Option<Uuid>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateProposalRequest {
+    pub title: String,
+    pub description: String,
+    pub governance_model: GovernanceModel,
+    pub voting_duration_hours: i32,
+    pub execution_delay_hours: Option<i32>,
+    /// Minimum total voting power cast; defaults to 50
+    #[serde(default)]
+    pub quorum_required: Option<i32>,
+    /// Percentage (0–100) of cast voting power needed to pass; defaults to 50
+    #[serde(default)]
+    pub approval_threshold: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CastVoteRequest {
+    pub vote_choice: VoteChoice,
+}
+
+/// Tally for GET /api/governance/proposals/:id/results
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProposalResults {
+    pub proposal: GovernanceProposal,
+    pub votes_for: i64,
+    pub votes_against: i64,
+    pub votes_abstain: i64,
+    pub total_votes: i64,
+    pub quorum_met: bool,
+    pub approved: bool,
+    /// Earliest time the proposal may be executed (voting end + execution delay)
+    pub executable_at: DateTime<Utc>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// PORTABLE CONTRACT BUNDLES
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Current bundle format produced by GET /api/contracts/:id/export
+pub const CONTRACT_BUNDLE_FORMAT_VERSION: u32 = 1;
+
+/// Self-contained snapshot of a contract for archival or migration between registries
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractBundle {
+    pub format_version: u32,
+    pub exported_at: DateTime<Utc>,
+    pub publisher: Publisher,
+    pub contract: Contract,
+    pub versions: Vec<ContractVersion>,
+    pub verifications: Vec<Verification>,
+    pub deployments: Vec<ContractDeployment>,
+    pub latest_analytics: Option<DailyAggregate>,
+}
+
+impl ContractBundle {
+    /// Check that every nested record points back at the bundled contract/publisher.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        let contract_id = self.contract.id;
+
+        if self.format_version != CONTRACT_BUNDLE_FORMAT_VERSION {
+            errors.push(format!(
+                "unsupported bundle format_version {}",
+                self.format_version
+            ));
+        }
+        if self.contract.publisher_id != self.publisher.id {
+            errors.push("contract.publisher_id does not match publisher.id".to_string());
+        }
+
+        let mut seen_versions = std::collections::HashSet::new();
+        for v in &self.versions {
+            if v.contract_id != contract_id {
+                errors.push(format!("version {} belongs to another contract", v.version));
+            }
+            if !seen_versions.insert(v.version.as_str()) {
+                errors.push(format!("duplicate version {}", v.version));
+            }
+        }
+        for v in &self.verifications {
+            if v.contract_id != contract_id {
+                errors.push(format!("verification {} belongs to another contract", v.id));
+            }
+        }
+        for d in &self.deployments {
+            if d.contract_id != contract_id {
+                errors.push(format!("deployment {} belongs to another contract", d.id));
+            }
+        }
+        if let Some(a) = &self.latest_analytics {
+            if a.contract_id != contract_id {
+                errors.push("latest_analytics belongs to another contract".to_string());
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// CONTRACT BACKUPS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// One daily backup of a contract. The full bundle (metadata, state
+/// snapshot, WASM) lives in object storage under `object_key`.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ContractBackup {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub backup_date: chrono::NaiveDate,
+    pub wasm_hash: String,
+    pub metadata: serde_json::Value,
+    pub state_snapshot: Option<serde_json::Value>,
+    pub storage_size_bytes: i64,
+    pub verified: bool,
+    pub primary_region: String,
+    pub backup_regions: Vec<String>,
+    pub object_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BackupRestoration {
+    pub id: Uuid,
+    pub backup_id: Uuid,
+    pub restored_by: Uuid,
+    pub restore_duration_ms: i32,
+    pub success: bool,
+    pub error_message: Option<String>,
+    pub restored_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CreateBackupRequest {
+    #[serde(default)]
+    pub include_state: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RestoreBackupRequest {
+    /// YYYY-MM-DD
+    pub backup_date: String,
+}
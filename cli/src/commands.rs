@@ -17,11 +17,25 @@ pub enum Network {
 
 use std::path::Path;
 
+use registry_client::{ClientError, RegistryClient};
+use serde::de::DeserializeOwned;
+use shared::{
+    ContractConfigResponse, ContractSearchParams, DependencyTreeNode, Migration, PublishRequest,
+};
+
 use crate::patch::{PatchManager, Severity};
 use crate::profiler;
 use crate::sla::SlaManager;
 use crate::test_framework;
 
+/// Decode a successful API response as one of the shared models. A body that
+/// doesn't match fails with the endpoint, the expected type and the field.
+async fn read_json<T: DeserializeOwned>(response: reqwest::Response) -> Result<T> {
+    let url = response.url().to_string();
+    let body = response.text().await?;
+    Ok(registry_client::decode(&url, &body)?)
+}
+
 pub async fn search(
     api_url: &str,
     query: &str,
@@ -29,33 +43,26 @@ pub async fn search(
     verified_only: bool,
 	 json: bool,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
-    let mut url = format!(
-        "{}/api/contracts?query={}&network={}",
-        api_url, query, network
-    );
-
-    if verified_only {
-        url.push_str("&verified_only=true");
-    }
-
-    let response = client
-        .get(&url)
-        .send()
+    let client = RegistryClient::new(api_url);
+    let page = client
+        .search(&ContractSearchParams {
+            query: Some(query.to_string()),
+            network: Some(network.into()),
+            verified_only: verified_only.then_some(true),
+            ..Default::default()
+        })
         .await
         .context("Failed to search contracts")?;
-
-    let data: serde_json::Value = response.json().await?;
-    let items = data["items"].as_array().context("Invalid response")?;
+    let items = page.items;
 
 	 if json {
         let contracts: Vec<serde_json::Value> = items
             .iter()
             .map(|c| serde_json::json!({
-                "id":          c["contract_id"].as_str().unwrap_or(""),
-                "name":        c["name"].as_str().unwrap_or("Unknown"),
-                "is_verified": c["is_verified"].as_bool().unwrap_or(false),
-                "network":     c["network"].as_str().unwrap_or(""),
+                "id":          c.contract_id,
+                "name":        c.name,
+                "is_verified": c.is_verified,
+                "network":     c.network.to_string(),
             }))
             .collect();
         println!("{}", serde_json::to_string_pretty(&serde_json::json!({ "contracts": contracts }))?);
@@ -70,25 +77,20 @@ pub async fn search(
         return Ok(());
     }
 
-    for contract in items {
-        let name = contract["name"].as_str().unwrap_or("Unknown");
-        let contract_id = contract["contract_id"].as_str().unwrap_or("");
-        let is_verified = contract["is_verified"].as_bool().unwrap_or(false);
-        let network = contract["network"].as_str().unwrap_or("");
-
-        println!("\n{} {}", "●".green(), name.bold());
-        println!("  ID: {}", contract_id.bright_black());
+    for contract in &items {
+        println!("\n{} {}", "●".green(), contract.name.bold());
+        println!("  ID: {}", contract.contract_id.bright_black());
         println!(
             "  Status: {} | Network: {}",
-            if is_verified {
+            if contract.is_verified {
                 "✓ Verified".green()
             } else {
                 "○ Unverified".yellow()
             },
-            network.bright_blue()
+            contract.network.to_string().bright_blue()
         );
 
-        if let Some(desc) = contract["description"].as_str() {
+        if let Some(desc) = &contract.description {
             println!("  {}", desc.bright_black());
         }
     }
@@ -188,6 +190,35 @@ mod tests {
         let res = upgrade_analyze("http://localhost:3001", old_path.to_str().unwrap(), new_path.to_str().unwrap(), true).await;
         assert!(res.is_ok());
     }
+
+    #[test]
+    fn config_response_missing_a_field_names_it() {
+        let url = "http://localhost:3001/api/contracts/c/config?environment=prod";
+        let body = r#"{
+            "id": "7f2c1c8e-2b5e-4a43-9a51-0f4b8c1f6a10",
+            "contract_id": "0b7d7a4e-52a4-4c8b-8f4e-3d1f2a9c6b21",
+            "environment": "prod",
+            "config_data": {},
+            "has_secrets": false,
+            "created_at": "2026-10-16T12:00:00Z",
+            "created_by": "alice"
+        }"#;
+        let err = registry_client::decode::<ContractConfigResponse>(url, body).unwrap_err();
+        let message = err.to_string();
+        assert!(message.contains(url), "{}", message);
+        assert!(message.contains("ContractConfigResponse"), "{}", message);
+        assert!(message.contains("missing field `version`"), "{}", message);
+    }
+
+    #[test]
+    fn dependency_tree_nodes_are_checked_recursively() {
+        let body = r#"[{
+            "contract_id": "CA", "name": "root", "current_version": "1.0.0", "constraint_to_parent": "*",
+            "dependencies": [{ "contract_id": "CB", "name": "child", "current_version": "0.1.0", "dependencies": [] }]
+        }]"#;
+        let err = registry_client::decode::<Vec<DependencyTreeNode>>("/deps", body).unwrap_err();
+        assert!(err.to_string().contains("missing field `constraint_to_parent`"), "{}", err);
+    }
 }
 
 impl fmt::Display for Network {
@@ -200,6 +231,16 @@ impl fmt::Display for Network {
     }
 }
 
+impl From<Network> for shared::Network {
+    fn from(network: Network) -> Self {
+        match network {
+            Network::Mainnet => shared::Network::Mainnet,
+            Network::Testnet => shared::Network::Testnet,
+            Network::Futurenet => shared::Network::Futurenet,
+        }
+    }
+}
+
 impl FromStr for Network {
     type Err = anyhow::Error;
 
@@ -234,80 +275,65 @@ pub async fn publish(
     tags: Vec<String>,
     publisher: &str,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/api/contracts", api_url);
-
-    let payload = json!({
-        "contract_id": contract_id,
-        "name": name,
-        "description": description,
-        "network": network.to_string(),
-        "category": category,
-        "tags": tags,
-        "publisher_address": publisher,
-    });
+    let client = RegistryClient::new(api_url);
+    let request = PublishRequest {
+        contract_id: contract_id.to_string(),
+        name: name.to_string(),
+        description: description.map(str::to_string),
+        network: network.into(),
+        category: category.map(str::to_string),
+        tags,
+        source_url: None,
+        publisher_address: publisher.to_string(),
+        dependencies: Vec::new(),
+        license: None,
+    };
 
     println!("\n{}", "Publishing contract...".bold().cyan());
 
-    let response = client
-        .post(&url)
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to publish contract")?;
-
-    if !response.status().is_success() {
-        let error_text = response.text().await?;
-        anyhow::bail!("Failed to publish: {}", error_text);
-    }
-
-    let contract: serde_json::Value = response.json().await?;
+    let published = match client.publish(&request).await {
+        Ok(published) => published,
+        Err(ClientError::Api { message, .. }) => anyhow::bail!("Failed to publish: {}", message),
+        Err(err) => return Err(err).context("Failed to publish contract"),
+    };
+    let contract = &published.contract;
 
     println!("{}", "✓ Contract published successfully!".green().bold());
-    println!(
-        "\n{}: {}",
-        "Name".bold(),
-        contract["name"].as_str().unwrap_or("")
-    );
-    println!(
-        "{}: {}",
-        "ID".bold(),
-        contract["contract_id"].as_str().unwrap_or("")
-    );
+    println!("\n{}: {}", "Name".bold(), contract.name);
+    println!("{}: {}", "ID".bold(), contract.contract_id);
     println!(
         "{}: {}",
         "Network".bold(),
-        contract["network"].as_str().unwrap_or("").bright_blue()
+        contract.network.to_string().bright_blue()
     );
+
+    if !published.warnings.is_empty() {
+        println!("\n{}", "Warnings:".bold().yellow());
+        for warning in &published.warnings {
+            println!("  {} {}", "⚠".yellow(), warning.yellow());
+        }
+    }
     println!();
 
     Ok(())
 }
 
 pub async fn list(api_url: &str, limit: usize, network: Network, json: bool,) -> Result<()> {
-    let client = reqwest::Client::new();
-    let url = format!(
-        "{}/api/contracts?page_size={}&network={}",
-        api_url, limit, network
-    );
-
-    let response = client
-        .get(&url)
-        .send()
+    let client = RegistryClient::new(api_url);
+    let page = client
+        .list(limit as i64, network.into())
         .await
         .context("Failed to list contracts")?;
-
-    let data: serde_json::Value = response.json().await?;
-    let items = data["items"].as_array().context("Invalid response")?;
+    let items = page.items;
 
 	if json {
         let contracts: Vec<serde_json::Value> = items
             .iter()
             .map(|c| serde_json::json!({
-                "id":          c["contract_id"].as_str().unwrap_or(""),
-                "name":        c["name"].as_str().unwrap_or("Unknown"),
-                "is_verified": c["is_verified"].as_bool().unwrap_or(false),
-                "network":     c["network"].as_str().unwrap_or(""),
+                "id":          c.contract_id,
+                "name":        c.name,
+                "is_verified": c.is_verified,
+                "network":     c.network.to_string(),
             }))
             .collect();
         println!("{}", serde_json::to_string_pretty(&serde_json::json!({ "contracts": contracts }))?);
@@ -323,16 +349,11 @@ pub async fn list(api_url: &str, limit: usize, network: Network, json: bool,) ->
     }
 
     for (i, contract) in items.iter().enumerate() {
-        let name = contract["name"].as_str().unwrap_or("Unknown");
-        let contract_id = contract["contract_id"].as_str().unwrap_or("");
-        let is_verified = contract["is_verified"].as_bool().unwrap_or(false);
-        let network = contract["network"].as_str().unwrap_or("");
-
         println!(
             "\n{}. {} {}",
             i + 1,
-            name.bold(),
-            if is_verified {
+            contract.name.bold(),
+            if contract.is_verified {
                 "✓".green()
             } else {
                 "".normal()
@@ -340,8 +361,8 @@ pub async fn list(api_url: &str, limit: usize, network: Network, json: bool,) ->
         );
         println!(
             "   {} | {}",
-            contract_id.bright_black(),
-            network.bright_blue()
+            contract.contract_id.bright_black(),
+            contract.network.to_string().bright_blue()
         );
     }
 
@@ -473,8 +494,8 @@ pub async fn migrate(
         anyhow::bail!("API Error: {}", err);
     }
 
-    let migration: serde_json::Value = response.json().await?;
-    let migration_id = migration["id"].as_str().unwrap();
+    let migration: Migration = read_json(response).await?;
+    let migration_id = migration.id.to_string();
     println!("{}", "OK".green());
     println!("Migration ID: {}", migration_id);
 
@@ -816,8 +837,7 @@ pub async fn deps_list(api_url: &str, contract_id: &str) -> Result<()> {
         anyhow::bail!("Failed to fetch dependencies: {}", response.status());
     }
 
-    let items: serde_json::Value = response.json().await?;
-    let tree = items.as_array().context("Invalid response format")?;
+    let tree: Vec<DependencyTreeNode> = read_json(response).await?;
 
     println!("\n{}", "Dependency Tree:".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
@@ -827,12 +847,8 @@ pub async fn deps_list(api_url: &str, contract_id: &str) -> Result<()> {
         return Ok(());
     }
 
-    fn print_tree(nodes: &[serde_json::Value], prefix: &str, is_last: bool) {
+    fn print_tree(nodes: &[DependencyTreeNode], prefix: &str, is_last: bool) {
         for (i, node) in nodes.iter().enumerate() {
-            let name = node["name"].as_str().unwrap_or("Unknown");
-            let constraint = node["constraint_to_parent"].as_str().unwrap_or("*");
-            let contract_id = node["contract_id"].as_str().unwrap_or("");
-            
             let is_node_last = i == nodes.len() - 1;
             let marker = if is_node_last { "└──" } else { "├──" };
             
@@ -840,21 +856,19 @@ pub async fn deps_list(api_url: &str, contract_id: &str) -> Result<()> {
                 "{}{} {} ({}) {}", 
                 prefix, 
                 marker.bright_black(), 
-                name.bold(), 
-                constraint.cyan(),
-                if contract_id == "unknown" { "[Unresolved]".red() } else { "".normal() }
+                node.name.bold(), 
+                node.constraint_to_parent.cyan(),
+                if node.contract_id == "unknown" { "[Unresolved]".red() } else { "".normal() }
             );
 
-            if let Some(children) = node["dependencies"].as_array() {
-                if !children.is_empty() {
-                     let new_prefix = format!("{}{}", prefix, if is_node_last { "    " } else { "│   " });
-                     print_tree(children, &new_prefix, true);
-                }
+            if !node.dependencies.is_empty() {
+                 let new_prefix = format!("{}{}", prefix, if is_node_last { "    " } else { "│   " });
+                 print_tree(&node.dependencies, &new_prefix, true);
             }
         }
     }
 
-    print_tree(tree, "", false);
+    print_tree(&tree, "", false);
 
     println!();
     Ok(())
@@ -1022,17 +1036,17 @@ pub async fn config_get(api_url: &str, contract_id: &str, environment: &str) ->
         anyhow::bail!("Failed to get config: {}", response.text().await.unwrap_or_default());
     }
 
-    let config: serde_json::Value = response.json().await?;
+    let config: ContractConfigResponse = read_json(response).await?;
 
     println!("\n{}", "Contract Configuration (Latest):".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
     println!("{}: {}", "Contract ID".bold(), contract_id);
     println!("{}: {}", "Environment".bold(), environment);
-    println!("{}: {}", "Version".bold(), config["version"].as_i64().unwrap_or(0));
-    println!("{}: {}", "Contains Secrets".bold(), config["has_secrets"].as_bool().unwrap_or(false));
-    println!("{}: {}", "Created By".bold(), config["created_by"].as_str().unwrap_or("Unknown"));
+    println!("{}: {}", "Version".bold(), config.version);
+    println!("{}: {}", "Contains Secrets".bold(), config.has_secrets);
+    println!("{}: {}", "Created By".bold(), config.created_by);
     println!("{}:", "Config Data".bold());
-    println!("{}", serde_json::to_string_pretty(&config["config_data"]).unwrap_or_default().green());
+    println!("{}", serde_json::to_string_pretty(&config.config_data).unwrap_or_default().green());
     println!();
 
     Ok(())
@@ -1068,11 +1082,11 @@ pub async fn config_set(
         anyhow::bail!("Failed to set config: {}", response.text().await.unwrap_or_default());
     }
 
-    let config: serde_json::Value = response.json().await?;
+    let config: ContractConfigResponse = read_json(response).await?;
 
     println!("{}", "✓ Configuration published successfully!".green().bold());
     println!("  {}: {}", "Environment".bold(), environment);
-    println!("  {}: {}", "New Version".bold(), config["version"].as_i64().unwrap_or(0));
+    println!("  {}: {}", "New Version".bold(), config.version);
     println!();
 
     Ok(())
@@ -1088,7 +1102,7 @@ pub async fn config_history(api_url: &str, contract_id: &str, environment: &str)
         anyhow::bail!("Failed to get config history: {}", response.text().await.unwrap_or_default());
     }
 
-    let configs: Vec<serde_json::Value> = response.json().await?;
+    let configs: Vec<ContractConfigResponse> = read_json(response).await?;
 
     println!("\n{}", "Configuration History:".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
@@ -1102,9 +1116,12 @@ pub async fn config_history(api_url: &str, contract_id: &str, environment: &str)
         println!(
             "  {}. {} (v{}) - By: {}",
             i + 1,
-            config["created_at"].as_str().unwrap_or("Unknown Date").bright_black(),
-            config["version"].as_i64().unwrap_or(0),
-            config["created_by"].as_str().unwrap_or("Unknown").bright_blue()
+            config
+                .created_at
+                .to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+                .bright_black(),
+            config.version,
+            config.created_by.bright_blue()
         );
     }
     println!();
@@ -1135,11 +1152,11 @@ pub async fn config_rollback(
         anyhow::bail!("Failed to rollback config: {}", response.text().await.unwrap_or_default());
     }
 
-    let config: serde_json::Value = response.json().await?;
+    let config: ContractConfigResponse = read_json(response).await?;
 
     println!("{}", "✓ Configuration rolled back successfully!".green().bold());
     println!("  {}: {}", "Environment".bold(), environment);
-    println!("  {}: {}", "New Active Version".bold(), config["version"].as_i64().unwrap_or(0));
+    println!("  {}: {}", "New Active Version".bold(), config.version);
     println!();
 
     Ok(())
@@ -1518,21 +1535,17 @@ pub async fn list_functions(api_url: &str, contract_id: &str) -> Result<()> {
 /// Use --network to get network-specific config (e.g. mainnet, testnet).
 pub async fn info(api_url: &str, id: &str, network: crate::config::Network) -> Result<()> {
     println!("\n{}", "Fetching contract information...".bold().cyan());
-    
-    let url = format!("{}/api/contracts/{}", api_url.trim_end_matches('/'), id);
-    let client = reqwest::Client::new();
-    let response = client
-        .get(&url)
-        .query(&[("network", network.to_string())])
-        .send()
-        .await?;
 
-    if response.status().is_success() {
-        let contract_info: serde_json::Value = response.json().await?;
-        println!("\n{}", serde_json::to_string_pretty(&contract_info)?);
-    } else {
-        anyhow::bail!("Failed to fetch contract info: {}", response.status());
-    }
+    let client = RegistryClient::new(api_url);
+    let contract_info = match client.get_contract(id, network.to_api_network()).await {
+        Ok(contract_info) => contract_info,
+        Err(ClientError::Api { status, .. }) => anyhow::bail!(
+            "Failed to fetch contract info: {}",
+            reqwest::StatusCode::from_u16(status).map_or(status.to_string(), |s| s.to_string())
+        ),
+        Err(err) => return Err(err.into()),
+    };
+    println!("\n{}", serde_json::to_string_pretty(&contract_info)?);
 
     Ok(())
 }
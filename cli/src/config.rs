@@ -29,6 +29,18 @@ impl fmt::Display for Network {
     }
 }
 
+impl Network {
+    /// The network to send to the API; `None` for `Auto`, letting the registry choose
+    pub fn to_api_network(self) -> Option<shared::Network> {
+        match self {
+            Network::Mainnet => Some(shared::Network::Mainnet),
+            Network::Testnet => Some(shared::Network::Testnet),
+            Network::Futurenet => Some(shared::Network::Futurenet),
+            Network::Auto => None,
+        }
+    }
+}
+
 impl FromStr for Network {
     type Err = anyhow::Error;
 
@@ -56,6 +68,8 @@ struct DefaultsSection {
     network: Option<String>,
     api_base: Option<String>,
     timeout: Option<u64>,
+    /// Extra registries searched alongside `api_base`
+    registries: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone)]
@@ -100,6 +114,31 @@ pub fn resolve_runtime_config(
     })
 }
 
+/// Registries to search: the primary API URL, then `--registry` flags, then
+/// `defaults.registries` from the config file. Duplicates are dropped.
+pub fn resolve_registries(primary: &str, cli_registries: &[String]) -> Result<Vec<String>> {
+    let config = load_defaults_section()?;
+    Ok(merge_registries(
+        primary,
+        cli_registries,
+        config.registries.as_deref().unwrap_or_default(),
+    ))
+}
+
+fn merge_registries(primary: &str, cli_registries: &[String], configured: &[String]) -> Vec<String> {
+    let mut registries: Vec<String> = Vec::new();
+    let candidates = std::iter::once(primary)
+        .chain(cli_registries.iter().map(String::as_str))
+        .chain(configured.iter().map(String::as_str));
+    for url in candidates {
+        let url = url.trim().trim_end_matches('/');
+        if !url.is_empty() && !registries.iter().any(|r| r == url) {
+            registries.push(url.to_string());
+        }
+    }
+    registries
+}
+
 pub fn show_config() -> Result<()> {
     let path = config_file_path().context("Could not determine home directory")?;
     let defaults = load_defaults_section()?;
@@ -225,4 +264,17 @@ timeout = 55
         assert_eq!(defaults.api_base.as_deref(), Some("http://localhost:9000"));
         assert_eq!(defaults.timeout, Some(55));
     }
+
+    #[test]
+    fn registries_merge_in_order_without_duplicates() {
+        let merged = merge_registries(
+            "http://localhost:3001/",
+            &["https://a.example".to_string(), "http://localhost:3001".to_string()],
+            &["https://b.example".to_string(), "https://a.example/".to_string()],
+        );
+        assert_eq!(
+            merged,
+            vec!["http://localhost:3001", "https://a.example", "https://b.example"]
+        );
+    }
 }
@@ -10,6 +10,7 @@ mod import;
 mod incident;
 mod manifest;
 mod migration;
+mod multi_search;
 mod multisig;
 mod package_signing;
 mod patch;
@@ -58,6 +59,9 @@ pub enum Commands {
         /// Output results as machine-readable JSON
         #[arg(long)]
         json: bool,
+        /// Also search this registry (repeatable); results are merged
+        #[arg(long = "registry")]
+        registries: Vec<String>,
     },
 
     /// Get detailed information about a contract
@@ -471,8 +475,9 @@ pub enum MultisigCommands {
         proposal_id: String,
         #[arg(long)]
         signer: String,
+        /// Hex Ed25519 signature over the proposal's signing message
         #[arg(long)]
-        signature_data: Option<String>,
+        signature_data: String,
     },
 
     /// Execute an approved deployment proposal
@@ -624,10 +629,28 @@ pub enum MigrateCommands {
     },
     /// Rollback a migration by migration ID
     Rollback { migration_id: String },
+    /// Check the recorded history for inconsistencies
+    VerifyHistory,
+    /// Write a contract snapshot's state to a JSON file for editing
+    ExportState { id: String, file: String },
+    /// Load a contract snapshot's state from a JSON file, checked against its schema
+    ImportState { id: String, file: String },
     /// Show migration history
     History {
         #[arg(long, default_value = "20")]
         limit: usize,
+        /// Only show one kind of entry
+        #[arg(long, value_parser = migration::HISTORY_ACTIONS)]
+        action: Option<String>,
+        /// Only show migrations from or to this contract
+        #[arg(long)]
+        contract: Option<String>,
+        /// Only show entries at or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        since: Option<String>,
+        /// Only show entries at or before this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long)]
+        until: Option<String>,
     },
 }
 
@@ -657,13 +680,20 @@ async fn main() -> Result<()> {
             query,
             verified_only,
             json,
+            registries,
         } => {
+            let registries = config::resolve_registries(&cli.api_url, &registries)?;
             log::debug!(
-                "Command: search | query={:?} verified_only={}",
+                "Command: search | query={:?} verified_only={} registries={:?}",
                 query,
-                verified_only
+                verified_only,
+                registries
             );
-            commands::search(&cli.api_url, &query, network, verified_only, json).await?;
+            if registries.len() > 1 {
+                multi_search::search(&registries, &query, network, verified_only, json).await?;
+            } else {
+                commands::search(&cli.api_url, &query, network, verified_only, json).await?;
+            }
         }
         Commands::Info { contract_id } => {
             log::debug!("Command: info | contract_id={}", contract_id);
@@ -745,9 +775,40 @@ async fn main() -> Result<()> {
                 log::debug!("Command: migrate rollback | migration_id={}", migration_id);
                 migration::rollback(&migration_id)?;
             }
-            MigrateCommands::History { limit } => {
-                log::debug!("Command: migrate history | limit={}", limit);
-                migration::history(limit)?;
+            MigrateCommands::ExportState { id, file } => {
+                log::debug!("Command: migrate export-state | id={} file={}", id, file);
+                migration::export_state(&id, &file)?;
+            }
+            MigrateCommands::ImportState { id, file } => {
+                log::debug!("Command: migrate import-state | id={} file={}", id, file);
+                migration::import_state(&id, &file)?;
+            }
+            MigrateCommands::VerifyHistory => {
+                log::debug!("Command: migrate verify-history");
+                migration::verify_history()?;
+            }
+            MigrateCommands::History {
+                limit,
+                action,
+                contract,
+                since,
+                until,
+            } => {
+                log::debug!(
+                    "Command: migrate history | limit={} action={:?} contract={:?} since={:?} until={:?}",
+                    limit, action, contract, since, until
+                );
+                let filter = migration::HistoryFilter {
+                    action,
+                    contract_id: contract,
+                    since: since
+                        .map(|s| migration::parse_history_date(&s, false))
+                        .transpose()?,
+                    until: until
+                        .map(|s| migration::parse_history_date(&s, true))
+                        .transpose()?,
+                };
+                migration::history(limit, &filter)?;
             }
         },
         Commands::Export {
@@ -908,7 +969,7 @@ async fn main() -> Result<()> {
                     &cli.api_url,
                     &proposal_id,
                     &signer,
-                    signature_data.as_deref(),
+                    &signature_data,
                 )
                 .await?;
             }
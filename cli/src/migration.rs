@@ -1,12 +1,13 @@
 use anyhow::{anyhow, bail, Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use serde_json::{Map, Value};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, VecDeque};
 use std::fs::{self, OpenOptions};
 use std::io::{BufRead, BufReader, Write};
 use std::path::{Path, PathBuf};
+use schema_migration::{convert_value, SchemaDiff};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,20 +21,6 @@ struct ContractSnapshot {
     state: Map<String, Value>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct SchemaDiff {
-    added_fields: Vec<String>,
-    removed_fields: Vec<String>,
-    changed_types: Vec<TypeChange>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-struct TypeChange {
-    field: String,
-    old_type: String,
-    new_type: String,
-}
-
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct MigrationRecord {
     id: String,
@@ -48,6 +35,9 @@ struct MigrationRecord {
     after_state: Option<Value>,
     backup_old_snapshot: Option<ContractSnapshot>,
     backup_new_snapshot: Option<ContractSnapshot>,
+    /// For `rollback` records: the id of the apply being undone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rolled_back_id: Option<String>,
 }
 
 pub fn preview(old_id: &str, new_id: &str) -> Result<()> {
@@ -88,6 +78,7 @@ pub fn preview(old_id: &str, new_id: &str) -> Result<()> {
         after_state: Some(Value::Object(migrated)),
         backup_old_snapshot: None,
         backup_new_snapshot: None,
+        rolled_back_id: None,
     })?;
 
     Ok(())
@@ -193,6 +184,7 @@ pub fn apply(old_id: &str, new_id: &str) -> Result<()> {
         after_state: Some(Value::Object(migrated_state)),
         backup_old_snapshot: Some(old_snapshot),
         backup_new_snapshot: previous_new_snapshot,
+        rolled_back_id: None,
     })?;
 
     println!(
@@ -203,6 +195,10 @@ pub fn apply(old_id: &str, new_id: &str) -> Result<()> {
     Ok(())
 }
 
+/// Warning recorded on rollback entries; older histories only carry the
+/// rolled-back id here.
+const ROLLBACK_NOTE_PREFIX: &str = "Rolled back migration ";
+
 pub fn rollback(migration_id: &str) -> Result<()> {
     let records = read_history()?;
     let record = records
@@ -237,11 +233,12 @@ pub fn rollback(migration_id: &str) -> Result<()> {
         old_id: Some(old_id),
         new_id: None,
         diff: None,
-        warnings: vec![format!("Rolled back migration {}", migration_id)],
+        warnings: vec![format!("{}{}", ROLLBACK_NOTE_PREFIX, migration_id)],
         before_state: None,
         after_state: None,
         backup_old_snapshot: None,
         backup_new_snapshot: None,
+        rolled_back_id: Some(migration_id.to_string()),
     })?;
 
     println!(
@@ -252,12 +249,200 @@ pub fn rollback(migration_id: &str) -> Result<()> {
     Ok(())
 }
 
-pub fn history(limit: usize) -> Result<()> {
-    let records = read_history()?;
+/// Write a snapshot's state to `file` as a standalone JSON object.
+pub fn export_state(contract_id: &str, file: &str) -> Result<()> {
+    let snapshot = load_snapshot(contract_id)?;
+    write_state_file(&snapshot.state, Path::new(file))?;
+    println!(
+        "{} {} ({} fields) -> {}",
+        "Exported state:".green().bold(),
+        contract_id,
+        snapshot.state.len(),
+        file
+    );
+    Ok(())
+}
+
+/// Replace a snapshot's state with the contents of `file`, after checking it
+/// against the snapshot's schema.
+pub fn import_state(contract_id: &str, file: &str) -> Result<()> {
+    let mut snapshot = load_snapshot(contract_id)?;
+    let state = read_state_file(Path::new(file))?;
+
+    snapshot.state = match validate_state_against_schema(&snapshot.schema, state) {
+        Ok(state) => state,
+        Err(issues) => {
+            for issue in &issues {
+                eprintln!("{} {}", "Schema violation:".red().bold(), issue);
+            }
+            bail!("Import rejected: {} does not match the schema of {}", file, contract_id)
+        }
+    };
+    persist_snapshot(&snapshot)?;
+
+    println!(
+        "{} {} ({} fields) <- {}",
+        "Imported state:".green().bold(),
+        contract_id,
+        snapshot.state.len(),
+        file
+    );
+    Ok(())
+}
+
+fn write_state_file(state: &Map<String, Value>, path: &Path) -> Result<()> {
+    fs::write(path, serde_json::to_string_pretty(state)?)
+        .with_context(|| format!("Failed to write state file {}", path.display()))
+}
+
+fn read_state_file(path: &Path) -> Result<Map<String, Value>> {
+    let data = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read state file {}", path.display()))?;
+    match serde_json::from_str(&data)
+        .with_context(|| format!("Invalid JSON in state file {}", path.display()))?
+    {
+        Value::Object(state) => Ok(state),
+        _ => bail!("State file {} must contain a JSON object", path.display()),
+    }
+}
+
+/// Check every field is declared in `schema` and convertible to its type,
+/// returning the state with values normalized to those types. An empty
+/// schema accepts any state unchanged.
+fn validate_state_against_schema(
+    schema: &BTreeMap<String, String>,
+    state: Map<String, Value>,
+) -> std::result::Result<Map<String, Value>, Vec<String>> {
+    if schema.is_empty() {
+        return Ok(state);
+    }
+
+    let mut issues = Vec::new();
+    let mut normalized = Map::new();
+    for (field, value) in state {
+        match schema.get(&field) {
+            None => issues.push(format!("Field '{}' is not part of the schema", field)),
+            Some(ty) => match convert_value(&value, ty) {
+                Some(converted) => {
+                    normalized.insert(field, converted);
+                }
+                None => issues.push(format!(
+                    "Field '{}' value {} is not compatible with type '{}'",
+                    field, value, ty
+                )),
+            },
+        }
+    }
+
+    if issues.is_empty() {
+        Ok(normalized)
+    } else {
+        Err(issues)
+    }
+}
+
+/// Narrows `migrate history` output. Unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    /// preview | apply | rollback
+    pub action: Option<String>,
+    /// Matches either side of the migration
+    pub contract_id: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+pub const HISTORY_ACTIONS: [&str; 3] = ["preview", "apply", "rollback"];
+
+impl HistoryFilter {
+    fn matches(&self, record: &MigrationRecord) -> bool {
+        if let Some(action) = &self.action {
+            if !record.action.eq_ignore_ascii_case(action) {
+                return false;
+            }
+        }
+        if let Some(contract) = &self.contract_id {
+            let touches = record.old_id.as_deref() == Some(contract.as_str())
+                || record.new_id.as_deref() == Some(contract.as_str());
+            if !touches {
+                return false;
+            }
+        }
+        if self.since.is_some() || self.until.is_some() {
+            // Records with unreadable timestamps cannot satisfy a date range
+            let Ok(at) = DateTime::parse_from_rfc3339(&record.timestamp) else {
+                return false;
+            };
+            let at = at.with_timezone(&Utc);
+            if self.since.is_some_and(|since| at < since) || self.until.is_some_and(|until| at > until) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Parse a `--since`/`--until` value: RFC 3339, or a bare date meaning the
+/// start (or, with `end_of_day`, the end) of that day in UTC.
+pub fn parse_history_date(raw: &str, end_of_day: bool) -> Result<DateTime<Utc>> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(raw) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let date = NaiveDate::parse_from_str(raw, "%Y-%m-%d")
+        .with_context(|| format!("Invalid date '{}'; use YYYY-MM-DD or RFC 3339", raw))?;
+    let time = if end_of_day {
+        date.and_hms_nano_opt(23, 59, 59, 999_999_999)
+    } else {
+        date.and_hms_opt(0, 0, 0)
+    };
+    Ok(time.expect("valid time of day").and_utc())
+}
+
+/// Stream history from `reader`, keeping only the newest `limit` records that
+/// match `filter`. Memory use is bounded by `limit`, not by the file size.
+fn filter_history<R: BufRead>(
+    reader: R,
+    filter: &HistoryFilter,
+    limit: usize,
+) -> Result<Vec<MigrationRecord>> {
+    let mut newest: VecDeque<MigrationRecord> = VecDeque::with_capacity(limit.min(1024));
+    if limit == 0 {
+        return Ok(Vec::new());
+    }
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: MigrationRecord = serde_json::from_str(&line)
+            .with_context(|| format!("Failed to parse migration history line {}", index + 1))?;
+        if !filter.matches(&record) {
+            continue;
+        }
+        if newest.len() == limit {
+            newest.pop_front();
+        }
+        newest.push_back(record);
+    }
+
+    Ok(newest.into_iter().rev().collect())
+}
+
+pub fn history(limit: usize, filter: &HistoryFilter) -> Result<()> {
+    let path = history_path()?;
+    let records = if path.exists() {
+        let file = fs::File::open(&path)
+            .with_context(|| format!("Failed to open history file {}", path.display()))?;
+        filter_history(BufReader::new(file), filter, limit)?
+    } else {
+        Vec::new()
+    };
+
     println!("\n{}", "Migration History".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
 
-    for record in records.iter().rev().take(limit) {
+    for record in &records {
         println!(
             "{} | {} | {} | {} -> {}",
             record.timestamp,
@@ -274,185 +459,221 @@ pub fn history(limit: usize) -> Result<()> {
     Ok(())
 }
 
-fn analyze_internal(
-    old_snapshot: &ContractSnapshot,
-    new_snapshot: &ContractSnapshot,
-) -> SchemaDiff {
-    let mut added_fields = Vec::new();
-    let mut removed_fields = Vec::new();
-    let mut changed_types = Vec::new();
-
-    for (field, new_ty) in &new_snapshot.schema {
-        match old_snapshot.schema.get(field) {
-            Some(old_ty) if old_ty != new_ty => changed_types.push(TypeChange {
-                field: field.clone(),
-                old_type: old_ty.clone(),
-                new_type: new_ty.clone(),
-            }),
-            None => added_fields.push(field.clone()),
-            _ => {}
-        }
-    }
+/// Something wrong with the recorded history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryAnomaly {
+    /// 1-based line in the history file
+    pub line: usize,
+    pub record_id: Option<String>,
+    pub message: String,
+}
 
-    for field in old_snapshot.schema.keys() {
-        if !new_snapshot.schema.contains_key(field) {
-            removed_fields.push(field.clone());
+impl HistoryAnomaly {
+    fn new(line: usize, record_id: Option<&str>, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            record_id: record_id.map(str::to_string),
+            message: message.into(),
         }
     }
+}
 
-    SchemaDiff {
-        added_fields,
-        removed_fields,
-        changed_types,
+impl MigrationRecord {
+    fn rolled_back_target(&self) -> Option<&str> {
+        self.rolled_back_id.as_deref().or_else(|| {
+            self.warnings
+                .iter()
+                .find_map(|w| w.strip_prefix(ROLLBACK_NOTE_PREFIX))
+        })
     }
 }
 
-fn validate_internal(
-    old_snapshot: &ContractSnapshot,
-    new_snapshot: &ContractSnapshot,
-    diff: &SchemaDiff,
-) -> Vec<String> {
-    let mut issues = Vec::new();
+/// Replay the history and report anything a well-behaved CLI could not have
+/// written: rollbacks of unknown, failed or already rolled back applies,
+/// applies whose snapshots have since disappeared, timestamps going
+/// backwards, and lines that do not parse.
+fn verify_history_lines<R: BufRead>(
+    reader: R,
+    snapshot_exists: impl Fn(&str) -> bool,
+) -> Result<Vec<HistoryAnomaly>> {
+    let mut anomalies = Vec::new();
+    // apply id -> (line, still in effect, record)
+    let mut applies: BTreeMap<String, (usize, bool, MigrationRecord)> = BTreeMap::new();
+    let mut seen_ids: BTreeMap<String, usize> = BTreeMap::new();
+    let mut previous: Option<(usize, DateTime<Utc>)> = None;
+
+    for (index, line) in reader.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: MigrationRecord = match serde_json::from_str(&line) {
+            Ok(record) => record,
+            Err(err) => {
+                anomalies.push(HistoryAnomaly::new(line_no, None, format!("unparseable record: {}", err)));
+                continue;
+            }
+        };
+        let id = record.id.as_str();
 
-    for field in &diff.removed_fields {
-        if let Some(value) = old_snapshot.state.get(field) {
-            if !value.is_null() {
-                issues.push(format!(
-                    "Field '{}' is removed but currently contains data; migration would drop value {}",
-                    field, value
-                ));
+        if let Some(first) = seen_ids.insert(record.id.clone(), line_no) {
+            anomalies.push(HistoryAnomaly::new(
+                line_no,
+                Some(id),
+                format!("duplicate record id (first seen on line {})", first),
+            ));
+        }
+
+        match DateTime::parse_from_rfc3339(&record.timestamp) {
+            Ok(at) => {
+                let at = at.with_timezone(&Utc);
+                if let Some((prev_line, prev_at)) = previous {
+                    if at < prev_at {
+                        anomalies.push(HistoryAnomaly::new(
+                            line_no,
+                            Some(id),
+                            format!(
+                                "timestamp {} is earlier than line {} ({})",
+                                record.timestamp,
+                                prev_line,
+                                prev_at.to_rfc3339()
+                            ),
+                        ));
+                    }
+                }
+                previous = Some((line_no, at));
             }
+            Err(_) => anomalies.push(HistoryAnomaly::new(
+                line_no,
+                Some(id),
+                format!("invalid timestamp '{}'", record.timestamp),
+            )),
         }
-    }
 
-    for change in &diff.changed_types {
-        if let Some(value) = old_snapshot.state.get(&change.field) {
-            if convert_value(value, &change.new_type).is_none() {
-                issues.push(format!(
-                    "Field '{}' type change {} -> {} is not safely convertible for value {}",
-                    change.field, change.old_type, change.new_type, value
-                ));
+        match record.action.as_str() {
+            "apply" => {
+                if record.status == "success" && record.backup_old_snapshot.is_none() {
+                    anomalies.push(HistoryAnomaly::new(
+                        line_no,
+                        Some(id),
+                        "apply is missing the old snapshot needed for rollback",
+                    ));
+                }
+                applies.insert(record.id.clone(), (line_no, record.status == "success", record));
             }
+            "rollback" => match record.rolled_back_target() {
+                None => anomalies.push(HistoryAnomaly::new(
+                    line_no,
+                    Some(id),
+                    "rollback does not say which migration it undid",
+                )),
+                Some(target) => match applies.get_mut(target) {
+                    None => anomalies.push(HistoryAnomaly::new(
+                        line_no,
+                        Some(id),
+                        format!("rollback references unknown or later apply {}", target),
+                    )),
+                    Some((apply_line, in_effect, apply)) => {
+                        if apply.status != "success" {
+                            anomalies.push(HistoryAnomaly::new(
+                                line_no,
+                                Some(id),
+                                format!("rollback references failed apply on line {}", apply_line),
+                            ));
+                        } else if !*in_effect {
+                            anomalies.push(HistoryAnomaly::new(
+                                line_no,
+                                Some(id),
+                                format!("apply on line {} was already rolled back", apply_line),
+                            ));
+                        }
+                        *in_effect = false;
+                    }
+                },
+            },
+            "preview" => {}
+            other => anomalies.push(HistoryAnomaly::new(
+                line_no,
+                Some(id),
+                format!("unknown action '{}'", other),
+            )),
         }
     }
 
-    for (field, new_ty) in &new_snapshot.schema {
-        if let Some(value) = old_snapshot.state.get(field) {
-            if convert_value(value, new_ty).is_none() {
-                issues.push(format!(
-                    "Field '{}' cannot be represented as target type '{}'",
-                    field, new_ty
+    // Applies still in effect should have their snapshots on disk
+    for (apply_id, (line_no, in_effect, apply)) in &applies {
+        if !*in_effect {
+            continue;
+        }
+        for contract in [apply.old_id.as_deref(), apply.new_id.as_deref()].into_iter().flatten() {
+            if !snapshot_exists(contract) {
+                anomalies.push(HistoryAnomaly::new(
+                    *line_no,
+                    Some(apply_id),
+                    format!("snapshot for '{}' no longer exists", contract),
                 ));
             }
         }
     }
 
-    issues
+    anomalies.sort_by_key(|a| a.line);
+    Ok(anomalies)
 }
 
-fn dry_run_internal(
-    old_snapshot: &ContractSnapshot,
-    new_snapshot: &ContractSnapshot,
-    diff: &SchemaDiff,
-) -> (Map<String, Value>, Vec<String>) {
-    let mut migrated = Map::new();
-    let mut warnings = Vec::new();
-
-    for (field, new_ty) in &new_snapshot.schema {
-        let value = match old_snapshot.state.get(field) {
-            Some(existing) => match convert_value(existing, new_ty) {
-                Some(converted) => converted,
-                None => {
-                    warnings.push(format!(
-                        "Field '{}' could not convert to '{}'; using default value",
-                        field, new_ty
-                    ));
-                    default_for_type(new_ty)
-                }
-            },
-            None => default_for_type(new_ty),
-        };
+pub fn verify_history() -> Result<()> {
+    let path = history_path()?;
+    println!("\n{}", "Migration History Integrity".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
 
-        migrated.insert(field.clone(), value);
+    if !path.exists() {
+        println!("{}", "No migration history recorded.".green());
+        return Ok(());
     }
 
-    for field in &diff.removed_fields {
-        if old_snapshot.state.contains_key(field) {
-            warnings.push(format!(
-                "Field '{}' removed in new schema and omitted from migrated state",
-                field
-            ));
-        }
+    let file = fs::File::open(&path)
+        .with_context(|| format!("Failed to open history file {}", path.display()))?;
+    let anomalies = verify_history_lines(BufReader::new(file), |contract| {
+        snapshot_path(contract).exists()
+    })?;
+
+    if anomalies.is_empty() {
+        println!("{}", "History is consistent.".green().bold());
+        return Ok(());
     }
 
-    (migrated, warnings)
-}
-
-fn convert_value(value: &Value, target_type: &str) -> Option<Value> {
-    match normalize_type(target_type).as_str() {
-        "string" => Some(Value::String(match value {
-            Value::String(s) => s.clone(),
-            other => other.to_string(),
-        })),
-        "number" | "float" => match value {
-            Value::Number(_) => Some(value.clone()),
-            Value::String(s) => s
-                .parse::<f64>()
-                .ok()
-                .and_then(serde_json::Number::from_f64)
-                .map(Value::Number),
-            Value::Bool(b) => Some(Value::Number(serde_json::Number::from(if *b {
-                1
-            } else {
-                0
-            }))),
-            _ => None,
-        },
-        "integer" | "int" => match value {
-            Value::Number(n) => n
-                .as_i64()
-                .map(|i| Value::Number(serde_json::Number::from(i))),
-            Value::String(s) => s
-                .parse::<i64>()
-                .ok()
-                .map(|i| Value::Number(serde_json::Number::from(i))),
-            Value::Bool(b) => Some(Value::Number(serde_json::Number::from(if *b {
-                1
-            } else {
-                0
-            }))),
-            _ => None,
-        },
-        "boolean" | "bool" => match value {
-            Value::Bool(_) => Some(value.clone()),
-            Value::Number(n) => n.as_i64().map(|i| Value::Bool(i != 0)),
-            Value::String(s) => match s.to_ascii_lowercase().as_str() {
-                "true" | "1" => Some(Value::Bool(true)),
-                "false" | "0" => Some(Value::Bool(false)),
-                _ => None,
-            },
-            _ => None,
-        },
-        "array" => value.as_array().map(|_| value.clone()),
-        "object" | "map" => value.as_object().map(|_| value.clone()),
-        _ => Some(value.clone()),
+    for anomaly in &anomalies {
+        println!(
+            "line {:>5} | {} | {}",
+            anomaly.line,
+            anomaly.record_id.as_deref().unwrap_or("-"),
+            anomaly.message
+        );
     }
+    bail!("Found {} anomalies in {}", anomalies.len(), path.display())
 }
 
-fn default_for_type(target_type: &str) -> Value {
-    match normalize_type(target_type).as_str() {
-        "string" => Value::String(String::new()),
-        "number" | "float" => Value::Number(serde_json::Number::from(0)),
-        "integer" | "int" => Value::Number(serde_json::Number::from(0)),
-        "boolean" | "bool" => Value::Bool(false),
-        "array" => Value::Array(Vec::new()),
-        "object" | "map" => Value::Object(Map::new()),
-        _ => Value::Null,
-    }
+fn analyze_internal(
+    old_snapshot: &ContractSnapshot,
+    new_snapshot: &ContractSnapshot,
+) -> SchemaDiff {
+    schema_migration::analyze(&old_snapshot.schema, &new_snapshot.schema)
 }
 
-fn normalize_type(raw: &str) -> String {
-    raw.trim().to_ascii_lowercase()
+fn validate_internal(
+    old_snapshot: &ContractSnapshot,
+    new_snapshot: &ContractSnapshot,
+    diff: &SchemaDiff,
+) -> Vec<String> {
+    schema_migration::validate(&old_snapshot.state, &new_snapshot.schema, diff)
+}
+
+fn dry_run_internal(
+    old_snapshot: &ContractSnapshot,
+    new_snapshot: &ContractSnapshot,
+    diff: &SchemaDiff,
+) -> (Map<String, Value>, Vec<String>) {
+    schema_migration::dry_run(&old_snapshot.state, &new_snapshot.schema, diff)
 }
 
 fn print_diff(old_id: &str, new_id: &str, diff: &SchemaDiff) {
@@ -773,4 +994,251 @@ mod tests {
         );
         assert_eq!(migrated.get("active").unwrap(), &Value::Bool(false));
     }
+
+    fn record(action: &str, old_id: &str, new_id: Option<&str>, timestamp: &str) -> MigrationRecord {
+        MigrationRecord {
+            id: Uuid::new_v4().to_string(),
+            action: action.to_string(),
+            timestamp: timestamp.to_string(),
+            status: "success".to_string(),
+            old_id: Some(old_id.to_string()),
+            new_id: new_id.map(str::to_string),
+            diff: None,
+            warnings: Vec::new(),
+            before_state: None,
+            after_state: None,
+            backup_old_snapshot: None,
+            backup_new_snapshot: None,
+            rolled_back_id: None,
+        }
+    }
+
+    fn synthetic_history() -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        let records = [
+            record("preview", "token_v1", Some("token_v2"), "2026-01-01T10:00:00Z"),
+            record("apply", "token_v1", Some("token_v2"), "2026-01-02T10:00:00Z"),
+            record("apply", "vault_v1", Some("vault_v2"), "2026-01-03T10:00:00Z"),
+            record("rollback", "token_v1", None, "2026-01-04T10:00:00Z"),
+            record("apply", "token_v2", Some("token_v3"), "2026-01-05T10:00:00Z"),
+        ];
+        for r in &records {
+            writeln!(file, "{}", serde_json::to_string(r).unwrap()).unwrap();
+        }
+        writeln!(file).unwrap();
+        file
+    }
+
+    fn run(filter: &HistoryFilter, limit: usize) -> Vec<(String, String)> {
+        let file = synthetic_history();
+        let reader = BufReader::new(fs::File::open(file.path()).unwrap());
+        filter_history(reader, filter, limit)
+            .unwrap()
+            .into_iter()
+            .map(|r| (r.action, r.timestamp[..10].to_string()))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected.iter().map(|(a, d)| (a.to_string(), d.to_string())).collect()
+    }
+
+    #[test]
+    fn history_filters_by_action_newest_first() {
+        let filter = HistoryFilter {
+            action: Some("apply".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&filter, 20),
+            pairs(&[("apply", "2026-01-05"), ("apply", "2026-01-03"), ("apply", "2026-01-02")])
+        );
+        assert_eq!(run(&filter, 1), pairs(&[("apply", "2026-01-05")]));
+    }
+
+    #[test]
+    fn history_filters_by_contract_on_either_side() {
+        let filter = HistoryFilter {
+            contract_id: Some("token_v2".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&filter, 20),
+            pairs(&[("apply", "2026-01-05"), ("apply", "2026-01-02"), ("preview", "2026-01-01")])
+        );
+    }
+
+    #[test]
+    fn history_filters_by_date_range() {
+        let filter = HistoryFilter {
+            since: Some(parse_history_date("2026-01-02", false).unwrap()),
+            until: Some(parse_history_date("2026-01-04", true).unwrap()),
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&filter, 20),
+            pairs(&[("rollback", "2026-01-04"), ("apply", "2026-01-03"), ("apply", "2026-01-02")])
+        );
+
+        let combined = HistoryFilter {
+            action: Some("apply".into()),
+            contract_id: Some("token_v1".into()),
+            since: Some(parse_history_date("2026-01-02T09:00:00Z", false).unwrap()),
+            until: None,
+        };
+        assert_eq!(run(&combined, 20), pairs(&[("apply", "2026-01-02")]));
+    }
+
+    #[test]
+    fn invalid_dates_are_rejected() {
+        assert!(parse_history_date("last tuesday", false).is_err());
+    }
+
+    fn snapshot(contract_id: &str) -> ContractSnapshot {
+        ContractSnapshot {
+            contract_id: contract_id.to_string(),
+            version: None,
+            schema: BTreeMap::new(),
+            state: Map::new(),
+        }
+    }
+
+    fn applied(id: &str, old_id: &str, new_id: &str, timestamp: &str) -> MigrationRecord {
+        MigrationRecord {
+            id: id.to_string(),
+            backup_old_snapshot: Some(snapshot(old_id)),
+            ..record("apply", old_id, Some(new_id), timestamp)
+        }
+    }
+
+    fn rolled_back(id: &str, target: &str, timestamp: &str) -> MigrationRecord {
+        MigrationRecord {
+            id: id.to_string(),
+            rolled_back_id: Some(target.to_string()),
+            ..record("rollback", "token_v1", None, timestamp)
+        }
+    }
+
+    fn verify(lines: &[String], existing: &[&str]) -> Vec<(usize, String)> {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        for line in lines {
+            writeln!(file, "{}", line).unwrap();
+        }
+        let reader = BufReader::new(fs::File::open(file.path()).unwrap());
+        verify_history_lines(reader, |c| existing.contains(&c))
+            .unwrap()
+            .into_iter()
+            .map(|a| (a.line, a.message))
+            .collect()
+    }
+
+    fn json(r: &MigrationRecord) -> String {
+        serde_json::to_string(r).unwrap()
+    }
+
+    #[test]
+    fn consistent_history_has_no_anomalies() {
+        let lines = vec![
+            json(&applied("a1", "token_v1", "token_v2", "2026-01-01T00:00:00Z")),
+            json(&rolled_back("r1", "a1", "2026-01-02T00:00:00Z")),
+            json(&applied("a2", "vault_v1", "vault_v2", "2026-01-03T00:00:00Z")),
+        ];
+        assert!(verify(&lines, &["vault_v1", "vault_v2"]).is_empty());
+    }
+
+    #[test]
+    fn broken_history_reports_each_anomaly() {
+        let mut legacy_rollback = record("rollback", "token_v1", None, "2026-01-05T00:00:00Z");
+        legacy_rollback.warnings = vec!["Rolled back migration a1".into()];
+
+        let lines = vec![
+            // 1: rollback before the apply it references
+            json(&rolled_back("r0", "a1", "2026-01-01T00:00:00Z")),
+            // 2: apply whose new snapshot has been deleted
+            json(&applied("a1", "token_v1", "token_v2", "2026-01-02T00:00:00Z")),
+            // 3: timestamp goes backwards
+            json(&applied("a2", "vault_v1", "vault_v2", "2025-12-31T00:00:00Z")),
+            // 4: hand-edited garbage
+            "{not json".to_string(),
+            // 5: valid rollback of a1 (legacy form, id only in the warning)
+            json(&legacy_rollback),
+            // 6: second rollback of the same apply
+            json(&rolled_back("r2", "a1", "2026-01-06T00:00:00Z")),
+            // 7: rollback of an apply that never happened
+            json(&rolled_back("r3", "nope", "2026-01-07T00:00:00Z")),
+        ];
+
+        let anomalies = verify(&lines, &["token_v1", "vault_v1"]);
+        let lines_flagged: Vec<usize> = anomalies.iter().map(|(l, _)| *l).collect();
+        assert_eq!(lines_flagged, vec![1, 3, 3, 4, 6, 7], "{anomalies:#?}");
+
+        assert!(anomalies[0].1.contains("unknown or later apply a1"));
+        let line3: Vec<&str> = anomalies
+            .iter()
+            .filter(|(l, _)| *l == 3)
+            .map(|(_, m)| m.as_str())
+            .collect();
+        assert!(line3.iter().any(|m| m.contains("earlier than line 2")));
+        assert!(line3.iter().any(|m| m.contains("'vault_v2' no longer exists")));
+        assert!(anomalies[3].1.contains("unparseable"));
+        assert!(anomalies[4].1.contains("already rolled back"));
+        assert!(anomalies[5].1.contains("unknown or later apply nope"));
+    }
+
+    fn token_schema() -> BTreeMap<String, String> {
+        BTreeMap::from([
+            ("owner".to_string(), "string".to_string()),
+            ("supply".to_string(), "integer".to_string()),
+            ("paused".to_string(), "boolean".to_string()),
+        ])
+    }
+
+    #[test]
+    fn state_round_trips_through_a_file() {
+        let state: Map<String, Value> = serde_json::from_value(serde_json::json!({
+            "owner": "GABC",
+            "supply": 1_000_000,
+            "paused": false,
+        }))
+        .unwrap();
+        let file = tempfile::NamedTempFile::new().unwrap();
+
+        write_state_file(&state, file.path()).unwrap();
+        let read_back = read_state_file(file.path()).unwrap();
+        assert_eq!(read_back, state);
+
+        let validated = validate_state_against_schema(&token_schema(), read_back).unwrap();
+        assert_eq!(validated, state);
+    }
+
+    #[test]
+    fn import_rejects_state_that_violates_the_schema() {
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(
+            file.path(),
+            r#"{"owner": "GABC", "supply": "lots", "paused": "maybe", "admin": "GXYZ"}"#,
+        )
+        .unwrap();
+
+        let state = read_state_file(file.path()).unwrap();
+        let issues = validate_state_against_schema(&token_schema(), state).unwrap_err();
+
+        assert_eq!(issues.len(), 3, "{issues:?}");
+        assert!(issues.iter().any(|i| i.contains("'admin' is not part of the schema")));
+        assert!(issues.iter().any(|i| i.contains("'supply'")));
+        assert!(issues.iter().any(|i| i.contains("'paused'")));
+    }
+
+    #[test]
+    fn import_normalizes_convertible_values_and_rejects_non_objects() {
+        let state: Map<String, Value> =
+            serde_json::from_value(serde_json::json!({ "supply": "42", "paused": 1 })).unwrap();
+        let validated = validate_state_against_schema(&token_schema(), state).unwrap();
+        assert_eq!(validated["supply"], serde_json::json!(42));
+        assert_eq!(validated["paused"], serde_json::json!(true));
+
+        let file = tempfile::NamedTempFile::new().unwrap();
+        fs::write(file.path(), "[1, 2, 3]").unwrap();
+        assert!(read_state_file(file.path()).is_err());
+    }
 }
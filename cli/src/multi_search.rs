@@ -0,0 +1,305 @@
+//! Search several registry instances at once.
+//!
+//! Each registry is queried concurrently. Results are merged in registry
+//! order and de-duplicated by `contract_id`; every result records the
+//! registry it came from and any others that also list it. A registry that
+//! is down or returns garbage is reported but does not fail the search.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+use crate::config::Network;
+
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SearchHit {
+    /// Registry the result was taken from
+    pub source: String,
+    /// Other registries that returned the same contract_id
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub also_in: Vec<String>,
+    pub contract: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RegistryFailure {
+    pub registry: String,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MultiSearchResult {
+    pub results: Vec<SearchHit>,
+    pub failures: Vec<RegistryFailure>,
+}
+
+async fn search_registry(
+    client: &reqwest::Client,
+    api_url: &str,
+    query: &str,
+    network: Network,
+    verified_only: bool,
+) -> Result<Vec<Value>> {
+    let mut params = vec![("query", query.to_string()), ("network", network.to_string())];
+    if verified_only {
+        params.push(("verified_only", "true".to_string()));
+    }
+
+    let response = client
+        .get(format!("{}/api/contracts", api_url))
+        .query(&params)
+        .send()
+        .await
+        .context("request failed")?
+        .error_for_status()
+        .context("registry returned an error")?;
+    let data: Value = response.json().await.context("invalid JSON response")?;
+    data["items"]
+        .as_array()
+        .cloned()
+        .context("response has no items array")
+}
+
+/// Merge per-registry results (in registry order), keeping the first copy of
+/// each contract_id and noting where else it was found.
+pub fn merge_results(per_registry: Vec<(String, Vec<Value>)>) -> Vec<SearchHit> {
+    let mut merged: Vec<SearchHit> = Vec::new();
+    let mut index_by_id: HashMap<String, usize> = HashMap::new();
+
+    for (registry, items) in per_registry {
+        for contract in items {
+            let key = contract["contract_id"].as_str().map(str::to_string);
+            match key.as_ref().and_then(|k| index_by_id.get(k)) {
+                Some(&i) => {
+                    if merged[i].source != registry && !merged[i].also_in.contains(&registry) {
+                        merged[i].also_in.push(registry.clone());
+                    }
+                }
+                None => {
+                    if let Some(k) = key {
+                        index_by_id.insert(k, merged.len());
+                    }
+                    merged.push(SearchHit {
+                        source: registry.clone(),
+                        also_in: Vec::new(),
+                        contract,
+                    });
+                }
+            }
+        }
+    }
+    merged
+}
+
+/// Query every registry concurrently and merge what comes back.
+pub async fn search_all(
+    registries: &[String],
+    query: &str,
+    network: Network,
+    verified_only: bool,
+) -> MultiSearchResult {
+    let client = reqwest::Client::builder()
+        .timeout(REGISTRY_TIMEOUT)
+        .build()
+        .unwrap_or_default();
+
+    let mut tasks = JoinSet::new();
+    for (position, registry) in registries.iter().enumerate() {
+        let client = client.clone();
+        let registry = registry.clone();
+        let query = query.to_string();
+        tasks.spawn(async move {
+            let outcome = search_registry(&client, &registry, &query, network, verified_only).await;
+            (position, registry, outcome)
+        });
+    }
+
+    let mut outcomes = Vec::with_capacity(registries.len());
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(outcome) => outcomes.push(outcome),
+            Err(err) => log::warn!("registry search task failed: {}", err),
+        }
+    }
+    outcomes.sort_by_key(|(position, _, _)| *position);
+
+    let mut succeeded = Vec::new();
+    let mut failures = Vec::new();
+    for (_, registry, outcome) in outcomes {
+        match outcome {
+            Ok(items) => succeeded.push((registry, items)),
+            Err(err) => {
+                log::debug!("registry {} failed: {:#}", registry, err);
+                failures.push(RegistryFailure {
+                    registry,
+                    error: format!("{:#}", err),
+                });
+            }
+        }
+    }
+
+    MultiSearchResult {
+        results: merge_results(succeeded),
+        failures,
+    }
+}
+
+pub async fn search(
+    registries: &[String],
+    query: &str,
+    network: Network,
+    verified_only: bool,
+    json: bool,
+) -> Result<()> {
+    let outcome = search_all(registries, query, network, verified_only).await;
+    if outcome.failures.len() == registries.len() {
+        anyhow::bail!("All {} registries failed to respond", registries.len());
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&outcome)?);
+        return Ok(());
+    }
+
+    println!(
+        "\n{} {}",
+        "Search Results across".bold().cyan(),
+        format!("{} registries:", registries.len()).bold().cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+
+    for failure in &outcome.failures {
+        println!(
+            "{} {} ({})",
+            "⚠ Unavailable:".yellow(),
+            failure.registry,
+            failure.error.bright_black()
+        );
+    }
+
+    if outcome.results.is_empty() {
+        println!("{}", "No contracts found.".yellow());
+        return Ok(());
+    }
+
+    for hit in &outcome.results {
+        let contract = &hit.contract;
+        let name = contract["name"].as_str().unwrap_or("Unknown");
+        let contract_id = contract["contract_id"].as_str().unwrap_or("");
+        let is_verified = contract["is_verified"].as_bool().unwrap_or(false);
+
+        println!("\n{} {}", "●".green(), name.bold());
+        println!("  ID: {}", contract_id.bright_black());
+        println!(
+            "  Status: {} | Registry: {}",
+            if is_verified {
+                "✓ Verified".green()
+            } else {
+                "○ Unverified".yellow()
+            },
+            hit.source.bright_blue()
+        );
+        if !hit.also_in.is_empty() {
+            println!("  Also in: {}", hit.also_in.join(", ").bright_black());
+        }
+    }
+
+    println!("\n{}", "=".repeat(80).cyan());
+    println!("Found {} contract(s)\n", outcome.results.len());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Minimal HTTP server answering every request with `status` and `body`.
+    async fn mock_registry(status: &'static str, body: Value) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let body = body.to_string();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let body = body.clone();
+                tokio::spawn(async move {
+                    let mut buf = [0u8; 4096];
+                    let _ = socket.read(&mut buf).await;
+                    let response = format!(
+                        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+        format!("http://{}", addr)
+    }
+
+    fn contract(id: &str, name: &str) -> Value {
+        json!({ "contract_id": id, "name": name, "is_verified": true })
+    }
+
+    #[tokio::test]
+    async fn one_registry_failing_does_not_fail_the_search() {
+        let healthy = mock_registry(
+            "200 OK",
+            json!({ "items": [contract("CAAA", "Token"), contract("CBBB", "Vault")] }),
+        )
+        .await;
+        let failing = mock_registry("500 Internal Server Error", json!({ "error": "boom" })).await;
+
+        let outcome = search_all(
+            &[failing.clone(), healthy.clone()],
+            "token",
+            Network::Testnet,
+            false,
+        )
+        .await;
+
+        assert_eq!(outcome.results.len(), 2);
+        assert!(outcome.results.iter().all(|h| h.source == healthy));
+        assert_eq!(outcome.failures.len(), 1);
+        assert_eq!(outcome.failures[0].registry, failing);
+    }
+
+    #[tokio::test]
+    async fn results_are_deduplicated_and_annotated() {
+        let first = mock_registry(
+            "200 OK",
+            json!({ "items": [contract("CAAA", "Token"), contract("CBBB", "Vault")] }),
+        )
+        .await;
+        let second = mock_registry(
+            "200 OK",
+            json!({ "items": [contract("CBBB", "Vault (mirror)"), contract("CCCC", "Oracle")] }),
+        )
+        .await;
+
+        let outcome = search_all(&[first.clone(), second.clone()], "x", Network::Testnet, false).await;
+
+        let ids: Vec<&str> = outcome
+            .results
+            .iter()
+            .map(|h| h.contract["contract_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(ids, vec!["CAAA", "CBBB", "CCCC"]);
+        assert!(outcome.failures.is_empty());
+
+        let vault = &outcome.results[1];
+        assert_eq!(vault.source, first);
+        assert_eq!(vault.contract["name"], "Vault");
+        assert_eq!(vault.also_in, vec![second.clone()]);
+        assert_eq!(outcome.results[2].source, second);
+    }
+}
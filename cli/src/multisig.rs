@@ -174,7 +174,7 @@ pub async fn sign_proposal(
     api_url: &str,
     proposal_id: &str,
     signer_address: &str,
-    signature_data: Option<&str>,
+    signature_data: &str,
 ) -> Result<()> {
     let client = reqwest::Client::new();
     let url = format!("{}/api/contracts/{}/sign", api_url, proposal_id);
@@ -299,6 +299,9 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
     let proposal = &data["proposal"];
     let policy = &data["policy"];
     let signatures = data["signatures"].as_array().cloned().unwrap_or_default();
+    let total = data["signatures_total"]
+        .as_i64()
+        .unwrap_or(signatures.len() as i64);
     let needed = data["signatures_needed"].as_i64().unwrap_or(0);
 
     println!("\n{}", "Proposal Information:".bold().cyan());
@@ -375,7 +378,7 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
     println!(
         "\n  {} Signatures: {}/{} collected{}",
         "→".bright_black(),
-        signatures.len(),
+        total,
         policy["threshold"].as_i64().unwrap_or(0),
         if needed > 0 {
             format!(" ({} more needed)", needed.to_string().yellow())
@@ -394,6 +397,14 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
             sig["signed_at"].as_str().unwrap_or("?")
         );
     }
+    if (signatures.len() as i64) < total {
+        println!(
+            "    {} and {} more (see /api/contracts/{}/signatures)",
+            "…".bright_black(),
+            total - signatures.len() as i64,
+            proposal_id
+        );
+    }
 
     println!("\n{}", "=".repeat(70).cyan());
     println!();
@@ -0,0 +1,9 @@
+-- Admin-configurable resource quotas per publisher
+CREATE TABLE publisher_quotas (
+    publisher_id UUID PRIMARY KEY REFERENCES publishers(id) ON DELETE CASCADE,
+    max_contracts BIGINT NOT NULL,
+    max_storage_bytes BIGINT NOT NULL,
+    max_versions BIGINT NOT NULL,
+    updated_by TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
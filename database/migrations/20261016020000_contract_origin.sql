@@ -0,0 +1,5 @@
+-- Link contracts promoted to another network back to their origin row
+ALTER TABLE contracts
+    ADD COLUMN origin_contract_id UUID REFERENCES contracts(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_contracts_origin_contract_id ON contracts(origin_contract_id);
@@ -0,0 +1,14 @@
+-- Per-network fee constants used by the cost estimator
+CREATE TABLE network_fee_schedules (
+    network network_type PRIMARY KEY,
+    base_gas_cost BIGINT NOT NULL,
+    storage_cost_per_kb BIGINT NOT NULL,
+    bandwidth_cost_per_kb BIGINT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO network_fee_schedules (network, base_gas_cost, storage_cost_per_kb, bandwidth_cost_per_kb)
+VALUES
+    ('mainnet', 100000, 50000, 10000),
+    ('testnet', 50000, 25000, 5000),
+    ('futurenet', 20000, 10000, 2000);
@@ -0,0 +1,2 @@
+-- Keep the (tail-truncated) build output of failed verification attempts
+ALTER TABLE verifications ADD COLUMN build_log TEXT;
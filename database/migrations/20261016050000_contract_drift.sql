@@ -0,0 +1,9 @@
+-- On-chain reconciliation: flag contracts whose deployed state no longer
+-- matches the registry record
+ALTER TABLE contracts
+    ADD COLUMN drift_detected BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN onchain_wasm_hash VARCHAR(64),
+    ADD COLUMN last_reconciled_at TIMESTAMPTZ;
+
+CREATE INDEX idx_contracts_last_reconciled_at ON contracts(last_reconciled_at NULLS FIRST);
+CREATE INDEX idx_contracts_drift_detected ON contracts(drift_detected) WHERE drift_detected;
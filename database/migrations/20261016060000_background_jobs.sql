@@ -0,0 +1,12 @@
+-- Run status for periodic background tasks (aggregation, popularity, ...)
+CREATE TABLE background_jobs (
+    job_name         VARCHAR(100) PRIMARY KEY,
+    interval_seconds INTEGER      NOT NULL,
+    last_run_at      TIMESTAMPTZ,
+    last_success_at  TIMESTAMPTZ,
+    last_duration_ms BIGINT,
+    rows_processed   BIGINT,
+    last_error       TEXT,
+    run_count        BIGINT       NOT NULL DEFAULT 0,
+    failure_count    BIGINT       NOT NULL DEFAULT 0
+);
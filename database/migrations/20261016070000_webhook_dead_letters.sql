@@ -0,0 +1,17 @@
+-- Webhook deliveries that exhausted their retries, parked for inspection
+-- and manual replay
+CREATE TABLE webhook_dead_letters (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    delivery_id UUID NOT NULL UNIQUE REFERENCES webhook_deliveries(id) ON DELETE CASCADE,
+    webhook_id UUID NOT NULL REFERENCES webhooks(id) ON DELETE CASCADE,
+    event_type TEXT NOT NULL,
+    payload JSONB NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT,
+    dead_lettered_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    replay_count INTEGER NOT NULL DEFAULT 0,
+    replayed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_webhook_dead_letters_parked
+    ON webhook_dead_letters(dead_lettered_at DESC) WHERE replayed_at IS NULL;
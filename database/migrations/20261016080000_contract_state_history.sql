@@ -0,0 +1,22 @@
+-- Versioned key/value state per contract: latest value plus full history
+CREATE TABLE contract_state (
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    value JSONB NOT NULL,
+    version INTEGER NOT NULL DEFAULT 1,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (contract_id, key)
+);
+
+CREATE TABLE contract_state_history (
+    id BIGSERIAL PRIMARY KEY,
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    key TEXT NOT NULL,
+    value JSONB NOT NULL,
+    version INTEGER NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_id, key, version)
+);
+
+CREATE INDEX idx_contract_state_history_lookup
+    ON contract_state_history(contract_id, key, recorded_at DESC);
@@ -0,0 +1,12 @@
+-- Normalized contract search queries, for discovery analytics.
+-- Deliberately holds no searcher-identifying data.
+CREATE TABLE search_queries (
+    id BIGSERIAL PRIMARY KEY,
+    query VARCHAR(200) NOT NULL,
+    result_count BIGINT NOT NULL,
+    zero_result BOOLEAN NOT NULL,
+    searched_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_search_queries_searched_at ON search_queries(searched_at DESC);
+CREATE INDEX idx_search_queries_zero_result ON search_queries(searched_at DESC) WHERE zero_result;
@@ -0,0 +1,2 @@
+-- Backup bundles now live in object storage; rows keep the key.
+ALTER TABLE contract_backups ADD COLUMN object_key TEXT;
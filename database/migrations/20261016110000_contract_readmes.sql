@@ -0,0 +1,7 @@
+-- Publisher-supplied markdown documentation, one per contract
+CREATE TABLE contract_readmes (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    markdown TEXT NOT NULL CHECK (octet_length(markdown) <= 65536),
+    updated_by VARCHAR(56) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,12 @@
+-- Operator overrides for trust score factor weights (single row).
+-- Absent row means the built-in defaults apply.
+CREATE TABLE trust_score_weights (
+    id SMALLINT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    verified DOUBLE PRECISION NOT NULL,
+    audit DOUBLE PRECISION NOT NULL,
+    usage DOUBLE PRECISION NOT NULL,
+    age DOUBLE PRECISION NOT NULL,
+    no_vulns DOUBLE PRECISION NOT NULL,
+    updated_by VARCHAR(56) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,4 @@
+-- Audit actions for maturity, maintenance and cross-network deployment changes.
+ALTER TYPE audit_action_type ADD VALUE IF NOT EXISTS 'maturity_changed';
+ALTER TYPE audit_action_type ADD VALUE IF NOT EXISTS 'maintenance_changed';
+ALTER TYPE audit_action_type ADD VALUE IF NOT EXISTS 'deployed';
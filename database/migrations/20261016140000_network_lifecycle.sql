@@ -0,0 +1,13 @@
+-- Lifecycle metadata per Stellar network. Futurenet is reset periodically;
+-- contracts registered before `last_reset_at` no longer exist on chain.
+CREATE TABLE network_lifecycle (
+    network network_type PRIMARY KEY,
+    active BOOLEAN NOT NULL DEFAULT TRUE,
+    deprecated BOOLEAN NOT NULL DEFAULT FALSE,
+    reset_notice TEXT,
+    last_reset_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO network_lifecycle (network) VALUES ('mainnet'), ('testnet'), ('futurenet')
+ON CONFLICT DO NOTHING;
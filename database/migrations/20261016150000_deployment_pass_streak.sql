@@ -0,0 +1,4 @@
+-- Consecutive passing health checks since the last failure; switching to a
+-- green deployment requires a minimum streak.
+ALTER TABLE contract_deployments
+    ADD COLUMN consecutive_passes INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,15 @@
+-- Contract-scoped API tokens: let a contract push its own state without the
+-- owner's session. Only the SHA-256 of the token is stored.
+CREATE TABLE contract_api_tokens (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    label TEXT,
+    created_by VARCHAR(56) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ,
+    last_used_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_contract_api_tokens_contract ON contract_api_tokens(contract_id);
@@ -0,0 +1,11 @@
+-- approval_threshold is a percentage of cast voting power; quorum_required is
+-- an absolute amount of voting power. NOT VALID leaves any existing rows alone
+-- while enforcing the ranges for new and updated proposals.
+ALTER TABLE governance_proposals
+    ADD CONSTRAINT governance_proposals_approval_threshold_range
+        CHECK (approval_threshold BETWEEN 0 AND 100) NOT VALID,
+    ADD CONSTRAINT governance_proposals_quorum_non_negative
+        CHECK (quorum_required >= 0) NOT VALID;
+
+COMMENT ON COLUMN governance_proposals.quorum_required IS 'Minimum total voting power cast (absolute)';
+COMMENT ON COLUMN governance_proposals.approval_threshold IS 'Percentage (0-100) of cast voting power that must vote for';
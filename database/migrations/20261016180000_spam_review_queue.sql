@@ -0,0 +1,21 @@
+-- Contracts held back from listings until an admin reviews them, either
+-- because the publish-time spam heuristic fired or an admin flagged them.
+ALTER TABLE contracts
+    ADD COLUMN pending_review BOOLEAN NOT NULL DEFAULT false;
+
+CREATE INDEX idx_contracts_pending_review ON contracts(pending_review) WHERE pending_review;
+
+CREATE TABLE contract_review_queue (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    score DOUBLE PRECISION NOT NULL,
+    reasons TEXT[] NOT NULL DEFAULT '{}',
+    -- 'heuristic', or the admin address for manual flags
+    flagged_by TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    resolved_at TIMESTAMPTZ,
+    -- 'approved' or 'rejected'
+    resolution TEXT,
+    resolved_by TEXT
+);
+
+CREATE INDEX idx_contract_review_queue_open ON contract_review_queue(created_at) WHERE resolved_at IS NULL;
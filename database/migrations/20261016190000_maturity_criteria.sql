@@ -0,0 +1,26 @@
+-- Configurable maturity promotion criteria, one row per (level, criterion).
+-- `threshold` is the minimum for versions/usage and the maximum number of
+-- unresolved high-severity findings for security_detections. The seed rows
+-- reproduce the previously hardcoded requirements.
+CREATE TABLE IF NOT EXISTS maturity_criteria (
+    level maturity_level NOT NULL,
+    criterion TEXT NOT NULL
+        CHECK (criterion IN ('verified', 'versions', 'usage', 'security_detections')),
+    required BOOLEAN NOT NULL DEFAULT TRUE,
+    threshold BIGINT CHECK (threshold IS NULL OR threshold >= 0),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (level, criterion)
+);
+
+INSERT INTO maturity_criteria (level, criterion, required, threshold) VALUES
+    ('beta', 'verified', TRUE, NULL),
+    ('beta', 'versions', TRUE, 1),
+    ('stable', 'verified', TRUE, NULL),
+    ('stable', 'versions', TRUE, 2),
+    ('stable', 'usage', TRUE, 10),
+    ('stable', 'security_detections', TRUE, 0),
+    ('mature', 'verified', TRUE, NULL),
+    ('mature', 'versions', TRUE, 5),
+    ('mature', 'usage', TRUE, 100),
+    ('mature', 'security_detections', TRUE, 0)
+ON CONFLICT (level, criterion) DO NOTHING;
@@ -0,0 +1,4 @@
+-- Debounced contract detail views, flushed in batches by the API
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS view_count BIGINT NOT NULL DEFAULT 0;
+
+CREATE INDEX IF NOT EXISTS idx_contracts_view_count ON contracts (view_count DESC);
@@ -0,0 +1,10 @@
+-- Optional delivery scopes for webhooks. An empty array means "any".
+ALTER TABLE webhooks
+    ADD COLUMN IF NOT EXISTS contract_ids UUID[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS publisher_ids UUID[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS networks TEXT[] NOT NULL DEFAULT '{}';
+
+-- Containment (@>) lookups in the delivery fan-out
+CREATE INDEX IF NOT EXISTS idx_webhooks_contract_ids ON webhooks USING GIN (contract_ids) WHERE active;
+CREATE INDEX IF NOT EXISTS idx_webhooks_publisher_ids ON webhooks USING GIN (publisher_ids) WHERE active;
+CREATE INDEX IF NOT EXISTS idx_webhooks_networks ON webhooks USING GIN (networks) WHERE active;
@@ -0,0 +1,12 @@
+-- Operator overrides for popularity score weights (single row).
+-- Absent row means the built-in defaults apply.
+CREATE TABLE popularity_weights (
+    id SMALLINT PRIMARY KEY DEFAULT 1 CHECK (id = 1),
+    deployments DOUBLE PRECISION NOT NULL,
+    interactions DOUBLE PRECISION NOT NULL,
+    uniqueness DOUBLE PRECISION NOT NULL,
+    recency DOUBLE PRECISION NOT NULL,
+    verification DOUBLE PRECISION NOT NULL,
+    updated_by VARCHAR(56) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
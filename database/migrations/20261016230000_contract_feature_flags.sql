@@ -0,0 +1,3 @@
+-- Per-contract feature flags, toggled by the contract's publisher.
+-- Missing keys take their defaults in the API.
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS feature_flags JSONB NOT NULL DEFAULT '{}'::jsonb;
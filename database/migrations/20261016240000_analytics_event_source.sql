@@ -0,0 +1,7 @@
+-- Events recorded by the indexer carry the on-chain event they came from, so
+-- re-processing a ledger does not count the same deployment twice.
+ALTER TABLE analytics_events ADD COLUMN IF NOT EXISTS source_event_id TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_analytics_events_source_event
+    ON analytics_events (source_event_id)
+    WHERE source_event_id IS NOT NULL;
@@ -0,0 +1,10 @@
+-- Human-friendly short names (e.g. `defi/amm-v2`) that resolve to a contract.
+-- Aliases are globally unique; a contract may have several.
+CREATE TABLE IF NOT EXISTS contract_aliases (
+    alias TEXT PRIMARY KEY,
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    created_by VARCHAR(56) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_aliases_contract ON contract_aliases(contract_id);
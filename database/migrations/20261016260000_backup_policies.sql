@@ -0,0 +1,10 @@
+-- Per-contract backup schedules, run by the API's backup scheduler.
+CREATE TABLE IF NOT EXISTS contract_backup_policies (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    frequency TEXT NOT NULL CHECK (frequency IN ('daily', 'weekly')),
+    retention_count INTEGER NOT NULL CHECK (retention_count > 0),
+    include_state BOOLEAN NOT NULL DEFAULT FALSE,
+    last_run_at TIMESTAMPTZ,
+    updated_by VARCHAR(56) NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
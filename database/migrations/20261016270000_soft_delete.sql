@@ -0,0 +1,7 @@
+-- Soft deletion for contracts and publishers. Rows with deleted_at set are
+-- permanently removed by the API's purge task once past retention.
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE publishers ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_contracts_deleted_at ON contracts(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_publishers_deleted_at ON publishers(deleted_at) WHERE deleted_at IS NOT NULL;
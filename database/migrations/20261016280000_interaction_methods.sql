@@ -0,0 +1,13 @@
+-- Method-level interaction analytics. The indexer records each contract
+-- method call with the on-chain operation it came from, so re-processing a
+-- ledger does not count the same call twice.
+ALTER TABLE contract_interactions ADD COLUMN IF NOT EXISTS method_name VARCHAR(255);
+ALTER TABLE contract_interactions ADD COLUMN IF NOT EXISTS source_event_id TEXT;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_contract_interactions_source_event
+    ON contract_interactions (source_event_id)
+    WHERE source_event_id IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contract_interactions_method
+    ON contract_interactions (contract_id, method_name)
+    WHERE method_name IS NOT NULL;
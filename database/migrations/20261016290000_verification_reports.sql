@@ -0,0 +1,13 @@
+-- Build inputs and output of each successful verification, so third parties
+-- can reproduce the build independently
+CREATE TABLE verification_reports (
+    verification_id UUID PRIMARY KEY REFERENCES verifications(id) ON DELETE CASCADE,
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    compiler_version VARCHAR(50),
+    build_params JSONB,
+    source_hash CHAR(64) NOT NULL,
+    wasm_hash VARCHAR(64) NOT NULL,
+    verified_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_verification_reports_contract ON verification_reports(contract_id, verified_at DESC);
@@ -0,0 +1,9 @@
+-- Webhooks can opt into one batched delivery per day instead of one per event.
+-- Their events are held as 'digest_pending' deliveries until the digest task
+-- folds them into a single 'digest.daily' delivery and marks them 'digested'.
+ALTER TABLE webhooks
+    ADD COLUMN IF NOT EXISTS delivery_mode TEXT NOT NULL DEFAULT 'immediate'
+        CHECK (delivery_mode IN ('immediate', 'daily_digest'));
+
+CREATE INDEX IF NOT EXISTS idx_webhook_deliveries_digest_pending
+    ON webhook_deliveries(webhook_id, created_at) WHERE status = 'digest_pending';
@@ -0,0 +1,18 @@
+-- On-chain first sighting of a contract, as opposed to `created_at` (when the
+-- registry row was inserted). Set by the indexer from the close time of the
+-- earliest deployment operation it sees; NULL for contracts never indexed.
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS first_seen_at TIMESTAMPTZ;
+
+-- Backfill from deployment events already recorded by the indexer. Events
+-- written before `deployed_at` was added to their metadata only carry the
+-- time they were recorded, which is still no later than the row's creation.
+UPDATE contracts c
+SET first_seen_at = d.first_seen_at
+FROM (
+    SELECT contract_id,
+           MIN(COALESCE((metadata->>'deployed_at')::timestamptz, created_at)) AS first_seen_at
+    FROM analytics_events
+    WHERE event_type = 'contract_deployed' AND metadata->>'source' = 'indexer'
+    GROUP BY contract_id
+) d
+WHERE d.contract_id = c.id AND c.first_seen_at IS NULL;
@@ -0,0 +1,26 @@
+-- Every status transition of a deployment proposal, recorded by trigger so
+-- the audit trail covers all code paths that move a proposal along
+CREATE TABLE proposal_status_changes (
+    id          UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    proposal_id UUID            NOT NULL REFERENCES deploy_proposals(id) ON DELETE CASCADE,
+    from_status proposal_status NOT NULL,
+    to_status   proposal_status NOT NULL,
+    changed_at  TIMESTAMPTZ     NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_proposal_status_changes_proposal ON proposal_status_changes(proposal_id, changed_at);
+
+CREATE OR REPLACE FUNCTION record_proposal_status_change()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO proposal_status_changes (proposal_id, from_status, to_status)
+    VALUES (NEW.id, OLD.status, NEW.status);
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER record_deploy_proposal_status_change
+    AFTER UPDATE OF status ON deploy_proposals
+    FOR EACH ROW
+    WHEN (OLD.status IS DISTINCT FROM NEW.status)
+    EXECUTE FUNCTION record_proposal_status_change();
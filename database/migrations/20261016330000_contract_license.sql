@@ -0,0 +1,4 @@
+-- SPDX license identifier or expression declared by the publisher
+ALTER TABLE contracts ADD COLUMN license VARCHAR(255);
+
+CREATE INDEX idx_contracts_license ON contracts(license) WHERE license IS NOT NULL;
@@ -0,0 +1,21 @@
+-- Per-subscriber notification channel settings; channels without a row use
+-- their default (sse and in_app on, email off)
+CREATE TABLE notification_preferences (
+    subscriber_address VARCHAR(56) NOT NULL,
+    channel TEXT NOT NULL CHECK (channel IN ('sse', 'in_app', 'email')),
+    enabled BOOLEAN NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (subscriber_address, channel)
+);
+
+-- In-app notification inbox
+CREATE TABLE notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subscriber_address VARCHAR(56) NOT NULL,
+    event_type VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    read_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_notifications_subscriber ON notifications(subscriber_address, created_at DESC);
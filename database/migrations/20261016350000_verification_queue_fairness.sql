@@ -0,0 +1,12 @@
+-- Paid or urgent attempts are claimed ahead of the rest of the queue
+ALTER TABLE verifications ADD COLUMN priority BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- When each publisher last had a queued attempt claimed. The worker serves
+-- the publisher who has waited longest, so one large batch cannot starve
+-- everyone else's attempts.
+CREATE TABLE verification_queue_turns (
+    publisher_id UUID PRIMARY KEY REFERENCES publishers(id) ON DELETE CASCADE,
+    last_claimed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_verifications_pending ON verifications(priority DESC, created_at) WHERE status = 'pending';
@@ -0,0 +1,53 @@
+-- Contract method benchmarks: one record per run of N iterations, the
+-- individual iterations, and p95 regression alerts between runs.
+
+CREATE TYPE benchmark_status AS ENUM ('pending', 'running', 'completed', 'failed');
+
+CREATE TABLE benchmark_records (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_version TEXT NOT NULL,
+    method_name TEXT NOT NULL,
+    iterations INTEGER NOT NULL,
+    args_json JSONB,
+    status benchmark_status NOT NULL DEFAULT 'pending',
+    min_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    max_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    avg_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    p95_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    p99_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    stddev_ms DOUBLE PRECISION NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_benchmark_records_contract_method
+    ON benchmark_records(contract_id, method_name, created_at DESC);
+
+CREATE TABLE benchmark_runs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    benchmark_id UUID NOT NULL REFERENCES benchmark_records(id) ON DELETE CASCADE,
+    iteration INTEGER NOT NULL,
+    execution_time_ms DOUBLE PRECISION NOT NULL,
+    cpu_instructions BIGINT,
+    memory_bytes BIGINT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (benchmark_id, iteration)
+);
+
+-- Separate from performance_alerts, which tracks metric thresholds
+CREATE TABLE benchmark_alerts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    method_name TEXT NOT NULL,
+    baseline_benchmark_id UUID NOT NULL REFERENCES benchmark_records(id) ON DELETE CASCADE,
+    current_benchmark_id UUID NOT NULL REFERENCES benchmark_records(id) ON DELETE CASCADE,
+    baseline_p95_ms DOUBLE PRECISION NOT NULL,
+    current_p95_ms DOUBLE PRECISION NOT NULL,
+    regression_pct DOUBLE PRECISION NOT NULL,
+    alert_threshold_pct DOUBLE PRECISION NOT NULL,
+    resolved BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_benchmark_alerts_contract ON benchmark_alerts(contract_id, resolved);
@@ -0,0 +1,30 @@
+-- Security audits against the checklist: one row per audit and one per
+-- checklist item. Read by maturity promotion (unresolved high-severity
+-- detections), trust scores and the leaderboard.
+
+CREATE TABLE security_audits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_source TEXT,
+    auditor TEXT NOT NULL,
+    audit_date TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    overall_score DOUBLE PRECISION NOT NULL DEFAULT 0,
+    summary TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_security_audits_contract_date ON security_audits(contract_id, audit_date DESC);
+
+CREATE TABLE audit_checks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    audit_id UUID NOT NULL REFERENCES security_audits(id) ON DELETE CASCADE,
+    check_id TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('passed', 'failed', 'not_applicable', 'pending')),
+    notes TEXT,
+    auto_detected BOOLEAN NOT NULL DEFAULT FALSE,
+    evidence TEXT,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (audit_id, check_id)
+);
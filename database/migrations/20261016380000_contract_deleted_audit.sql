@@ -0,0 +1,2 @@
+-- Audit action for a contract soft-deleted through DELETE /api/contracts/:id.
+ALTER TYPE audit_action_type ADD VALUE IF NOT EXISTS 'contract_deleted';
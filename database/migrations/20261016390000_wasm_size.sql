@@ -0,0 +1,3 @@
+-- Size of each contract's uploaded WASM, summed per publisher for the
+-- storage quota. NULL until a binary is uploaded.
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS wasm_size_bytes BIGINT;
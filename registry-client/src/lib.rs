@@ -0,0 +1,302 @@
+//! Typed async client for the Soroban Registry API.
+//!
+//! Every method returns the DTOs from `shared::models`, so a change to the
+//! API's response shape shows up as a [`ClientError::Decode`] naming the
+//! endpoint and the field, rather than as silently missing data.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), registry_client::ClientError> {
+//! use registry_client::RegistryClient;
+//! use shared::ContractSearchParams;
+//!
+//! let client = RegistryClient::new("http://localhost:3001");
+//! let page = client
+//!     .search(&ContractSearchParams {
+//!         query: Some("token".into()),
+//!         ..Default::default()
+//!     })
+//!     .await?;
+//! for contract in page.items {
+//!     println!("{} {}", contract.contract_id, contract.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::{Method, RequestBuilder, Response};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use shared::{
+    Contract, ContractGetResponse, ContractSearchParams, Network, PaginatedResponse, PublishRequest,
+    PublishResponse,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The request never got a response (connection refused, timeout, ...)
+    #[error("request to {url} failed: {source}")]
+    Transport {
+        url: String,
+        #[source]
+        source: reqwest::Error,
+    },
+    /// The API answered with a non-success status
+    #[error("{url} returned {status}: {message}")]
+    Api {
+        url: String,
+        status: u16,
+        /// Machine-readable error code from the API, e.g. `ContractNotFound`
+        error: Option<String>,
+        message: String,
+    },
+    /// The response body did not match the expected type
+    #[error("unexpected response from {url} (expected {expected}): {source}")]
+    Decode {
+        url: String,
+        /// Short name of the type the body was decoded as, e.g. `Vec<Contract>`
+        expected: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl ClientError {
+    /// HTTP status of an API error response
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Error body returned by the API (see `api/src/error.rs`)
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: Option<String>,
+    message: Option<String>,
+}
+
+/// Decode a response body as `T`, naming the endpoint and type on failure.
+pub fn decode<T: DeserializeOwned>(url: &str, body: &str) -> Result<T> {
+    serde_json::from_str(body).map_err(|source| ClientError::Decode {
+        url: url.to_string(),
+        expected: short_type_name::<T>(),
+        source,
+    })
+}
+
+/// `std::any::type_name` without module paths:
+/// `shared::models::PaginatedResponse<shared::models::Contract>` -> `PaginatedResponse<Contract>`
+fn short_type_name<T>() -> String {
+    let mut out = String::new();
+    let mut path = String::new();
+    for c in std::any::type_name::<T>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            out.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            out.push(c);
+        }
+    }
+    out.push_str(path.rsplit("::").next().unwrap_or_default());
+    out
+}
+
+#[derive(Debug, Clone)]
+pub struct RegistryClient {
+    http: reqwest::Client,
+    base_url: String,
+}
+
+impl RegistryClient {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self::with_http_client(base_url, reqwest::Client::new())
+    }
+
+    /// Use a preconfigured `reqwest::Client` (timeouts, proxies, default headers).
+    pub fn with_http_client(base_url: impl Into<String>, http: reqwest::Client) -> Self {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        Self { http, base_url }
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// GET /api/contracts — search and filter contracts
+    pub async fn search(&self, params: &ContractSearchParams) -> Result<PaginatedResponse<Contract>> {
+        let request = self.request(Method::GET, "/api/contracts").query(&search_pairs(params));
+        self.send("/api/contracts", request).await
+    }
+
+    /// The most recent contracts on `network`
+    pub async fn list(&self, limit: i64, network: Network) -> Result<PaginatedResponse<Contract>> {
+        self.search(&ContractSearchParams {
+            network: Some(network),
+            limit: Some(limit),
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// GET /api/contracts/:id — by registry UUID or on-chain contract id.
+    /// With `network`, the response carries that network's config slice.
+    pub async fn get_contract(&self, id: &str, network: Option<Network>) -> Result<ContractGetResponse> {
+        let path = format!("/api/contracts/{}", id);
+        let mut request = self.request(Method::GET, &path);
+        if let Some(network) = network {
+            request = request.query(&[("network", network.to_string())]);
+        }
+        self.send(&path, request).await
+    }
+
+    /// POST /api/contracts
+    pub async fn publish(&self, req: &PublishRequest) -> Result<PublishResponse> {
+        let request = self.request(Method::POST, "/api/contracts").json(req);
+        self.send("/api/contracts", request).await
+    }
+
+    /// GET any endpoint and decode its body as `T`, for endpoints without a
+    /// dedicated method.
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T> {
+        let request = self.request(Method::GET, path);
+        self.send(path, request).await
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, self.url(path))
+    }
+
+    async fn send<T: DeserializeOwned>(&self, path: &str, request: RequestBuilder) -> Result<T> {
+        let url = self.url(path);
+        let response = request.send().await.map_err(|source| ClientError::Transport {
+            url: url.clone(),
+            source,
+        })?;
+        let (status, body) = read_body(&url, response).await?;
+        if !(200..300).contains(&status) {
+            return Err(api_error(url, status, &body));
+        }
+        decode(&url, &body)
+    }
+}
+
+async fn read_body(url: &str, response: Response) -> Result<(u16, String)> {
+    let status = response.status().as_u16();
+    let body = response.text().await.map_err(|source| ClientError::Transport {
+        url: url.to_string(),
+        source,
+    })?;
+    Ok((status, body))
+}
+
+fn api_error(url: String, status: u16, body: &str) -> ClientError {
+    let parsed: Option<ErrorBody> = serde_json::from_str(body).ok();
+    let (error, message) = match parsed {
+        Some(ErrorBody { error, message }) => (error, message),
+        None => (None, None),
+    };
+    ClientError::Api {
+        url,
+        status,
+        message: message.unwrap_or_else(|| body.trim().to_string()),
+        error,
+    }
+}
+
+/// Query pairs for the search parameters that are set.
+fn search_pairs(params: &ContractSearchParams) -> Vec<(&'static str, String)> {
+    let mut pairs = Vec::new();
+    let mut push = |key: &'static str, value: Option<String>| {
+        if let Some(value) = value {
+            pairs.push((key, value));
+        }
+    };
+    push("query", params.query.clone());
+    push("network", params.network.as_ref().map(Network::to_string));
+    push("verified_only", params.verified_only.map(|v| v.to_string()));
+    push("category", params.category.clone());
+    push("exclude_tags", params.exclude_tags.clone());
+    push("exclude_category", params.exclude_category.clone());
+    push("maturity", params.maturity.as_ref().and_then(enum_value));
+    push("license", params.license.clone());
+    push("page", params.page.map(|v| v.to_string()));
+    push("limit", params.limit.map(|v| v.to_string()));
+    push("sort_by", params.sort_by.as_ref().and_then(enum_value));
+    push("sort_order", params.sort_order.as_ref().and_then(enum_value));
+    push("fields", params.fields.clone());
+    for network in params.networks.iter().flatten() {
+        pairs.push(("network", network.to_string()));
+    }
+    pairs
+}
+
+/// The serde name of a unit enum variant, e.g. `SortBy::CreatedAt` -> `createdat`
+fn enum_value<T: serde::Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value).ok()? {
+        serde_json::Value::String(s) => Some(s),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::{SortBy, SortOrder};
+
+    #[test]
+    fn search_pairs_include_only_set_params() {
+        let pairs = search_pairs(&ContractSearchParams {
+            query: Some("token".into()),
+            network: Some(Network::Testnet),
+            verified_only: Some(true),
+            sort_by: Some(SortBy::CreatedAt),
+            sort_order: Some(SortOrder::Desc),
+            ..Default::default()
+        });
+        assert_eq!(
+            pairs,
+            vec![
+                ("query", "token".to_string()),
+                ("network", "testnet".to_string()),
+                ("verified_only", "true".to_string()),
+                ("sort_by", "createdat".to_string()),
+                ("sort_order", "desc".to_string()),
+            ]
+        );
+        assert!(search_pairs(&ContractSearchParams::default()).is_empty());
+    }
+
+    #[test]
+    fn api_errors_carry_the_error_code() {
+        let err = api_error(
+            "http://registry/api/contracts/x".into(),
+            404,
+            r#"{"error":"ContractNotFound","message":"No contract found","code":404}"#,
+        );
+        assert_eq!(err.status(), Some(404));
+        assert!(matches!(&err, ClientError::Api { error: Some(code), .. } if code == "ContractNotFound"));
+        assert_eq!(err.to_string(), "http://registry/api/contracts/x returned 404: No contract found");
+
+        let plain = api_error("u".into(), 502, "Bad Gateway\n");
+        assert!(matches!(plain, ClientError::Api { error: None, ref message, .. } if message == "Bad Gateway"));
+    }
+
+    #[test]
+    fn decode_errors_name_the_type_and_missing_field() {
+        let err = decode::<Vec<Contract>>("http://registry/api/contracts", r#"[{"contract_id":"C"}]"#).unwrap_err();
+        let message = err.to_string();
+        assert!(message.starts_with("unexpected response from http://registry/api/contracts (expected Vec<Contract>)"));
+        assert!(message.contains("missing field"), "{}", message);
+        assert_eq!(short_type_name::<PaginatedResponse<Contract>>(), "PaginatedResponse<Contract>");
+    }
+}
@@ -0,0 +1,151 @@
+use mockito::{Matcher, Server};
+use registry_client::{ClientError, RegistryClient};
+use serde_json::json;
+use shared::{ContractSearchParams, Network, PublishRequest};
+
+const CONTRACT_ID: &str = "CAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAABSC4";
+const PUBLISHER: &str = "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC";
+
+fn contract_json(name: &str) -> serde_json::Value {
+    json!({
+        "id": uuid::Uuid::new_v4(),
+        "contract_id": CONTRACT_ID,
+        "wasm_hash": "ab".repeat(32),
+        "name": name,
+        "description": "A token",
+        "publisher_id": uuid::Uuid::new_v4(),
+        "network": "testnet",
+        "is_verified": true,
+        "category": "DeFi",
+        "tags": ["token"],
+        "created_at": chrono::Utc::now(),
+        "updated_at": chrono::Utc::now(),
+    })
+}
+
+#[tokio::test]
+async fn search_decodes_a_page_of_contracts() {
+    let mut server = Server::new_async().await;
+    let mock = server
+        .mock("GET", "/api/contracts")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("query".into(), "token".into()),
+            Matcher::UrlEncoded("network".into(), "testnet".into()),
+            Matcher::UrlEncoded("verified_only".into(), "true".into()),
+        ]))
+        .with_header("content-type", "application/json")
+        .with_body(json!({ "contracts": [contract_json("Token")], "total": 1, "page": 1, "pages": 1 }).to_string())
+        .create_async()
+        .await;
+
+    let client = RegistryClient::new(server.url());
+    let page = client
+        .search(&ContractSearchParams {
+            query: Some("token".into()),
+            network: Some(Network::Testnet),
+            verified_only: Some(true),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(page.total, 1);
+    assert_eq!(page.items[0].name, "Token");
+    assert!(page.items[0].is_verified);
+}
+
+#[tokio::test]
+async fn get_contract_passes_the_network() {
+    let mut server = Server::new_async().await;
+    let mut body = contract_json("Token");
+    body["current_network"] = json!("testnet");
+    let mock = server
+        .mock("GET", format!("/api/contracts/{}", CONTRACT_ID).as_str())
+        .match_query(Matcher::UrlEncoded("network".into(), "testnet".into()))
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let client = RegistryClient::new(format!("{}/", server.url()));
+    let response = client.get_contract(CONTRACT_ID, Some(Network::Testnet)).await.unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(response.contract.contract_id, CONTRACT_ID);
+    assert!(matches!(response.current_network, Some(Network::Testnet)));
+}
+
+#[tokio::test]
+async fn publish_posts_the_request_and_returns_warnings() {
+    let mut server = Server::new_async().await;
+    let mut body = contract_json("Token");
+    body["warnings"] = json!(["description is short"]);
+    let mock = server
+        .mock("POST", "/api/contracts")
+        .match_body(Matcher::PartialJson(json!({
+            "contract_id": CONTRACT_ID,
+            "name": "Token",
+            "network": "testnet",
+            "publisher_address": PUBLISHER,
+        })))
+        .with_status(201)
+        .with_body(body.to_string())
+        .create_async()
+        .await;
+
+    let client = RegistryClient::new(server.url());
+    let published = client
+        .publish(&PublishRequest {
+            contract_id: CONTRACT_ID.into(),
+            name: "Token".into(),
+            description: None,
+            network: Network::Testnet,
+            category: None,
+            tags: vec![],
+            source_url: None,
+            publisher_address: PUBLISHER.into(),
+            dependencies: vec![],
+            license: None,
+        })
+        .await
+        .unwrap();
+
+    mock.assert_async().await;
+    assert_eq!(published.contract.name, "Token");
+    assert_eq!(published.warnings, vec!["description is short"]);
+}
+
+#[tokio::test]
+async fn error_responses_become_api_errors() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/api/contracts/missing")
+        .with_status(404)
+        .with_body(json!({ "error": "ContractNotFound", "message": "No contract found", "code": 404 }).to_string())
+        .create_async()
+        .await;
+
+    let err = RegistryClient::new(server.url())
+        .get_contract("missing", None)
+        .await
+        .unwrap_err();
+    assert_eq!(err.status(), Some(404));
+    assert!(err.to_string().contains("No contract found"));
+}
+
+#[tokio::test]
+async fn unexpected_shapes_become_decode_errors() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/api/contracts")
+        .with_body(json!({ "items": [], "total": 0 }).to_string())
+        .create_async()
+        .await;
+
+    let err = RegistryClient::new(server.url())
+        .search(&ContractSearchParams::default())
+        .await
+        .unwrap_err();
+    assert!(matches!(err, ClientError::Decode { .. }));
+    assert!(err.to_string().contains("/api/contracts"));
+}